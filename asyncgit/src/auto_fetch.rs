@@ -0,0 +1,177 @@
+//! periodic fetch running in the background
+
+use crate::{
+	asyncjob::{AsyncJob, AsyncSingleJob, RunParams},
+	error::Result,
+	sync::{
+		cred::need_username_password, remotes::fetch_all, RepoPath,
+	},
+	AsyncGitNotification,
+};
+use crossbeam_channel::Sender;
+use std::{
+	sync::{Arc, Mutex},
+	time::{Duration, Instant, SystemTime},
+};
+
+#[derive(Clone)]
+struct AsyncAutoFetchJob {
+	result: Arc<Mutex<Option<Result<()>>>>,
+	repo: RepoPath,
+	prune: bool,
+	retries: usize,
+}
+
+impl AsyncJob for AsyncAutoFetchJob {
+	type Notification = AsyncGitNotification;
+	type Progress = ();
+
+	fn run(
+		&mut self,
+		_params: RunParams<Self::Notification, Self::Progress>,
+	) -> Result<Self::Notification> {
+		let res = fetch_all(
+			&self.repo,
+			self.prune,
+			self.retries,
+			&None,
+			&None,
+		);
+
+		if let Ok(mut result) = self.result.lock() {
+			*result = Some(res);
+		}
+
+		Ok(AsyncGitNotification::AutoFetch)
+	}
+}
+
+/// fetches all remotes every `interval` without user interaction.
+/// remotes asking for username and password are skipped since
+/// there is nobody to ask.
+pub struct AsyncAutoFetch {
+	job: AsyncSingleJob<AsyncAutoFetchJob>,
+	interval: Option<Duration>,
+	last_run: Option<Instant>,
+	last_fetch: Option<SystemTime>,
+}
+
+impl AsyncAutoFetch {
+	///
+	pub fn new(sender: &Sender<AsyncGitNotification>) -> Self {
+		Self {
+			job: AsyncSingleJob::new(sender.clone()),
+			interval: None,
+			last_run: None,
+			last_fetch: None,
+		}
+	}
+
+	/// `None` disables the background fetch
+	pub fn set_interval(&mut self, interval: Option<Duration>) {
+		self.interval = interval;
+	}
+
+	///
+	pub fn is_pending(&self) -> bool {
+		self.job.is_pending()
+	}
+
+	/// time of the last successful background fetch
+	pub const fn last_fetch(&self) -> Option<SystemTime> {
+		self.last_fetch
+	}
+
+	fn is_due(&self) -> bool {
+		self.interval.map_or(false, |interval| {
+			self.last_run
+				.map_or(true, |last| last.elapsed() >= interval)
+		})
+	}
+
+	/// spawns a fetch if the interval elapsed since the last one,
+	/// returns `true` if it did
+	pub fn update(
+		&mut self,
+		repo: &RepoPath,
+		prune: bool,
+		retries: usize,
+	) -> Result<bool> {
+		if !self.is_due() || self.is_pending() {
+			return Ok(false);
+		}
+
+		self.last_run = Some(Instant::now());
+
+		// no remote or one we cannot authenticate against on our own
+		if need_username_password(repo).unwrap_or(true) {
+			return Ok(false);
+		}
+
+		self.job.spawn(AsyncAutoFetchJob {
+			result: Arc::new(Mutex::new(None)),
+			repo: repo.clone(),
+			prune,
+			retries,
+		});
+
+		Ok(true)
+	}
+
+	/// result of the fetch finished last, `None` if it was taken before
+	pub fn take_result(&mut self) -> Option<Result<()>> {
+		let result = self
+			.job
+			.take_last()
+			.and_then(|job| job.result.lock().ok()?.take());
+
+		if matches!(result, Some(Ok(()))) {
+			self.last_fetch = Some(SystemTime::now());
+		}
+
+		result
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::{
+		branch_compare_upstream,
+		tests::{repo_clone, repo_init, write_commit_file},
+	};
+	use crossbeam_channel::unbounded;
+
+	#[test]
+	fn test_auto_fetch() {
+		let (remote_dir, remote) = repo_init().unwrap();
+		let remote_path = remote_dir.path().to_str().unwrap();
+		let (repo_dir, _repo) = repo_clone(remote_path).unwrap();
+		let repo_path: &RepoPath =
+			&repo_dir.path().to_str().unwrap().into();
+
+		write_commit_file(&remote, "f.txt", "foo", "c1");
+
+		let (sender, receiver) = unbounded();
+		let mut auto_fetch = AsyncAutoFetch::new(&sender);
+
+		assert!(!auto_fetch.update(repo_path, true, 0).unwrap());
+
+		auto_fetch.set_interval(Some(Duration::from_secs(60)));
+
+		assert!(auto_fetch.update(repo_path, true, 0).unwrap());
+		assert_eq!(
+			receiver.recv().unwrap(),
+			AsyncGitNotification::AutoFetch
+		);
+		assert!(matches!(auto_fetch.take_result(), Some(Ok(()))));
+		assert!(auto_fetch.last_fetch().is_some());
+
+		// not due again before the interval elapsed
+		assert!(!auto_fetch.update(repo_path, true, 0).unwrap());
+
+		let compare =
+			branch_compare_upstream(repo_path, "master").unwrap();
+		assert_eq!(compare.behind, 1);
+	}
+}
@@ -0,0 +1,75 @@
+//!
+
+use crate::{
+	asyncjob::{AsyncJob, RunParams},
+	error::Result,
+	sync::{branches_compare_upstream, BranchCompare, RepoPath},
+	AsyncGitNotification,
+};
+
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex},
+};
+
+enum JobState {
+	Request,
+	Response(Result<HashMap<String, BranchCompare>>),
+}
+
+/// ahead/behind of all local branches compared to their upstream
+#[derive(Clone)]
+pub struct AsyncBranchesCompareJob {
+	state: Arc<Mutex<Option<JobState>>>,
+	repo: RepoPath,
+}
+
+///
+impl AsyncBranchesCompareJob {
+	///
+	pub fn new(repo: RepoPath) -> Self {
+		Self {
+			repo,
+			state: Arc::new(Mutex::new(Some(JobState::Request))),
+		}
+	}
+
+	///
+	pub fn result(
+		&self,
+	) -> Option<Result<HashMap<String, BranchCompare>>> {
+		if let Ok(mut state) = self.state.lock() {
+			if let Some(state) = state.take() {
+				return match state {
+					JobState::Request => None,
+					JobState::Response(result) => Some(result),
+				};
+			}
+		}
+
+		None
+	}
+}
+
+impl AsyncJob for AsyncBranchesCompareJob {
+	type Notification = AsyncGitNotification;
+	type Progress = ();
+
+	fn run(
+		&mut self,
+		_params: RunParams<Self::Notification, Self::Progress>,
+	) -> Result<Self::Notification> {
+		if let Ok(mut state) = self.state.lock() {
+			*state = state.take().map(|state| match state {
+				JobState::Request => JobState::Response(
+					branches_compare_upstream(&self.repo),
+				),
+				JobState::Response(result) => {
+					JobState::Response(result)
+				}
+			});
+		}
+
+		Ok(AsyncGitNotification::BranchesCompare)
+	}
+}
@@ -1,6 +1,6 @@
 use crate::{
 	error::Result,
-	sync::{self, CommitId, RepoPath},
+	sync::{self, CommitId, FileDiffStats, RepoPath},
 	AsyncGitNotification, StatusItem,
 };
 use crossbeam_channel::Sender;
@@ -9,7 +9,7 @@ use std::sync::{
 	Arc, Mutex,
 };
 
-type ResultType = Vec<StatusItem>;
+type ResultType = (Vec<StatusItem>, FileDiffStats);
 struct Request<R, A>(R, A);
 
 ///
@@ -127,9 +127,15 @@ impl AsyncCommitFiles {
 
 		log::trace!("get_commit_files: {:?} ({})", params, res.len());
 
+		let stats = sync::get_commit_files_stats(
+			repo_path,
+			params.id,
+			params.other,
+		)?;
+
 		{
 			let mut current = arc_current.lock()?;
-			*current = Some(Request(params, res));
+			*current = Some(Request(params, (res, stats)));
 		}
 
 		Ok(())
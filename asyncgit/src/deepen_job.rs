@@ -0,0 +1,77 @@
+//!
+
+use crate::{
+	asyncjob::{AsyncJob, RunParams},
+	error::Result,
+	sync::{deepen, RepoPath},
+	AsyncGitNotification,
+};
+use std::sync::{Arc, Mutex};
+
+enum JobState {
+	Request(Option<u32>),
+	Response(Result<()>),
+}
+
+/// fetches more history into a shallow clone
+#[derive(Clone)]
+pub struct AsyncDeepenJob {
+	state: Arc<Mutex<Option<JobState>>>,
+	repo: RepoPath,
+	remote: String,
+}
+
+///
+impl AsyncDeepenJob {
+	/// `depth` of `None` fetches the whole history
+	pub fn new(
+		repo: RepoPath,
+		remote: String,
+		depth: Option<u32>,
+	) -> Self {
+		Self {
+			repo,
+			remote,
+			state: Arc::new(Mutex::new(Some(JobState::Request(
+				depth,
+			)))),
+		}
+	}
+
+	///
+	pub fn result(&self) -> Option<Result<()>> {
+		if let Ok(mut state) = self.state.lock() {
+			if let Some(state) = state.take() {
+				return match state {
+					JobState::Request(_) => None,
+					JobState::Response(result) => Some(result),
+				};
+			}
+		}
+
+		None
+	}
+}
+
+impl AsyncJob for AsyncDeepenJob {
+	type Notification = AsyncGitNotification;
+	type Progress = ();
+
+	fn run(
+		&mut self,
+		_params: RunParams<Self::Notification, Self::Progress>,
+	) -> Result<Self::Notification> {
+		if let Ok(mut state) = self.state.lock() {
+			*state = state.take().map(|state| match state {
+				JobState::Request(depth) => JobState::Response(
+					deepen(&self.repo, &self.remote, depth),
+				),
+				JobState::Response(result) => {
+					JobState::Response(result)
+				}
+			});
+		}
+
+		Ok(AsyncGitNotification::Deepen)
+	}
+}
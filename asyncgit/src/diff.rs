@@ -24,6 +24,18 @@ pub enum DiffType {
 	Stage,
 	/// diff against file in workdir
 	WorkDir,
+	/// diff of `HEAD` against workdir like a stash would capture it
+	Stash {
+		/// include untracked files
+		untracked: bool,
+	},
+	/// diff of workdir (or stage) against file in a given commit
+	Restore {
+		/// commit to restore the file from
+		id: CommitId,
+		/// restore into the stage instead of the workdir
+		stage: bool,
+	},
 }
 
 ///
@@ -171,6 +183,23 @@ impl AsyncDiff {
 				false,
 				Some(params.options),
 			)?,
+			DiffType::Stash { untracked } => {
+				sync::diff::get_diff_head_to_workdir(
+					repo_path,
+					&params.path,
+					untracked,
+					Some(params.options),
+				)?
+			}
+			DiffType::Restore { id, stage } => {
+				sync::diff::get_diff_restore(
+					repo_path,
+					id,
+					&params.path,
+					stage,
+					Some(params.options),
+				)?
+			}
 			DiffType::Commit(id) => sync::diff::get_diff_commit(
 				repo_path,
 				id,
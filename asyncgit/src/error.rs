@@ -18,6 +18,10 @@ pub enum Error {
 	#[error("git: conflict during rebase")]
 	RebaseConflict,
 
+	/// force push with lease found the remote branch moved
+	#[error("git: remote branch '{0}' changed since last fetch")]
+	ForceWithLeaseRejected(String),
+
 	///
 	#[error("git: remote url not found")]
 	UnknownRemote,
@@ -69,6 +73,14 @@ pub enum Error {
 	///
 	#[error("path string error")]
 	PathString,
+
+	///
+	#[error("sign builder error: {0}")]
+	SignBuilder(#[from] crate::sync::sign::SignBuilderError),
+
+	///
+	#[error("sign error: {0}")]
+	Sign(#[from] crate::sync::sign::SignError),
 }
 
 ///
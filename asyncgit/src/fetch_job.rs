@@ -3,12 +3,15 @@
 use crate::{
 	asyncjob::{AsyncJob, RunParams},
 	error::Result,
-	sync::remotes::fetch_all,
+	sync::remotes::{fetch_all, FetchAllProgress},
 	sync::{cred::BasicAuthCredential, RepoPath},
-	AsyncGitNotification, ProgressPercent,
+	AsyncGitNotification,
+};
+use crossbeam_channel::unbounded;
+use std::{
+	sync::{Arc, Mutex},
+	thread,
 };
-
-use std::sync::{Arc, Mutex};
 
 enum JobState {
 	Request(Option<BasicAuthCredential>),
@@ -20,6 +23,8 @@ enum JobState {
 pub struct AsyncFetchJob {
 	state: Arc<Mutex<Option<JobState>>>,
 	repo: RepoPath,
+	prune: bool,
+	retries: usize,
 }
 
 ///
@@ -27,10 +32,14 @@ impl AsyncFetchJob {
 	///
 	pub fn new(
 		repo: RepoPath,
+		prune: bool,
+		retries: usize,
 		basic_credential: Option<BasicAuthCredential>,
 	) -> Self {
 		Self {
 			repo,
+			prune,
+			retries,
 			state: Arc::new(Mutex::new(Some(JobState::Request(
 				basic_credential,
 			)))),
@@ -54,22 +63,39 @@ impl AsyncFetchJob {
 
 impl AsyncJob for AsyncFetchJob {
 	type Notification = AsyncGitNotification;
-	type Progress = ProgressPercent;
+	type Progress = FetchAllProgress;
 
 	fn run(
 		&mut self,
-		_params: RunParams<Self::Notification, Self::Progress>,
+		params: RunParams<Self::Notification, Self::Progress>,
 	) -> Result<Self::Notification> {
 		if let Ok(mut state) = self.state.lock() {
 			*state = state.take().map(|state| match state {
 				JobState::Request(basic_credentials) => {
-					//TODO: support progress
+					let (sender, receiver) = unbounded();
+
+					let handle = thread::spawn(move || {
+						while let Ok(progress) = receiver.recv() {
+							if params.set_progress(progress).is_err()
+								|| params
+									.send(AsyncGitNotification::Fetch)
+									.is_err()
+							{
+								break;
+							}
+						}
+					});
+
 					let result = fetch_all(
 						&self.repo,
+						self.prune,
+						self.retries,
 						&basic_credentials,
-						&None,
+						&Some(sender),
 					);
 
+					handle.join().ok();
+
 					JobState::Response(result)
 				}
 				JobState::Response(result) => {
@@ -0,0 +1,88 @@
+//!
+
+use crate::{
+	asyncjob::{AsyncJob, RunParams},
+	error::Result,
+	sync::{grep, CommitId, GrepMatch, RepoPath},
+	AsyncGitNotification,
+};
+use std::sync::{Arc, Mutex};
+
+enum JobState {
+	Request,
+	Response(Result<Vec<GrepMatch>>),
+}
+
+/// searches the contents of the tracked files
+#[derive(Clone)]
+pub struct AsyncGrepJob {
+	state: Arc<Mutex<Option<JobState>>>,
+	repo: RepoPath,
+	pattern: String,
+	commit: Option<CommitId>,
+	max_matches: usize,
+}
+
+///
+impl AsyncGrepJob {
+	/// searches the work tree if `commit` is `None`
+	pub fn new(
+		repo: RepoPath,
+		pattern: String,
+		commit: Option<CommitId>,
+		max_matches: usize,
+	) -> Self {
+		Self {
+			repo,
+			pattern,
+			commit,
+			max_matches,
+			state: Arc::new(Mutex::new(Some(JobState::Request))),
+		}
+	}
+
+	///
+	pub fn pattern(&self) -> &str {
+		&self.pattern
+	}
+
+	///
+	pub fn result(&self) -> Option<Result<Vec<GrepMatch>>> {
+		if let Ok(mut state) = self.state.lock() {
+			if let Some(state) = state.take() {
+				return match state {
+					JobState::Request => None,
+					JobState::Response(result) => Some(result),
+				};
+			}
+		}
+
+		None
+	}
+}
+
+impl AsyncJob for AsyncGrepJob {
+	type Notification = AsyncGitNotification;
+	type Progress = ();
+
+	fn run(
+		&mut self,
+		_params: RunParams<Self::Notification, Self::Progress>,
+	) -> Result<Self::Notification> {
+		if let Ok(mut state) = self.state.lock() {
+			*state = state.take().map(|state| match state {
+				JobState::Request => JobState::Response(grep(
+					&self.repo,
+					&self.pattern,
+					self.commit,
+					self.max_matches,
+				)),
+				JobState::Response(result) => {
+					JobState::Response(result)
+				}
+			});
+		}
+
+		Ok(AsyncGitNotification::Grep)
+	}
+}
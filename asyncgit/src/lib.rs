@@ -23,12 +23,16 @@
 // #![deny(clippy::expect_used)]
 
 pub mod asyncjob;
+mod auto_fetch;
 mod blame;
+pub mod branches_compare;
 pub mod cached;
 mod commit_files;
+mod deepen_job;
 mod diff;
 mod error;
 mod fetch_job;
+mod grep_job;
 mod progress;
 mod pull;
 mod push;
@@ -41,11 +45,14 @@ pub mod sync;
 mod tags;
 
 pub use crate::{
+	auto_fetch::AsyncAutoFetch,
 	blame::{AsyncBlame, BlameParams},
 	commit_files::{AsyncCommitFiles, CommitFilesParams},
+	deepen_job::AsyncDeepenJob,
 	diff::{AsyncDiff, DiffParams, DiffType},
 	error::{Error, Result},
 	fetch_job::AsyncFetchJob,
+	grep_job::AsyncGrepJob,
 	progress::ProgressPercent,
 	pull::{AsyncPull, FetchRequest},
 	push::{AsyncPush, PushRequest},
@@ -95,6 +102,14 @@ pub enum AsyncGitNotification {
 	RemoteTags,
 	///
 	Fetch,
+	///
+	AutoFetch,
+	///
+	Deepen,
+	///
+	BranchesCompare,
+	///
+	Grep,
 }
 
 /// helper function to calculate the hash of an arbitrary type that implements the `Hash` trait
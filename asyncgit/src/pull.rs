@@ -2,7 +2,9 @@ use crate::{
 	error::{Error, Result},
 	sync::{
 		cred::BasicAuthCredential,
-		remotes::{fetch, push::ProgressNotification},
+		remotes::{
+			fetch, push::ProgressNotification, retry_transient,
+		},
 		RepoPath,
 	},
 	AsyncGitNotification, RemoteProgress,
@@ -20,6 +22,8 @@ pub struct FetchRequest {
 	pub remote: String,
 	///
 	pub branch: String,
+	/// how often to retry transient network errors
+	pub retries: usize,
 	///
 	pub basic_credential: Option<BasicAuthCredential>,
 }
@@ -97,11 +101,17 @@ impl AsyncPull {
 				arc_progress,
 			);
 
-			let res = fetch(
-				&repo,
-				&params.branch,
-				params.basic_credential,
-				Some(progress_sender.clone()),
+			let res = retry_transient(
+				params.retries,
+				Some(&progress_sender),
+				|| {
+					fetch(
+						&repo,
+						&params.branch,
+						params.basic_credential.clone(),
+						Some(progress_sender.clone()),
+					)
+				},
 			);
 
 			progress_sender
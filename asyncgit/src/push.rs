@@ -2,8 +2,9 @@ use crate::{
 	error::{Error, Result},
 	sync::{
 		cred::BasicAuthCredential,
-		remotes::push::push_raw,
+		remotes::push::{check_force_with_lease, push_raw},
 		remotes::push::{ProgressNotification, PushType},
+		remotes::retry_transient,
 		RepoPath,
 	},
 	AsyncGitNotification, RemoteProgress,
@@ -15,22 +16,50 @@ use std::{
 };
 
 ///
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Default, Clone, Debug)]
 pub struct PushRequest {
 	///
 	pub remote: String,
 	///
 	pub branch: String,
+	/// name on the remote if it differs from `branch`
+	pub remote_branch: Option<String>,
 	///
 	pub push_type: PushType,
 	///
 	pub force: bool,
+	/// only force push if the remote branch did not move since
+	/// the last fetch
+	pub force_with_lease: bool,
 	///
 	pub delete: bool,
+	/// set upstream of `branch` if it has none yet
+	pub set_upstream: bool,
+	/// also push annotated tags reachable from `branch`
+	pub follow_tags: bool,
+	/// more local branches to push to the same name on `remote`
+	/// (`push.default=matching`)
+	pub matching: Vec<String>,
+	/// how often to retry transient network errors
+	pub retries: usize,
 	///
 	pub basic_credential: Option<BasicAuthCredential>,
 }
 
+impl PushRequest {
+	/// whether the lease is checked before force pushing a branch.
+	/// such pushes are not retried, an attempt that moved the remote
+	/// branch but was reported as failed would fail the lease against
+	/// our own push
+	const fn uses_lease(&self) -> bool {
+		self.force
+			&& self.force_with_lease
+			&& !self.delete
+			&& matches!(self.push_type, PushType::Branch)
+	}
+}
+
 //TODO: since this is empty we can go with a simple AtomicBool to mark that we are fetching or not
 #[derive(Default, Clone, Debug)]
 struct PushState {}
@@ -39,6 +68,7 @@ struct PushState {}
 pub struct AsyncPush {
 	state: Arc<Mutex<Option<PushState>>>,
 	last_result: Arc<Mutex<Option<String>>>,
+	lease_rejected: Arc<Mutex<bool>>,
 	progress: Arc<Mutex<Option<ProgressNotification>>>,
 	sender: Sender<AsyncGitNotification>,
 	repo: RepoPath,
@@ -54,6 +84,7 @@ impl AsyncPush {
 			repo,
 			state: Arc::new(Mutex::new(None)),
 			last_result: Arc::new(Mutex::new(None)),
+			lease_rejected: Arc::new(Mutex::new(false)),
 			progress: Arc::new(Mutex::new(None)),
 			sender: sender.clone(),
 		}
@@ -71,6 +102,12 @@ impl AsyncPush {
 		Ok(res.clone())
 	}
 
+	/// whether the last push failed because of the lease
+	/// (see `PushRequest::force_with_lease`)
+	pub fn lease_rejected(&self) -> Result<bool> {
+		Ok(*self.lease_rejected.lock()?)
+	}
+
 	///
 	pub fn progress(&self) -> Result<Option<RemoteProgress>> {
 		let res = self.progress.lock()?;
@@ -90,6 +127,7 @@ impl AsyncPush {
 
 		let arc_state = Arc::clone(&self.state);
 		let arc_res = Arc::clone(&self.last_result);
+		let arc_lease = Arc::clone(&self.lease_rejected);
 		let arc_progress = Arc::clone(&self.progress);
 		let sender = self.sender.clone();
 		let repo = self.repo.clone();
@@ -104,15 +142,14 @@ impl AsyncPush {
 				arc_progress,
 			);
 
-			let res = push_raw(
-				&repo,
-				params.remote.as_str(),
-				params.branch.as_str(),
-				params.push_type,
-				params.force,
-				params.delete,
-				params.basic_credential.clone(),
-				Some(progress_sender.clone()),
+			let res = retry_transient(
+				if params.uses_lease() {
+					0
+				} else {
+					params.retries
+				},
+				Some(&progress_sender),
+				|| Self::push(&repo, &params, &progress_sender),
 			);
 
 			progress_sender
@@ -121,7 +158,8 @@ impl AsyncPush {
 
 			handle.join().expect("joining thread failed");
 
-			Self::set_result(&arc_res, res).expect("result error");
+			Self::set_result(&arc_res, &arc_lease, res)
+				.expect("result error");
 
 			Self::clear_request(&arc_state).expect("clear error");
 
@@ -133,6 +171,68 @@ impl AsyncPush {
 		Ok(())
 	}
 
+	/// checks the lease (if any) and pushes
+	fn push(
+		repo: &RepoPath,
+		params: &PushRequest,
+		progress_sender: &Sender<ProgressNotification>,
+	) -> Result<()> {
+		Self::push_branch(
+			repo,
+			params,
+			&params.branch,
+			params.remote_branch.as_deref(),
+			progress_sender,
+		)?;
+
+		for branch in &params.matching {
+			Self::push_branch(
+				repo,
+				&PushRequest {
+					set_upstream: false,
+					follow_tags: false,
+					..params.clone()
+				},
+				branch,
+				None,
+				progress_sender,
+			)?;
+		}
+
+		Ok(())
+	}
+
+	fn push_branch(
+		repo: &RepoPath,
+		params: &PushRequest,
+		branch: &str,
+		remote_branch: Option<&str>,
+		progress_sender: &Sender<ProgressNotification>,
+	) -> Result<()> {
+		if params.uses_lease() {
+			check_force_with_lease(
+				repo,
+				params.remote.as_str(),
+				remote_branch.unwrap_or(branch),
+				params.basic_credential.clone(),
+			)?;
+		}
+
+		push_raw(
+			repo,
+			params.remote.as_str(),
+			branch,
+			remote_branch,
+			params.push_type,
+			params.force,
+			params.delete,
+			params.set_upstream,
+			params.follow_tags,
+			params.basic_credential.clone(),
+			Some(progress_sender.clone()),
+		)
+	}
+
 	fn set_request(&self, _params: &PushRequest) -> Result<()> {
 		let mut state = self.state.lock()?;
 
@@ -157,10 +257,14 @@ impl AsyncPush {
 
 	fn set_result(
 		arc_result: &Arc<Mutex<Option<String>>>,
+		arc_lease: &Arc<Mutex<bool>>,
 		res: Result<()>,
 	) -> Result<()> {
 		let mut last_res = arc_result.lock()?;
 
+		*arc_lease.lock()? =
+			matches!(res, Err(Error::ForceWithLeaseRejected(_)));
+
 		*last_res = match res {
 			Ok(_) => None,
 			Err(e) => {
@@ -3,7 +3,10 @@
 use crate::{
 	error::Result,
 	progress::ProgressPercent,
-	sync::remotes::push::{AsyncProgress, ProgressNotification},
+	sync::remotes::{
+		push::{AsyncProgress, ProgressNotification},
+		Retry,
+	},
 	AsyncGitNotification,
 };
 use crossbeam_channel::{Receiver, Sender};
@@ -24,6 +27,8 @@ pub enum RemoteProgressState {
 	Pushing,
 	/// fetch progress
 	Transfer,
+	/// waiting to retry after a network error
+	Retrying(Retry),
 	/// remote progress done
 	Done,
 }
@@ -143,6 +148,9 @@ impl From<ProgressNotification> for RemoteProgress {
 				objects,
 				total_objects,
 			),
+			ProgressNotification::Retry(retry) => {
+				Self::new(RemoteProgressState::Retrying(retry), 0, 1)
+			}
 			_ => Self::new(RemoteProgressState::Done, 1, 1),
 		}
 	}
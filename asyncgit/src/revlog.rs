@@ -101,6 +101,13 @@ impl AsyncLog {
 	}
 
 	///
+	/// makes the next `fetch` reload the log even if `HEAD` is
+	/// unchanged, e.g. after older history was fetched
+	pub fn invalidate(&mut self) -> Result<()> {
+		*self.current_head.lock()? = None;
+		Ok(())
+	}
+
 	fn head_changed(&self) -> Result<bool> {
 		if let Ok(head) = repo(&self.repo)?.head() {
 			return Ok(
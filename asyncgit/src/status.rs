@@ -2,12 +2,15 @@ use crate::{
 	error::Result,
 	hash,
 	sync::{
-		self, status::StatusType, RepoPath, ShowUntrackedFilesConfig,
+		self,
+		status::{StatusSortData, StatusType},
+		RepoPath, ShowUntrackedFilesConfig,
 	},
 	AsyncGitNotification, StatusItem,
 };
 use crossbeam_channel::Sender;
 use std::{
+	collections::BTreeMap,
 	hash::Hash,
 	sync::{
 		atomic::{AtomicUsize, Ordering},
@@ -26,6 +29,9 @@ fn current_tick() -> u128 {
 #[derive(Default, Hash, Clone)]
 pub struct Status {
 	pub items: Vec<StatusItem>,
+	/// per path, larger ones sort first, filled for
+	/// [`StatusParams::with_sort_data`]
+	pub sort_keys: BTreeMap<String, u64>,
 }
 
 ///
@@ -34,6 +40,7 @@ pub struct StatusParams {
 	tick: u128,
 	status_type: StatusType,
 	config: Option<ShowUntrackedFilesConfig>,
+	sort_data: StatusSortData,
 }
 
 impl StatusParams {
@@ -46,8 +53,18 @@ impl StatusParams {
 			tick: current_tick(),
 			status_type,
 			config,
+			sort_data: StatusSortData::None,
 		}
 	}
+
+	/// also gathers what to sort the items by, off the ui thread
+	#[must_use]
+	pub const fn with_sort_data(
+		self,
+		sort_data: StatusSortData,
+	) -> Self {
+		Self { sort_data, ..self }
+	}
 }
 
 struct Request<R, A>(R, Option<A>);
@@ -122,6 +139,7 @@ impl AsyncStatus {
 		let arc_pending = Arc::clone(&self.pending);
 		let status_type = params.status_type;
 		let config = params.config;
+		let sort_data = params.sort_data;
 		let repo = self.repo.clone();
 
 		self.pending.fetch_add(1, Ordering::Relaxed);
@@ -131,6 +149,7 @@ impl AsyncStatus {
 				&repo,
 				status_type,
 				config,
+				sort_data,
 				hash_request,
 				&arc_current,
 				&arc_last,
@@ -152,11 +171,13 @@ impl AsyncStatus {
 		repo: &RepoPath,
 		status_type: StatusType,
 		config: Option<ShowUntrackedFilesConfig>,
+		sort_data: StatusSortData,
 		hash_request: u64,
 		arc_current: &Arc<Mutex<Request<u64, Status>>>,
 		arc_last: &Arc<Mutex<Status>>,
 	) -> Result<()> {
-		let res = Self::get_status(repo, status_type, config)?;
+		let res =
+			Self::get_status(repo, status_type, config, sort_data)?;
 		log::trace!(
 			"status fetched: {} (type: {:?})",
 			hash_request,
@@ -182,13 +203,24 @@ impl AsyncStatus {
 		repo: &RepoPath,
 		status_type: StatusType,
 		config: Option<ShowUntrackedFilesConfig>,
+		sort_data: StatusSortData,
 	) -> Result<Status> {
-		Ok(Status {
-			items: sync::status::get_status(
-				repo,
-				status_type,
-				config,
-			)?,
-		})
+		let items =
+			sync::status::get_status(repo, status_type, config)?;
+
+		let sort_keys = match sort_data {
+			StatusSortData::None => BTreeMap::new(),
+			StatusSortData::ModTime => {
+				sync::status::get_status_mod_times(repo, &items)?
+			}
+			StatusSortData::DiffSize => {
+				sync::status::get_status_diff_sizes(
+					repo,
+					status_type,
+				)?
+			}
+		};
+
+		Ok(Status { items, sort_keys })
 	}
 }
@@ -0,0 +1,325 @@
+//! stash local changes around operations that need a clean working tree
+
+use super::{
+	stash_apply, stash_drop, stash_save, CommitId, RepoPath,
+};
+use crate::{
+	error::{Error, Result},
+	sync::repository::repo,
+};
+use scopetime::scope_time;
+
+const AUTOSTASH_MSG: &str = "autostash";
+
+/// what happened to the local changes stashed by [`autostash`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AutostashResult {
+	/// working tree was clean, nothing got stashed
+	Clean,
+	/// changes were stashed and re-applied cleanly
+	Reapplied,
+	/// re-applying failed, changes are kept in `stash`
+	Kept {
+		///
+		stash: CommitId,
+		/// paths the stash conflicts with the new `HEAD` on
+		conflicts: Vec<String>,
+	},
+	/// `op` left a merge or rebase in progress, changes are kept in
+	/// `stash` to be popped once it is resolved
+	Pending {
+		///
+		stash: CommitId,
+	},
+}
+
+/// `true` if there are staged, unstaged or untracked changes
+pub fn has_local_changes(repo_path: &RepoPath) -> Result<bool> {
+	scope_time!("has_local_changes");
+
+	let repo = repo(repo_path)?;
+	let statuses = repo.statuses(Some(
+		git2::StatusOptions::new()
+			.include_ignored(false)
+			.include_untracked(true)
+			.recurse_untracked_dirs(true),
+	))?;
+
+	Ok(!statuses.is_empty())
+}
+
+/// `true` if `e` is an operation refusing to overwrite local
+/// changes, which stashing them first would get past
+pub fn blocked_by_local_changes(e: &Error) -> bool {
+	match e {
+		Error::UncommittedChanges => true,
+		Error::Git(e) => matches!(
+			e.code(),
+			git2::ErrorCode::Conflict | git2::ErrorCode::Uncommitted
+		),
+		_ => false,
+	}
+}
+
+/// stashes local changes (including untracked files), runs `op`
+/// and re-applies the stash afterwards.
+///
+/// the stash is re-applied even if `op` fails, but not while `op`
+/// left a merge or rebase to be resolved, like `git` keeps it then.
+pub fn autostash<T, F>(
+	repo_path: &RepoPath,
+	op: F,
+) -> Result<(T, AutostashResult)>
+where
+	F: FnOnce() -> Result<T>,
+{
+	scope_time!("autostash");
+
+	if !has_local_changes(repo_path)? {
+		return Ok((op()?, AutostashResult::Clean));
+	}
+
+	let stash =
+		stash_save(repo_path, Some(AUTOSTASH_MSG), true, false)?;
+
+	let res = op();
+
+	if in_progress(repo_path)? {
+		return match res {
+			Ok(value) => {
+				Ok((value, AutostashResult::Pending { stash }))
+			}
+			Err(e) => Err(kept_error(&e, stash)),
+		};
+	}
+
+	let reapplied = reapply(repo_path, stash);
+	if let Err(e) = &reapplied {
+		log::error!("autostash pop failed: {}", e);
+	}
+
+	let conflicts = match reapplied {
+		Ok(conflicts) if conflicts.is_empty() => {
+			return res
+				.map(|value| (value, AutostashResult::Reapplied));
+		}
+		Ok(conflicts) => conflicts,
+		Err(_) => Vec::new(),
+	};
+
+	match res {
+		Ok(value) => {
+			Ok((value, AutostashResult::Kept { stash, conflicts }))
+		}
+		Err(e) => Err(kept_error(&e, stash)),
+	}
+}
+
+fn kept_error(e: &Error, stash: CommitId) -> Error {
+	Error::Generic(format!(
+		"{} (local changes kept in stash {})",
+		e,
+		stash.get_short_string()
+	))
+}
+
+/// `true` while a merge or rebase waits for its conflicts to be
+/// resolved
+fn in_progress(repo_path: &RepoPath) -> Result<bool> {
+	let repo = repo(repo_path)?;
+
+	Ok(repo.state() != git2::RepositoryState::Clean
+		|| repo.index()?.has_conflicts())
+}
+
+/// applies and drops `stash` unless it conflicts with `HEAD`,
+/// returns the conflicting paths otherwise
+fn reapply(
+	repo_path: &RepoPath,
+	stash: CommitId,
+) -> Result<Vec<String>> {
+	let conflicts = stash_conflicts(repo_path, stash)?;
+	if !conflicts.is_empty() {
+		return Ok(conflicts);
+	}
+
+	stash_apply(repo_path, stash, false, false)?;
+	stash_drop(repo_path, stash)?;
+
+	Ok(conflicts)
+}
+
+/// libgit2 happily writes conflict markers when applying a stash
+/// so we merge the stashed worktree into `HEAD` in memory first
+fn stash_conflicts(
+	repo_path: &RepoPath,
+	stash: CommitId,
+) -> Result<Vec<String>> {
+	let repo = repo(repo_path)?;
+
+	let stash = repo.find_commit(stash.into())?;
+	let base = stash.parent(0)?;
+	let head = repo.head()?.peel_to_commit()?;
+
+	let index = repo.merge_trees(
+		&base.tree()?,
+		&head.tree()?,
+		&stash.tree()?,
+		None,
+	)?;
+
+	let mut paths = Vec::new();
+	for conflict in index.conflicts()? {
+		let conflict = conflict?;
+		if let Some(entry) =
+			conflict.our.or(conflict.their).or(conflict.ancestor)
+		{
+			paths.push(
+				String::from_utf8_lossy(&entry.path).to_string(),
+			);
+		}
+	}
+
+	Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::{
+		checkout_branch, create_branch, get_stashes,
+		merge_upstream_commit,
+		remotes::{fetch, push::push_branch},
+		repo_state,
+		tests::{
+			repo_clone, repo_init, repo_init_bare, write_commit_file,
+		},
+		utils::{repo_read_file, repo_write_file},
+		RepoState,
+	};
+
+	#[test]
+	fn test_autostash_reapplied() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		write_commit_file(&repo, "foo", "a", "c1");
+		let master = String::from("refs/heads/master");
+		create_branch(repo_path, "other").unwrap();
+
+		repo_write_file(&repo, "foo", "local").unwrap();
+		assert!(has_local_changes(repo_path).unwrap());
+		assert!(blocked_by_local_changes(
+			&checkout_branch(repo_path, &master).unwrap_err()
+		));
+
+		let ((), res) = autostash(repo_path, || {
+			checkout_branch(repo_path, &master)
+		})
+		.unwrap();
+
+		assert_eq!(res, AutostashResult::Reapplied);
+		assert_eq!(repo_read_file(&repo, "foo").unwrap(), "local");
+		assert_eq!(repo.head().unwrap().name().unwrap(), master);
+		assert!(get_stashes(repo_path).unwrap().is_empty());
+	}
+
+	#[test]
+	fn test_autostash_conflict() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		write_commit_file(&repo, "foo", "a", "c1");
+		repo_write_file(&repo, "foo", "local").unwrap();
+
+		let (_, res) = autostash(repo_path, || {
+			Ok(write_commit_file(&repo, "foo", "upstream", "c2"))
+		})
+		.unwrap();
+
+		assert!(matches!(
+			res,
+			AutostashResult::Kept { conflicts, .. }
+				if conflicts == vec![String::from("foo")]
+		));
+		assert_eq!(get_stashes(repo_path).unwrap().len(), 1);
+		assert_eq!(repo_read_file(&repo, "foo").unwrap(), "upstream");
+	}
+
+	#[test]
+	fn test_autostash_untracked() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		repo_write_file(&repo, "untracked", "local").unwrap();
+
+		assert!(has_local_changes(repo_path).unwrap());
+	}
+
+	#[test]
+	fn test_autostash_pull_conflict() {
+		let (r1_dir, _repo) = repo_init_bare().unwrap();
+		let (clone1_dir, clone1) =
+			repo_clone(r1_dir.path().to_str().unwrap()).unwrap();
+		let (clone2_dir, clone2) =
+			repo_clone(r1_dir.path().to_str().unwrap()).unwrap();
+		let clone1_path: &RepoPath =
+			&clone1_dir.path().to_str().unwrap().into();
+		let clone2_path: &RepoPath =
+			&clone2_dir.path().to_str().unwrap().into();
+
+		write_commit_file(&clone1, "test.txt", "upstream", "c1");
+		push_branch(
+			clone1_path,
+			"origin",
+			"master",
+			false,
+			false,
+			None,
+			None,
+		)
+		.unwrap();
+
+		write_commit_file(&clone2, "other.txt", "a", "c2");
+		write_commit_file(&clone2, "test.txt", "local", "c3");
+		fetch(clone2_path, "master", None, None).unwrap();
+
+		repo_write_file(&clone2, "other.txt", "changed").unwrap();
+
+		let (merged, res) = autostash(clone2_path, || {
+			merge_upstream_commit(clone2_path, "master")
+		})
+		.unwrap();
+
+		assert_eq!(merged, None);
+		assert!(matches!(res, AutostashResult::Pending { .. }));
+		assert_eq!(
+			repo_state(clone2_path).unwrap(),
+			RepoState::Merge
+		);
+		assert_eq!(get_stashes(clone2_path).unwrap().len(), 1);
+		assert_eq!(
+			repo_read_file(&clone2, "other.txt").unwrap(),
+			"a"
+		);
+	}
+
+	#[test]
+	fn test_autostash_clean() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let (value, res) = autostash(repo_path, || Ok(42)).unwrap();
+
+		assert_eq!(value, 42);
+		assert_eq!(res, AutostashResult::Clean);
+	}
+}
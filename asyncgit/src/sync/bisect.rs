@@ -0,0 +1,168 @@
+use super::{CommitId, RepoPath};
+use crate::{
+	error::Result,
+	sync::{repository::repo, utils::read_file},
+};
+use git2::{build::CheckoutBuilder, Repository};
+use scopetime::scope_time;
+
+const GIT_BISECT_START_FILE: &str = "BISECT_START";
+const BISECT_REFS: &str = "refs/bisect/";
+
+/// state files `git bisect` leaves in the git dir next to `BISECT_LOG`
+const BISECT_FILES: &[&str] = &[
+	"BISECT_START",
+	"BISECT_LOG",
+	"BISECT_TERMS",
+	"BISECT_NAMES",
+	"BISECT_EXPECTED_REV",
+	"BISECT_ANCESTORS_OK",
+	"BISECT_HEAD",
+	"BISECT_FIRST_PARENT",
+	"BISECT_RUN",
+];
+
+///
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct BisectState {
+	/// branch or commit the bisect session was started from
+	pub start: String,
+	/// first known bad commit
+	pub bad: Option<CommitId>,
+	/// known good commits
+	pub good: Vec<CommitId>,
+}
+
+/// progress of a pending bisect session
+pub fn bisect_state(repo_path: &RepoPath) -> Result<BisectState> {
+	scope_time!("bisect_state");
+
+	let repo = repo(repo_path)?;
+
+	let start = read_file(&repo.path().join(GIT_BISECT_START_FILE))
+		.map(|start| start.trim().to_string())
+		.unwrap_or_default();
+
+	let mut state = BisectState {
+		start,
+		..BisectState::default()
+	};
+
+	for reference in repo.references_glob("refs/bisect/*")? {
+		let reference = reference?;
+
+		if let (Some(name), Some(id)) =
+			(reference.name(), reference.target())
+		{
+			match name.trim_start_matches(BISECT_REFS) {
+				"bad" => state.bad = Some(id.into()),
+				term if term.starts_with("good") => {
+					state.good.push(id.into());
+				}
+				_ => (),
+			}
+		}
+	}
+
+	Ok(state)
+}
+
+/// ends a pending bisect session (like `git bisect reset`),
+/// checking out what it was started from
+pub fn abort_bisect(repo_path: &RepoPath) -> Result<()> {
+	scope_time!("abort_bisect");
+
+	let repo = repo(repo_path)?;
+
+	let state = bisect_state(repo_path)?;
+
+	if !state.start.is_empty() {
+		checkout_start(&repo, &state.start)?;
+	}
+
+	for reference in repo.references_glob("refs/bisect/*")? {
+		reference?.delete()?;
+	}
+
+	for file in BISECT_FILES {
+		let path = repo.path().join(file);
+		if path.exists() {
+			std::fs::remove_file(path)?;
+		}
+	}
+
+	Ok(())
+}
+
+fn checkout_start(repo: &Repository, start: &str) -> Result<()> {
+	let branch_ref = format!("refs/heads/{}", start);
+
+	if repo.find_reference(&branch_ref).is_ok() {
+		repo.set_head(&branch_ref)?;
+	} else {
+		repo.set_head_detached(git2::Oid::from_str(start)?)?;
+	}
+
+	repo.checkout_head(Some(CheckoutBuilder::new().safe()))?;
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{abort_bisect, bisect_state};
+	use crate::sync::{
+		repo_state,
+		tests::{repo_init, write_commit_file},
+		RepoPath, RepoState,
+	};
+	use std::fs;
+
+	#[test]
+	fn test_bisect_abort() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let good =
+			write_commit_file(&repo, "test.txt", "test1", "commit1");
+		let bad =
+			write_commit_file(&repo, "test.txt", "test2", "commit2");
+
+		// what `git bisect start <bad> <good>` leaves behind
+		fs::write(repo.path().join("BISECT_START"), "master\n")
+			.unwrap();
+		fs::write(repo.path().join("BISECT_LOG"), "").unwrap();
+		repo.reference("refs/bisect/bad", bad.into(), false, "")
+			.unwrap();
+		repo.reference(
+			&format!("refs/bisect/good-{}", good.to_string()),
+			good.into(),
+			false,
+			"",
+		)
+		.unwrap();
+		repo.set_head_detached(good.into()).unwrap();
+
+		assert_eq!(repo_state(repo_path).unwrap(), RepoState::Bisect);
+
+		let state = bisect_state(repo_path).unwrap();
+		assert_eq!(state.start, "master");
+		assert_eq!(state.bad, Some(bad));
+		assert_eq!(state.good, vec![good]);
+
+		abort_bisect(repo_path).unwrap();
+
+		assert_eq!(repo_state(repo_path).unwrap(), RepoState::Clean);
+		assert_eq!(
+			repo.head().unwrap().name(),
+			Some("refs/heads/master")
+		);
+		assert!(repo
+			.references_glob("refs/bisect/*")
+			.unwrap()
+			.next()
+			.is_none());
+	}
+}
@@ -3,13 +3,16 @@
 use super::{utils, CommitId, RepoPath};
 use crate::{
 	error::{Error, Result},
-	sync::{get_commits_info, repository::repo},
+	sync::{
+		config::get_config_strings_repo, get_commits_info,
+		repository::repo,
+	},
 };
-use git2::BlameOptions;
+use git2::{Blame, BlameOptions, Repository};
 use scopetime::scope_time;
 use std::collections::{HashMap, HashSet};
 use std::io::{BufRead, BufReader};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// A `BlameHunk` contains all the information that will be shown to the user.
 #[derive(Clone, Hash, Debug, PartialEq, Eq)]
@@ -26,6 +29,10 @@ pub struct BlameHunk {
 	pub start_line: usize,
 	///
 	pub end_line: usize,
+	/// path of the file in `commit_id`
+	pub orig_path: String,
+	/// 0-based index of the first line in `commit_id`
+	pub orig_start_line: usize,
 }
 
 /// A `BlameFile` represents a collection of lines. This is targeted at how the
@@ -90,9 +97,38 @@ pub fn blame_file(
 
 	let reader = BufReader::new(blob.content());
 
-	let unique_commit_ids: HashSet<_> = blame
+	let ignored = ignored_revs(&repo)?;
+	let mut parent_blames = HashMap::new();
+
+	let lines: Vec<(Option<LineOrigin>, String)> = reader
+		.lines()
+		.enumerate()
+		.map(|(i, line)| {
+			// Line indices in a `FileBlame` are 1-based.
+			let origin = blame
+				.get_line(i + 1)
+				.map(|hunk| LineOrigin::new(&hunk, file_path))
+				.map(|origin| {
+					if ignored.contains(&origin.commit_id) {
+						follow_ignored(
+							&repo,
+							&ignored,
+							&mut parent_blames,
+							origin.single_line(i),
+						)
+					} else {
+						origin
+					}
+				});
+
+			(origin, line.unwrap_or_else(|_| "".into()))
+		})
+		.collect();
+
+	let unique_commit_ids: HashSet<_> = lines
 		.iter()
-		.map(|hunk| CommitId::new(hunk.final_commit_id()))
+		.filter_map(|(origin, _)| origin.as_ref())
+		.map(|origin| origin.commit_id)
 		.collect();
 	let mut commit_ids = Vec::with_capacity(unique_commit_ids.len());
 	commit_ids.extend(unique_commit_ids);
@@ -103,40 +139,24 @@ pub fn blame_file(
 		.map(|commit_info| (commit_info.id, commit_info))
 		.collect();
 
-	let lines: Vec<(Option<BlameHunk>, String)> = reader
-		.lines()
-		.enumerate()
-		.map(|(i, line)| {
-			// Line indices in a `FileBlame` are 1-based.
-			let corresponding_hunk = blame.get_line(i + 1);
-
-			if let Some(hunk) = corresponding_hunk {
-				let commit_id = CommitId::new(hunk.final_commit_id());
-				// Line indices in a `BlameHunk` are 1-based.
-				let start_line =
-					hunk.final_start_line().saturating_sub(1);
-				let end_line =
-					start_line.saturating_add(hunk.lines_in_hunk());
-
-				if let Some(commit_info) =
-					unique_commit_infos.get(&commit_id)
-				{
-					let hunk = BlameHunk {
-						commit_id,
+	let lines: Vec<(Option<BlameHunk>, String)> = lines
+		.into_iter()
+		.map(|(origin, line)| {
+			let hunk = origin.and_then(|origin| {
+				unique_commit_infos.get(&origin.commit_id).map(
+					|commit_info| BlameHunk {
+						commit_id: origin.commit_id,
 						author: commit_info.author.clone(),
 						time: commit_info.time,
-						start_line,
-						end_line,
-					};
-
-					return (
-						Some(hunk),
-						line.unwrap_or_else(|_| "".into()),
-					);
-				}
-			}
+						start_line: origin.start_line,
+						end_line: origin.end_line,
+						orig_path: origin.orig_path,
+						orig_start_line: origin.orig_start_line,
+					},
+				)
+			});
 
-			(None, line.unwrap_or_else(|_| "".into()))
+			(hunk, line)
 		})
 		.collect();
 
@@ -149,12 +169,193 @@ pub fn blame_file(
 	Ok(file_blame)
 }
 
+/// first parent of `commit_id` if `file_path` exists in it, to
+/// continue blaming past the commit that last touched a line
+pub fn blame_parent(
+	repo_path: &RepoPath,
+	commit_id: CommitId,
+	file_path: &str,
+) -> Result<Option<CommitId>> {
+	scope_time!("blame_parent");
+
+	let repo = repo(repo_path)?;
+	parent_with_file(&repo, commit_id, file_path)
+}
+
+fn parent_with_file(
+	repo: &Repository,
+	commit_id: CommitId,
+	file_path: &str,
+) -> Result<Option<CommitId>> {
+	let commit = repo.find_commit(commit_id.into())?;
+
+	let parent = match commit.parents().next() {
+		Some(parent) => parent,
+		None => return Ok(None),
+	};
+
+	let exists = parent
+		.tree()?
+		.get_path(Path::new(&fixup_windows_path(file_path)))
+		.is_ok();
+
+	Ok(exists.then(|| parent.id().into()))
+}
+
+/// commit and position of a hunk before its commit infos are known
+struct LineOrigin {
+	commit_id: CommitId,
+	start_line: usize,
+	end_line: usize,
+	orig_path: String,
+	orig_start_line: usize,
+}
+
+impl LineOrigin {
+	fn new(hunk: &git2::BlameHunk, file_path: &str) -> Self {
+		// Line indices in a `BlameHunk` are 1-based.
+		let start_line = hunk.final_start_line().saturating_sub(1);
+
+		Self {
+			commit_id: CommitId::new(hunk.final_commit_id()),
+			start_line,
+			end_line: start_line.saturating_add(hunk.lines_in_hunk()),
+			orig_path: hunk.path().map_or_else(
+				|| file_path.to_string(),
+				|path| fixup_windows_path(&path.to_string_lossy()),
+			),
+			orig_start_line: hunk.orig_start_line().saturating_sub(1),
+		}
+	}
+
+	/// narrows the hunk down to line `line`
+	fn single_line(self, line: usize) -> Self {
+		Self {
+			orig_start_line: self.orig_start_line
+				+ line.saturating_sub(self.start_line),
+			start_line: line,
+			end_line: line + 1,
+			..self
+		}
+	}
+}
+
+/// commits listed in the `blame.ignoreRevsFile` files or as
+/// `gitui.blameIgnoreRev`, like `git blame` lines get blamed on
+/// whatever came before them
+fn ignored_revs(repo: &Repository) -> Result<HashSet<CommitId>> {
+	let mut revs = Vec::new();
+
+	for file in get_config_strings_repo(repo, "blame.ignoreRevsFile")?
+	{
+		// an empty value resets the list like in git
+		if file.is_empty() {
+			revs.clear();
+			continue;
+		}
+
+		let path = repo.workdir().map_or_else(
+			|| PathBuf::from(&file),
+			|dir| dir.join(&file),
+		);
+
+		match std::fs::read_to_string(&path) {
+			Ok(content) => revs.extend(
+				content
+					.lines()
+					.filter_map(|line| line.split('#').next())
+					.map(str::trim)
+					.filter(|line| !line.is_empty())
+					.map(String::from),
+			),
+			Err(e) => {
+				log::warn!("blame ignore file {:?}: {}", path, e);
+			}
+		}
+	}
+
+	revs.extend(get_config_strings_repo(
+		repo,
+		"gitui.blameIgnoreRev",
+	)?);
+
+	Ok(revs
+		.iter()
+		.filter_map(|rev| {
+			repo.revparse_single(rev)
+				.and_then(|object| object.peel_to_commit())
+				.ok()
+		})
+		.map(|commit| commit.id().into())
+		.collect())
+}
+
+/// blames a single line blamed on an ignored commit on the parent of
+/// that commit instead, until it ends up at a commit that is not
+/// ignored or that introduced the line
+fn follow_ignored<'a>(
+	repo: &'a Repository,
+	ignored: &HashSet<CommitId>,
+	parent_blames: &mut HashMap<
+		(CommitId, String),
+		Option<Blame<'a>>,
+	>,
+	mut origin: LineOrigin,
+) -> LineOrigin {
+	while ignored.contains(&origin.commit_id) {
+		let parent = match parent_with_file(
+			repo,
+			origin.commit_id,
+			&origin.orig_path,
+		) {
+			Ok(Some(parent)) => parent,
+			_ => break,
+		};
+
+		let blame = parent_blames
+			.entry((parent, origin.orig_path.clone()))
+			.or_insert_with(|| {
+				let mut opts = BlameOptions::new();
+				opts.newest_commit(parent.into());
+
+				repo.blame_file(
+					Path::new(&origin.orig_path),
+					Some(&mut opts),
+				)
+				.ok()
+			});
+
+		// the line most likely sits where it was before the commit
+		let line = origin.orig_start_line + 1;
+		let hunk = match blame.as_ref().and_then(|b| b.get_line(line))
+		{
+			Some(hunk) => hunk,
+			None => break,
+		};
+
+		let offset = line.saturating_sub(hunk.final_start_line());
+		let next = LineOrigin::new(&hunk, &origin.orig_path);
+
+		origin = LineOrigin {
+			commit_id: next.commit_id,
+			orig_path: next.orig_path,
+			orig_start_line: next.orig_start_line + offset,
+			..origin
+		};
+	}
+
+	origin
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
 	use crate::{
 		error::Result,
-		sync::{commit, stage_add_file, tests::repo_init_empty},
+		sync::{
+			commit, stage_add_file,
+			tests::{repo_init_empty, write_commit_file},
+		},
 	};
 	use std::{
 		fs::{File, OpenOptions},
@@ -266,4 +467,84 @@ mod tests {
 
 		assert!(blame_file(&repo_path, "bar\\foo", None).is_ok());
 	}
+
+	#[test]
+	fn test_blame_parent() -> Result<()> {
+		let (_td, repo) = repo_init_empty()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let first =
+			write_commit_file(&repo, "foo", "a\nb\n", "first");
+		let second = write_commit_file(
+			&repo,
+			"foo",
+			"a\nb changed\n",
+			"second",
+		);
+		let third = write_commit_file(&repo, "bar", "new\n", "third");
+
+		let blame = blame_file(repo_path, "foo", None)?;
+		let hunk = blame.lines[1].0.clone().unwrap();
+
+		assert_eq!(hunk.commit_id, second);
+		assert_eq!(hunk.orig_path, "foo");
+		assert_eq!(hunk.orig_start_line, 1);
+
+		assert_eq!(
+			blame_parent(repo_path, second, "foo")?,
+			Some(first)
+		);
+		assert_eq!(blame_parent(repo_path, first, "foo")?, None);
+		assert_eq!(blame_parent(repo_path, third, "bar")?, None);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_blame_ignore_revs() -> Result<()> {
+		let (_td, repo) = repo_init_empty()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let first =
+			write_commit_file(&repo, "foo", "a\nb\n", "first");
+		let reformat =
+			write_commit_file(&repo, "foo", "a\nB\n", "reformat");
+		let second =
+			write_commit_file(&repo, "foo", "A\nB\n", "second");
+
+		let blamed_on = |repo_path: &RepoPath| -> Vec<CommitId> {
+			blame_file(repo_path, "foo", None)
+				.unwrap()
+				.lines
+				.into_iter()
+				.map(|(hunk, _)| hunk.unwrap().commit_id)
+				.collect()
+		};
+
+		assert_eq!(blamed_on(repo_path), vec![second, reformat]);
+
+		File::create(&root.join(".git-blame-ignore-revs"))?
+			.write_all(
+				format!("# reformat\n{}\n", reformat.to_string())
+					.as_bytes(),
+			)?;
+		repo.config()?.set_str(
+			"blame.ignoreRevsFile",
+			".git-blame-ignore-revs",
+		)?;
+
+		assert_eq!(blamed_on(repo_path), vec![second, first]);
+
+		repo.config()?.set_str("blame.ignoreRevsFile", "")?;
+		repo.config()?
+			.set_str("gitui.blameIgnoreRev", &second.to_string())?;
+
+		assert_eq!(blamed_on(repo_path), vec![first, reformat]);
+
+		Ok(())
+	}
 }
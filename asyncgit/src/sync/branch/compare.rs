@@ -0,0 +1,110 @@
+//! comparing two branches like `git log A...B`
+
+use crate::{
+	error::Result,
+	sync::{repository::repo, CommitId, RepoPath},
+};
+use git2::{Oid, Repository, Sort};
+use scopetime::scope_time;
+
+/// commits unique to either side of two branches
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchesDiff {
+	/// tip of the left branch
+	pub left: CommitId,
+	/// tip of the right branch
+	pub right: CommitId,
+	/// `None` if the branches have no history in common
+	pub merge_base: Option<CommitId>,
+	/// commits reachable from `left` but not `right`, newest first
+	pub only_left: Vec<CommitId>,
+	/// commits reachable from `right` but not `left`, newest first
+	pub only_right: Vec<CommitId>,
+}
+
+/// symmetric difference of the branches (or any revisions) `left`
+/// and `right`, each side holding at most `limit` commits
+pub fn compare_branches(
+	repo_path: &RepoPath,
+	left: &str,
+	right: &str,
+	limit: usize,
+) -> Result<BranchesDiff> {
+	scope_time!("compare_branches");
+
+	let repo = repo(repo_path)?;
+
+	let left = repo.revparse_single(left)?.peel_to_commit()?.id();
+	let right = repo.revparse_single(right)?.peel_to_commit()?.id();
+
+	let merge_base = repo.merge_base(left, right).ok();
+
+	Ok(BranchesDiff {
+		left: left.into(),
+		right: right.into(),
+		merge_base: merge_base.map(Into::into),
+		only_left: unique_commits(&repo, left, right, limit)?,
+		only_right: unique_commits(&repo, right, left, limit)?,
+	})
+}
+
+fn unique_commits(
+	repo: &Repository,
+	from: Oid,
+	hide: Oid,
+	limit: usize,
+) -> Result<Vec<CommitId>> {
+	let mut walk = repo.revwalk()?;
+	walk.set_sorting(Sort::TOPOLOGICAL | Sort::TIME)?;
+	walk.push(from)?;
+	walk.hide(hide)?;
+
+	let mut res = Vec::new();
+	for id in walk.take(limit) {
+		res.push(id?.into());
+	}
+
+	Ok(res)
+}
+
+#[cfg(test)]
+mod test {
+	use super::compare_branches;
+	use crate::sync::{
+		checkout_branch, create_branch,
+		tests::{repo_init, write_commit_file},
+		RepoPath,
+	};
+
+	#[test]
+	fn test_compare_branches() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let base = write_commit_file(&repo, "b.txt", "b", "base");
+
+		create_branch(repo_path, "feature").unwrap();
+		let f1 = write_commit_file(&repo, "f.txt", "1", "f1");
+		let f2 = write_commit_file(&repo, "f.txt", "2", "f2");
+
+		checkout_branch(repo_path, "refs/heads/master").unwrap();
+		let m1 = write_commit_file(&repo, "m.txt", "1", "m1");
+
+		let res =
+			compare_branches(repo_path, "master", "feature", 10)
+				.unwrap();
+
+		assert_eq!(res.left, m1);
+		assert_eq!(res.right, f2);
+		assert_eq!(res.merge_base, Some(base));
+		assert_eq!(res.only_left, vec![m1]);
+		assert_eq!(res.only_right, vec![f2, f1]);
+
+		let res = compare_branches(repo_path, "master", "feature", 1)
+			.unwrap();
+
+		assert_eq!(res.only_right, vec![f2]);
+	}
+}
@@ -78,15 +78,29 @@ pub(crate) fn commit_merge_with_head(
 	let mut parents = vec![&head_commit];
 	parents.extend(commits);
 
-	let commit_id = repo
-		.commit(
-			Some("HEAD"),
-			&signature,
-			&signature,
-			msg,
-			&tree,
-			parents.as_slice(),
-		)?
+	let config = repo.config()?;
+
+	let commit_id =
+		if crate::sync::commit::gpgsign_enabled(&config) {
+			crate::sync::commit::sign_commit_and_update_head(
+				repo,
+				&config,
+				&signature,
+				&signature,
+				msg,
+				&tree,
+				parents.as_slice(),
+			)?
+		} else {
+			repo.commit(
+				Some("HEAD"),
+				&signature,
+				&signature,
+				msg,
+				&tree,
+				parents.as_slice(),
+			)?
+		}
 		.into();
 	repo.cleanup_state()?;
 	Ok(commit_id)
@@ -3,8 +3,10 @@
 use crate::{
 	error::{Error, Result},
 	sync::{
-		rebase::conflict_free_rebase, repository::repo, CommitId,
-		RepoPath,
+		rebase::{conflict_free_rebase, rebase_branch, RebaseState},
+		repository::repo,
+		utils::bytes2string,
+		CommitId, RepoPath,
 	},
 };
 use git2::BranchType;
@@ -33,6 +35,34 @@ pub fn merge_upstream_rebase(
 	conflict_free_rebase(&repo, &annotated_upstream)
 }
 
+/// rebases current branch onto its upstream like `git pull --rebase`.
+/// unlike `merge_upstream_rebase` conflicts leave the rebase in
+/// progress to be resolved and continued (or aborted)
+pub fn pull_rebase(
+	repo_path: &RepoPath,
+	branch_name: &str,
+	update_refs: bool,
+) -> Result<RebaseState> {
+	scope_time!("pull_rebase");
+
+	let repo = repo(repo_path)?;
+	if super::get_branch_name_repo(&repo)? != branch_name {
+		return Err(Error::Generic(String::from(
+			"can only rebase in head branch",
+		)));
+	}
+
+	let branch = repo.find_branch(branch_name, BranchType::Local)?;
+	let upstream = bytes2string(branch.upstream()?.name_bytes()?)?;
+
+	rebase_branch(
+		repo_path,
+		&upstream,
+		BranchType::Remote,
+		update_refs,
+	)
+}
+
 #[cfg(test)]
 mod test {
 	use super::*;
@@ -353,4 +383,58 @@ mod test {
 			vec![String::from("commit3"), String::from("commit1")]
 		);
 	}
+
+	#[test]
+	fn test_pull_rebase_conflict() {
+		let (r1_dir, _repo) = repo_init_bare().unwrap();
+
+		let (clone1_dir, clone1) =
+			repo_clone(r1_dir.path().to_str().unwrap()).unwrap();
+
+		let clone1_dir = clone1_dir.path().to_str().unwrap();
+
+		write_commit_file(&clone1, "test.txt", "test", "commit1");
+
+		push_branch(
+			&clone1_dir.into(),
+			"origin",
+			"master",
+			false,
+			false,
+			None,
+			None,
+		)
+		.unwrap();
+
+		let (clone2_dir, clone2) =
+			repo_clone(r1_dir.path().to_str().unwrap()).unwrap();
+
+		let clone2_dir = clone2_dir.path().to_str().unwrap();
+
+		write_commit_file(&clone2, "test2.txt", "test", "commit2");
+
+		push_branch(
+			&clone2_dir.into(),
+			"origin",
+			"master",
+			false,
+			false,
+			None,
+			None,
+		)
+		.unwrap();
+
+		write_commit_file(&clone1, "test2.txt", "foo", "commit3");
+
+		fetch(&clone1_dir.into(), "master", None, None).unwrap();
+
+		let res =
+			pull_rebase(&clone1_dir.into(), "master", false).unwrap();
+		assert_eq!(res, RebaseState::Conflicted);
+
+		// conflicts are left to be resolved like any other rebase
+		let state =
+			crate::sync::repo_state(&clone1_dir.into()).unwrap();
+		assert_eq!(state, RepoState::Rebase);
+	}
 }
@@ -1,21 +1,20 @@
 //! branch functions
 
+pub mod compare;
 pub mod merge_commit;
 pub mod merge_ff;
 pub mod merge_rebase;
+pub mod recent;
 pub mod rename;
 
-use super::{
-	remotes::get_default_remote_in_repo, utils::bytes2string,
-	RepoPath,
-};
+use super::{utils::bytes2string, RepoPath};
 use crate::{
 	error::{Error, Result},
 	sync::{repository::repo, utils::get_head_repo, CommitId},
 };
 use git2::{Branch, BranchType, Repository};
 use scopetime::scope_time;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 /// returns the branch-name head is currently pointing to
 /// this might be expensive, see `cached::BranchName`
@@ -54,8 +53,12 @@ pub struct LocalBranch {
 	pub is_head: bool,
 	///
 	pub has_upstream: bool,
+	/// short name of the upstream branch (like `origin/master`)
+	pub upstream: Option<String>,
 	///
 	pub remote: Option<String>,
+	/// `branch.<name>.description`
+	pub description: Option<String>,
 }
 
 ///
@@ -85,6 +88,8 @@ pub struct BranchInfo {
 	pub top_commit_message: String,
 	///
 	pub top_commit: CommitId,
+	/// commit time of `top_commit` in seconds since epoch
+	pub top_commit_time: i64,
 	///
 	pub details: BranchDetails,
 }
@@ -118,6 +123,7 @@ pub fn get_branches_info(
 	scope_time!("get_branches_info");
 
 	let repo = repo(repo_path)?;
+	let config = repo.config()?.snapshot()?;
 
 	let (filter, remotes_with_tracking) = if local {
 		(BranchType::Local, HashSet::default())
@@ -158,7 +164,18 @@ pub fn get_branches_info(
 				BranchDetails::Local(LocalBranch {
 					is_head: branch.is_head(),
 					has_upstream: upstream.is_ok(),
+					upstream: upstream.ok().and_then(|upstream| {
+						bytes2string(upstream.name_bytes().ok()?).ok()
+					}),
 					remote,
+					description: config
+						.get_string(&description_key(&bytes2string(
+							name_bytes,
+						)?))
+						.ok()
+						.filter(|description| {
+							!description.trim().is_empty()
+						}),
 				})
 			} else {
 				BranchDetails::Remote(RemoteBranch {
@@ -174,6 +191,7 @@ pub fn get_branches_info(
 					top_commit.summary_bytes().unwrap_or_default(),
 				)?,
 				top_commit: top_commit.id().into(),
+				top_commit_time: top_commit.time().seconds(),
 				details,
 			})
 		})
@@ -186,7 +204,7 @@ pub fn get_branches_info(
 }
 
 ///
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub struct BranchCompare {
 	///
 	pub ahead: usize,
@@ -194,10 +212,13 @@ pub struct BranchCompare {
 	pub behind: usize,
 }
 
-///
+/// sets upstream of `branch_name` to `remote_branch` on `remote`
+/// unless it already has one
 pub(crate) fn branch_set_upstream(
 	repo: &Repository,
 	branch_name: &str,
+	remote: &str,
+	remote_branch: &str,
 ) -> Result<()> {
 	scope_time!("branch_set_upstream");
 
@@ -205,14 +226,61 @@ pub(crate) fn branch_set_upstream(
 		repo.find_branch(branch_name, BranchType::Local)?;
 
 	if branch.upstream().is_err() {
-		let remote = get_default_remote_in_repo(repo)?;
-		let upstream_name = format!("{}/{}", remote, branch_name);
+		let upstream_name = format!("{}/{}", remote, remote_branch);
 		branch.set_upstream(Some(upstream_name.as_str()))?;
 	}
 
 	Ok(())
 }
 
+/// sets `branch.<name>.remote` and `branch.<name>.merge` of local
+/// `branch` to track the remote branch `upstream` (e.g. `origin/main`)
+pub fn set_branch_upstream(
+	repo_path: &RepoPath,
+	branch: &str,
+	upstream: &str,
+) -> Result<()> {
+	scope_time!("set_branch_upstream");
+
+	let repo = repo(repo_path)?;
+	let mut branch = repo.find_branch(branch, BranchType::Local)?;
+
+	branch.set_upstream(Some(upstream))?;
+
+	Ok(())
+}
+
+fn description_key(branch: &str) -> String {
+	format!("branch.{}.description", branch)
+}
+
+/// sets `branch.<name>.description` of local `branch`,
+/// an empty `description` removes it
+pub fn set_branch_description(
+	repo_path: &RepoPath,
+	branch: &str,
+	description: &str,
+) -> Result<()> {
+	scope_time!("set_branch_description");
+
+	let repo = repo(repo_path)?;
+	let mut cfg =
+		repo.config()?.open_level(git2::ConfigLevel::Local)?;
+	let key = description_key(branch);
+
+	if description.trim().is_empty() {
+		if let Err(e) = cfg.remove(&key) {
+			if e.code() != git2::ErrorCode::NotFound {
+				return Err(e.into());
+			}
+		}
+	} else {
+		cfg.set_str(&key, description.trim_end())?;
+	}
+
+	Ok(())
+}
+
 /// returns remote of the upstream tracking branch for `branch`
 pub fn get_branch_remote(
 	repo_path: &RepoPath,
@@ -229,6 +297,52 @@ pub fn get_branch_remote(
 	}
 }
 
+/// returns names of local branches configured to track an upstream
+/// that no longer exists (shown as `[gone]` by `git branch -vv`)
+pub fn get_branches_gone_upstream(
+	repo_path: &RepoPath,
+) -> Result<Vec<String>> {
+	scope_time!("get_branches_gone_upstream");
+
+	let repo = repo(repo_path)?;
+
+	let mut gone = Vec::new();
+	for b in repo.branches(Some(BranchType::Local))? {
+		let (branch, _) = b?;
+		let reference = bytes2string(branch.get().name_bytes())?;
+
+		if repo.branch_upstream_name(&reference).is_ok()
+			&& branch.upstream().is_err()
+		{
+			gone.push(bytes2string(branch.name_bytes()?)?);
+		}
+	}
+
+	Ok(gone)
+}
+
+/// splits the remote branch `reference` (like
+/// `refs/remotes/origin/feature/foo`) into the name of its remote and
+/// the branch name on that remote (`origin` and `feature/foo`)
+pub fn split_remote_branch(
+	repo_path: &RepoPath,
+	reference: &str,
+) -> Result<(String, String)> {
+	let repo = repo(repo_path)?;
+	let remote =
+		bytes2string(repo.branch_remote_name(reference)?.as_ref())?;
+	let prefix = format!("refs/remotes/{}/", remote);
+	let branch =
+		reference.strip_prefix(&prefix).ok_or_else(|| {
+			Error::Generic(format!(
+				"'{}' is not a branch of remote '{}'",
+				reference, remote
+			))
+		})?;
+
+	Ok((remote, branch.to_string()))
+}
+
 /// returns whether the pull merge strategy is set to rebase
 pub fn config_is_pull_rebase(repo_path: &RepoPath) -> Result<bool> {
 	let repo = repo(repo_path)?;
@@ -243,6 +357,41 @@ pub fn config_is_pull_rebase(repo_path: &RepoPath) -> Result<bool> {
 	Ok(false)
 }
 
+/// how pulled changes get integrated into the local branch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PullMode {
+	/// fast-forward if possible, merge commit otherwise
+	Merge,
+	///
+	Rebase,
+	/// fail unless a fast-forward is possible
+	FastForwardOnly,
+}
+
+impl Default for PullMode {
+	fn default() -> Self {
+		Self::Merge
+	}
+}
+
+/// pull mode as configured by `pull.rebase` and `pull.ff`
+pub fn config_pull_mode(repo_path: &RepoPath) -> Result<PullMode> {
+	if config_is_pull_rebase(repo_path)? {
+		return Ok(PullMode::Rebase);
+	}
+
+	let repo = repo(repo_path)?;
+	let config = repo.config()?;
+
+	if let Ok(ff) = config.get_entry("pull.ff") {
+		if ff.value() == Some("only") {
+			return Ok(PullMode::FastForwardOnly);
+		}
+	}
+
+	Ok(PullMode::Merge)
+}
+
 ///
 pub fn branch_compare_upstream(
 	repo_path: &RepoPath,
@@ -268,6 +417,35 @@ pub fn branch_compare_upstream(
 	Ok(BranchCompare { ahead, behind })
 }
 
+/// `BranchCompare` of every local branch that has an upstream, by branch name
+pub fn branches_compare_upstream(
+	repo_path: &RepoPath,
+) -> Result<HashMap<String, BranchCompare>> {
+	scope_time!("branches_compare_upstream");
+
+	let repo = repo(repo_path)?;
+
+	let mut res = HashMap::new();
+
+	for branch in repo.branches(Some(BranchType::Local))? {
+		let branch = branch?.0;
+
+		if let Ok(upstream) = branch.upstream() {
+			let name = bytes2string(branch.name_bytes()?)?;
+			let branch_commit = branch.get().peel_to_commit()?.id();
+			let upstream_commit =
+				upstream.into_reference().peel_to_commit()?.id();
+
+			let (ahead, behind) = repo
+				.graph_ahead_behind(branch_commit, upstream_commit)?;
+
+			res.insert(name, BranchCompare { ahead, behind });
+		}
+	}
+
+	Ok(res)
+}
+
 /// Modify HEAD to point to a branch then checkout head, does not work if there are uncommitted changes
 pub fn checkout_branch(
 	repo_path: &RepoPath,
@@ -301,6 +479,52 @@ pub fn checkout_branch(
 	}
 }
 
+/// checks out `commit_id` into a detached `HEAD`, refusing to touch
+/// uncommitted changes just like [`checkout_branch`]
+pub fn checkout_commit(
+	repo_path: &RepoPath,
+	commit_id: CommitId,
+) -> Result<()> {
+	scope_time!("checkout_commit");
+
+	let repo = repo(repo_path)?;
+	let cur_ref = repo.head()?;
+
+	if !repo
+		.statuses(Some(
+			git2::StatusOptions::new().include_ignored(false),
+		))?
+		.is_empty()
+	{
+		return Err(Error::UncommittedChanges);
+	}
+
+	repo.set_head_detached(commit_id.into())?;
+
+	if let Err(e) = repo.checkout_head(Some(
+		git2::build::CheckoutBuilder::new().force(),
+	)) {
+		// This is safe beacuse cur_ref was just found
+		repo.set_head(bytes2string(cur_ref.name_bytes())?.as_str())?;
+		return Err(Error::Git(e));
+	}
+
+	Ok(())
+}
+
+/// commit `HEAD` points to, `None` unless `HEAD` is detached
+pub fn detached_head(
+	repo_path: &RepoPath,
+) -> Result<Option<CommitId>> {
+	let repo = repo(repo_path)?;
+
+	if repo.head_detached()? {
+		Ok(Some(get_head_repo(&repo)?))
+	} else {
+		Ok(None)
+	}
+}
+
 ///
 pub fn checkout_remote_branch(
 	repo_path: &RepoPath,
@@ -361,6 +585,70 @@ pub fn delete_branch(
 	Ok(())
 }
 
+/// outcome of [`delete_merged_branches`]
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct DeletedBranches {
+	///
+	pub deleted: Vec<String>,
+	/// refused because they are not merged
+	pub unmerged: Vec<String>,
+	/// branch names and the error deleting them
+	pub failed: Vec<(String, String)>,
+}
+
+/// whether the tip of `branch` is contained in its upstream or,
+/// lacking one, in `HEAD` (like `git branch -d` checks)
+fn is_branch_merged(
+	repo: &Repository,
+	branch: &Branch,
+) -> Result<bool> {
+	let tip = branch.get().peel_to_commit()?.id();
+	let target = match branch.upstream() {
+		Ok(upstream) => upstream.get().peel_to_commit()?.id(),
+		Err(_) => repo.head()?.peel_to_commit()?.id(),
+	};
+
+	Ok(tip == target || repo.graph_descendant_of(target, tip)?)
+}
+
+/// deletes the local `branches` (by name) that are fully merged,
+/// refusing the others
+pub fn delete_merged_branches(
+	repo_path: &RepoPath,
+	branches: &[String],
+) -> Result<DeletedBranches> {
+	scope_time!("delete_merged_branches");
+
+	let repo = repo(repo_path)?;
+
+	let mut result = DeletedBranches::default();
+	for name in branches {
+		let mut branch =
+			match repo.find_branch(name, BranchType::Local) {
+				Ok(branch) => branch,
+				Err(e) => {
+					result.failed.push((name.clone(), e.to_string()));
+					continue;
+				}
+			};
+
+		if branch.is_head() {
+			result.failed.push((
+				name.clone(),
+				String::from("cannot delete the checked out branch"),
+			));
+		} else if !is_branch_merged(&repo, &branch)? {
+			result.unmerged.push(name.clone());
+		} else if let Err(e) = branch.delete() {
+			result.failed.push((name.clone(), e.to_string()));
+		} else {
+			result.deleted.push(name.clone());
+		}
+	}
+
+	Ok(result)
+}
+
 /// creates a new branch pointing to current HEAD commit and updating HEAD to new branch
 pub fn create_branch(
 	repo_path: &RepoPath,
@@ -437,7 +725,7 @@ mod tests_create_branch {
 #[cfg(test)]
 mod tests_branch_compare {
 	use super::*;
-	use crate::sync::tests::repo_init;
+	use crate::sync::tests::{repo_init, write_commit_file};
 
 	#[test]
 	fn test_smoke() {
@@ -452,6 +740,39 @@ mod tests_branch_compare {
 
 		assert_eq!(res.is_err(), true);
 	}
+
+	#[test]
+	fn test_all_branches() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		create_branch(repo_path, "test").unwrap();
+		write_commit_file(&repo, "test.txt", "test", "commit1");
+
+		repo.find_branch("test", BranchType::Local)
+			.unwrap()
+			.set_upstream(Some("master"))
+			.unwrap();
+
+		let res = branches_compare_upstream(repo_path).unwrap();
+
+		assert_eq!(res.len(), 1);
+		assert_eq!(
+			res.get("test"),
+			Some(&BranchCompare {
+				ahead: 1,
+				behind: 0
+			})
+		);
+
+		let branches = get_branches_info(repo_path, true).unwrap();
+		assert_eq!(
+			branches[1].local_details().unwrap().upstream,
+			Some(String::from("master"))
+		);
+	}
 }
 
 #[cfg(test)]
@@ -626,6 +947,69 @@ mod tests_branches {
 
 		assert!(get_branch_remote(repo_path, "foo").is_err());
 	}
+
+	#[test]
+	fn test_set_branch_description() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let description = || {
+			get_branches_info(repo_path, true).unwrap()[0]
+				.local_details()
+				.unwrap()
+				.description
+				.clone()
+		};
+
+		assert_eq!(description(), None);
+
+		set_branch_description(repo_path, "master", "review: foo\n")
+			.unwrap();
+		assert_eq!(description().as_deref(), Some("review: foo"));
+
+		set_branch_description(repo_path, "master", "").unwrap();
+		assert_eq!(description(), None);
+
+		// removing a missing description is fine
+		set_branch_description(repo_path, "master", " ").unwrap();
+	}
+}
+
+#[cfg(test)]
+mod tests_pull_mode {
+	use super::*;
+	use crate::sync::tests::repo_init;
+
+	#[test]
+	fn test_config_pull_mode() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		assert_eq!(
+			config_pull_mode(repo_path).unwrap(),
+			PullMode::Merge
+		);
+
+		repo.config().unwrap().set_str("pull.ff", "only").unwrap();
+		assert_eq!(
+			config_pull_mode(repo_path).unwrap(),
+			PullMode::FastForwardOnly
+		);
+
+		// pull.rebase wins like it does for git
+		repo.config()
+			.unwrap()
+			.set_bool("pull.rebase", true)
+			.unwrap();
+		assert_eq!(
+			config_pull_mode(repo_path).unwrap(),
+			PullMode::Rebase
+		);
+	}
 }
 
 #[cfg(test)]
@@ -663,12 +1047,72 @@ mod tests_checkout {
 		);
 		assert!(checkout_branch(repo_path, "refs/heads/test").is_ok());
 	}
+
+	#[test]
+	fn test_checkout_commit() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let head = get_head_repo(&repo).unwrap();
+
+		assert_eq!(detached_head(repo_path).unwrap(), None);
+
+		checkout_commit(repo_path, head).unwrap();
+
+		assert!(repo.head_detached().unwrap());
+		assert_eq!(detached_head(repo_path).unwrap(), Some(head));
+
+		std::fs::write(root.join("foo"), "dirty").unwrap();
+		let mut index = repo.index().unwrap();
+		index.add_path(std::path::Path::new("foo")).unwrap();
+		index.write().unwrap();
+
+		assert!(matches!(
+			checkout_commit(repo_path, head),
+			Err(Error::UncommittedChanges)
+		));
+	}
 }
 
 #[cfg(test)]
 mod test_delete_branch {
 	use super::*;
-	use crate::sync::tests::repo_init;
+	use crate::sync::tests::{repo_init, write_commit_file};
+
+	#[test]
+	fn test_delete_merged_branches() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		create_branch(repo_path, "merged").unwrap();
+		create_branch(repo_path, "unmerged").unwrap();
+		write_commit_file(&repo, "test.txt", "test", "commit1");
+		checkout_branch(repo_path, "refs/heads/master").unwrap();
+
+		let result = delete_merged_branches(
+			repo_path,
+			&[
+				String::from("merged"),
+				String::from("unmerged"),
+				String::from("master"),
+			],
+		)
+		.unwrap();
+
+		assert_eq!(result.deleted, vec![String::from("merged")]);
+		assert_eq!(result.unmerged, vec![String::from("unmerged")]);
+		assert_eq!(result.failed.len(), 1);
+		assert!(repo
+			.find_branch("merged", BranchType::Local)
+			.is_err());
+		assert!(repo
+			.find_branch("unmerged", BranchType::Local)
+			.is_ok());
+	}
 
 	#[test]
 	fn test_delete_branch() {
@@ -968,4 +1412,140 @@ mod test_remote_branches {
 		);
 		assert!(branches_2[2].remote_details().unwrap().has_tracking);
 	}
+
+	#[test]
+	fn test_set_branch_upstream() {
+		let (r1_dir, _repo) = repo_init_bare().unwrap();
+
+		let (clone1_dir, clone1) =
+			repo_clone(r1_dir.path().to_str().unwrap()).unwrap();
+		let clone1_dir = clone1_dir.path().to_str().unwrap();
+
+		write_commit_file(&clone1, "test.txt", "test", "commit1");
+		push_branch(
+			&clone1_dir.into(),
+			"origin",
+			"master",
+			false,
+			false,
+			None,
+			None,
+		)
+		.unwrap();
+		create_branch(&clone1_dir.into(), "foo").unwrap();
+
+		let upstream_of_foo = || {
+			get_branches_info(&clone1_dir.into(), true)
+				.unwrap()
+				.into_iter()
+				.find(|b| b.name == "foo")
+				.unwrap()
+				.local_details()
+				.unwrap()
+				.upstream
+				.clone()
+		};
+
+		assert_eq!(upstream_of_foo(), None);
+
+		set_branch_upstream(
+			&clone1_dir.into(),
+			"foo",
+			"origin/master",
+		)
+		.unwrap();
+
+		assert_eq!(
+			upstream_of_foo(),
+			Some(String::from("origin/master"))
+		);
+		assert_eq!(
+			get_branch_remote(&clone1_dir.into(), "foo").unwrap(),
+			Some(String::from("origin"))
+		);
+	}
+
+	#[test]
+	fn test_split_remote_branch() {
+		let (r1_dir, _repo) = repo_init_bare().unwrap();
+
+		let (clone1_dir, clone1) =
+			repo_clone(r1_dir.path().to_str().unwrap()).unwrap();
+		let clone1_dir = clone1_dir.path().to_str().unwrap();
+
+		write_commit_file(&clone1, "test.txt", "test", "commit1");
+		create_branch(&clone1_dir.into(), "feature/foo").unwrap();
+		push_branch(
+			&clone1_dir.into(),
+			"origin",
+			"feature/foo",
+			false,
+			false,
+			None,
+			None,
+		)
+		.unwrap();
+
+		assert_eq!(
+			split_remote_branch(
+				&clone1_dir.into(),
+				"refs/remotes/origin/feature/foo"
+			)
+			.unwrap(),
+			(String::from("origin"), String::from("feature/foo"))
+		);
+		assert!(split_remote_branch(
+			&clone1_dir.into(),
+			"refs/heads/feature/foo"
+		)
+		.is_err());
+	}
+
+	#[test]
+	fn test_branches_gone_upstream() {
+		let (r1_dir, _repo) = repo_init_bare().unwrap();
+
+		let (clone1_dir, clone1) =
+			repo_clone(r1_dir.path().to_str().unwrap()).unwrap();
+		let clone1_dir = clone1_dir.path().to_str().unwrap();
+
+		write_commit_file(&clone1, "test.txt", "test", "commit1");
+		push_branch(
+			&clone1_dir.into(),
+			"origin",
+			"master",
+			false,
+			false,
+			None,
+			None,
+		)
+		.unwrap();
+		create_branch(&clone1_dir.into(), "foo").unwrap();
+		push_branch(
+			&clone1_dir.into(),
+			"origin",
+			"foo",
+			false,
+			false,
+			None,
+			None,
+		)
+		.unwrap();
+		create_branch(&clone1_dir.into(), "bar").unwrap();
+
+		assert!(get_branches_gone_upstream(&clone1_dir.into())
+			.unwrap()
+			.is_empty());
+
+		clone1
+			.find_reference("refs/remotes/origin/foo")
+			.unwrap()
+			.delete()
+			.unwrap();
+
+		assert_eq!(
+			get_branches_gone_upstream(&clone1_dir.into()).unwrap(),
+			vec![String::from("foo")]
+		);
+	}
 }
@@ -0,0 +1,110 @@
+//! branches ordered by most recent checkout
+
+use crate::{
+	error::Result,
+	sync::{repository::repo, RepoPath},
+};
+use git2::BranchType;
+use scopetime::scope_time;
+
+const CHECKOUT_PREFIX: &str = "checkout: moving from ";
+
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecentBranch {
+	///
+	pub name: String,
+	/// time of the last checkout of this branch (or away from it)
+	pub time: i64,
+}
+
+/// local branches other than `HEAD` ordered by their last checkout
+/// according to the `HEAD` reflog, most recent first
+pub fn get_recent_branches(
+	repo_path: &RepoPath,
+	limit: usize,
+) -> Result<Vec<RecentBranch>> {
+	scope_time!("get_recent_branches");
+
+	let repo = repo(repo_path)?;
+	let head = repo.head()?;
+	let current = if head.is_branch() {
+		head.shorthand()
+	} else {
+		None
+	};
+
+	let mut res: Vec<RecentBranch> = Vec::new();
+
+	for entry in repo.reflog("HEAD")?.iter() {
+		if res.len() >= limit {
+			break;
+		}
+
+		let moved = entry.message().and_then(|msg| {
+			let (from, to) = msg
+				.strip_prefix(CHECKOUT_PREFIX)?
+				.split_once(" to ")?;
+			Some([to, from])
+		});
+
+		for name in moved.into_iter().flatten() {
+			let known = Some(name) == current
+				|| res.iter().any(|branch| branch.name == name);
+
+			if !known
+				&& res.len() < limit
+				&& repo.find_branch(name, BranchType::Local).is_ok()
+			{
+				res.push(RecentBranch {
+					name: name.to_string(),
+					time: entry.committer().when().seconds(),
+				});
+			}
+		}
+	}
+
+	Ok(res)
+}
+
+#[cfg(test)]
+mod test {
+	use super::get_recent_branches;
+	use crate::sync::{
+		checkout_branch, create_branch, delete_branch,
+		tests::repo_init, RepoPath,
+	};
+
+	fn names(repo_path: &RepoPath, limit: usize) -> Vec<String> {
+		get_recent_branches(repo_path, limit)
+			.unwrap()
+			.into_iter()
+			.map(|branch| branch.name)
+			.collect()
+	}
+
+	#[test]
+	fn test_recent_branches() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		assert!(names(repo_path, 10).is_empty());
+
+		create_branch(repo_path, "a").unwrap();
+		create_branch(repo_path, "b").unwrap();
+		create_branch(repo_path, "c").unwrap();
+
+		checkout_branch(repo_path, "refs/heads/a").unwrap();
+		checkout_branch(repo_path, "refs/heads/b").unwrap();
+
+		assert_eq!(names(repo_path, 10), vec!["a", "c", "master"]);
+		assert_eq!(names(repo_path, 2), vec!["a", "c"]);
+
+		checkout_branch(repo_path, "refs/heads/a").unwrap();
+		delete_branch(repo_path, "refs/heads/c").unwrap();
+
+		assert_eq!(names(repo_path, 10), vec!["b", "master"]);
+	}
+}
@@ -0,0 +1,73 @@
+use super::{CommitId, RepoPath};
+use crate::{
+	error::Result,
+	sync::{repository::repo, utils::read_file},
+};
+use scopetime::scope_time;
+
+const GIT_CHERRY_PICK_HEAD_FILE: &str = "CHERRY_PICK_HEAD";
+
+/// commit being picked in a pending cherry-pick
+pub fn cherrypick_head(repo_path: &RepoPath) -> Result<CommitId> {
+	scope_time!("cherrypick_head");
+
+	let path =
+		repo(repo_path)?.path().join(GIT_CHERRY_PICK_HEAD_FILE);
+
+	let file_content = read_file(&path)?;
+
+	let id = git2::Oid::from_str(file_content.trim())?;
+
+	Ok(id.into())
+}
+
+/// commits the result of a pending cherry-pick and cleans up its state
+pub fn commit_cherrypick(
+	repo_path: &RepoPath,
+	msg: &str,
+) -> Result<CommitId> {
+	scope_time!("commit_cherrypick");
+
+	let id = crate::sync::commit(repo_path, msg)?;
+
+	repo(repo_path)?.cleanup_state()?;
+
+	Ok(id)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{cherrypick_head, commit_cherrypick};
+	use crate::sync::{
+		checkout_branch, create_branch, repo_state,
+		tests::{repo_init, write_commit_file},
+		RepoPath, RepoState,
+	};
+
+	#[test]
+	fn test_cherrypick_state() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		create_branch(repo_path, "foo").unwrap();
+		let picked =
+			write_commit_file(&repo, "test.txt", "test", "commit1");
+
+		checkout_branch(repo_path, "refs/heads/master").unwrap();
+
+		let commit = repo.find_commit(picked.into()).unwrap();
+		repo.cherrypick(&commit, None).unwrap();
+
+		assert_eq!(
+			repo_state(repo_path).unwrap(),
+			RepoState::CherryPick
+		);
+		assert_eq!(cherrypick_head(repo_path).unwrap(), picked);
+
+		commit_cherrypick(repo_path, "commit1").unwrap();
+
+		assert_eq!(repo_state(repo_path).unwrap(), RepoState::Clean);
+	}
+}
@@ -1,11 +1,58 @@
 use super::{CommitId, RepoPath};
 use crate::{
-	error::Result,
-	sync::{repository::repo, utils::get_head_repo},
+	error::{Error, Result},
+	sync::{
+		repository::repo, sign::SignBuilder, utils::get_head_repo,
+	},
+};
+use git2::{
+	Commit, Config, ErrorCode, ObjectType, Oid, Repository,
+	Signature, Time, Tree,
 };
-use git2::{ErrorCode, ObjectType, Repository, Signature};
 use scopetime::scope_time;
 
+/// author identity and date of a commit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitAuthor {
+	///
+	pub name: String,
+	///
+	pub email: String,
+	/// time in secs since Unix epoch
+	pub time: i64,
+	/// timezone offset in minutes
+	pub offset: i32,
+}
+
+impl CommitAuthor {
+	fn to_signature(&self) -> Result<Signature<'static>> {
+		Ok(Signature::new(
+			&self.name,
+			&self.email,
+			&Time::new(self.time, self.offset),
+		)?)
+	}
+}
+
+/// author of commit `id` including the timezone offset
+pub fn get_commit_author(
+	repo_path: &RepoPath,
+	id: CommitId,
+) -> Result<CommitAuthor> {
+	scope_time!("get_commit_author");
+
+	let repo = repo(repo_path)?;
+	let commit = repo.find_commit(id.into())?;
+	let author = commit.author();
+
+	Ok(CommitAuthor {
+		name: String::from_utf8_lossy(author.name_bytes()).into(),
+		email: String::from_utf8_lossy(author.email_bytes()).into(),
+		time: author.when().seconds(),
+		offset: author.when().offset_minutes(),
+	})
+}
+
 ///
 pub fn amend(
 	repo_path: &RepoPath,
@@ -14,6 +61,27 @@ pub fn amend(
 ) -> Result<CommitId> {
 	scope_time!("amend");
 
+	amend_commit(repo_path, id, msg, None)
+}
+
+/// amend commit `id` replacing its author name, email and date
+pub fn amend_with_author(
+	repo_path: &RepoPath,
+	id: CommitId,
+	msg: &str,
+	author: &CommitAuthor,
+) -> Result<CommitId> {
+	scope_time!("amend_with_author");
+
+	amend_commit(repo_path, id, msg, Some(&author.to_signature()?))
+}
+
+fn amend_commit(
+	repo_path: &RepoPath,
+	id: CommitId,
+	msg: &str,
+	author: Option<&Signature>,
+) -> Result<CommitId> {
 	let repo = repo(repo_path)?;
 	let commit = repo.find_commit(id.into())?;
 
@@ -21,18 +89,92 @@ pub fn amend(
 	let tree_id = index.write_tree()?;
 	let tree = repo.find_tree(tree_id)?;
 
-	let new_id = commit.amend(
-		Some("HEAD"),
-		None,
-		None,
-		None,
-		Some(msg),
-		Some(&tree),
-	)?;
+	let config = repo.config()?;
+
+	let new_id = if gpgsign_enabled(&config) {
+		let parents = commit.parents().collect::<Vec<_>>();
+		let parents = parents.iter().collect::<Vec<_>>();
+		let original_author = commit.author();
+
+		sign_commit_and_update_head(
+			&repo,
+			&config,
+			author.unwrap_or(&original_author),
+			&commit.committer(),
+			msg,
+			&tree,
+			parents.as_slice(),
+		)?
+	} else {
+		commit.amend(
+			Some("HEAD"),
+			author,
+			None,
+			None,
+			Some(msg),
+			Some(&tree),
+		)?
+	};
 
 	Ok(CommitId::new(new_id))
 }
 
+/// checks `commit.gpgsign` to see whether commits need to be signed
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) fn gpgsign_enabled(config: &Config) -> bool {
+	config.get_bool("commit.gpgsign").unwrap_or_default()
+}
+
+/// signs the commit using the method configured in `gpg.format`
+/// and points `HEAD` (or the branch it refers to) to the result.
+///
+/// `Repository::commit_signed` does not update any reference
+/// so this has to be done manually here.
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) fn sign_commit_and_update_head(
+	repo: &Repository,
+	config: &Config,
+	author: &Signature,
+	committer: &Signature,
+	msg: &str,
+	tree: &Tree,
+	parents: &[&Commit],
+) -> Result<Oid> {
+	let buffer = repo.commit_create_buffer(
+		author, committer, msg, tree, parents,
+	)?;
+
+	let commit = std::str::from_utf8(&buffer).map_err(|_| {
+		Error::Generic(String::from(
+			"commit buffer is not valid utf8",
+		))
+	})?;
+
+	let signer = SignBuilder::from_gitconfig(repo, config)?;
+	let (signature, signature_field) = signer.sign(&buffer)?;
+
+	let id = repo.commit_signed(
+		commit,
+		&signature,
+		signature_field.as_deref(),
+	)?;
+
+	if let Ok(mut head) = repo.head() {
+		head.set_target(id, msg)?;
+	} else {
+		// unborn branch: `HEAD` points to a ref that does not exist yet
+		let head = repo.find_reference("HEAD")?;
+		let branch = head.symbolic_target().ok_or_else(|| {
+			Error::Generic(String::from(
+				"HEAD is neither a branch nor a commit",
+			))
+		})?;
+		repo.reference(branch, id, true, msg)?;
+	}
+
+	Ok(id)
+}
+
 /// Wrap `Repository::signature` to allow unknown user.name.
 ///
 /// See <https://github.com/extrawurst/gitui/issues/79>.
@@ -79,8 +221,20 @@ pub fn commit(repo_path: &RepoPath, msg: &str) -> Result<CommitId> {
 
 	let parents = parents.iter().collect::<Vec<_>>();
 
-	Ok(repo
-		.commit(
+	let config = repo.config()?;
+
+	let id = if gpgsign_enabled(&config) {
+		sign_commit_and_update_head(
+			&repo,
+			&config,
+			&signature,
+			&signature,
+			msg,
+			&tree,
+			parents.as_slice(),
+		)?
+	} else {
+		repo.commit(
 			Some("HEAD"),
 			&signature,
 			&signature,
@@ -88,7 +242,9 @@ pub fn commit(repo_path: &RepoPath, msg: &str) -> Result<CommitId> {
 			&tree,
 			parents.as_slice(),
 		)?
-		.into())
+	};
+
+	Ok(id.into())
 }
 
 /// Tag a commit.
@@ -119,20 +275,107 @@ pub fn tag_commit(
 	Ok(c)
 }
 
+/// checks `tag.gpgsign` to see whether annotated tags should be
+/// signed by default
+pub fn tag_gpgsign_enabled(repo_path: &RepoPath) -> Result<bool> {
+	let repo = repo(repo_path)?;
+	let config = repo.config()?;
+
+	Ok(config.get_bool("tag.gpgsign").unwrap_or_default())
+}
+
+/// `true` if commits get signed (`commit.gpgsign`)
+pub fn commit_gpgsign_enabled(repo_path: &RepoPath) -> Result<bool> {
+	let repo = repo(repo_path)?;
+	let config = repo.config()?;
+
+	Ok(gpgsign_enabled(&config))
+}
+
+/// Create an annotated tag signed using the method configured in
+/// `gpg.format`, like `git tag -s`.
+///
+/// libgit2 cannot sign tags, so the tag object is assembled here and
+/// the signature appended to its message the way git does it.
+pub fn tag_commit_signed(
+	repo_path: &RepoPath,
+	commit_id: &CommitId,
+	tag: &str,
+	message: &str,
+) -> Result<CommitId> {
+	scope_time!("tag_commit_signed");
+
+	let repo = repo(repo_path)?;
+	let config = repo.config()?;
+
+	let ref_name = format!("refs/tags/{}", tag);
+	if !git2::Reference::is_valid_name(&ref_name) {
+		return Err(Error::Generic(format!(
+			"'{}' is not a valid tag name",
+			tag
+		)));
+	}
+
+	let target = repo
+		.find_object(commit_id.get_oid(), Some(ObjectType::Commit))?;
+	let tagger = signature_allow_undefined_name(&repo)?;
+
+	let mut buffer = format!(
+		"object {}\ntype commit\ntag {}\ntagger {}\n\n{}",
+		target.id(),
+		tag,
+		signature_line(&tagger),
+		message
+	);
+	if !buffer.ends_with('\n') {
+		buffer.push('\n');
+	}
+
+	let signer = SignBuilder::from_gitconfig(&repo, &config)?;
+	let (signature, _) = signer.sign(buffer.as_bytes())?;
+	buffer.push_str(&signature);
+
+	let id = repo.odb()?.write(ObjectType::Tag, buffer.as_bytes())?;
+	repo.reference(&ref_name, id, false, "tag: signed")?;
+
+	Ok(id.into())
+}
+
+/// `name <email> time offset` as it appears in object headers
+fn signature_line(signature: &Signature) -> String {
+	let when = signature.when();
+	let offset = when.offset_minutes().abs();
+
+	format!(
+		"{} <{}> {} {}{:02}{:02}",
+		String::from_utf8_lossy(signature.name_bytes()),
+		String::from_utf8_lossy(signature.email_bytes()),
+		when.seconds(),
+		if when.offset_minutes() < 0 { '-' } else { '+' },
+		offset / 60,
+		offset % 60
+	)
+}
+
 #[cfg(test)]
 mod tests {
 
 	use crate::error::Result;
 	use crate::sync::tags::Tag;
-	use crate::sync::RepoPath;
 	use crate::sync::{
-		commit, get_commit_details, get_commit_files, stage_add_file,
-		tags::get_tags,
+		commit, get_commit_details, get_commit_files,
+		sign::Verification,
+		stage_add_file,
+		tags::{get_tags, verify_tag},
 		tests::{get_statuses, repo_init, repo_init_empty},
 		utils::get_head,
 		LogWalker,
 	};
-	use commit::{amend, tag_commit};
+	use crate::sync::{CommitId, RepoPath};
+	use commit::{
+		amend, amend_with_author, get_commit_author, tag_commit,
+		tag_commit_signed, tag_gpgsign_enabled, CommitAuthor,
+	};
 	use git2::Repository;
 	use std::{fs::File, io::Write, path::Path};
 
@@ -231,6 +474,119 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_commit_empty() -> Result<()> {
+		let file_path = Path::new("foo");
+		let (_td, repo) = repo_init_empty()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		File::create(root.join(file_path))?.write_all(b"test1")?;
+
+		stage_add_file(repo_path, file_path)?;
+		let first = commit(repo_path, "commit msg")?;
+
+		assert_eq!(get_statuses(repo_path), (0, 0));
+
+		let second = commit(repo_path, "empty")?;
+
+		assert_eq!(count_commits(&repo, 10), 2);
+
+		let tree = |id: CommitId| {
+			repo.find_commit(id.into()).unwrap().tree_id()
+		};
+		assert_eq!(tree(first), tree(second));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_amend_with_author() -> Result<()> {
+		let file_path = Path::new("foo");
+		let (_td, repo) = repo_init_empty()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		File::create(root.join(file_path))?.write_all(b"test1")?;
+
+		stage_add_file(repo_path, file_path)?;
+		let id = commit(repo_path, "commit msg")?;
+
+		let author = CommitAuthor {
+			name: String::from("other"),
+			email: String::from("other@example.com"),
+			time: 1_000_000,
+			offset: 120,
+		};
+
+		let new_id =
+			amend_with_author(repo_path, id, "amended", &author)?;
+
+		assert_eq!(count_commits(&repo, 10), 1);
+		assert_eq!(get_head(repo_path)?, new_id);
+		assert_eq!(get_commit_author(repo_path, new_id)?, author);
+
+		let details = get_commit_details(repo_path, new_id)?;
+		assert_eq!(details.message.unwrap().subject, "amended");
+		assert_eq!(details.author.name, "other");
+		assert_eq!(details.author.time, 1_000_000);
+
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(unix)]
+	fn test_commit_gpgsign() -> Result<()> {
+		use std::os::unix::fs::PermissionsExt;
+
+		let file_path = Path::new("foo");
+		let (td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		// fake gpg that produces a constant signature
+		let gpg = td.path().join("fake-gpg");
+		std::fs::write(
+			&gpg,
+			"#!/bin/sh\ncat > /dev/null\necho '\n[GNUPG:] SIG_CREATED ' >&2\necho 'SIGNATURE'\n",
+		)?;
+		std::fs::set_permissions(
+			&gpg,
+			std::fs::Permissions::from_mode(0o755),
+		)?;
+
+		{
+			let mut config = repo.config()?;
+			config.set_bool("commit.gpgsign", true)?;
+			config.set_str("gpg.program", gpg.to_str().unwrap())?;
+		}
+
+		File::create(&root.join(file_path))?.write_all(b"test1")?;
+		stage_add_file(repo_path, file_path)?;
+
+		let id = commit(repo_path, "commit msg")?;
+
+		assert_eq!(get_head(repo_path)?, id);
+		assert_eq!(count_commits(&repo, 10), 1);
+
+		let (signature, _) =
+			repo.extract_signature(&id.into(), None)?;
+		assert_eq!(signature.as_str(), Some("SIGNATURE\n"));
+
+		let amended = amend(repo_path, id, "amended")?;
+
+		assert_eq!(get_head(repo_path)?, amended);
+		assert_eq!(count_commits(&repo, 10), 1);
+		assert!(repo
+			.extract_signature(&amended.into(), None)
+			.is_ok());
+
+		Ok(())
+	}
+
 	#[test]
 	fn test_tag() -> Result<()> {
 		let file_path = Path::new("foo");
@@ -307,6 +663,61 @@ mod tests {
 	///
 	/// As of February 2021, `repo_init_empty` sets all git config locations
 	/// to an empty temporary directory, so this constraint is met.
+	#[test]
+	#[cfg(unix)]
+	fn test_tag_signed() -> Result<()> {
+		use std::os::unix::fs::PermissionsExt;
+
+		let file_path = Path::new("foo");
+		let (td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		// fake gpg that signs with a constant signature and
+		// accepts every signature it is asked to verify
+		let gpg = td.path().join("fake-gpg");
+		std::fs::write(
+			&gpg,
+			"#!/bin/sh\ncat > /dev/null\ncase \"$*\" in\n*--verify*) echo '[GNUPG:] GOODSIG ABCD tester <t@t>' ;;\n*) echo '\n[GNUPG:] SIG_CREATED ' >&2\nprintf -- '-----BEGIN PGP SIGNATURE-----\\nSIG\\n-----END PGP SIGNATURE-----\\n' ;;\nesac\n",
+		)?;
+		std::fs::set_permissions(
+			&gpg,
+			std::fs::Permissions::from_mode(0o755),
+		)?;
+
+		repo.config()?
+			.set_str("gpg.program", gpg.to_str().unwrap())?;
+
+		File::create(&root.join(file_path))?.write_all(b"test")?;
+		stage_add_file(repo_path, file_path)?;
+		let new_id = commit(repo_path, "commit msg")?;
+
+		assert!(!tag_gpgsign_enabled(repo_path)?);
+
+		tag_commit_signed(repo_path, &new_id, "tag", "tag-message")?;
+
+		assert_eq!(
+			get_tags(repo_path).unwrap()[&new_id]
+				.iter()
+				.map(|t| t.annotation.as_deref())
+				.collect::<Vec<_>>(),
+			vec![Some("tag-message\n")]
+		);
+
+		assert_eq!(
+			verify_tag(repo_path, "tag")?,
+			Some(Verification::Good(String::from("tester <t@t>")))
+		);
+
+		assert!(tag_commit_signed(
+			repo_path, &new_id, "tag", "again"
+		)
+		.is_err());
+
+		Ok(())
+	}
+
 	#[test]
 	fn test_empty_email() -> Result<()> {
 		let file_path = Path::new("foo");
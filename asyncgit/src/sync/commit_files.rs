@@ -6,9 +6,12 @@ use super::{
 use crate::{
 	error::Result, sync::repository::repo, StatusItem, StatusItemType,
 };
-use git2::{Diff, Repository};
+use git2::{Diff, Patch, Repository};
 use scopetime::scope_time;
-use std::cmp::Ordering;
+use std::{cmp::Ordering, collections::HashMap};
+
+/// added and deleted lines per path of a commit's files
+pub type FileDiffStats = HashMap<String, (usize, usize)>;
 
 /// get all files that are part of a commit
 pub fn get_commit_files(
@@ -45,6 +48,44 @@ pub fn get_commit_files(
 	Ok(res)
 }
 
+/// number of added and deleted lines of every file that is part of
+/// a commit (or of the changes between two commits)
+pub fn get_commit_files_stats(
+	repo_path: &RepoPath,
+	id: CommitId,
+	other: Option<CommitId>,
+) -> Result<FileDiffStats> {
+	scope_time!("get_commit_files_stats");
+
+	let repo = repo(repo_path)?;
+
+	let diff = if let Some(other) = other {
+		get_compare_commits_diff(&repo, (id, other), None, None)?
+	} else {
+		get_commit_diff(repo_path, &repo, id, None, None)?
+	};
+
+	let mut stats = HashMap::with_capacity(diff.deltas().len());
+
+	for idx in 0..diff.deltas().len() {
+		if let Some(patch) = Patch::from_diff(&diff, idx)? {
+			let path = patch
+				.delta()
+				.new_file()
+				.path()
+				.and_then(|p| p.to_str())
+				.map(String::from);
+
+			if let Some(path) = path {
+				let (_, added, deleted) = patch.line_stats()?;
+				stats.insert(path, (added, deleted));
+			}
+		}
+	}
+
+	Ok(stats)
+}
+
 /// get diff of two arbitrary commits
 #[allow(clippy::needless_pass_by_value)]
 pub fn get_compare_commits_diff(
@@ -147,12 +188,12 @@ pub fn get_commit_diff<'a>(
 
 #[cfg(test)]
 mod tests {
-	use super::get_commit_files;
+	use super::{get_commit_files, get_commit_files_stats};
 	use crate::{
 		error::Result,
 		sync::{
 			commit, stage_add_file, stash_save,
-			tests::{get_statuses, repo_init},
+			tests::{get_statuses, repo_init, write_commit_file},
 			RepoPath,
 		},
 		StatusItemType,
@@ -232,4 +273,25 @@ mod tests {
 
 		Ok(())
 	}
+
+	#[test]
+	fn test_files_stats() -> Result<()> {
+		let (_td, repo) = repo_init()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let c1 = write_commit_file(&repo, "a.txt", "a\nb\n", "c1");
+		let c2 = write_commit_file(&repo, "a.txt", "a\nc\nd\n", "c2");
+
+		let stats = get_commit_files_stats(repo_path, c2, None)?;
+
+		assert_eq!(stats.get("a.txt"), Some(&(2, 1)));
+
+		let stats = get_commit_files_stats(repo_path, c1, None)?;
+
+		assert_eq!(stats.get("a.txt"), Some(&(2, 0)));
+
+		Ok(())
+	}
 }
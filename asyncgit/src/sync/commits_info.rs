@@ -1,5 +1,8 @@
 use super::RepoPath;
-use crate::{error::Result, sync::repository::repo};
+use crate::{
+	error::Result,
+	sync::{repository::repo, LogWalker},
+};
 use git2::{Commit, Error, Oid};
 use scopetime::scope_time;
 use unicode_truncate::UnicodeTruncateStr;
@@ -52,7 +55,7 @@ impl From<Oid> for CommitId {
 }
 
 ///
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CommitInfo {
 	///
 	pub message: String,
@@ -99,6 +102,24 @@ pub fn get_commits_info(
 	Ok(res)
 }
 
+/// infos of the last `max_count` commits reachable from `HEAD`
+pub fn get_recent_commits_info(
+	repo_path: &RepoPath,
+	max_count: usize,
+	message_length_limit: usize,
+) -> Result<Vec<CommitInfo>> {
+	scope_time!("get_recent_commits_info");
+
+	let ids = {
+		let repo = repo(repo_path)?;
+		let mut ids = Vec::new();
+		LogWalker::new(&repo, max_count)?.read(&mut ids)?;
+		ids
+	};
+
+	get_commits_info(repo_path, &ids, message_length_limit)
+}
+
 ///
 pub fn get_commit_info(
 	repo_path: &RepoPath,
@@ -139,7 +160,7 @@ pub fn get_message(
 
 #[cfg(test)]
 mod tests {
-	use super::get_commits_info;
+	use super::{get_commits_info, get_recent_commits_info};
 	use crate::{
 		error::Result,
 		sync::{
@@ -175,6 +196,30 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_recent_commits() -> Result<()> {
+		let file_path = Path::new("foo");
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		for msg in ["commit1", "commit2", "commit3"] {
+			File::create(root.join(file_path))?
+				.write_all(msg.as_bytes())?;
+			stage_add_file(repo_path, file_path).unwrap();
+			commit(repo_path, msg).unwrap();
+		}
+
+		let res = get_recent_commits_info(repo_path, 2, 50).unwrap();
+
+		assert_eq!(res.len(), 2);
+		assert_eq!(res[0].message.as_str(), "commit3");
+		assert_eq!(res[1].message.as_str(), "commit2");
+
+		Ok(())
+	}
+
 	#[test]
 	fn test_log_first_msg_line() -> Result<()> {
 		let file_path = Path::new("foo");
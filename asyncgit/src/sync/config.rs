@@ -1,5 +1,5 @@
 use crate::error::Result;
-use git2::Repository;
+use git2::{ConfigLevel, Repository};
 use scopetime::scope_time;
 
 use super::{repository::repo, RepoPath};
@@ -97,6 +97,79 @@ pub fn get_config_string_repo(
 	}
 }
 
+/// bool in the config, taking what git takes for a bool (`yes`,
+/// `on`, `1`..), `None` if unset or not a bool
+pub fn get_config_bool(
+	repo_path: &RepoPath,
+	key: &str,
+) -> Result<Option<bool>> {
+	scope_time!("get_config_bool");
+
+	let repo = repo(repo_path)?;
+	let cfg = repo.config()?;
+
+	Ok(cfg.get_bool(key).ok())
+}
+
+/// all values of a multi-valued config key (like
+/// `versionsort.suffix`) in the order they are defined
+pub fn get_config_strings(
+	repo_path: &RepoPath,
+	key: &str,
+) -> Result<Vec<String>> {
+	let repo = repo(repo_path)?;
+	get_config_strings_repo(&repo, key)
+}
+
+pub fn get_config_strings_repo(
+	repo: &Repository,
+	key: &str,
+) -> Result<Vec<String>> {
+	scope_time!("get_config_strings_repo");
+
+	let cfg = repo.config()?;
+
+	let mut values = Vec::new();
+	let mut entries = cfg.multivar(key, None)?;
+	while let Some(entry) = entries.next() {
+		if let Some(value) = entry?.value() {
+			values.push(value.to_string());
+		}
+	}
+
+	Ok(values)
+}
+
+/// set bool in the repository local config (`.git/config`)
+pub fn set_config_bool(
+	repo_path: &RepoPath,
+	key: &str,
+	value: bool,
+) -> Result<()> {
+	scope_time!("set_config_bool");
+
+	let repo = repo(repo_path)?;
+	let mut cfg = repo.config()?.open_level(ConfigLevel::Local)?;
+	cfg.set_bool(key, value)?;
+
+	Ok(())
+}
+
+/// set string in the repository local config (`.git/config`)
+pub fn set_config_string(
+	repo_path: &RepoPath,
+	key: &str,
+	value: &str,
+) -> Result<()> {
+	scope_time!("set_config_string");
+
+	let repo = repo(repo_path)?;
+	let mut cfg = repo.config()?.open_level(ConfigLevel::Local)?;
+	cfg.set_str(key, value)?;
+
+	Ok(())
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -122,4 +195,34 @@ mod tests {
 		assert!(good_cfg.is_ok());
 		assert!(good_cfg.unwrap().is_some());
 	}
+
+	#[test]
+	fn test_set_config_bool() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		set_config_bool(repo_path, "gitui.test", true).unwrap();
+
+		assert_eq!(
+			get_config_string(repo_path, "gitui.test").unwrap(),
+			Some(String::from("true"))
+		);
+	}
+
+	#[test]
+	fn test_set_config_string() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		set_config_string(repo_path, "gitui.test", "foo").unwrap();
+
+		assert_eq!(
+			get_config_string(repo_path, "gitui.test").unwrap(),
+			Some(String::from("foo"))
+		);
+	}
 }
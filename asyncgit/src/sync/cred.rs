@@ -5,6 +5,10 @@ use super::{
 };
 use crate::error::{Error, Result};
 use git2::CredentialHelper;
+use std::{
+	io::Write,
+	process::{Command, Stdio},
+};
 
 /// basic Authentication Credentials
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
@@ -53,24 +57,135 @@ pub fn extract_username_password(
 		.url()
 		.ok_or(Error::UnknownRemote)?
 		.to_owned();
-	let mut helper = CredentialHelper::new(&url);
 
-	//TODO: look at Cred::credential_helper,
-	//if the username is in the url we need to set it here,
-	//I dont think `config` will pick it up
+	Ok(fill_credential(repo_path, &url)
+		.unwrap_or_else(|| extract_cred_from_url(&url)))
+}
+
+/// asks the configured `credential.helper`s through `git credential fill`
+/// like git does, with terminal prompts off since we own the terminal
+fn fill_credential(
+	repo_path: &RepoPath,
+	url: &str,
+) -> Option<BasicAuthCredential> {
+	let child = Command::new("git")
+		.args(["credential", "fill"])
+		.current_dir(repo_path.gitpath())
+		.env("GIT_TERMINAL_PROMPT", "0")
+		.stdin(Stdio::piped())
+		.stdout(Stdio::piped())
+		.stderr(Stdio::null())
+		.spawn();
+
+	let mut child = match child {
+		Ok(child) => child,
+		Err(e) => {
+			log::warn!("git credential fill failed: {}", e);
+			return execute_credential_helper(repo_path, url);
+		}
+	};
+
+	write!(child.stdin.take()?, "url={}\n\n", url).ok()?;
+
+	let output = child.wait_with_output().ok()?;
+	if !output.status.success() {
+		return None;
+	}
+
+	let output = String::from_utf8_lossy(&output.stdout);
+	let value = |key: &str| {
+		output.lines().find_map(|line| {
+			line.strip_prefix(key)
+				.and_then(|line| line.strip_prefix('='))
+				.map(String::from)
+		})
+	};
+
+	Some(BasicAuthCredential::new(
+		value("username"),
+		value("password"),
+	))
+}
+
+/// without a `git` executable we fall back to libgit2 running the
+/// configured `credential.helper`s itself
+fn execute_credential_helper(
+	repo_path: &RepoPath,
+	url: &str,
+) -> Option<BasicAuthCredential> {
+	let repo = repo(repo_path).ok()?;
+	let mut helper = CredentialHelper::new(url);
 
 	if let Ok(config) = repo.config() {
 		helper.config(&config);
 	}
 
-	Ok(match helper.execute() {
-		Some((username, password)) => {
-			BasicAuthCredential::new(Some(username), Some(password))
-		}
-		None => extract_cred_from_url(&url),
+	helper.execute().map(|(username, password)| {
+		BasicAuthCredential::new(Some(username), Some(password))
 	})
 }
 
+/// hands credentials that worked for `url` to the configured
+/// `credential.helper`s (manager-core, libsecret, osxkeychain..) to keep
+pub fn approve_credential(
+	repo_path: &RepoPath,
+	url: &str,
+	credential: &BasicAuthCredential,
+) -> Result<()> {
+	run_credential_helpers(repo_path, "approve", url, credential)
+}
+
+/// makes the configured `credential.helper`s forget credentials
+/// the remote at `url` turned down
+pub fn reject_credential(
+	repo_path: &RepoPath,
+	url: &str,
+	credential: &BasicAuthCredential,
+) -> Result<()> {
+	run_credential_helpers(repo_path, "reject", url, credential)
+}
+
+/// `git credential` knows how to talk to every kind of helper
+fn run_credential_helpers(
+	repo_path: &RepoPath,
+	action: &str,
+	url: &str,
+	credential: &BasicAuthCredential,
+) -> Result<()> {
+	let (username, password) =
+		match (&credential.username, &credential.password) {
+			(Some(username), Some(password)) => (username, password),
+			_ => return Ok(()),
+		};
+
+	let mut child = Command::new("git")
+		.args(["credential", action])
+		.current_dir(repo_path.gitpath())
+		.stdin(Stdio::piped())
+		.stdout(Stdio::null())
+		.stderr(Stdio::piped())
+		.spawn()?;
+
+	if let Some(mut stdin) = child.stdin.take() {
+		write!(
+			stdin,
+			"url={}\nusername={}\npassword={}\n\n",
+			url, username, password
+		)?;
+	}
+
+	let output = child.wait_with_output()?;
+	if !output.status.success() {
+		return Err(Error::Generic(format!(
+			"git credential {} failed: {}",
+			action,
+			String::from_utf8_lossy(&output.stderr).trim()
+		)));
+	}
+
+	Ok(())
+}
+
 /// extract credentials from url
 pub fn extract_cred_from_url(url: &str) -> BasicAuthCredential {
 	if let Ok(url) = url::Url::parse(url) {
@@ -91,8 +206,9 @@ pub fn extract_cred_from_url(url: &str) -> BasicAuthCredential {
 mod tests {
 	use crate::sync::{
 		cred::{
-			extract_cred_from_url, extract_username_password,
-			need_username_password, BasicAuthCredential,
+			approve_credential, extract_cred_from_url,
+			extract_username_password, need_username_password,
+			reject_credential, BasicAuthCredential,
 		},
 		remotes::DEFAULT_REMOTE_NAME,
 		tests::repo_init,
@@ -275,4 +391,43 @@ mod tests {
 
 		extract_username_password(repo_path).unwrap();
 	}
+
+	#[test]
+	#[serial]
+	fn test_credential_helper_store_and_erase() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let url = "https://github.com/user/repo";
+		repo.remote(DEFAULT_REMOTE_NAME, url).unwrap();
+		repo.config()
+			.unwrap()
+			.set_str(
+				"credential.helper",
+				&format!(
+					"store --file={}",
+					root.join("creds").to_str().unwrap()
+				),
+			)
+			.unwrap();
+
+		let credential = BasicAuthCredential::new(
+			Some("user".to_owned()),
+			Some("pass".to_owned()),
+		);
+
+		approve_credential(repo_path, url, &credential).unwrap();
+		assert_eq!(
+			extract_username_password(repo_path).unwrap(),
+			credential
+		);
+
+		reject_credential(repo_path, url, &credential).unwrap();
+		assert_eq!(
+			extract_username_password(repo_path).unwrap(),
+			BasicAuthCredential::new(None, None)
+		);
+	}
 }
@@ -247,6 +247,81 @@ pub fn get_diff_commits(
 	raw_diff_to_file_diff(&diff, work_dir)
 }
 
+/// returns diff of a specific file between `HEAD` and the workdir
+/// (staged and unstaged changes combined) like a stash captures it,
+/// untracked files are only included if `untracked` is set
+pub fn get_diff_head_to_workdir(
+	repo_path: &RepoPath,
+	p: &str,
+	untracked: bool,
+	options: Option<DiffOptions>,
+) -> Result<FileDiff> {
+	scope_time!("get_diff_head_to_workdir");
+
+	let repo = repo(repo_path)?;
+	let work_dir = work_dir(&repo)?;
+
+	let mut opt = git2::DiffOptions::new();
+	if let Some(options) = options {
+		opt.context_lines(options.context);
+		opt.ignore_whitespace(options.ignore_whitespace);
+		opt.interhunk_lines(options.interhunk_lines);
+	}
+	opt.pathspec(p);
+	opt.include_untracked(untracked);
+	opt.recurse_untracked_dirs(untracked);
+
+	let tree = match get_head_repo(&repo) {
+		Ok(id) => Some(repo.find_commit(id.into())?.tree()?),
+		Err(_) => None,
+	};
+
+	let diff = repo.diff_tree_to_workdir_with_index(
+		tree.as_ref(),
+		Some(&mut opt),
+	)?;
+
+	raw_diff_to_file_diff(&diff, work_dir)
+}
+
+/// returns diff of a specific file between the workdir (or the stage)
+/// and its version in commit `id`, i.e. what restoring it would change
+pub fn get_diff_restore(
+	repo_path: &RepoPath,
+	id: CommitId,
+	p: &str,
+	stage: bool,
+	options: Option<DiffOptions>,
+) -> Result<FileDiff> {
+	scope_time!("get_diff_restore");
+
+	let repo = repo(repo_path)?;
+	let work_dir = work_dir(&repo)?;
+
+	let mut opt = git2::DiffOptions::new();
+	if let Some(options) = options {
+		opt.context_lines(options.context);
+		opt.ignore_whitespace(options.ignore_whitespace);
+		opt.interhunk_lines(options.interhunk_lines);
+	}
+	opt.pathspec(p);
+	opt.reverse(true);
+
+	let tree = repo.find_commit(id.into())?.tree()?;
+
+	let diff = if stage {
+		repo.diff_tree_to_index(
+			Some(&tree),
+			Some(&repo.index()?),
+			Some(&mut opt),
+		)?
+	} else {
+		repo.diff_tree_to_workdir(Some(&tree), Some(&mut opt))?
+	};
+
+	raw_diff_to_file_diff(&diff, work_dir)
+}
+
 ///
 //TODO: refactor into helper type with the inline closures as dedicated functions
 #[allow(clippy::too_many_lines)]
@@ -411,7 +486,9 @@ fn new_file_content(path: &Path) -> Option<Vec<u8>> {
 
 #[cfg(test)]
 mod tests {
-	use super::{get_diff, get_diff_commit};
+	use super::{
+		get_diff, get_diff_commit, get_diff_head_to_workdir,
+	};
 	use crate::{
 		error::Result,
 		sync::{
@@ -665,4 +742,48 @@ mod tests {
 
 		Ok(())
 	}
+
+	#[test]
+	fn test_diff_head_to_workdir() -> Result<()> {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		File::create(root.join("foo.txt"))?.write_all(b"a\n")?;
+		stage_add_file(repo_path, Path::new("foo.txt")).unwrap();
+		commit(repo_path, "").unwrap();
+
+		File::create(root.join("foo.txt"))?.write_all(b"a\nb\n")?;
+		stage_add_file(repo_path, Path::new("foo.txt")).unwrap();
+		File::create(root.join("foo.txt"))?
+			.write_all(b"a\nb\nc\n")?;
+		File::create(root.join("new.txt"))?.write_all(b"new\n")?;
+
+		let diff = get_diff_head_to_workdir(
+			repo_path, "foo.txt", false, None,
+		)
+		.unwrap();
+
+		assert_eq!(diff.hunks.len(), 1);
+		assert_eq!(&*diff.hunks[0].lines[2].content, "b");
+		assert_eq!(&*diff.hunks[0].lines[3].content, "c");
+
+		let diff = get_diff_head_to_workdir(
+			repo_path, "new.txt", false, None,
+		)
+		.unwrap();
+
+		assert!(diff.hunks.is_empty());
+
+		let diff = get_diff_head_to_workdir(
+			repo_path, "new.txt", true, None,
+		)
+		.unwrap();
+
+		assert_eq!(diff.hunks.len(), 1);
+		assert_eq!(&*diff.hunks[0].lines[1].content, "new");
+
+		Ok(())
+	}
 }
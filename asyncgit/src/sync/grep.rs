@@ -0,0 +1,183 @@
+use super::{utils::work_dir, CommitId, RepoPath};
+use crate::{error::Result, sync::repository::repo};
+use git2::{ObjectType, TreeWalkMode, TreeWalkResult};
+use scopetime::scope_time;
+use std::fs;
+
+/// a line matching a `grep` search
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GrepMatch {
+	/// path of the file relative to the repository root
+	pub path: String,
+	/// zero based line number
+	pub line: usize,
+	/// content of the matching line
+	pub text: String,
+}
+
+/// searches the tracked files for lines containing `pattern`, like
+/// `git grep -F`. the search ignores case unless `pattern` contains
+/// uppercase characters. searches the work tree if `commit` is `None`
+/// and stops after `max_matches` hits.
+pub fn grep(
+	repo_path: &RepoPath,
+	pattern: &str,
+	commit: Option<CommitId>,
+	max_matches: usize,
+) -> Result<Vec<GrepMatch>> {
+	scope_time!("grep");
+
+	let repo = repo(repo_path)?;
+
+	let ignore_case = !pattern.chars().any(char::is_uppercase);
+	let pattern = if ignore_case {
+		pattern.to_lowercase()
+	} else {
+		pattern.to_string()
+	};
+
+	let mut matches = Vec::new();
+
+	// returns `false` once `max_matches` are found
+	let mut search = |path: &str, content: &[u8]| {
+		if content.contains(&0) {
+			return true;
+		}
+
+		let content = String::from_utf8_lossy(content);
+		for (line, text) in content.lines().enumerate() {
+			if matches.len() >= max_matches {
+				return false;
+			}
+
+			let hit = if ignore_case {
+				text.to_lowercase().contains(&pattern)
+			} else {
+				text.contains(&pattern)
+			};
+
+			if hit {
+				matches.push(GrepMatch {
+					path: path.to_string(),
+					line,
+					text: text.trim_end().to_string(),
+				});
+			}
+		}
+
+		matches.len() < max_matches
+	};
+
+	if let Some(commit) = commit {
+		let tree = repo.find_commit(commit.into())?.tree()?;
+
+		let mut error = None;
+		let walk =
+			tree.walk(TreeWalkMode::PreOrder, |root, entry| {
+				let path = match (entry.kind(), entry.name()) {
+					(Some(ObjectType::Blob), Some(name)) => {
+						format!("{}{}", root, name)
+					}
+					_ => return TreeWalkResult::Ok,
+				};
+
+				match repo.find_blob(entry.id()) {
+					Ok(blob) if search(&path, blob.content()) => {
+						TreeWalkResult::Ok
+					}
+					Ok(_) => TreeWalkResult::Abort,
+					Err(e) => {
+						error = Some(e);
+						TreeWalkResult::Abort
+					}
+				}
+			});
+
+		if let Some(e) = error {
+			return Err(e.into());
+		}
+
+		// stopping early reports as a user error
+		if let Err(e) = walk {
+			if e.code() != git2::ErrorCode::User {
+				return Err(e.into());
+			}
+		}
+	} else {
+		let work_dir = work_dir(&repo)?;
+		let index = repo.index()?;
+
+		for entry in index.iter() {
+			let path = String::from_utf8_lossy(&entry.path);
+			// unreadable files like submodules are skipped
+			if let Ok(content) = fs::read(work_dir.join(&*path)) {
+				if !search(&path, &content) {
+					break;
+				}
+			}
+		}
+	}
+
+	Ok(matches)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::{
+		tests::{repo_init, write_commit_file},
+		utils::repo_write_file,
+	};
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn test_grep() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let c1 = write_commit_file(
+			&repo,
+			"a.txt",
+			"first line\nFoo bar\nfoo",
+			"c1",
+		);
+		repo_write_file(&repo, "a.txt", "foo").unwrap();
+
+		let lines = |matches: Vec<GrepMatch>| {
+			matches.into_iter().map(|m| m.line).collect::<Vec<_>>()
+		};
+
+		assert_eq!(
+			lines(grep(repo_path, "foo", Some(c1), 10).unwrap()),
+			vec![1, 2]
+		);
+		assert_eq!(
+			lines(grep(repo_path, "Foo", Some(c1), 10).unwrap()),
+			vec![1]
+		);
+		assert_eq!(
+			lines(grep(repo_path, "foo", Some(c1), 1).unwrap()),
+			vec![1]
+		);
+		assert_eq!(
+			grep(repo_path, "foo", None, 10).unwrap(),
+			vec![GrepMatch {
+				path: String::from("a.txt"),
+				line: 0,
+				text: String::from("foo"),
+			}]
+		);
+
+		let c2 = write_commit_file(&repo, "b.txt", "foo", "c2");
+		assert_eq!(
+			grep(repo_path, "foo", Some(c2), 1).unwrap(),
+			vec![GrepMatch {
+				path: String::from("a.txt"),
+				line: 1,
+				text: String::from("Foo bar"),
+			}]
+		);
+	}
+}
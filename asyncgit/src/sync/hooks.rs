@@ -1,4 +1,4 @@
-use super::{repository::repo, RepoPath};
+use super::{repository::repo, CommitId, RepoPath};
 use crate::error::{self, Result};
 use scopetime::scope_time;
 use std::{
@@ -12,6 +12,7 @@ use std::{
 const HOOK_POST_COMMIT: &str = "post-commit";
 const HOOK_PRE_COMMIT: &str = "pre-commit";
 const HOOK_COMMIT_MSG: &str = "commit-msg";
+const HOOK_PREPARE_COMMIT_MSG: &str = "prepare-commit-msg";
 const HOOK_COMMIT_MSG_TEMP_FILE: &str = "COMMIT_EDITMSG";
 
 struct HookPaths {
@@ -126,6 +127,81 @@ pub fn hooks_commit_msg(
 	}
 }
 
+/// where the message passed to `prepare-commit-msg` comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrepareCommitMsgSource {
+	/// message given up front (`-m`)
+	Message,
+	/// `commit.template`
+	Template,
+	/// merge in progress (`MERGE_MSG`)
+	Merge,
+	/// squash in progress (`SQUASH_MSG`)
+	Squash,
+	/// message of an existing commit (amend or `-c`)
+	Commit(CommitId),
+}
+
+impl PrepareCommitMsgSource {
+	const fn as_arg(self) -> &'static str {
+		match self {
+			Self::Message => "message",
+			Self::Template => "template",
+			Self::Merge => "merge",
+			Self::Squash => "squash",
+			Self::Commit(_) => "commit",
+		}
+	}
+}
+
+/// this hook is documented here <https://git-scm.com/docs/githooks#_prepare_commit_msg>
+///
+/// it gets the same temp file as `commit-msg` and `source` (if any) as arguments,
+/// `msg` gets updated with whatever the hook leaves in the file.
+pub fn hooks_prepare_commit_msg(
+	repo_path: &RepoPath,
+	source: Option<PrepareCommitMsgSource>,
+	msg: &mut String,
+) -> Result<HookResult> {
+	scope_time!("hooks_prepare_commit_msg");
+
+	let hooks_path =
+		HookPaths::new(repo_path, HOOK_PREPARE_COMMIT_MSG)?;
+
+	if hooks_path.is_executable() {
+		let temp_file =
+			hooks_path.git.join(HOOK_COMMIT_MSG_TEMP_FILE);
+		File::create(&temp_file)?.write_all(msg.as_bytes())?;
+
+		let temp_file_arg =
+			temp_file.as_os_str().to_string_lossy().to_string();
+		let commit_arg = match source {
+			Some(PrepareCommitMsgSource::Commit(id)) => {
+				id.to_string()
+			}
+			_ => String::new(),
+		};
+
+		let mut args = vec![temp_file_arg.as_str()];
+		if let Some(source) = source {
+			args.push(source.as_arg());
+		}
+		if !commit_arg.is_empty() {
+			args.push(&commit_arg);
+		}
+
+		let res = hooks_path.run_hook(&args)?;
+
+		// load possibly altered msg
+		msg.clear();
+		File::open(temp_file)?.read_to_string(msg)?;
+
+		Ok(res)
+	} else {
+		Ok(HookResult::Ok)
+	}
+}
+
 /// this hook is documented here <https://git-scm.com/docs/githooks#_pre_commit>
 ///
 pub fn hooks_pre_commit(repo_path: &RepoPath) -> Result<HookResult> {
@@ -257,6 +333,72 @@ exit 0
 		assert_eq!(msg, String::from("test"));
 	}
 
+	#[test]
+	fn test_hooks_prepare_commit_msg() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let hook = b"#!/bin/sh
+echo \"$2 $3\" >> $1
+exit 0
+        ";
+
+		create_hook(repo_path, HOOK_PREPARE_COMMIT_MSG, hook);
+
+		let mut msg = String::from("test\n");
+		let res = hooks_prepare_commit_msg(
+			repo_path,
+			Some(PrepareCommitMsgSource::Template),
+			&mut msg,
+		)
+		.unwrap();
+
+		assert_eq!(res, HookResult::Ok);
+		assert_eq!(msg, String::from("test\ntemplate \n"));
+
+		let id = CommitId::new(
+			git2::Oid::from_str(
+				"0000000000000000000000000000000000000001",
+			)
+			.unwrap(),
+		);
+		let mut msg = String::new();
+		hooks_prepare_commit_msg(
+			repo_path,
+			Some(PrepareCommitMsgSource::Commit(id)),
+			&mut msg,
+		)
+		.unwrap();
+
+		assert_eq!(msg, format!("commit {}\n", id.to_string()));
+	}
+
+	#[test]
+	fn test_hooks_prepare_commit_msg_reject() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let hook = b"#!/bin/sh
+echo 'rejected'
+exit 1
+        ";
+
+		create_hook(repo_path, HOOK_PREPARE_COMMIT_MSG, hook);
+
+		let mut msg = String::from("test");
+		let res = hooks_prepare_commit_msg(repo_path, None, &mut msg)
+			.unwrap();
+
+		assert_eq!(
+			res,
+			HookResult::NotOk(String::from("rejected\n"))
+		);
+	}
+
 	#[test]
 	fn test_pre_commit_sh() {
 		let (_td, repo) = repo_init().unwrap();
@@ -1,11 +1,14 @@
 use super::CommitId;
 use crate::sync::RepoPath;
 use crate::{error::Result, sync::commit_files::get_commit_diff};
-use git2::{Commit, Oid, Repository};
+use git2::{
+	Commit, Delta, DiffFindOptions, DiffOptions, Oid, Repository,
+	Tree,
+};
 use std::{
 	cmp::Ordering,
-	collections::{BinaryHeap, HashSet},
-	sync::Arc,
+	collections::{BinaryHeap, HashMap, HashSet},
+	sync::{Arc, Mutex},
 };
 
 struct TimeOrderedCommit<'a>(Commit<'a>);
@@ -59,6 +62,130 @@ pub fn diff_contains_file(
 	))
 }
 
+/// path of a followed file in a commit that changed it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FollowedPath {
+	///
+	pub path: String,
+	/// set if the commit renamed the file to `path`
+	pub renamed_from: Option<String>,
+}
+
+/// paths of a followed file by the commits that changed it,
+/// filled while walking with `diff_contains_file_follow`
+pub type FollowedPaths = Arc<Mutex<HashMap<CommitId, FollowedPath>>>;
+
+/// like `diff_contains_file` but follows the file across renames,
+/// just like `git log --follow`
+pub fn diff_contains_file_follow(
+	file_path: String,
+) -> (LogWalkerFilter, FollowedPaths) {
+	let paths = FollowedPaths::default();
+	let paths_filter = Arc::clone(&paths);
+	let current_path = Mutex::new(file_path.clone());
+
+	let filter: LogWalkerFilter = Arc::new(Box::new(
+		move |repo: &Repository,
+		      commit_id: &CommitId|
+		      -> Result<bool> {
+			// every walk starts at `HEAD`, so a new walk starts
+			// over from the path we were asked for
+			if repo.refname_to_id("HEAD")? == (*commit_id).into() {
+				current_path.lock()?.clone_from(&file_path);
+				paths_filter.lock()?.clear();
+			}
+
+			follow_file(
+				repo,
+				*commit_id,
+				&mut *current_path.lock()?,
+				&paths_filter,
+			)
+		},
+	));
+
+	(filter, paths)
+}
+
+/// checks if `commit_id` changed `current_path` and moves
+/// `current_path` to the old path if it was renamed in there
+fn follow_file(
+	repo: &Repository,
+	commit_id: CommitId,
+	current_path: &mut String,
+	paths: &FollowedPaths,
+) -> Result<bool> {
+	let commit = repo.find_commit(commit_id.into())?;
+	let tree = commit.tree()?;
+	let parent_tree = commit
+		.parents()
+		.next()
+		.map(|parent| parent.tree())
+		.transpose()?;
+
+	let mut opts = DiffOptions::new();
+	opts.pathspec(current_path.as_str())
+		.disable_pathspec_match(true);
+	let diff = repo.diff_tree_to_tree(
+		parent_tree.as_ref(),
+		Some(&tree),
+		Some(&mut opts),
+	)?;
+
+	let added = match diff.deltas().next() {
+		Some(delta) => delta.status() == Delta::Added,
+		None => return Ok(false),
+	};
+
+	let renamed_from = if added {
+		renamed_from(repo, parent_tree.as_ref(), &tree, current_path)?
+	} else {
+		None
+	};
+
+	paths.lock()?.insert(
+		commit_id,
+		FollowedPath {
+			path: current_path.clone(),
+			renamed_from: renamed_from.clone(),
+		},
+	);
+
+	if let Some(renamed_from) = renamed_from {
+		*current_path = renamed_from;
+	}
+
+	Ok(true)
+}
+
+/// the path `path` had in `old` if it got renamed between the trees
+fn renamed_from(
+	repo: &Repository,
+	old: Option<&Tree>,
+	new: &Tree,
+	path: &str,
+) -> Result<Option<String>> {
+	let mut diff = repo.diff_tree_to_tree(old, Some(new), None)?;
+	diff.find_similar(Some(DiffFindOptions::new().renames(true)))?;
+
+	let renamed_from = diff
+		.deltas()
+		.filter(|delta| delta.status() == Delta::Renamed)
+		.find(|delta| {
+			delta.new_file().path().and_then(|p| p.to_str())
+				== Some(path)
+		})
+		.and_then(|delta| {
+			delta
+				.old_file()
+				.path()
+				.and_then(|p| p.to_str())
+				.map(String::from)
+		});
+
+	Ok(renamed_from)
+}
+
 ///
 pub struct LogWalker<'a> {
 	commits: BinaryHeap<TimeOrderedCommit<'a>>,
@@ -136,8 +263,8 @@ mod tests {
 	use crate::error::Result;
 	use crate::sync::RepoPath;
 	use crate::sync::{
-		commit, get_commits_info, stage_add_file,
-		tests::repo_init_empty,
+		commit, get_commits_info, stage_add_all, stage_add_file,
+		stage_addremoved, tests::repo_init_empty,
 	};
 	use pretty_assertions::assert_eq;
 	use std::{fs::File, io::Write, path::Path};
@@ -254,4 +381,49 @@ mod tests {
 
 		Ok(())
 	}
+
+	#[test]
+	fn test_logwalker_follow_renames() -> Result<()> {
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: RepoPath =
+			root.as_os_str().to_str().unwrap().into();
+
+		File::create(&root.join("foo"))?.write_all(b"a\nb\nc\n")?;
+		stage_add_file(&repo_path, Path::new("foo")).unwrap();
+		let first_commit_id = commit(&repo_path, "commit1").unwrap();
+
+		std::fs::rename(root.join("foo"), root.join("bar"))?;
+		stage_add_all(&repo_path, "*", None).unwrap();
+		stage_addremoved(&repo_path, Path::new("foo")).unwrap();
+		let rename_commit_id = commit(&repo_path, "rename").unwrap();
+
+		File::create(&root.join("bar"))?.write_all(b"a\nb\nd\n")?;
+		stage_add_file(&repo_path, Path::new("bar")).unwrap();
+		let last_commit_id = commit(&repo_path, "commit3").unwrap();
+
+		let (filter, paths) = diff_contains_file_follow("bar".into());
+
+		let mut items = Vec::new();
+		let mut walker =
+			LogWalker::new(&repo, 100)?.filter(Some(filter));
+		walker.read(&mut items).unwrap();
+
+		assert_eq!(
+			items,
+			vec![last_commit_id, rename_commit_id, first_commit_id]
+		);
+
+		let paths = paths.lock().unwrap();
+		assert_eq!(
+			paths[&rename_commit_id],
+			FollowedPath {
+				path: "bar".into(),
+				renamed_from: Some("foo".into()),
+			}
+		);
+		assert_eq!(paths[&first_commit_id].path, "foo");
+
+		Ok(())
+	}
 }
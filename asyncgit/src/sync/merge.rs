@@ -4,13 +4,18 @@ use crate::{
 		branch::merge_commit::commit_merge_with_head,
 		rebase::{
 			abort_rebase, continue_rebase, get_rebase_progress,
+			skip_rebase,
 		},
 		repository::repo,
 		reset_stage, reset_workdir, CommitId,
 	},
 };
-use git2::{BranchType, Commit, MergeOptions, Repository};
+use git2::{
+	build::CheckoutBuilder, AnnotatedCommit, BranchType, Commit,
+	MergeOptions, Repository,
+};
 use scopetime::scope_time;
+use std::fs;
 
 use super::{
 	rebase::{RebaseProgress, RebaseState},
@@ -64,6 +69,164 @@ pub fn merge_branch(
 	Ok(())
 }
 
+/// fast-forward behavior of a merge, like the flags of `git merge`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MergeKind {
+	/// fast-forward if possible, merge commit otherwise
+	FastForward,
+	/// always create a merge commit (`--no-ff`)
+	NoFastForward,
+	/// refuse unless fast-forward is possible (`--ff-only`)
+	FastForwardOnly,
+	/// stage the changes for a single regular commit (`--squash`)
+	Squash,
+}
+
+/// result of [`merge_branch_with`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MergeOutcome {
+	/// nothing to merge
+	UpToDate,
+	/// `HEAD` was moved to the merged branch
+	FastForwarded,
+	/// changes are staged and need to be committed
+	NeedsCommit,
+}
+
+/// name of the file holding the message for a squash merge commit
+const SQUASH_MSG_FILE: &str = "SQUASH_MSG";
+
+/// merges `branch` into `HEAD` the way `kind` asks for
+pub fn merge_branch_with(
+	repo_path: &RepoPath,
+	branch: &str,
+	branch_type: BranchType,
+	kind: MergeKind,
+) -> Result<MergeOutcome> {
+	scope_time!("merge_branch_with");
+
+	let repo = repo(repo_path)?;
+
+	let branch = repo.find_branch(branch, branch_type)?;
+	let annotated =
+		repo.reference_to_annotated_commit(branch.get())?;
+
+	let (analysis, _) = repo.merge_analysis(&[&annotated])?;
+
+	//TODO: support merge on unborn
+	if analysis.is_unborn() {
+		return Err(Error::Generic("head is unborn".into()));
+	}
+
+	if analysis.is_up_to_date() {
+		return Ok(MergeOutcome::UpToDate);
+	}
+
+	let fast_forward = analysis.is_fast_forward();
+
+	match kind {
+		MergeKind::FastForward | MergeKind::FastForwardOnly
+			if fast_forward =>
+		{
+			let commit = repo.find_commit(annotated.id())?;
+			repo.checkout_tree(
+				commit.as_object(),
+				Some(CheckoutBuilder::new().safe()),
+			)?;
+			repo.head()?
+				.set_target(annotated.id(), "fast-forward")?;
+
+			Ok(MergeOutcome::FastForwarded)
+		}
+		MergeKind::FastForwardOnly => Err(Error::Generic(
+			"fast forward merge not possible".into(),
+		)),
+		MergeKind::FastForward | MergeKind::NoFastForward => {
+			repo.merge(
+				&[&annotated],
+				Some(&mut MergeOptions::default()),
+				None,
+			)?;
+
+			Ok(MergeOutcome::NeedsCommit)
+		}
+		MergeKind::Squash => {
+			let msg = squash_msg_of(&repo, &annotated)?;
+
+			repo.merge(
+				&[&annotated],
+				Some(&mut MergeOptions::default()),
+				None,
+			)?;
+			repo.cleanup_state()?;
+
+			fs::write(repo.path().join(SQUASH_MSG_FILE), msg)?;
+
+			Ok(MergeOutcome::NeedsCommit)
+		}
+	}
+}
+
+/// lists the commits to be squashed like `git merge --squash` does
+fn squash_msg_of(
+	repo: &Repository,
+	annotated: &AnnotatedCommit,
+) -> Result<String> {
+	let mut walk = repo.revwalk()?;
+	walk.push(annotated.id())?;
+	walk.hide_head()?;
+
+	let mut lines =
+		vec![String::from("Squashed commit of the following:")];
+	for id in walk {
+		let commit = repo.find_commit(id?)?;
+		let author = commit.author();
+
+		lines.push(String::new());
+		lines.push(format!("commit {}", commit.id()));
+		lines.push(format!(
+			"Author: {} <{}>",
+			author.name().unwrap_or_default(),
+			author.email().unwrap_or_default(),
+		));
+		lines.push(String::new());
+		lines.extend(
+			commit.message().unwrap_or_default().lines().map(
+				|line| format!("    {}", line).trim_end().to_string(),
+			),
+		);
+	}
+	lines.push(String::new());
+
+	Ok(lines.join("\n"))
+}
+
+/// whether the index contains conflicts left by a merge
+pub fn has_conflicts(repo_path: &RepoPath) -> Result<bool> {
+	let repo = repo(repo_path)?;
+
+	Ok(repo.index()?.has_conflicts())
+}
+
+/// message prepared by a squash merge that is yet to be committed
+pub fn squash_msg(repo_path: &RepoPath) -> Result<Option<String>> {
+	let repo = repo(repo_path)?;
+
+	Ok(fs::read_to_string(repo.path().join(SQUASH_MSG_FILE)).ok())
+}
+
+/// forgets the message of a squash merge once it was committed
+pub fn clear_squash_msg(repo_path: &RepoPath) -> Result<()> {
+	let repo = repo(repo_path)?;
+	let path = repo.path().join(SQUASH_MSG_FILE);
+
+	if path.exists() {
+		fs::remove_file(path)?;
+	}
+
+	Ok(())
+}
+
 ///
 pub fn rebase_progress(
 	repo_path: &RepoPath,
@@ -86,6 +249,17 @@ pub fn continue_pending_rebase(
 	continue_rebase(&repo)
 }
 
+/// drops the changes of the current rebase step and continues
+pub fn skip_pending_rebase(
+	repo_path: &RepoPath,
+) -> Result<RebaseState> {
+	scope_time!("skip_pending_rebase");
+
+	let repo = repo(repo_path)?;
+
+	skip_rebase(&repo)
+}
+
 ///
 pub fn abort_pending_rebase(repo_path: &RepoPath) -> Result<()> {
 	scope_time!("abort_pending_rebase");
@@ -161,6 +335,87 @@ mod tests {
 	};
 	use pretty_assertions::assert_eq;
 
+	#[test]
+	fn test_merge_kinds() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		write_commit_file(&repo, "test.txt", "test", "commit1");
+		create_branch(repo_path, "foo").unwrap();
+		let c2 =
+			write_commit_file(&repo, "test.txt", "test2", "commit2");
+
+		let checkout_master = || {
+			crate::sync::checkout_branch(
+				repo_path,
+				"refs/heads/master",
+			)
+			.unwrap();
+		};
+
+		checkout_master();
+		assert_eq!(
+			merge_branch_with(
+				repo_path,
+				"foo",
+				BranchType::Local,
+				MergeKind::FastForward
+			)
+			.unwrap(),
+			MergeOutcome::FastForwarded
+		);
+		assert_eq!(crate::sync::get_head(repo_path).unwrap(), c2);
+		assert_eq!(
+			merge_branch_with(
+				repo_path,
+				"foo",
+				BranchType::Local,
+				MergeKind::FastForward
+			)
+			.unwrap(),
+			MergeOutcome::UpToDate
+		);
+
+		// master diverges from foo
+		write_commit_file(&repo, "other.txt", "other", "commit3");
+		crate::sync::checkout_branch(repo_path, "refs/heads/foo")
+			.unwrap();
+		write_commit_file(&repo, "test.txt", "test3", "commit4");
+		checkout_master();
+
+		assert!(merge_branch_with(
+			repo_path,
+			"foo",
+			BranchType::Local,
+			MergeKind::FastForwardOnly
+		)
+		.is_err());
+
+		assert_eq!(
+			merge_branch_with(
+				repo_path,
+				"foo",
+				BranchType::Local,
+				MergeKind::Squash
+			)
+			.unwrap(),
+			MergeOutcome::NeedsCommit
+		);
+		assert_eq!(
+			crate::sync::repo_state(repo_path).unwrap(),
+			crate::sync::RepoState::Clean
+		);
+		let msg = squash_msg(repo_path).unwrap().unwrap();
+		assert!(msg.starts_with("Squashed commit of the following:"));
+		assert!(msg.contains("    commit4"));
+		assert!(!has_conflicts(repo_path).unwrap());
+
+		clear_squash_msg(repo_path).unwrap();
+		assert_eq!(squash_msg(repo_path).unwrap(), None);
+	}
+
 	#[test]
 	fn test_smoke() {
 		let (_td, repo) = repo_init().unwrap();
@@ -3,8 +3,11 @@
 //TODO: remove once we have this activated on the toplevel
 #![deny(clippy::expect_used)]
 
+mod autostash;
+mod bisect;
 pub mod blame;
 pub mod branch;
+mod cherry_pick;
 mod commit;
 mod commit_details;
 pub mod commit_files;
@@ -13,6 +16,7 @@ mod commits_info;
 mod config;
 pub mod cred;
 pub mod diff;
+mod grep;
 mod hooks;
 mod hunks;
 mod ignore;
@@ -23,61 +27,99 @@ mod rebase;
 pub mod remotes;
 mod repository;
 mod reset;
+pub mod sign;
 mod staging;
 mod stash;
 mod state;
 pub mod status;
 mod submodules;
 mod tags;
+mod trailers;
 mod tree;
 pub mod utils;
+mod worktree;
 
-pub use blame::{blame_file, BlameHunk, FileBlame};
+pub use autostash::{
+	autostash, blocked_by_local_changes, has_local_changes,
+	AutostashResult,
+};
+pub use bisect::{abort_bisect, bisect_state, BisectState};
+pub use blame::{blame_file, blame_parent, BlameHunk, FileBlame};
 pub use branch::{
-	branch_compare_upstream, checkout_branch, config_is_pull_rebase,
-	create_branch, delete_branch, get_branch_remote,
-	get_branches_info, merge_commit::merge_upstream_commit,
+	branch_compare_upstream, branches_compare_upstream,
+	checkout_branch, checkout_commit,
+	compare::{compare_branches, BranchesDiff},
+	config_is_pull_rebase, config_pull_mode, create_branch,
+	delete_branch, delete_merged_branches, detached_head,
+	get_branch_remote, get_branches_gone_upstream, get_branches_info,
+	merge_commit::merge_upstream_commit,
 	merge_ff::branch_merge_upstream_fastforward,
-	merge_rebase::merge_upstream_rebase, rename::rename_branch,
-	validate_branch_name, BranchCompare, BranchInfo,
+	merge_rebase::{merge_upstream_rebase, pull_rebase},
+	recent::{get_recent_branches, RecentBranch},
+	rename::rename_branch,
+	set_branch_description, set_branch_upstream, split_remote_branch,
+	validate_branch_name, BranchCompare, BranchInfo, DeletedBranches,
+	PullMode,
+};
+pub use cherry_pick::{cherrypick_head, commit_cherrypick};
+pub use commit::{
+	amend, amend_with_author, commit, commit_gpgsign_enabled,
+	get_commit_author, tag_commit, tag_commit_signed,
+	tag_gpgsign_enabled, CommitAuthor,
 };
-pub use commit::{amend, commit, tag_commit};
 pub use commit_details::{
 	get_commit_details, CommitDetails, CommitMessage, CommitSignature,
 };
-pub use commit_files::get_commit_files;
+pub use commit_files::{
+	get_commit_files, get_commit_files_stats, FileDiffStats,
+};
 pub use commit_revert::{commit_revert, revert_commit, revert_head};
 pub use commits_info::{
-	get_commit_info, get_commits_info, CommitId, CommitInfo,
+	get_commit_info, get_commits_info, get_recent_commits_info,
+	CommitId, CommitInfo,
 };
 pub use config::{
-	get_config_string, untracked_files_config,
+	get_config_bool, get_config_string, get_config_strings,
+	set_config_bool, set_config_string, untracked_files_config,
 	ShowUntrackedFilesConfig,
 };
 pub use diff::get_diff_commit;
 pub use git2::BranchType;
+pub use grep::{grep, GrepMatch};
 pub use hooks::{
-	hooks_commit_msg, hooks_post_commit, hooks_pre_commit, HookResult,
+	hooks_commit_msg, hooks_post_commit, hooks_pre_commit,
+	hooks_prepare_commit_msg, HookResult, PrepareCommitMsgSource,
 };
 pub use hunks::{reset_hunk, stage_hunk, unstage_hunk};
 pub use ignore::add_to_ignore;
-pub use logwalker::{diff_contains_file, LogWalker, LogWalkerFilter};
+pub use logwalker::{
+	diff_contains_file, diff_contains_file_follow, FollowedPath,
+	FollowedPaths, LogWalker, LogWalkerFilter,
+};
 pub use merge::{
-	abort_pending_rebase, abort_pending_state,
-	continue_pending_rebase, merge_branch, merge_commit, merge_msg,
-	mergehead_ids, rebase_progress,
+	abort_pending_rebase, abort_pending_state, clear_squash_msg,
+	continue_pending_rebase, has_conflicts, merge_branch,
+	merge_branch_with, merge_commit, merge_msg, mergehead_ids,
+	rebase_progress, skip_pending_rebase, squash_msg, MergeKind,
+	MergeOutcome,
 };
 pub use rebase::rebase_branch;
 pub use remotes::{
-	get_default_remote, get_remotes, push::AsyncProgress,
-	tags::PushTagsProgress,
+	add_remote, deepen, get_default_remote, get_remotes,
+	get_remotes_info, push::AsyncProgress, push_destination,
+	remove_remote, rename_remote, set_remote_push_url,
+	set_remote_url, shallow_commits, tags::PushTagsProgress,
+	FetchAllProgress, PushDestination, RemoteFetchProgress,
+	RemoteInfo, Retry,
 };
 pub(crate) use repository::repo;
 pub use repository::{RepoPath, RepoPathRef};
-pub use reset::{reset_stage, reset_workdir};
+pub use reset::{reset_stage, reset_workdir, restore_file};
 pub use staging::{discard_lines, stage_lines};
 pub use stash::{
-	get_stashes, stash_apply, stash_drop, stash_pop, stash_save,
+	get_stash_untracked_files, get_stashes, is_stash_commit,
+	stash_apply, stash_branch, stash_drop, stash_origin_branch,
+	stash_pop, stash_save, stash_save_paths, stash_save_untracked,
 };
 pub use state::{repo_state, RepoState};
 pub use status::is_workdir_clean;
@@ -85,17 +127,29 @@ pub use submodules::{
 	get_submodules, update_submodule, SubmoduleInfo, SubmoduleStatus,
 };
 pub use tags::{
-	delete_tag, get_tags, get_tags_with_metadata, CommitTags, Tag,
-	TagWithMetadata, Tags,
+	delete_tag, get_tag_details, get_tags, get_tags_with_metadata,
+	tags_matching, verify_tag, version_cmp, CommitTags, Tag,
+	TagDetails, TagKind, TagWithMetadata, Tags,
+};
+pub use trailers::{
+	append_trailer, coauthor_candidates, coauthor_trailer,
+	signoff_trailer,
+};
+pub use tree::{
+	commit_file_bytes, commit_file_content, tree_file_content,
+	tree_files, TreeFile,
 };
-pub use tree::{tree_file_content, tree_files, TreeFile};
 pub use utils::{
-	get_head, get_head_tuple, is_repo, repo_dir, stage_add_all,
-	stage_add_file, stage_addremoved, Head,
+	get_head, get_head_tuple, is_repo, repo_dir, resolve_revision,
+	stage_add_all, stage_add_file, stage_addremoved, Head,
+};
+pub use worktree::{
+	add_worktree, get_worktrees, prune_worktrees, remove_worktree,
+	WorktreeInfo,
 };
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
 	use super::{
 		commit,
 		repository::repo,
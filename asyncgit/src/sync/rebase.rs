@@ -1,5 +1,6 @@
-use git2::{BranchType, Repository};
+use git2::{build::CheckoutBuilder, BranchType, Oid, Repository};
 use scopetime::scope_time;
+use std::{collections::HashMap, fs};
 
 use crate::{
 	error::{Error, Result},
@@ -8,30 +9,125 @@ use crate::{
 
 use super::{CommitId, RepoPath};
 
-/// rebase current HEAD on `branch`
+/// file in the rebase state dir listing the branches to move along
+/// with the rebased commits, one `<old id> <refname>` per line
+const UPDATE_REFS_FILE: &str = "gitui-update-refs";
+
+/// rebase current HEAD on `branch`,
+/// `update_refs` moves other local branches pointing into the
+/// rebased range along (like `git rebase --update-refs`)
 pub fn rebase_branch(
 	repo_path: &RepoPath,
 	branch: &str,
 	branch_type: BranchType,
+	update_refs: bool,
 ) -> Result<RebaseState> {
 	scope_time!("rebase_branch");
 
 	let repo = repo(repo_path)?;
 
-	rebase_branch_repo(&repo, branch, branch_type)
+	rebase_branch_repo(&repo, branch, branch_type, update_refs)
 }
 
 fn rebase_branch_repo(
 	repo: &Repository,
 	branch_name: &str,
 	branch_type: BranchType,
+	update_refs: bool,
 ) -> Result<RebaseState> {
 	let branch = repo.find_branch(branch_name, branch_type)?;
 
 	let annotated =
 		repo.reference_to_annotated_commit(&branch.into_reference())?;
 
-	rebase(repo, &annotated)
+	let stacked = if update_refs {
+		stacked_branches(repo, annotated.id())?
+	} else {
+		Vec::new()
+	};
+
+	let mut rebase =
+		repo.rebase(None, Some(&annotated), None, None)?;
+
+	if !stacked.is_empty() {
+		let content = stacked
+			.iter()
+			.map(|(id, name)| format!("{} {}", id, name))
+			.collect::<Vec<_>>()
+			.join("\n");
+		fs::write(rebase_dir(repo).join(UPDATE_REFS_FILE), content)?;
+	}
+
+	run_rebase(repo, &mut rebase)
+}
+
+/// local branches other than `HEAD` whose tip is in `upstream..HEAD`
+fn stacked_branches(
+	repo: &Repository,
+	upstream: Oid,
+) -> Result<Vec<(Oid, String)>> {
+	let mut walk = repo.revwalk()?;
+	walk.push_head()?;
+	walk.hide(upstream)?;
+	let range = walk.collect::<std::result::Result<Vec<_>, _>>()?;
+
+	let head = repo.head()?;
+	let head_name = head.name().unwrap_or_default();
+
+	let mut res = Vec::new();
+	for branch in repo.branches(Some(BranchType::Local))? {
+		let (branch, _) = branch?;
+		let reference = branch.get();
+
+		if let (Some(name), Some(target)) =
+			(reference.name(), reference.target())
+		{
+			if name != head_name && range.contains(&target) {
+				res.push((target, name.to_string()));
+			}
+		}
+	}
+
+	Ok(res)
+}
+
+fn rebase_dir(repo: &Repository) -> std::path::PathBuf {
+	repo.path().join("rebase-merge")
+}
+
+/// moves the branches recorded when the rebase started to the
+/// commits their tips got rewritten to, branches on skipped
+/// commits stay where they are
+fn update_stacked_refs(repo: &Repository) -> Result<()> {
+	let dir = rebase_dir(repo);
+
+	let stacked = match fs::read_to_string(dir.join(UPDATE_REFS_FILE))
+	{
+		Ok(content) => content,
+		Err(_) => return Ok(()),
+	};
+
+	let rewritten = fs::read_to_string(dir.join("rewritten"))
+		.unwrap_or_default()
+		.lines()
+		.filter_map(|line| {
+			let mut ids = line.split(' ');
+			let old = Oid::from_str(ids.next()?).ok()?;
+			let new = Oid::from_str(ids.next()?).ok()?;
+			Some((old, new))
+		})
+		.collect::<HashMap<_, _>>();
+
+	for line in stacked.lines() {
+		if let Some((old, name)) = line.split_once(' ') {
+			if let Some(new) = rewritten.get(&Oid::from_str(old)?) {
+				repo.find_reference(name)?
+					.set_target(*new, "rebase: update-refs")?;
+			}
+		}
+	}
+
+	Ok(())
 }
 
 /// rebase attempt which aborts and undo's rebase if any conflict appears
@@ -77,12 +173,11 @@ pub enum RebaseState {
 	Conflicted,
 }
 
-/// rebase
-pub fn rebase(
+/// runs the steps of a freshly started rebase
+fn run_rebase(
 	repo: &git2::Repository,
-	commit: &git2::AnnotatedCommit,
+	rebase: &mut git2::Rebase,
 ) -> Result<RebaseState> {
-	let mut rebase = repo.rebase(None, Some(commit), None, None)?;
 	let signature =
 		crate::sync::commit::signature_allow_undefined_name(repo)?;
 
@@ -101,6 +196,7 @@ pub fn rebase(
 		return Ok(RebaseState::Conflicted);
 	}
 
+	update_stacked_refs(repo)?;
 	rebase.finish(Some(&signature))?;
 
 	Ok(RebaseState::Finished)
@@ -138,6 +234,44 @@ pub fn continue_rebase(
 		return Ok(RebaseState::Conflicted);
 	}
 
+	update_stacked_refs(repo)?;
+	rebase.finish(Some(&signature))?;
+
+	Ok(RebaseState::Finished)
+}
+
+/// skip the current step of a pending rebase (like `git rebase --skip`)
+pub fn skip_rebase(repo: &git2::Repository) -> Result<RebaseState> {
+	let mut rebase = repo.open_rebase(None)?;
+	let signature =
+		crate::sync::commit::signature_allow_undefined_name(repo)?;
+
+	// drop whatever the current step left in index and workdir,
+	// a hard reset would also clean up the rebase state
+	let head = repo.head()?.peel_to_tree()?;
+	let mut index = repo.index()?;
+	index.read_tree(&head)?;
+	index.write()?;
+	repo.checkout_index(
+		Some(&mut index),
+		Some(CheckoutBuilder::new().force()),
+	)?;
+
+	while let Some(op) = rebase.next() {
+		let _op = op?;
+
+		if repo.index()?.has_conflicts() {
+			return Ok(RebaseState::Conflicted);
+		}
+
+		rebase.commit(None, &signature, None)?;
+	}
+
+	if repo.index()?.has_conflicts() {
+		return Ok(RebaseState::Conflicted);
+	}
+
+	update_stacked_refs(repo)?;
 	rebase.finish(Some(&signature))?;
 
 	Ok(RebaseState::Finished)
@@ -273,13 +407,62 @@ mod test_conflict_free_rebase {
 
 		checkout_branch(repo_path, "refs/heads/foo").unwrap();
 
-		let res =
-			rebase_branch(repo_path, "master", BranchType::Local);
+		let res = rebase_branch(
+			repo_path,
+			"master",
+			BranchType::Local,
+			false,
+		);
 
 		assert!(matches!(res.unwrap(), RebaseState::Conflicted));
 
 		assert_eq!(repo_state(repo_path).unwrap(), RepoState::Rebase);
 	}
+
+	#[test]
+	fn test_update_refs() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		write_commit_file(&repo, "test1.txt", "test", "commit1");
+
+		create_branch(repo_path, "foo").unwrap();
+		let c2 =
+			write_commit_file(&repo, "test2.txt", "test", "commit2");
+
+		create_branch(repo_path, "bar").unwrap();
+		write_commit_file(&repo, "test3.txt", "test", "commit3");
+
+		checkout_branch(repo_path, "refs/heads/master").unwrap();
+		let c4 =
+			write_commit_file(&repo, "test4.txt", "test", "commit4");
+
+		checkout_branch(repo_path, "refs/heads/bar").unwrap();
+
+		let res = rebase_branch(
+			repo_path,
+			"master",
+			BranchType::Local,
+			true,
+		);
+
+		assert_eq!(res.unwrap(), RebaseState::Finished);
+
+		let tip = |name: &str| -> CommitId {
+			repo.find_branch(name, BranchType::Local)
+				.unwrap()
+				.get()
+				.target()
+				.unwrap()
+				.into()
+		};
+
+		assert_ne!(tip("foo"), c2);
+		assert_eq!(parent_ids(&repo, tip("foo")), vec![c4]);
+		assert_eq!(parent_ids(&repo, tip("bar")), vec![tip("foo")]);
+	}
 }
 
 #[cfg(test)]
@@ -287,11 +470,12 @@ mod test_rebase {
 	use crate::sync::{
 		checkout_branch, create_branch,
 		rebase::{
-			abort_rebase, get_rebase_progress, RebaseProgress,
-			RebaseState,
+			abort_rebase, get_rebase_progress, skip_rebase,
+			RebaseProgress, RebaseState,
 		},
 		rebase_branch, repo_state,
 		tests::{repo_init, write_commit_file},
+		utils::repo_read_file,
 		RepoPath, RepoState,
 	};
 	use git2::BranchType;
@@ -320,8 +504,13 @@ mod test_rebase {
 
 		// rebase
 
-		let r = rebase_branch(repo_path, "master", BranchType::Local)
-			.unwrap();
+		let r = rebase_branch(
+			repo_path,
+			"master",
+			BranchType::Local,
+			false,
+		)
+		.unwrap();
 
 		assert_eq!(r, RebaseState::Conflicted);
 		assert_eq!(repo_state(repo_path).unwrap(), RepoState::Rebase);
@@ -340,4 +529,52 @@ mod test_rebase {
 
 		assert_eq!(repo_state(repo_path).unwrap(), RepoState::Clean);
 	}
+
+	#[test]
+	fn test_conflicted_skip() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		write_commit_file(&repo, "test.txt", "test1", "commit1");
+
+		create_branch(repo_path, "foo").unwrap();
+
+		write_commit_file(&repo, "test.txt", "test2", "commit2");
+
+		checkout_branch(repo_path, "refs/heads/master").unwrap();
+
+		let c =
+			write_commit_file(&repo, "test.txt", "test3", "commit3");
+
+		checkout_branch(repo_path, "refs/heads/foo").unwrap();
+
+		let r = rebase_branch(
+			repo_path,
+			"master",
+			BranchType::Local,
+			false,
+		)
+		.unwrap();
+
+		assert_eq!(r, RebaseState::Conflicted);
+
+		// skip
+
+		assert_eq!(
+			skip_rebase(&repo).unwrap(),
+			RebaseState::Finished
+		);
+
+		assert_eq!(repo_state(repo_path).unwrap(), RepoState::Clean);
+		assert_eq!(
+			repo.head().unwrap().peel_to_commit().unwrap().id(),
+			c.into()
+		);
+		assert_eq!(
+			repo_read_file(&repo, "test.txt").unwrap(),
+			"test3"
+		);
+	}
 }
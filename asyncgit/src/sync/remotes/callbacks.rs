@@ -1,5 +1,14 @@
 use super::push::ProgressNotification;
-use crate::{error::Result, sync::cred::BasicAuthCredential};
+use crate::{
+	error::Result,
+	sync::{
+		cred::{
+			approve_credential, reject_credential,
+			BasicAuthCredential,
+		},
+		RepoPath,
+	},
+};
 use crossbeam_channel::Sender;
 use git2::{Cred, Error as GitError, RemoteCallbacks};
 use std::sync::{
@@ -20,6 +29,9 @@ pub struct Callbacks {
 	basic_credential: Option<BasicAuthCredential>,
 	stats: Arc<Mutex<CallbackStats>>,
 	first_call_to_credentials: Arc<AtomicBool>,
+	/// url the username and password were handed out for
+	credential_url: Arc<Mutex<Option<String>>>,
+	credential_rejected: Arc<AtomicBool>,
 }
 
 impl Callbacks {
@@ -37,6 +49,39 @@ impl Callbacks {
 			first_call_to_credentials: Arc::new(AtomicBool::new(
 				true,
 			)),
+			credential_url: Arc::new(Mutex::new(None)),
+			credential_rejected: Arc::new(AtomicBool::new(false)),
+		}
+	}
+
+	/// stores the username and password with the credential helpers
+	/// once they worked and erases them if the remote turned them down
+	pub fn settle_credential<T, E>(
+		&self,
+		repo_path: &RepoPath,
+		res: &std::result::Result<T, E>,
+	) {
+		let url = self
+			.credential_url
+			.lock()
+			.ok()
+			.and_then(|url| url.clone());
+
+		if let (Some(url), Some(credential)) =
+			(url, &self.basic_credential)
+		{
+			let settled =
+				if self.credential_rejected.load(Ordering::Relaxed) {
+					reject_credential(repo_path, &url, credential)
+				} else if res.is_ok() {
+					approve_credential(repo_path, &url, credential)
+				} else {
+					Ok(())
+				};
+
+			if let Err(e) = settled {
+				log::error!("credential helper: {}", e);
+			}
 		}
 	}
 
@@ -198,6 +243,15 @@ impl Callbacks {
 			self.first_call_to_credentials
 				.store(false, Ordering::Relaxed);
 		} else {
+			// asked again means the ones handed out got rejected
+			if self
+				.credential_url
+				.lock()
+				.map_or(false, |url| url.is_some())
+			{
+				self.credential_rejected
+					.store(true, Ordering::Relaxed);
+			}
 			return Err(GitError::from_str("Bad credentials."));
 		}
 
@@ -216,6 +270,11 @@ impl Callbacks {
 				username: Some(user),
 				password: Some(pwd),
 			}) if allowed_types.is_user_pass_plaintext() => {
+				if let Ok(mut credential_url) =
+					self.credential_url.lock()
+				{
+					*credential_url = Some(url.to_string());
+				}
 				Cred::userpass_plaintext(user, pwd)
 			}
 			Some(BasicAuthCredential {
@@ -0,0 +1,173 @@
+//! adding, removing, renaming and editing remotes
+
+use crate::{
+	error::{Error, Result},
+	sync::{repository::repo, RepoPath},
+};
+use scopetime::scope_time;
+
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteInfo {
+	///
+	pub name: String,
+	/// url fetched from
+	pub url: Option<String>,
+	/// url pushed to if it differs from `url`
+	pub push_url: Option<String>,
+}
+
+///
+pub fn get_remotes_info(
+	repo_path: &RepoPath,
+) -> Result<Vec<RemoteInfo>> {
+	scope_time!("get_remotes_info");
+
+	let repo = repo(repo_path)?;
+	let remotes = repo.remotes()?;
+
+	let mut res = Vec::with_capacity(remotes.len());
+	for name in remotes.iter().flatten() {
+		let remote = repo.find_remote(name)?;
+		res.push(RemoteInfo {
+			name: name.to_string(),
+			url: remote.url().map(String::from),
+			push_url: remote.pushurl().map(String::from),
+		});
+	}
+
+	Ok(res)
+}
+
+///
+pub fn add_remote(
+	repo_path: &RepoPath,
+	name: &str,
+	url: &str,
+) -> Result<()> {
+	scope_time!("add_remote");
+
+	let repo = repo(repo_path)?;
+	repo.remote(name, url)?;
+
+	Ok(())
+}
+
+/// removes the remote together with its remote tracking branches
+pub fn remove_remote(repo_path: &RepoPath, name: &str) -> Result<()> {
+	scope_time!("remove_remote");
+
+	let repo = repo(repo_path)?;
+	repo.remote_delete(name)?;
+
+	Ok(())
+}
+
+/// renames the remote and its remote tracking branches
+pub fn rename_remote(
+	repo_path: &RepoPath,
+	name: &str,
+	new_name: &str,
+) -> Result<()> {
+	scope_time!("rename_remote");
+
+	let repo = repo(repo_path)?;
+	let problems = repo.remote_rename(name, new_name)?;
+
+	// refspecs that are not the default one are left untouched
+	if !problems.is_empty() {
+		log::warn!(
+			"rename remote: refspecs not renamed: {:?}",
+			problems.iter().flatten().collect::<Vec<_>>()
+		);
+	}
+
+	Ok(())
+}
+
+///
+pub fn set_remote_url(
+	repo_path: &RepoPath,
+	name: &str,
+	url: &str,
+) -> Result<()> {
+	scope_time!("set_remote_url");
+
+	if url.is_empty() {
+		return Err(Error::Generic(String::from(
+			"remote url cannot be empty",
+		)));
+	}
+
+	let repo = repo(repo_path)?;
+	repo.remote_set_url(name, url)?;
+
+	Ok(())
+}
+
+/// an empty `url` removes the push url so `url` is pushed to again
+pub fn set_remote_push_url(
+	repo_path: &RepoPath,
+	name: &str,
+	url: &str,
+) -> Result<()> {
+	scope_time!("set_remote_push_url");
+
+	let repo = repo(repo_path)?;
+	repo.remote_set_pushurl(
+		name,
+		if url.is_empty() { None } else { Some(url) },
+	)?;
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::tests::repo_init;
+
+	#[test]
+	fn test_manage_remotes() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		assert!(get_remotes_info(repo_path).unwrap().is_empty());
+
+		add_remote(repo_path, "origin", "https://a.com/r.git")
+			.unwrap();
+		set_remote_push_url(repo_path, "origin", "ssh://a.com/r.git")
+			.unwrap();
+
+		assert_eq!(
+			get_remotes_info(repo_path).unwrap(),
+			vec![RemoteInfo {
+				name: String::from("origin"),
+				url: Some(String::from("https://a.com/r.git")),
+				push_url: Some(String::from("ssh://a.com/r.git")),
+			}]
+		);
+
+		rename_remote(repo_path, "origin", "upstream").unwrap();
+		set_remote_url(repo_path, "upstream", "https://b.com/r.git")
+			.unwrap();
+		set_remote_push_url(repo_path, "upstream", "").unwrap();
+
+		assert_eq!(
+			get_remotes_info(repo_path).unwrap(),
+			vec![RemoteInfo {
+				name: String::from("upstream"),
+				url: Some(String::from("https://b.com/r.git")),
+				push_url: None,
+			}]
+		);
+
+		assert!(set_remote_url(repo_path, "upstream", "").is_err());
+
+		remove_remote(repo_path, "upstream").unwrap();
+
+		assert!(get_remotes_info(repo_path).unwrap().is_empty());
+	}
+}
@@ -1,7 +1,13 @@
 //!
 
 mod callbacks;
+mod manage;
+mod proxy;
 pub(crate) mod push;
+mod push_default;
+mod retry;
+mod shallow;
+mod ssh;
 pub(crate) mod tags;
 
 use crate::{
@@ -12,12 +18,23 @@ use crate::{
 	},
 	ProgressPercent,
 };
-use crossbeam_channel::Sender;
-use git2::{BranchType, FetchOptions, ProxyOptions, Repository};
+use crossbeam_channel::{unbounded, Select, Sender};
+use git2::{BranchType, Direction, FetchOptions, Remote, Repository};
+use push::AsyncProgress;
 use scopetime::scope_time;
+use std::thread;
 use utils::bytes2string;
 
 pub use callbacks::Callbacks;
+pub use manage::{
+	add_remote, get_remotes_info, remove_remote, rename_remote,
+	set_remote_push_url, set_remote_url, RemoteInfo,
+};
+pub use proxy::proxy_options;
+pub use push_default::{push_destination, PushDestination};
+pub use retry::{retry_transient, Retry};
+pub use shallow::{deepen, shallow_commits};
+pub use ssh::register_ssh_transport;
 pub use tags::tags_missing_remote;
 
 use super::RepoPath;
@@ -25,11 +42,14 @@ use super::RepoPath;
 /// origin
 pub const DEFAULT_REMOTE_NAME: &str = "origin";
 
-///
-pub fn proxy_auto<'a>() -> ProxyOptions<'a> {
-	let mut proxy = ProxyOptions::new();
-	proxy.auto();
-	proxy
+/// looks up a remote to connect to, letting the ssh transport pick up
+/// the `core.sshCommand` of `repo`
+fn find_remote<'a>(
+	repo: &'a Repository,
+	name: &str,
+) -> Result<Remote<'a>> {
+	ssh::use_repo_config(repo);
+	Ok(repo.find_remote(name)?)
 }
 
 ///
@@ -89,61 +109,168 @@ pub(crate) fn get_default_remote_in_repo(
 fn fetch_from_remote(
 	repo_path: &RepoPath,
 	remote: &str,
+	prune: bool,
 	basic_credential: Option<BasicAuthCredential>,
 	progress_sender: Option<Sender<ProgressNotification>>,
 ) -> Result<()> {
 	let repo = repo(repo_path)?;
 
-	let mut remote = repo.find_remote(remote)?;
+	let mut remote = find_remote(&repo, remote)?;
 
 	let mut options = FetchOptions::new();
 	let callbacks = Callbacks::new(progress_sender, basic_credential);
-	options.prune(git2::FetchPrune::On);
-	options.proxy_options(proxy_auto());
+	options.prune(if prune {
+		git2::FetchPrune::On
+	} else {
+		git2::FetchPrune::Off
+	});
+	options.proxy_options(proxy_options(
+		&repo,
+		&remote,
+		Direction::Fetch,
+	));
 	options.download_tags(git2::AutotagOption::All);
 	options.remote_callbacks(callbacks.callbacks());
-	remote.fetch(&[] as &[&str], Some(&mut options), None)?;
-	// fetch tags (also removing remotely deleted ones)
-	remote.fetch(
-		&["refs/tags/*:refs/tags/*"],
-		Some(&mut options),
-		None,
-	)?;
+	let res = remote
+		.fetch(&[] as &[&str], Some(&mut options), None)
+		.and_then(|()| {
+			// fetch tags (also removing remotely deleted ones)
+			remote.fetch(
+				&["refs/tags/*:refs/tags/*"],
+				Some(&mut options),
+				None,
+			)
+		});
+	callbacks.settle_credential(repo_path, &res);
+	res?;
 
 	Ok(())
 }
 
-/// updates/prunes all branches from all remotes
+/// progress of a single remote in `fetch_all`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RemoteFetchProgress {
+	///
+	pub name: String,
+	///
+	pub progress: ProgressPercent,
+	/// set while a network error is being retried
+	pub retry: Option<Retry>,
+	/// fetching this remote finished (successfully or not)
+	pub done: bool,
+	///
+	pub failed: bool,
+}
+
+/// progress of every remote fetched in `fetch_all`
+pub type FetchAllProgress = Vec<RemoteFetchProgress>;
+
+/// updates (and optionally prunes) all branches from all remotes.
+/// the remotes are fetched concurrently, each on its own thread,
+/// retrying each up to `retries` times on transient network errors.
 pub fn fetch_all(
 	repo_path: &RepoPath,
+	prune: bool,
+	retries: usize,
 	basic_credential: &Option<BasicAuthCredential>,
-	progress_sender: &Option<Sender<ProgressPercent>>,
+	progress_sender: &Option<Sender<FetchAllProgress>>,
 ) -> Result<()> {
 	scope_time!("fetch_all");
 
-	let repo = repo(repo_path)?;
-	let remotes = repo
-		.remotes()?
+	let remotes = get_remotes(repo_path)?;
+
+	let mut progress: FetchAllProgress = remotes
 		.iter()
-		.flatten()
-		.map(String::from)
-		.collect::<Vec<_>>();
-	let remotes_count = remotes.len();
+		.map(|name| RemoteFetchProgress {
+			name: name.clone(),
+			..RemoteFetchProgress::default()
+		})
+		.collect();
+
+	let mut receivers = Vec::with_capacity(remotes.len());
+	let mut handles = Vec::with_capacity(remotes.len());
 
 	for (idx, remote) in remotes.into_iter().enumerate() {
-		fetch_from_remote(
-			repo_path,
-			&remote,
-			basic_credential.clone(),
-			None,
-		)?;
-
-		if let Some(sender) = progress_sender {
-			let progress = ProgressPercent::new(idx, remotes_count);
-			sender.send(progress)?;
+		let (sender, receiver) = unbounded();
+		let repo_path = repo_path.clone();
+		let basic_credential = basic_credential.clone();
+
+		receivers.push((idx, receiver));
+		handles.push(thread::spawn(move || {
+			retry_transient(retries, Some(&sender), || {
+				fetch_from_remote(
+					&repo_path,
+					&remote,
+					prune,
+					basic_credential.clone(),
+					Some(sender.clone()),
+				)
+			})
+		}));
+	}
+
+	// a receiver disconnects once its fetch is done with the callbacks
+	while !receivers.is_empty() {
+		let mut select = Select::new();
+		for (_, receiver) in &receivers {
+			select.recv(receiver);
+		}
+
+		let op = select.select();
+		let index = op.index();
+		let (idx, receiver) = &receivers[index];
+
+		match op.recv(receiver) {
+			Ok(notification) => {
+				if let ProgressNotification::Retry(retry) =
+					notification
+				{
+					progress[*idx].retry = Some(retry);
+				}
+				progress[*idx].progress = notification.progress();
+				send_fetch_progress(
+					progress_sender.as_ref(),
+					&progress,
+				)?;
+			}
+			Err(_) => {
+				receivers.remove(index);
+			}
+		}
+	}
+
+	let mut result = Ok(());
+	for (idx, handle) in handles.into_iter().enumerate() {
+		let res = handle.join().unwrap_or_else(|_| {
+			Err(Error::Generic(String::from("fetch thread panicked")))
+		});
+
+		progress[idx].done = true;
+		progress[idx].failed = res.is_err();
+
+		if let Err(e) = res {
+			log::error!("fetch {}: {}", progress[idx].name, e);
+			if result.is_ok() {
+				result = Err(e);
+			}
+		} else {
+			progress[idx].progress = ProgressPercent::full();
 		}
 	}
 
+	send_fetch_progress(progress_sender.as_ref(), &progress)?;
+
+	result
+}
+
+fn send_fetch_progress(
+	progress_sender: Option<&Sender<FetchAllProgress>>,
+	progress: &FetchAllProgress,
+) -> Result<()> {
+	if let Some(sender) = progress_sender {
+		sender.send(progress.clone())?;
+	}
+
 	Ok(())
 }
 
@@ -163,15 +290,21 @@ pub(crate) fn fetch(
 	let branch_ref = bytes2string(branch_ref.name_bytes())?;
 	let remote_name = repo.branch_upstream_remote(&branch_ref)?;
 	let remote_name = bytes2string(&remote_name)?;
-	let mut remote = repo.find_remote(&remote_name)?;
+	let mut remote = find_remote(&repo, &remote_name)?;
 
 	let mut options = FetchOptions::new();
 	options.download_tags(git2::AutotagOption::All);
 	let callbacks = Callbacks::new(progress_sender, basic_credential);
 	options.remote_callbacks(callbacks.callbacks());
-	options.proxy_options(proxy_auto());
+	options.proxy_options(proxy_options(
+		&repo,
+		&remote,
+		Direction::Fetch,
+	));
 
-	remote.fetch(&[branch], Some(&mut options), None)?;
+	let res = remote.fetch(&[branch], Some(&mut options), None);
+	callbacks.settle_credential(repo_path, &res);
+	res?;
 
 	Ok(remote.stats().received_bytes())
 }
@@ -202,6 +335,45 @@ mod tests {
 		fetch(repo_path, "master", None, None).unwrap();
 	}
 
+	#[test]
+	fn test_fetch_all_progress_per_remote() {
+		let (remote_dir, _remote) = repo_init().unwrap();
+		let remote_path = remote_dir.path().to_str().unwrap();
+		let (repo_dir, repo) = repo_clone(remote_path).unwrap();
+		let repo_path: &RepoPath =
+			&repo_dir.path().to_str().unwrap().into();
+
+		debug_cmd_print(
+			repo_path,
+			&format!("git remote add second {}", remote_path)[..],
+		);
+		debug_cmd_print(
+			repo_path,
+			"git remote add zbroken /does/not/exist",
+		);
+
+		let (sender, receiver) = crossbeam_channel::unbounded();
+
+		let res = fetch_all(repo_path, true, 0, &None, &Some(sender));
+
+		assert!(res.is_err());
+
+		let progress = receiver.try_iter().last().unwrap();
+		let names: Vec<_> =
+			progress.iter().map(|p| p.name.as_str()).collect();
+
+		assert_eq!(names, vec!["origin", "second", "zbroken"]);
+		assert!(progress.iter().all(|p| p.done));
+		assert!(!progress[0].failed);
+		assert!(!progress[1].failed);
+		assert!(progress[2].failed);
+		assert_eq!(progress[1].progress, ProgressPercent::full());
+
+		assert!(repo
+			.find_branch("second/master", BranchType::Remote)
+			.is_ok());
+	}
+
 	#[test]
 	fn test_default_remote() {
 		let (remote_dir, _remote) = repo_init().unwrap();
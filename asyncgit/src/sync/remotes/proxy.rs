@@ -0,0 +1,229 @@
+//! proxy lookup for fetching from and pushing to remotes
+
+use git2::{Config, Direction, ProxyOptions, Remote, Repository};
+use std::env;
+
+/// proxy to connect to `remote` through, looked up the way git does.
+///
+/// `remote.<name>.proxy`, `http.<url>.proxy`, `http.proxy` and then the
+/// `https_proxy`/`http_proxy` environment variables unless `no_proxy`
+/// excludes the host. unlike libgit2 we accept proxies without a scheme
+/// and an empty value to turn the proxy off, like git does.
+pub fn proxy_options<'a>(
+	repo: &Repository,
+	remote: &Remote,
+	direction: Direction,
+) -> ProxyOptions<'a> {
+	let url = match direction {
+		Direction::Fetch => remote.url(),
+		Direction::Push => remote.pushurl().or_else(|| remote.url()),
+	}
+	.unwrap_or_default();
+
+	let proxy = repo.config().ok().and_then(|config| {
+		resolve_proxy(&config, remote.name(), url, |key| {
+			env::var(key).ok()
+		})
+	});
+
+	let mut options = ProxyOptions::new();
+	if let Some(proxy) = proxy {
+		log::debug!("proxy for '{}': {}", url, proxy);
+		options.url(&proxy);
+	}
+	options
+}
+
+fn resolve_proxy(
+	config: &Config,
+	remote_name: Option<&str>,
+	url: &str,
+	env: impl Fn(&str) -> Option<String>,
+) -> Option<String> {
+	let https = url.starts_with("https://");
+
+	// ssh and local remotes never go through a http proxy
+	if !https && !url.starts_with("http://") {
+		return None;
+	}
+
+	let configured = remote_name
+		.and_then(|name| {
+			config.get_string(&format!("remote.{}.proxy", name)).ok()
+		})
+		.or_else(|| {
+			url_prefixes(url).find_map(|prefix| {
+				config
+					.get_string(&format!("http.{}.proxy", prefix))
+					.ok()
+			})
+		})
+		.or_else(|| config.get_string("http.proxy").ok());
+
+	let proxy = if let Some(proxy) = configured {
+		proxy
+	} else {
+		let keys = if https {
+			["https_proxy", "HTTPS_PROXY"]
+		} else {
+			["http_proxy", "HTTP_PROXY"]
+		};
+		let proxy = keys.iter().find_map(|key| env(key))?;
+
+		let no_proxy = env("no_proxy")
+			.or_else(|| env("NO_PROXY"))
+			.unwrap_or_default();
+		if no_proxy_matches(&no_proxy, url_host(url)) {
+			return None;
+		}
+
+		proxy
+	};
+
+	let proxy = proxy.trim();
+	if proxy.is_empty() {
+		None
+	} else if proxy.contains("://") {
+		Some(proxy.to_string())
+	} else {
+		Some(format!("http://{}", proxy))
+	}
+}
+
+/// `url` and its parents down to the bare host,
+/// matching the keys of `http.<url>.*` config sections
+fn url_prefixes(url: &str) -> impl Iterator<Item = &str> {
+	let host_end = url
+		.find("://")
+		.map(|idx| idx + 3)
+		.and_then(|start| {
+			url[start..].find('/').map(|idx| start + idx)
+		})
+		.unwrap_or(url.len());
+
+	let mut next = Some(url);
+	std::iter::from_fn(move || {
+		let current = next?;
+		next = if current.len() <= host_end {
+			None
+		} else if let Some(trimmed) = current.strip_suffix('/') {
+			Some(trimmed)
+		} else {
+			current.rfind('/').map(|idx| &current[..=idx])
+		};
+		Some(current)
+	})
+}
+
+fn url_host(url: &str) -> &str {
+	let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+	let authority = rest.split('/').next().unwrap_or_default();
+	let host = authority
+		.rsplit_once('@')
+		.map_or(authority, |(_, host)| host);
+
+	if host.starts_with('[') {
+		host.split_once(']')
+			.map_or(host, |(host, _)| host.trim_start_matches('['))
+	} else {
+		host.split(':').next().unwrap_or_default()
+	}
+}
+
+fn no_proxy_matches(no_proxy: &str, host: &str) -> bool {
+	no_proxy
+		.split(|c: char| c == ',' || c.is_whitespace())
+		.filter(|entry| !entry.is_empty())
+		.any(|entry| {
+			if entry == "*" {
+				return true;
+			}
+
+			let entry = entry.trim_start_matches('*');
+			let domain = entry.trim_start_matches('.');
+			let domain = domain.split(':').next().unwrap_or_default();
+
+			host.eq_ignore_ascii_case(domain)
+				|| host
+					.to_lowercase()
+					.ends_with(&format!(".{}", domain.to_lowercase()))
+		})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::tests::repo_init;
+
+	#[test]
+	fn test_resolve_proxy() {
+		let (_td, repo) = repo_init().unwrap();
+		let mut config = repo.config().unwrap();
+		let url = "https://git.corp.com/team/repo.git";
+
+		let env = |key: &str| match key {
+			"https_proxy" => Some(String::from("envproxy:3128")),
+			"no_proxy" => Some(String::from("localhost,.internal")),
+			_ => None,
+		};
+
+		assert_eq!(
+			resolve_proxy(&config, Some("origin"), url, env),
+			Some(String::from("http://envproxy:3128"))
+		);
+		assert_eq!(
+			resolve_proxy(
+				&config,
+				Some("origin"),
+				"https://git.internal/repo.git",
+				env
+			),
+			None
+		);
+		assert_eq!(
+			resolve_proxy(
+				&config,
+				Some("origin"),
+				"git@a.com:r",
+				env
+			),
+			None
+		);
+
+		config.set_str("http.proxy", "proxy.corp.com:8080").unwrap();
+		assert_eq!(
+			resolve_proxy(&config, Some("origin"), url, env),
+			Some(String::from("http://proxy.corp.com:8080"))
+		);
+
+		config
+			.set_str(
+				"http.https://git.corp.com/team.proxy",
+				"https://team:pw@teamproxy:443",
+			)
+			.unwrap();
+		assert_eq!(
+			resolve_proxy(&config, Some("origin"), url, env),
+			Some(String::from("https://team:pw@teamproxy:443"))
+		);
+
+		config.set_str("remote.origin.proxy", "").unwrap();
+		assert_eq!(
+			resolve_proxy(&config, Some("origin"), url, env),
+			None
+		);
+	}
+
+	#[test]
+	fn test_no_proxy() {
+		assert!(no_proxy_matches("*", "a.com"));
+		assert!(no_proxy_matches("b.com, a.com", "a.com"));
+		assert!(no_proxy_matches(".a.com", "git.a.com"));
+		assert!(no_proxy_matches("a.com:443", "git.a.com"));
+		assert!(!no_proxy_matches("a.com", "ba.com"));
+		assert!(!no_proxy_matches("", "a.com"));
+
+		assert_eq!(url_host("https://u:p@a.com:8443/r.git"), "a.com");
+		assert_eq!(url_host("http://[::1]:80/r"), "::1");
+	}
+}
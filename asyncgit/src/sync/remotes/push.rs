@@ -4,13 +4,16 @@ use crate::{
 	sync::{
 		branch::branch_set_upstream,
 		cred::BasicAuthCredential,
-		remotes::{proxy_auto, Callbacks},
+		remotes::{
+			find_remote, proxy_options,
+			tags::follow_tags_missing_remote, Callbacks, Retry,
+		},
 		repository::repo,
 		CommitId, RepoPath,
 	},
 };
 use crossbeam_channel::Sender;
-use git2::{PackBuilderStage, PushOptions};
+use git2::{Direction, PackBuilderStage, PushOptions};
 use scopetime::scope_time;
 
 ///
@@ -58,6 +61,8 @@ pub enum ProgressNotification {
 		///
 		current: usize,
 	},
+	/// a network error is about to be retried
+	Retry(Retry),
 	///
 	Done,
 }
@@ -86,6 +91,7 @@ impl AsyncProgress for ProgressNotification {
 				total_objects,
 				..
 			} => ProgressPercent::new(objects, total_objects),
+			Self::Retry(_) => ProgressPercent::empty(),
 			_ => ProgressPercent::full(),
 		}
 	}
@@ -120,33 +126,109 @@ pub fn push_branch(
 		repo_path,
 		remote,
 		branch,
+		None,
 		PushType::Branch,
 		force,
 		delete,
+		true,
+		false,
 		basic_credential,
 		progress_sender,
 	)
 }
 
+/// checks that `branch` on `remote` still is where our remote
+/// tracking branch says it is, like `git push --force-with-lease`
+/// does before overwriting it.
+///
+/// this is a separate connection ahead of the push, the remote can
+/// still move in between: git2 0.15 has no push negotiation callback
+/// to check the lease as part of the push itself
+pub fn check_force_with_lease(
+	repo_path: &RepoPath,
+	remote: &str,
+	branch: &str,
+	basic_credential: Option<BasicAuthCredential>,
+) -> Result<()> {
+	scope_time!("check_force_with_lease");
+
+	let repo = repo(repo_path)?;
+	let expected = repo
+		.refname_to_id(&format!("refs/remotes/{}/{}", remote, branch))
+		.ok();
+
+	let mut remote = find_remote(&repo, remote)?;
+	let callbacks = Callbacks::new(None, basic_credential);
+	let proxy = proxy_options(&repo, &remote, Direction::Fetch);
+	let conn = remote.connect_auth(
+		Direction::Fetch,
+		Some(callbacks.callbacks()),
+		Some(proxy),
+	)?;
+
+	let remote_ref = format!("refs/heads/{}", branch);
+	let actual = conn
+		.list()?
+		.iter()
+		.find(|head| head.name() == remote_ref)
+		.map(git2::RemoteHead::oid);
+
+	if actual != expected {
+		return Err(Error::ForceWithLeaseRejected(
+			branch.to_string(),
+		));
+	}
+
+	Ok(())
+}
+
+/// `remote_branch` is the name to push `branch` to on the remote
+/// if it differs from the local name (`git push <remote> a:b`)
 //TODO: clenaup
-#[allow(clippy::too_many_arguments)]
+#[allow(
+	clippy::too_many_arguments,
+	clippy::fn_params_excessive_bools
+)]
 pub fn push_raw(
 	repo_path: &RepoPath,
 	remote: &str,
 	branch: &str,
+	remote_branch: Option<&str>,
 	ref_type: PushType,
 	force: bool,
 	delete: bool,
+	set_upstream: bool,
+	follow_tags: bool,
 	basic_credential: Option<BasicAuthCredential>,
 	progress_sender: Option<Sender<ProgressNotification>>,
 ) -> Result<()> {
 	scope_time!("push");
 
 	let repo = repo(repo_path)?;
-	let mut remote = repo.find_remote(remote)?;
+	let remote_name = remote;
+	let mut remote = find_remote(&repo, remote)?;
+
+	let tags = if follow_tags
+		&& !delete
+		&& matches!(ref_type, PushType::Branch)
+	{
+		follow_tags_missing_remote(
+			&repo,
+			repo_path,
+			remote_name,
+			branch,
+			basic_credential.clone(),
+		)?
+	} else {
+		Vec::new()
+	};
 
 	let mut options = PushOptions::new();
-	options.proxy_options(proxy_auto());
+	options.proxy_options(proxy_options(
+		&repo,
+		&remote,
+		Direction::Push,
+	));
 
 	let callbacks = Callbacks::new(progress_sender, basic_credential);
 	options.remote_callbacks(callbacks.callbacks());
@@ -163,9 +245,28 @@ pub fn push_raw(
 		PushType::Tag => "tags",
 	};
 
-	let branch_name =
-		format!("{}refs/{}/{}", branch_modifier, ref_type, branch);
-	remote.push(&[branch_name.as_str()], Some(&mut options))?;
+	let branch_name = match remote_branch {
+		Some(remote_branch) if !delete => format!(
+			"{}refs/{}/{}:refs/{}/{}",
+			branch_modifier,
+			ref_type,
+			branch,
+			ref_type,
+			remote_branch
+		),
+		_ => format!(
+			"{}refs/{}/{}",
+			branch_modifier,
+			ref_type,
+			remote_branch.unwrap_or(branch)
+		),
+	};
+	let refspecs = std::iter::once(branch_name.as_str())
+		.chain(tags.iter().map(String::as_str))
+		.collect::<Vec<_>>();
+	let res = remote.push(&refspecs, Some(&mut options));
+	callbacks.settle_credential(repo_path, &res);
+	res?;
 
 	if let Some((reference, msg)) =
 		callbacks.get_stats()?.push_rejected_msg
@@ -176,8 +277,13 @@ pub fn push_raw(
 		)));
 	}
 
-	if !delete {
-		branch_set_upstream(&repo, branch)?;
+	if set_upstream && !delete {
+		branch_set_upstream(
+			&repo,
+			branch,
+			remote_name,
+			remote_branch.unwrap_or(branch),
+		)?;
 	}
 
 	Ok(())
@@ -432,6 +538,111 @@ mod tests {
 		assert_eq!(new_upstream_parent, repo_2_parent,);
 	}
 
+	#[test]
+	fn test_push_to_remote_branch() {
+		let (upstream_dir, upstream_repo) = repo_init_bare().unwrap();
+
+		let (tmp_repo_dir, repo) =
+			repo_clone(upstream_dir.path().to_str().unwrap())
+				.unwrap();
+
+		write_commit_file(
+			&repo,
+			"temp_file.txt",
+			"SomeContent",
+			"Initial commit",
+		);
+
+		push_raw(
+			&tmp_repo_dir.path().to_str().unwrap().into(),
+			"origin",
+			"master",
+			Some("other"),
+			PushType::Branch,
+			false,
+			false,
+			true,
+			false,
+			None,
+			None,
+		)
+		.unwrap();
+
+		assert!(upstream_repo
+			.find_branch("other", git2::BranchType::Local)
+			.is_ok());
+		assert!(upstream_repo
+			.find_branch("master", git2::BranchType::Local)
+			.is_err());
+
+		let upstream = repo
+			.find_branch("master", git2::BranchType::Local)
+			.unwrap()
+			.upstream()
+			.unwrap();
+		assert_eq!(upstream.name().unwrap(), Some("origin/other"));
+	}
+
+	#[test]
+	fn test_force_with_lease() {
+		let (upstream_dir, _upstream) = repo_init_bare().unwrap();
+		let upstream_path = upstream_dir.path().to_str().unwrap();
+
+		let (clone1_dir, clone1) = repo_clone(upstream_path).unwrap();
+		let clone1_path: &RepoPath =
+			&clone1_dir.path().to_str().unwrap().into();
+
+		write_commit_file(&clone1, "test.txt", "test", "commit1");
+		push_branch(
+			clone1_path,
+			"origin",
+			"master",
+			false,
+			false,
+			None,
+			None,
+		)
+		.unwrap();
+
+		let (clone2_dir, clone2) = repo_clone(upstream_path).unwrap();
+		let clone2_path: &RepoPath =
+			&clone2_dir.path().to_str().unwrap().into();
+
+		write_commit_file(&clone2, "test2.txt", "test", "commit2");
+		push_branch(
+			clone2_path,
+			"origin",
+			"master",
+			false,
+			false,
+			None,
+			None,
+		)
+		.unwrap();
+
+		// clone1 did not see commit2 yet
+		assert!(matches!(
+			check_force_with_lease(
+				clone1_path,
+				"origin",
+				"master",
+				None
+			),
+			Err(Error::ForceWithLeaseRejected(_))
+		));
+
+		sync::remotes::fetch(clone1_path, "master", None, None)
+			.unwrap();
+
+		assert!(check_force_with_lease(
+			clone1_path,
+			"origin",
+			"master",
+			None
+		)
+		.is_ok());
+	}
+
 	#[test]
 	fn test_delete_remote_branch() {
 		// This test mimics the scenario of a user creating a branch, push it, and then remove it on the remote
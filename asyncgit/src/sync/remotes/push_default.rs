@@ -0,0 +1,241 @@
+//! where a plain push goes, following `push.default`
+
+use super::get_default_remote_in_repo;
+use crate::{
+	error::{Error, Result},
+	sync::{repository::repo, RepoPath},
+};
+use git2::{BranchType, Config, Repository};
+use scopetime::scope_time;
+
+/// `push.default`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PushDefault {
+	/// refuse to push without naming a destination
+	Nothing,
+	/// push to the branch of the same name
+	Current,
+	/// push to the upstream branch (also `tracking`)
+	Upstream,
+	/// like `Current` but refuse if the upstream has another name
+	Simple,
+	/// push all branches that exist on the remote under the same name
+	Matching,
+}
+
+impl PushDefault {
+	fn from_config(config: &Config) -> Self {
+		match config
+			.get_string("push.default")
+			.unwrap_or_default()
+			.to_lowercase()
+			.as_str()
+		{
+			"nothing" => Self::Nothing,
+			"current" => Self::Current,
+			"upstream" | "tracking" => Self::Upstream,
+			"matching" => Self::Matching,
+			_ => Self::Simple,
+		}
+	}
+}
+
+/// resolved destination of pushing a branch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PushDestination {
+	///
+	pub remote: String,
+	/// name of the branch on `remote`
+	pub branch: String,
+	/// other local branches pushed along (`push.default=matching`)
+	pub matching: Vec<String>,
+}
+
+/// resolves where pushing local `branch` without naming a destination
+/// goes, like `git push` does.
+///
+/// the remote is `branch.<name>.pushRemote`, `remote.pushDefault`, the
+/// remote of the upstream or the default remote, in that order. the
+/// branch on it is picked according to `push.default`.
+pub fn push_destination(
+	repo_path: &RepoPath,
+	branch: &str,
+) -> Result<PushDestination> {
+	scope_time!("push_destination");
+
+	let repo = repo(repo_path)?;
+	let config = repo.config()?;
+
+	let upstream = upstream(&config, branch);
+
+	let remote = config
+		.get_string(&format!("branch.{}.pushRemote", branch))
+		.or_else(|_| config.get_string("remote.pushDefault"))
+		.ok()
+		.or_else(|| {
+			upstream.as_ref().map(|(remote, _)| remote.clone())
+		})
+		.map_or_else(|| get_default_remote_in_repo(&repo), Ok)?;
+
+	// pushing somewhere else than the upstream comes from
+	let tracking = upstream.is_some();
+	let upstream = upstream
+		.filter(|(upstream_remote, _)| upstream_remote == &remote);
+
+	let mut destination = PushDestination {
+		remote,
+		branch: branch.to_string(),
+		matching: Vec::new(),
+	};
+
+	match PushDefault::from_config(&config) {
+		PushDefault::Nothing => {
+			return Err(Error::Generic(String::from(
+				"push.default is 'nothing': no destination to push to",
+			)));
+		}
+		PushDefault::Current => (),
+		PushDefault::Upstream => {
+			if let Some((_, upstream_branch)) = upstream {
+				destination.branch = upstream_branch;
+			} else if tracking {
+				return Err(Error::Generic(format!(
+					"push.default is 'upstream' but '{}' is not the upstream remote of '{}'",
+					destination.remote, branch
+				)));
+			}
+		}
+		PushDefault::Simple => {
+			if let Some((_, upstream_branch)) = upstream {
+				if upstream_branch != branch {
+					return Err(Error::Generic(format!(
+						"the upstream of '{}' is named '{}', push to it explicitly or set push.default to 'upstream'",
+						branch, upstream_branch
+					)));
+				}
+			}
+		}
+		PushDefault::Matching => {
+			destination.matching = matching_branches(
+				&repo,
+				&destination.remote,
+				branch,
+			)?;
+		}
+	}
+
+	Ok(destination)
+}
+
+/// remote and remote branch name `branch` tracks, if configured
+fn upstream(
+	config: &Config,
+	branch: &str,
+) -> Option<(String, String)> {
+	let remote = config
+		.get_string(&format!("branch.{}.remote", branch))
+		.ok()?;
+	let merge = config
+		.get_string(&format!("branch.{}.merge", branch))
+		.ok()?;
+	let merge = merge
+		.strip_prefix("refs/heads/")
+		.unwrap_or(&merge)
+		.to_string();
+
+	Some((remote, merge))
+}
+
+/// local branches other than `branch` that `remote` has as well
+fn matching_branches(
+	repo: &Repository,
+	remote: &str,
+	branch: &str,
+) -> Result<Vec<String>> {
+	let mut matching = Vec::new();
+
+	for b in repo.branches(Some(BranchType::Local))? {
+		let (local, _) = b?;
+		let name = local.name()?.unwrap_or_default().to_string();
+
+		if name != branch
+			&& repo
+				.find_reference(&format!(
+					"refs/remotes/{}/{}",
+					remote, name
+				))
+				.is_ok()
+		{
+			matching.push(name);
+		}
+	}
+
+	Ok(matching)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::{
+		create_branch,
+		remotes::push::push_branch,
+		tests::{repo_clone, repo_init_bare, write_commit_file},
+	};
+
+	#[test]
+	fn test_push_destination() {
+		let (remote_dir, _remote) = repo_init_bare().unwrap();
+		let remote_path = remote_dir.path().to_str().unwrap();
+		let (clone_dir, clone) = repo_clone(remote_path).unwrap();
+		let repo_path: &RepoPath =
+			&clone_dir.path().to_str().unwrap().into();
+
+		write_commit_file(&clone, "f.txt", "1", "c1");
+		push_branch(
+			repo_path, "origin", "master", false, false, None, None,
+		)
+		.unwrap();
+
+		create_branch(repo_path, "feature").unwrap();
+		let mut config = clone.config().unwrap();
+		config.set_str("branch.feature.remote", "origin").unwrap();
+		config
+			.set_str("branch.feature.merge", "refs/heads/feat")
+			.unwrap();
+
+		let destination =
+			|branch| push_destination(repo_path, branch);
+
+		// simple refuses the differently named upstream
+		assert!(destination("feature").is_err());
+
+		config.set_str("push.default", "upstream").unwrap();
+		assert_eq!(destination("feature").unwrap().branch, "feat");
+
+		config.set_str("push.default", "current").unwrap();
+		assert_eq!(destination("feature").unwrap().branch, "feature");
+
+		config.set_str("push.default", "matching").unwrap();
+		assert_eq!(
+			destination("feature").unwrap().matching,
+			vec![String::from("master")]
+		);
+
+		config.set_str("push.default", "nothing").unwrap();
+		assert!(destination("feature").is_err());
+
+		config.remove("push.default").unwrap();
+		config.set_str("remote.pushDefault", "fork").unwrap();
+		assert_eq!(
+			destination("feature").unwrap(),
+			PushDestination {
+				remote: String::from("fork"),
+				branch: String::from("feature"),
+				matching: Vec::new(),
+			}
+		);
+
+		config.set_str("branch.feature.pushRemote", "mine").unwrap();
+		assert_eq!(destination("feature").unwrap().remote, "mine");
+	}
+}
@@ -0,0 +1,212 @@
+//! retrying network operations that failed for transient reasons
+
+use super::push::ProgressNotification;
+use crate::error::{Error, Result};
+use crossbeam_channel::Sender;
+use git2::ErrorClass;
+use std::{io, thread, time::Duration};
+
+/// delay before the first retry, doubled for every further one
+const BACKOFF: Duration = Duration::from_secs(1);
+const BACKOFF_MAX: Duration = Duration::from_secs(16);
+
+/// a failed attempt that is about to be repeated
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Retry {
+	/// 1 for the first retry
+	pub attempt: usize,
+	///
+	pub max: usize,
+}
+
+/// runs `f` and up to `retries` more times as long as it fails with
+/// what looks like a flaky connection (timeouts, resets, 5xx..).
+/// every retry is announced on `progress_sender` before backing off.
+pub fn retry_transient<T>(
+	retries: usize,
+	progress_sender: Option<&Sender<ProgressNotification>>,
+	f: impl FnMut() -> Result<T>,
+) -> Result<T> {
+	retry_with_backoff(retries, BACKOFF, progress_sender, f)
+}
+
+fn retry_with_backoff<T>(
+	retries: usize,
+	backoff: Duration,
+	progress_sender: Option<&Sender<ProgressNotification>>,
+	mut f: impl FnMut() -> Result<T>,
+) -> Result<T> {
+	let mut delay = backoff;
+
+	for attempt in 1..=retries {
+		match f() {
+			Err(e) if is_transient(&e) => {
+				log::warn!(
+					"network error, retry {}/{} in {:?}: {}",
+					attempt,
+					retries,
+					delay,
+					e
+				);
+
+				if let Some(sender) = progress_sender {
+					sender
+						.send(ProgressNotification::Retry(Retry {
+							attempt,
+							max: retries,
+						}))
+						.ok();
+				}
+
+				thread::sleep(delay);
+				delay = (delay * 2).min(BACKOFF_MAX);
+			}
+			res => return res,
+		}
+	}
+
+	f()
+}
+
+/// whether `e` is worth trying again, as opposed to errors like
+/// rejected credentials or a push being refused
+fn is_transient(e: &Error) -> bool {
+	match e {
+		Error::Io(e) => matches!(
+			e.kind(),
+			io::ErrorKind::TimedOut
+				| io::ErrorKind::ConnectionReset
+				| io::ErrorKind::ConnectionAborted
+				| io::ErrorKind::BrokenPipe
+				| io::ErrorKind::UnexpectedEof
+		),
+		Error::Git(e) => {
+			matches!(
+				e.class(),
+				ErrorClass::Net
+					| ErrorClass::Os
+					| ErrorClass::Ssl
+					| ErrorClass::Ssh
+					| ErrorClass::Http
+					| ErrorClass::Callback
+			) && is_transient_msg(e.message())
+		}
+		_ => false,
+	}
+}
+
+fn is_transient_msg(msg: &str) -> bool {
+	const TRANSIENT: [&str; 11] = [
+		"timed out",
+		"timeout",
+		"connection reset",
+		"reset by peer",
+		"connection aborted",
+		"broken pipe",
+		"early eof",
+		"unexpected eof",
+		"status code: 502",
+		"status code: 503",
+		"status code: 504",
+	];
+
+	let msg = msg.to_lowercase();
+	TRANSIENT.iter().any(|transient| msg.contains(transient))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crossbeam_channel::unbounded;
+
+	fn transient_error() -> Error {
+		Error::Git(git2::Error::new(
+			git2::ErrorCode::GenericError,
+			ErrorClass::Net,
+			"failed to connect to host: Connection timed out",
+		))
+	}
+
+	#[test]
+	fn test_is_transient() {
+		assert!(is_transient(&transient_error()));
+		assert!(is_transient(&Error::Io(io::Error::from(
+			io::ErrorKind::ConnectionReset
+		))));
+		assert!(is_transient(&Error::Git(git2::Error::new(
+			git2::ErrorCode::GenericError,
+			ErrorClass::Http,
+			"unexpected http status code: 503",
+		))));
+
+		assert!(!is_transient(&Error::Git(git2::Error::new(
+			git2::ErrorCode::Auth,
+			ErrorClass::Http,
+			"too many redirects or authentication replays",
+		))));
+		assert!(!is_transient(&Error::Git(git2::Error::new(
+			git2::ErrorCode::GenericError,
+			ErrorClass::Reference,
+			"timed out",
+		))));
+		assert!(!is_transient(&Error::Generic(String::from(
+			"push rejected"
+		))));
+	}
+
+	#[test]
+	fn test_retry_until_success() {
+		let (sender, receiver) = unbounded();
+		let mut calls = 0;
+
+		let res = retry_with_backoff(
+			3,
+			Duration::ZERO,
+			Some(&sender),
+			|| {
+				calls += 1;
+				if calls < 3 {
+					Err(transient_error())
+				} else {
+					Ok(calls)
+				}
+			},
+		);
+
+		assert_eq!(res.unwrap(), 3);
+		assert_eq!(
+			receiver.try_iter().collect::<Vec<_>>(),
+			vec![
+				ProgressNotification::Retry(Retry {
+					attempt: 1,
+					max: 3
+				}),
+				ProgressNotification::Retry(Retry {
+					attempt: 2,
+					max: 3
+				}),
+			]
+		);
+	}
+
+	#[test]
+	fn test_retry_gives_up() {
+		let mut calls = 0;
+		let res: Result<()> =
+			retry_with_backoff(2, Duration::ZERO, None, || {
+				calls += 1;
+				Err(transient_error())
+			});
+		assert!(res.is_err());
+		assert_eq!(calls, 3);
+
+		let mut calls = 0;
+		let res: Result<()> =
+			retry_with_backoff(2, Duration::ZERO, None, || {
+				calls += 1;
+				Err(Error::Generic(String::from("rejected")))
+			});
+		assert!(res.is_err());
+		assert_eq!(calls, 1);
+	}
+}
@@ -0,0 +1,125 @@
+//! shallow clones: finding where their history ends and fetching more
+
+use crate::{
+	error::{Error, Result},
+	sync::{repository::repo, CommitId, RepoPath},
+};
+use git2::{Oid, Repository};
+use scopetime::scope_time;
+use std::{
+	collections::HashSet,
+	fs,
+	path::PathBuf,
+	process::{Command, Stdio},
+};
+
+/// linked worktrees keep the `shallow` file in the main repository
+fn common_dir(repo: &Repository) -> PathBuf {
+	let path = repo.path();
+	fs::read_to_string(path.join("commondir")).map_or_else(
+		|_| path.to_path_buf(),
+		|dir| path.join(dir.trim()),
+	)
+}
+
+/// commits whose parents were not fetched, empty unless `repo_path`
+/// is a shallow clone
+pub fn shallow_commits(
+	repo_path: &RepoPath,
+) -> Result<HashSet<CommitId>> {
+	scope_time!("shallow_commits");
+
+	let repo = repo(repo_path)?;
+	if !repo.is_shallow() {
+		return Ok(HashSet::new());
+	}
+
+	let content =
+		fs::read_to_string(common_dir(&repo).join("shallow"))?;
+
+	Ok(content
+		.lines()
+		.filter_map(|line| Oid::from_str(line.trim()).ok())
+		.map(CommitId::new)
+		.collect())
+}
+
+/// fetches `depth` more commits of history from `remote`,
+/// `None` fetches all of it. libgit2 cannot deepen a shallow
+/// clone so this runs `git fetch`.
+pub fn deepen(
+	repo_path: &RepoPath,
+	remote: &str,
+	depth: Option<u32>,
+) -> Result<()> {
+	scope_time!("deepen");
+
+	let depth = depth.map_or_else(
+		|| String::from("--unshallow"),
+		|depth| format!("--deepen={}", depth),
+	);
+
+	let output = Command::new("git")
+		.args(["fetch", &depth, remote])
+		.current_dir(repo_path.gitpath())
+		.env("GIT_TERMINAL_PROMPT", "0")
+		.stdin(Stdio::null())
+		.output()?;
+
+	if !output.status.success() {
+		return Err(Error::Generic(format!(
+			"git fetch {} failed: {}",
+			depth,
+			String::from_utf8_lossy(&output.stderr).trim()
+		)));
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::tests::{
+		debug_cmd_print, repo_init, write_commit_file,
+	};
+	use tempfile::TempDir;
+
+	#[test]
+	fn test_deepen_shallow_clone() {
+		let (remote_dir, remote) = repo_init().unwrap();
+		write_commit_file(&remote, "f.txt", "1", "c1");
+		let c2 = write_commit_file(&remote, "f.txt", "2", "c2");
+		let c3 = write_commit_file(&remote, "f.txt", "3", "c3");
+
+		let td = TempDir::new().unwrap();
+		let clone_path = td.path().join("clone");
+		let parent_path: &RepoPath =
+			&td.path().to_str().unwrap().into();
+		debug_cmd_print(
+			parent_path,
+			&format!(
+				"git clone --depth 1 file://{} clone",
+				remote_dir.path().to_str().unwrap()
+			),
+		);
+		let repo_path: &RepoPath =
+			&clone_path.to_str().unwrap().into();
+
+		assert_eq!(
+			shallow_commits(repo_path).unwrap(),
+			HashSet::from([c3])
+		);
+
+		deepen(repo_path, "origin", Some(1)).unwrap();
+
+		assert_eq!(
+			shallow_commits(repo_path).unwrap(),
+			HashSet::from([c2])
+		);
+
+		deepen(repo_path, "origin", None).unwrap();
+
+		assert!(shallow_commits(repo_path).unwrap().is_empty());
+	}
+}
@@ -0,0 +1,457 @@
+//! ssh transport running the `ssh` executable, so `~/.ssh/config`
+//! (host aliases, `IdentityFile`, `Port`, `User`, `ProxyJump`..)
+//! applies exactly like it does for git on the command line
+
+use crate::{
+	error::{Error, Result},
+	sync::{repository::repo, RepoPath},
+};
+use git2::{
+	transport::{
+		self, Service, SmartSubtransport, SmartSubtransportStream,
+		Transport,
+	},
+	Config, Repository,
+};
+use std::{
+	cell::RefCell,
+	env,
+	io::{self, Read, Write},
+	path::Path,
+	process::{Child, ChildStdin, ChildStdout, Command, Stdio},
+	sync::Once,
+	thread::{self, JoinHandle},
+};
+
+/// set to `false` to keep the libssh2 transport of libgit2
+const TRANSPORT_KEY: &str = "gitui.sshTransport";
+
+/// replaces the libssh2 transport of libgit2 for `ssh://` and scp like
+/// `user@host:path` urls. only the first call registers anything and it
+/// has to happen before any remote is connected to.
+///
+/// libssh2 stays in place if `gitui.sshTransport` is `false` or no ssh
+/// program is configured or found on `PATH`
+pub fn register_ssh_transport(repo_path: &RepoPath) -> Result<()> {
+	static REGISTER: Once = Once::new();
+
+	if !use_ssh_executable(repo_path) {
+		log::info!("ssh transport: keeping libssh2");
+		return Ok(());
+	}
+
+	let mut res = Ok(());
+	REGISTER.call_once(|| {
+		for scheme in ["ssh", "ssh+git", "git+ssh"] {
+			// SAFETY: `Once` keeps this from racing with itself and
+			// nothing connects to a remote before the app registers it
+			let registered = unsafe {
+				transport::register(scheme, |remote| {
+					Transport::smart(remote, false, OpenSsh)
+				})
+			};
+
+			if let Err(e) = registered {
+				res = Err(e.into());
+				return;
+			}
+		}
+	});
+
+	res
+}
+
+fn use_ssh_executable(repo_path: &RepoPath) -> bool {
+	let config = repo(repo_path)
+		.and_then(|repo| Ok(repo.config()?))
+		.or_else(|_| Config::open_default())
+		.ok();
+
+	let enabled = config
+		.as_ref()
+		.and_then(|config| config.get_bool(TRANSPORT_KEY).ok())
+		.unwrap_or(true);
+
+	let configured = env::var_os("GIT_SSH_COMMAND").is_some()
+		|| env::var_os("GIT_SSH").is_some()
+		|| config.map_or(false, |config| {
+			config.get_string(SSH_COMMAND_KEY).is_ok()
+		});
+
+	enabled && (configured || ssh_in_path())
+}
+
+fn ssh_in_path() -> bool {
+	env::var_os("PATH").map_or(false, |paths| {
+		env::split_paths(&paths).any(|dir| {
+			dir.join("ssh").is_file() || dir.join("ssh.exe").is_file()
+		})
+	})
+}
+
+const SSH_COMMAND_KEY: &str = "core.sshCommand";
+
+thread_local! {
+	/// config of the repository a remote is connected to on this
+	/// thread, libgit2 hands nothing but the remote to the transport
+	static REPO_CONFIG: RefCell<Option<Config>> = RefCell::new(None);
+}
+
+/// has the transport read `core.sshCommand` from `repo`, including
+/// its local config, for connections made on this thread
+pub fn use_repo_config(repo: &Repository) {
+	let config = repo.config().ok();
+	REPO_CONFIG.with(|repo_config| repo_config.replace(config));
+}
+
+/// `core.sshCommand` of the repository connected to, the global one
+/// if there is none
+fn configured_ssh_command() -> Option<String> {
+	REPO_CONFIG.with(|repo_config| {
+		repo_config
+			.borrow()
+			.as_ref()
+			.map_or_else(
+				|| {
+					Config::open_default().and_then(|config| {
+						config.get_string(SSH_COMMAND_KEY)
+					})
+				},
+				|config| config.get_string(SSH_COMMAND_KEY),
+			)
+			.ok()
+	})
+}
+
+struct OpenSsh;
+
+impl SmartSubtransport for OpenSsh {
+	fn action(
+		&self,
+		url: &str,
+		action: Service,
+	) -> std::result::Result<
+		Box<dyn SmartSubtransportStream>,
+		git2::Error,
+	> {
+		let service = match action {
+			Service::UploadPackLs | Service::UploadPack => {
+				"git-upload-pack"
+			}
+			Service::ReceivePackLs | Service::ReceivePack => {
+				"git-receive-pack"
+			}
+		};
+
+		let stream = SshUrl::parse(url)
+			.and_then(|url| SshStream::spawn(&url, service))
+			.map_err(|e| git2::Error::from_str(&e.to_string()))?;
+
+		Ok(Box::new(stream))
+	}
+
+	fn close(&self) -> std::result::Result<(), git2::Error> {
+		Ok(())
+	}
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct SshUrl {
+	/// `user@host` or just `host`
+	host: String,
+	port: Option<String>,
+	path: String,
+}
+
+impl SshUrl {
+	fn parse(url: &str) -> Result<Self> {
+		let invalid =
+			|| Error::Generic(format!("invalid ssh url: {}", url));
+
+		if let Some((_, rest)) = url.split_once("://") {
+			let (authority, path) = rest
+				.find('/')
+				.map(|idx| rest.split_at(idx))
+				.ok_or_else(invalid)?;
+
+			let (host, port) = match authority.rsplit_once(':') {
+				Some((host, port)) if !port.contains(']') => {
+					(host, Some(port.to_string()))
+				}
+				_ => (authority, None),
+			};
+
+			// `ssh://host/~user/repo` is relative to that home
+			let path = match path.strip_prefix('/') {
+				Some(home) if home.starts_with('~') => home,
+				_ => path,
+			};
+
+			Ok(Self {
+				host: host.replace(['[', ']'], ""),
+				port,
+				path: path.to_string(),
+			})
+		} else {
+			let (host, path) = if url.contains('[') {
+				let (host, path) =
+					url.split_once("]:").ok_or_else(invalid)?;
+				(host.replace('[', ""), path)
+			} else {
+				let (host, path) =
+					url.split_once(':').ok_or_else(invalid)?;
+				(host.to_string(), path)
+			};
+
+			Ok(Self {
+				host,
+				port: None,
+				path: path.to_string(),
+			})
+		}
+	}
+
+	/// the remote command line, quoted like git does
+	fn command(&self, service: &str) -> String {
+		format!(
+			"{} '{}'",
+			service,
+			self.path.replace('\'', "'\\''").replace('!', "'\\!'")
+		)
+	}
+}
+
+/// `GIT_SSH_COMMAND`, `core.sshCommand` or `GIT_SSH` like git,
+/// defaulting to `ssh`. prompts would hang since we own the terminal,
+/// so they are turned off for whatever program ends up running
+fn ssh_command() -> Command {
+	let configured = env::var("GIT_SSH_COMMAND")
+		.ok()
+		.or_else(configured_ssh_command);
+
+	let (mut cmd, program) = configured.map_or_else(
+		|| {
+			let program =
+				env::var("GIT_SSH").unwrap_or_else(|_| "ssh".into());
+			(Command::new(&program), program)
+		},
+		|configured| shell_command(&configured),
+	);
+
+	cmd.env("SSH_ASKPASS_REQUIRE", "never")
+		.env("GIT_TERMINAL_PROMPT", "0");
+
+	if is_openssh(&program) {
+		cmd.args(["-o", "BatchMode=yes"]);
+	}
+
+	cmd
+}
+
+/// runs `configured` through the shell like git, for quoting, paths
+/// with spaces and variables, the ssh arguments follow as `$@`.
+/// returns the program it names too
+fn shell_command(configured: &str) -> (Command, String) {
+	let program = configured
+		.split_whitespace()
+		.next()
+		.unwrap_or_default()
+		.trim_matches(|c| c == '"' || c == '\'')
+		.to_string();
+
+	let mut cmd = Command::new("sh");
+	cmd.arg("-c")
+		.arg(format!("{} \"$@\"", configured))
+		.arg(configured);
+
+	(cmd, program)
+}
+
+/// only OpenSSH is known to take `-o BatchMode=yes`, unlike `plink`
+fn is_openssh(program: &str) -> bool {
+	Path::new(program)
+		.file_stem()
+		.map_or(false, |name| name == "ssh")
+}
+
+struct SshStream {
+	child: Child,
+	stdin: ChildStdin,
+	stdout: ChildStdout,
+	stderr: Option<JoinHandle<String>>,
+}
+
+impl SshStream {
+	fn spawn(url: &SshUrl, service: &str) -> Result<Self> {
+		let mut cmd = ssh_command();
+		if let Some(port) = &url.port {
+			cmd.args(["-p", port]);
+		}
+		cmd.arg(&url.host).arg(url.command(service));
+
+		log::debug!("ssh transport: {:?}", cmd);
+
+		let mut child = cmd
+			.stdin(Stdio::piped())
+			.stdout(Stdio::piped())
+			.stderr(Stdio::piped())
+			.spawn()
+			.map_err(|e| {
+				Error::Generic(format!("failed to run ssh: {}", e))
+			})?;
+
+		let stdin = child.stdin.take().ok_or_else(|| {
+			Error::Generic(String::from("ssh stdin missing"))
+		})?;
+		let stdout = child.stdout.take().ok_or_else(|| {
+			Error::Generic(String::from("ssh stdout missing"))
+		})?;
+		let stderr = child.stderr.take().map(|mut stderr| {
+			thread::spawn(move || {
+				let mut msg = String::new();
+				stderr.read_to_string(&mut msg).ok();
+				msg
+			})
+		});
+
+		Ok(Self {
+			child,
+			stdin,
+			stdout,
+			stderr,
+		})
+	}
+
+	/// what ssh complained about once it is gone, `None` if it
+	/// succeeded. warnings like newly known hosts or server banners
+	/// are printed on success too, so they are only logged then
+	fn error(&mut self) -> Option<String> {
+		let status = self.child.wait().ok();
+
+		let msg = self
+			.stderr
+			.take()
+			.and_then(|handle| handle.join().ok())
+			.map(|msg| msg.trim().to_string())
+			.filter(|msg| !msg.is_empty());
+
+		match status {
+			Some(status) if status.success() => {
+				if let Some(msg) = msg {
+					log::debug!("ssh: {}", msg);
+				}
+				None
+			}
+			Some(status) => msg
+				.or_else(|| Some(format!("ssh failed: {}", status))),
+			None => msg,
+		}
+	}
+}
+
+impl Read for SshStream {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		let read = self.stdout.read(buf)?;
+
+		if read == 0 && !buf.is_empty() {
+			if let Some(msg) = self.error() {
+				return Err(io::Error::new(
+					io::ErrorKind::Other,
+					msg,
+				));
+			}
+		}
+
+		Ok(read)
+	}
+}
+
+impl Write for SshStream {
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+		self.stdin.write(buf)
+	}
+
+	fn flush(&mut self) -> io::Result<()> {
+		self.stdin.flush()
+	}
+}
+
+impl Drop for SshStream {
+	fn drop(&mut self) {
+		if matches!(self.child.try_wait(), Ok(None)) {
+			self.child.kill().ok();
+		}
+		self.child.wait().ok();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::tests::repo_init;
+
+	#[test]
+	fn test_parse_ssh_url() {
+		assert_eq!(
+			SshUrl::parse("git@work-github:org/repo.git").unwrap(),
+			SshUrl {
+				host: String::from("git@work-github"),
+				port: None,
+				path: String::from("org/repo.git"),
+			}
+		);
+		assert_eq!(
+			SshUrl::parse("ssh://git@host:2222/org/repo.git")
+				.unwrap(),
+			SshUrl {
+				host: String::from("git@host"),
+				port: Some(String::from("2222")),
+				path: String::from("/org/repo.git"),
+			}
+		);
+		assert_eq!(
+			SshUrl::parse("git+ssh://[::1]/~me/repo").unwrap(),
+			SshUrl {
+				host: String::from("::1"),
+				port: None,
+				path: String::from("~me/repo"),
+			}
+		);
+		assert_eq!(
+			SshUrl::parse("git@[::1]:repo").unwrap().host,
+			"git@::1"
+		);
+		assert!(SshUrl::parse("ssh://host").is_err());
+
+		assert_eq!(
+			SshUrl::parse("host:it's")
+				.unwrap()
+				.command("git-upload-pack"),
+			"git-upload-pack 'it'\\''s'"
+		);
+	}
+
+	#[test]
+	fn test_is_openssh() {
+		assert!(is_openssh("ssh"));
+		assert!(is_openssh("/usr/bin/ssh"));
+		assert!(!is_openssh("plink"));
+		assert!(!is_openssh("/usr/bin/ssh-wrapper"));
+	}
+
+	#[test]
+	fn test_repo_local_ssh_command() {
+		let (_td, repo) = repo_init().unwrap();
+		repo.config()
+			.unwrap()
+			.set_str("core.sshCommand", "ssh -i 'my key'")
+			.unwrap();
+
+		use_repo_config(&repo);
+
+		assert_eq!(
+			configured_ssh_command().as_deref(),
+			Some("ssh -i 'my key'")
+		);
+	}
+}
@@ -6,13 +6,13 @@ use crate::{
 	progress::ProgressPercent,
 	sync::{
 		cred::BasicAuthCredential,
-		remotes::{proxy_auto, Callbacks},
+		remotes::{find_remote, proxy_options, Callbacks},
 		repository::repo,
 		RepoPath,
 	},
 };
 use crossbeam_channel::Sender;
-use git2::{Direction, PushOptions};
+use git2::{BranchType, Direction, PushOptions, Repository};
 use scopetime::scope_time;
 use std::collections::HashSet;
 
@@ -56,12 +56,13 @@ fn remote_tag_refs(
 	scope_time!("remote_tags");
 
 	let repo = repo(repo_path)?;
-	let mut remote = repo.find_remote(remote)?;
+	let mut remote = find_remote(&repo, remote)?;
 	let callbacks = Callbacks::new(None, basic_credential);
+	let proxy = proxy_options(&repo, &remote, Direction::Fetch);
 	let conn = remote.connect_auth(
 		Direction::Fetch,
 		Some(callbacks.callbacks()),
-		Some(proxy_auto()),
+		Some(proxy),
 	)?;
 
 	let remote_heads = conn.list()?;
@@ -101,6 +102,52 @@ pub fn tags_missing_remote(
 	Ok(local_tags.into_iter().collect())
 }
 
+/// annotated tags missing on `remote` that point at a commit
+/// reachable from the local `branch` (see `git push --follow-tags`)
+pub fn follow_tags_missing_remote(
+	repo: &Repository,
+	repo_path: &RepoPath,
+	remote: &str,
+	branch: &str,
+	basic_credential: Option<BasicAuthCredential>,
+) -> Result<Vec<String>> {
+	scope_time!("follow_tags_missing_remote");
+
+	let head = repo
+		.find_branch(branch, BranchType::Local)?
+		.get()
+		.peel_to_commit()?
+		.id();
+
+	let mut tags = Vec::new();
+	for name in repo.tag_names(None)?.iter().flatten() {
+		let name = format!("refs/tags/{}", name);
+
+		// lightweight tags do not peel to a tag object
+		let target = match repo
+			.find_reference(&name)
+			.and_then(|r| r.peel_to_tag())
+			.and_then(|tag| tag.target())
+			.and_then(|target| target.peel_to_commit())
+		{
+			Ok(commit) => commit.id(),
+			Err(_) => continue,
+		};
+
+		if target == head || repo.graph_descendant_of(head, target)? {
+			tags.push(name);
+		}
+	}
+
+	if !tags.is_empty() {
+		let remote_tags =
+			remote_tag_refs(repo_path, remote, basic_credential)?;
+		tags.retain(|tag| !remote_tags.contains(tag));
+	}
+
+	Ok(tags)
+}
+
 ///
 pub fn push_tags(
 	repo_path: &RepoPath,
@@ -121,7 +168,7 @@ pub fn push_tags(
 	)?;
 
 	let repo = repo(repo_path)?;
-	let mut remote = repo.find_remote(remote)?;
+	let mut remote = find_remote(&repo, remote)?;
 
 	let total = tags_missing.len();
 
@@ -135,8 +182,14 @@ pub fn push_tags(
 			Callbacks::new(None, basic_credential.clone());
 		options.remote_callbacks(callbacks.callbacks());
 		options.packbuilder_parallelism(0);
-		options.proxy_options(proxy_auto());
-		remote.push(&[tag.as_str()], Some(&mut options))?;
+		options.proxy_options(proxy_options(
+			&repo,
+			&remote,
+			Direction::Push,
+		));
+		let res = remote.push(&[tag.as_str()], Some(&mut options));
+		callbacks.settle_credential(repo_path, &res);
+		res?;
 
 		progress_sender.as_ref().map(|sender| {
 			sender.send(PushTagsProgress::Push {
@@ -365,13 +418,64 @@ mod tests {
 
 		// clone 2 - pull
 
-		fetch_all(clone2_dir, &None, &None).unwrap();
+		fetch_all(clone2_dir, true, 0, &None, &None).unwrap();
 
 		let tags2 = sync::get_tags(clone2_dir).unwrap();
 
 		assert_eq!(tags1, tags2);
 	}
 
+	#[test]
+	fn test_push_follow_tags() {
+		let (r1_dir, _repo) = repo_init_bare().unwrap();
+		let r1_dir = r1_dir.path().to_str().unwrap();
+
+		let (clone1_dir, clone1) = repo_clone(r1_dir).unwrap();
+		let clone1_dir: &RepoPath =
+			&clone1_dir.path().to_str().unwrap().into();
+
+		let commit1 =
+			write_commit_file(&clone1, "test.txt", "test", "commit1");
+		push_branch(
+			clone1_dir, "origin", "master", false, false, None, None,
+		)
+		.unwrap();
+
+		let commit2 = write_commit_file(
+			&clone1, "test.txt", "test2", "commit2",
+		);
+
+		sync::tag_commit(clone1_dir, &commit1, "v1", Some("v1"))
+			.unwrap();
+		sync::tag_commit(clone1_dir, &commit2, "v2", Some("v2"))
+			.unwrap();
+		sync::tag_commit(clone1_dir, &commit2, "light", None)
+			.unwrap();
+
+		push_raw(
+			clone1_dir,
+			"origin",
+			"master",
+			None,
+			PushType::Branch,
+			false,
+			false,
+			false,
+			true,
+			None,
+			None,
+		)
+		.unwrap();
+
+		// only annotated tags follow the branch
+		let tags_missing =
+			tags_missing_remote(clone1_dir, "origin", None).unwrap();
+		assert_eq!(
+			tags_missing,
+			vec![String::from("refs/tags/light")]
+		);
+	}
+
 	#[test]
 	fn test_tags_delete_remote() {
 		let (r1_dir, _repo) = repo_init_bare().unwrap();
@@ -399,7 +503,7 @@ mod tests {
 
 		// clone 2 - pull
 
-		fetch_all(clone2_dir, &None, &None).unwrap();
+		fetch_all(clone2_dir, true, 0, &None, &None).unwrap();
 		assert_eq!(sync::get_tags(clone2_dir).unwrap().len(), 1);
 
 		// delete on clone 1
@@ -410,9 +514,12 @@ mod tests {
 			clone1_dir,
 			"origin",
 			"tag1",
+			None,
 			PushType::Tag,
 			false,
 			true,
+			false,
+			false,
 			None,
 			None,
 		)
@@ -422,7 +529,7 @@ mod tests {
 
 		// clone 2
 
-		fetch_all(clone2_dir, &None, &None).unwrap();
+		fetch_all(clone2_dir, true, 0, &None, &None).unwrap();
 		assert_eq!(sync::get_tags(clone2_dir).unwrap().len(), 0);
 	}
 }
@@ -1,7 +1,11 @@
-use super::{utils::get_head_repo, RepoPath};
-use crate::{error::Result, sync::repository::repo};
+use super::{utils::get_head_repo, CommitId, RepoPath};
+use crate::{
+	error::{Error, Result},
+	sync::repository::repo,
+};
 use git2::{build::CheckoutBuilder, ObjectType};
 use scopetime::scope_time;
+use std::path::Path;
 
 ///
 pub fn reset_stage(repo_path: &RepoPath, path: &str) -> Result<()> {
@@ -38,9 +42,46 @@ pub fn reset_workdir(repo_path: &RepoPath, path: &str) -> Result<()> {
 	Ok(())
 }
 
+/// restores `path` to its version in `commit_id`, either in the
+/// workdir leaving the stage alone (`git restore --source`) or in the
+/// stage leaving the workdir alone (`git restore --staged --source`)
+pub fn restore_file(
+	repo_path: &RepoPath,
+	commit_id: CommitId,
+	path: &str,
+	stage: bool,
+) -> Result<()> {
+	scope_time!("restore_file");
+
+	let repo = repo(repo_path)?;
+	let commit = repo.find_commit(commit_id.into())?;
+
+	if commit.tree()?.get_path(Path::new(path)).is_err() {
+		return Err(Error::Generic(format!(
+			"'{}' does not exist in {}",
+			path,
+			commit_id.get_short_string()
+		)));
+	}
+
+	if stage {
+		repo.reset_default(Some(commit.as_object()), &[path])?;
+	} else {
+		let mut checkout_opts = CheckoutBuilder::new();
+		checkout_opts.update_index(false).force().path(path);
+
+		repo.checkout_tree(
+			commit.as_object(),
+			Some(&mut checkout_opts),
+		)?;
+	}
+
+	Ok(())
+}
+
 #[cfg(test)]
 mod tests {
-	use super::{reset_stage, reset_workdir};
+	use super::{reset_stage, reset_workdir, restore_file};
 	use crate::error::Result;
 	use crate::sync::{
 		commit,
@@ -319,4 +360,33 @@ mod tests {
 
 		assert_eq!(get_statuses(repo_path), (0, 0));
 	}
+
+	#[test]
+	fn test_restore_file() -> Result<()> {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		File::create(&root.join("foo"))?.write_all(b"a")?;
+		stage_add_file(repo_path, Path::new("foo"))?;
+		let first = commit(repo_path, "first")?;
+
+		File::create(&root.join("foo"))?.write_all(b"b")?;
+		stage_add_file(repo_path, Path::new("foo"))?;
+		commit(repo_path, "second")?;
+
+		restore_file(repo_path, first, "foo", false)?;
+
+		assert_eq!(fs::read_to_string(root.join("foo"))?, "a");
+		assert_eq!(get_statuses(repo_path), (1, 0));
+
+		restore_file(repo_path, first, "foo", true)?;
+
+		assert_eq!(get_statuses(repo_path), (0, 1));
+
+		assert!(restore_file(repo_path, first, "bar", false).is_err());
+
+		Ok(())
+	}
 }
@@ -0,0 +1,768 @@
+//! Sign commit data.
+
+use std::path::{Path, PathBuf};
+use tempfile::NamedTempFile;
+
+/// Error type for [`SignBuilder`], used to create [`Sign`]'s
+#[derive(thiserror::Error, Debug)]
+pub enum SignBuilderError {
+	/// The given format is invalid
+	#[error("Failed to derive a commit signing method from git configuration 'gpg.format': {0}")]
+	InvalidFormat(String),
+
+	/// The GPG signing key could not be retrieved
+	#[error("Failed to retrieve 'user.signingkey' from the git configuration: {0}")]
+	GPGSigningKey(String),
+
+	/// The SSH signing key could not be retrieved
+	#[error("Failed to retrieve 'user.signingkey' from the git configuration: {0}")]
+	SSHSigningKey(String),
+
+	/// No signing signature could be built from the configuration data present
+	#[error("Failed to build signing signature: {0}")]
+	Signature(String),
+
+	/// Failure on unimplemented signing methods
+	/// to be removed once all methods have been implemented
+	#[error("Select signing method '{0}' has not been implemented")]
+	MethodNotImplemented(String),
+}
+
+/// Error type for [`Sign`], used to sign data
+#[derive(thiserror::Error, Debug)]
+pub enum SignError {
+	/// Unable to spawn process
+	#[error("Failed to spawn signing process: {0}")]
+	Spawn(String),
+
+	/// Unable to acquire the child process' standard input to write the commit data for signing
+	#[error("Failed to acquire standard input handler")]
+	Stdin,
+
+	/// Unable to write commit data to sign to standard input of the child process
+	#[error("Failed to write buffer to standard input of signing process: {0}")]
+	WriteBuffer(String),
+
+	/// Unable to retrieve the signed data from the child process
+	#[error("Failed to get output of signing process call: {0}")]
+	Output(String),
+
+	/// Failure of the child process
+	#[error("Failed to execute signing process: {0}")]
+	Shellout(String),
+
+	/// The configured ssh signing key is neither a literal key nor an existing file
+	#[error("Failed to find ssh signing key file: {0}")]
+	KeyFile(String),
+}
+
+/// Sign commit data using various methods
+pub trait Sign {
+	/// Sign commit with the respective implementation.
+	///
+	/// Retrieve an implementation using [`SignBuilder::from_gitconfig`].
+	///
+	/// The `commit` buffer can be created using the following steps:
+	/// - create a buffer using [`git2::Repository::commit_create_buffer`]
+	///
+	/// The function returns a tuple of `signature` and `signature_field`.
+	/// These values can then be passed into [`git2::Repository::commit_signed`].
+	/// Finally, the repository head needs to be advanced to the resulting commit ID
+	/// using [`git2::Reference::set_target`].
+	fn sign(
+		&self,
+		commit: &[u8],
+	) -> Result<(String, Option<String>), SignError>;
+
+	/// only available in `#[cfg(test)]` helping to diagnose issues
+	#[cfg(test)]
+	fn program(&self) -> &String;
+
+	/// only available in `#[cfg(test)]` helping to diagnose issues
+	#[cfg(test)]
+	fn signing_key(&self) -> &String;
+}
+
+/// A builder to facilitate the creation of a signing method ([`Sign`]) by examining the git configuration.
+pub struct SignBuilder;
+
+impl SignBuilder {
+	/// Get a [`Sign`] from the given repository configuration to sign commit data
+	///
+	///
+	/// ```no_run
+	/// use asyncgit::sync::sign::SignBuilder;
+	/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+	///
+	/// /// Repo in a temporary directory for demonstration
+	/// let dir = std::env::temp_dir();
+	/// let repo = git2::Repository::init(dir)?;
+	///
+	/// /// Get the config from the repository
+	/// let config = repo.config()?;
+	///
+	/// /// Retrieve a `Sign` implementation
+	/// let sign = SignBuilder::from_gitconfig(&repo, &config)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn from_gitconfig(
+		repo: &git2::Repository,
+		config: &git2::Config,
+	) -> Result<Box<dyn Sign>, SignBuilderError> {
+		let format = config
+			.get_string("gpg.format")
+			.unwrap_or_else(|_| "openpgp".to_string());
+
+		// Variants are described in the git config documentation
+		// https://git-scm.com/docs/git-config#Documentation/git-config.txt-gpgformat
+		match format.as_str() {
+			"openpgp" => {
+				// Try to retrieve the gpg program from the git configuration,
+				// moving from the least to the most specific config key,
+				// defaulting to "gpg" if nothing is explicitly defined (per git's implementation)
+				// https://git-scm.com/docs/git-config#Documentation/git-config.txt-gpgprogram
+				let program = config
+					.get_string("gpg.openpgp.program")
+					.or_else(|_| config.get_string("gpg.program"))
+					.unwrap_or_else(|_| "gpg".to_string());
+
+				// Optional signing key.
+				// If 'user.signingKey' is not set, we'll use 'user.name' and 'user.email'
+				// to build a default signature in the format 'name <email>'.
+				// https://git-scm.com/docs/git-config#Documentation/git-config.txt-usersigningKey
+				let signing_key = config
+					.get_string("user.signingKey")
+					.or_else(
+						|_| -> Result<String, SignBuilderError> {
+							Ok(crate::sync::commit::signature_allow_undefined_name(repo)
+								.map_err(|err| {
+									SignBuilderError::Signature(
+										err.to_string(),
+									)
+								})?
+								.to_string())
+						},
+					)
+					.map_err(|err| {
+						SignBuilderError::GPGSigningKey(
+							err.to_string(),
+						)
+					})?;
+
+				Ok(Box::new(GPGSign {
+					program,
+					signing_key,
+				}))
+			}
+			"x509" => Err(SignBuilderError::MethodNotImplemented(
+				String::from("x509"),
+			)),
+			"ssh" => {
+				let program = config
+					.get_string("gpg.ssh.program")
+					.unwrap_or_else(|_| "ssh-keygen".to_string());
+
+				let signing_key = config
+					.get_string("user.signingKey")
+					.map_err(|err| {
+						SignBuilderError::SSHSigningKey(
+							err.to_string(),
+						)
+					})
+					.and_then(|signing_key| {
+						Self::ssh_key_path(&signing_key)
+					})?;
+
+				Ok(Box::new(SSHSign {
+					program,
+					signing_key,
+				}))
+			}
+			_ => Err(SignBuilderError::InvalidFormat(format)),
+		}
+	}
+
+	/// `user.signingKey` may either be a path to a key file or
+	/// a literal public key (starting with `ssh-` or prefixed with
+	/// `key::`), in which case the matching private key is looked up
+	/// by the ssh agent.
+	fn ssh_key_path(
+		signing_key: &str,
+	) -> Result<String, SignBuilderError> {
+		if literal_ssh_key(signing_key).is_some() {
+			return Ok(signing_key.to_string());
+		}
+
+		let expanded = shellexpand::tilde(signing_key);
+		let path = PathBuf::from(expanded.as_ref());
+
+		path.to_str().map(ToString::to_string).ok_or_else(|| {
+			SignBuilderError::SSHSigningKey(String::from(
+				"ssh key path is not valid unicode",
+			))
+		})
+	}
+}
+
+/// the public key if `signing_key` is a literal one rather than a path
+fn literal_ssh_key(signing_key: &str) -> Option<&str> {
+	signing_key.strip_prefix("key::").or_else(|| {
+		signing_key.starts_with("ssh-").then(|| signing_key)
+	})
+}
+
+/// writes `content` into a new uniquely named temporary file, which
+/// is removed again once dropped
+fn temp_file(
+	prefix: &str,
+	suffix: &str,
+	content: &[u8],
+) -> Result<NamedTempFile, SignError> {
+	use std::io::Write;
+
+	let mut file = tempfile::Builder::new()
+		.prefix(prefix)
+		.suffix(suffix)
+		.tempfile()
+		.map_err(|e| SignError::WriteBuffer(e.to_string()))?;
+	file.write_all(content)
+		.map_err(|e| SignError::WriteBuffer(e.to_string()))?;
+
+	Ok(file)
+}
+
+/// Sign commit data using `OpenPGP`
+pub struct GPGSign {
+	program: String,
+	signing_key: String,
+}
+
+impl GPGSign {
+	/// Create new [`GPGSign`] using given program and signing key.
+	pub fn new(program: &str, signing_key: &str) -> Self {
+		Self {
+			program: program.to_string(),
+			signing_key: signing_key.to_string(),
+		}
+	}
+}
+
+impl Sign for GPGSign {
+	fn sign(
+		&self,
+		commit: &[u8],
+	) -> Result<(String, Option<String>), SignError> {
+		use std::io::Write;
+		use std::process::{Command, Stdio};
+
+		let mut cmd = Command::new(&self.program);
+		cmd.stdin(Stdio::piped())
+			.stdout(Stdio::piped())
+			.stderr(Stdio::piped())
+			.arg("--status-fd=2")
+			.arg("-bsau")
+			.arg(&self.signing_key);
+
+		log::trace!("signing command: {:?}", cmd);
+
+		let mut child = cmd
+			.spawn()
+			.map_err(|e| SignError::Spawn(e.to_string()))?;
+
+		let mut stdin = child.stdin.take().ok_or(SignError::Stdin)?;
+
+		stdin
+			.write_all(commit)
+			.map_err(|e| SignError::WriteBuffer(e.to_string()))?;
+		drop(stdin); // close stdin to not block indefinitely
+
+		let output = child
+			.wait_with_output()
+			.map_err(|e| SignError::Output(e.to_string()))?;
+
+		if !output.status.success() {
+			return Err(SignError::Shellout(format!(
+				"failed to sign data, program '{}' exited non-zero: {}",
+				&self.program,
+				std::str::from_utf8(&output.stderr)
+					.unwrap_or("[error could not be read from stderr]")
+			)));
+		}
+
+		let stderr = std::str::from_utf8(&output.stderr)
+			.map_err(|e| SignError::Shellout(e.to_string()))?;
+
+		if !stderr.contains("\n[GNUPG:] SIG_CREATED ") {
+			return Err(SignError::Shellout(
+				format!("failed to sign data, program '{}' failed, SIG_CREATED not seen in stderr", &self.program),
+			));
+		}
+
+		let signed_commit = std::str::from_utf8(&output.stdout)
+			.map_err(|e| SignError::Shellout(e.to_string()))?;
+
+		Ok((signed_commit.to_string(), Some("gpgsig".to_string())))
+	}
+
+	#[cfg(test)]
+	fn program(&self) -> &String {
+		&self.program
+	}
+
+	#[cfg(test)]
+	fn signing_key(&self) -> &String {
+		&self.signing_key
+	}
+}
+
+/// Sign commit data using `ssh-keygen -Y sign`
+pub struct SSHSign {
+	program: String,
+	signing_key: String,
+}
+
+impl SSHSign {
+	/// Create new [`SSHSign`] using given program and signing key.
+	pub fn new(program: &str, signing_key: &str) -> Self {
+		Self {
+			program: program.to_string(),
+			signing_key: signing_key.to_string(),
+		}
+	}
+
+	/// a literal key has to be handed to `ssh-keygen` via a file,
+	/// so it gets written into a temporary one, `None` if the signing
+	/// key already is a key file
+	fn key_file(&self) -> Result<Option<NamedTempFile>, SignError> {
+		if let Some(key) = literal_ssh_key(&self.signing_key) {
+			return temp_file(
+				"gitui-ssh-signing-",
+				".pub",
+				key.as_bytes(),
+			)
+			.map(Some);
+		}
+
+		if Path::new(&self.signing_key).is_file() {
+			Ok(None)
+		} else {
+			Err(SignError::KeyFile(self.signing_key.clone()))
+		}
+	}
+}
+
+impl Sign for SSHSign {
+	fn sign(
+		&self,
+		commit: &[u8],
+	) -> Result<(String, Option<String>), SignError> {
+		use std::io::Write;
+		use std::process::{Command, Stdio};
+
+		let literal_key = self.key_file()?;
+		let key_file = literal_key.as_ref().map_or_else(
+			|| Path::new(&self.signing_key),
+			NamedTempFile::path,
+		);
+
+		let mut cmd = Command::new(&self.program);
+		cmd.stdin(Stdio::piped())
+			.stdout(Stdio::piped())
+			.stderr(Stdio::piped())
+			.arg("-Y")
+			.arg("sign")
+			.arg("-n")
+			.arg("git")
+			.arg("-f")
+			.arg(key_file);
+
+		if literal_key.is_some() {
+			// key is only known to the ssh agent
+			cmd.arg("-U");
+		}
+
+		log::trace!("signing command: {:?}", cmd);
+
+		let mut child = cmd
+			.spawn()
+			.map_err(|e| SignError::Spawn(e.to_string()))?;
+
+		let mut stdin = child.stdin.take().ok_or(SignError::Stdin)?;
+
+		stdin
+			.write_all(commit)
+			.map_err(|e| SignError::WriteBuffer(e.to_string()))?;
+		drop(stdin);
+
+		let output = child
+			.wait_with_output()
+			.map_err(|e| SignError::Output(e.to_string()))?;
+
+		if !output.status.success() {
+			return Err(SignError::Shellout(format!(
+				"failed to sign data, program '{}' exited non-zero: {}",
+				&self.program,
+				std::str::from_utf8(&output.stderr)
+					.unwrap_or("[error could not be read from stderr]")
+			)));
+		}
+
+		let signed_commit = std::str::from_utf8(&output.stdout)
+			.map_err(|e| SignError::Shellout(e.to_string()))?;
+
+		Ok((signed_commit.to_string(), None))
+	}
+
+	#[cfg(test)]
+	fn program(&self) -> &String {
+		&self.program
+	}
+
+	#[cfg(test)]
+	fn signing_key(&self) -> &String {
+		&self.signing_key
+	}
+}
+
+/// Outcome of checking a signature with [`verify_signature`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verification {
+	/// the signature is valid, carries the signer as reported by the
+	/// signing program
+	Good(String),
+	/// the signature could not be verified, carries the reason
+	Bad(String),
+}
+
+/// Verify a detached `signature` over `data`, picking gpg or
+/// `ssh-keygen` (and their configured programs) by the kind of
+/// signature, like `git verify-tag` does.
+pub fn verify_signature(
+	config: &git2::Config,
+	data: &[u8],
+	signature: &str,
+) -> Result<Verification, SignError> {
+	let sig_file =
+		temp_file("gitui-verify-", ".sig", signature.as_bytes())?;
+
+	if signature.starts_with(SSH_SIGNATURE_HEADER) {
+		verify_ssh(config, data, sig_file.path())
+	} else {
+		verify_gpg(config, data, sig_file.path())
+	}
+}
+
+const SSH_SIGNATURE_HEADER: &str = "-----BEGIN SSH SIGNATURE-----";
+
+/// runs `cmd` with `data` on its standard input
+fn run_with_input(
+	cmd: &mut std::process::Command,
+	data: &[u8],
+) -> Result<std::process::Output, SignError> {
+	use std::io::Write;
+	use std::process::Stdio;
+
+	cmd.stdin(Stdio::piped())
+		.stdout(Stdio::piped())
+		.stderr(Stdio::piped());
+
+	log::trace!("verify command: {:?}", cmd);
+
+	let mut child =
+		cmd.spawn().map_err(|e| SignError::Spawn(e.to_string()))?;
+
+	let mut stdin = child.stdin.take().ok_or(SignError::Stdin)?;
+	stdin
+		.write_all(data)
+		.map_err(|e| SignError::WriteBuffer(e.to_string()))?;
+	drop(stdin);
+
+	child
+		.wait_with_output()
+		.map_err(|e| SignError::Output(e.to_string()))
+}
+
+fn verify_gpg(
+	config: &git2::Config,
+	data: &[u8],
+	sig_file: &std::path::Path,
+) -> Result<Verification, SignError> {
+	let program = config
+		.get_string("gpg.openpgp.program")
+		.or_else(|_| config.get_string("gpg.program"))
+		.unwrap_or_else(|_| "gpg".to_string());
+
+	let output = run_with_input(
+		std::process::Command::new(&program)
+			.arg("--status-fd=1")
+			.arg("--verify")
+			.arg(sig_file)
+			.arg("-"),
+		data,
+	)?;
+
+	let status = String::from_utf8_lossy(&output.stdout);
+
+	for line in status.lines() {
+		if let Some(signer) = line.strip_prefix("[GNUPG:] GOODSIG ") {
+			// `<long keyid> <user id>`
+			let signer =
+				signer.split_once(' ').map_or(signer, |(_, uid)| uid);
+			return Ok(Verification::Good(signer.to_string()));
+		}
+	}
+
+	let reason = status
+		.lines()
+		.filter_map(|line| line.strip_prefix("[GNUPG:] "))
+		.find(|line| {
+			["BADSIG", "EXPSIG", "EXPKEYSIG", "REVKEYSIG", "ERRSIG"]
+				.iter()
+				.any(|kind| line.starts_with(kind))
+		})
+		.map_or_else(
+			|| {
+				String::from_utf8_lossy(&output.stderr)
+					.trim()
+					.to_string()
+			},
+			ToString::to_string,
+		);
+
+	Ok(Verification::Bad(reason))
+}
+
+fn verify_ssh(
+	config: &git2::Config,
+	data: &[u8],
+	sig_file: &std::path::Path,
+) -> Result<Verification, SignError> {
+	let program = config
+		.get_string("gpg.ssh.program")
+		.unwrap_or_else(|_| "ssh-keygen".to_string());
+
+	let allowed_signers = match config
+		.get_path("gpg.ssh.allowedSignersFile")
+	{
+		Ok(path) => path,
+		Err(_) => {
+			return Ok(Verification::Bad(String::from(
+				"gpg.ssh.allowedSignersFile needs to be configured to verify ssh signatures",
+			)));
+		}
+	};
+
+	let output = run_with_input(
+		std::process::Command::new(&program)
+			.arg("-Y")
+			.arg("find-principals")
+			.arg("-f")
+			.arg(&allowed_signers)
+			.arg("-s")
+			.arg(sig_file),
+		&[],
+	)?;
+
+	let principals = String::from_utf8_lossy(&output.stdout);
+	let principal = match principals.lines().next() {
+		Some(principal) => principal,
+		None => {
+			return Ok(Verification::Bad(String::from(
+				"no principal matched in allowed signers",
+			)));
+		}
+	};
+
+	let output = run_with_input(
+		std::process::Command::new(&program)
+			.arg("-Y")
+			.arg("verify")
+			.arg("-n")
+			.arg("git")
+			.arg("-f")
+			.arg(&allowed_signers)
+			.arg("-I")
+			.arg(principal)
+			.arg("-s")
+			.arg(sig_file),
+		data,
+	)?;
+
+	if output.status.success() {
+		Ok(Verification::Good(principal.to_string()))
+	} else {
+		Ok(Verification::Bad(
+			String::from_utf8_lossy(&output.stderr)
+				.trim()
+				.to_string(),
+		))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::error::Result;
+	use crate::sync::tests::repo_init_empty;
+
+	#[test]
+	fn test_invalid_signing_format() -> Result<()> {
+		let (_temp_dir, repo) = repo_init_empty()?;
+
+		{
+			let mut config = repo.config()?;
+			config.set_str("gpg.format", "INVALID_SIGNING_FORMAT")?;
+		}
+
+		let sign =
+			SignBuilder::from_gitconfig(&repo, &repo.config()?);
+
+		assert!(sign.is_err());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_program_and_signing_key_defaults() -> Result<()> {
+		let (_tmp_dir, repo) = repo_init_empty()?;
+		let sign =
+			SignBuilder::from_gitconfig(&repo, &repo.config()?)?;
+
+		assert_eq!("gpg", sign.program());
+		assert_eq!("name <email>", sign.signing_key());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_gpg_program_configs() -> Result<()> {
+		let (_tmp_dir, repo) = repo_init_empty()?;
+
+		{
+			let mut config = repo.config()?;
+			config.set_str("gpg.program", "GPG_PROGRAM_TEST")?;
+		}
+
+		let sign =
+			SignBuilder::from_gitconfig(&repo, &repo.config()?)?;
+
+		// we get gpg.program, because gpg.openpgp.program is not set
+		assert_eq!("GPG_PROGRAM_TEST", sign.program());
+
+		{
+			let mut config = repo.config()?;
+			config.set_str(
+				"gpg.openpgp.program",
+				"GPG_OPENPGP_PROGRAM_TEST",
+			)?;
+		}
+
+		let sign =
+			SignBuilder::from_gitconfig(&repo, &repo.config()?)?;
+
+		// since gpg.openpgp.program is now set as well, it is more specific than
+		// gpg.program and therefore takes precedence
+		assert_eq!("GPG_OPENPGP_PROGRAM_TEST", sign.program());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_user_signingkey() -> Result<()> {
+		let (_tmp_dir, repo) = repo_init_empty()?;
+
+		{
+			let mut config = repo.config()?;
+			config.set_str("user.signingKey", "FFAA")?;
+		}
+
+		let sign =
+			SignBuilder::from_gitconfig(&repo, &repo.config()?)?;
+
+		assert_eq!("FFAA", sign.signing_key());
+		Ok(())
+	}
+
+	#[test]
+	fn test_ssh_program_configs() -> Result<()> {
+		let (_tmp_dir, repo) = repo_init_empty()?;
+
+		{
+			let mut config = repo.config()?;
+			config.set_str("gpg.format", "ssh")?;
+			config.set_str("user.signingKey", "/tmp/key.pub")?;
+		}
+
+		let sign =
+			SignBuilder::from_gitconfig(&repo, &repo.config()?)?;
+
+		assert_eq!("ssh-keygen", sign.program());
+		assert_eq!("/tmp/key.pub", sign.signing_key());
+
+		{
+			let mut config = repo.config()?;
+			config.set_str("gpg.ssh.program", "ssh_keygen_TEST")?;
+		}
+
+		let sign =
+			SignBuilder::from_gitconfig(&repo, &repo.config()?)?;
+
+		assert_eq!("ssh_keygen_TEST", sign.program());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_ssh_literal_signingkey() -> Result<()> {
+		let (_tmp_dir, repo) = repo_init_empty()?;
+
+		{
+			let mut config = repo.config()?;
+			config.set_str("gpg.format", "ssh")?;
+			config.set_str(
+				"user.signingKey",
+				"key::ssh-ed25519 AAAA",
+			)?;
+		}
+
+		let sign =
+			SignBuilder::from_gitconfig(&repo, &repo.config()?)?;
+
+		assert_eq!("key::ssh-ed25519 AAAA", sign.signing_key());
+		assert_eq!(
+			literal_ssh_key(sign.signing_key()),
+			Some("ssh-ed25519 AAAA")
+		);
+		assert_eq!(
+			literal_ssh_key("ssh-rsa AAAA"),
+			Some("ssh-rsa AAAA")
+		);
+		assert_eq!(literal_ssh_key("/tmp/key.pub"), None);
+
+		assert!(matches!(
+			SSHSign::new("ssh-keygen", "/tmp/gitui-missing-key.pub")
+				.sign(b"data"),
+			Err(SignError::KeyFile(_))
+		));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_ssh_requires_signingkey() -> Result<()> {
+		let (_tmp_dir, repo) = repo_init_empty()?;
+
+		{
+			let mut config = repo.config()?;
+			config.set_str("gpg.format", "ssh")?;
+		}
+
+		assert!(matches!(
+			SignBuilder::from_gitconfig(&repo, &repo.config()?),
+			Err(SignBuilderError::SSHSigningKey(_))
+		));
+
+		Ok(())
+	}
+}
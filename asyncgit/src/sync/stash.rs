@@ -1,13 +1,20 @@
 use super::{CommitId, RepoPath};
 use crate::{
 	error::{Error, Result},
-	sync::repository::repo,
+	sync::{
+		branch::get_branch_name_repo,
+		checkout_branch, delete_branch,
+		repository::repo,
+		status::{get_status, StatusItemType, StatusType},
+		utils::{bytes2string, work_dir},
+	},
 };
 use git2::{
-	build::CheckoutBuilder, Oid, Repository, StashApplyOptions,
-	StashFlags,
+	build::CheckoutBuilder, Commit, ObjectType, Oid, Repository,
+	StashApplyOptions, StashFlags, TreeWalkMode, TreeWalkResult,
 };
 use scopetime::scope_time;
+use std::{fs, path::Path};
 
 ///
 pub fn get_stashes(repo_path: &RepoPath) -> Result<Vec<CommitId>> {
@@ -34,6 +41,16 @@ pub fn is_stash_commit(
 	Ok(stashes.contains(id))
 }
 
+/// branch a stash was created on, parsed from its message like
+/// `On <branch>: <msg>` or `WIP on <branch>: <commit>`
+pub fn stash_origin_branch(message: &str) -> Option<&str> {
+	message
+		.strip_prefix("WIP on ")
+		.or_else(|| message.strip_prefix("On "))
+		.and_then(|rest| rest.split_once(": "))
+		.map(|(branch, _)| branch)
+}
+
 ///
 pub fn stash_drop(
 	repo_path: &RepoPath,
@@ -66,11 +83,13 @@ pub fn stash_pop(
 	Ok(())
 }
 
-///
+/// applies the stash to the workdir, with `reinstate_index` the
+/// staged changes are restored into the index like `--index` does
 pub fn stash_apply(
 	repo_path: &RepoPath,
 	stash_id: CommitId,
 	allow_conflicts: bool,
+	reinstate_index: bool,
 ) -> Result<()> {
 	scope_time!("stash_apply");
 
@@ -83,11 +102,74 @@ pub fn stash_apply(
 
 	let mut opt = StashApplyOptions::default();
 	opt.checkout_options(checkout);
+	if reinstate_index {
+		opt.reinstantiate_index();
+	}
 	repo.stash_apply(index, Some(&mut opt))?;
 
 	Ok(())
 }
 
+/// creates `branch` at the commit the stash was based on, checks it
+/// out and pops the stash with its index like `git stash branch`
+pub fn stash_branch(
+	repo_path: &RepoPath,
+	stash_id: CommitId,
+	branch: &str,
+) -> Result<()> {
+	scope_time!("stash_branch");
+
+	let mut repo = repo(repo_path)?;
+
+	let branch_ref = {
+		let base = repo.find_commit(stash_id.into())?.parent(0)?;
+		let branch = repo.branch(branch, &base, false)?;
+		bytes2string(branch.get().name_bytes())?
+	};
+
+	if let Err(e) = checkout_branch(repo_path, &branch_ref) {
+		delete_branch(repo_path, &branch_ref)?;
+		return Err(e);
+	}
+
+	let index = get_stash_index(&mut repo, stash_id.into())?;
+
+	let mut opt = StashApplyOptions::default();
+	opt.reinstantiate_index();
+	repo.stash_pop(index, Some(&mut opt))?;
+
+	Ok(())
+}
+
+/// paths of the untracked files stored in a stash (its third parent)
+pub fn get_stash_untracked_files(
+	repo_path: &RepoPath,
+	stash_id: CommitId,
+) -> Result<Vec<String>> {
+	scope_time!("get_stash_untracked_files");
+
+	let repo = repo(repo_path)?;
+	let stash = repo.find_commit(stash_id.into())?;
+
+	let mut res = Vec::new();
+
+	if let Ok(untracked) = stash.parent(2) {
+		untracked.tree()?.walk(
+			TreeWalkMode::PreOrder,
+			|root, entry| {
+				if entry.kind() == Some(ObjectType::Blob) {
+					if let Some(name) = entry.name() {
+						res.push(format!("{}{}", root, name));
+					}
+				}
+				TreeWalkResult::Ok
+			},
+		)?;
+	}
+
+	Ok(res)
+}
+
 fn get_stash_index(
 	repo: &mut Repository,
 	stash_id: Oid,
@@ -135,11 +217,224 @@ pub fn stash_save(
 	Ok(CommitId::new(id))
 }
 
+/// stashes only the changes to `paths` like `git stash push -- <paths>`
+///
+/// the rest of the working tree is left untouched, untracked files in
+/// `paths` are always stashed and `keep_index` keeps their staged changes
+pub fn stash_save_paths(
+	repo_path: &RepoPath,
+	message: Option<&str>,
+	paths: &[String],
+	keep_index: bool,
+) -> Result<CommitId> {
+	scope_time!("stash_save_paths");
+
+	if paths.is_empty() {
+		return Err(Error::Generic(String::from(
+			"no paths to stash",
+		)));
+	}
+
+	let repo = repo(repo_path)?;
+	let sig = repo.signature()?;
+	let head = repo.head()?.peel_to_commit()?;
+	let head_tree = head.tree()?;
+
+	let (tracked, untracked): (Vec<&String>, Vec<&String>) = {
+		let index = repo.index()?;
+		paths.iter().partition(|path| {
+			index.get_path(Path::new(path), 0).is_some()
+				|| head_tree.get_path(Path::new(path)).is_ok()
+		})
+	};
+
+	let (index_tree, worktree_tree, untracked_tree) =
+		stash_trees(repo_path, &head_tree, &tracked, &untracked)?;
+
+	let branch = get_branch_name_repo(&repo)
+		.unwrap_or_else(|_| String::from("(no branch)"));
+	let head_msg = format!(
+		"{}: {} {}",
+		branch,
+		CommitId::from(head.id()).get_short_string(),
+		head.summary().unwrap_or_default()
+	);
+
+	let commit = |msg: &str, tree: Oid, parents: &[&Commit]| {
+		repo.commit(
+			None,
+			&sig,
+			&sig,
+			msg,
+			&repo.find_tree(tree)?,
+			parents,
+		)
+	};
+
+	let index_commit = repo.find_commit(commit(
+		&format!("index on {}", head_msg),
+		index_tree,
+		&[&head],
+	)?)?;
+
+	let mut parents = vec![&head, &index_commit];
+	let untracked_commit = untracked_tree
+		.map(|tree| {
+			commit(
+				&format!("untracked files on {}", head_msg),
+				tree,
+				&[],
+			)
+			.and_then(|id| repo.find_commit(id))
+		})
+		.transpose()?;
+	if let Some(untracked_commit) = &untracked_commit {
+		parents.push(untracked_commit);
+	}
+
+	let stash_msg = message.map_or_else(
+		|| format!("WIP on {}", head_msg),
+		|msg| format!("On {}: {}", branch, msg),
+	);
+	let stash = commit(&stash_msg, worktree_tree, &parents)?;
+
+	repo.reference_ensure_log("refs/stash")?;
+	repo.reference("refs/stash", stash, true, &stash_msg)?;
+
+	reset_paths(&repo, &head, &tracked, &untracked, keep_index)?;
+
+	Ok(stash.into())
+}
+
+/// stashes only the untracked files, tracked changes are left
+/// untouched
+pub fn stash_save_untracked(
+	repo_path: &RepoPath,
+	message: Option<&str>,
+) -> Result<CommitId> {
+	scope_time!("stash_save_untracked");
+
+	let paths = get_status(repo_path, StatusType::WorkingDir, None)?
+		.into_iter()
+		.filter(|item| item.status == StatusItemType::New)
+		.map(|item| item.path)
+		.collect::<Vec<_>>();
+
+	if paths.is_empty() {
+		return Err(Error::Generic(String::from(
+			"no untracked files to stash",
+		)));
+	}
+
+	stash_save_paths(repo_path, message, &paths, false)
+}
+
+/// index, worktree and untracked trees of a stash of `tracked` and
+/// `untracked` paths based on `head_tree`
+fn stash_trees(
+	repo_path: &RepoPath,
+	head_tree: &git2::Tree,
+	tracked: &[&String],
+	untracked: &[&String],
+) -> Result<(Oid, Oid, Option<Oid>)> {
+	// a separate instance so the repos index is never modified
+	let repo = repo(repo_path)?;
+	let mut index = repo.index()?;
+
+	let staged = tracked
+		.iter()
+		.map(|path| (path, index.get_path(Path::new(path), 0)))
+		.collect::<Vec<_>>();
+
+	index.read_tree(head_tree)?;
+
+	for (path, entry) in staged {
+		if let Some(entry) = entry {
+			index.add(&entry)?;
+		} else {
+			index.remove_path(Path::new(path))?;
+		}
+	}
+
+	let index_tree = index.write_tree()?;
+
+	let workdir = work_dir(&repo)?;
+	for path in tracked {
+		if workdir.join(path).exists() {
+			index.add_path(Path::new(path))?;
+		} else {
+			index.remove_path(Path::new(path))?;
+		}
+	}
+
+	let worktree_tree = index.write_tree()?;
+
+	let untracked_tree = if untracked.is_empty() {
+		None
+	} else {
+		index.clear()?;
+		for path in untracked {
+			index.add_path(Path::new(path))?;
+		}
+		Some(index.write_tree()?)
+	};
+
+	Ok((index_tree, worktree_tree, untracked_tree))
+}
+
+/// reverts `tracked` to `HEAD` (or the index with `keep_index`)
+/// and removes `untracked` from the working tree
+fn reset_paths(
+	repo: &Repository,
+	head: &Commit,
+	tracked: &[&String],
+	untracked: &[&String],
+	keep_index: bool,
+) -> Result<()> {
+	let workdir = work_dir(repo)?;
+
+	if !tracked.is_empty() {
+		if !keep_index {
+			repo.reset_default(Some(head.as_object()), tracked)?;
+		}
+
+		let index = repo.index()?;
+		let mut checkout = CheckoutBuilder::new();
+		checkout.force();
+		for path in tracked {
+			checkout.path(path.as_str());
+		}
+		repo.checkout_index(None, Some(&mut checkout))?;
+
+		for path in tracked {
+			if index.get_path(Path::new(path), 0).is_none() {
+				remove_file(&workdir.join(path))?;
+			}
+		}
+	}
+
+	for path in untracked {
+		remove_file(&workdir.join(path))?;
+	}
+
+	Ok(())
+}
+
+fn remove_file(path: &Path) -> Result<()> {
+	if path.exists() {
+		fs::remove_file(path)?;
+	}
+
+	Ok(())
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
 	use crate::sync::{
-		commit, get_commit_files, get_commits_info, stage_add_file,
+		commit,
+		diff::get_diff_commit,
+		get_commit_files, get_commits_info, stage_add_file,
 		tests::{
 			debug_cmd_print, get_statuses, repo_init,
 			write_commit_file,
@@ -266,7 +561,7 @@ mod tests {
 
 		repo_write_file(&repo, "test.txt", "foo").unwrap();
 
-		let res = stash_apply(repo_path, id, false);
+		let res = stash_apply(repo_path, id, false, false);
 
 		assert!(res.is_err());
 	}
@@ -287,7 +582,7 @@ mod tests {
 
 		repo_write_file(&repo, "test.txt", "test3").unwrap();
 
-		let res = stash_apply(repo_path, id, false);
+		let res = stash_apply(repo_path, id, false, false);
 
 		assert!(res.is_err());
 	}
@@ -308,15 +603,120 @@ mod tests {
 
 		repo_write_file(&repo, "test.txt", "test3").unwrap();
 
-		let res = stash_apply(repo_path, id, false);
+		let res = stash_apply(repo_path, id, false, false);
 
 		assert!(res.is_err());
 
-		let res = stash_apply(repo_path, id, true);
+		let res = stash_apply(repo_path, id, true, false);
 
 		assert!(res.is_ok());
 	}
 
+	#[test]
+	fn test_stash_untracked_only() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		write_commit_file(&repo, "a.txt", "a", "c1");
+
+		assert!(stash_save_untracked(repo_path, None).is_err());
+
+		repo_write_file(&repo, "a.txt", "a2").unwrap();
+		fs::create_dir(root.join("dir")).unwrap();
+		repo_write_file(&repo, "dir/new.txt", "new").unwrap();
+
+		assert_eq!(get_statuses(repo_path), (2, 0));
+
+		let id =
+			stash_save_untracked(repo_path, Some("foo")).unwrap();
+
+		assert_eq!(get_statuses(repo_path), (1, 0));
+		assert_eq!(repo_read_file(&repo, "a.txt").unwrap(), "a2");
+		assert!(!root.join("dir/new.txt").exists());
+
+		assert_eq!(
+			get_stash_untracked_files(repo_path, id).unwrap().len(),
+			1
+		);
+	}
+
+	#[test]
+	fn test_stash_origin_branch() {
+		assert_eq!(
+			stash_origin_branch("On master: foo"),
+			Some("master")
+		);
+		assert_eq!(
+			stash_origin_branch("WIP on feature/x: 1234567 bar"),
+			Some("feature/x")
+		);
+		assert_eq!(
+			stash_origin_branch("On (no branch): foo: bar"),
+			Some("(no branch)")
+		);
+		assert_eq!(stash_origin_branch("foo"), None);
+	}
+
+	#[test]
+	fn test_stash_keep_index() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		write_commit_file(&repo, "a.txt", "a", "c1");
+		write_commit_file(&repo, "b.txt", "b", "c2");
+
+		repo_write_file(&repo, "a.txt", "a2").unwrap();
+		stage_add_file(repo_path, Path::new("a.txt")).unwrap();
+		repo_write_file(&repo, "b.txt", "b2").unwrap();
+
+		let id =
+			stash_save(repo_path, Some("foo"), false, true).unwrap();
+
+		// staged changes are left as they were
+		assert_eq!(get_statuses(repo_path), (0, 1));
+		assert_eq!(repo_read_file(&repo, "a.txt").unwrap(), "a2");
+		assert_eq!(repo_read_file(&repo, "b.txt").unwrap(), "b");
+
+		// but still part of the stash like `git stash --keep-index`
+		let files = get_commit_files(repo_path, id, None).unwrap();
+		assert_eq!(files.len(), 2);
+	}
+
+	#[test]
+	fn test_stash_apply_reinstate_index() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		write_commit_file(&repo, "a.txt", "a", "c1");
+		write_commit_file(&repo, "b.txt", "b", "c2");
+
+		repo_write_file(&repo, "a.txt", "a2").unwrap();
+		stage_add_file(repo_path, Path::new("a.txt")).unwrap();
+		repo_write_file(&repo, "b.txt", "b2").unwrap();
+
+		assert_eq!(get_statuses(repo_path), (1, 1));
+
+		let id =
+			stash_save(repo_path, Some("foo"), false, false).unwrap();
+
+		assert_eq!(get_statuses(repo_path), (0, 0));
+
+		stash_apply(repo_path, id, false, false).unwrap();
+
+		assert_eq!(get_statuses(repo_path), (2, 0));
+
+		stash_save(repo_path, Some("tmp"), false, false).unwrap();
+		stash_apply(repo_path, id, false, true).unwrap();
+
+		assert_eq!(get_statuses(repo_path), (1, 1));
+	}
+
 	#[test]
 	fn test_stash_pop_no_conflict() {
 		let (_td, repo) = repo_init().unwrap();
@@ -387,4 +787,134 @@ mod tests {
 			"test3"
 		);
 	}
+
+	#[test]
+	fn test_stash_save_paths() -> Result<()> {
+		let (_td, repo) = repo_init()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		write_commit_file(&repo, "a.txt", "a", "c1");
+		write_commit_file(&repo, "b.txt", "b", "c2");
+
+		repo_write_file(&repo, "a.txt", "a2")?;
+		repo_write_file(&repo, "b.txt", "b2")?;
+		repo_write_file(&repo, "new.txt", "new")?;
+		repo_write_file(&repo, "other.txt", "other")?;
+
+		let id = stash_save_paths(
+			repo_path,
+			Some("partial"),
+			&[String::from("a.txt"), String::from("new.txt")],
+			false,
+		)?;
+
+		assert_eq!(get_stashes(repo_path)?, vec![id]);
+		assert_eq!(repo_read_file(&repo, "a.txt")?, "a");
+		assert_eq!(repo_read_file(&repo, "b.txt")?, "b2");
+		assert!(!root.join("new.txt").exists());
+		assert!(root.join("other.txt").exists());
+
+		let infos = get_commits_info(repo_path, &[id], 100)?;
+		assert_eq!(infos[0].message, "On master: partial");
+
+		repo_write_file(&repo, "b.txt", "b")?;
+		stash_pop(repo_path, id)?;
+
+		assert!(get_stashes(repo_path)?.is_empty());
+		assert_eq!(repo_read_file(&repo, "a.txt")?, "a2");
+		assert_eq!(repo_read_file(&repo, "new.txt")?, "new");
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_stash_untracked_files() -> Result<()> {
+		let (_td, repo) = repo_init()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		write_commit_file(&repo, "a.txt", "a", "c1");
+
+		repo_write_file(&repo, "a.txt", "a2")?;
+		fs::create_dir(root.join("dir"))?;
+		repo_write_file(&repo, "dir/new.txt", "new")?;
+
+		let id = stash_save(repo_path, None, false, false)?;
+		assert!(get_stash_untracked_files(repo_path, id)?.is_empty());
+		stash_pop(repo_path, id)?;
+
+		let id = stash_save(repo_path, None, true, false)?;
+		assert_eq!(
+			get_stash_untracked_files(repo_path, id)?,
+			vec![String::from("dir/new.txt")]
+		);
+
+		let diff = get_diff_commit(
+			repo_path,
+			id,
+			String::from("dir/new.txt"),
+			None,
+		)?;
+		assert_eq!(diff.hunks.len(), 1);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_stash_branch() -> Result<()> {
+		let (_td, repo) = repo_init()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let base = write_commit_file(&repo, "a.txt", "a", "c1");
+
+		repo_write_file(&repo, "a.txt", "a2")?;
+		stage_add_file(repo_path, Path::new("a.txt"))?;
+		repo_write_file(&repo, "b.txt", "b")?;
+
+		let id = stash_save(repo_path, None, true, false)?;
+
+		write_commit_file(&repo, "a.txt", "a3", "c2");
+
+		stash_branch(repo_path, id, "recovered")?;
+
+		assert!(get_stashes(repo_path)?.is_empty());
+		assert_eq!(repo.head()?.shorthand(), Some("recovered"));
+		assert_eq!(
+			CommitId::from(repo.head()?.peel_to_commit()?.id()),
+			base
+		);
+		assert_eq!(repo_read_file(&repo, "a.txt")?, "a2");
+		assert_eq!(repo_read_file(&repo, "b.txt")?, "b");
+		assert_eq!(get_statuses(repo_path), (1, 1));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_stash_branch_dirty() -> Result<()> {
+		let (_td, repo) = repo_init()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		write_commit_file(&repo, "a.txt", "a", "c1");
+
+		repo_write_file(&repo, "a.txt", "a2")?;
+		let id = stash_save(repo_path, None, true, false)?;
+
+		repo_write_file(&repo, "a.txt", "a3")?;
+
+		assert!(stash_branch(repo_path, id, "recovered").is_err());
+		assert!(repo
+			.find_branch("recovered", git2::BranchType::Local)
+			.is_err());
+		assert_eq!(get_stashes(repo_path)?, vec![id]);
+
+		Ok(())
+	}
 }
@@ -15,6 +15,10 @@ pub enum RepoState {
 	///
 	Revert,
 	///
+	CherryPick,
+	///
+	Bisect,
+	///
 	Other,
 }
 
@@ -23,7 +27,11 @@ impl From<RepositoryState> for RepoState {
 		match state {
 			RepositoryState::Clean => Self::Clean,
 			RepositoryState::Merge => Self::Merge,
-			RepositoryState::Revert => Self::Revert,
+			RepositoryState::Revert
+			| RepositoryState::RevertSequence => Self::Revert,
+			RepositoryState::CherryPick
+			| RepositoryState::CherryPickSequence => Self::CherryPick,
+			RepositoryState::Bisect => Self::Bisect,
 			RepositoryState::RebaseMerge => Self::Rebase,
 			_ => {
 				log::warn!("state not supported yet: {:?}", state);
@@ -5,9 +5,11 @@ use crate::{
 	error::Result,
 	sync::{config::untracked_files_config_repo, repository::repo},
 };
-use git2::{Delta, Status, StatusOptions, StatusShow};
+use git2::{
+	Delta, DiffOptions, Patch, Status, StatusOptions, StatusShow,
+};
 use scopetime::scope_time;
-use std::path::Path;
+use std::{collections::BTreeMap, path::Path, time::UNIX_EPOCH};
 
 use super::{RepoPath, ShowUntrackedFilesConfig};
 
@@ -84,6 +86,24 @@ impl Default for StatusType {
 	}
 }
 
+/// data besides the path to sort status items by, gathered along
+/// with the status
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub enum StatusSortData {
+	///
+	None,
+	/// see [`get_status_mod_times`]
+	ModTime,
+	/// see [`get_status_diff_sizes`]
+	DiffSize,
+}
+
+impl Default for StatusSortData {
+	fn default() -> Self {
+		Self::None
+	}
+}
+
 impl From<StatusType> for StatusShow {
 	fn from(s: StatusType) -> Self {
 		match s {
@@ -195,3 +215,156 @@ pub fn get_status(
 
 	Ok(res)
 }
+
+/// last modification of the files of `items` in the working dir
+/// (seconds since the epoch) per path, used to sort status lists by
+/// the most recently modified files, deleted files are left out
+pub fn get_status_mod_times(
+	repo_path: &RepoPath,
+	items: &[StatusItem],
+) -> Result<BTreeMap<String, u64>> {
+	scope_time!("get_status_mod_times");
+
+	let workdir = super::utils::repo_work_dir(repo_path)?;
+
+	Ok(items
+		.iter()
+		.filter_map(|item| {
+			let modified = Path::new(&workdir)
+				.join(&item.path)
+				.metadata()
+				.and_then(|meta| meta.modified())
+				.ok()?;
+			let secs = modified
+				.duration_since(UNIX_EPOCH)
+				.unwrap_or_default()
+				.as_secs();
+			Some((item.path.clone(), secs))
+		})
+		.collect())
+}
+
+/// number of changed lines (added + deleted) per path,
+/// used to sort status lists by the size of their diff
+pub fn get_status_diff_sizes(
+	repo_path: &RepoPath,
+	status_type: StatusType,
+) -> Result<BTreeMap<String, u64>> {
+	scope_time!("get_status_diff_sizes");
+
+	let repo = repo(repo_path)?;
+
+	let mut opts = DiffOptions::new();
+	opts.include_untracked(true)
+		.recurse_untracked_dirs(true)
+		.show_untracked_content(true);
+
+	let head =
+		repo.head().ok().and_then(|head| head.peel_to_tree().ok());
+
+	let diff = match status_type {
+		StatusType::WorkingDir => {
+			repo.diff_index_to_workdir(None, Some(&mut opts))?
+		}
+		StatusType::Stage => repo.diff_tree_to_index(
+			head.as_ref(),
+			None,
+			Some(&mut opts),
+		)?,
+		StatusType::Both => repo.diff_tree_to_workdir_with_index(
+			head.as_ref(),
+			Some(&mut opts),
+		)?,
+	};
+
+	let mut sizes = BTreeMap::new();
+
+	for idx in 0..diff.deltas().len() {
+		if let Some(patch) = Patch::from_diff(&diff, idx)? {
+			let delta = patch.delta();
+			let path = delta
+				.new_file()
+				.path()
+				.or_else(|| delta.old_file().path())
+				.and_then(Path::to_str);
+
+			if let Some(path) = path {
+				let (_, added, deleted) = patch.line_stats()?;
+				sizes.insert(
+					path.to_string(),
+					u64::try_from(added + deleted)?,
+				);
+			}
+		}
+	}
+
+	Ok(sizes)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		get_status, get_status_diff_sizes, get_status_mod_times,
+		StatusType,
+	};
+	use crate::sync::{
+		stage_add_file,
+		tests::{repo_init, write_commit_file},
+		utils::repo_write_file,
+		RepoPath,
+	};
+	use std::path::Path;
+
+	#[test]
+	fn test_status_diff_sizes() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		write_commit_file(&repo, "a.txt", "a\nb\n", "commit1");
+
+		repo_write_file(&repo, "a.txt", "a\nc\n").unwrap();
+		repo_write_file(&repo, "new.txt", "1\n2\n3\n").unwrap();
+
+		let sizes =
+			get_status_diff_sizes(repo_path, StatusType::WorkingDir)
+				.unwrap();
+
+		assert_eq!(sizes.get("a.txt"), Some(&2));
+		assert_eq!(sizes.get("new.txt"), Some(&3));
+
+		stage_add_file(repo_path, Path::new("new.txt")).unwrap();
+
+		let sizes =
+			get_status_diff_sizes(repo_path, StatusType::Stage)
+				.unwrap();
+
+		assert_eq!(sizes.len(), 1);
+		assert_eq!(sizes.get("new.txt"), Some(&3));
+	}
+
+	#[test]
+	fn test_status_mod_times() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		write_commit_file(&repo, "a.txt", "a", "commit1");
+
+		repo_write_file(&repo, "new.txt", "1").unwrap();
+		std::fs::remove_file(root.join("a.txt")).unwrap();
+
+		let items =
+			get_status(repo_path, StatusType::WorkingDir, None)
+				.unwrap();
+		let times = get_status_mod_times(repo_path, &items).unwrap();
+
+		assert_eq!(items.len(), 2);
+		assert_eq!(times.len(), 1);
+		assert!(
+			times.get("new.txt").copied().unwrap_or_default() > 0
+		);
+	}
+}
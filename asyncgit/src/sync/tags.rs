@@ -1,10 +1,15 @@
-use super::{get_commits_info, CommitId, RepoPath};
+use super::{get_commits_info, CommitId, CommitSignature, RepoPath};
 use crate::{
 	error::Result,
-	sync::{repository::repo, utils::bytes2string},
+	sync::{
+		repository::repo,
+		sign::{verify_signature, Verification},
+		utils::bytes2string,
+	},
 };
 use scopetime::scope_time;
 use std::{
+	cmp::Ordering,
 	collections::{BTreeMap, HashMap, HashSet},
 	ops::Not,
 };
@@ -33,14 +38,30 @@ pub type CommitTags = Vec<Tag>;
 /// hashmap of tag target commit hash to tag names
 pub type Tags = BTreeMap<CommitId, CommitTags>;
 
+/// how a tag is stored
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TagKind {
+	/// plain ref pointing to the commit
+	Lightweight,
+	/// tag object with tagger and annotation
+	Annotated,
+	/// annotated tag carrying a gpg or ssh signature
+	Signed,
+}
+
 ///
+#[derive(Clone)]
 pub struct TagWithMetadata {
 	///
 	pub name: String,
 	///
 	pub author: String,
-	///
+	/// time of the commit the tag points to
 	pub time: i64,
+	/// tagger time of annotated tags, commit time otherwise
+	pub created: i64,
+	///
+	pub kind: TagKind,
 	///
 	pub message: String,
 	///
@@ -49,6 +70,19 @@ pub struct TagWithMetadata {
 	pub annotation: Option<String>,
 }
 
+/// tagger and full annotation of a single tag
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TagDetails {
+	///
+	pub name: String,
+	/// commit the tag points to
+	pub target: CommitId,
+	/// `None` for lightweight tags
+	pub tagger: Option<CommitSignature>,
+	/// full annotation without a signature
+	pub annotation: Option<String>,
+}
+
 static MAX_MESSAGE_WIDTH: usize = 100;
 
 /// returns `Tags` type filled with all tags found in repo
@@ -92,6 +126,11 @@ pub fn get_tags(repo_path: &RepoPath) -> Result<Tags> {
 				.ok()
 				.as_ref()
 				.and_then(git2::Tag::message_bytes)
+				.map(|msg| {
+					// signed tags carry the signature in the message
+					split_tag_signature(msg)
+						.map_or(msg, |(msg, _)| msg)
+				})
 				.and_then(|msg| {
 					msg.is_empty()
 						.not()
@@ -150,14 +189,42 @@ pub fn get_tags_with_metadata(
 		.map(|commit_info| (commit_info.id, commit_info))
 		.collect();
 
+	let repo = repo(repo_path)?;
+	// tagger time and kind, `None` for lightweight tags
+	let tag_object = |name: &str| {
+		repo.find_reference(&format!("refs/tags/{}", name))
+			.and_then(|reference| reference.peel_to_tag())
+			.ok()
+			.map(|tag| {
+				let signed = tag
+					.message_bytes()
+					.and_then(split_tag_signature)
+					.is_some();
+
+				(
+					tag.tagger().map(|sig| sig.when().seconds()),
+					if signed {
+						TagKind::Signed
+					} else {
+						TagKind::Annotated
+					},
+				)
+			})
+	};
+
 	let mut tags: Vec<TagWithMetadata> = tags_with_commit_id
 		.into_iter()
 		.filter_map(|(tag, annotation, commit_id)| {
 			unique_commit_infos.get(commit_id).map(|commit_info| {
+				let (tagger_time, kind) = tag_object(tag)
+					.unwrap_or((None, TagKind::Lightweight));
+
 				TagWithMetadata {
 					name: String::from(tag),
 					author: commit_info.author.clone(),
 					time: commit_info.time,
+					created: tagger_time.unwrap_or(commit_info.time),
+					kind,
 					message: commit_info.message.clone(),
 					commit_id: *commit_id,
 					annotation: annotation.map(String::from),
@@ -184,6 +251,206 @@ pub fn delete_tag(
 	Ok(())
 }
 
+/// names of all local tags matching the glob `pattern`
+/// (like `git tag -l <pattern>`), sorted by name
+pub fn tags_matching(
+	repo_path: &RepoPath,
+	pattern: &str,
+) -> Result<Vec<String>> {
+	scope_time!("tags_matching");
+
+	let repo = repo(repo_path)?;
+	let mut names: Vec<String> = repo
+		.tag_names(Some(pattern))?
+		.iter()
+		.flatten()
+		.map(String::from)
+		.collect();
+
+	names.sort();
+
+	Ok(names)
+}
+
+/// compares tag names like `git tag --sort=version:refname`
+///
+/// runs of digits compare as numbers and a name continuing with one
+/// of `suffixes` (`versionsort.suffix`) where the two start to differ
+/// sorts before the other, suffixes in the order they are given
+pub fn version_cmp(
+	a: &str,
+	b: &str,
+	suffixes: &[String],
+) -> Ordering {
+	let common =
+		a.bytes().zip(b.bytes()).take_while(|(a, b)| a == b).count();
+
+	match (
+		suffix_at(a, common, suffixes),
+		suffix_at(b, common, suffixes),
+	) {
+		(Some(a), Some(b)) if a != b => return a.cmp(&b),
+		(Some(_), None) => return Ordering::Less,
+		(None, Some(_)) => return Ordering::Greater,
+		_ => (),
+	}
+
+	natural_cmp(a.as_bytes(), b.as_bytes())
+}
+
+/// index of the suffix starting earliest in `name` while covering
+/// byte `offset`
+fn suffix_at(
+	name: &str,
+	offset: usize,
+	suffixes: &[String],
+) -> Option<usize> {
+	suffixes
+		.iter()
+		.enumerate()
+		.filter(|(_, suffix)| !suffix.is_empty())
+		.filter_map(|(idx, suffix)| {
+			name.match_indices(suffix.as_str())
+				.map(|(start, _)| start)
+				.find(|start| {
+					*start <= offset && start + suffix.len() > offset
+				})
+				.map(|start| (start, idx))
+		})
+		.min()
+		.map(|(_, idx)| idx)
+}
+
+fn natural_cmp(mut a: &[u8], mut b: &[u8]) -> Ordering {
+	loop {
+		match (a.split_first(), b.split_first()) {
+			(None, None) => return Ordering::Equal,
+			(None, Some(_)) => return Ordering::Less,
+			(Some(_), None) => return Ordering::Greater,
+			(Some((ca, _)), Some((cb, _)))
+				if ca.is_ascii_digit() && cb.is_ascii_digit() =>
+			{
+				let (number_a, rest_a) = split_number(a);
+				let (number_b, rest_b) = split_number(b);
+
+				let ord = number_a
+					.len()
+					.cmp(&number_b.len())
+					.then_with(|| number_a.cmp(number_b));
+				if ord != Ordering::Equal {
+					return ord;
+				}
+
+				a = rest_a;
+				b = rest_b;
+			}
+			(Some((ca, rest_a)), Some((cb, rest_b))) => {
+				if ca != cb {
+					return ca.cmp(cb);
+				}
+
+				a = rest_a;
+				b = rest_b;
+			}
+		}
+	}
+}
+
+/// leading digits without leading zeros and the rest
+fn split_number(s: &[u8]) -> (&[u8], &[u8]) {
+	let end = s
+		.iter()
+		.position(|c| !c.is_ascii_digit())
+		.unwrap_or(s.len());
+	let (digits, rest) = s.split_at(end);
+	let zeros = digits.iter().take_while(|c| **c == b'0').count();
+
+	(digits.split_at(zeros).1, rest)
+}
+
+/// looks up tagger and full annotation of tag `tag_name`
+pub fn get_tag_details(
+	repo_path: &RepoPath,
+	tag_name: &str,
+) -> Result<TagDetails> {
+	scope_time!("get_tag_details");
+
+	let repo = repo(repo_path)?;
+
+	let reference =
+		repo.find_reference(&format!("refs/tags/{}", tag_name))?;
+	let target = reference.peel_to_commit()?.id().into();
+
+	let (tagger, annotation) =
+		reference.peel_to_tag().map_or((None, None), |tag| {
+			(
+				tag.tagger().as_ref().map(CommitSignature::from),
+				tag.message_bytes()
+					.map(|msg| {
+						split_tag_signature(msg)
+							.map_or(msg, |(msg, _)| msg)
+					})
+					.and_then(|msg| bytes2string(msg).ok()),
+			)
+		});
+
+	Ok(TagDetails {
+		name: tag_name.to_string(),
+		target,
+		tagger,
+		annotation,
+	})
+}
+
+/// verifies the signature of tag `tag_name` like `git verify-tag`,
+/// `None` if it is a lightweight or unsigned tag
+pub fn verify_tag(
+	repo_path: &RepoPath,
+	tag_name: &str,
+) -> Result<Option<Verification>> {
+	scope_time!("verify_tag");
+
+	let repo = repo(repo_path)?;
+
+	let id = repo
+		.find_reference(&format!("refs/tags/{}", tag_name))?
+		.target();
+	let id = match id {
+		Some(id) if repo.find_tag(id).is_ok() => id,
+		_ => return Ok(None),
+	};
+
+	let odb = repo.odb()?;
+	let object = odb.read(id)?;
+	let (payload, signature) =
+		match split_tag_signature(object.data()) {
+			Some(split) => split,
+			None => return Ok(None),
+		};
+
+	Ok(Some(verify_signature(
+		&repo.config()?,
+		payload,
+		&String::from_utf8_lossy(signature),
+	)?))
+}
+
+/// splits a raw tag object into the signed payload and the
+/// signature git appends to the message
+fn split_tag_signature(data: &[u8]) -> Option<(&[u8], &[u8])> {
+	const HEADERS: [&[u8]; 2] = [
+		b"-----BEGIN PGP SIGNATURE-----",
+		b"-----BEGIN SSH SIGNATURE-----",
+	];
+
+	(0..data.len())
+		.filter(|&i| i == 0 || data[i - 1] == b'\n')
+		.find(|&i| {
+			HEADERS.iter().any(|header| data[i..].starts_with(header))
+		})
+		.map(|i| data.split_at(i))
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -248,4 +515,217 @@ mod tests {
 
 		assert_eq!(tags.len(), 0);
 	}
+
+	#[test]
+	fn test_split_tag_signature() {
+		let data = b"object 0000\ntype commit\ntag v1\n\nmsg\n-----BEGIN PGP SIGNATURE-----\nabc\n-----END PGP SIGNATURE-----\n";
+
+		let (payload, signature) = split_tag_signature(data).unwrap();
+
+		assert_eq!(
+			payload,
+			b"object 0000\ntype commit\ntag v1\n\nmsg\n"
+		);
+		assert!(
+			signature.starts_with(b"-----BEGIN PGP SIGNATURE-----")
+		);
+
+		assert!(split_tag_signature(b"tag v1\n\nmsg\n").is_none());
+	}
+
+	#[test]
+	fn test_verify_unsigned_tags() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let sig = repo.signature().unwrap();
+		let target = repo
+			.find_object(
+				repo.head().unwrap().target().unwrap(),
+				Some(ObjectType::Commit),
+			)
+			.unwrap();
+
+		repo.tag("annotated", &target, &sig, "msg", false).unwrap();
+		repo.tag_lightweight("light", &target, false).unwrap();
+
+		assert_eq!(verify_tag(repo_path, "annotated").unwrap(), None);
+		assert_eq!(verify_tag(repo_path, "light").unwrap(), None);
+	}
+
+	#[test]
+	fn test_tag_details() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let sig = repo.signature().unwrap();
+		let head_id = repo.head().unwrap().target().unwrap();
+		let target = repo
+			.find_object(head_id, Some(ObjectType::Commit))
+			.unwrap();
+
+		repo.tag(
+			"annotated",
+			&target,
+			&sig,
+			"subject\n\nbody",
+			false,
+		)
+		.unwrap();
+		repo.tag_lightweight("light", &target, false).unwrap();
+
+		let details =
+			get_tag_details(repo_path, "annotated").unwrap();
+
+		assert_eq!(details.target, CommitId::new(head_id));
+		assert_eq!(details.tagger.unwrap().name, "name");
+		assert_eq!(
+			details.annotation.as_deref(),
+			Some("subject\n\nbody")
+		);
+
+		let details = get_tag_details(repo_path, "light").unwrap();
+
+		assert_eq!(details.target, CommitId::new(head_id));
+		assert_eq!(details.tagger, None);
+		assert_eq!(details.annotation, None);
+	}
+
+	#[test]
+	fn test_tags_matching() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let head_id = repo.head().unwrap().target().unwrap();
+		let target = repo
+			.find_object(head_id, Some(ObjectType::Commit))
+			.unwrap();
+
+		for name in ["nightly-2", "v1.0", "nightly-1"] {
+			repo.tag_lightweight(name, &target, false).unwrap();
+		}
+
+		assert_eq!(
+			tags_matching(repo_path, "nightly-*").unwrap(),
+			vec![
+				String::from("nightly-1"),
+				String::from("nightly-2")
+			]
+		);
+		assert!(tags_matching(repo_path, "beta*")
+			.unwrap()
+			.is_empty());
+	}
+
+	#[test]
+	fn test_version_cmp() {
+		let suffixes = vec![String::from("-rc")];
+		let mut names = vec![
+			"v10.0",
+			"v2.0",
+			"v2.0-rc1",
+			"v2.0-rc10",
+			"v2.0-rc2",
+		];
+
+		names.sort_by(|a, b| version_cmp(a, b, &[]));
+		assert_eq!(
+			names,
+			vec![
+				"v2.0",
+				"v2.0-rc1",
+				"v2.0-rc2",
+				"v2.0-rc10",
+				"v10.0"
+			]
+		);
+
+		names.sort_by(|a, b| version_cmp(a, b, &suffixes));
+		assert_eq!(
+			names,
+			vec![
+				"v2.0-rc1",
+				"v2.0-rc2",
+				"v2.0-rc10",
+				"v2.0",
+				"v10.0"
+			]
+		);
+
+		assert_eq!(
+			version_cmp("v1.02", "v1.2", &[]),
+			Ordering::Equal
+		);
+	}
+
+	#[test]
+	fn test_tag_created() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let head_id = repo.head().unwrap().target().unwrap();
+		let target = repo
+			.find_object(head_id, Some(ObjectType::Commit))
+			.unwrap();
+		let tagger = git2::Signature::new(
+			"name",
+			"email",
+			&git2::Time::new(42, 0),
+		)
+		.unwrap();
+
+		repo.tag("annotated", &target, &tagger, "msg", false)
+			.unwrap();
+		repo.tag_lightweight("light", &target, false).unwrap();
+
+		let tags = get_tags_with_metadata(repo_path).unwrap();
+		let created = |name: &str| {
+			tags.iter().find(|tag| tag.name == name).unwrap().created
+		};
+
+		assert_eq!(created("annotated"), 42);
+		assert_eq!(created("light"), tags[0].time);
+	}
+
+	#[test]
+	fn test_tag_kind() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let sig = repo.signature().unwrap();
+		let head_id = repo.head().unwrap().target().unwrap();
+		let target = repo
+			.find_object(head_id, Some(ObjectType::Commit))
+			.unwrap();
+
+		repo.tag("annotated", &target, &sig, "msg", false).unwrap();
+		repo.tag(
+			"signed",
+			&target,
+			&sig,
+			"msg\n-----BEGIN PGP SIGNATURE-----\nsig\n-----END PGP SIGNATURE-----\n",
+			false,
+		)
+		.unwrap();
+		repo.tag_lightweight("light", &target, false).unwrap();
+
+		let tags = get_tags_with_metadata(repo_path).unwrap();
+		let kind = |name: &str| {
+			tags.iter().find(|tag| tag.name == name).unwrap().kind
+		};
+
+		assert_eq!(kind("annotated"), TagKind::Annotated);
+		assert_eq!(kind("signed"), TagKind::Signed);
+		assert_eq!(kind("light"), TagKind::Lightweight);
+	}
 }
@@ -0,0 +1,213 @@
+//! commit message trailers like `Signed-off-by`
+//! see <https://git-scm.com/docs/git-interpret-trailers>
+
+use super::{commit::signature_allow_undefined_name, RepoPath};
+use crate::{error::Result, sync::repository::repo};
+use git2::Sort;
+use scopetime::scope_time;
+use std::collections::HashSet;
+
+const SIGNOFF_KEY: &str = "Signed-off-by";
+const COAUTHOR_KEY: &str = "Co-authored-by";
+
+/// `Signed-off-by` trailer built from `user.name` and `user.email`
+pub fn signoff_trailer(repo_path: &RepoPath) -> Result<String> {
+	scope_time!("signoff_trailer");
+
+	let repo = repo(repo_path)?;
+	let signature = signature_allow_undefined_name(&repo)?;
+
+	Ok(format!(
+		"{}: {} <{}>",
+		SIGNOFF_KEY,
+		signature.name().unwrap_or_default(),
+		signature.email().unwrap_or_default()
+	))
+}
+
+/// `Co-authored-by` trailer for `author` (formatted as `name <email>`)
+pub fn coauthor_trailer(author: &str) -> String {
+	format!("{}: {}", COAUTHOR_KEY, author)
+}
+
+/// possible co-authors (as `name <email>`), most recent first.
+///
+/// looks at the authors and `Co-authored-by` trailers of the last
+/// `max_commits` commits reachable from `HEAD`, skipping the current user.
+pub fn coauthor_candidates(
+	repo_path: &RepoPath,
+	max_commits: usize,
+) -> Result<Vec<String>> {
+	scope_time!("coauthor_candidates");
+
+	let repo = repo(repo_path)?;
+
+	let me = signature_allow_undefined_name(&repo)
+		.ok()
+		.and_then(|s| s.email().map(str::to_lowercase));
+
+	let mut walk = repo.revwalk()?;
+	if walk.push_head().is_err() {
+		// unborn head
+		return Ok(Vec::new());
+	}
+	walk.set_sorting(Sort::TIME)?;
+
+	let mut seen = HashSet::new();
+	let mut result = Vec::new();
+
+	let mut add = |name: &str, email: &str| {
+		let key = email.to_lowercase();
+		if me.as_ref() != Some(&key) && seen.insert(key) {
+			result.push(format!("{} <{}>", name, email));
+		}
+	};
+
+	for id in walk.take(max_commits) {
+		let commit = repo.find_commit(id?)?;
+
+		let author = commit.author();
+		if let (Some(name), Some(email)) =
+			(author.name(), author.email())
+		{
+			add(name, email);
+		}
+
+		for line in commit.message().unwrap_or_default().lines() {
+			if let Some((name, email)) = line
+				.strip_prefix(COAUTHOR_KEY)
+				.and_then(|rest| rest.strip_prefix(':'))
+				.and_then(|person| person.trim().split_once(" <"))
+			{
+				add(name, email.trim_end_matches('>'));
+			}
+		}
+	}
+
+	Ok(result)
+}
+
+fn is_trailer_line(line: &str) -> bool {
+	line.split_once(": ").map_or(false, |(key, _)| {
+		!key.is_empty()
+			&& key.chars().all(|c| c.is_alphanumeric() || c == '-')
+	})
+}
+
+/// appends `trailer` to the trailer block at the end of `msg`,
+/// starting a new block if there is none yet.
+/// does nothing if the exact trailer is already present.
+pub fn append_trailer(msg: &str, trailer: &str) -> String {
+	let msg = msg.trim_end();
+
+	if msg.lines().any(|line| line.trim() == trailer) {
+		return format!("{}\n", msg);
+	}
+
+	if msg.is_empty() {
+		return format!("{}\n", trailer);
+	}
+
+	let last_paragraph =
+		msg.rsplit("\n\n").next().unwrap_or_default();
+	let has_trailer_block = msg.contains("\n\n")
+		&& last_paragraph.lines().all(is_trailer_line);
+
+	if has_trailer_block {
+		format!("{}\n{}\n", msg, trailer)
+	} else {
+		format!("{}\n\n{}\n", msg, trailer)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::tests::repo_init;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn test_append_new_block() {
+		assert_eq!(
+			append_trailer("subject", "Signed-off-by: a <b>"),
+			"subject\n\nSigned-off-by: a <b>\n"
+		);
+		assert_eq!(
+			append_trailer(
+				"subject\n\nbody\n",
+				"Signed-off-by: a <b>"
+			),
+			"subject\n\nbody\n\nSigned-off-by: a <b>\n"
+		);
+	}
+
+	#[test]
+	fn test_append_existing_block() {
+		assert_eq!(
+			append_trailer(
+				"subject\n\nCo-authored-by: c <d>",
+				"Signed-off-by: a <b>"
+			),
+			"subject\n\nCo-authored-by: c <d>\nSigned-off-by: a <b>\n"
+		);
+	}
+
+	#[test]
+	fn test_append_subject_only_is_no_block() {
+		assert_eq!(
+			append_trailer("fix: stuff", "Signed-off-by: a <b>"),
+			"fix: stuff\n\nSigned-off-by: a <b>\n"
+		);
+	}
+
+	#[test]
+	fn test_append_twice() {
+		let msg = append_trailer("subject", "Signed-off-by: a <b>");
+		assert_eq!(append_trailer(&msg, "Signed-off-by: a <b>"), msg);
+	}
+
+	#[test]
+	fn test_coauthor_candidates() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let sig =
+			git2::Signature::now("other", "other@mail").unwrap();
+		let tree = repo
+			.find_tree(repo.index().unwrap().write_tree().unwrap())
+			.unwrap();
+		let head = repo.head().unwrap().peel_to_commit().unwrap();
+		repo.commit(
+			Some("HEAD"),
+			&sig,
+			&sig,
+			"msg\n\nCo-authored-by: third <third@mail>",
+			&tree,
+			&[&head],
+		)
+		.unwrap();
+
+		assert_eq!(
+			coauthor_candidates(repo_path, 10).unwrap(),
+			vec![
+				String::from("other <other@mail>"),
+				String::from("third <third@mail>")
+			]
+		);
+	}
+
+	#[test]
+	fn test_signoff_trailer() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		assert_eq!(
+			signoff_trailer(repo_path).unwrap(),
+			"Signed-off-by: name <email>"
+		);
+	}
+}
@@ -3,7 +3,7 @@ use crate::{
 	error::{Error, Result},
 	sync::repository::repo,
 };
-use git2::{Oid, Repository, Tree};
+use git2::{Blob, Odb, Oid, Repository, Tree};
 use scopetime::scope_time;
 use std::{
 	cmp::Ordering,
@@ -17,6 +17,8 @@ pub struct TreeFile {
 	pub path: PathBuf,
 	/// unix filemode
 	pub filemode: i32,
+	/// size of the blob in bytes
+	pub size: u64,
 	// internal object id
 	id: Oid,
 }
@@ -33,9 +35,16 @@ pub fn tree_files(
 	let commit = repo.find_commit(commit.into())?;
 	let tree = commit.tree()?;
 
+	let odb = repo.odb()?;
 	let mut files: Vec<TreeFile> = Vec::new();
 
-	tree_recurse(&repo, &PathBuf::from("./"), &tree, &mut files)?;
+	tree_recurse(
+		&repo,
+		&odb,
+		&PathBuf::from("./"),
+		&tree,
+		&mut files,
+	)?;
 
 	sort_file_list(&mut files);
 
@@ -91,9 +100,55 @@ pub fn tree_file_content(
 	Ok(content)
 }
 
+/// content of `path` as of `commit`, will only work on utf8 content
+pub fn commit_file_content(
+	repo_path: &RepoPath,
+	commit: CommitId,
+	path: &str,
+) -> Result<String> {
+	scope_time!("commit_file_content");
+
+	let repo = repo(repo_path)?;
+	let blob = commit_file_blob(&repo, commit, path)?;
+
+	if blob.is_binary() {
+		return Err(Error::BinaryFile);
+	}
+
+	let content = String::from_utf8_lossy(blob.content()).to_string();
+
+	Ok(content)
+}
+
+/// raw content of the file at `path` as of `commit`, binary or not
+pub fn commit_file_bytes(
+	repo_path: &RepoPath,
+	commit: CommitId,
+	path: &str,
+) -> Result<Vec<u8>> {
+	scope_time!("commit_file_bytes");
+
+	let repo = repo(repo_path)?;
+	let blob = commit_file_blob(&repo, commit, path)?;
+
+	Ok(blob.content().to_vec())
+}
+
+fn commit_file_blob<'a>(
+	repo: &'a Repository,
+	commit: CommitId,
+	path: &str,
+) -> Result<Blob<'a>> {
+	let tree = repo.find_commit(commit.into())?.tree()?;
+	let blob = tree.get_path(Path::new(path))?.to_object(repo)?;
+
+	Ok(blob.peel_to_blob()?)
+}
+
 ///
 fn tree_recurse(
 	repo: &Repository,
+	odb: &Odb,
 	path: &Path,
 	tree: &Tree,
 	out: &mut Vec<TreeFile>,
@@ -107,12 +162,18 @@ fn tree_recurse(
 			Some(git2::ObjectType::Blob) => {
 				let id = e.id();
 				let filemode = e.filemode();
-				out.push(TreeFile { path, filemode, id });
+				let (size, _) = odb.read_header(id)?;
+				out.push(TreeFile {
+					path,
+					filemode,
+					size: size as u64,
+					id,
+				});
 			}
 			Some(git2::ObjectType::Tree) => {
 				let obj = e.to_object(repo)?;
 				let tree = obj.peel_to_tree()?;
-				tree_recurse(repo, &path, &tree, out)?;
+				tree_recurse(repo, odb, &path, &tree, out)?;
 			}
 			Some(_) | None => (),
 		}
@@ -140,6 +201,7 @@ mod tests {
 
 		assert_eq!(files.len(), 1);
 		assert_eq!(files[0].path, PathBuf::from("./test.txt"));
+		assert_eq!(files[0].size, 7);
 
 		let c2 =
 			write_commit_file(&repo, "test.txt", "content2", "c2");
@@ -154,6 +216,28 @@ mod tests {
 		assert_ne!(files_c2[0], files[0]);
 	}
 
+	#[test]
+	fn test_commit_file_content() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let c1 =
+			write_commit_file(&repo, "test.txt", "content", "c1");
+		write_commit_file(&repo, "test.txt", "content2", "c2");
+
+		assert_eq!(
+			commit_file_content(repo_path, c1, "test.txt").unwrap(),
+			"content"
+		);
+		assert!(commit_file_content(repo_path, c1, "b.txt").is_err());
+		assert_eq!(
+			commit_file_bytes(repo_path, c1, "test.txt").unwrap(),
+			b"content"
+		);
+	}
+
 	#[test]
 	fn test_sorting() {
 		let mut list = vec!["file", "folder/file", "folder/afile"]
@@ -161,6 +245,7 @@ mod tests {
 			.map(|f| TreeFile {
 				path: PathBuf::from(f),
 				filemode: 0,
+				size: 0,
 				id: Oid::zero(),
 			})
 			.collect::<Vec<_>>();
@@ -186,6 +271,7 @@ mod tests {
 			.map(|f| TreeFile {
 				path: PathBuf::from(f),
 				filemode: 0,
+				size: 0,
 				id: Oid::zero(),
 			})
 			.collect::<Vec<_>>();
@@ -210,6 +296,7 @@ mod tests {
 			.map(|f| TreeFile {
 				path: PathBuf::from(f),
 				filemode: 0,
+				size: 0,
 				id: Oid::zero(),
 			})
 			.collect::<Vec<_>>();
@@ -86,6 +86,19 @@ pub fn get_head_repo(repo: &Repository) -> Result<CommitId> {
 	head.map_or(Err(Error::NoHead), |head_id| Ok(head_id.into()))
 }
 
+/// commit a revision like `HEAD~2`, a branch, tag or hash points to
+pub fn resolve_revision(
+	repo_path: &RepoPath,
+	revision: &str,
+) -> Result<CommitId> {
+	scope_time!("resolve_revision");
+
+	let repo = repo(repo_path)?;
+	let commit = repo.revparse_single(revision)?.peel_to_commit()?;
+
+	Ok(commit.id().into())
+}
+
 /// add a file diff from workingdir to stage (will not add removed files see `stage_addremoved`)
 pub fn stage_add_file(
 	repo_path: &RepoPath,
@@ -459,4 +472,25 @@ mod tests {
 
 		Ok(())
 	}
+
+	#[test]
+	fn test_resolve_revision() -> Result<()> {
+		let (_td, repo) = repo_init()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let first = get_head(repo_path)?;
+		let second = write_commit_file(&repo, "f.txt", "", "second");
+
+		assert_eq!(resolve_revision(repo_path, "HEAD")?, second);
+		assert_eq!(resolve_revision(repo_path, "HEAD~")?, first);
+		assert_eq!(
+			resolve_revision(repo_path, &first.to_string())?,
+			first
+		);
+		assert!(resolve_revision(repo_path, "nope").is_err());
+
+		Ok(())
+	}
 }
@@ -0,0 +1,287 @@
+//! git worktree management
+
+use super::{repo, RepoPath};
+use crate::error::{Error, Result};
+use git2::{
+	BranchType, Repository, StatusOptions, WorktreeAddOptions,
+	WorktreeLockStatus, WorktreePruneOptions,
+};
+use scopetime::scope_time;
+use std::path::{Path, PathBuf};
+
+///
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub struct WorktreeInfo {
+	/// name git tracks a linked worktree by, `None` for the main one
+	pub name: Option<String>,
+	///
+	pub path: PathBuf,
+	/// checked out branch, `None` if `HEAD` is detached
+	pub branch: Option<String>,
+	///
+	pub is_dirty: bool,
+	/// the worktree gitui currently runs in
+	pub is_current: bool,
+	/// the worktree directory is gone and can be pruned
+	pub is_stale: bool,
+	///
+	pub is_locked: bool,
+}
+
+impl WorktreeInfo {
+	///
+	pub fn repo_path(&self) -> RepoPath {
+		RepoPath::Path(self.path.clone())
+	}
+}
+
+/// the repository owning the linked worktrees
+fn main_repo(repo: Repository) -> Result<Repository> {
+	if !repo.is_worktree() {
+		return Ok(repo);
+	}
+
+	let commondir =
+		std::fs::read_to_string(repo.path().join("commondir"))?;
+
+	Ok(Repository::open(repo.path().join(commondir.trim()))?)
+}
+
+fn same_path(a: &Path, b: &Path) -> bool {
+	match (a.canonicalize(), b.canonicalize()) {
+		(Ok(a), Ok(b)) => a == b,
+		_ => a == b,
+	}
+}
+
+fn is_dirty(repo: &Repository) -> Result<bool> {
+	let statuses = repo.statuses(Some(
+		StatusOptions::new()
+			.include_untracked(true)
+			.include_ignored(false)
+			.exclude_submodules(true),
+	))?;
+
+	Ok(!statuses.is_empty())
+}
+
+fn head_branch(repo: &Repository) -> Option<String> {
+	if repo.head_detached().unwrap_or_default() {
+		return None;
+	}
+
+	repo.head().ok()?.shorthand().map(String::from)
+}
+
+fn worktree_info(
+	name: Option<String>,
+	path: &Path,
+	current: &Path,
+	is_locked: bool,
+) -> Result<WorktreeInfo> {
+	let (branch, is_dirty, is_stale) = match Repository::open(path) {
+		Ok(repo) => (head_branch(&repo), is_dirty(&repo)?, false),
+		Err(_) => (None, false, true),
+	};
+
+	Ok(WorktreeInfo {
+		name,
+		path: path.to_path_buf(),
+		branch,
+		is_dirty,
+		is_current: same_path(path, current),
+		is_stale,
+		is_locked,
+	})
+}
+
+/// lists the main worktree followed by all linked ones
+pub fn get_worktrees(
+	repo_path: &RepoPath,
+) -> Result<Vec<WorktreeInfo>> {
+	scope_time!("get_worktrees");
+
+	let repo = repo(repo_path)?;
+	let current =
+		repo.workdir().ok_or(Error::NoWorkDir)?.to_path_buf();
+	let main = main_repo(repo)?;
+
+	let mut res = Vec::new();
+
+	if let Some(workdir) = main.workdir() {
+		res.push(worktree_info(None, workdir, &current, false)?);
+	}
+
+	for name in main.worktrees()?.iter().flatten() {
+		let worktree = main.find_worktree(name)?;
+		let is_locked = matches!(
+			worktree.is_locked()?,
+			WorktreeLockStatus::Locked(_)
+		);
+
+		res.push(worktree_info(
+			Some(name.to_string()),
+			worktree.path(),
+			&current,
+			is_locked,
+		)?);
+	}
+
+	Ok(res)
+}
+
+/// checks out the local `branch` into a new worktree at `path`,
+/// the worktree is named after the last component of `path`
+pub fn add_worktree(
+	repo_path: &RepoPath,
+	path: &Path,
+	branch: &str,
+) -> Result<()> {
+	scope_time!("add_worktree");
+
+	let repo = main_repo(repo(repo_path)?)?;
+
+	let name = path
+		.file_name()
+		.and_then(std::ffi::OsStr::to_str)
+		.ok_or_else(|| {
+			Error::Generic(format!(
+				"invalid worktree path: {}",
+				path.display()
+			))
+		})?;
+
+	let reference = repo
+		.find_branch(branch, BranchType::Local)?
+		.into_reference();
+
+	let mut options = WorktreeAddOptions::new();
+	options.reference(Some(&reference));
+
+	repo.worktree(name, path, Some(&options))?;
+
+	Ok(())
+}
+
+/// deletes the linked worktree `name` including its directory,
+/// refuses to throw away local changes
+pub fn remove_worktree(
+	repo_path: &RepoPath,
+	name: &str,
+) -> Result<()> {
+	scope_time!("remove_worktree");
+
+	let repo = main_repo(repo(repo_path)?)?;
+	let worktree = repo.find_worktree(name)?;
+
+	if let WorktreeLockStatus::Locked(_) = worktree.is_locked()? {
+		return Err(Error::Generic(format!(
+			"worktree '{}' is locked",
+			name
+		)));
+	}
+
+	if let Ok(wt_repo) = Repository::open(worktree.path()) {
+		if is_dirty(&wt_repo)? {
+			return Err(Error::UncommittedChanges);
+		}
+	}
+
+	worktree.prune(Some(
+		WorktreePruneOptions::new().valid(true).working_tree(true),
+	))?;
+
+	Ok(())
+}
+
+/// drops the administrative data of worktrees whose directory is gone,
+/// returns how many got pruned
+pub fn prune_worktrees(repo_path: &RepoPath) -> Result<usize> {
+	scope_time!("prune_worktrees");
+
+	let repo = main_repo(repo(repo_path)?)?;
+
+	let mut count = 0;
+	for name in repo.worktrees()?.iter().flatten() {
+		let worktree = repo.find_worktree(name)?;
+
+		if worktree.is_prunable(None)? {
+			worktree.prune(None)?;
+			count += 1;
+		}
+	}
+
+	Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::{
+		checkout_branch, create_branch,
+		tests::{repo_init, write_commit_file},
+	};
+	use std::fs;
+
+	#[test]
+	fn test_worktrees() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		write_commit_file(&repo, "test.txt", "test", "commit1");
+		create_branch(repo_path, "feature").unwrap();
+		checkout_branch(repo_path, "refs/heads/master").unwrap();
+
+		let wt_dir = tempfile::TempDir::new().unwrap();
+		let wt_path = wt_dir.path().join("feature-wt");
+
+		add_worktree(repo_path, &wt_path, "feature").unwrap();
+
+		let worktrees = get_worktrees(repo_path).unwrap();
+		assert_eq!(worktrees.len(), 2);
+		assert!(worktrees[0].is_current);
+		assert_eq!(worktrees[0].branch.as_deref(), Some("master"));
+		assert_eq!(worktrees[1].name.as_deref(), Some("feature-wt"));
+		assert_eq!(worktrees[1].branch.as_deref(), Some("feature"));
+		assert!(!worktrees[1].is_dirty);
+
+		let from_linked =
+			get_worktrees(&worktrees[1].repo_path()).unwrap();
+		assert_eq!(from_linked.len(), 2);
+		assert!(from_linked[1].is_current);
+
+		fs::write(wt_path.join("new.txt"), "new").unwrap();
+		assert!(get_worktrees(repo_path).unwrap()[1].is_dirty);
+		assert!(remove_worktree(repo_path, "feature-wt").is_err());
+
+		fs::remove_file(wt_path.join("new.txt")).unwrap();
+		remove_worktree(repo_path, "feature-wt").unwrap();
+
+		assert!(!wt_path.exists());
+		assert_eq!(get_worktrees(repo_path).unwrap().len(), 1);
+	}
+
+	#[test]
+	fn test_prune_worktrees() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		create_branch(repo_path, "feature").unwrap();
+		checkout_branch(repo_path, "refs/heads/master").unwrap();
+
+		let wt_dir = tempfile::TempDir::new().unwrap();
+		let wt_path = wt_dir.path().join("gone");
+
+		add_worktree(repo_path, &wt_path, "feature").unwrap();
+		fs::remove_dir_all(&wt_path).unwrap();
+
+		assert!(get_worktrees(repo_path).unwrap()[1].is_stale);
+		assert_eq!(prune_worktrees(repo_path).unwrap(), 1);
+		assert_eq!(get_worktrees(repo_path).unwrap().len(), 1);
+	}
+}
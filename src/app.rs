@@ -1,42 +1,61 @@
 use crate::{
-	accessors,
+	accessors, clipboard,
 	cmdbar::CommandBar,
 	components::{
-		event_pump, AppOption, BlameFileComponent,
-		BranchListComponent, CommandBlocking, CommandInfo,
-		CommitComponent, CompareCommitsComponent, Component,
-		ConfirmComponent, CreateBranchComponent, DrawableComponent,
+		event_pump, AddWorktreeComponent, AppOption, AutoFetch,
+		BlameFileComponent, BranchDescriptionComponent,
+		BranchListComponent, ClipboardBackend, CoAuthorsPopup,
+		CommandBlocking, CommandInfo, CommitComponent,
+		CommitMsgPickerPopup, CompactMode, CompareBranchesComponent,
+		CompareCommitsComponent, Component, ConfirmComponent,
+		CopyPathFormat, CreateBranchComponent, DeepenPopup,
+		DeleteTagsComponent, DrawableComponent, EditRemoteComponent,
 		ExternalEditorComponent, FetchComponent, FileFindPopup,
-		FileRevlogComponent, HelpComponent, InspectCommitComponent,
-		MsgComponent, OptionsPopupComponent, PullComponent,
-		PushComponent, PushTagsComponent, RenameBranchComponent,
-		RevisionFilesPopup, SharedOptions, StashMsgComponent,
+		FileRevlogComponent, FileViewPopup, GrepPopup, HelpComponent,
+		HookOutputPopup, InspectCommitComponent, LogViewerPopup,
+		MergeOptionsPopup, MouseSupport, MsgComponent, NerdIcons,
+		NetworkRetries, OptionsPopupComponent, PaneLayout,
+		PruneBranchesPopup, PullComponent, PushComponent,
+		PushTagsComponent, PushTargetPopup, RecentBranchesPopup,
+		RemotesPopup, RenameBranchComponent, RepoSwitcherPopup,
+		RevisionFilesPopup, RevisionPickerPopup, SharedOptions,
+		StartTab, StashBranchComponent, StashMsgComponent,
 		SubmodulesListComponent, TagCommitComponent,
-		TagListComponent,
+		TagListComponent, TagRemotePopup, TerminalTitle,
+		ThemePickerPopup, ToastTimeout, ToastsComponent,
+		WorktreesPopup,
 	},
-	input::{Input, InputEvent, InputState},
+	input::{set_mouse_capture, Input, InputEvent, InputState},
 	keys::{key_match, KeyConfig, SharedKeyConfig},
+	options_file::OptionsFile,
 	popup_stack::PopupStack,
 	queue::{
-		Action, InternalEvent, NeedsUpdate, Queue, StackablePopupOpen,
+		Action, AutostashOp, InternalEvent, NeedsUpdate, Queue,
+		StackablePopupOpen,
 	},
 	setup_popups,
 	strings::{self, order},
 	tabs::{FilesTab, Revlog, StashList, Stashing, Status},
-	ui::style::{SharedTheme, Theme},
+	title_bar::{
+		restore_terminal_title, save_terminal_title,
+		set_terminal_title, TitleBar,
+	},
+	ui::style::{SharedTheme, Theme, ThemePreset},
 	AsyncAppNotification, AsyncNotification,
 };
 use anyhow::{bail, Result};
 use asyncgit::{
-	sync::{self, RepoPathRef},
+	sync::{self, CommitId, RepoPath, RepoPathRef},
 	AsyncGitNotification, PushType,
 };
 use crossbeam_channel::Sender;
-use crossterm::event::{Event, KeyEvent};
+use crossterm::event::{
+	Event, KeyEvent, MouseButton, MouseEvent, MouseEventKind,
+};
 use std::{
 	cell::{Cell, RefCell},
-	path::Path,
-	rc::Rc,
+	fs,
+	path::{Path, PathBuf},
 };
 use tui::{
 	backend::Backend,
@@ -45,11 +64,21 @@ use tui::{
 	widgets::{Block, Borders, Tabs},
 	Frame,
 };
+use unicode_width::UnicodeWidthStr;
+
+/// why the app stopped running
+#[derive(Clone)]
+pub enum QuitState {
+	None,
+	Close,
+	/// restart the app in another repository
+	OpenRepo(RepoPath),
+}
 
 /// the main app type
 pub struct App {
 	repo: RepoPathRef,
-	do_quit: bool,
+	do_quit: QuitState,
 	help: HelpComponent,
 	msg: MsgComponent,
 	reset: ConfirmComponent,
@@ -57,23 +86,50 @@ pub struct App {
 	blame_file_popup: BlameFileComponent,
 	file_revlog_popup: FileRevlogComponent,
 	stashmsg_popup: StashMsgComponent,
+	stash_branch_popup: StashBranchComponent,
+	deepen_popup: DeepenPopup,
 	inspect_commit_popup: InspectCommitComponent,
 	compare_commits_popup: CompareCommitsComponent,
+	compare_branches_popup: CompareBranchesComponent,
 	external_editor_popup: ExternalEditorComponent,
 	revision_files_popup: RevisionFilesPopup,
+	file_view_popup: FileViewPopup,
 	find_file_popup: FileFindPopup,
+	grep_popup: GrepPopup,
+	theme_picker_popup: ThemePickerPopup,
+	coauthors_popup: CoAuthorsPopup,
+	commit_msg_picker_popup: CommitMsgPickerPopup,
+	revision_picker_popup: RevisionPickerPopup,
+	hook_output_popup: HookOutputPopup,
+	prune_branches_popup: PruneBranchesPopup,
+	merge_options_popup: MergeOptionsPopup,
 	push_popup: PushComponent,
 	push_tags_popup: PushTagsComponent,
+	push_target_popup: PushTargetPopup,
+	tag_remote_popup: TagRemotePopup,
 	pull_popup: PullComponent,
 	fetch_popup: FetchComponent,
 	tag_commit_popup: TagCommitComponent,
 	create_branch_popup: CreateBranchComponent,
 	rename_branch_popup: RenameBranchComponent,
+	branch_description_popup: BranchDescriptionComponent,
 	select_branch_popup: BranchListComponent,
+	recent_branches_popup: RecentBranchesPopup,
 	options_popup: OptionsPopupComponent,
 	submodule_popup: SubmodulesListComponent,
+	worktrees_popup: WorktreesPopup,
+	add_worktree_popup: AddWorktreeComponent,
+	remotes_popup: RemotesPopup,
+	edit_remote_popup: EditRemoteComponent,
 	tags_popup: TagListComponent,
+	delete_tags_popup: DeleteTagsComponent,
+	toasts: ToastsComponent,
+	log_viewer_popup: LogViewerPopup,
+	repo_switcher_popup: RepoSwitcherPopup,
 	cmdbar: RefCell<CommandBar>,
+	title_bar: TitleBar,
+	/// window title last set in the terminal
+	terminal_title: String,
 	tab: usize,
 	revlog: Revlog,
 	status_tab: Status,
@@ -83,9 +139,15 @@ pub struct App {
 	queue: Queue,
 	theme: SharedTheme,
 	key_config: SharedKeyConfig,
+	/// theme file to reload when no preset is picked
+	theme_file: PathBuf,
+	options: SharedOptions,
 	input: Input,
 	popup_stack: PopupStack,
 
+	/// where the tab titles were drawn last
+	tabs_area: Cell<Rect>,
+
 	// "Flags"
 	requires_redraw: Cell<bool>,
 	file_to_open: Option<String>,
@@ -100,13 +162,46 @@ impl App {
 		sender: &Sender<AsyncGitNotification>,
 		sender_app: &Sender<AsyncAppNotification>,
 		input: Input,
-		theme: Theme,
-		key_config: KeyConfig,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+		theme_file: PathBuf,
 	) -> Self {
 		let queue = Queue::new();
-		let theme = Rc::new(theme);
-		let key_config = Rc::new(key_config);
 		let options = SharedOptions::default();
+		options.borrow_mut().auto_fetch =
+			AutoFetch::load(&repo.borrow());
+		options.borrow_mut().network_retries =
+			NetworkRetries::load(&repo.borrow());
+		options.borrow_mut().copy_path_format =
+			CopyPathFormat::load(&repo.borrow());
+		options.borrow_mut().clipboard =
+			ClipboardBackend::load(&repo.borrow());
+		clipboard::set_backend(options.borrow().clipboard);
+		options.borrow_mut().mouse =
+			MouseSupport::load(&repo.borrow());
+		options.borrow_mut().icons = NerdIcons::load(&repo.borrow());
+		options.borrow_mut().compact =
+			CompactMode::load(&repo.borrow());
+		options.borrow_mut().terminal_title =
+			TerminalTitle::load(&repo.borrow());
+		options.borrow_mut().toast_timeout =
+			ToastTimeout::load(&repo.borrow());
+		options.borrow_mut().start_tab =
+			StartTab::load(&repo.borrow());
+		let options_file = OptionsFile::load();
+		options.borrow_mut().status_sort = options_file.status_sort;
+		options.borrow_mut().confirmations =
+			options_file.confirmations;
+		options.borrow_mut().status_layout =
+			PaneLayout::load(&options_file, "status");
+		options.borrow_mut().log_layout =
+			PaneLayout::load(&options_file, "log");
+		options.borrow_mut().files_layout =
+			PaneLayout::load(&options_file, "files");
+		options.borrow_mut().load_git_config(&repo.borrow());
+
+		theme.set_icons(options.borrow().icons.enabled());
+		theme.set_compact(options.borrow().compact.enabled());
 
 		Self {
 			input,
@@ -125,6 +220,7 @@ impl App {
 				&repo,
 				&queue,
 				sender,
+				sender_app,
 				&strings::blame_title(&key_config),
 				theme.clone(),
 				key_config.clone(),
@@ -137,12 +233,20 @@ impl App {
 				key_config.clone(),
 				options.clone(),
 			),
+			file_view_popup: FileViewPopup::new(
+				&repo,
+				&queue,
+				sender_app,
+				theme.clone(),
+				key_config.clone(),
+			),
 			revision_files_popup: RevisionFilesPopup::new(
 				repo.clone(),
 				&queue,
 				sender_app,
 				theme.clone(),
 				key_config.clone(),
+				options.clone(),
 			),
 			stashmsg_popup: StashMsgComponent::new(
 				repo.clone(),
@@ -150,6 +254,31 @@ impl App {
 				theme.clone(),
 				key_config.clone(),
 			),
+			stash_branch_popup: StashBranchComponent::new(
+				repo.clone(),
+				queue.clone(),
+				theme.clone(),
+				key_config.clone(),
+			),
+			deepen_popup: DeepenPopup::new(
+				repo.clone(),
+				&queue,
+				sender,
+				theme.clone(),
+				key_config.clone(),
+			),
+			grep_popup: GrepPopup::new(
+				repo.clone(),
+				&queue,
+				sender,
+				theme.clone(),
+				key_config.clone(),
+			),
+			theme_picker_popup: ThemePickerPopup::new(
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+			),
 			inspect_commit_popup: InspectCommitComponent::new(
 				&repo,
 				&queue,
@@ -164,6 +293,13 @@ impl App {
 				theme.clone(),
 				key_config.clone(),
 			),
+			compare_branches_popup: CompareBranchesComponent::new(
+				&repo,
+				&queue,
+				sender,
+				theme.clone(),
+				key_config.clone(),
+			),
 			external_editor_popup: ExternalEditorComponent::new(
 				theme.clone(),
 				key_config.clone(),
@@ -174,6 +310,19 @@ impl App {
 				sender,
 				theme.clone(),
 				key_config.clone(),
+				options.clone(),
+			),
+			push_target_popup: PushTargetPopup::new(
+				repo.clone(),
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+			),
+			tag_remote_popup: TagRemotePopup::new(
+				repo.clone(),
+				&queue,
+				theme.clone(),
+				key_config.clone(),
 			),
 			push_tags_popup: PushTagsComponent::new(
 				&repo,
@@ -188,6 +337,7 @@ impl App {
 				sender,
 				theme.clone(),
 				key_config.clone(),
+				options.clone(),
 			),
 			fetch_popup: FetchComponent::new(
 				repo.clone(),
@@ -195,6 +345,7 @@ impl App {
 				sender,
 				theme.clone(),
 				key_config.clone(),
+				options.clone(),
 			),
 			tag_commit_popup: TagCommitComponent::new(
 				repo.clone(),
@@ -214,11 +365,26 @@ impl App {
 				theme.clone(),
 				key_config.clone(),
 			),
+			branch_description_popup: BranchDescriptionComponent::new(
+				repo.clone(),
+				queue.clone(),
+				theme.clone(),
+				key_config.clone(),
+			),
 			select_branch_popup: BranchListComponent::new(
 				repo.clone(),
 				queue.clone(),
+				sender,
+				theme.clone(),
+				key_config.clone(),
+				options.clone(),
+			),
+			recent_branches_popup: RecentBranchesPopup::new(
+				repo.clone(),
+				&queue,
 				theme.clone(),
 				key_config.clone(),
+				options.clone(),
 			),
 			tags_popup: TagListComponent::new(
 				repo.clone(),
@@ -226,6 +392,13 @@ impl App {
 				sender,
 				theme.clone(),
 				key_config.clone(),
+				options.clone(),
+			),
+			delete_tags_popup: DeleteTagsComponent::new(
+				repo.clone(),
+				queue.clone(),
+				theme.clone(),
+				key_config.clone(),
 			),
 			options_popup: OptionsPopupComponent::new(
 				&queue,
@@ -233,26 +406,112 @@ impl App {
 				key_config.clone(),
 				options.clone(),
 			),
+			toasts: ToastsComponent::new(
+				options.clone(),
+				theme.clone(),
+				key_config.clone(),
+			),
+			log_viewer_popup: LogViewerPopup::new(
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+			),
+			repo_switcher_popup: RepoSwitcherPopup::new(
+				repo.clone(),
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+			),
 			submodule_popup: SubmodulesListComponent::new(
 				repo.clone(),
 				theme.clone(),
 				key_config.clone(),
 			),
+			worktrees_popup: WorktreesPopup::new(
+				repo.clone(),
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+			),
+			add_worktree_popup: AddWorktreeComponent::new(
+				repo.clone(),
+				queue.clone(),
+				theme.clone(),
+				key_config.clone(),
+			),
+			remotes_popup: RemotesPopup::new(
+				repo.clone(),
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+			),
+			edit_remote_popup: EditRemoteComponent::new(
+				repo.clone(),
+				queue.clone(),
+				theme.clone(),
+				key_config.clone(),
+			),
 			find_file_popup: FileFindPopup::new(
+				repo.clone(),
 				&queue,
 				theme.clone(),
 				key_config.clone(),
 			),
-			do_quit: false,
+			merge_options_popup: MergeOptionsPopup::new(
+				repo.clone(),
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+			),
+			prune_branches_popup: PruneBranchesPopup::new(
+				repo.clone(),
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+			),
+			coauthors_popup: CoAuthorsPopup::new(
+				repo.clone(),
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+			),
+			commit_msg_picker_popup: CommitMsgPickerPopup::new(
+				repo.clone(),
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+			),
+			revision_picker_popup: RevisionPickerPopup::new(
+				repo.clone(),
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+			),
+			hook_output_popup: HookOutputPopup::new(
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+			),
+			do_quit: QuitState::None,
 			cmdbar: RefCell::new(CommandBar::new(
 				theme.clone(),
 				key_config.clone(),
 			)),
+			title_bar: TitleBar::new(
+				repo.clone(),
+				theme.clone(),
+				key_config.clone(),
+			),
+			terminal_title: String::new(),
 			help: HelpComponent::new(
 				theme.clone(),
 				key_config.clone(),
 			),
-			msg: MsgComponent::new(theme.clone(), key_config.clone()),
+			msg: MsgComponent::new(
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+			),
 			tab: 0,
 			revlog: Revlog::new(
 				&repo,
@@ -260,6 +519,7 @@ impl App {
 				sender,
 				theme.clone(),
 				key_config.clone(),
+				options.clone(),
 			),
 			status_tab: Status::new(
 				repo.clone(),
@@ -267,7 +527,7 @@ impl App {
 				sender,
 				theme.clone(),
 				key_config.clone(),
-				options,
+				options.clone(),
 			),
 			stashing_tab: Stashing::new(
 				&repo,
@@ -275,6 +535,7 @@ impl App {
 				&queue,
 				theme.clone(),
 				key_config.clone(),
+				options.clone(),
 			),
 			stashlist_tab: StashList::new(
 				repo.clone(),
@@ -288,12 +549,16 @@ impl App {
 				&queue,
 				theme.clone(),
 				key_config.clone(),
+				options.clone(),
 			),
 			queue,
 			theme,
 			key_config,
+			theme_file,
+			options,
 			requires_redraw: Cell::new(false),
 			file_to_open: None,
+			tabs_area: Cell::new(Rect::new(0, 0, 0, 0)),
 			repo,
 			popup_stack: PopupStack::default(),
 		}
@@ -305,13 +570,16 @@ impl App {
 
 		self.cmdbar.borrow_mut().refresh_width(fsize.width);
 
+		// without the line below the tabs in compact mode
+		let tabs_height = if self.theme.compact() { 1 } else { 2 };
+
 		let chunks_main = Layout::default()
 			.direction(Direction::Vertical)
 			.constraints(
 				[
-					Constraint::Length(2),
+					Constraint::Length(tabs_height),
 					Constraint::Min(2),
-					Constraint::Length(self.cmdbar.borrow().height()),
+					Constraint::Length(1),
 				]
 				.as_ref(),
 			)
@@ -325,8 +593,10 @@ impl App {
 		// to make this scale better?
 		let fullscreen_popup_open =
 			self.revision_files_popup.is_visible()
+				|| self.file_view_popup.is_visible()
 				|| self.inspect_commit_popup.is_visible()
 				|| self.compare_commits_popup.is_visible()
+				|| self.compare_branches_popup.is_visible()
 				|| self.blame_file_popup.is_visible()
 				|| self.file_revlog_popup.is_visible();
 
@@ -342,6 +612,8 @@ impl App {
 			};
 		}
 
+		self.toasts.draw_toasts(f, chunks_main[1]);
+
 		self.draw_popups(f)?;
 
 		Ok(())
@@ -356,6 +628,10 @@ impl App {
 				return Ok(());
 			}
 
+			if let Event::Mouse(mouse) = ev {
+				return self.mouse_event(mouse);
+			}
+
 			let mut flags = NeedsUpdate::empty();
 
 			if event_pump(&ev, self.components_mut().as_mut_slice())?
@@ -365,46 +641,81 @@ impl App {
 			} else if let Event::Key(k) = &ev {
 				let new_flags = if key_match(
 					k,
-					self.key_config.keys.tab_toggle,
+					self.key_config.keys().tab_toggle,
 				) {
 					self.toggle_tabs(false)?;
 					NeedsUpdate::COMMANDS
 				} else if key_match(
 					k,
-					self.key_config.keys.tab_toggle_reverse,
+					self.key_config.keys().tab_toggle_reverse,
 				) {
 					self.toggle_tabs(true)?;
 					NeedsUpdate::COMMANDS
 				} else if key_match(
 					k,
-					self.key_config.keys.tab_status,
+					self.key_config.keys().tab_status,
 				) || key_match(
 					k,
-					self.key_config.keys.tab_log,
+					self.key_config.keys().tab_log,
 				) || key_match(
 					k,
-					self.key_config.keys.tab_files,
+					self.key_config.keys().tab_files,
 				) || key_match(
 					k,
-					self.key_config.keys.tab_stashing,
+					self.key_config.keys().tab_stashing,
 				) || key_match(
 					k,
-					self.key_config.keys.tab_stashes,
+					self.key_config.keys().tab_stashes,
 				) {
 					self.switch_tab(k)?;
 					NeedsUpdate::COMMANDS
 				} else if key_match(
 					k,
-					self.key_config.keys.cmd_bar_toggle,
+					self.key_config.keys().cmd_bar_toggle,
 				) {
-					self.cmdbar.borrow_mut().toggle_more();
+					self.cmdbar.borrow_mut().next_page();
 					NeedsUpdate::empty()
 				} else if key_match(
 					k,
-					self.key_config.keys.open_options,
+					self.key_config.keys().open_options,
 				) {
 					self.options_popup.show()?;
 					NeedsUpdate::ALL
+				} else if key_match(
+					k,
+					self.key_config.keys().toast_history,
+				) {
+					self.toasts.show()?;
+					NeedsUpdate::ALL
+				} else if key_match(
+					k,
+					self.key_config.keys().open_log_viewer,
+				) {
+					self.log_viewer_popup.open()?;
+					NeedsUpdate::ALL
+				} else if key_match(
+					k,
+					self.key_config.keys().open_repo_switcher,
+				) {
+					self.repo_switcher_popup.open()?;
+					NeedsUpdate::ALL
+				} else if key_match(
+					k,
+					self.key_config.keys().open_theme_picker,
+				) {
+					self.theme_picker_popup.open()?;
+					NeedsUpdate::ALL
+				} else if key_match(
+					k,
+					self.key_config.keys().open_grep,
+				) {
+					let commit = if self.files_tab.is_visible() {
+						self.files_tab.revision()
+					} else {
+						None
+					};
+					self.grep_popup.open(commit)?;
+					NeedsUpdate::ALL
 				} else {
 					NeedsUpdate::empty()
 				};
@@ -416,25 +727,72 @@ impl App {
 		} else if let InputEvent::State(polling_state) = ev {
 			self.external_editor_popup.hide();
 			if let InputState::Paused = polling_state {
-				let result = match self.file_to_open.take() {
-					Some(path) => {
-						ExternalEditorComponent::open_file_in_editor(
-							&self.repo.borrow(),
-							Path::new(&path),
-						)
+				if self.commit.is_signing_pending() {
+					if let Err(e) = self.commit.finish_signed_commit()
+					{
+						let msg =
+							format!("failed to sign commit:\n{}", e);
+						log::error!("{}", msg.as_str());
+						self.msg.show_error(msg.as_str())?;
+					}
+				} else if self.tag_commit_popup.is_signing_pending() {
+					if let Err(e) =
+						self.tag_commit_popup.finish_signed_tag()
+					{
+						let msg =
+							format!("failed to sign tag:\n{}", e);
+						log::error!("{}", msg.as_str());
+						self.msg.show_error(msg.as_str())?;
+					}
+				} else {
+					let result = match self.file_to_open.take() {
+						Some(path) => {
+							ExternalEditorComponent::open_file_in_editor(
+								&self.repo.borrow(),
+								Path::new(&path),
+							)
+						}
+						None => self.commit.show_editor(),
+					};
+
+					if let Err(e) = result {
+						let msg = format!(
+							"failed to launch editor:\n{}",
+							e
+						);
+						log::error!("{}", msg.as_str());
+						self.msg.show_error(msg.as_str())?;
 					}
-					None => self.commit.show_editor(),
-				};
-
-				if let Err(e) = result {
-					let msg =
-						format!("failed to launch editor:\n{}", e);
-					log::error!("{}", msg.as_str());
-					self.msg.show_error(msg.as_str())?;
 				}
 
 				self.requires_redraw.set(true);
 				self.input.set_polling(true);
+				self.process_queue(NeedsUpdate::COMMANDS)?;
+			}
+		}
+
+		Ok(())
+	}
+
+	/// removes notifications that timed out, returns whether the
+	/// screen needs to be redrawn
+	pub fn expire_toasts(&mut self) -> bool {
+		self.toasts.expire()
+	}
+
+	/// reports mouse events only while enabled in the options
+	pub fn update_mouse_capture(&self) -> Result<()> {
+		set_mouse_capture(self.options.borrow().mouse.enabled())
+	}
+
+	/// sets the window title when the repository, branch or pending
+	/// operation changed
+	fn update_terminal_title(&mut self) -> Result<()> {
+		if self.options.borrow().terminal_title.enabled() {
+			let title = self.title_bar.terminal_title();
+			if title != self.terminal_title {
+				set_terminal_title(&title)?;
+				self.terminal_title = title;
 			}
 		}
 
@@ -452,6 +810,9 @@ impl App {
 		self.files_tab.update()?;
 		self.stashing_tab.update()?;
 		self.stashlist_tab.update()?;
+		self.title_bar.update();
+		self.update_terminal_title()?;
+		self.log_viewer_popup.update();
 
 		self.update_commands();
 
@@ -473,15 +834,20 @@ impl App {
 			self.file_revlog_popup.update_git(ev)?;
 			self.inspect_commit_popup.update_git(ev)?;
 			self.compare_commits_popup.update_git(ev)?;
+			self.compare_branches_popup.update_git(ev)?;
 			self.push_popup.update_git(ev)?;
 			self.push_tags_popup.update_git(ev)?;
 			self.pull_popup.update_git(ev);
 			self.fetch_popup.update_git(ev);
+			self.deepen_popup.update_git(ev);
+			self.grep_popup.update_git(ev);
 			self.select_branch_popup.update_git(ev)?;
 		}
 
 		self.files_tab.update_async(ev);
 		self.revision_files_popup.update(ev);
+		self.file_view_popup.update(ev);
+		self.blame_file_popup.update_async(ev);
 		self.tags_popup.update(ev);
 
 		//TODO: better system for this
@@ -493,25 +859,40 @@ impl App {
 
 	///
 	pub fn is_quit(&self) -> bool {
-		self.do_quit || self.input.is_aborted()
+		!matches!(self.do_quit, QuitState::None)
+			|| self.input.is_aborted()
+	}
+
+	///
+	pub fn quit_state(&self) -> QuitState {
+		if self.input.is_aborted() {
+			QuitState::Close
+		} else {
+			self.do_quit.clone()
+		}
 	}
 
 	///
 	pub fn any_work_pending(&self) -> bool {
 		self.status_tab.anything_pending()
 			|| self.revlog.any_work_pending()
+			|| self.deepen_popup.is_pending()
+			|| self.grep_popup.any_work_pending()
 			|| self.stashing_tab.anything_pending()
 			|| self.files_tab.anything_pending()
 			|| self.blame_file_popup.any_work_pending()
 			|| self.file_revlog_popup.any_work_pending()
 			|| self.inspect_commit_popup.any_work_pending()
 			|| self.compare_commits_popup.any_work_pending()
+			|| self.compare_branches_popup.any_work_pending()
 			|| self.input.is_state_changing()
 			|| self.push_popup.any_work_pending()
 			|| self.push_tags_popup.any_work_pending()
 			|| self.pull_popup.any_work_pending()
+			|| self.select_branch_popup.any_work_pending()
 			|| self.fetch_popup.any_work_pending()
 			|| self.revision_files_popup.any_work_pending()
+			|| self.file_view_popup.any_work_pending()
 			|| self.tags_popup.any_work_pending()
 	}
 
@@ -534,12 +915,21 @@ impl App {
 			find_file_popup,
 			msg,
 			reset,
+			hook_output_popup,
+			coauthors_popup,
+			commit_msg_picker_popup,
+			revision_picker_popup,
 			commit,
+			file_view_popup,
+			grep_popup,
 			blame_file_popup,
 			file_revlog_popup,
 			stashmsg_popup,
+			stash_branch_popup,
+			deepen_popup,
 			inspect_commit_popup,
 			compare_commits_popup,
+			compare_branches_popup,
 			external_editor_popup,
 			push_popup,
 			push_tags_popup,
@@ -548,11 +938,26 @@ impl App {
 			tag_commit_popup,
 			create_branch_popup,
 			rename_branch_popup,
+			branch_description_popup,
+			prune_branches_popup,
+			merge_options_popup,
+			theme_picker_popup,
 			select_branch_popup,
+			recent_branches_popup,
 			revision_files_popup,
 			submodule_popup,
+			worktrees_popup,
+			add_worktree_popup,
+			remotes_popup,
+			edit_remote_popup,
+			push_target_popup,
+			tag_remote_popup,
+			delete_tags_popup,
 			tags_popup,
 			options_popup,
+			toasts,
+			log_viewer_popup,
+			repo_switcher_popup,
 			help,
 			revlog,
 			status_tab,
@@ -566,38 +971,64 @@ impl App {
 		self,
 		[
 			commit,
+			coauthors_popup,
+			commit_msg_picker_popup,
+			hook_output_popup,
 			stashmsg_popup,
+			stash_branch_popup,
+			deepen_popup,
 			help,
 			inspect_commit_popup,
 			compare_commits_popup,
+			compare_branches_popup,
 			blame_file_popup,
 			file_revlog_popup,
 			external_editor_popup,
-			tag_commit_popup,
 			select_branch_popup,
+			recent_branches_popup,
 			submodule_popup,
+			worktrees_popup,
+			add_worktree_popup,
+			remotes_popup,
+			edit_remote_popup,
 			tags_popup,
+			tag_remote_popup,
+			delete_tags_popup,
+			tag_commit_popup,
 			create_branch_popup,
 			rename_branch_popup,
+			branch_description_popup,
+			prune_branches_popup,
+			merge_options_popup,
+			theme_picker_popup,
 			revision_files_popup,
+			grep_popup,
+			file_view_popup,
 			find_file_popup,
+			revision_picker_popup,
+			push_target_popup,
 			push_popup,
 			push_tags_popup,
 			pull_popup,
 			fetch_popup,
 			options_popup,
+			toasts,
+			log_viewer_popup,
+			repo_switcher_popup,
 			reset,
 			msg
 		]
 	);
 
 	fn check_quit(&mut self, ev: &Event) -> bool {
-		if self.any_popup_visible() {
+		if self.any_popup_visible()
+			|| self.stashlist_tab.is_filtering()
+		{
 			return false;
 		}
 		if let Event::Key(e) = ev {
-			if key_match(e, self.key_config.keys.quit) {
-				self.do_quit = true;
+			if key_match(e, self.key_config.keys().quit) {
+				self.do_quit = QuitState::Close;
 				return true;
 			}
 		}
@@ -606,8 +1037,8 @@ impl App {
 
 	fn check_hard_exit(&mut self, ev: &Event) -> bool {
 		if let Event::Key(e) = ev {
-			if key_match(e, self.key_config.keys.exit) {
-				self.do_quit = true;
+			if key_match(e, self.key_config.keys().exit) {
+				self.do_quit = QuitState::Close;
 				return true;
 			}
 		}
@@ -624,6 +1055,114 @@ impl App {
 		]
 	}
 
+	/// clicks on tab titles and command bar entries are handled here,
+	/// everything else goes to the components. the wheel scrolls like
+	/// the arrow keys after giving components a chance to focus the
+	/// pane under the cursor.
+	fn mouse_event(&mut self, mouse: MouseEvent) -> Result<()> {
+		if !self.options.borrow().mouse.enabled() {
+			return Ok(());
+		}
+
+		if mouse.kind == MouseEventKind::Down(MouseButton::Left) {
+			let command = self
+				.cmdbar
+				.borrow_mut()
+				.click(mouse.column, mouse.row);
+			if let Some(key) = command.and_then(|name| {
+				self.key_config.key_from_command(&name)
+			}) {
+				return self.event(InputEvent::Input(Event::Key(
+					(&key).into(),
+				)));
+			}
+
+			if !self.any_popup_visible() {
+				if let Some(tab) =
+					self.tab_at(mouse.column, mouse.row)
+				{
+					self.set_tab(tab)?;
+				}
+			}
+		}
+
+		event_pump(
+			&Event::Mouse(mouse),
+			self.components_mut().as_mut_slice(),
+		)?;
+
+		let popup = self.any_popup_visible();
+		let scroll = match mouse.kind {
+			MouseEventKind::ScrollDown if popup => {
+				Some(self.key_config.keys().popup_down)
+			}
+			MouseEventKind::ScrollDown => {
+				Some(self.key_config.keys().move_down)
+			}
+			MouseEventKind::ScrollUp if popup => {
+				Some(self.key_config.keys().popup_up)
+			}
+			MouseEventKind::ScrollUp => {
+				Some(self.key_config.keys().move_up)
+			}
+			_ => None,
+		};
+
+		if let Some(key) = scroll {
+			return self
+				.event(InputEvent::Input(Event::Key((&key).into())));
+		}
+
+		self.process_queue(NeedsUpdate::COMMANDS)
+	}
+
+	fn tab_titles(&self) -> [String; 5] {
+		[
+			strings::tab_status(&self.key_config),
+			strings::tab_log(&self.key_config),
+			strings::tab_files(&self.key_config),
+			strings::tab_stashing(&self.key_config),
+			strings::tab_stashes(&self.key_config),
+		]
+	}
+
+	/// width the `Tabs` widget needs for all titles
+	fn tabs_width(&self) -> u16 {
+		let divider = strings::tab_divider(&self.key_config).width();
+		let titles = self.tab_titles();
+		let width = titles
+			.iter()
+			.map(|title| title.width() + 2)
+			.sum::<usize>()
+			+ divider * titles.len().saturating_sub(1);
+
+		u16::try_from(width).unwrap_or(u16::MAX)
+	}
+
+	/// index of the tab whose title is drawn at the given position,
+	/// following the layout of the `Tabs` widget
+	fn tab_at(&self, column: u16, row: u16) -> Option<usize> {
+		let area = self.tabs_area.get();
+		if row != area.y {
+			return None;
+		}
+
+		let column = usize::from(column);
+		let divider = strings::tab_divider(&self.key_config).width();
+		let mut x = usize::from(area.x);
+
+		for (tab, title) in self.tab_titles().iter().enumerate() {
+			let width = title.width();
+			x += 1;
+			if (x..x + width).contains(&column) {
+				return Some(tab);
+			}
+			x += width + 1 + divider;
+		}
+
+		None
+	}
+
 	fn toggle_tabs(&mut self, reverse: bool) -> Result<()> {
 		let tabs_len = self.get_tabs().len();
 		let new_tab = if reverse {
@@ -636,22 +1175,22 @@ impl App {
 	}
 
 	fn switch_tab(&mut self, k: &KeyEvent) -> Result<()> {
-		if key_match(k, self.key_config.keys.tab_status) {
+		if key_match(k, self.key_config.keys().tab_status) {
 			self.set_tab(0)?;
-		} else if key_match(k, self.key_config.keys.tab_log) {
+		} else if key_match(k, self.key_config.keys().tab_log) {
 			self.set_tab(1)?;
-		} else if key_match(k, self.key_config.keys.tab_files) {
+		} else if key_match(k, self.key_config.keys().tab_files) {
 			self.set_tab(2)?;
-		} else if key_match(k, self.key_config.keys.tab_stashing) {
+		} else if key_match(k, self.key_config.keys().tab_stashing) {
 			self.set_tab(3)?;
-		} else if key_match(k, self.key_config.keys.tab_stashes) {
+		} else if key_match(k, self.key_config.keys().tab_stashes) {
 			self.set_tab(4)?;
 		}
 
 		Ok(())
 	}
 
-	fn set_tab(&mut self, tab: usize) -> Result<()> {
+	pub fn set_tab(&mut self, tab: usize) -> Result<()> {
 		let tabs = self.get_tabs();
 		for (i, t) in tabs.into_iter().enumerate() {
 			if tab == i {
@@ -667,10 +1206,13 @@ impl App {
 	}
 
 	fn update_commands(&mut self) {
+		let cmds = self.commands(false);
 		if self.help.is_visible() {
 			self.help.set_cmds(self.commands(true));
+		} else {
+			self.help.set_context_cmds(&cmds);
 		}
-		self.cmdbar.borrow_mut().set_cmds(self.commands(false));
+		self.cmdbar.borrow_mut().set_cmds(cmds);
 	}
 
 	fn process_queue(&mut self, flags: NeedsUpdate) -> Result<()> {
@@ -685,8 +1227,10 @@ impl App {
 		//NOTE: set when any tree component changed selection
 		if flags.contains(NeedsUpdate::DIFF) {
 			self.status_tab.update_diff()?;
+			self.stashing_tab.update_diff()?;
 			self.inspect_commit_popup.update_diff()?;
 			self.compare_commits_popup.update_diff()?;
+			self.compare_branches_popup.update_diff()?;
 			self.file_revlog_popup.update_diff()?;
 		}
 		if flags.contains(NeedsUpdate::COMMANDS) {
@@ -695,10 +1239,60 @@ impl App {
 		if flags.contains(NeedsUpdate::BRANCHES) {
 			self.select_branch_popup.update_branches()?;
 		}
+		if flags.contains(NeedsUpdate::TAGS)
+			&& self.tags_popup.is_visible()
+		{
+			self.tags_popup.update_tags()?;
+		}
+
+		Ok(())
+	}
+
+	/// opens a view right away, e.g. when started via `gitui blame`
+	/// or `--commit`
+	pub fn open_on_start(
+		&mut self,
+		open: StackablePopupOpen,
+	) -> Result<()> {
+		self.open_popup(open)
+	}
+
+	/// tab to start on as configured for the repository
+	pub fn start_tab(&self) -> usize {
+		self.options.borrow().start_tab.index()
+	}
+
+	///
+	pub fn show_error(&mut self, msg: &str) -> Result<()> {
+		self.msg.show_error(msg)
+	}
+
+	/// reads the theme and key config again and applies them in
+	/// place, keeping open popups, inputs and running jobs
+	pub fn reload_config(&mut self) -> Result<()> {
+		let theme = ThemePreset::load().theme().map_or_else(
+			|| Theme::reload(self.theme_file.clone()),
+			Ok,
+		)?;
+		let key_config = KeyConfig::reload()?;
+
+		self.theme.replace_colors(theme);
+		self.key_config.replace(key_config);
+		self.requires_redraw.set(true);
+		self.update_commands();
 
 		Ok(())
 	}
 
+	/// error popup that offers to send `retry` again
+	pub fn show_error_retry(
+		&mut self,
+		msg: &str,
+		retry: InternalEvent,
+	) -> Result<()> {
+		self.msg.show_error_retry(msg, retry)
+	}
+
 	fn open_popup(
 		&mut self,
 		popup: StackablePopupOpen,
@@ -713,12 +1307,21 @@ impl App {
 			StackablePopupOpen::FileTree(param) => {
 				self.revision_files_popup.open(param)?;
 			}
+			StackablePopupOpen::FileView(param) => {
+				self.file_view_popup.open(param)?;
+			}
+			StackablePopupOpen::Grep(commit) => {
+				self.grep_popup.open(commit)?;
+			}
 			StackablePopupOpen::InspectCommit(param) => {
 				self.inspect_commit_popup.open(param)?;
 			}
 			StackablePopupOpen::CompareCommits(param) => {
 				self.compare_commits_popup.open(param)?;
 			}
+			StackablePopupOpen::CompareBranches(param) => {
+				self.compare_branches_popup.open(param)?;
+			}
 		}
 
 		Ok(())
@@ -751,7 +1354,26 @@ impl App {
 				self.process_confirmed_action(action, &mut flags)?;
 			}
 			InternalEvent::ConfirmAction(action) => {
-				self.reset.open(action)?;
+				if self
+					.options
+					.borrow()
+					.confirmations
+					.required(&action)
+				{
+					self.reset.open(action)?;
+					flags.insert(NeedsUpdate::COMMANDS);
+				} else {
+					self.process_confirmed_action(
+						action, &mut flags,
+					)?;
+				}
+			}
+			InternalEvent::ReloadConfig => {
+				if let Err(e) = self.reload_config() {
+					self.msg.show_error(
+						&strings::config_reload_error(&e.to_string()),
+					)?;
+				}
 				flags.insert(NeedsUpdate::COMMANDS);
 			}
 			InternalEvent::ShowErrorMsg(msg) => {
@@ -759,6 +1381,14 @@ impl App {
 				flags
 					.insert(NeedsUpdate::ALL | NeedsUpdate::COMMANDS);
 			}
+			InternalEvent::ShowErrorRetry(msg, retry) => {
+				self.msg.show_error_retry(msg.as_str(), *retry)?;
+				flags
+					.insert(NeedsUpdate::ALL | NeedsUpdate::COMMANDS);
+			}
+			InternalEvent::ShowToast(msg) => {
+				self.toasts.push(msg);
+			}
 			InternalEvent::ShowInfoMsg(msg) => {
 				self.msg.show_info(msg.as_str())?;
 				flags
@@ -766,13 +1396,27 @@ impl App {
 			}
 			InternalEvent::Update(u) => flags.insert(u),
 			InternalEvent::OpenCommit => self.commit.show()?,
-			InternalEvent::PopupStashing(opts) => {
-				self.stashmsg_popup.options(opts);
+			InternalEvent::PopupStashing(opts, paths) => {
+				self.stashmsg_popup.options(opts, paths);
 				self.stashmsg_popup.show()?;
 			}
+			InternalEvent::StashBranch(stash) => {
+				self.stash_branch_popup.open(stash)?;
+			}
+			InternalEvent::DeepenHistory => {
+				self.deepen_popup.open()?;
+			}
 			InternalEvent::TagCommit(id) => {
 				self.tag_commit_popup.open(id)?;
 			}
+			InternalEvent::TagRevision => {
+				let revision =
+					self.revlog.single_marked_commit().map_or_else(
+						|| String::from("HEAD"),
+						|id| id.to_string(),
+					);
+				self.tag_commit_popup.open_at_revision(revision)?;
+			}
 
 			InternalEvent::CreateBranch => {
 				self.create_branch_popup.open()?;
@@ -781,15 +1425,43 @@ impl App {
 				self.rename_branch_popup
 					.open(branch_ref, cur_name)?;
 			}
+			InternalEvent::EditBranchDescription(
+				branch,
+				description,
+			) => {
+				self.branch_description_popup
+					.open(branch, description)?;
+			}
 			InternalEvent::SelectBranch => {
 				self.select_branch_popup.open()?;
 			}
+			InternalEvent::OpenRecentBranches => {
+				self.recent_branches_popup.open()?;
+			}
 			InternalEvent::ViewSubmodules => {
 				self.submodule_popup.open()?;
 			}
+			InternalEvent::ViewWorktrees => {
+				self.worktrees_popup.open()?;
+			}
+			InternalEvent::ViewRemotes => {
+				self.remotes_popup.open()?;
+			}
+			InternalEvent::EditRemote(edit) => {
+				self.edit_remote_popup.open(edit)?;
+			}
+			InternalEvent::AddWorktree(branch) => {
+				self.add_worktree_popup.open(branch)?;
+			}
+			InternalEvent::OpenRepo(repo) => {
+				self.do_quit = QuitState::OpenRepo(repo);
+			}
 			InternalEvent::Tags => {
 				self.tags_popup.open()?;
 			}
+			InternalEvent::DeleteMatchingTags => {
+				self.delete_tags_popup.open()?;
+			}
 			InternalEvent::TabSwitchStatus => self.set_tab(0)?,
 			InternalEvent::SelectCommitInRevlog(id) => {
 				if let Err(error) = self.revlog.select_commit(id) {
@@ -807,11 +1479,90 @@ impl App {
 				self.file_to_open = path;
 				flags.insert(NeedsUpdate::COMMANDS);
 			}
+			InternalEvent::SuspendForSigning => {
+				self.input.set_polling(false);
+			}
+			InternalEvent::OpenMergeOptions(branch, branch_type) => {
+				self.merge_options_popup.open(branch, branch_type)?;
+				flags
+					.insert(NeedsUpdate::ALL | NeedsUpdate::COMMANDS);
+			}
+			InternalEvent::OpenPruneBranches => {
+				self.prune_branches_popup.open()?;
+				flags
+					.insert(NeedsUpdate::ALL | NeedsUpdate::COMMANDS);
+			}
+			InternalEvent::OpenCoAuthors => {
+				self.coauthors_popup.open()?;
+				flags
+					.insert(NeedsUpdate::ALL | NeedsUpdate::COMMANDS);
+			}
+			InternalEvent::AddCommitTrailer(trailer) => {
+				self.commit.add_trailer(&trailer);
+				flags
+					.insert(NeedsUpdate::ALL | NeedsUpdate::COMMANDS);
+			}
+			InternalEvent::OpenRevisionPicker => {
+				self.revision_picker_popup.open()?;
+				flags.insert(NeedsUpdate::COMMANDS);
+			}
+			InternalEvent::SelectFilesRevision(revision) => {
+				self.files_tab.set_revision(revision)?;
+				flags.insert(NeedsUpdate::COMMANDS);
+			}
+			InternalEvent::OpenCommitMsgPicker => {
+				self.commit_msg_picker_popup.open()?;
+				flags
+					.insert(NeedsUpdate::ALL | NeedsUpdate::COMMANDS);
+			}
+			InternalEvent::ReuseCommitMessage(id) => {
+				self.commit.reuse_message(id)?;
+				flags
+					.insert(NeedsUpdate::ALL | NeedsUpdate::COMMANDS);
+			}
+			InternalEvent::ShowHookOutput(hook, output) => {
+				self.hook_output_popup.open(hook, output)?;
+				flags
+					.insert(NeedsUpdate::ALL | NeedsUpdate::COMMANDS);
+			}
+			InternalEvent::CommitNoVerify => {
+				if let Err(e) = self.commit.commit_no_verify() {
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						format!("commit error:\n{}", e),
+					));
+				}
+				flags
+					.insert(NeedsUpdate::ALL | NeedsUpdate::COMMANDS);
+			}
 			InternalEvent::Push(branch, push_type, force, delete) => {
 				self.push_popup
 					.push(branch, push_type, force, delete)?;
 				flags.insert(NeedsUpdate::ALL);
 			}
+			InternalEvent::OpenPushTarget(branch) => {
+				self.push_target_popup.open(branch)?;
+				flags.insert(NeedsUpdate::COMMANDS);
+			}
+			InternalEvent::PushTo(branch, remote, remote_branch) => {
+				self.push_popup.push_to(
+					branch,
+					remote,
+					remote_branch,
+				)?;
+				flags.insert(NeedsUpdate::ALL);
+			}
+			InternalEvent::RetryPush => {
+				self.push_popup.retry()?;
+				flags.insert(NeedsUpdate::ALL);
+			}
+			InternalEvent::OpenTagRemote(tag, action) => {
+				self.tag_remote_popup.open(tag, action)?;
+				flags.insert(NeedsUpdate::COMMANDS);
+			}
+			InternalEvent::PushTag(tag, remote) => {
+				self.push_popup.push_tag(tag, remote, false)?;
+				flags.insert(NeedsUpdate::ALL);
+			}
 			InternalEvent::Pull(branch) => {
 				if let Err(error) = self.pull_popup.fetch(branch) {
 					self.queue.push(InternalEvent::ShowErrorMsg(
@@ -835,8 +1586,8 @@ impl App {
 			InternalEvent::StatusLastFileMoved => {
 				self.status_tab.last_file_moved()?;
 			}
-			InternalEvent::OpenFileFinder(files) => {
-				self.find_file_popup.open(&files)?;
+			InternalEvent::OpenFileFinder(files, revision) => {
+				self.find_file_popup.open(&files, revision)?;
 				flags
 					.insert(NeedsUpdate::ALL | NeedsUpdate::COMMANDS);
 			}
@@ -845,15 +1596,253 @@ impl App {
 					AppOption::StatusShowUntracked => {
 						self.status_tab.update()?;
 					}
+					AppOption::StatusSort => {
+						let sort = self.options.borrow().status_sort;
+						if let Err(e) = sort.save() {
+							self.queue.push(
+								InternalEvent::ShowErrorMsg(format!(
+									"saving sort order failed:\n{}",
+									e
+								)),
+							);
+						}
+						self.status_tab.update()?;
+					}
 					AppOption::DiffContextLines
 					| AppOption::DiffIgnoreWhitespaces
 					| AppOption::DiffInterhunkLines => {
 						self.status_tab.update_diff()?;
 					}
+					AppOption::AutoFetch => {
+						let auto_fetch =
+							self.options.borrow().auto_fetch;
+						if let Err(e) =
+							auto_fetch.save(&self.repo.borrow())
+						{
+							self.queue.push(
+								InternalEvent::ShowErrorMsg(format!(
+									"saving auto fetch failed:\n{}",
+									e
+								)),
+							);
+						}
+					}
+					AppOption::NetworkRetries => {
+						let retries =
+							self.options.borrow().network_retries;
+						if let Err(e) =
+							retries.save(&self.repo.borrow())
+						{
+							self.queue.push(
+								InternalEvent::ShowErrorMsg(format!(
+									"saving network retries failed:\n{}",
+									e
+								)),
+							);
+						}
+					}
+					AppOption::CopyPathFormat => {
+						let format =
+							self.options.borrow().copy_path_format;
+						if let Err(e) =
+							format.save(&self.repo.borrow())
+						{
+							self.queue.push(
+								InternalEvent::ShowErrorMsg(format!(
+									"saving copy path format failed:\n{}",
+									e
+								)),
+							);
+						}
+					}
+					AppOption::Clipboard => {
+						let backend = self.options.borrow().clipboard;
+						if let Err(e) =
+							backend.save(&self.repo.borrow())
+						{
+							self.queue.push(
+								InternalEvent::ShowErrorMsg(format!(
+									"saving clipboard setting failed:\n{}",
+									e
+								)),
+							);
+						}
+						clipboard::set_backend(backend);
+					}
+					AppOption::Mouse => {
+						let mouse = self.options.borrow().mouse;
+						if let Err(e) =
+							mouse.save(&self.repo.borrow())
+						{
+							self.queue.push(
+								InternalEvent::ShowErrorMsg(format!(
+									"saving mouse setting failed:\n{}",
+									e
+								)),
+							);
+						}
+						self.update_mouse_capture()?;
+					}
+					AppOption::TerminalTitle => {
+						let terminal_title =
+							self.options.borrow().terminal_title;
+						if let Err(e) =
+							terminal_title.save(&self.repo.borrow())
+						{
+							self.queue.push(
+								InternalEvent::ShowErrorMsg(format!(
+									"saving terminal title setting failed:\n{}",
+									e
+								)),
+							);
+						}
+
+						if !terminal_title.enabled() {
+							// keep the original title saved for the
+							// next time the setting is turned on
+							restore_terminal_title()?;
+							save_terminal_title()?;
+						}
+						self.terminal_title.clear();
+						self.update_terminal_title()?;
+					}
+					AppOption::Confirm(_) => {
+						if let Err(e) =
+							self.options.borrow().confirmations.save()
+						{
+							self.queue.push(
+								InternalEvent::ShowErrorMsg(format!(
+									"saving confirmation setting failed:\n{}",
+									e
+								)),
+							);
+						}
+					}
+					AppOption::StartTab => {
+						if let Err(e) = self
+							.options
+							.borrow()
+							.start_tab
+							.save(&self.repo.borrow())
+						{
+							self.queue.push(
+								InternalEvent::ShowErrorMsg(format!(
+									"saving start tab failed:\n{}",
+									e
+								)),
+							);
+						}
+					}
+					AppOption::ToastTimeout => {
+						if let Err(e) = self
+							.options
+							.borrow()
+							.toast_timeout
+							.save(&self.repo.borrow())
+						{
+							self.queue.push(
+								InternalEvent::ShowErrorMsg(format!(
+									"saving notification setting failed:\n{}",
+									e
+								)),
+							);
+						}
+					}
+					AppOption::Icons => {
+						let icons = self.options.borrow().icons;
+						self.theme.set_icons(icons.enabled());
+						if let Err(e) =
+							icons.save(&self.repo.borrow())
+						{
+							self.queue.push(
+								InternalEvent::ShowErrorMsg(format!(
+									"saving icon setting failed:\n{}",
+									e
+								)),
+							);
+						}
+					}
+					AppOption::Compact => {
+						let compact = self.options.borrow().compact;
+						self.theme.set_compact(compact.enabled());
+						if let Err(e) =
+							compact.save(&self.repo.borrow())
+						{
+							self.queue.push(
+								InternalEvent::ShowErrorMsg(format!(
+									"saving compact mode failed:\n{}",
+									e
+								)),
+							);
+						}
+					}
+					AppOption::StatusSplit
+					| AppOption::StatusOrientation
+					| AppOption::LogSplit
+					| AppOption::LogOrientation
+					| AppOption::FilesSplit
+					| AppOption::FilesOrientation => {
+						let options = *self.options.borrow();
+						let res = [
+							("status", options.status_layout),
+							("log", options.log_layout),
+							("files", options.files_layout),
+						]
+						.into_iter()
+						.try_for_each(|(tab, layout)| {
+							layout.save(tab)
+						});
+
+						if let Err(e) = res {
+							self.queue.push(
+								InternalEvent::ShowErrorMsg(format!(
+									"saving layout failed:\n{}",
+									e
+								)),
+							);
+						}
+					}
+					AppOption::Autostash
+					| AppOption::RebaseUpdateRefs
+					| AppOption::FetchPrune
+					| AppOption::PullMode
+					| AppOption::PushFollowTags => (),
 				}
 
 				flags.insert(NeedsUpdate::ALL);
 			}
+			InternalEvent::OpenExternally(path, commit) => {
+				if let Err(e) = self.open_externally(&path, commit) {
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						format!("open externally error:\n{}", e),
+					));
+				}
+			}
+			InternalEvent::CopyPath(path, line) => {
+				let work_dir =
+					sync::utils::repo_work_dir(&self.repo.borrow())?;
+				let text = self
+					.options
+					.borrow()
+					.copy_path_format
+					.format(&work_dir, &path, line);
+
+				if let Err(e) = clipboard::copy_string(&text) {
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						format!("copy to clipboard error:\n{}", e),
+					));
+				}
+			}
+			InternalEvent::FileFinderFilter(query) => {
+				if self.revision_files_popup.is_visible() {
+					self.revision_files_popup
+						.file_finder_filter(query)?;
+				} else {
+					self.files_tab.file_finder_filter(query)?;
+				}
+				flags
+					.insert(NeedsUpdate::ALL | NeedsUpdate::COMMANDS);
+			}
 			InternalEvent::FileFinderChanged(file) => {
 				self.files_tab.file_finder_update(&file);
 				self.revision_files_popup.file_finder_update(&file);
@@ -883,6 +1872,37 @@ impl App {
 		Ok(flags)
 	}
 
+	/// opens the work tree file, or a temporary copy of it as of
+	/// `commit`, with the default application of the os
+	fn open_externally(
+		&self,
+		path: &str,
+		commit: Option<CommitId>,
+	) -> Result<()> {
+		let repo = self.repo.borrow();
+
+		let file = if let Some(commit) = commit {
+			let content =
+				sync::commit_file_bytes(&repo, commit, path)?;
+			let dir = std::env::temp_dir()
+				.join("gitui")
+				.join(commit.get_short_string());
+			let file = dir.join(
+				Path::new(path).file_name().unwrap_or_default(),
+			);
+
+			fs::create_dir_all(&dir)?;
+			fs::write(&file, content)?;
+
+			file
+		} else {
+			PathBuf::from(sync::utils::repo_work_dir(&repo)?)
+				.join(path)
+		};
+
+		crate::opener::open_file(&file)
+	}
+
 	#[allow(clippy::too_many_lines)]
 	fn process_confirmed_action(
 		&mut self,
@@ -932,27 +1952,21 @@ impl App {
 				self.select_branch_popup.update_branches()?;
 			}
 			Action::DeleteRemoteBranch(branch_ref) => {
-				self.queue.push(
-					//TODO: check if this is correct based on the fix in `c6abbaf`
-					branch_ref.rsplit('/').next().map_or_else(
-						|| {
-							InternalEvent::ShowErrorMsg(format!(
-						"Failed to find the branch name in {}",
-						branch_ref
-					))
-						},
-						|name| {
-							InternalEvent::Push(
-								name.to_string(),
-								PushType::Branch,
-								false,
-								true,
-							)
-						},
-					),
-				);
+				match sync::split_remote_branch(
+					&self.repo.borrow(),
+					&branch_ref,
+				) {
+					Ok((remote, branch)) => {
+						self.push_popup
+							.delete_remote_branch(remote, branch)?;
+					}
+					Err(e) => {
+						self.queue.push(InternalEvent::ShowErrorMsg(
+							e.to_string(),
+						));
+					}
+				}
 				flags.insert(NeedsUpdate::ALL);
-				self.select_branch_popup.update_branches()?;
 			}
 			Action::DeleteTag(tag_name) => {
 				if let Err(error) =
@@ -974,15 +1988,49 @@ impl App {
 					self.tags_popup.update_tags()?;
 				}
 			}
-			Action::DeleteRemoteTag(tag_name, _remote) => {
-				self.queue.push(InternalEvent::Push(
-					tag_name,
-					PushType::Tag,
-					false,
-					true,
+			Action::DeleteRemoteTag(tag_name, remote) => {
+				self.push_popup.push_tag(tag_name, remote, true)?;
+			}
+			Action::DeleteTags(tags) => {
+				let mut deleted = Vec::new();
+				let mut failed = Vec::new();
+				for tag in tags {
+					if let Err(e) =
+						sync::delete_tag(&self.repo.borrow(), &tag)
+					{
+						failed.push((tag, e.to_string()));
+					} else {
+						deleted.push(tag);
+					}
+				}
+
+				self.queue.push(InternalEvent::ShowInfoMsg(
+					strings::delete_tags_summary(&deleted, &failed),
 				));
+
+				// no (default) remote means there is nothing to
+				// delete remotely
+				if let Ok(remote) =
+					sync::get_default_remote(&self.repo.borrow())
+				{
+					if !deleted.is_empty() {
+						self.queue.push(
+							InternalEvent::ConfirmAction(
+								Action::DeleteRemoteTags(
+									deleted, remote,
+								),
+							),
+						);
+					}
+				}
+
+				flags.insert(NeedsUpdate::ALL);
+				self.tags_popup.update_tags()?;
+			}
+			Action::DeleteRemoteTags(tags, remote) => {
+				self.push_popup.delete_remote_tags(tags, remote)?;
 			}
-			Action::ForcePush(branch, force) => {
+			Action::ForcePush(branch, force, _) => {
 				self.queue.push(InternalEvent::Push(
 					branch,
 					PushType::Branch,
@@ -990,11 +2038,16 @@ impl App {
 					false,
 				));
 			}
+			Action::ForcePushUnsafe(_) => {
+				self.push_popup.force_push_unsafe()?;
+			}
 			Action::PullMerge { rebase, .. } => {
 				self.pull_popup.try_conflict_free_merge(rebase);
 				flags.insert(NeedsUpdate::ALL);
 			}
-			Action::AbortRevert | Action::AbortMerge => {
+			Action::AbortRevert
+			| Action::AbortMerge
+			| Action::AbortCherryPick => {
 				self.status_tab.revert_pending_state();
 				flags.insert(NeedsUpdate::ALL);
 			}
@@ -1002,6 +2055,117 @@ impl App {
 				self.status_tab.abort_rebase();
 				flags.insert(NeedsUpdate::ALL);
 			}
+			Action::AbortBisect => {
+				self.status_tab.abort_bisect();
+				flags.insert(NeedsUpdate::ALL);
+			}
+			Action::DeleteLocalBranches(branches) => {
+				match sync::delete_merged_branches(
+					&self.repo.borrow(),
+					&branches,
+				) {
+					Ok(result) => {
+						self.queue.push(InternalEvent::ShowInfoMsg(
+							strings::delete_branches_summary(
+								&result.deleted,
+								&result.unmerged,
+								&result.failed,
+							),
+						));
+					}
+					Err(e) => {
+						self.queue.push(InternalEvent::ShowErrorMsg(
+							e.to_string(),
+						));
+					}
+				}
+				flags.insert(NeedsUpdate::ALL);
+				self.select_branch_popup.update_branches()?;
+			}
+			Action::PruneBranches(branches) => {
+				let mut deleted = Vec::new();
+				let mut failed = Vec::new();
+				for branch in branches {
+					if let Err(e) = sync::delete_branch(
+						&self.repo.borrow(),
+						&format!("refs/heads/{}", branch),
+					) {
+						failed.push((branch, e.to_string()));
+					} else {
+						deleted.push(branch);
+					}
+				}
+
+				self.queue.push(InternalEvent::ShowInfoMsg(
+					strings::delete_branches_summary(
+						&deleted,
+						&[],
+						&failed,
+					),
+				));
+				flags.insert(NeedsUpdate::ALL);
+				self.select_branch_popup.update_branches()?;
+			}
+			Action::RemoveWorktree(name) => {
+				if let Err(e) =
+					sync::remove_worktree(&self.repo.borrow(), &name)
+				{
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						format!("remove worktree error:\n{}", e),
+					));
+				}
+				flags.insert(NeedsUpdate::ALL);
+				self.worktrees_popup.update_worktrees()?;
+			}
+			Action::RemoveRemote(name) => {
+				if let Err(e) =
+					sync::remove_remote(&self.repo.borrow(), &name)
+				{
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						format!("remove remote error:\n{}", e),
+					));
+				}
+				flags
+					.insert(NeedsUpdate::ALL | NeedsUpdate::BRANCHES);
+				self.remotes_popup.update_remotes()?;
+			}
+			Action::RestoreFile {
+				path,
+				commit_id,
+				stage,
+			} => {
+				if let Err(e) = sync::restore_file(
+					&self.repo.borrow(),
+					commit_id,
+					&path,
+					stage,
+				) {
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						format!("restore file error:\n{}", e),
+					));
+				}
+				self.file_revlog_popup.end_restore_preview()?;
+				flags.insert(NeedsUpdate::ALL);
+			}
+			Action::Autostash(op) => {
+				match op {
+					AutostashOp::PullMerge(rebase) => {
+						self.pull_popup.merge_with_autostash(rebase);
+					}
+					AutostashOp::CheckoutTag(tag) => {
+						self.tags_popup.checkout_tag(&tag, true)?;
+					}
+					op => {
+						self.select_branch_popup
+							.autostash_confirmed(&op)?;
+					}
+				}
+				flags.insert(NeedsUpdate::ALL);
+			}
+			Action::CommitEmpty => {
+				self.commit.show_allow_empty()?;
+				flags.insert(NeedsUpdate::ALL);
+			}
 		};
 
 		Ok(())
@@ -1054,6 +2218,31 @@ impl App {
 			)
 			.order(order::NAV),
 		);
+		res.push(CommandInfo::new(
+			strings::commands::toast_history(&self.key_config),
+			true,
+			!self.any_popup_visible(),
+		));
+		res.push(CommandInfo::new(
+			strings::commands::open_log_viewer(&self.key_config),
+			true,
+			!self.any_popup_visible(),
+		));
+		res.push(CommandInfo::new(
+			strings::commands::open_repo_switcher(&self.key_config),
+			true,
+			!self.any_popup_visible(),
+		));
+		res.push(CommandInfo::new(
+			strings::commands::open_grep(&self.key_config),
+			true,
+			!self.any_popup_visible(),
+		));
+		res.push(CommandInfo::new(
+			strings::commands::open_theme_picker(&self.key_config),
+			true,
+			!self.any_popup_visible(),
+		));
 
 		res.push(
 			CommandInfo::new(
@@ -1069,28 +2258,34 @@ impl App {
 
 	//TODO: make this dynamic
 	fn draw_tabs<B: Backend>(&self, f: &mut Frame<B>, r: Rect) {
-		let r = r.inner(&Margin {
-			vertical: 0,
-			horizontal: 1,
-		});
-
-		let tabs = [
-			Span::raw(strings::tab_status(&self.key_config)),
-			Span::raw(strings::tab_log(&self.key_config)),
-			Span::raw(strings::tab_files(&self.key_config)),
-			Span::raw(strings::tab_stashing(&self.key_config)),
-			Span::raw(strings::tab_stashes(&self.key_config)),
-		]
-		.iter()
-		.cloned()
-		.map(Spans::from)
-		.collect();
+		let r = if self.theme.compact() {
+			r
+		} else {
+			r.inner(&Margin {
+				vertical: 0,
+				horizontal: 1,
+			})
+		};
+
+		self.tabs_area.set(r);
+
+		let borders = if self.theme.compact() {
+			Borders::NONE
+		} else {
+			Borders::BOTTOM
+		};
+
+		let tabs = self
+			.tab_titles()
+			.into_iter()
+			.map(|title| Spans::from(Span::raw(title)))
+			.collect();
 
 		f.render_widget(
 			Tabs::new(tabs)
 				.block(
 					Block::default()
-						.borders(Borders::BOTTOM)
+						.borders(borders)
 						.border_style(self.theme.block(false)),
 				)
 				.style(self.theme.tab(false))
@@ -1099,5 +2294,11 @@ impl App {
 				.select(self.tab),
 			r,
 		);
+
+		self.title_bar.draw(
+			f,
+			r,
+			r.width.saturating_sub(self.tabs_width() + 1),
+		);
 	}
 }
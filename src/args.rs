@@ -1,30 +1,189 @@
-use crate::bug_report;
+use crate::{
+	bug_report,
+	components::{
+		BlameFileOpen, FileViewOpen, InspectCommitOpen, StartTab,
+	},
+	keys::CheatsheetFormat,
+	queue::StackablePopupOpen,
+};
 use anyhow::{anyhow, Result};
-use asyncgit::sync::RepoPath;
+use asyncgit::sync::{self, RepoPath};
 use clap::{
 	crate_authors, crate_description, crate_name, crate_version,
 	App as ClapApp, Arg,
 };
+use clap_complete::Shell;
 use simplelog::{Config, LevelFilter, WriteLogger};
 use std::{
 	env,
 	fs::{self, File},
-	path::PathBuf,
+	io,
+	path::{Path, PathBuf},
 };
 
 pub struct CliArgs {
 	pub theme: PathBuf,
 	pub repo_path: RepoPath,
+	pub open: Option<OpenArgs>,
+	/// overrides the configured start tab
+	pub tab: Option<StartTab>,
+	pub keys: Option<KeysArgs>,
+	pub status: Option<StatusArgs>,
+	pub log: Option<LogArgs>,
 }
 
-pub fn process_cmdline() -> Result<CliArgs> {
-	let app = app();
+/// `gitui status [--json]`
+pub struct StatusArgs {
+	pub json: bool,
+}
+
+/// `gitui log [--json] [-n <COUNT>]`
+pub struct LogArgs {
+	pub json: bool,
+	pub max_count: usize,
+}
+
+/// `gitui keys [FILE] [--format <md|text>]`
+pub struct KeysArgs {
+	/// stdout if `None`
+	pub file: Option<PathBuf>,
+	pub format: CheatsheetFormat,
+}
+
+/// view to start in, from `gitui blame` or
+/// `--blame`, `--file` and `--commit`
+pub enum OpenArgs {
+	/// at the revision if given, the work tree otherwise
+	Blame(FileArgs, Option<String>),
+	/// at the revision if given, `HEAD` otherwise
+	File(FileArgs, Option<String>),
+	Commit(String),
+}
 
-	let arg_matches = app.get_matches();
+impl OpenArgs {
+	///
+	pub fn open(
+		&self,
+		repo: &RepoPath,
+	) -> Result<StackablePopupOpen> {
+		let resolve = |rev: &str| {
+			sync::resolve_revision(repo, rev)
+				.map_err(|e| anyhow!("{}: {}", rev, e))
+		};
+
+		Ok(match self {
+			Self::Blame(file, rev) => {
+				let (file_path, selection) = file.resolve(repo)?;
+				StackablePopupOpen::BlameFile(BlameFileOpen {
+					file_path,
+					commit_id: rev
+						.as_deref()
+						.map(resolve)
+						.transpose()?,
+					selection,
+				})
+			}
+			Self::File(file, rev) => {
+				let (file_path, selection) = file.resolve(repo)?;
+				StackablePopupOpen::FileView(FileViewOpen {
+					file_path,
+					commit_id: resolve(
+						rev.as_deref().unwrap_or("HEAD"),
+					)?,
+					selection,
+				})
+			}
+			Self::Commit(rev) => StackablePopupOpen::InspectCommit(
+				InspectCommitOpen::new(resolve(rev)?),
+			),
+		})
+	}
+}
+
+/// `<FILE>` with an optional line like `<FILE>:<LINE>`
+pub struct FileArgs {
+	pub file: PathBuf,
+	/// 1-based like in editors
+	pub line: Option<usize>,
+}
+
+impl FileArgs {
+	/// splits off a trailing `:<LINE>`
+	fn parse(arg: &str) -> Self {
+		if let Some((file, line)) = arg.rsplit_once(':') {
+			if let (false, Ok(line)) = (file.is_empty(), line.parse())
+			{
+				return Self {
+					file: PathBuf::from(file),
+					line: Some(line),
+				};
+			}
+		}
+
+		Self {
+			file: PathBuf::from(arg),
+			line: None,
+		}
+	}
+
+	/// path relative to the working directory of `repo` and
+	/// the 0-based line to select
+	fn resolve(
+		&self,
+		repo: &RepoPath,
+	) -> Result<(String, Option<usize>)> {
+		let workdir =
+			fs::canonicalize(sync::utils::repo_work_dir(repo)?)?;
+		let file = env::current_dir()?.join(&self.file);
+		let file = fs::canonicalize(&file).unwrap_or(file);
+
+		let file_path =
+			file.strip_prefix(&workdir).map_err(|_| {
+				anyhow!(
+					"{} is not inside of {}",
+					self.file.display(),
+					workdir.display()
+				)
+			})?;
+
+		Ok((
+			git_path(file_path),
+			self.line.map(|line| line.saturating_sub(1)),
+		))
+	}
+}
+
+/// git always separates path components with `/`
+fn git_path(path: &Path) -> String {
+	path.components()
+		.map(|c| c.as_os_str().to_string_lossy())
+		.collect::<Vec<_>>()
+		.join("/")
+}
+
+/// commits `gitui log` prints without `-n`
+const DEFAULT_LOG_COUNT: usize = 50;
+
+#[allow(clippy::too_many_lines)]
+pub fn process_cmdline() -> Result<CliArgs> {
+	let arg_matches = app().get_matches();
 	if arg_matches.is_present("bugreport") {
 		bug_report::generate_bugreport();
 		std::process::exit(0);
 	}
+	if let Some(shell) = arg_matches
+		.subcommand_matches("completions")
+		.and_then(|m| m.value_of("shell"))
+		.and_then(|shell| shell.parse::<Shell>().ok())
+	{
+		clap_complete::generate(
+			shell,
+			&mut app(),
+			crate_name!(),
+			&mut io::stdout(),
+		);
+		std::process::exit(0);
+	}
 	if arg_matches.is_present("logging") {
 		setup_logging()?;
 	}
@@ -41,6 +200,60 @@ pub fn process_cmdline() -> Result<CliArgs> {
 		RepoPath::Path(gitdir)
 	};
 
+	let commit = arg_matches.value_of("commit").map(String::from);
+	#[allow(clippy::option_if_let_else)]
+	let open = if let Some(m) = arg_matches.subcommand_matches("blame") {
+		Some(OpenArgs::Blame(
+			FileArgs {
+				file: m
+					.value_of("file")
+					.map(PathBuf::from)
+					.unwrap_or_default(),
+				line: m.value_of("line").and_then(|l| l.parse().ok()),
+			},
+			m.value_of("commit").map(String::from).or(commit),
+		))
+	} else if let Some(file) = arg_matches.value_of("blame") {
+		Some(OpenArgs::Blame(FileArgs::parse(file), commit))
+	} else if let Some(file) = arg_matches.value_of("file") {
+		Some(OpenArgs::File(FileArgs::parse(file), commit))
+	} else {
+		commit.map(OpenArgs::Commit)
+	};
+
+	let tab = arg_matches
+		.value_of("tab")
+		.and_then(StartTab::from_config_value);
+
+	let keys = arg_matches.subcommand_matches("keys").map(|m| {
+		let file = m.value_of("file").map(PathBuf::from);
+		let format = match m.value_of("format") {
+			Some("md") => CheatsheetFormat::Markdown,
+			Some(_) => CheatsheetFormat::Text,
+			None => file.as_deref().map_or(
+				CheatsheetFormat::Text,
+				CheatsheetFormat::from_path,
+			),
+		};
+
+		KeysArgs { file, format }
+	});
+
+	let status = arg_matches.subcommand_matches("status").map(|m| {
+		StatusArgs {
+			json: m.is_present("json"),
+		}
+	});
+
+	let log =
+		arg_matches.subcommand_matches("log").map(|m| LogArgs {
+			json: m.is_present("json"),
+			max_count: m
+				.value_of("max-count")
+				.and_then(|n| n.parse().ok())
+				.unwrap_or(DEFAULT_LOG_COUNT),
+		});
+
 	let arg_theme =
 		arg_matches.value_of("theme").unwrap_or("theme.ron");
 
@@ -48,15 +261,26 @@ pub fn process_cmdline() -> Result<CliArgs> {
 		Ok(CliArgs {
 			theme: get_app_config_path()?.join(arg_theme),
 			repo_path,
+			open,
+			tab,
+			keys,
+			status,
+			log,
 		})
 	} else {
 		Ok(CliArgs {
 			theme: get_app_config_path()?.join("theme.ron"),
 			repo_path,
+			open,
+			tab,
+			keys,
+			status,
+			log,
 		})
 	}
 }
 
+#[allow(clippy::too_many_lines)]
 fn app() -> ClapApp<'static> {
 	let app = ClapApp::new(crate_name!())
 		.author(crate_authors!())
@@ -96,23 +320,153 @@ fn app() -> ClapApp<'static> {
 				.long("workdir")
 				.env("GIT_WORK_TREE")
 				.takes_value(true),
+		)
+		.arg(
+			Arg::with_name("blame")
+				.help("Start in the blame view of a file, optionally at a line like FILE:LINE")
+				.long("blame")
+				.value_name("FILE")
+				.conflicts_with("file")
+				.takes_value(true),
+		)
+		.arg(
+			Arg::with_name("file")
+				.help("Start in the view of a file as of --commit or HEAD, optionally at a line like FILE:LINE")
+				.long("file")
+				.value_name("FILE")
+				.takes_value(true),
+		)
+		.arg(
+			Arg::with_name("commit")
+				.help("Start in the details of a commit, or show --blame or --file as of it")
+				.long("commit")
+				.value_name("REV")
+				.takes_value(true),
+		)
+		.arg(
+			Arg::with_name("tab")
+				.help("Start on this tab instead of the configured one (gitui.startTab)")
+				.long("tab")
+				.value_name("TAB")
+				.possible_values(["status", "log", "files", "stashing", "stashes"])
+				.takes_value(true),
+		)
+		.subcommand(
+			ClapApp::new("blame")
+				.about("Open the blame view of a file")
+				.arg(
+					Arg::with_name("file")
+						.help("The file to blame")
+						.value_name("FILE")
+						.required(true),
+				)
+				.arg(
+					Arg::with_name("line")
+						.help("Select this line (1-based)")
+						.short('L')
+						.long("line")
+						.value_name("LINE")
+						.takes_value(true)
+						.validator(str::parse::<usize>),
+				)
+				.arg(
+					Arg::with_name("commit")
+						.help("Blame the file as of this revision instead of the work tree")
+						.long("commit")
+						.value_name("REV")
+						.takes_value(true),
+				),
+		)
+		.subcommand(
+			ClapApp::new("keys")
+				.about("Write the key bindings in effect to a cheatsheet")
+				.arg(
+					Arg::with_name("file")
+						.help("Write to this file instead of stdout")
+						.value_name("FILE"),
+				)
+				.arg(
+					Arg::with_name("format")
+						.help("Markdown or plain text, defaults to Markdown for .md files")
+						.short('f')
+						.long("format")
+						.value_name("FORMAT")
+						.possible_values(["md", "text"])
+						.takes_value(true),
+				),
+		)
+		.subcommand(
+			ClapApp::new("status")
+				.about("Print the branch and the changed files for scripts")
+				.arg(
+					Arg::with_name("json")
+						.help("Print as JSON")
+						.long("json"),
+				),
+		)
+		.subcommand(
+			ClapApp::new("log")
+				.about("Print the most recent commits for scripts")
+				.arg(
+					Arg::with_name("json")
+						.help("Print as JSON")
+						.long("json"),
+				)
+				.arg(
+					Arg::with_name("max-count")
+						.help("Number of commits to print (defaults to 50)")
+						.short('n')
+						.long("max-count")
+						.value_name("COUNT")
+						.takes_value(true)
+						.validator(str::parse::<usize>),
+				),
+		)
+		.subcommand(
+			ClapApp::new("completions")
+				.about("Print the shell completions for gitui")
+				.arg(
+					Arg::with_name("shell")
+						.help("The shell to complete in")
+						.value_name("SHELL")
+						.possible_values([
+							"bash",
+							"elvish",
+							"fish",
+							"powershell",
+							"zsh",
+						])
+						.required(true),
+				),
 		);
 	app
 }
 
 fn setup_logging() -> Result<()> {
-	let mut path = get_app_cache_path()?;
-	path.push("gitui.log");
-
 	let _ = WriteLogger::init(
 		LevelFilter::Trace,
 		Config::default(),
-		File::create(path)?,
+		File::create(log_file_path()?)?,
 	);
 
 	Ok(())
 }
 
+/// where `--logging` writes to
+pub fn log_file_path() -> Result<PathBuf> {
+	Ok(get_app_cache_path()?.join("gitui.log"))
+}
+
+/// repositories the repository switcher offers
+pub fn recent_repos_path() -> Result<PathBuf> {
+	Ok(get_app_cache_path()?.join("recent_repos.ron"))
+}
+
+/// options shared by all repositories
+pub fn options_path() -> Result<PathBuf> {
+	Ok(get_app_config_path()?.join("options.ron"))
+}
+
 fn get_app_cache_path() -> Result<PathBuf> {
 	let mut path = dirs_next::cache_dir()
 		.ok_or_else(|| anyhow!("failed to find os cache dir."))?;
@@ -139,3 +493,18 @@ pub fn get_app_config_path() -> Result<PathBuf> {
 fn verify_app() {
 	app().debug_assert();
 }
+
+#[test]
+fn test_file_args_parse() {
+	let args = FileArgs::parse("src/main.rs:123");
+	assert_eq!(args.file, PathBuf::from("src/main.rs"));
+	assert_eq!(args.line, Some(123));
+
+	let args = FileArgs::parse("src/main.rs");
+	assert_eq!(args.file, PathBuf::from("src/main.rs"));
+	assert_eq!(args.line, None);
+
+	let args = FileArgs::parse("notes:todo");
+	assert_eq!(args.file, PathBuf::from("notes:todo"));
+	assert_eq!(args.line, None);
+}
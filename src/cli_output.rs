@@ -0,0 +1,221 @@
+use crate::{
+	args::{LogArgs, StatusArgs},
+	strings,
+};
+use anyhow::Result;
+use asyncgit::{
+	cached,
+	sync::{
+		self,
+		status::{StatusItem, StatusItemType, StatusType},
+		CommitInfo, RepoPath, RepoState,
+	},
+};
+use chrono::{DateTime, Local, NaiveDateTime, Utc};
+use serde::Serialize;
+use std::{
+	cell::RefCell,
+	collections::BTreeMap,
+	io::{self, ErrorKind, Write},
+};
+
+#[derive(Serialize)]
+struct StatusOutput {
+	/// `None` on an unborn branch
+	branch: Option<String>,
+	/// `None` without an upstream
+	ahead: Option<usize>,
+	behind: Option<usize>,
+	/// pending operation like `merge` or `rebase`
+	state: Option<&'static str>,
+	staged: Vec<FileOutput>,
+	unstaged: Vec<FileOutput>,
+}
+
+#[derive(Serialize)]
+struct FileOutput {
+	path: String,
+	status: &'static str,
+}
+
+impl From<&StatusItem> for FileOutput {
+	fn from(item: &StatusItem) -> Self {
+		Self {
+			path: item.path.clone(),
+			status: match item.status {
+				StatusItemType::New => "new",
+				StatusItemType::Modified => "modified",
+				StatusItemType::Deleted => "deleted",
+				StatusItemType::Renamed => "renamed",
+				StatusItemType::Typechange => "typechange",
+				StatusItemType::Conflicted => "conflicted",
+			},
+		}
+	}
+}
+
+#[derive(Serialize)]
+struct CommitOutput {
+	id: String,
+	short_id: String,
+	author: String,
+	/// seconds since the unix epoch
+	time: i64,
+	/// first line of the message
+	summary: String,
+}
+
+impl From<CommitInfo> for CommitOutput {
+	fn from(info: CommitInfo) -> Self {
+		Self {
+			id: info.id.to_string(),
+			short_id: info.id.get_short_string(),
+			author: info.author,
+			time: info.time,
+			summary: info.message,
+		}
+	}
+}
+
+/// `gitui status`, like `git status --short --branch` or as json
+pub fn print_status(
+	repo: &RepoPath,
+	args: &StatusArgs,
+) -> Result<()> {
+	let show_untracked = sync::untracked_files_config(repo).ok();
+	let staged = sync::status::get_status(
+		repo,
+		StatusType::Stage,
+		show_untracked,
+	)?;
+	let unstaged = sync::status::get_status(
+		repo,
+		StatusType::WorkingDir,
+		show_untracked,
+	)?;
+
+	let branch = cached::BranchName::new(RefCell::new(repo.clone()))
+		.lookup()
+		.ok();
+	let compare = branch.as_ref().and_then(|branch| {
+		sync::branch_compare_upstream(repo, branch).ok()
+	});
+	let state = sync::repo_state(repo)?;
+
+	let output = StatusOutput {
+		branch,
+		ahead: compare.as_ref().map(|compare| compare.ahead),
+		behind: compare.as_ref().map(|compare| compare.behind),
+		state: (state != RepoState::Clean)
+			.then(|| strings::repo_state_name(&state)),
+		staged: staged.iter().map(FileOutput::from).collect(),
+		unstaged: unstaged.iter().map(FileOutput::from).collect(),
+	};
+
+	if args.json {
+		return print(&serde_json::to_string_pretty(&output)?);
+	}
+
+	let mut header = format!(
+		"## {}",
+		output.branch.as_deref().unwrap_or("HEAD (no branch)")
+	);
+	if let (Some(ahead), Some(behind)) = (output.ahead, output.behind)
+	{
+		header = format!(
+			"{} [ahead {}, behind {}]",
+			header, ahead, behind
+		);
+	}
+	if let Some(state) = output.state {
+		header = format!("{} ({})", header, state);
+	}
+
+	// one line per path with the staged and the unstaged change
+	let mut files: BTreeMap<&str, (char, char)> = BTreeMap::new();
+	for item in &staged {
+		files.entry(&item.path).or_insert((' ', ' ')).0 =
+			status_char(item.status, true);
+	}
+	for item in &unstaged {
+		let file = files.entry(&item.path).or_insert((' ', ' '));
+		file.1 = status_char(item.status, false);
+		if file.1 == '?' {
+			file.0 = '?';
+		}
+	}
+
+	let lines = std::iter::once(header)
+		.chain(files.into_iter().map(|(path, (stage, workdir))| {
+			format!("{}{} {}", stage, workdir, path)
+		}))
+		.collect::<Vec<_>>();
+
+	print(&lines.join("\n"))
+}
+
+/// `gitui log`, one commit per line or as json
+pub fn print_log(repo: &RepoPath, args: &LogArgs) -> Result<()> {
+	let commits = sync::get_recent_commits_info(
+		repo,
+		args.max_count,
+		usize::MAX,
+	)?
+	.into_iter()
+	.map(CommitOutput::from)
+	.collect::<Vec<_>>();
+
+	if args.json {
+		return print(&serde_json::to_string_pretty(&commits)?);
+	}
+
+	let lines = commits
+		.iter()
+		.map(|commit| {
+			format!(
+				"{} {} {} {}",
+				commit.short_id,
+				format_time(commit.time),
+				commit.author,
+				commit.summary
+			)
+		})
+		.collect::<Vec<_>>();
+
+	print(&lines.join("\n"))
+}
+
+/// a reader like `head` closing the pipe early is no error
+fn print(text: &str) -> Result<()> {
+	let stdout = io::stdout();
+	let mut stdout = stdout.lock();
+	match stdout
+		.write_all(text.as_bytes())
+		.and_then(|_| stdout.write_all(b"\n"))
+	{
+		Err(e) if e.kind() != ErrorKind::BrokenPipe => Err(e.into()),
+		_ => Ok(()),
+	}
+}
+
+/// letters as used by `git status --short`
+const fn status_char(status: StatusItemType, staged: bool) -> char {
+	match status {
+		StatusItemType::New if staged => 'A',
+		StatusItemType::New => '?',
+		StatusItemType::Modified => 'M',
+		StatusItemType::Deleted => 'D',
+		StatusItemType::Renamed => 'R',
+		StatusItemType::Typechange => 'T',
+		StatusItemType::Conflicted => 'U',
+	}
+}
+
+fn format_time(secs: i64) -> String {
+	DateTime::<Local>::from(DateTime::<Utc>::from_utc(
+		NaiveDateTime::from_timestamp(secs, 0),
+		Utc,
+	))
+	.format("%Y-%m-%d %H:%M")
+	.to_string()
+}
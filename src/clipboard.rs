@@ -1,9 +1,89 @@
+use crate::components::ClipboardBackend;
 use anyhow::{anyhow, Result};
-use std::io::Write;
+use std::io::{self, Write};
 use std::path::PathBuf;
 use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicU8, Ordering};
 use which::which;
 
+static BACKEND: AtomicU8 = AtomicU8::new(0);
+
+/// backend `copy_string` uses from now on
+pub fn set_backend(backend: ClipboardBackend) {
+	let value = match backend {
+		ClipboardBackend::Auto => 0,
+		ClipboardBackend::System => 1,
+		ClipboardBackend::Osc52 => 2,
+	};
+	BACKEND.store(value, Ordering::Relaxed);
+}
+
+fn backend() -> ClipboardBackend {
+	match BACKEND.load(Ordering::Relaxed) {
+		1 => ClipboardBackend::System,
+		2 => ClipboardBackend::Osc52,
+		_ => ClipboardBackend::Auto,
+	}
+}
+
+/// copies with the backend set by `set_backend`, `Auto` uses OSC 52
+/// over ssh and when no clipboard tool could be run
+pub fn copy_string(text: &str) -> Result<()> {
+	match backend() {
+		ClipboardBackend::System => system_copy(text),
+		ClipboardBackend::Osc52 => osc52_copy(text),
+		ClipboardBackend::Auto => {
+			if is_remote_session() {
+				osc52_copy(text)
+			} else {
+				system_copy(text).or_else(|_| osc52_copy(text))
+			}
+		}
+	}
+}
+
+fn is_remote_session() -> bool {
+	std::env::var_os("SSH_TTY").is_some()
+		|| std::env::var_os("SSH_CONNECTION").is_some()
+}
+
+/// asks the terminal to set the clipboard, which also works through
+/// ssh. inside tmux this needs `set-clipboard on`.
+fn osc52_copy(text: &str) -> Result<()> {
+	let mut stdout = io::stdout();
+	write!(stdout, "\x1b]52;c;{}\x07", base64(text.as_bytes()))?;
+	stdout.flush()?;
+	Ok(())
+}
+
+fn base64(data: &[u8]) -> String {
+	const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+	let mut res = String::with_capacity((data.len() + 2) / 3 * 4);
+
+	for chunk in data.chunks(3) {
+		let bytes = [
+			chunk[0],
+			chunk.get(1).copied().unwrap_or_default(),
+			chunk.get(2).copied().unwrap_or_default(),
+		];
+		let n = u32::from(bytes[0]) << 16
+			| u32::from(bytes[1]) << 8
+			| u32::from(bytes[2]);
+
+		for i in 0..4 {
+			if i <= chunk.len() {
+				let index = (n >> (18 - 6 * i)) & 0x3f;
+				res.push(char::from(ALPHABET[index as usize]));
+			} else {
+				res.push('=');
+			}
+		}
+	}
+
+	res
+}
+
 fn exec_copy_with_args(
 	command: &str,
 	args: &[&str],
@@ -39,7 +119,7 @@ fn exec_copy(command: &str, text: &str) -> Result<()> {
 }
 
 #[cfg(all(target_family = "unix", not(target_os = "macos")))]
-pub fn copy_string(text: &str) -> Result<()> {
+fn system_copy(text: &str) -> Result<()> {
 	if std::env::var("WAYLAND_DISPLAY").is_ok() {
 		return exec_copy("wl-copy", text);
 	}
@@ -58,11 +138,26 @@ pub fn copy_string(text: &str) -> Result<()> {
 }
 
 #[cfg(target_os = "macos")]
-pub fn copy_string(text: &str) -> Result<()> {
+fn system_copy(text: &str) -> Result<()> {
 	exec_copy("pbcopy", text)
 }
 
 #[cfg(windows)]
-pub fn copy_string(text: &str) -> Result<()> {
+fn system_copy(text: &str) -> Result<()> {
 	exec_copy("clip", text)
 }
+
+#[cfg(test)]
+mod tests {
+	use super::base64;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn test_base64() {
+		assert_eq!(base64(b""), "");
+		assert_eq!(base64(b"f"), "Zg==");
+		assert_eq!(base64(b"fo"), "Zm8=");
+		assert_eq!(base64(b"foo"), "Zm9v");
+		assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+	}
+}
@@ -2,7 +2,7 @@ use crate::{
 	components::CommandInfo, keys::SharedKeyConfig, strings,
 	ui::style::SharedTheme,
 };
-use std::borrow::Cow;
+use std::{borrow::Cow, cell::Cell};
 use tui::{
 	backend::Backend,
 	layout::{Alignment, Rect},
@@ -15,6 +15,8 @@ use unicode_width::UnicodeWidthStr;
 enum DrawListEntry {
 	LineBreak,
 	Splitter,
+	/// separates commands of different groups
+	GroupSplitter,
 	Command(Command),
 }
 
@@ -22,6 +24,16 @@ struct Command {
 	txt: String,
 	enabled: bool,
 	line: usize,
+	/// offset of the entry within its line
+	column: usize,
+}
+
+impl Command {
+	fn contains(&self, line: usize, column: usize) -> bool {
+		let width = UnicodeWidthStr::width(self.txt.as_str());
+		self.line == line
+			&& (self.column..self.column + width).contains(&column)
+	}
 }
 
 /// helper to be used while drawing
@@ -30,13 +42,16 @@ pub struct CommandBar {
 	cmd_infos: Vec<CommandInfo>,
 	theme: SharedTheme,
 	key_config: SharedKeyConfig,
-	lines: u16,
+	/// number of pages the commands are spread over
+	pages: usize,
+	/// page currently shown
+	page: usize,
 	width: u16,
-	expandable: bool,
-	expanded: bool,
+	/// where the bar was drawn last
+	area: Cell<Rect>,
 }
 
-const MORE_WIDTH: u16 = 9;
+const MORE_WIDTH: u16 = 10;
 
 impl CommandBar {
 	pub const fn new(
@@ -48,10 +63,15 @@ impl CommandBar {
 			cmd_infos: Vec::new(),
 			theme,
 			key_config,
-			lines: 0,
+			pages: 0,
+			page: 0,
 			width: 0,
-			expandable: false,
-			expanded: false,
+			area: Cell::new(Rect {
+				x: 0,
+				y: 0,
+				width: 0,
+				height: 0,
+			}),
 		}
 	}
 
@@ -62,140 +82,196 @@ impl CommandBar {
 		}
 	}
 
-	fn is_multiline(&self, width: u16) -> bool {
-		let mut line_width = 0_usize;
-		for c in &self.cmd_infos {
-			let entry_w =
-				UnicodeWidthStr::width(c.text.name.as_str());
-
-			if line_width + entry_w > width as usize {
-				return true;
-			}
-
-			line_width += entry_w + 1;
-		}
-
-		false
-	}
-
-	fn refresh_list(&mut self, width: u16) {
-		self.draw_list.clear();
-
-		let width = if self.is_multiline(width) {
-			width.saturating_sub(MORE_WIDTH)
-		} else {
-			width
-		};
+	/// lays the commands out in pages of one line each, the
+	/// separator between commands is wider where the group changes
+	fn layout(&self, width: usize) -> Vec<DrawListEntry> {
+		let group_splitter_w = UnicodeWidthStr::width(
+			strings::cmd_group_splitter(&self.key_config).as_str(),
+		);
 
+		let mut draw_list = Vec::new();
 		let mut line_width = 0_usize;
-		let mut lines = 1_u16;
+		let mut line = 0_usize;
+		let mut group = None;
 
 		for c in &self.cmd_infos {
 			let entry_w =
 				UnicodeWidthStr::width(c.text.name.as_str());
 
-			if line_width + entry_w > width as usize {
-				self.draw_list.push(DrawListEntry::LineBreak);
+			let new_group =
+				group.map_or(false, |g| g != c.text.group);
+			let (splitter, splitter_w) = if line_width == 0 {
+				(None, 0)
+			} else if new_group {
+				(Some(DrawListEntry::GroupSplitter), group_splitter_w)
+			} else {
+				(Some(DrawListEntry::Splitter), 1)
+			};
+
+			if line_width > 0
+				&& line_width + splitter_w + entry_w > width
+			{
+				draw_list.push(DrawListEntry::LineBreak);
 				line_width = 0;
-				lines += 1;
-			} else if line_width > 0 {
-				self.draw_list.push(DrawListEntry::Splitter);
+				line += 1;
+			} else if let Some(splitter) = splitter {
+				draw_list.push(splitter);
+				line_width += splitter_w;
 			}
 
-			line_width += entry_w + 1;
-
-			self.draw_list.push(DrawListEntry::Command(Command {
+			draw_list.push(DrawListEntry::Command(Command {
 				txt: c.text.name.to_string(),
 				enabled: c.enabled,
-				line: lines.saturating_sub(1) as usize,
+				line,
+				column: line_width,
 			}));
+
+			line_width += entry_w;
+			group = Some(c.text.group);
+		}
+
+		draw_list
+	}
+
+	fn refresh_list(&mut self, width: u16) {
+		self.draw_list = self.layout(usize::from(width));
+
+		if self
+			.draw_list
+			.iter()
+			.any(|e| matches!(e, DrawListEntry::LineBreak))
+		{
+			// leave room for the page indicator
+			self.draw_list = self.layout(usize::from(
+				width.saturating_sub(MORE_WIDTH),
+			));
 		}
 
-		self.expandable = lines > 1;
+		self.pages = self
+			.draw_list
+			.iter()
+			.filter(|e| matches!(e, DrawListEntry::LineBreak))
+			.count() + 1;
 
-		self.lines = lines;
+		if self.page >= self.pages {
+			self.page = 0;
+		}
 	}
 
+	/// keeps commands of the same group next to each other within
+	/// each priority
 	pub fn set_cmds(&mut self, cmds: Vec<CommandInfo>) {
+		let mut groups: Vec<&'static str> = Vec::new();
+
 		self.cmd_infos = cmds
 			.into_iter()
 			.filter(CommandInfo::show_in_quickbar)
 			.collect::<Vec<_>>();
-		self.cmd_infos.sort_by_key(|e| e.order);
+
+		for c in &self.cmd_infos {
+			if !groups.contains(&c.text.group) {
+				groups.push(c.text.group);
+			}
+		}
+
+		self.cmd_infos.sort_by_key(|e| {
+			(e.order, groups.iter().position(|g| *g == e.text.group))
+		});
 		self.refresh_list(self.width);
 	}
 
-	pub const fn height(&self) -> u16 {
-		if self.expandable && self.expanded {
-			self.lines
-		} else {
-			1_u16
+	/// shows the next page of commands, wrapping around after the last
+	pub fn next_page(&mut self) {
+		if self.pages > 1 {
+			self.page = (self.page + 1) % self.pages;
 		}
 	}
 
-	pub fn toggle_more(&mut self) {
-		if self.expandable {
-			self.expanded = !self.expanded;
+	/// flips the page when clicking the page indicator, otherwise
+	/// returns the name of the clicked command
+	pub fn click(&mut self, column: u16, row: u16) -> Option<String> {
+		let area = self.area.get();
+		if !area.intersects(Rect::new(column, row, 1, 1)) {
+			return None;
+		}
+
+		let column = usize::from(column - area.x);
+
+		if self.pages > 1
+			&& column + usize::from(MORE_WIDTH)
+				>= usize::from(area.width)
+		{
+			self.next_page();
+			return None;
 		}
+
+		self.draw_list.iter().find_map(|entry| match entry {
+			DrawListEntry::Command(c)
+				if c.enabled && c.contains(self.page, column) =>
+			{
+				Some(c.txt.clone())
+			}
+			_ => None,
+		})
 	}
 
 	pub fn draw<B: Backend>(&self, f: &mut Frame<B>, r: Rect) {
+		self.area.set(r);
+
 		if r.width < MORE_WIDTH {
 			return;
 		}
 		let splitter = Span::raw(Cow::from(strings::cmd_splitter(
 			&self.key_config,
 		)));
+		let group_splitter = Span::styled(
+			Cow::from(strings::cmd_group_splitter(&self.key_config)),
+			self.theme.commandbar(false, self.page),
+		);
 
-		let texts = self
+		let text = self
 			.draw_list
 			.split(|c| matches!(c, DrawListEntry::LineBreak))
-			.map(|c_arr| {
-				Spans::from(
-					c_arr
-						.iter()
-						.map(|c| match c {
-							DrawListEntry::Command(c) => {
-								Span::styled(
-									Cow::from(c.txt.as_str()),
-									self.theme.commandbar(
-										c.enabled, c.line,
-									),
-								)
-							}
-							DrawListEntry::LineBreak => {
-								// Doesn't exist in split array
-								Span::raw("")
-							}
-							DrawListEntry::Splitter => {
-								splitter.clone()
-							}
-						})
-						.collect::<Vec<Span>>(),
-				)
+			.nth(self.page)
+			.unwrap_or_default()
+			.iter()
+			.map(|c| match c {
+				DrawListEntry::Command(c) => Span::styled(
+					Cow::from(c.txt.as_str()),
+					self.theme.commandbar(c.enabled, c.line),
+				),
+				DrawListEntry::LineBreak => {
+					// Doesn't exist in split array
+					Span::raw("")
+				}
+				DrawListEntry::Splitter => splitter.clone(),
+				DrawListEntry::GroupSplitter => {
+					group_splitter.clone()
+				}
 			})
-			.collect::<Vec<Spans>>();
+			.collect::<Vec<Span>>();
 
 		f.render_widget(
-			Paragraph::new(texts).alignment(Alignment::Left),
+			Paragraph::new(Spans::from(text))
+				.alignment(Alignment::Left),
 			r,
 		);
 
-		if self.expandable {
+		if self.pages > 1 {
 			let r = Rect::new(
 				r.width.saturating_sub(MORE_WIDTH),
-				r.y + r.height.saturating_sub(1),
+				r.y,
 				MORE_WIDTH.min(r.width),
 				1.min(r.height),
 			);
 
 			f.render_widget(
 				Paragraph::new(Spans::from(vec![Span::raw(
-					Cow::from(if self.expanded {
-						"less [.]"
-					} else {
-						"more [.]"
-					}),
+					Cow::from(strings::cmd_page(
+						&self.key_config,
+						self.page,
+						self.pages,
+					)),
 				)]))
 				.alignment(Alignment::Right),
 				r,
@@ -0,0 +1,165 @@
+use super::{
+	textinput::TextInputComponent, visibility_blocking,
+	CommandBlocking, CommandInfo, Component, DrawableComponent,
+	EventState,
+};
+use crate::{
+	keys::{key_match, SharedKeyConfig},
+	queue::{InternalEvent, NeedsUpdate, Queue},
+	strings,
+	ui::style::SharedTheme,
+};
+use anyhow::Result;
+use asyncgit::sync::{self, RepoPathRef};
+use crossterm::event::Event;
+use std::path::Path;
+use tui::{backend::Backend, layout::Rect, Frame};
+
+pub struct AddWorktreeComponent {
+	repo: RepoPathRef,
+	input: TextInputComponent,
+	branch: Option<String>,
+	queue: Queue,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for AddWorktreeComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		self.input.draw(f, rect)?;
+
+		Ok(())
+	}
+}
+
+impl Component for AddWorktreeComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			self.input.commands(out, force_all);
+
+			out.push(CommandInfo::new(
+				strings::commands::worktree_add_confirm(
+					&self.key_config,
+				),
+				!self.input.get_text().is_empty(),
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, ev: &Event) -> Result<EventState> {
+		if self.is_visible() {
+			if self.input.event(ev)?.is_consumed() {
+				return Ok(EventState::Consumed);
+			}
+
+			if let Event::Key(e) = ev {
+				if key_match(e, self.key_config.keys().enter) {
+					self.add_worktree();
+				}
+
+				return Ok(EventState::Consumed);
+			}
+		}
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.input.is_visible()
+	}
+
+	fn hide(&mut self) {
+		self.input.hide();
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.input.show()?;
+
+		Ok(())
+	}
+}
+
+impl AddWorktreeComponent {
+	///
+	pub fn new(
+		repo: RepoPathRef,
+		queue: Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			repo,
+			queue,
+			input: TextInputComponent::new(
+				theme,
+				key_config.clone(),
+				&strings::add_worktree_popup_title(&key_config),
+				&strings::add_worktree_popup_msg(&key_config),
+				true,
+			),
+			branch: None,
+			key_config,
+		}
+	}
+
+	/// suggests a sibling directory of the workdir named after `branch`
+	pub fn open(&mut self, branch: String) -> Result<()> {
+		let workdir =
+			sync::utils::repo_work_dir(&self.repo.borrow())?;
+		let workdir = Path::new(&workdir);
+
+		let suggestion = workdir
+			.file_name()
+			.map(|name| {
+				workdir.with_file_name(format!(
+					"{}-{}",
+					name.to_string_lossy(),
+					branch.replace('/', "-")
+				))
+			})
+			.map(|path| path.to_string_lossy().to_string())
+			.unwrap_or_default();
+
+		self.branch = Some(branch);
+		self.input.set_text(suggestion);
+		self.show()?;
+
+		Ok(())
+	}
+
+	///
+	pub fn add_worktree(&mut self) {
+		if let Some(branch) = &self.branch {
+			let res = sync::add_worktree(
+				&self.repo.borrow(),
+				Path::new(self.input.get_text()),
+				branch,
+			);
+
+			match res {
+				Ok(()) => {
+					self.queue.push(InternalEvent::Update(
+						NeedsUpdate::ALL,
+					));
+					self.hide();
+					self.queue.push(InternalEvent::ViewWorktrees);
+				}
+				Err(e) => {
+					log::error!("add worktree: {}", e);
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						format!("add worktree error:\n{}", e),
+					));
+				}
+			}
+		}
+	}
+}
@@ -8,22 +8,24 @@ use crate::{
 	keys::{key_match, SharedKeyConfig},
 	queue::{InternalEvent, Queue, StackablePopupOpen},
 	string_utils::tabs_to_spaces,
-	strings,
-	ui::{self, style::SharedTheme},
+	strings, try_or_popup,
+	ui::{self, style::SharedTheme, AsyncSyntaxJob},
+	AsyncAppNotification, AsyncNotification, SyntaxHighlightProgress,
 };
 use anyhow::Result;
 use asyncgit::{
-	sync::{BlameHunk, CommitId, FileBlame, RepoPathRef},
+	asyncjob::AsyncSingleJob,
+	sync::{self, BlameHunk, CommitId, FileBlame, RepoPathRef},
 	AsyncBlame, AsyncGitNotification, BlameParams,
 };
 use crossbeam_channel::Sender;
 use crossterm::event::Event;
-use std::convert::TryInto;
+use std::{convert::TryInto, path::Path};
 use tui::{
 	backend::Backend,
 	layout::{Constraint, Rect},
 	symbols::line::VERTICAL,
-	text::Span,
+	text::{Span, Spans, Text},
 	widgets::{Block, Borders, Cell, Clear, Row, Table, TableState},
 	Frame,
 };
@@ -41,6 +43,7 @@ pub struct BlameFileOpen {
 }
 
 pub struct BlameFileComponent {
+	repo: RepoPathRef,
 	title: String,
 	theme: SharedTheme,
 	queue: Queue,
@@ -49,9 +52,18 @@ pub struct BlameFileComponent {
 	open_request: Option<BlameFileOpen>,
 	params: Option<BlameParams>,
 	file_blame: Option<FileBlame>,
+	/// times of the newest and oldest commit in `file_blame`
+	time_range: Option<(i64, i64)>,
+	async_highlighting: AsyncSingleJob<AsyncSyntaxJob>,
+	/// highlighted content of `file_blame`
+	syntax: Option<ui::SyntaxText>,
 	table_state: std::cell::Cell<TableState>,
 	key_config: SharedKeyConfig,
 	current_height: std::cell::Cell<usize>,
+	/// where the table was drawn last
+	area: std::cell::Cell<Rect>,
+	/// soft wrap long source lines instead of cutting them off
+	wrap: bool,
 }
 impl DrawableComponent for BlameFileComponent {
 	fn draw<B: Backend>(
@@ -80,8 +92,6 @@ impl DrawableComponent for BlameFileComponent {
 				Constraint::Percentage(100),
 			];
 
-			let number_of_rows = rows.len();
-
 			let table = Table::new(rows)
 				.widths(&constraints)
 				.column_spacing(1)
@@ -101,27 +111,12 @@ impl DrawableComponent for BlameFileComponent {
 			f.render_widget(Clear, area);
 			f.render_stateful_widget(table, area, &mut table_state);
 
+			self.area.set(area);
 			ui::draw_scrollbar(
 				f,
 				area,
 				&self.theme,
-				// April 2021: `draw_scrollbar` assumes that the last parameter
-				// is `scroll_top`.  Therefore, it subtracts the area’s height
-				// before calculating the position of the scrollbar. To account
-				// for that, we add the current height.
-				number_of_rows + (area.height as usize),
-				// April 2021: we don’t have access to `table_state.offset`
-				// (it’s private), so we use `table_state.selected()` as a
-				// replacement.
-				//
-				// Other widgets, for example `BranchListComponent`, manage
-				// scroll state themselves and use `self.scroll_top` in this
-				// situation.
-				//
-				// There are plans to change `render_stateful_widgets`, so this
-				// might be acceptable as an interim solution.
-				//
-				// https://github.com/fdehau/tui-rs/issues/448
+				self.get_max_line_number(),
 				table_state.selected().unwrap_or(0),
 			);
 
@@ -158,10 +153,18 @@ impl Component for BlameFileComponent {
 			);
 			out.push(
 				CommandInfo::new(
-					strings::commands::commit_details_open(
+					strings::commands::blame_inspect_commit(
 						&self.key_config,
 					),
-					true,
+					self.selected_commit().is_some(),
+					self.file_blame.is_some(),
+				)
+				.order(1),
+			);
+			out.push(
+				CommandInfo::new(
+					strings::commands::blame_parent(&self.key_config),
+					self.selected_commit().is_some(),
 					self.file_blame.is_some(),
 				)
 				.order(1),
@@ -176,66 +179,101 @@ impl Component for BlameFileComponent {
 				)
 				.order(1),
 			);
+			out.push(CommandInfo::new(
+				strings::commands::copy_path(&self.key_config),
+				true,
+				self.file_blame.is_some(),
+			));
+			out.push(CommandInfo::new(
+				strings::commands::toggle_wrap(
+					&self.key_config,
+					self.wrap,
+				),
+				true,
+				self.file_blame.is_some(),
+			));
 		}
 
 		visibility_blocking(self)
 	}
 
+	#[allow(clippy::too_many_lines)]
 	fn event(
 		&mut self,
 		event: &crossterm::event::Event,
 	) -> Result<EventState> {
 		if self.is_visible() {
+			if let Event::Mouse(mouse) = event {
+				if let Some(line) = ui::scrollbar_position(
+					self.area.get(),
+					self.get_max_line_number(),
+					*mouse,
+				) {
+					let mut table_state = self.table_state.take();
+					table_state.select(Some(line));
+					self.table_state.set(table_state);
+				}
+			}
+
 			if let Event::Key(key) = event {
-				if key_match(key, self.key_config.keys.exit_popup) {
+				if key_match(key, self.key_config.keys().exit_popup) {
 					self.hide_stacked(false);
-				} else if key_match(key, self.key_config.keys.move_up)
-				{
+				} else if key_match(
+					key,
+					self.key_config.keys().move_up,
+				) {
 					self.move_selection(ScrollType::Up);
 				} else if key_match(
 					key,
-					self.key_config.keys.move_down,
+					self.key_config.keys().move_down,
 				) {
 					self.move_selection(ScrollType::Down);
 				} else if key_match(
 					key,
-					self.key_config.keys.shift_up,
+					self.key_config.keys().shift_up,
 				) || key_match(
 					key,
-					self.key_config.keys.home,
+					self.key_config.keys().home,
 				) {
 					self.move_selection(ScrollType::Home);
 				} else if key_match(
 					key,
-					self.key_config.keys.shift_down,
+					self.key_config.keys().shift_down,
 				) || key_match(
 					key,
-					self.key_config.keys.end,
+					self.key_config.keys().end,
 				) {
 					self.move_selection(ScrollType::End);
 				} else if key_match(
 					key,
-					self.key_config.keys.page_down,
+					self.key_config.keys().page_down,
 				) {
 					self.move_selection(ScrollType::PageDown);
-				} else if key_match(key, self.key_config.keys.page_up)
-				{
+				} else if key_match(
+					key,
+					self.key_config.keys().page_up,
+				) {
 					self.move_selection(ScrollType::PageUp);
 				} else if key_match(
 					key,
-					self.key_config.keys.focus_right,
+					self.key_config.keys().focus_right,
+				) || key_match(
+					key,
+					self.key_config.keys().enter,
 				) {
-					if let Some(commit_id) = self.selected_commit() {
-						self.hide_stacked(true);
-						self.queue.push(InternalEvent::OpenPopup(
-							StackablePopupOpen::InspectCommit(
-								InspectCommitOpen::new(commit_id),
-							),
-						));
-					}
+					self.inspect_selected_commit();
+				} else if key_match(
+					key,
+					self.key_config.keys().blame_parent,
+				) {
+					try_or_popup!(
+						self,
+						"blame error:",
+						self.blame_parent()
+					);
 				} else if key_match(
 					key,
-					self.key_config.keys.file_history,
+					self.key_config.keys().file_history,
 				) {
 					if let Some(filepath) = self
 						.params
@@ -249,6 +287,21 @@ impl Component for BlameFileComponent {
 							),
 						));
 					}
+				} else if key_match(
+					key,
+					self.key_config.keys().toggle_wrap,
+				) {
+					self.wrap = !self.wrap;
+				} else if key_match(
+					key,
+					self.key_config.keys().copy_path,
+				) {
+					if let Some(params) = &self.params {
+						self.queue.push(InternalEvent::CopyPath(
+							params.file_path.clone(),
+							self.get_selection(),
+						));
+					}
 				}
 
 				return Ok(EventState::Consumed);
@@ -275,11 +328,13 @@ impl BlameFileComponent {
 		repo: &RepoPathRef,
 		queue: &Queue,
 		sender: &Sender<AsyncGitNotification>,
+		sender_app: &Sender<AsyncAppNotification>,
 		title: &str,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
 	) -> Self {
 		Self {
+			repo: repo.clone(),
 			title: String::from(title),
 			theme,
 			async_blame: AsyncBlame::new(
@@ -290,10 +345,17 @@ impl BlameFileComponent {
 			visible: false,
 			params: None,
 			file_blame: None,
+			time_range: None,
+			async_highlighting: AsyncSingleJob::new(
+				sender_app.clone(),
+			),
+			syntax: None,
 			open_request: None,
 			table_state: std::cell::Cell::new(TableState::default()),
 			key_config,
 			current_height: std::cell::Cell::new(0),
+			area: std::cell::Cell::new(Rect::default()),
+			wrap: false,
 		}
 	}
 
@@ -322,6 +384,8 @@ impl BlameFileComponent {
 			commit_id: open.commit_id,
 		});
 		self.file_blame = None;
+		self.time_range = None;
+		self.syntax = None;
 		self.table_state.get_mut().select(Some(0));
 		self.visible = true;
 
@@ -333,6 +397,7 @@ impl BlameFileComponent {
 	///
 	pub fn any_work_pending(&self) -> bool {
 		self.async_blame.is_pending()
+			|| self.async_highlighting.is_pending()
 	}
 
 	///
@@ -347,6 +412,31 @@ impl BlameFileComponent {
 		Ok(())
 	}
 
+	///
+	pub fn update_async(&mut self, ev: AsyncNotification) {
+		if ev
+			== AsyncNotification::App(
+				AsyncAppNotification::SyntaxHighlighting(
+					SyntaxHighlightProgress::Done,
+				),
+			) {
+			if let Some(syntax) = self
+				.async_highlighting
+				.take_last()
+				.and_then(|job| job.result())
+			{
+				let current_file =
+					self.params.as_ref().map_or(false, |params| {
+						syntax.path() == Path::new(&params.file_path)
+					});
+
+				if current_file {
+					self.syntax = Some(syntax);
+				}
+			}
+		}
+	}
+
 	fn update(&mut self) -> Result<()> {
 		if self.is_visible() {
 			if let Some(params) = &self.params {
@@ -356,6 +446,9 @@ impl BlameFileComponent {
 				)) = self.async_blame.last()?
 				{
 					if previous_blame_params == *params {
+						self.time_range =
+							time_range(&last_file_blame);
+						self.highlight(&last_file_blame);
 						self.file_blame = Some(last_file_blame);
 						self.set_open_selection();
 
@@ -370,10 +463,29 @@ impl BlameFileComponent {
 		Ok(())
 	}
 
+	/// highlights the blamed content in the background, the lines
+	/// are shown unstyled until it is done
+	fn highlight(&mut self, file_blame: &FileBlame) {
+		let path = match &self.params {
+			Some(params) => params.file_path.clone(),
+			None => return,
+		};
+		let content = file_blame
+			.lines
+			.iter()
+			.map(|(_, line)| tabs_to_spaces(line.clone()))
+			.collect::<Vec<_>>()
+			.join("\n");
+
+		self.syntax = None;
+		self.async_highlighting
+			.spawn(AsyncSyntaxJob::new(content, path));
+	}
+
 	///
 	fn get_title(&self) -> String {
 		match (
-			self.any_work_pending(),
+			self.async_blame.is_pending(),
 			self.params.as_ref(),
 			self.file_blame.as_ref(),
 		) {
@@ -403,6 +515,11 @@ impl BlameFileComponent {
 
 	///
 	fn get_rows(&self, width: usize) -> Vec<Row> {
+		let mut highlighted = self
+			.syntax
+			.as_ref()
+			.map(|syntax| Text::from(syntax).lines.into_iter());
+
 		self.file_blame
 			.as_ref()
 			.map_or_else(Vec::new, |file_blame| {
@@ -415,6 +532,9 @@ impl BlameFileComponent {
 							width,
 							i,
 							(blame_hunk.as_ref(), line.as_ref()),
+							highlighted
+								.as_mut()
+								.and_then(Iterator::next),
 							file_blame,
 						)
 					})
@@ -422,13 +542,14 @@ impl BlameFileComponent {
 			})
 	}
 
-	fn get_line_blame(
-		&self,
+	fn get_line_blame<'a>(
+		&'a self,
 		width: usize,
 		line_number: usize,
-		hunk_and_line: (Option<&BlameHunk>, &str),
+		hunk_and_line: (Option<&BlameHunk>, &'a str),
+		highlighted: Option<Spans<'a>>,
 		file_blame: &FileBlame,
-	) -> Row {
+	) -> Row<'a> {
 		let (hunk_for_line, line) = hunk_and_line;
 
 		let show_metadata = if line_number == 0 {
@@ -461,12 +582,43 @@ impl BlameFileComponent {
 			))
 			.style(self.theme.text(true, false)),
 		);
-		cells.push(
-			Cell::from(tabs_to_spaces(String::from(line)))
-				.style(self.theme.text(true, false)),
-		);
+		let content = highlighted.unwrap_or_else(|| {
+			Spans::from(tabs_to_spaces(String::from(line)))
+		});
 
-		Row::new(cells)
+		if self.wrap {
+			let rows = ui::wrap_spans(
+				&content,
+				self.get_source_width(width),
+			);
+			let height = u16::try_from(rows.len()).unwrap_or(1);
+
+			cells.push(
+				Cell::from(Text::from(rows))
+					.style(self.theme.text(true, false)),
+			);
+
+			Row::new(cells).height(height)
+		} else {
+			cells.push(
+				Cell::from(content)
+					.style(self.theme.text(true, false)),
+			);
+
+			Row::new(cells)
+		}
+	}
+
+	/// width of the source column, see the constraints in `draw`
+	fn get_source_width(&self, width: usize) -> usize {
+		// borders and the spacing between the five columns
+		let decorations = 2 + 4;
+
+		width
+			.saturating_sub(decorations)
+			.saturating_sub(7 + 10)
+			.saturating_sub(get_author_width(width))
+			.saturating_sub(self.get_line_number_width() + 1)
 	}
 
 	fn get_metadata_for_line_blame(
@@ -503,15 +655,44 @@ impl BlameFileComponent {
 			})
 			.unwrap_or(false);
 
+		let (hash_style, time_style) = blame_hunk
+			.and_then(|hunk| self.age(hunk.time))
+			.map_or_else(
+				|| {
+					(
+						self.theme
+							.commit_hash_in_blame(is_blamed_commit),
+						self.theme.commit_time(false),
+					)
+				},
+				|age| {
+					(
+						self.theme.blame_age(age, is_blamed_commit),
+						self.theme.blame_age(age, false),
+					)
+				},
+			);
+
 		vec![
-			Cell::from(commit_hash).style(
-				self.theme.commit_hash_in_blame(is_blamed_commit),
-			),
-			Cell::from(time).style(self.theme.commit_time(false)),
+			Cell::from(commit_hash).style(hash_style),
+			Cell::from(time).style(time_style),
 			Cell::from(author).style(self.theme.commit_author(false)),
 		]
 	}
 
+	/// age of a commit from `time` in percent between the newest (0)
+	/// and oldest (100) commit of the file
+	fn age(&self, time: i64) -> Option<u8> {
+		let (newest, oldest) = self.time_range?;
+		let span = newest - oldest;
+
+		if span <= 0 {
+			return Some(0);
+		}
+
+		u8::try_from((newest - time) * 100 / span).ok()
+	}
+
 	fn get_max_line_number(&self) -> usize {
 		self.file_blame
 			.as_ref()
@@ -555,10 +736,60 @@ impl BlameFileComponent {
 		needs_update
 	}
 
+	/// opens the commit of the selected line, closing the commit
+	/// brings back the blame at the same line
+	fn inspect_selected_commit(&mut self) {
+		if let Some(commit_id) = self.selected_commit() {
+			self.hide_stacked(true);
+			self.queue.push(InternalEvent::OpenPopup(
+				StackablePopupOpen::InspectCommit(
+					InspectCommitOpen::new(commit_id),
+				),
+			));
+		}
+	}
+
+	/// blames the file as of the parent of the commit that last
+	/// touched the selected line, closing it walks back again
+	fn blame_parent(&mut self) -> Result<()> {
+		if let Some((selection, hunk)) = self.selected_hunk() {
+			let parent = sync::blame_parent(
+				&self.repo.borrow(),
+				hunk.commit_id,
+				&hunk.orig_path,
+			)?;
+
+			if let Some(parent) = parent {
+				// the line most likely sits where it was
+				// before that commit
+				let line = hunk.orig_start_line
+					+ selection.saturating_sub(hunk.start_line);
+
+				self.hide_stacked(true);
+				self.queue.push(InternalEvent::OpenPopup(
+					StackablePopupOpen::BlameFile(BlameFileOpen {
+						file_path: hunk.orig_path,
+						commit_id: Some(parent),
+						selection: Some(line),
+					}),
+				));
+			} else {
+				self.queue.push(InternalEvent::ShowInfoMsg(
+					strings::blame_no_parent(
+						&hunk.commit_id.get_short_string(),
+					),
+				));
+			}
+		}
+
+		Ok(())
+	}
+
 	fn set_open_selection(&mut self) {
 		if let Some(selection) =
 			self.open_request.as_ref().and_then(|req| req.selection)
 		{
+			let selection = selection.min(self.get_max_line_number());
 			let mut table_state = self.table_state.take();
 			table_state.select(Some(selection));
 			self.table_state.set(table_state);
@@ -578,24 +809,39 @@ impl BlameFileComponent {
 	}
 
 	fn selected_commit(&self) -> Option<CommitId> {
+		self.selected_hunk().map(|(_, hunk)| hunk.commit_id)
+	}
+
+	/// selected line and the hunk it belongs to
+	fn selected_hunk(&self) -> Option<(usize, BlameHunk)> {
 		self.file_blame.as_ref().and_then(|file_blame| {
 			let table_state = self.table_state.take();
 
-			let commit_id =
-				table_state.selected().and_then(|selected| {
-					file_blame.lines[selected]
-						.0
-						.as_ref()
-						.map(|hunk| hunk.commit_id)
-				});
+			let hunk = table_state.selected().and_then(|selected| {
+				file_blame
+					.lines
+					.get(selected)
+					.and_then(|(hunk, _)| hunk.clone())
+					.map(|hunk| (selected, hunk))
+			});
 
 			self.table_state.set(table_state);
 
-			commit_id
+			hunk
 		})
 	}
 }
 
+/// times of the newest and oldest commit blamed
+fn time_range(file_blame: &FileBlame) -> Option<(i64, i64)> {
+	let times = file_blame
+		.lines
+		.iter()
+		.filter_map(|(hunk, _)| hunk.as_ref().map(|hunk| hunk.time));
+
+	times.clone().max().zip(times.min())
+}
+
 fn get_author_width(width: usize) -> usize {
 	(width.saturating_sub(19) / 3)
 		.clamp(MIN_AUTHOR_WIDTH, MAX_AUTHOR_WIDTH)
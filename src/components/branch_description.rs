@@ -0,0 +1,158 @@
+use super::{
+	textinput::TextInputComponent, visibility_blocking,
+	CommandBlocking, CommandInfo, Component, DrawableComponent,
+	EventState,
+};
+use crate::{
+	keys::{key_match, SharedKeyConfig},
+	queue::{InternalEvent, NeedsUpdate, Queue},
+	strings,
+	ui::style::SharedTheme,
+};
+use anyhow::Result;
+use asyncgit::sync::{self, RepoPathRef};
+use crossterm::event::Event;
+use tui::{backend::Backend, layout::Rect, Frame};
+
+pub struct BranchDescriptionComponent {
+	repo: RepoPathRef,
+	input: TextInputComponent,
+	branch: Option<String>,
+	queue: Queue,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for BranchDescriptionComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		self.input.draw(f, rect)?;
+
+		Ok(())
+	}
+}
+
+impl Component for BranchDescriptionComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			self.input.commands(out, force_all);
+
+			out.push(CommandInfo::new(
+				strings::commands::branch_description_confirm(
+					&self.key_config,
+				),
+				true,
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, ev: &Event) -> Result<EventState> {
+		if self.is_visible() {
+			if self.input.event(ev)?.is_consumed() {
+				return Ok(EventState::Consumed);
+			}
+
+			if let Event::Key(e) = ev {
+				if key_match(e, self.key_config.keys().enter) {
+					self.set_description();
+				}
+
+				return Ok(EventState::Consumed);
+			}
+		}
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.input.is_visible()
+	}
+
+	fn hide(&mut self) {
+		self.input.hide();
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.input.show()?;
+
+		Ok(())
+	}
+}
+
+impl BranchDescriptionComponent {
+	///
+	pub fn new(
+		repo: RepoPathRef,
+		queue: Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			repo,
+			queue,
+			input: TextInputComponent::new(
+				theme,
+				key_config.clone(),
+				&strings::branch_description_popup_title(&key_config),
+				&strings::branch_description_popup_msg(&key_config),
+				false,
+			),
+			branch: None,
+			key_config,
+		}
+	}
+
+	///
+	pub fn open(
+		&mut self,
+		branch: String,
+		description: String,
+	) -> Result<()> {
+		self.input.set_title(format!(
+			"{}: {}",
+			strings::branch_description_popup_title(&self.key_config),
+			branch
+		));
+		self.branch = Some(branch);
+		self.input.set_text(description);
+		self.show()?;
+
+		Ok(())
+	}
+
+	/// an empty text removes the description
+	pub fn set_description(&mut self) {
+		if let Some(branch) = &self.branch {
+			let res = sync::set_branch_description(
+				&self.repo.borrow(),
+				branch,
+				self.input.get_text(),
+			);
+
+			match res {
+				Ok(()) => {
+					self.queue.push(InternalEvent::Update(
+						NeedsUpdate::ALL,
+					));
+					self.hide();
+					self.input.clear();
+					self.queue.push(InternalEvent::SelectBranch);
+				}
+				Err(e) => {
+					log::error!("branch description: {}", e);
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						format!("branch description error:\n{}", e),
+					));
+				}
+			}
+		}
+	}
+}
@@ -1,48 +1,163 @@
 use super::{
-	utils::scroll_vertical::VerticalScroll, visibility_blocking,
-	CommandBlocking, CommandInfo, Component, DrawableComponent,
-	EventState, InspectCommitOpen,
+	utils::{
+		autostash::run_with_autostash,
+		scroll_vertical::VerticalScroll, time_to_age,
+	},
+	visibility_blocking, CommandBlocking, CommandInfo,
+	CompareBranchesOpen, Component, DrawableComponent, EventState,
+	InspectCommitOpen, SharedOptions,
 };
 use crate::{
 	components::ScrollType,
 	keys::{key_match, SharedKeyConfig},
 	queue::{
-		Action, InternalEvent, NeedsUpdate, Queue, StackablePopupOpen,
+		Action, AutostashOp, InternalEvent, NeedsUpdate, Queue,
+		StackablePopupOpen,
 	},
-	strings, try_or_popup,
-	ui::{self, Size},
+	strings::{self, symbol},
+	try_or_popup,
+	ui::{self, icons, Size},
 };
 use anyhow::Result;
 use asyncgit::{
+	asyncjob::AsyncSingleJob,
+	branches_compare::AsyncBranchesCompareJob,
 	sync::{
 		self,
 		branch::{
 			checkout_remote_branch, BranchDetails, LocalBranch,
 			RemoteBranch,
 		},
-		checkout_branch, get_branches_info, BranchInfo, BranchType,
-		CommitId, RepoPathRef, RepoState,
+		checkout_branch, get_branches_info, get_config_string,
+		set_config_string, BranchCompare, BranchInfo, BranchType,
+		CommitId, RepoPath, RepoPathRef, RepoState,
 	},
 	AsyncGitNotification,
 };
+use chrono::Utc;
+use crossbeam_channel::Sender;
 use crossterm::event::Event;
-use std::{cell::Cell, convert::TryInto};
+use std::{
+	cell::Cell, cmp::Reverse, collections::HashMap, convert::TryInto,
+};
 use tui::{
 	backend::Backend,
 	layout::{
 		Alignment, Constraint, Direction, Layout, Margin, Rect,
 	},
 	text::{Span, Spans, Text},
-	widgets::{Block, BorderType, Borders, Clear, Paragraph, Tabs},
+	widgets::{
+		Block, BorderType, Borders, Clear, Paragraph, Tabs, Wrap,
+	},
 	Frame,
 };
 use ui::style::SharedTheme;
 use unicode_truncate::UnicodeTruncateStr;
 
+/// rows around the selection to load authors for before the first draw
+const AUTHOR_ROWS_DEFAULT: usize = 50;
+
+/// which local branches to list
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum BranchFilter {
+	All,
+	NoUpstream,
+	Behind,
+}
+
+impl BranchFilter {
+	const fn next(self) -> Self {
+		match self {
+			Self::All => Self::NoUpstream,
+			Self::NoUpstream => Self::Behind,
+			Self::Behind => Self::All,
+		}
+	}
+
+	const fn name(self) -> &'static str {
+		match self {
+			Self::All => "all",
+			Self::NoUpstream => "no upstream",
+			Self::Behind => "behind",
+		}
+	}
+}
+
+/// order of the branch list
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum BranchSort {
+	Name,
+	/// most recent top commit first
+	Date,
+	/// most commits behind and ahead of upstream first
+	AheadBehind,
+}
+
+impl BranchSort {
+	const CONFIG_KEY: &'static str = "gitui.branchSort";
+
+	const fn next(self) -> Self {
+		match self {
+			Self::Name => Self::Date,
+			Self::Date => Self::AheadBehind,
+			Self::AheadBehind => Self::Name,
+		}
+	}
+
+	const fn name(self) -> &'static str {
+		match self {
+			Self::Name => "name",
+			Self::Date => "date",
+			Self::AheadBehind => "ahead/behind",
+		}
+	}
+
+	const fn config_value(self) -> &'static str {
+		match self {
+			Self::Name => "name",
+			Self::Date => "date",
+			Self::AheadBehind => "aheadbehind",
+		}
+	}
+
+	/// sort order persisted in the repo config
+	fn load(repo: &RepoPath) -> Self {
+		let value = get_config_string(repo, Self::CONFIG_KEY)
+			.ok()
+			.flatten()
+			.unwrap_or_default();
+
+		[Self::Date, Self::AheadBehind]
+			.into_iter()
+			.find(|sort| sort.config_value() == value)
+			.unwrap_or(Self::Name)
+	}
+
+	fn save(self, repo: &RepoPath) -> Result<()> {
+		set_config_string(
+			repo,
+			Self::CONFIG_KEY,
+			self.config_value(),
+		)?;
+		Ok(())
+	}
+}
+
 ///
 pub struct BranchListComponent {
 	repo: RepoPathRef,
 	branches: Vec<BranchInfo>,
+	/// ahead/behind upstream of local branches, by name
+	upstream_compare: HashMap<String, BranchCompare>,
+	async_compare: AsyncSingleJob<AsyncBranchesCompareJob>,
+	/// authors of the top commits, loaded lazily for visible rows
+	top_commit_authors: HashMap<CommitId, String>,
+	filter: BranchFilter,
+	sort: BranchSort,
+	/// names of local branches marked for deletion
+	marked: Vec<String>,
+	/// local branch whose upstream is being picked from the remotes
+	upstream_for: Option<String>,
 	local: bool,
 	has_remotes: bool,
 	visible: bool,
@@ -52,6 +167,7 @@ pub struct BranchListComponent {
 	queue: Queue,
 	theme: SharedTheme,
 	key_config: SharedKeyConfig,
+	options: SharedOptions,
 }
 
 impl DrawableComponent for BranchListComponent {
@@ -75,11 +191,14 @@ impl DrawableComponent for BranchListComponent {
 
 			f.render_widget(Clear, area);
 
+			let title = self.title();
+
 			f.render_widget(
 				Block::default()
-					.title(strings::title_branches())
+					.title(title)
 					.border_type(BorderType::Thick)
-					.borders(Borders::ALL),
+					.borders(Borders::ALL)
+					.border_style(self.theme.popup_border()),
 				area,
 			);
 
@@ -88,16 +207,32 @@ impl DrawableComponent for BranchListComponent {
 				horizontal: 1,
 			});
 
+			let description = self.selected_description();
+			let description_height =
+				description.as_ref().map_or(0, |description| {
+					description.lines().count().min(4) + 1
+				});
+
 			let chunks = Layout::default()
 				.direction(Direction::Vertical)
 				.constraints(
-					[Constraint::Length(2), Constraint::Min(1)]
-						.as_ref(),
+					[
+						Constraint::Length(2),
+						Constraint::Min(1),
+						Constraint::Length(
+							description_height.try_into()?,
+						),
+					]
+					.as_ref(),
 				)
 				.split(area);
 
 			self.draw_tabs(f, chunks[0]);
 			self.draw_list(f, chunks[1])?;
+
+			if let Some(description) = description {
+				self.draw_description(f, chunks[2], &description);
+			}
 		}
 
 		Ok(())
@@ -105,6 +240,7 @@ impl DrawableComponent for BranchListComponent {
 }
 
 impl Component for BranchListComponent {
+	#[allow(clippy::too_many_lines)]
 	fn commands(
 		&self,
 		out: &mut Vec<CommandInfo>,
@@ -127,6 +263,18 @@ impl Component for BranchListComponent {
 				true,
 			));
 
+			if self.upstream_for.is_some() {
+				out.push(CommandInfo::new(
+					strings::commands::branch_pick_upstream(
+						&self.key_config,
+					),
+					self.valid_selection(),
+					true,
+				));
+
+				return visibility_blocking(self);
+			}
+
 			out.push(CommandInfo::new(
 				strings::commands::commit_details_open(
 					&self.key_config,
@@ -135,13 +283,23 @@ impl Component for BranchListComponent {
 				true,
 			));
 
-			out.push(CommandInfo::new(
-				strings::commands::compare_with_head(
-					&self.key_config,
-				),
-				!self.selection_is_cur_branch(),
-				true,
-			));
+			if self.marked.len() == 2 {
+				out.push(CommandInfo::new(
+					strings::commands::compare_branches(
+						&self.key_config,
+					),
+					true,
+					true,
+				));
+			} else {
+				out.push(CommandInfo::new(
+					strings::commands::compare_with_head(
+						&self.key_config,
+					),
+					!self.selection_is_cur_branch(),
+					true,
+				));
+			}
 
 			out.push(CommandInfo::new(
 				strings::commands::toggle_branch_popup(
@@ -173,10 +331,18 @@ impl Component for BranchListComponent {
 				strings::commands::delete_branch_popup(
 					&self.key_config,
 				),
-				!self.selection_is_cur_branch(),
+				!self.selection_is_cur_branch()
+					|| !self.marked.is_empty(),
 				true,
 			));
 
+			out.push(CommandInfo::new(
+				strings::commands::branch_mark(&self.key_config),
+				self.valid_selection()
+					&& !self.selection_is_cur_branch(),
+				self.local,
+			));
+
 			out.push(CommandInfo::new(
 				strings::commands::merge_branch_popup(
 					&self.key_config,
@@ -201,6 +367,48 @@ impl Component for BranchListComponent {
 				self.local,
 			));
 
+			out.push(CommandInfo::new(
+				strings::commands::branch_description_popup(
+					&self.key_config,
+				),
+				self.valid_selection(),
+				self.local,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::branch_set_upstream(
+					&self.key_config,
+				),
+				self.valid_selection(),
+				self.local,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::prune_gone_branches(
+					&self.key_config,
+				),
+				true,
+				self.local,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::branch_sort(
+					&self.key_config,
+					self.sort.name(),
+				),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::branch_filter(
+					&self.key_config,
+					self.filter.name(),
+				),
+				true,
+				self.local,
+			));
+
 			out.push(CommandInfo::new(
 				strings::commands::fetch_remotes(&self.key_config),
 				self.has_remotes,
@@ -211,73 +419,128 @@ impl Component for BranchListComponent {
 	}
 
 	//TODO: cleanup
-	#[allow(clippy::cognitive_complexity)]
+	#[allow(clippy::cognitive_complexity, clippy::too_many_lines)]
 	fn event(&mut self, ev: &Event) -> Result<EventState> {
 		if !self.visible {
 			return Ok(EventState::NotConsumed);
 		}
 
+		if let Event::Mouse(mouse) = ev {
+			if let Some(top) = self.scroll.click(*mouse) {
+				self.set_selection(top.try_into()?)?;
+			}
+			return Ok(EventState::Consumed);
+		}
+
 		if let Event::Key(e) = ev {
-			if key_match(e, self.key_config.keys.exit_popup) {
-				self.hide();
-			} else if key_match(e, self.key_config.keys.move_down) {
+			if key_match(e, self.key_config.keys().exit_popup) {
+				if self.upstream_for.is_some() {
+					self.stop_picking_upstream()?;
+				} else {
+					self.hide();
+				}
+			} else if key_match(e, self.key_config.keys().move_down) {
 				return self
 					.move_selection(ScrollType::Up)
 					.map(Into::into);
-			} else if key_match(e, self.key_config.keys.move_up) {
+			} else if key_match(e, self.key_config.keys().move_up) {
 				return self
 					.move_selection(ScrollType::Down)
 					.map(Into::into);
-			} else if key_match(e, self.key_config.keys.page_down) {
+			} else if key_match(e, self.key_config.keys().page_down) {
 				return self
 					.move_selection(ScrollType::PageDown)
 					.map(Into::into);
-			} else if key_match(e, self.key_config.keys.page_up) {
+			} else if key_match(e, self.key_config.keys().page_up) {
 				return self
 					.move_selection(ScrollType::PageUp)
 					.map(Into::into);
-			} else if key_match(e, self.key_config.keys.home) {
+			} else if key_match(e, self.key_config.keys().home) {
 				return self
 					.move_selection(ScrollType::Home)
 					.map(Into::into);
-			} else if key_match(e, self.key_config.keys.end) {
+			} else if key_match(e, self.key_config.keys().end) {
 				return self
 					.move_selection(ScrollType::End)
 					.map(Into::into);
-			} else if key_match(e, self.key_config.keys.tab_toggle) {
+			} else if let Some(branch) = self.upstream_for.clone() {
+				if key_match(e, self.key_config.keys().enter)
+					&& self.valid_selection()
+				{
+					try_or_popup!(
+						self,
+						"set upstream error:",
+						self.set_upstream(&branch)
+					);
+				} else if key_match(
+					e,
+					self.key_config.keys().tab_toggle,
+				) {
+					self.stop_picking_upstream()?;
+				}
+			} else if key_match(e, self.key_config.keys().tab_toggle)
+			{
 				self.local = !self.local;
 				self.check_remotes();
 				self.update_branches()?;
-			} else if key_match(e, self.key_config.keys.enter) {
+			} else if key_match(e, self.key_config.keys().enter) {
 				try_or_popup!(
 					self,
 					"switch branch error:",
 					self.switch_to_selected_branch()
 				);
-			} else if key_match(e, self.key_config.keys.create_branch)
-				&& self.local
+			} else if key_match(
+				e,
+				self.key_config.keys().create_branch,
+			) && self.local
 			{
 				self.queue.push(InternalEvent::CreateBranch);
-			} else if key_match(e, self.key_config.keys.rename_branch)
-				&& self.valid_selection()
+			} else if key_match(
+				e,
+				self.key_config.keys().rename_branch,
+			) && self.valid_selection()
 			{
 				self.rename_branch();
-			} else if key_match(e, self.key_config.keys.delete_branch)
-				&& !self.selection_is_cur_branch()
+			} else if key_match(
+				e,
+				self.key_config.keys().branch_description,
+			) && self.local
+				&& self.valid_selection()
+			{
+				self.edit_description();
+			} else if key_match(
+				e,
+				self.key_config.keys().delete_branch,
+			) && self.local
+				&& !self.marked.is_empty()
+			{
+				self.queue.push(InternalEvent::ConfirmAction(
+					Action::DeleteLocalBranches(self.marked.clone()),
+				));
+			} else if key_match(
+				e,
+				self.key_config.keys().delete_branch,
+			) && !self.selection_is_cur_branch()
 				&& self.valid_selection()
 			{
 				self.delete_branch();
-			} else if key_match(e, self.key_config.keys.merge_branch)
+			} else if key_match(e, self.key_config.keys().branch_mark)
+				&& self.local
+				&& self.valid_selection()
 				&& !self.selection_is_cur_branch()
+			{
+				self.toggle_mark();
+			} else if key_match(
+				e,
+				self.key_config.keys().merge_branch,
+			) && !self.selection_is_cur_branch()
 				&& self.valid_selection()
 			{
-				try_or_popup!(
-					self,
-					"merge branch error:",
-					self.merge_branch()
-				);
-			} else if key_match(e, self.key_config.keys.rebase_branch)
-				&& !self.selection_is_cur_branch()
+				self.merge_branch();
+			} else if key_match(
+				e,
+				self.key_config.keys().rebase_branch,
+			) && !self.selection_is_cur_branch()
 				&& self.valid_selection()
 			{
 				try_or_popup!(
@@ -285,13 +548,20 @@ impl Component for BranchListComponent {
 					"rebase error:",
 					self.rebase_branch()
 				);
-			} else if key_match(e, self.key_config.keys.move_right)
+			} else if key_match(e, self.key_config.keys().move_right)
 				&& self.valid_selection()
 			{
 				self.inspect_head_of_branch();
 			} else if key_match(
 				e,
-				self.key_config.keys.compare_commits,
+				self.key_config.keys().compare_commits,
+			) && self.local
+				&& self.marked.len() == 2
+			{
+				self.compare_marked();
+			} else if key_match(
+				e,
+				self.key_config.keys().compare_commits,
 			) && self.valid_selection()
 			{
 				self.hide();
@@ -302,13 +572,44 @@ impl Component for BranchListComponent {
 						),
 					));
 				}
-			} else if key_match(e, self.key_config.keys.pull)
-				&& !self.local && self.has_remotes
+			} else if key_match(
+				e,
+				self.key_config.keys().branch_set_upstream,
+			) && self.local
+				&& self.valid_selection()
+			{
+				self.start_picking_upstream()?;
+			} else if key_match(
+				e,
+				self.key_config.keys().branch_prune_gone,
+			) && self.local
+			{
+				self.queue.push(InternalEvent::OpenPruneBranches);
+			} else if key_match(e, self.key_config.keys().branch_sort)
+			{
+				self.sort = self.sort.next();
+				try_or_popup!(
+					self,
+					"save branch sort error:",
+					self.sort.save(&self.repo.borrow())
+				);
+				self.refresh_list()?;
+			} else if key_match(
+				e,
+				self.key_config.keys().branch_filter,
+			) && self.local
+			{
+				self.filter = self.filter.next();
+				self.set_selection(0)?;
+				self.refresh_list()?;
+			} else if key_match(e, self.key_config.keys().pull)
+				&& !self.local
+				&& self.has_remotes
 			{
 				self.queue.push(InternalEvent::FetchRemotes);
 			} else if key_match(
 				e,
-				self.key_config.keys.cmd_bar_toggle,
+				self.key_config.keys().cmd_bar_toggle,
 			) {
 				//do not consume if its the more key
 				return Ok(EventState::NotConsumed);
@@ -337,11 +638,20 @@ impl BranchListComponent {
 	pub fn new(
 		repo: RepoPathRef,
 		queue: Queue,
+		sender: &Sender<AsyncGitNotification>,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
+		options: SharedOptions,
 	) -> Self {
 		Self {
 			branches: Vec::new(),
+			upstream_compare: HashMap::new(),
+			async_compare: AsyncSingleJob::new(sender.clone()),
+			top_commit_authors: HashMap::new(),
+			filter: BranchFilter::All,
+			sort: BranchSort::Name,
+			upstream_for: None,
+			marked: Vec::new(),
 			local: true,
 			has_remotes: false,
 			visible: false,
@@ -350,6 +660,7 @@ impl BranchListComponent {
 			queue,
 			theme,
 			key_config,
+			options,
 			current_height: Cell::new(0),
 			repo,
 		}
@@ -357,12 +668,48 @@ impl BranchListComponent {
 
 	///
 	pub fn open(&mut self) -> Result<()> {
+		if self.upstream_for.take().is_some() {
+			self.local = true;
+		}
+		self.sort = BranchSort::load(&self.repo.borrow());
 		self.show()?;
 		self.update_branches()?;
 
 		Ok(())
 	}
 
+	/// switch to the remote branches to pick an upstream for the
+	/// selected local branch
+	fn start_picking_upstream(&mut self) -> Result<()> {
+		self.upstream_for = Some(
+			self.branches[usize::from(self.selection)].name.clone(),
+		);
+		self.local = false;
+		self.selection = 0;
+		self.update_branches()
+	}
+
+	fn stop_picking_upstream(&mut self) -> Result<()> {
+		self.upstream_for = None;
+		self.local = true;
+		self.update_branches()
+	}
+
+	fn set_upstream(&mut self, branch: &str) -> Result<()> {
+		let upstream = &self.branches[usize::from(self.selection)];
+
+		sync::set_branch_upstream(
+			&self.repo.borrow(),
+			branch,
+			&upstream.name,
+		)?;
+
+		self.stop_picking_upstream()?;
+		self.queue.push(InternalEvent::Update(NeedsUpdate::ALL));
+
+		Ok(())
+	}
+
 	fn check_remotes(&mut self) {
 		if !self.local {
 			self.has_remotes =
@@ -372,72 +719,245 @@ impl BranchListComponent {
 		}
 	}
 
-	/// fetch list of branches
+	/// fetch list of branches, along with the authors of the rows
+	/// around the selection
 	pub fn update_branches(&mut self) -> Result<()> {
 		if self.is_visible() {
 			self.check_remotes();
-			self.branches =
-				get_branches_info(&self.repo.borrow(), self.local)?;
-			//remove remote branch called `HEAD`
-			if !self.local {
-				self.branches
-					.iter()
-					.position(|b| b.name.ends_with("/HEAD"))
-					.map(|idx| self.branches.remove(idx));
+			self.refresh_list()?;
+
+			if self.local {
+				self.async_compare.spawn(
+					AsyncBranchesCompareJob::new(
+						self.repo.borrow().clone(),
+					),
+				);
 			}
-			self.set_selection(self.selection)?;
 		}
 		Ok(())
 	}
 
-	///
+	/// list branches using the last known upstream comparison
+	fn refresh_list(&mut self) -> Result<()> {
+		self.branches =
+			get_branches_info(&self.repo.borrow(), self.local)?;
+		//remove remote branch called `HEAD`
+		if !self.local {
+			self.branches
+				.iter()
+				.position(|b| b.name.ends_with("/HEAD"))
+				.map(|idx| self.branches.remove(idx));
+		}
+
+		if self.local {
+			let filter = self.filter;
+			let compare = &self.upstream_compare;
+			self.branches.retain(|branch| match filter {
+				BranchFilter::All => true,
+				BranchFilter::NoUpstream => !branch
+					.local_details()
+					.map_or(false, |details| details.has_upstream),
+				BranchFilter::Behind => compare
+					.get(&branch.name)
+					.map_or(false, |compare| compare.behind > 0),
+			});
+		}
+
+		self.sort_list();
+
+		if self.local {
+			let branches = &self.branches;
+			self.marked.retain(|name| {
+				branches.iter().any(|b| &b.name == name)
+			});
+		} else {
+			self.marked.clear();
+		}
+
+		// also loads the authors of new top commits
+		self.set_selection(self.selection)?;
+
+		Ok(())
+	}
+
+	/// branches come sorted by name
+	fn sort_list(&mut self) {
+		match self.sort {
+			BranchSort::Name => (),
+			BranchSort::Date => {
+				self.branches.sort_by_key(|branch| {
+					Reverse(branch.top_commit_time)
+				});
+			}
+			BranchSort::AheadBehind => {
+				let compare = &self.upstream_compare;
+				self.branches.sort_by_key(|branch| {
+					Reverse(
+						compare
+							.get(&branch.name)
+							.map_or((0, 0), |c| (c.behind, c.ahead)),
+					)
+				});
+			}
+		}
+	}
+
+	fn toggle_mark(&mut self) {
+		let name = &self.branches[usize::from(self.selection)].name;
+		if let Some(idx) = self.marked.iter().position(|m| m == name)
+		{
+			self.marked.remove(idx);
+		} else {
+			self.marked.push(name.clone());
+		}
+	}
+
+	fn title(&self) -> String {
+		if let Some(branch) = &self.upstream_for {
+			return strings::title_pick_upstream(branch);
+		}
+
+		let mut title = format!(
+			"{} (sort: {}",
+			strings::title_branches(),
+			self.sort.name()
+		);
+		if self.local && self.filter != BranchFilter::All {
+			title.push_str(", filter: ");
+			title.push_str(self.filter.name());
+		}
+		title.push(')');
+		title
+	}
+
+	/// refreshes the branches, their authors and ages once remote
+	/// branches might have moved
 	pub fn update_git(
 		&mut self,
 		ev: AsyncGitNotification,
 	) -> Result<()> {
-		if self.is_visible() && ev == AsyncGitNotification::Push {
+		if self.is_visible()
+			&& matches!(
+				ev,
+				AsyncGitNotification::Push
+					| AsyncGitNotification::Fetch
+					| AsyncGitNotification::AutoFetch
+					| AsyncGitNotification::Pull
+			) {
 			self.update_branches()?;
+		} else if ev == AsyncGitNotification::BranchesCompare {
+			if let Some(job) = self.async_compare.take_last() {
+				if let Some(Ok(compare)) = job.result() {
+					self.upstream_compare = compare;
+					if self.is_visible() && self.local {
+						self.refresh_list()?;
+					}
+				}
+			}
 		}
 
 		Ok(())
 	}
 
+	///
+	pub fn any_work_pending(&self) -> bool {
+		self.async_compare.is_pending()
+	}
+
 	fn valid_selection(&self) -> bool {
 		!self.branches.is_empty()
 	}
 
-	fn merge_branch(&mut self) -> Result<()> {
+	fn merge_branch(&mut self) {
 		if let Some(branch) =
 			self.branches.get(usize::from(self.selection))
 		{
-			sync::merge_branch(
-				&self.repo.borrow(),
-				&branch.name,
+			self.queue.push(InternalEvent::OpenMergeOptions(
+				branch.name.clone(),
 				self.get_branch_type(),
-			)?;
-
-			self.hide_and_switch_tab()?;
+			));
+			self.hide();
 		}
-
-		Ok(())
 	}
 
 	fn rebase_branch(&mut self) -> Result<()> {
 		if let Some(branch) =
 			self.branches.get(usize::from(self.selection))
 		{
-			sync::rebase_branch(
-				&self.repo.borrow(),
-				&branch.name,
+			let name = branch.name.clone();
+			let autostash = self.options.borrow().autostash;
+
+			self.rebase_onto(
+				&name,
 				self.get_branch_type(),
+				autostash,
 			)?;
+		}
+
+		Ok(())
+	}
+
+	fn rebase_onto(
+		&mut self,
+		branch: &str,
+		branch_type: BranchType,
+		autostash: bool,
+	) -> Result<()> {
+		let done = run_with_autostash(
+			&self.repo.borrow(),
+			&self.queue,
+			autostash,
+			AutostashOp::Rebase(branch.to_string(), branch_type),
+			|| {
+				sync::rebase_branch(
+					&self.repo.borrow(),
+					branch,
+					branch_type,
+					self.options.borrow().rebase_update_refs,
+				)
+			},
+		)?;
 
+		if done.is_some() {
 			self.hide_and_switch_tab()?;
 		}
 
 		Ok(())
 	}
 
+	/// retry of an operation that failed on local changes, stashing them
+	pub fn autostash_confirmed(
+		&mut self,
+		op: &AutostashOp,
+	) -> Result<()> {
+		match op {
+			AutostashOp::Checkout(reference) => {
+				self.checkout_local(reference, true)
+			}
+			AutostashOp::CheckoutRemote(name) => {
+				self.checkout_remote(name, true)
+			}
+			AutostashOp::Rebase(branch, branch_type) => {
+				self.rebase_onto(branch, *branch_type, true)
+			}
+			AutostashOp::PullMerge(_)
+			| AutostashOp::CheckoutTag(_) => Ok(()),
+		}
+	}
+
+	fn compare_marked(&mut self) {
+		if let [left, right] = self.marked.as_slice() {
+			let open = CompareBranchesOpen {
+				left: left.clone(),
+				right: right.clone(),
+			};
+			self.hide();
+			self.queue.push(InternalEvent::OpenPopup(
+				StackablePopupOpen::CompareBranches(open),
+			));
+		}
+	}
+
 	fn inspect_head_of_branch(&mut self) {
 		if let Some(commit_id) = self.get_selected() {
 			self.hide();
@@ -526,10 +1046,39 @@ impl BranchListComponent {
 
 		self.selection = selection;
 
+		self.fetch_authors()
+	}
+
+	/// loads the missing authors of all rows that could be visible
+	/// with the current selection
+	fn fetch_authors(&mut self) -> Result<()> {
+		let rows = match usize::from(self.current_height.get()) {
+			0 => AUTHOR_ROWS_DEFAULT,
+			height => height,
+		};
+
+		let ids = self
+			.branches
+			.iter()
+			.skip(usize::from(self.selection).saturating_sub(rows))
+			.take(rows * 2)
+			.map(|branch| branch.top_commit)
+			.filter(|id| !self.top_commit_authors.contains_key(id))
+			.collect::<Vec<_>>();
+
+		if !ids.is_empty() {
+			let commits =
+				sync::get_commits_info(&self.repo.borrow(), &ids, 0)?;
+			self.top_commit_authors.extend(
+				commits.into_iter().map(|c| (c.id, c.author)),
+			);
+		}
+
 		Ok(())
 	}
 
 	/// Get branches to display
+	#[allow(clippy::too_many_lines)]
 	fn get_text(
 		&self,
 		theme: &SharedTheme,
@@ -544,14 +1093,43 @@ impl BranchListComponent {
 		const THREE_DOTS_LENGTH: usize = THREE_DOTS.len(); // "..."
 		const COMMIT_HASH_LENGTH: usize = 8;
 		const IS_HEAD_STAR_LENGTH: usize = 3; // "*  "
+		const AHEAD_BEHIND_LENGTH: usize = 12; // "↑999 ↓999   "
+		const MARKER_LENGTH: usize = 2; // "✓ "
+		const AUTHOR_LENGTH: usize = 12;
+		const AGE_LENGTH: usize = 9; // "11mo ago "
+
+		let now = Utc::now().timestamp();
 
-		let branch_name_length: usize =
-			width_available as usize * 40 / 100;
+		let icon_length = if theme.icons() { 2 } else { 0 };
+		let marker_length = if self.marked.is_empty() {
+			0
+		} else {
+			MARKER_LENGTH
+		};
+
+		let (
+			branch_name_length,
+			upstream_length,
+			ahead_behind_length,
+		) = if self.local {
+			(
+				width_available as usize * 30 / 100,
+				width_available as usize * 20 / 100,
+				AHEAD_BEHIND_LENGTH,
+			)
+		} else {
+			(width_available as usize * 40 / 100, 0, 0)
+		};
 		// commit message takes up the remaining width
 		let commit_message_length: usize = (width_available as usize)
 			.saturating_sub(COMMIT_HASH_LENGTH)
 			.saturating_sub(branch_name_length)
+			.saturating_sub(upstream_length)
+			.saturating_sub(ahead_behind_length)
+			.saturating_sub(AUTHOR_LENGTH)
+			.saturating_sub(AGE_LENGTH)
 			.saturating_sub(IS_HEAD_STAR_LENGTH)
+			.saturating_sub(marker_length)
 			.saturating_sub(THREE_DOTS_LENGTH);
 		let mut txt = Vec::new();
 
@@ -572,19 +1150,10 @@ impl BranchListComponent {
 				commit_message += THREE_DOTS;
 			}
 
-			let mut branch_name = displaybranch.name.clone();
-			if branch_name.len()
-				> branch_name_length.saturating_sub(THREE_DOTS_LENGTH)
-			{
-				branch_name = branch_name
-					.unicode_truncate(
-						branch_name_length
-							.saturating_sub(THREE_DOTS_LENGTH),
-					)
-					.0
-					.to_string();
-				branch_name += THREE_DOTS;
-			}
+			let branch_name = truncate_with_dots(
+				&displaybranch.name,
+				branch_name_length.saturating_sub(icon_length),
+			);
 
 			let selected = (self.selection as usize
 				- self.scroll.get_top())
@@ -623,21 +1192,107 @@ impl BranchListComponent {
 				commit_message.to_string(),
 				theme.text(true, selected),
 			);
+			let icon = match (theme.icons(), self.local) {
+				(false, _) => String::new(),
+				(true, true) => format!("{} ", icons::BRANCH),
+				(true, false) => format!("{} ", icons::REMOTE_BRANCH),
+			};
 			let span_name = Span::styled(
 				format!(
-					"{:w$} ",
+					"{}{:w$} ",
+					icon,
 					branch_name,
 					w = branch_name_length
+						.saturating_sub(icon_length)
 				),
 				theme.branch(selected, is_head),
 			);
 
-			txt.push(Spans::from(vec![
-				span_prefix,
-				span_name,
-				span_hash,
-				span_msg,
-			]));
+			let mut spans = Vec::new();
+			if marker_length > 0 {
+				let marked =
+					self.marked.contains(&displaybranch.name);
+				spans.push(Span::styled(
+					format!(
+						"{:w$}",
+						if marked {
+							symbol::CHECKMARK
+						} else {
+							symbol::EMPTY_SPACE
+						},
+						w = MARKER_LENGTH
+					),
+					theme.log_marker(selected),
+				));
+			}
+			spans.push(span_prefix);
+			spans.push(span_name);
+
+			if self.local {
+				let upstream = displaybranch
+					.local_details()
+					.and_then(|details| details.upstream.as_deref())
+					.unwrap_or_default();
+				spans.push(Span::styled(
+					format!(
+						"{:w$} ",
+						truncate_with_dots(upstream, upstream_length),
+						w = upstream_length
+					),
+					theme.text(false, selected),
+				));
+
+				let ahead_behind = self
+					.upstream_compare
+					.get(&displaybranch.name)
+					.map(|compare| {
+						format!(
+							"{}{} {}{}",
+							UPSTREAM_SYMBOL,
+							compare.ahead,
+							TRACKING_SYMBOL,
+							compare.behind
+						)
+					})
+					.unwrap_or_default();
+				spans.push(Span::styled(
+					format!(
+						"{:w$}",
+						ahead_behind,
+						w = ahead_behind_length
+					),
+					theme.commit_author(selected),
+				));
+			}
+
+			let author = self
+				.top_commit_authors
+				.get(&displaybranch.top_commit)
+				.map_or("", String::as_str);
+			spans.push(Span::styled(
+				format!(
+					"{:w$} ",
+					truncate_with_dots(
+						author,
+						AUTHOR_LENGTH.saturating_sub(1)
+					),
+					w = AUTHOR_LENGTH.saturating_sub(1)
+				),
+				theme.commit_author(selected),
+			));
+			spans.push(Span::styled(
+				format!(
+					"{:w$}",
+					time_to_age(displaybranch.top_commit_time, now),
+					w = AGE_LENGTH
+				),
+				theme.commit_time(selected),
+			));
+
+			spans.push(span_hash);
+			spans.push(span_msg);
+
+			txt.push(Spans::from(spans));
 		}
 
 		Text::from(txt)
@@ -649,23 +1304,66 @@ impl BranchListComponent {
 			anyhow::bail!("no valid branch selected");
 		}
 
+		let autostash = self.options.borrow().autostash;
+		let branch = &self.branches[self.selection as usize];
+
 		if self.local {
-			checkout_branch(
-				&self.repo.borrow(),
-				&self.branches[self.selection as usize].reference,
-			)?;
-			self.hide();
+			let reference = branch.reference.clone();
+			self.checkout_local(&reference, autostash)
 		} else {
-			checkout_remote_branch(
-				&self.repo.borrow(),
-				&self.branches[self.selection as usize],
-			)?;
+			let name = branch.name.clone();
+			self.checkout_remote(&name, autostash)
+		}
+	}
+
+	fn checkout_local(
+		&mut self,
+		reference: &str,
+		autostash: bool,
+	) -> Result<()> {
+		let done = run_with_autostash(
+			&self.repo.borrow(),
+			&self.queue,
+			autostash,
+			AutostashOp::Checkout(reference.to_string()),
+			|| checkout_branch(&self.repo.borrow(), reference),
+		)?;
+
+		if done.is_some() {
+			self.hide();
+			self.queue.push(InternalEvent::Update(NeedsUpdate::ALL));
+		}
+
+		Ok(())
+	}
+
+	fn checkout_remote(
+		&mut self,
+		name: &str,
+		autostash: bool,
+	) -> Result<()> {
+		let branch = self
+			.branches
+			.iter()
+			.find(|branch| branch.name == name)
+			.ok_or_else(|| {
+				anyhow::anyhow!("remote branch '{}' not found", name)
+			})?;
+
+		let done = run_with_autostash(
+			&self.repo.borrow(),
+			&self.queue,
+			autostash,
+			AutostashOp::CheckoutRemote(name.to_string()),
+			|| checkout_remote_branch(&self.repo.borrow(), branch),
+		)?;
+
+		if done.is_some() {
 			self.local = true;
 			self.update_branches()?;
+			self.queue.push(InternalEvent::Update(NeedsUpdate::ALL));
 		}
 
-		self.queue.push(InternalEvent::Update(NeedsUpdate::ALL));
-
 		Ok(())
 	}
 
@@ -733,6 +1431,47 @@ impl BranchListComponent {
 		));
 	}
 
+	fn edit_description(&self) {
+		if let Some(branch) =
+			self.branches.get(usize::from(self.selection))
+		{
+			self.queue.push(InternalEvent::EditBranchDescription(
+				branch.name.clone(),
+				self.selected_description().unwrap_or_default(),
+			));
+		}
+	}
+
+	fn selected_description(&self) -> Option<String> {
+		self.branches
+			.get(usize::from(self.selection))
+			.and_then(BranchInfo::local_details)
+			.and_then(|details| details.description.clone())
+	}
+
+	fn draw_description<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		r: Rect,
+		description: &str,
+	) {
+		f.render_widget(
+			Paragraph::new(description.to_string())
+				.block(
+					Block::default()
+						.borders(Borders::TOP)
+						.border_style(self.theme.block(false))
+						.title(Span::styled(
+							strings::branch_description_panel_title(),
+							self.theme.title(false),
+						)),
+				)
+				.style(self.theme.text(true, false))
+				.wrap(Wrap { trim: false }),
+			r,
+		);
+	}
+
 	fn delete_branch(&mut self) {
 		let reference =
 			self.branches[self.selection as usize].reference.clone();
@@ -746,3 +1485,19 @@ impl BranchListComponent {
 		));
 	}
 }
+
+/// cut `text` so that it fits into `width`, marking the cut with "..."
+fn truncate_with_dots(text: &str, width: usize) -> String {
+	const THREE_DOTS: &str = "...";
+
+	if text.len() > width.saturating_sub(THREE_DOTS.len()) {
+		let mut truncated = text
+			.unicode_truncate(width.saturating_sub(THREE_DOTS.len()))
+			.0
+			.to_string();
+		truncated += THREE_DOTS;
+		truncated
+	} else {
+		text.to_string()
+	}
+}
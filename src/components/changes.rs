@@ -1,7 +1,7 @@
 use super::{
 	status_tree::StatusTreeComponent,
 	utils::filetree::{FileTreeItem, FileTreeItemKind},
-	CommandBlocking, DrawableComponent, SharedOptions,
+	CommandBlocking, DrawableComponent, SharedOptions, StatusSort,
 };
 use crate::{
 	components::{CommandInfo, Component, EventState},
@@ -16,7 +16,7 @@ use asyncgit::{
 	StatusItem, StatusItemType,
 };
 use crossterm::event::Event;
-use std::path::Path;
+use std::{cmp::Reverse, collections::BTreeMap, path::Path};
 use tui::{backend::Backend, layout::Rect, Frame};
 
 ///
@@ -59,10 +59,23 @@ impl ChangesComponent {
 		}
 	}
 
-	///
-	pub fn set_items(&mut self, list: &[StatusItem]) -> Result<()> {
+	/// `sort_keys` are gathered by the status job for the sort
+	/// orders that need more than the items
+	pub fn set_items(
+		&mut self,
+		list: &[StatusItem],
+		sort_keys: &BTreeMap<String, u64>,
+	) -> Result<()> {
 		self.files.show()?;
-		self.files.update(list)?;
+
+		let sort = self.options.borrow().status_sort;
+		if sort == StatusSort::Path {
+			self.files.update(list)?;
+		} else {
+			let list = sorted(list, sort, sort_keys);
+			self.files.update_list(&list, true)?;
+		}
+
 		Ok(())
 	}
 
@@ -219,6 +232,12 @@ impl Component for ChangesComponent {
 
 		let some_selection = self.selection().is_some();
 
+		out.push(CommandInfo::new(
+			strings::commands::open_externally(&self.key_config),
+			self.files.selection_file().is_some(),
+			self.focused() || force_all,
+		));
+
 		if self.is_working_dir {
 			out.push(CommandInfo::new(
 				strings::commands::stage_all(&self.key_config),
@@ -265,7 +284,7 @@ impl Component for ChangesComponent {
 			if let Event::Key(e) = ev {
 				return if key_match(
 					e,
-					self.key_config.keys.stage_unstage_item,
+					self.key_config.keys().stage_unstage_item,
 				) {
 					try_or_popup!(
 						self,
@@ -279,7 +298,7 @@ impl Component for ChangesComponent {
 					Ok(EventState::Consumed)
 				} else if key_match(
 					e,
-					self.key_config.keys.status_stage_all,
+					self.key_config.keys().status_stage_all,
 				) && !self.is_empty()
 				{
 					if self.is_working_dir {
@@ -296,17 +315,29 @@ impl Component for ChangesComponent {
 					Ok(EventState::Consumed)
 				} else if key_match(
 					e,
-					self.key_config.keys.status_reset_item,
+					self.key_config.keys().status_reset_item,
 				) && self.is_working_dir
 				{
 					Ok(self.dispatch_reset_workdir().into())
 				} else if key_match(
 					e,
-					self.key_config.keys.status_ignore_file,
+					self.key_config.keys().status_ignore_file,
 				) && self.is_working_dir
 					&& !self.is_empty()
 				{
 					Ok(self.add_to_ignore().into())
+				} else if key_match(
+					e,
+					self.key_config.keys().open_externally,
+				) {
+					if let Some(item) = self.files.selection_file() {
+						self.queue.push(
+							InternalEvent::OpenExternally(
+								item.path, None,
+							),
+						);
+					}
+					Ok(EventState::Consumed)
 				} else {
 					Ok(EventState::NotConsumed)
 				};
@@ -323,3 +354,37 @@ impl Component for ChangesComponent {
 		self.files.focus(focus);
 	}
 }
+
+/// `list` comes sorted by path which is kept for equal entries
+fn sorted(
+	list: &[StatusItem],
+	sort: StatusSort,
+	sort_keys: &BTreeMap<String, u64>,
+) -> Vec<StatusItem> {
+	let mut list = list.to_vec();
+
+	match sort {
+		StatusSort::Path => (),
+		StatusSort::Status => {
+			list.sort_by_key(|item| status_rank(item.status));
+		}
+		StatusSort::ModTime | StatusSort::DiffSize => {
+			list.sort_by_key(|item| {
+				Reverse(sort_keys.get(&item.path).copied())
+			});
+		}
+	}
+
+	list
+}
+
+const fn status_rank(status: StatusItemType) -> u8 {
+	match status {
+		StatusItemType::Conflicted => 0,
+		StatusItemType::New => 1,
+		StatusItemType::Modified => 2,
+		StatusItemType::Renamed => 3,
+		StatusItemType::Typechange => 4,
+		StatusItemType::Deleted => 5,
+	}
+}
@@ -1,7 +1,7 @@
 use super::{
-	textinput::TextInputComponent, visibility_blocking,
-	CommandBlocking, CommandInfo, Component, DrawableComponent,
-	EventState, ExternalEditorComponent,
+	textinput::{InputType, TextInputComponent},
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState, ExternalEditorComponent,
 };
 use crate::{
 	keys::{key_match, SharedKeyConfig},
@@ -13,26 +13,42 @@ use anyhow::Result;
 use asyncgit::{
 	cached, message_prettify,
 	sync::{
-		self, get_config_string, CommitId, HookResult, RepoPathRef,
+		self, get_config_bool, get_config_string, CommitAuthor,
+		CommitId, HookResult, PrepareCommitMsgSource, RepoPathRef,
 		RepoState,
 	},
 };
-use crossterm::event::Event;
+use chrono::{DateTime, FixedOffset, TimeZone};
+use crossterm::{
+	event::Event,
+	terminal::{EnterAlternateScreen, LeaveAlternateScreen},
+	ExecutableCommand,
+};
 use easy_cast::Cast;
+use scopeguard::defer;
 use std::{
 	fs::{read_to_string, File},
-	io::{Read, Write},
+	io::{self, Read, Write},
 };
 use tui::{
 	backend::Backend,
 	layout::{Alignment, Rect},
-	widgets::Paragraph,
+	widgets::{Block, Borders, Clear, Paragraph},
 	Frame,
 };
 
 enum CommitResult {
 	ComitDone,
 	Aborted,
+	/// signing needs the terminal, commit continues once input polling is suspended
+	SigningPending,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Focus {
+	Message,
+	Author,
+	Date,
 }
 
 enum Mode {
@@ -40,6 +56,7 @@ enum Mode {
 	Amend(CommitId),
 	Merge(Vec<CommitId>),
 	Revert,
+	CherryPick,
 }
 
 pub struct CommitComponent {
@@ -50,11 +67,22 @@ pub struct CommitComponent {
 	key_config: SharedKeyConfig,
 	git_branch_name: cached::BranchName,
 	commit_template: Option<String>,
+	signing_msg: Option<String>,
+	signoff: bool,
+	/// original author of the commit being amended
+	amend_author: Option<CommitAuthor>,
+	author_input: TextInputComponent,
+	date_input: TextInputComponent,
+	focus: Focus,
 	theme: SharedTheme,
 }
 
+const CONFIG_SIGNOFF: &str = "gitui.signOff";
+
 const FIRST_LINE_LIMIT: usize = 50;
 
+const AUTHOR_DATE_FORMAT: &str = "%Y-%m-%d %H:%M:%S %z";
+
 impl CommitComponent {
 	///
 	pub fn new(
@@ -73,14 +101,36 @@ impl CommitComponent {
 				&strings::commit_msg(&key_config),
 				true,
 			),
+			author_input: Self::new_field(&theme, &key_config),
+			date_input: Self::new_field(&theme, &key_config),
 			key_config,
 			git_branch_name: cached::BranchName::new(repo.clone()),
 			commit_template: None,
+			signing_msg: None,
+			signoff: false,
+			amend_author: None,
+			focus: Focus::Message,
 			theme,
 			repo,
 		}
 	}
 
+	fn new_field(
+		theme: &SharedTheme,
+		key_config: &SharedKeyConfig,
+	) -> TextInputComponent {
+		let mut input = TextInputComponent::new(
+			theme.clone(),
+			key_config.clone(),
+			"",
+			"",
+			false,
+		)
+		.with_input_type(InputType::Singleline);
+		input.embed();
+		input
+	}
+
 	///
 	pub fn update(&mut self) {
 		self.git_branch_name.lookup().ok();
@@ -133,6 +183,170 @@ impl CommitComponent {
 		}
 	}
 
+	fn draw_signoff<B: Backend>(&self, f: &mut Frame<B>) {
+		if self.signoff {
+			let msg = strings::commit_signoff_indicator();
+			let msg_length: u16 = msg.len().cast();
+			let w = Paragraph::new(msg)
+				.style(self.theme.text(true, false));
+
+			let rect = {
+				let mut rect = self.input.get_area();
+				rect.y += rect.height.saturating_sub(1);
+				rect.height = 1;
+				rect.x += 1;
+				rect.width =
+					msg_length.min(rect.width.saturating_sub(2));
+
+				rect
+			};
+
+			f.render_widget(w, rect);
+		}
+	}
+
+	fn draw_author_fields<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+	) -> Result<()> {
+		if self.amend_author.is_none() {
+			return Ok(());
+		}
+
+		let input_area = self.input.get_area();
+		let area = Rect {
+			y: input_area.y + input_area.height,
+			height: 4,
+			..input_area
+		}
+		.intersection(f.size());
+
+		f.render_widget(Clear, area);
+		f.render_widget(
+			Block::default()
+				.borders(Borders::ALL)
+				.border_style(self.theme.block(false)),
+			area,
+		);
+
+		let fields = [
+			(
+				strings::commit_author_label(),
+				&self.author_input,
+				Focus::Author,
+			),
+			(
+				strings::commit_date_label(),
+				&self.date_input,
+				Focus::Date,
+			),
+		];
+
+		for (idx, (label, input, focus)) in fields.iter().enumerate()
+		{
+			let row = Rect {
+				x: area.x + 1,
+				y: area.y + 1 + u16::try_from(idx)?,
+				width: area.width.saturating_sub(2),
+				height: 1,
+			}
+			.intersection(area);
+
+			let label_width: u16 = label.len().cast();
+			f.render_widget(
+				Paragraph::new(label.as_str())
+					.style(self.theme.title(self.focus == *focus)),
+				row,
+			);
+
+			input.draw(
+				f,
+				Rect {
+					x: row.x + label_width,
+					width: row.width.saturating_sub(label_width),
+					..row
+				},
+			)?;
+		}
+
+		Ok(())
+	}
+
+	fn focused_input(&mut self) -> &mut TextInputComponent {
+		match self.focus {
+			Focus::Message => &mut self.input,
+			Focus::Author => &mut self.author_input,
+			Focus::Date => &mut self.date_input,
+		}
+	}
+
+	fn switch_focus(&mut self, forward: bool) {
+		self.focus = match (self.focus, forward) {
+			(Focus::Message, true) | (Focus::Date, false) => {
+				Focus::Author
+			}
+			(Focus::Author, true) | (Focus::Message, false) => {
+				Focus::Date
+			}
+			(Focus::Date, true) | (Focus::Author, false) => {
+				Focus::Message
+			}
+		};
+	}
+
+	/// author to amend with if it was edited, `None` if unchanged
+	fn edited_author(&self) -> Result<Option<CommitAuthor>> {
+		if let Some(original) = &self.amend_author {
+			let (name, email) =
+				parse_author(self.author_input.get_text())
+					.ok_or_else(|| {
+						anyhow::anyhow!(
+							"invalid author, expected 'name <email>'"
+						)
+					})?;
+			let date = DateTime::parse_from_str(
+				self.date_input.get_text().trim(),
+				AUTHOR_DATE_FORMAT,
+			)
+			.map_err(|e| {
+				anyhow::anyhow!("invalid author date: {}", e)
+			})?;
+
+			let author = CommitAuthor {
+				name,
+				email,
+				time: date.timestamp(),
+				offset: date.offset().local_minus_utc() / 60,
+			};
+
+			if &author != original {
+				return Ok(Some(author));
+			}
+		}
+
+		Ok(None)
+	}
+
+	/// flips adding a `Signed-off-by` trailer and remembers it for this repo
+	fn toggle_signoff(&mut self) -> Result<()> {
+		self.signoff = !self.signoff;
+
+		sync::set_config_bool(
+			&self.repo.borrow(),
+			CONFIG_SIGNOFF,
+			self.signoff,
+		)?;
+
+		Ok(())
+	}
+
+	/// appends `trailer` to the message currently being edited
+	pub fn add_trailer(&mut self, trailer: &str) {
+		let msg =
+			sync::append_trailer(self.input.get_text(), trailer);
+		self.input.set_text(msg);
+	}
+
 	pub fn show_editor(&mut self) -> Result<()> {
 		let file_path = sync::repo_dir(&self.repo.borrow())?
 			.join("COMMIT_EDITMSG");
@@ -168,27 +382,92 @@ impl CommitComponent {
 		Ok(())
 	}
 
+	/// opens the popup to commit even though nothing is staged
+	pub fn show_allow_empty(&mut self) -> Result<()> {
+		self.show()?;
+
+		if matches!(self.mode, Mode::Normal) {
+			self.input.set_title(strings::commit_title_empty());
+		}
+
+		Ok(())
+	}
+
+	/// loads the full message of commit `id`, like `git commit -c`
+	pub fn reuse_message(&mut self, id: CommitId) -> Result<()> {
+		if !self.is_visible() {
+			self.show()?;
+		}
+
+		let details =
+			sync::get_commit_details(&self.repo.borrow(), id)?;
+
+		if let Some(msg) = details.message {
+			self.input.set_text(msg.combine());
+		}
+
+		Ok(())
+	}
+
 	fn commit(&mut self) -> Result<()> {
-		let gpgsign =
-			get_config_string(&self.repo.borrow(), "commit.gpgsign")
-				.ok()
-				.flatten()
-				.and_then(|path| path.parse::<bool>().ok())
-				.unwrap_or_default();
+		self.commit_verify(true)
+	}
 
-		if gpgsign {
-			anyhow::bail!("config commit.gpgsign=true detected.\ngpg signing not supported.\ndeactivate in your repo/gitconfig to be able to commit without signing.");
+	/// commits skipping `pre-commit` and `commit-msg` hooks,
+	/// like `git commit --no-verify`
+	pub fn commit_no_verify(&mut self) -> Result<()> {
+		if self.can_commit() {
+			self.commit_verify(false)?;
 		}
 
+		Ok(())
+	}
+
+	fn commit_verify(&mut self, verify: bool) -> Result<()> {
+		// fail early on malformed author fields, before running hooks
+		self.edited_author()?;
+
 		let msg = self.input.get_text().to_string();
 
-		if matches!(
-			self.commit_with_msg(msg)?,
-			CommitResult::ComitDone
-		) {
-			self.hide();
-			self.queue.push(InternalEvent::Update(NeedsUpdate::ALL));
-			self.input.clear();
+		match self.commit_with_msg(msg, verify)? {
+			CommitResult::ComitDone => self.commit_done(),
+			CommitResult::SigningPending => {
+				self.queue.push(InternalEvent::SuspendForSigning);
+			}
+			CommitResult::Aborted => (),
+		}
+
+		Ok(())
+	}
+
+	fn commit_done(&mut self) {
+		self.hide();
+		self.queue.push(InternalEvent::Update(NeedsUpdate::ALL));
+		self.input.clear();
+	}
+
+	fn gpgsign_enabled(&self) -> bool {
+		sync::commit_gpgsign_enabled(&self.repo.borrow())
+			.unwrap_or_default()
+	}
+
+	/// `true` if a commit waits for the terminal to be handed
+	/// over to the signing program (gpg pinentry, ssh passphrase)
+	pub const fn is_signing_pending(&self) -> bool {
+		self.signing_msg.is_some()
+	}
+
+	/// creates the pending signed commit,
+	/// only call this while input polling is suspended
+	pub fn finish_signed_commit(&mut self) -> Result<()> {
+		if let Some(msg) = self.signing_msg.take() {
+			io::stdout().execute(LeaveAlternateScreen)?;
+			defer! {
+				io::stdout().execute(EnterAlternateScreen).expect("reset terminal");
+			}
+
+			self.create_commit(&msg)?;
+			self.commit_done();
 		}
 
 		Ok(())
@@ -197,39 +476,82 @@ impl CommitComponent {
 	fn commit_with_msg(
 		&mut self,
 		msg: String,
+		verify: bool,
 	) -> Result<CommitResult> {
-		if let HookResult::NotOk(e) =
-			sync::hooks_pre_commit(&self.repo.borrow())?
-		{
-			log::error!("pre-commit hook error: {}", e);
-			self.queue.push(InternalEvent::ShowErrorMsg(format!(
-				"pre-commit hook error:\n{}",
-				e
-			)));
-			return Ok(CommitResult::Aborted);
+		if verify {
+			if let HookResult::NotOk(e) =
+				sync::hooks_pre_commit(&self.repo.borrow())?
+			{
+				log::error!("pre-commit hook error: {}", e);
+				self.queue.push(InternalEvent::ShowHookOutput(
+					String::from("pre-commit"),
+					e,
+				));
+				return Ok(CommitResult::Aborted);
+			}
 		}
 		let mut msg = message_prettify(msg, Some(b'#'))?;
-		if let HookResult::NotOk(e) =
-			sync::hooks_commit_msg(&self.repo.borrow(), &mut msg)?
-		{
-			log::error!("commit-msg hook error: {}", e);
-			self.queue.push(InternalEvent::ShowErrorMsg(format!(
-				"commit-msg hook error:\n{}",
-				e
-			)));
-			return Ok(CommitResult::Aborted);
+		if self.signoff {
+			msg = sync::append_trailer(
+				&msg,
+				&sync::signoff_trailer(&self.repo.borrow())?,
+			);
 		}
+		if verify {
+			let original = msg.clone();
+			if let HookResult::NotOk(e) =
+				sync::hooks_commit_msg(&self.repo.borrow(), &mut msg)?
+			{
+				log::error!("commit-msg hook error: {}", e);
+				// let the user see what the hook did to the message
+				if msg != original {
+					self.input.set_text(msg);
+				}
+				self.queue.push(InternalEvent::ShowHookOutput(
+					String::from("commit-msg"),
+					e,
+				));
+				return Ok(CommitResult::Aborted);
+			}
+		}
+
+		if self.gpgsign_enabled() {
+			self.signing_msg = Some(msg);
+			return Ok(CommitResult::SigningPending);
+		}
+
+		self.create_commit(&msg)?;
 
+		Ok(CommitResult::ComitDone)
+	}
+
+	fn create_commit(&self, msg: &str) -> Result<()> {
 		match &self.mode {
-			Mode::Normal => sync::commit(&self.repo.borrow(), &msg)?,
+			Mode::Normal => {
+				let id = sync::commit(&self.repo.borrow(), msg)?;
+				sync::clear_squash_msg(&self.repo.borrow())?;
+				id
+			}
 			Mode::Amend(amend) => {
-				sync::amend(&self.repo.borrow(), *amend, &msg)?
+				if let Some(author) = self.edited_author()? {
+					sync::amend_with_author(
+						&self.repo.borrow(),
+						*amend,
+						msg,
+						&author,
+					)?
+				} else {
+					sync::amend(&self.repo.borrow(), *amend, msg)?
+				}
 			}
 			Mode::Merge(ids) => {
-				sync::merge_commit(&self.repo.borrow(), &msg, ids)?
+				sync::merge_commit(&self.repo.borrow(), msg, ids)?
 			}
 			Mode::Revert => {
-				sync::commit_revert(&self.repo.borrow(), &msg)?
+				sync::commit_revert(&self.repo.borrow(), msg)?
+			}
+			Mode::CherryPick => {
+				sync::commit_cherrypick(&self.repo.borrow(), msg)?
 			}
 		};
 
@@ -243,7 +565,7 @@ impl CommitComponent {
 			)));
 		}
 
-		Ok(CommitResult::ComitDone)
+		Ok(())
 	}
 
 	fn can_commit(&self) -> bool {
@@ -265,6 +587,34 @@ impl CommitComponent {
 			!= self.commit_template.as_ref().map(|s| s.trim())
 	}
 
+	/// runs `prepare-commit-msg` on the message about to be edited,
+	/// a failing hook aborts the commit like in git
+	fn prepare_msg(
+		&mut self,
+		source: Option<PrepareCommitMsgSource>,
+	) -> Result<()> {
+		let mut msg = self.input.get_text().to_string();
+
+		let res = sync::hooks_prepare_commit_msg(
+			&self.repo.borrow(),
+			source,
+			&mut msg,
+		)?;
+
+		if let HookResult::NotOk(e) = res {
+			log::error!("prepare-commit-msg hook error: {}", e);
+			self.queue.push(InternalEvent::ShowHookOutput(
+				String::from("prepare-commit-msg"),
+				e,
+			));
+			self.hide();
+		} else {
+			self.input.set_text(msg);
+		}
+
+		Ok(())
+	}
+
 	fn amend(&mut self) -> Result<()> {
 		if self.can_amend() {
 			let id = sync::get_head(&self.repo.borrow())?;
@@ -278,6 +628,21 @@ impl CommitComponent {
 			if let Some(msg) = details.message {
 				self.input.set_text(msg.combine());
 			}
+
+			self.prepare_msg(Some(PrepareCommitMsgSource::Commit(
+				id,
+			)))?;
+
+			let author =
+				sync::get_commit_author(&self.repo.borrow(), id)?;
+			self.author_input.set_text(format!(
+				"{} <{}>",
+				author.name, author.email
+			));
+			self.date_input.set_text(format_author_date(&author));
+			self.author_input.show()?;
+			self.date_input.show()?;
+			self.amend_author = Some(author);
 		}
 
 		Ok(())
@@ -294,6 +659,8 @@ impl DrawableComponent for CommitComponent {
 			self.input.draw(f, rect)?;
 			self.draw_branch_name(f);
 			self.draw_warnings(f);
+			self.draw_signoff(f);
+			self.draw_author_fields(f)?;
 		}
 
 		Ok(())
@@ -315,12 +682,47 @@ impl Component for CommitComponent {
 				true,
 			));
 
+			out.push(CommandInfo::new(
+				strings::commands::commit_no_verify(&self.key_config),
+				self.can_commit(),
+				true,
+			));
+
 			out.push(CommandInfo::new(
 				strings::commands::commit_amend(&self.key_config),
 				self.can_amend(),
 				true,
 			));
 
+			out.push(CommandInfo::new(
+				strings::commands::commit_next_field(
+					&self.key_config,
+				),
+				true,
+				self.amend_author.is_some(),
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::commit_signoff(
+					&self.key_config,
+					self.signoff,
+				),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::commit_reuse_msg(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::commit_coauthor(&self.key_config),
+				true,
+				true,
+			));
+
 			out.push(CommandInfo::new(
 				strings::commands::commit_open_editor(
 					&self.key_config,
@@ -335,12 +737,34 @@ impl Component for CommitComponent {
 
 	fn event(&mut self, ev: &Event) -> Result<EventState> {
 		if self.is_visible() {
-			if self.input.event(ev)?.is_consumed() {
+			if let Event::Key(e) = ev {
+				if self.amend_author.is_some() {
+					if key_match(e, self.key_config.keys().tab_toggle)
+					{
+						self.switch_focus(true);
+						return Ok(EventState::Consumed);
+					} else if key_match(
+						e,
+						self.key_config.keys().tab_toggle_reverse,
+					) {
+						self.switch_focus(false);
+						return Ok(EventState::Consumed);
+					}
+				}
+
+				// closing always goes through the message input
+				if key_match(e, self.key_config.keys().exit_popup) {
+					self.input.event(ev)?;
+					return Ok(EventState::Consumed);
+				}
+			}
+
+			if self.focused_input().event(ev)?.is_consumed() {
 				return Ok(EventState::Consumed);
 			}
 
 			if let Event::Key(e) = ev {
-				if key_match(e, self.key_config.keys.enter)
+				if key_match(e, self.key_config.keys().enter)
 					&& self.can_commit()
 				{
 					try_or_popup!(
@@ -350,13 +774,43 @@ impl Component for CommitComponent {
 					);
 				} else if key_match(
 					e,
-					self.key_config.keys.commit_amend,
+					self.key_config.keys().commit_no_verify,
+				) && self.can_commit()
+				{
+					try_or_popup!(
+						self,
+						"commit error:",
+						self.commit_no_verify()
+					);
+				} else if key_match(
+					e,
+					self.key_config.keys().commit_amend,
 				) && self.can_amend()
 				{
 					self.amend()?;
 				} else if key_match(
 					e,
-					self.key_config.keys.open_commit_editor,
+					self.key_config.keys().commit_signoff,
+				) {
+					try_or_popup!(
+						self,
+						"sign-off error:",
+						self.toggle_signoff()
+					);
+				} else if key_match(
+					e,
+					self.key_config.keys().commit_coauthor,
+				) {
+					self.queue.push(InternalEvent::OpenCoAuthors);
+				} else if key_match(
+					e,
+					self.key_config.keys().commit_reuse_msg,
+				) {
+					self.queue
+						.push(InternalEvent::OpenCommitMsgPicker);
+				} else if key_match(
+					e,
+					self.key_config.keys().open_commit_editor,
 				) {
 					self.queue.push(
 						InternalEvent::OpenExternalEditor(None),
@@ -387,11 +841,26 @@ impl Component for CommitComponent {
 		}
 
 		self.mode = Mode::Normal;
+		self.amend_author = None;
+		self.focus = Focus::Message;
+		self.author_input.hide();
+		self.date_input.hide();
+
+		self.signoff =
+			get_config_bool(&self.repo.borrow(), CONFIG_SIGNOFF)
+				.ok()
+				.flatten()
+				.unwrap_or_default();
 
 		let repo_state = sync::repo_state(&self.repo.borrow())?;
 
+		// a message kept from before already went through the hook
+		let mut prepare = true;
+		let mut source = Some(PrepareCommitMsgSource::Message);
+
 		self.mode = match repo_state {
 			RepoState::Merge => {
+				source = Some(PrepareCommitMsgSource::Merge);
 				let ids = sync::mergehead_ids(&self.repo.borrow())?;
 				self.input.set_title(strings::commit_title_merge());
 				self.input
@@ -404,6 +873,13 @@ impl Component for CommitComponent {
 					.set_text(sync::merge_msg(&self.repo.borrow())?);
 				Mode::Revert
 			}
+			RepoState::CherryPick => {
+				self.input
+					.set_title(strings::commit_title_cherrypick());
+				self.input
+					.set_text(sync::merge_msg(&self.repo.borrow())?);
+				Mode::CherryPick
+			}
 			_ => {
 				self.commit_template = get_config_string(
 					&self.repo.borrow(),
@@ -414,9 +890,19 @@ impl Component for CommitComponent {
 				.and_then(|path| read_to_string(path).ok());
 
 				if self.is_empty() {
-					if let Some(s) = &self.commit_template {
+					source = None;
+					if let Some(msg) =
+						sync::squash_msg(&self.repo.borrow())?
+					{
+						self.input.set_text(msg);
+						source = Some(PrepareCommitMsgSource::Squash);
+					} else if let Some(s) = &self.commit_template {
 						self.input.set_text(s.clone());
+						source =
+							Some(PrepareCommitMsgSource::Template);
 					}
+				} else {
+					prepare = false;
 				}
 
 				self.input.set_title(strings::commit_title());
@@ -426,6 +912,72 @@ impl Component for CommitComponent {
 
 		self.input.show()?;
 
+		if prepare {
+			self.prepare_msg(source)?;
+		}
+
 		Ok(())
 	}
 }
+
+/// splits `name <email>`
+fn parse_author(text: &str) -> Option<(String, String)> {
+	let text = text.trim();
+	let start = text.rfind('<')?;
+	let name = text[..start].trim();
+	let email = text[start + 1..].strip_suffix('>')?.trim();
+
+	if name.is_empty() || email.is_empty() {
+		return None;
+	}
+
+	Some((name.to_string(), email.to_string()))
+}
+
+fn format_author_date(author: &CommitAuthor) -> String {
+	FixedOffset::east_opt(author.offset * 60)
+		.and_then(|tz| tz.timestamp_opt(author.time, 0).single())
+		.map(|date| date.format(AUTHOR_DATE_FORMAT).to_string())
+		.unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_author() {
+		assert_eq!(
+			parse_author(" foo bar <foo@bar.com> "),
+			Some((
+				String::from("foo bar"),
+				String::from("foo@bar.com")
+			))
+		);
+		assert_eq!(parse_author("foo bar"), None);
+		assert_eq!(parse_author("<foo@bar.com>"), None);
+		assert_eq!(parse_author("foo <foo@bar.com"), None);
+	}
+
+	#[test]
+	fn test_author_date_roundtrip() {
+		let author = CommitAuthor {
+			name: String::from("foo"),
+			email: String::from("foo@bar.com"),
+			time: 1_600_000_000,
+			offset: -330,
+		};
+
+		let formatted = format_author_date(&author);
+		assert_eq!(formatted, "2020-09-13 06:56:40 -0530");
+
+		let date =
+			DateTime::parse_from_str(&formatted, AUTHOR_DATE_FORMAT)
+				.unwrap();
+		assert_eq!(date.timestamp(), author.time);
+		assert_eq!(
+			date.offset().local_minus_utc() / 60,
+			author.offset
+		);
+	}
+}
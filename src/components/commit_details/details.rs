@@ -38,6 +38,9 @@ pub struct DetailsComponent {
 	scroll: VerticalScroll,
 	scroll_to_bottom_next_draw: Cell<bool>,
 	key_config: SharedKeyConfig,
+	/// wrap the message at the width of the view, one row per line
+	/// of the message otherwise
+	wrap: bool,
 }
 
 type WrappedCommitMessage<'a> =
@@ -61,6 +64,7 @@ impl DetailsComponent {
 			current_width: Cell::new(0),
 			scroll: VerticalScroll::new(),
 			key_config,
+			wrap: true,
 		}
 	}
 
@@ -98,13 +102,30 @@ impl DetailsComponent {
 		}
 	}
 
+	fn split_commit_details(
+		message: &CommitMessage,
+	) -> WrappedCommitMessage<'_> {
+		(
+			vec![Cow::from(message.subject.as_str())],
+			message
+				.body
+				.as_deref()
+				.map(|body| body.lines().map(Cow::from).collect())
+				.unwrap_or_default(),
+		)
+	}
+
+	/// `width` is `None` while wrapping is off
 	fn get_wrapped_lines(
 		data: &Option<CommitDetails>,
-		width: usize,
+		width: Option<usize>,
 	) -> WrappedCommitMessage<'_> {
 		if let Some(ref data) = data {
 			if let Some(ref message) = data.message {
-				return Self::wrap_commit_details(message, width);
+				return width.map_or_else(
+					|| Self::split_commit_details(message),
+					|width| Self::wrap_commit_details(message, width),
+				);
 			}
 		}
 
@@ -113,7 +134,7 @@ impl DetailsComponent {
 
 	fn get_number_of_lines(
 		details: &Option<CommitDetails>,
-		width: usize,
+		width: Option<usize>,
 	) -> usize {
 		let (wrapped_title, wrapped_message) =
 			Self::get_wrapped_lines(details, width);
@@ -135,7 +156,10 @@ impl DetailsComponent {
 		height: usize,
 	) -> Vec<Spans> {
 		let (wrapped_title, wrapped_message) =
-			Self::get_wrapped_lines(&self.data, width);
+			Self::get_wrapped_lines(
+				&self.data,
+				self.wrap.then(|| width),
+			);
 
 		[&wrapped_title[..], &wrapped_message[..]]
 			.concat()
@@ -259,10 +283,17 @@ impl DrawableComponent for DetailsComponent {
 		const CANSCROLL_STRING: &str = "[\u{2026}]";
 		const EMPTY_STRING: &str = "";
 
+		// no bottom border below the info in compact mode
+		let info_height = if self.theme.compact() { 7 } else { 8 };
+
 		let chunks = Layout::default()
 			.direction(Direction::Vertical)
 			.constraints(
-				[Constraint::Length(8), Constraint::Min(10)].as_ref(),
+				[
+					Constraint::Length(info_height),
+					Constraint::Min(10),
+				]
+				.as_ref(),
 			)
 			.split(rect);
 
@@ -278,17 +309,15 @@ impl DrawableComponent for DetailsComponent {
 			chunks[0],
 		);
 
-		// We have to take the border into account which is one character on
-		// each side.
-		let border_width: u16 = 2;
-
-		let width = chunks[1].width.saturating_sub(border_width);
-		let height = chunks[1].height.saturating_sub(border_width);
+		let inner = self.theme.pane_inner(chunks[1]);
+		let (width, height) = (inner.width, inner.height);
 
 		self.current_width.set(width);
 
-		let number_of_lines =
-			Self::get_number_of_lines(&self.data, usize::from(width));
+		let number_of_lines = Self::get_number_of_lines(
+			&self.data,
+			self.wrap.then(|| usize::from(width)),
+		);
 
 		self.scroll.update_no_selection(
 			number_of_lines,
@@ -340,8 +369,10 @@ impl Component for DetailsComponent {
 		force_all: bool,
 	) -> CommandBlocking {
 		let width = usize::from(self.current_width.get());
-		let number_of_lines =
-			Self::get_number_of_lines(&self.data, width);
+		let number_of_lines = Self::get_number_of_lines(
+			&self.data,
+			self.wrap.then(|| width),
+		);
 
 		out.push(
 			CommandInfo::new(
@@ -354,30 +385,58 @@ impl Component for DetailsComponent {
 			.order(order::NAV),
 		);
 
+		out.push(CommandInfo::new(
+			strings::commands::toggle_wrap(
+				&self.key_config,
+				self.wrap,
+			),
+			number_of_lines > 0,
+			self.focused || force_all,
+		));
+
 		CommandBlocking::PassingOn
 	}
 
 	fn event(&mut self, event: &Event) -> Result<EventState> {
 		if self.focused {
+			if let Event::Mouse(mouse) = event {
+				return Ok(self
+					.scroll
+					.click(*mouse)
+					.is_some()
+					.into());
+			}
+
 			if let Event::Key(e) = event {
 				return Ok(
-					if key_match(e, self.key_config.keys.move_up) {
+					if key_match(e, self.key_config.keys().move_up) {
 						self.move_scroll_top(ScrollType::Up).into()
 					} else if key_match(
 						e,
-						self.key_config.keys.move_down,
+						self.key_config.keys().move_down,
 					) {
 						self.move_scroll_top(ScrollType::Down).into()
-					} else if key_match(e, self.key_config.keys.home)
-						|| key_match(e, self.key_config.keys.shift_up)
-					{
+					} else if key_match(
+						e,
+						self.key_config.keys().home,
+					) || key_match(
+						e,
+						self.key_config.keys().shift_up,
+					) {
 						self.move_scroll_top(ScrollType::Home).into()
-					} else if key_match(e, self.key_config.keys.end)
+					} else if key_match(e, self.key_config.keys().end)
 						|| key_match(
 							e,
-							self.key_config.keys.shift_down,
+							self.key_config.keys().shift_down,
 						) {
 						self.move_scroll_top(ScrollType::End).into()
+					} else if key_match(
+						e,
+						self.key_config.keys().toggle_wrap,
+					) {
+						self.wrap = !self.wrap;
+						self.scroll.reset();
+						EventState::Consumed
 					} else {
 						EventState::NotConsumed
 					},
@@ -475,12 +534,20 @@ mod test_line_count {
 		};
 		let lines = DetailsComponent::get_number_of_lines(
 			&Some(commit.clone()),
-			50,
+			Some(50),
 		);
 		assert_eq!(lines, 2);
 
-		let lines =
-			DetailsComponent::get_number_of_lines(&Some(commit), 8);
+		let lines = DetailsComponent::get_number_of_lines(
+			&Some(commit.clone()),
+			Some(8),
+		);
 		assert_eq!(lines, 4);
+
+		let lines = DetailsComponent::get_number_of_lines(
+			&Some(commit),
+			None,
+		);
+		assert_eq!(lines, 2);
 	}
 }
@@ -15,7 +15,7 @@ use crate::{
 };
 use anyhow::Result;
 use asyncgit::{
-	sync::{CommitTags, RepoPathRef},
+	sync::{self, CommitTags, RepoPathRef},
 	AsyncCommitFiles, AsyncGitNotification, CommitFilesParams,
 };
 use compare_details::CompareDetailsComponent;
@@ -29,7 +29,10 @@ use tui::{
 };
 
 pub struct CommitDetailsComponent {
+	repo: RepoPathRef,
 	commit: Option<CommitFilesParams>,
+	/// files stored in the untracked part when showing a stash
+	stash_untracked: Vec<String>,
 	single_details: DetailsComponent,
 	compare_details: CompareDetailsComponent,
 	file_tree: StatusTreeComponent,
@@ -74,6 +77,8 @@ impl CommitDetailsComponent {
 			),
 			visible: false,
 			commit: None,
+			stash_untracked: Vec::new(),
+			repo: repo.clone(),
 			key_config,
 		}
 	}
@@ -81,11 +86,23 @@ impl CommitDetailsComponent {
 	fn get_files_title(&self) -> String {
 		let files_count = self.file_tree.file_count();
 
-		format!(
+		let title = format!(
 			"{} {}",
 			strings::commit::details_files_title(&self.key_config),
 			files_count
-		)
+		);
+
+		if self.stash_untracked.is_empty() {
+			title
+		} else {
+			format!(
+				"{} {}",
+				title,
+				strings::commit::details_files_untracked(
+					self.stash_untracked.len()
+				)
+			)
+		}
 	}
 
 	///
@@ -110,17 +127,21 @@ impl CommitDetailsComponent {
 					.set_commit(Some(id.id), tags.clone());
 			}
 
-			if let Some((fetched_id, res)) =
+			if let Some((fetched_id, (res, stats))) =
 				self.git_commit_files.current()?
 			{
 				if fetched_id == id {
+					self.stash_untracked =
+						self.get_stash_untracked(id)?;
 					self.file_tree.update(res.as_slice())?;
+					self.file_tree.set_diff_stats(stats);
 					self.file_tree.set_title(self.get_files_title());
 
 					return Ok(());
 				}
 			}
 
+			self.stash_untracked.clear();
 			self.file_tree.clear()?;
 			self.git_commit_files.fetch(id)?;
 		}
@@ -131,6 +152,21 @@ impl CommitDetailsComponent {
 	}
 
 	///
+	fn get_stash_untracked(
+		&self,
+		params: CommitFilesParams,
+	) -> Result<Vec<String>> {
+		let repo = self.repo.borrow();
+
+		if params.other.is_none()
+			&& sync::is_stash_commit(&repo, &params.id)?
+		{
+			Ok(sync::get_stash_untracked_files(&repo, params.id)?)
+		} else {
+			Ok(Vec::new())
+		}
+	}
+
 	pub fn any_work_pending(&self) -> bool {
 		self.git_commit_files.is_pending()
 	}
@@ -234,7 +270,7 @@ impl Component for CommitDetailsComponent {
 			if let Event::Key(e) = ev {
 				return if key_match(
 					e,
-					self.key_config.keys.focus_below,
+					self.key_config.keys().focus_below,
 				) && self.details_focused()
 				{
 					self.set_details_focus(false);
@@ -242,7 +278,7 @@ impl Component for CommitDetailsComponent {
 					Ok(EventState::Consumed)
 				} else if key_match(
 					e,
-					self.key_config.keys.focus_above,
+					self.key_config.keys().focus_above,
 				) && self.file_tree.focused()
 					&& !self.is_compare()
 				{
@@ -0,0 +1,331 @@
+use super::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState, ScrollType, TextInputComponent,
+};
+use crate::{
+	keys::{key_match, SharedKeyConfig},
+	queue::{InternalEvent, Queue},
+	string_utils::trim_length_left,
+	strings,
+	ui::{self, style::SharedTheme},
+};
+use anyhow::Result;
+use asyncgit::sync::{self, CommitInfo, RepoPathRef};
+use crossterm::event::Event;
+use fuzzy_matcher::FuzzyMatcher;
+use std::borrow::Cow;
+use tui::{
+	backend::Backend,
+	layout::{Constraint, Direction, Layout, Margin, Rect},
+	text::{Span, Spans},
+	widgets::{Block, Borders, Clear},
+	Frame,
+};
+
+/// number of commits offered to pick from
+const MAX_COMMITS: usize = 1000;
+const MESSAGE_LIMIT: usize = 100;
+
+/// fuzzy picker for a previous commit whose message gets reused
+pub struct CommitMsgPickerPopup {
+	repo: RepoPathRef,
+	queue: Queue,
+	visible: bool,
+	find_text: TextInputComponent,
+	theme: SharedTheme,
+	commits: Vec<CommitInfo>,
+	/// `<short hash> <subject>` per entry in `commits`
+	items: Vec<String>,
+	selection: usize,
+	filtered: Vec<(usize, Vec<usize>)>,
+	key_config: SharedKeyConfig,
+}
+
+impl CommitMsgPickerPopup {
+	///
+	pub fn new(
+		repo: RepoPathRef,
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		let mut find_text = TextInputComponent::new(
+			theme.clone(),
+			key_config.clone(),
+			"",
+			&strings::commit_msg_picker_msg(),
+			false,
+		);
+		find_text.embed();
+
+		Self {
+			repo,
+			queue: queue.clone(),
+			visible: false,
+			find_text,
+			theme,
+			commits: Vec::new(),
+			items: Vec::new(),
+			filtered: Vec::new(),
+			selection: 0,
+			key_config,
+		}
+	}
+
+	///
+	pub fn open(&mut self) -> Result<()> {
+		self.commits = sync::get_recent_commits_info(
+			&self.repo.borrow(),
+			MAX_COMMITS,
+			MESSAGE_LIMIT,
+		)?;
+		self.items = self
+			.commits
+			.iter()
+			.map(|c| {
+				format!("{} {}", c.id.get_short_string(), c.message)
+			})
+			.collect();
+		self.find_text.set_text(String::new());
+		self.find_text.show()?;
+		self.update_query();
+		self.show()?;
+
+		Ok(())
+	}
+
+	fn update_query(&mut self) {
+		let query = self.find_text.get_text();
+
+		self.filtered.clear();
+
+		if query.is_empty() {
+			self.filtered.extend(
+				(0..self.items.len()).map(|idx| (idx, Vec::new())),
+			);
+		} else {
+			let matcher =
+				fuzzy_matcher::skim::SkimMatcherV2::default();
+
+			let mut items = self
+				.items
+				.iter()
+				.enumerate()
+				.filter_map(|(idx, item)| {
+					matcher
+						.fuzzy_indices(item, query)
+						.map(|(score, indices)| (score, idx, indices))
+				})
+				.collect::<Vec<(_, _, _)>>();
+
+			items.sort_by(|(score1, _, _), (score2, _, _)| {
+				score2.cmp(score1)
+			});
+
+			self.filtered.extend(
+				items.into_iter().map(|entry| (entry.1, entry.2)),
+			);
+		}
+
+		self.selection = 0;
+	}
+
+	fn move_selection(&mut self, move_type: ScrollType) {
+		let new_selection = match move_type {
+			ScrollType::Up => self.selection.saturating_sub(1),
+			ScrollType::Down => self.selection.saturating_add(1),
+			_ => self.selection,
+		};
+
+		self.selection = new_selection
+			.clamp(0, self.filtered.len().saturating_sub(1));
+	}
+
+	fn selected_commit(&self) -> Option<&CommitInfo> {
+		self.filtered
+			.get(self.selection)
+			.and_then(|(idx, _)| self.commits.get(*idx))
+	}
+
+	fn confirm(&mut self) {
+		if let Some(commit) = self.selected_commit() {
+			self.queue
+				.push(InternalEvent::ReuseCommitMessage(commit.id));
+			self.hide();
+		}
+	}
+}
+
+impl DrawableComponent for CommitMsgPickerPopup {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		area: Rect,
+	) -> Result<()> {
+		if self.is_visible() {
+			const MAX_SIZE: (u16, u16) = (80, 20);
+
+			let area = ui::centered_rect_absolute(
+				MAX_SIZE.0, MAX_SIZE.1, area,
+			);
+
+			f.render_widget(Clear, area);
+			f.render_widget(
+				Block::default()
+					.borders(Borders::all())
+					.border_style(self.theme.popup_border())
+					.style(self.theme.title(true))
+					.title(Span::styled(
+						strings::POPUP_TITLE_COMMIT_MSG_PICKER,
+						self.theme.title(true),
+					)),
+				area,
+			);
+
+			let chunks = Layout::default()
+				.direction(Direction::Vertical)
+				.constraints(
+					[
+						Constraint::Length(1),
+						Constraint::Percentage(100),
+					]
+					.as_ref(),
+				)
+				.split(area.inner(&Margin {
+					horizontal: 1,
+					vertical: 1,
+				}));
+
+			self.find_text.draw(f, chunks[0])?;
+
+			let title = format!("Commits: {}", self.filtered.len());
+
+			let height = usize::from(chunks[1].height);
+			let width = usize::from(chunks[1].width);
+			let scroll_top = ui::calc_scroll_top(
+				0,
+				height.saturating_sub(1),
+				self.selection,
+			);
+
+			let items = self
+				.filtered
+				.iter()
+				.enumerate()
+				.skip(scroll_top)
+				.take(height)
+				.map(|(pos, (idx, indices))| {
+					let selected = pos == self.selection;
+					let full_text =
+						trim_length_left(&self.items[*idx], width);
+					Spans::from(
+						full_text
+							.char_indices()
+							.map(|(c_idx, c)| {
+								Span::styled(
+									Cow::from(c.to_string()),
+									self.theme.text(
+										selected,
+										indices.contains(&c_idx),
+									),
+								)
+							})
+							.collect::<Vec<_>>(),
+					)
+				});
+
+			ui::draw_list_block(
+				f,
+				chunks[1],
+				Block::default()
+					.title(Span::styled(
+						title,
+						self.theme.title(true),
+					))
+					.borders(Borders::TOP),
+				items,
+			);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for CommitMsgPickerPopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			out.push(
+				CommandInfo::new(
+					strings::commands::close_popup(&self.key_config),
+					true,
+					true,
+				)
+				.order(1),
+			);
+
+			out.push(CommandInfo::new(
+				strings::commands::reuse_msg_confirm(
+					&self.key_config,
+				),
+				self.selected_commit().is_some(),
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: &Event) -> Result<EventState> {
+		if self.is_visible() {
+			if let Event::Key(key) = event {
+				if key_match(key, self.key_config.keys().exit_popup) {
+					self.hide();
+				} else if key_match(key, self.key_config.keys().enter)
+				{
+					self.confirm();
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_down,
+				) {
+					self.move_selection(ScrollType::Down);
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_up,
+				) {
+					self.move_selection(ScrollType::Up);
+				}
+			}
+
+			if self.find_text.event(event)?.is_consumed() {
+				self.update_query();
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+		Ok(())
+	}
+}
@@ -7,21 +7,30 @@ use crate::{
 	keys::{key_match, SharedKeyConfig},
 	strings::{self, symbol},
 	ui::style::{SharedTheme, Theme},
-	ui::{calc_scroll_top, draw_scrollbar},
+	ui::{
+		calc_scroll_top, draw_scrollbar, icons, scrollbar_position,
+	},
 };
 use anyhow::Result;
 use asyncgit::sync::{CommitId, Tags};
 use chrono::{DateTime, Local};
-use crossterm::event::Event;
+use crossterm::event::{
+	Event, MouseButton, MouseEvent, MouseEventKind,
+};
 use itertools::Itertools;
 use std::{
-	borrow::Cow, cell::Cell, cmp, convert::TryFrom, time::Instant,
+	borrow::Cow,
+	cell::Cell,
+	cmp,
+	collections::{HashMap, HashSet},
+	convert::TryFrom,
+	time::Instant,
 };
 use tui::{
 	backend::Backend,
 	layout::{Alignment, Rect},
 	text::{Span, Spans},
-	widgets::{Block, Borders, Paragraph},
+	widgets::{Block, Paragraph},
 	Frame,
 };
 
@@ -37,8 +46,16 @@ pub struct CommitList {
 	marked: Vec<CommitId>,
 	scroll_state: (Instant, f32),
 	tags: Option<Tags>,
+	/// branch column, only shown if set
+	branches: Option<HashMap<CommitId, String>>,
+	/// commits of a shallow clone whose parents were not fetched
+	shallow: HashSet<CommitId>,
+	/// drawn before every entry while icons are on
+	icon: Option<&'static str>,
 	current_size: Cell<(u16, u16)>,
 	scroll_top: Cell<usize>,
+	/// where the list was drawn last
+	area: Cell<Rect>,
 	theme: SharedTheme,
 	key_config: SharedKeyConfig,
 }
@@ -58,8 +75,12 @@ impl CommitList {
 			count_total: 0,
 			scroll_state: (Instant::now(), 0_f32),
 			tags: None,
+			branches: None,
+			shallow: HashSet::new(),
+			icon: None,
 			current_size: Cell::new((0, 0)),
 			scroll_top: Cell::new(0),
+			area: Cell::new(Rect::default()),
 			theme,
 			key_config,
 			title: title.into(),
@@ -71,6 +92,16 @@ impl CommitList {
 		&mut self.items
 	}
 
+	///
+	pub fn set_title(&mut self, title: &str) {
+		self.title = title.into();
+	}
+
+	/// glyph out of `ui::icons` marking the entries
+	pub fn set_icon(&mut self, icon: &'static str) {
+		self.icon = Some(icon);
+	}
+
 	///
 	pub fn set_branch(&mut self, name: Option<String>) {
 		self.branch = name;
@@ -114,6 +145,23 @@ impl CommitList {
 		self.tags = Some(tags);
 	}
 
+	/// shows `branches` as a column, e.g. the branch a stash was
+	/// created on
+	pub fn set_branches(
+		&mut self,
+		branches: HashMap<CommitId, String>,
+	) {
+		self.branches = Some(branches);
+	}
+
+	/// marks `commits` as the end of the fetched history
+	pub fn set_shallow_commits(
+		&mut self,
+		commits: HashSet<CommitId>,
+	) {
+		self.shallow = commits;
+	}
+
 	///
 	pub fn selected_entry(&self) -> Option<&LogEntry> {
 		self.items.iter().nth(
@@ -188,6 +236,34 @@ impl CommitList {
 		Ok(needs_update)
 	}
 
+	/// selects the commit in the clicked row or at the position
+	/// clicked on the scrollbar
+	fn click(&mut self, mouse: MouseEvent) -> bool {
+		if let Some(pos) = scrollbar_position(
+			self.area.get(),
+			self.count_total,
+			mouse,
+		) {
+			self.selection = pos.min(self.selection_max());
+			return true;
+		}
+
+		let area = self.theme.pane_inner(self.area.get());
+		if mouse.kind != MouseEventKind::Down(MouseButton::Left)
+			|| !(area.y..area.bottom()).contains(&mouse.row)
+			|| !(area.x..area.right()).contains(&mouse.column)
+		{
+			return false;
+		}
+
+		self.selection = (self.items.index_offset()
+			+ self.scroll_top.get()
+			+ usize::from(mouse.row - area.y))
+		.min(self.selection_max());
+
+		true
+	}
+
 	fn mark(&mut self) {
 		if let Some(e) = self.selected_entry() {
 			let id = e.id;
@@ -232,14 +308,18 @@ impl CommitList {
 		}
 	}
 
+	#[allow(clippy::too_many_arguments)]
 	fn get_entry_to_add<'a>(
 		e: &'a LogEntry,
 		selected: bool,
 		tags: Option<String>,
+		branch: Option<&str>,
 		theme: &Theme,
 		width: usize,
 		now: DateTime<Local>,
 		marked: Option<bool>,
+		shallow: bool,
+		icon: Option<&'a str>,
 	) -> Spans<'a> {
 		let mut txt: Vec<Span> = Vec::with_capacity(
 			ELEMENTS_PER_LINE + if marked.is_some() { 2 } else { 0 },
@@ -262,6 +342,14 @@ impl CommitList {
 			txt.push(splitter.clone());
 		}
 
+		if let Some(icon) = icon.filter(|_| theme.icons()) {
+			txt.push(Span::styled(
+				Cow::from(icon),
+				theme.commit_hash(selected),
+			));
+			txt.push(splitter.clone());
+		}
+
 		// commit hash
 		txt.push(Span::styled(
 			Cow::from(&*e.hash_short),
@@ -290,11 +378,32 @@ impl CommitList {
 
 		txt.push(splitter.clone());
 
+		// branch
+		if let Some(branch) = branch {
+			let branch = if theme.icons() && !branch.is_empty() {
+				format!("{} {}", icons::BRANCH, branch)
+			} else {
+				branch.to_string()
+			};
+			txt.push(Span::styled::<String>(
+				string_width_align(&branch, author_width),
+				theme.branch(selected, false),
+			));
+
+			txt.push(splitter.clone());
+		}
+
 		// commit tags
 		txt.push(Span::styled(
 			Cow::from(tags.map_or_else(
 				|| String::from(""),
-				|tags| format!(" {}", tags),
+				|tags| {
+					if theme.icons() {
+						format!(" {} {}", icons::TAG, tags)
+					} else {
+						format!(" {}", tags)
+					}
+				},
 			)),
 			theme.tags(selected),
 		));
@@ -307,6 +416,14 @@ impl CommitList {
 			theme.text(true, selected),
 		));
 
+		// older history of a shallow clone
+		if shallow {
+			txt.push(Span::styled(
+				Cow::from(strings::shallow_boundary()),
+				theme.text(false, selected),
+			));
+		}
+
 		Spans::from(txt)
 	}
 
@@ -331,6 +448,11 @@ impl CommitList {
 					|tags| tags.iter().map(|t| &t.name).join(" "),
 				);
 
+			let branch = self
+				.branches
+				.as_ref()
+				.map(|b| b.get(&e.id).map_or("", String::as_str));
+
 			let marked = if any_marked {
 				self.is_marked(&e.id)
 			} else {
@@ -341,10 +463,13 @@ impl CommitList {
 				e,
 				idx + self.scroll_top.get() == selection,
 				tags,
+				branch,
 				&self.theme,
 				width,
 				now,
 				marked,
+				self.shallow.contains(&e.id),
+				self.icon,
 			));
 		}
 
@@ -367,11 +492,10 @@ impl DrawableComponent for CommitList {
 		f: &mut Frame<B>,
 		area: Rect,
 	) -> Result<()> {
-		let current_size = (
-			area.width.saturating_sub(2),
-			area.height.saturating_sub(2),
-		);
+		let inner = self.theme.pane_inner(area);
+		let current_size = (inner.width, inner.height);
 		self.current_size.set(current_size);
+		self.area.set(area);
 
 		let height_in_lines = self.current_size.get().1 as usize;
 		let selection = self.relative_selection();
@@ -402,7 +526,7 @@ impl DrawableComponent for CommitList {
 			)
 			.block(
 				Block::default()
-					.borders(Borders::ALL)
+					.borders(self.theme.pane_borders())
 					.title(Span::styled(
 						title.as_str(),
 						self.theme.title(true),
@@ -427,31 +551,46 @@ impl DrawableComponent for CommitList {
 
 impl Component for CommitList {
 	fn event(&mut self, ev: &Event) -> Result<EventState> {
+		if let Event::Mouse(mouse) = ev {
+			return Ok(self.click(*mouse).into());
+		}
+
 		if let Event::Key(k) = ev {
 			let selection_changed =
-				if key_match(k, self.key_config.keys.move_up) {
+				if key_match(k, self.key_config.keys().move_up) {
 					self.move_selection(ScrollType::Up)?
-				} else if key_match(k, self.key_config.keys.move_down)
-				{
+				} else if key_match(
+					k,
+					self.key_config.keys().move_down,
+				) {
 					self.move_selection(ScrollType::Down)?
-				} else if key_match(k, self.key_config.keys.shift_up)
-					|| key_match(k, self.key_config.keys.home)
-				{
+				} else if key_match(
+					k,
+					self.key_config.keys().shift_up,
+				) || key_match(
+					k,
+					self.key_config.keys().home,
+				) {
 					self.move_selection(ScrollType::Home)?
 				} else if key_match(
 					k,
-					self.key_config.keys.shift_down,
-				) || key_match(k, self.key_config.keys.end)
-				{
+					self.key_config.keys().shift_down,
+				) || key_match(
+					k,
+					self.key_config.keys().end,
+				) {
 					self.move_selection(ScrollType::End)?
-				} else if key_match(k, self.key_config.keys.page_up) {
-					self.move_selection(ScrollType::PageUp)?
-				} else if key_match(k, self.key_config.keys.page_down)
+				} else if key_match(k, self.key_config.keys().page_up)
 				{
+					self.move_selection(ScrollType::PageUp)?
+				} else if key_match(
+					k,
+					self.key_config.keys().page_down,
+				) {
 					self.move_selection(ScrollType::PageDown)?
 				} else if key_match(
 					k,
-					self.key_config.keys.log_mark_commit,
+					self.key_config.keys().log_mark_commit,
 				) {
 					self.mark();
 					true
@@ -0,0 +1,536 @@
+use super::{
+	command_pump, event_pump, utils::time_to_string,
+	visibility_blocking, CommandBlocking, CommandInfo,
+	CommitDetailsComponent, Component, DiffComponent,
+	DrawableComponent, EventState, InspectCommitOpen, ScrollType,
+};
+use crate::{
+	accessors,
+	keys::{key_match, SharedKeyConfig},
+	queue::{InternalEvent, Queue, StackablePopupOpen},
+	strings,
+	ui::{self, style::SharedTheme},
+};
+use anyhow::Result;
+use asyncgit::{
+	sync::{
+		self, diff::DiffOptions, BranchesDiff, CommitId, CommitInfo,
+		RepoPathRef,
+	},
+	AsyncDiff, AsyncGitNotification, CommitFilesParams, DiffParams,
+	DiffType,
+};
+use crossbeam_channel::Sender;
+use crossterm::event::Event;
+use tui::{
+	backend::Backend,
+	layout::{Constraint, Direction, Layout, Rect},
+	text::{Span, Spans},
+	widgets::Clear,
+	Frame,
+};
+
+/// how many commits are listed per side at most
+const MAX_COMMITS: usize = 1000;
+const MESSAGE_LENGTH: usize = 100;
+
+///
+#[derive(Clone, Debug)]
+pub struct CompareBranchesOpen {
+	pub left: String,
+	pub right: String,
+}
+
+/// which side of the comparison a commit is unique to
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Side {
+	Left,
+	Right,
+}
+
+/// commits unique to either of two branches (`git log A...B`) next to
+/// their aggregated diff since the merge base (`git diff A...B`)
+pub struct CompareBranchesComponent {
+	repo: RepoPathRef,
+	open_request: Option<CompareBranchesOpen>,
+	compare: Option<BranchesDiff>,
+	commits: Vec<(Side, CommitInfo)>,
+	selection: usize,
+	commits_focused: bool,
+	diff: DiffComponent,
+	details: CommitDetailsComponent,
+	git_diff: AsyncDiff,
+	visible: bool,
+	theme: SharedTheme,
+	key_config: SharedKeyConfig,
+	queue: Queue,
+}
+
+impl DrawableComponent for CompareBranchesComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		if self.is_visible() {
+			let percentages = if self.diff.focused() {
+				(30, 70)
+			} else {
+				(50, 50)
+			};
+
+			let chunks = Layout::default()
+				.direction(Direction::Horizontal)
+				.constraints(
+					[
+						Constraint::Percentage(percentages.0),
+						Constraint::Percentage(percentages.1),
+					]
+					.as_ref(),
+				)
+				.split(rect);
+
+			let left = Layout::default()
+				.direction(Direction::Vertical)
+				.constraints(
+					[
+						Constraint::Percentage(40),
+						Constraint::Percentage(60),
+					]
+					.as_ref(),
+				)
+				.split(chunks[0]);
+
+			f.render_widget(Clear, rect);
+
+			self.draw_commits(f, left[0]);
+			self.details.draw(f, left[1])?;
+			self.diff.draw(f, chunks[1])?;
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for CompareBranchesComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			command_pump(
+				out,
+				force_all,
+				self.components().as_slice(),
+			);
+
+			out.push(
+				CommandInfo::new(
+					strings::commands::close_popup(&self.key_config),
+					true,
+					true,
+				)
+				.order(1),
+			);
+
+			out.push(CommandInfo::new(
+				strings::commands::commit_details_open(
+					&self.key_config,
+				),
+				!self.commits.is_empty(),
+				self.commits_focused || force_all,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::compare_branches_files(
+					&self.key_config,
+				),
+				self.details.files().file_count() > 0,
+				self.commits_focused || force_all,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::diff_focus_right(&self.key_config),
+				self.can_focus_diff(),
+				(!self.commits_focused && !self.diff.focused())
+					|| force_all,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::diff_focus_left(&self.key_config),
+				true,
+				self.diff.focused() || force_all,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, ev: &Event) -> Result<EventState> {
+		if self.is_visible() {
+			if self.commits_focused {
+				if let Event::Key(e) = ev {
+					if key_match(e, self.key_config.keys().move_up) {
+						self.move_selection(ScrollType::Up);
+						return Ok(EventState::Consumed);
+					} else if key_match(
+						e,
+						self.key_config.keys().move_down,
+					) {
+						self.move_selection(ScrollType::Down);
+						return Ok(EventState::Consumed);
+					} else if key_match(
+						e,
+						self.key_config.keys().enter,
+					) || key_match(
+						e,
+						self.key_config.keys().focus_right,
+					) {
+						self.inspect_commit();
+						return Ok(EventState::Consumed);
+					} else if key_match(
+						e,
+						self.key_config.keys().focus_below,
+					) && self.details.files().file_count()
+						> 0
+					{
+						self.focus_commits(false);
+						return Ok(EventState::Consumed);
+					}
+				}
+			} else if event_pump(
+				ev,
+				self.components_mut().as_mut_slice(),
+			)?
+			.is_consumed()
+			{
+				if !self.details.is_visible() {
+					self.hide_stacked(true);
+				}
+				return Ok(EventState::Consumed);
+			}
+
+			if let Event::Key(e) = ev {
+				if key_match(e, self.key_config.keys().exit_popup) {
+					self.hide_stacked(false);
+				} else if key_match(
+					e,
+					self.key_config.keys().focus_right,
+				) && !self.commits_focused
+					&& self.can_focus_diff()
+				{
+					self.details.focus(false);
+					self.diff.focus(true);
+				} else if key_match(
+					e,
+					self.key_config.keys().focus_left,
+				) && self.diff.focused()
+				{
+					self.details.focus(true);
+					self.diff.focus(false);
+				} else if key_match(
+					e,
+					self.key_config.keys().focus_above,
+				) && !self.commits_focused
+				{
+					self.focus_commits(true);
+				} else if key_match(
+					e,
+					self.key_config.keys().focus_left,
+				) {
+					self.hide_stacked(false);
+				}
+
+				return Ok(EventState::Consumed);
+			}
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+		self.details.show()?;
+		self.focus_commits(true);
+		self.update()?;
+		Ok(())
+	}
+}
+
+impl CompareBranchesComponent {
+	accessors!(self, [diff, details]);
+
+	///
+	pub fn new(
+		repo: &RepoPathRef,
+		queue: &Queue,
+		sender: &Sender<AsyncGitNotification>,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			repo: repo.clone(),
+			details: CommitDetailsComponent::new(
+				repo,
+				queue,
+				sender,
+				theme.clone(),
+				key_config.clone(),
+			),
+			diff: DiffComponent::new(
+				repo.clone(),
+				queue.clone(),
+				theme.clone(),
+				key_config.clone(),
+				true,
+			),
+			open_request: None,
+			compare: None,
+			commits: Vec::new(),
+			selection: 0,
+			commits_focused: true,
+			git_diff: AsyncDiff::new(repo.borrow().clone(), sender),
+			visible: false,
+			theme,
+			key_config,
+			queue: queue.clone(),
+		}
+	}
+
+	///
+	pub fn open(&mut self, open: CompareBranchesOpen) -> Result<()> {
+		let compare = sync::compare_branches(
+			&self.repo.borrow(),
+			&open.left,
+			&open.right,
+			MAX_COMMITS,
+		)?;
+
+		let ids = compare
+			.only_left
+			.iter()
+			.chain(compare.only_right.iter())
+			.copied()
+			.collect::<Vec<_>>();
+		let infos = sync::get_commits_info(
+			&self.repo.borrow(),
+			&ids,
+			MESSAGE_LENGTH,
+		)?;
+
+		self.commits = infos
+			.into_iter()
+			.enumerate()
+			.map(|(idx, info)| {
+				let side = if idx < compare.only_left.len() {
+					Side::Left
+				} else {
+					Side::Right
+				};
+				(side, info)
+			})
+			.collect();
+		self.selection = 0;
+		self.compare = Some(compare);
+		self.open_request = Some(open);
+		self.show()?;
+
+		Ok(())
+	}
+
+	///
+	pub fn any_work_pending(&self) -> bool {
+		self.git_diff.is_pending() || self.details.any_work_pending()
+	}
+
+	///
+	pub fn update_git(
+		&mut self,
+		ev: AsyncGitNotification,
+	) -> Result<()> {
+		if self.is_visible() {
+			if ev == AsyncGitNotification::CommitFiles {
+				self.update()?;
+			} else if ev == AsyncGitNotification::Diff {
+				self.update_diff()?;
+			}
+		}
+
+		Ok(())
+	}
+
+	/// `git diff A...B` compares the merge base with `B`
+	fn get_ids(&self) -> Option<(CommitId, CommitId)> {
+		self.compare.as_ref().and_then(|compare| {
+			compare.merge_base.map(|base| (compare.right, base))
+		})
+	}
+
+	/// called when any tree component changed selection
+	pub fn update_diff(&mut self) -> Result<()> {
+		if self.is_visible() {
+			if let Some(ids) = self.get_ids() {
+				if let Some(f) = self.details.files().selection_file()
+				{
+					let diff_params = DiffParams {
+						path: f.path.clone(),
+						diff_type: DiffType::Commits(ids),
+						options: DiffOptions::default(),
+					};
+
+					if let Some((params, last)) =
+						self.git_diff.last()?
+					{
+						if params == diff_params {
+							self.diff.update(f.path, false, last);
+							return Ok(());
+						}
+					}
+
+					self.git_diff.request(diff_params)?;
+					self.diff.clear(true);
+					return Ok(());
+				}
+			}
+
+			self.diff.clear(false);
+		}
+
+		Ok(())
+	}
+
+	fn update(&mut self) -> Result<()> {
+		self.details.set_commits(
+			self.get_ids().map(CommitFilesParams::from),
+			&None,
+		)?;
+		self.update_diff()?;
+
+		Ok(())
+	}
+
+	fn move_selection(&mut self, move_type: ScrollType) {
+		let new_selection = match move_type {
+			ScrollType::Up => self.selection.saturating_sub(1),
+			ScrollType::Down => self.selection.saturating_add(1),
+			_ => self.selection,
+		};
+
+		self.selection = new_selection
+			.clamp(0, self.commits.len().saturating_sub(1));
+	}
+
+	fn focus_commits(&mut self, focus: bool) {
+		self.commits_focused = focus;
+		self.details.focus(!focus);
+		self.diff.focus(false);
+	}
+
+	fn can_focus_diff(&self) -> bool {
+		self.details.files().selection_file().is_some()
+	}
+
+	fn inspect_commit(&mut self) {
+		if let Some((_, commit)) = self.commits.get(self.selection) {
+			let id = commit.id;
+			self.hide_stacked(true);
+			self.queue.push(InternalEvent::OpenPopup(
+				StackablePopupOpen::InspectCommit(
+					InspectCommitOpen::new(id),
+				),
+			));
+		}
+	}
+
+	fn hide_stacked(&mut self, stack: bool) {
+		self.hide();
+		if stack {
+			if let Some(request) = self.open_request.clone() {
+				self.queue.push(InternalEvent::PopupStackPush(
+					StackablePopupOpen::CompareBranches(request),
+				));
+			}
+		} else {
+			self.queue.push(InternalEvent::PopupStackPop);
+		}
+	}
+
+	fn draw_commits<B: Backend>(&self, f: &mut Frame<B>, area: Rect) {
+		let title = self.open_request.as_ref().map_or_else(
+			String::new,
+			|open| {
+				let count = |side: Side| {
+					self.commits
+						.iter()
+						.filter(|c| c.0 == side)
+						.count()
+				};
+				strings::title_compare_branches(
+					&open.left,
+					&open.right,
+					count(Side::Left),
+					count(Side::Right),
+				)
+			},
+		);
+
+		let height = usize::from(self.theme.pane_inner(area).height);
+		let scroll_top = ui::calc_scroll_top(
+			0,
+			height.saturating_sub(1),
+			self.selection,
+		);
+
+		let items = self
+			.commits
+			.iter()
+			.enumerate()
+			.skip(scroll_top)
+			.take(height)
+			.map(|(idx, (side, commit))| {
+				let selected =
+					self.commits_focused && idx == self.selection;
+				Spans::from(vec![
+					Span::styled(
+						if *side == Side::Left { "< " } else { "> " },
+						self.theme.text(true, selected),
+					),
+					Span::styled(
+						format!("{} ", commit.id.get_short_string()),
+						self.theme.commit_hash(selected),
+					),
+					Span::styled(
+						format!(
+							"{} ",
+							time_to_string(commit.time, true)
+						),
+						self.theme.commit_time(selected),
+					),
+					Span::styled(
+						format!("{} ", commit.author),
+						self.theme.commit_author(selected),
+					),
+					Span::styled(
+						commit.message.clone(),
+						self.theme.text(true, selected),
+					),
+				])
+			});
+
+		ui::draw_list(
+			f,
+			area,
+			&title,
+			items,
+			self.commits_focused,
+			&self.theme,
+		);
+	}
+}
@@ -120,25 +120,25 @@ impl Component for CompareCommitsComponent {
 			}
 
 			if let Event::Key(e) = ev {
-				if key_match(e, self.key_config.keys.exit_popup) {
+				if key_match(e, self.key_config.keys().exit_popup) {
 					self.hide_stacked(false);
 				} else if key_match(
 					e,
-					self.key_config.keys.focus_right,
+					self.key_config.keys().focus_right,
 				) && self.can_focus_diff()
 				{
 					self.details.focus(false);
 					self.diff.focus(true);
 				} else if key_match(
 					e,
-					self.key_config.keys.focus_left,
+					self.key_config.keys().focus_left,
 				) && self.diff.focused()
 				{
 					self.details.focus(true);
 					self.diff.focus(false);
 				} else if key_match(
 					e,
-					self.key_config.keys.focus_left,
+					self.key_config.keys().focus_left,
 				) {
 					self.hide_stacked(false);
 				}
@@ -68,7 +68,7 @@ impl Component for CreateBranchComponent {
 			}
 
 			if let Event::Key(e) = ev {
-				if key_match(e, self.key_config.keys.enter) {
+				if key_match(e, self.key_config.keys().enter) {
 					self.create_branch();
 				}
 
@@ -125,10 +125,8 @@ impl CreateBranchComponent {
 
 	///
 	pub fn create_branch(&mut self) {
-		let res = sync::create_branch(
-			&self.repo.borrow(),
-			self.input.get_text(),
-		);
+		let name = self.input.get_text().to_string();
+		let res = sync::create_branch(&self.repo.borrow(), &name);
 
 		self.input.clear();
 		self.hide();
@@ -138,6 +136,9 @@ impl CreateBranchComponent {
 				self.queue.push(InternalEvent::Update(
 					NeedsUpdate::ALL | NeedsUpdate::BRANCHES,
 				));
+				self.queue.push(InternalEvent::ShowToast(
+					strings::toast_branch_created(&name),
+				));
 			}
 			Err(e) => {
 				log::error!("create branch: {}", e,);
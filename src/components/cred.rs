@@ -106,7 +106,7 @@ impl Component for CredComponent {
 	fn event(&mut self, ev: &Event) -> Result<EventState> {
 		if self.visible {
 			if let Event::Key(e) = ev {
-				if key_match(e, self.key_config.keys.exit_popup) {
+				if key_match(e, self.key_config.keys().exit_popup) {
 					self.hide();
 					return Ok(EventState::Consumed);
 				}
@@ -114,7 +114,7 @@ impl Component for CredComponent {
 					|| self.input_password.event(ev)?.is_consumed()
 				{
 					return Ok(EventState::Consumed);
-				} else if key_match(e, self.key_config.keys.enter) {
+				} else if key_match(e, self.key_config.keys().enter) {
 					if self.input_username.is_visible() {
 						self.cred = BasicAuthCredential::new(
 							Some(
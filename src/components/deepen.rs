@@ -0,0 +1,187 @@
+use super::{
+	textinput::TextInputComponent, visibility_blocking,
+	CommandBlocking, CommandInfo, Component, DrawableComponent,
+	EventState,
+};
+use crate::{
+	keys::{key_match, SharedKeyConfig},
+	queue::{InternalEvent, NeedsUpdate, Queue},
+	strings,
+	ui::style::SharedTheme,
+};
+use anyhow::Result;
+use asyncgit::{
+	asyncjob::AsyncSingleJob,
+	sync::{self, RepoPathRef},
+	AsyncDeepenJob, AsyncGitNotification,
+};
+use crossbeam_channel::Sender;
+use crossterm::event::Event;
+use tui::{backend::Backend, layout::Rect, Frame};
+
+/// asks how many commits of history to add to a shallow clone
+pub struct DeepenPopup {
+	repo: RepoPathRef,
+	input: TextInputComponent,
+	async_deepen: AsyncSingleJob<AsyncDeepenJob>,
+	queue: Queue,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for DeepenPopup {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		self.input.draw(f, rect)?;
+
+		Ok(())
+	}
+}
+
+impl Component for DeepenPopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			self.input.commands(out, force_all);
+
+			out.push(CommandInfo::new(
+				strings::commands::deepen_confirm(&self.key_config),
+				self.is_valid() && !self.is_pending(),
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, ev: &Event) -> Result<EventState> {
+		if self.is_visible() {
+			if self.is_pending() {
+				return Ok(EventState::Consumed);
+			}
+
+			if self.input.event(ev)?.is_consumed() {
+				return Ok(EventState::Consumed);
+			}
+
+			if let Event::Key(e) = ev {
+				if key_match(e, self.key_config.keys().enter)
+					&& self.is_valid()
+				{
+					self.deepen()?;
+				}
+
+				return Ok(EventState::Consumed);
+			}
+		}
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.input.is_visible()
+	}
+
+	fn hide(&mut self) {
+		self.input.hide();
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.input.show()?;
+
+		Ok(())
+	}
+}
+
+impl DeepenPopup {
+	///
+	pub fn new(
+		repo: RepoPathRef,
+		queue: &Queue,
+		sender: &Sender<AsyncGitNotification>,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			repo,
+			queue: queue.clone(),
+			input: TextInputComponent::new(
+				theme,
+				key_config.clone(),
+				&strings::deepen_popup_title(),
+				&strings::deepen_popup_msg(),
+				false,
+			),
+			async_deepen: AsyncSingleJob::new(sender.clone()),
+			key_config,
+		}
+	}
+
+	///
+	pub fn open(&mut self) -> Result<()> {
+		self.input.clear();
+		self.input.set_title(strings::deepen_popup_title());
+		self.show()?;
+
+		Ok(())
+	}
+
+	///
+	pub fn is_pending(&self) -> bool {
+		self.async_deepen.is_pending()
+	}
+
+	/// empty input fetches the whole history
+	fn depth(&self) -> Option<u32> {
+		self.input.get_text().trim().parse().ok()
+	}
+
+	fn is_valid(&self) -> bool {
+		self.input.get_text().trim().is_empty()
+			|| self.depth().map_or(false, |depth| depth > 0)
+	}
+
+	fn deepen(&mut self) -> Result<()> {
+		let remote = sync::get_default_remote(&self.repo.borrow())?;
+
+		self.input.set_title(strings::deepen_popup_pending());
+		self.async_deepen.spawn(AsyncDeepenJob::new(
+			self.repo.borrow().clone(),
+			remote,
+			self.depth(),
+		));
+
+		Ok(())
+	}
+
+	///
+	pub fn update_git(&mut self, ev: AsyncGitNotification) {
+		if ev != AsyncGitNotification::Deepen {
+			return;
+		}
+
+		if let Some(res) =
+			self.async_deepen.take_last().and_then(|job| job.result())
+		{
+			self.hide();
+
+			match res {
+				Ok(()) => {
+					self.queue.push(InternalEvent::Update(
+						NeedsUpdate::ALL | NeedsUpdate::BRANCHES,
+					));
+				}
+				Err(e) => {
+					log::error!("deepen: {}", e);
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						format!("fetching history failed:\n{}", e),
+					));
+				}
+			}
+		}
+	}
+}
@@ -0,0 +1,146 @@
+use super::{
+	textinput::TextInputComponent, visibility_blocking,
+	CommandBlocking, CommandInfo, Component, DrawableComponent,
+	EventState,
+};
+use crate::{
+	keys::{key_match, SharedKeyConfig},
+	queue::{Action, InternalEvent, Queue},
+	strings,
+	ui::style::SharedTheme,
+};
+use anyhow::Result;
+use asyncgit::sync::{self, RepoPathRef};
+use crossterm::event::Event;
+use tui::{backend::Backend, layout::Rect, Frame};
+
+pub struct DeleteTagsComponent {
+	repo: RepoPathRef,
+	input: TextInputComponent,
+	queue: Queue,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for DeleteTagsComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		self.input.draw(f, rect)?;
+
+		Ok(())
+	}
+}
+
+impl Component for DeleteTagsComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			self.input.commands(out, force_all);
+
+			out.push(CommandInfo::new(
+				strings::commands::delete_matching_tags_confirm(
+					&self.key_config,
+				),
+				!self.input.get_text().is_empty(),
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, ev: &Event) -> Result<EventState> {
+		if self.is_visible() {
+			if self.input.event(ev)?.is_consumed() {
+				return Ok(EventState::Consumed);
+			}
+
+			if let Event::Key(e) = ev {
+				if key_match(e, self.key_config.keys().enter)
+					&& !self.input.get_text().is_empty()
+				{
+					self.confirm();
+				}
+
+				return Ok(EventState::Consumed);
+			}
+		}
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.input.is_visible()
+	}
+
+	fn hide(&mut self) {
+		self.input.hide();
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.input.show()?;
+
+		Ok(())
+	}
+}
+
+impl DeleteTagsComponent {
+	///
+	pub fn new(
+		repo: RepoPathRef,
+		queue: Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			repo,
+			queue,
+			input: TextInputComponent::new(
+				theme,
+				key_config.clone(),
+				&strings::delete_tags_popup_title(),
+				&strings::delete_tags_popup_msg(),
+				true,
+			),
+			key_config,
+		}
+	}
+
+	///
+	pub fn open(&mut self) -> Result<()> {
+		self.input.clear();
+		self.show()?;
+
+		Ok(())
+	}
+
+	/// lists the tags matching the pattern in a confirmation popup
+	fn confirm(&mut self) {
+		let pattern = self.input.get_text().to_string();
+		let res = sync::tags_matching(&self.repo.borrow(), &pattern);
+
+		match res {
+			Ok(tags) if tags.is_empty() => {
+				self.queue.push(InternalEvent::ShowInfoMsg(
+					strings::delete_tags_none(&pattern),
+				));
+			}
+			Ok(tags) => {
+				self.hide();
+				self.queue.push(InternalEvent::ConfirmAction(
+					Action::DeleteTags(tags),
+				));
+			}
+			Err(e) => {
+				log::error!("delete tags: {}", e);
+				self.queue.push(InternalEvent::ShowErrorMsg(
+					format!("delete tags error:\n{}", e),
+				));
+			}
+		}
+	}
+}
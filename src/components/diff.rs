@@ -8,7 +8,7 @@ use crate::{
 	queue::{Action, InternalEvent, NeedsUpdate, Queue, ResetItem},
 	string_utils::tabs_to_spaces,
 	strings, try_or_popup,
-	ui::style::SharedTheme,
+	ui::{self, style::SharedTheme},
 };
 use anyhow::Result;
 use asyncgit::{
@@ -24,9 +24,10 @@ use tui::{
 	layout::Rect,
 	symbols,
 	text::{Span, Spans},
-	widgets::{Block, Borders, Paragraph},
+	widgets::{Block, Paragraph},
 	Frame,
 };
+use unicode_width::UnicodeWidthStr;
 
 #[derive(Default)]
 struct Current {
@@ -113,6 +114,8 @@ pub struct DiffComponent {
 	theme: SharedTheme,
 	key_config: SharedKeyConfig,
 	is_immutable: bool,
+	/// soft wrap long lines instead of cutting them off
+	wrap: bool,
 }
 
 impl DiffComponent {
@@ -137,6 +140,7 @@ impl DiffComponent {
 			theme,
 			key_config,
 			is_immutable,
+			wrap: false,
 			repo,
 		}
 	}
@@ -370,18 +374,36 @@ impl DiffComponent {
 							if line_cursor >= min
 								&& line_cursor <= max
 							{
-								res.push(Self::get_line_to_add(
-									width,
-									line,
-									self.focused()
-										&& self
-											.selection
-											.contains(line_cursor),
-									hunk_selected,
-									i == hunk_len as usize - 1,
-									&self.theme,
-								));
-								lines_added += 1;
+								let selected = self.focused()
+									&& self
+										.selection
+										.contains(line_cursor);
+								let end_of_hunk =
+									i == hunk_len as usize - 1;
+
+								if self.wrap {
+									let rows =
+										Self::get_wrapped_line_to_add(
+											width,
+											line,
+											selected,
+											hunk_selected,
+											end_of_hunk,
+											&self.theme,
+										);
+									lines_added += rows.len();
+									res.extend(rows);
+								} else {
+									res.push(Self::get_line_to_add(
+										width,
+										line,
+										selected,
+										hunk_selected,
+										end_of_hunk,
+										&self.theme,
+									));
+									lines_added += 1;
+								}
 							}
 
 							line_cursor += 1;
@@ -437,6 +459,93 @@ impl DiffComponent {
 		])
 	}
 
+	/// rows of a line soft wrapped at the width inside the borders
+	fn get_wrapped_line_to_add(
+		width: u16,
+		line: &DiffLine,
+		selected: bool,
+		selected_hunk: bool,
+		end_of_hunk: bool,
+		theme: &SharedTheme,
+	) -> Vec<Spans<'static>> {
+		let marker_style = theme.diff_hunk_marker(selected_hunk);
+		let line_style = theme.diff_line(line.line_type, selected);
+		let content_width = usize::from(width.saturating_sub(1));
+
+		let rows = ui::wrap_spans(
+			&Spans::from(Span::styled(
+				Self::line_content(line),
+				line_style,
+			)),
+			content_width,
+		);
+		let rows_count = rows.len();
+
+		rows.into_iter()
+			.enumerate()
+			.map(|(row, mut spans)| {
+				let marker = if end_of_hunk && row + 1 == rows_count {
+					symbols::line::BOTTOM_LEFT
+				} else if row == 0
+					&& line.line_type == DiffLineType::Header
+				{
+					symbols::line::TOP_LEFT
+				} else {
+					symbols::line::VERTICAL
+				};
+
+				if selected {
+					spans.0.push(Span::styled(
+						" ".repeat(
+							content_width
+								.saturating_sub(spans.width()),
+						),
+						line_style,
+					));
+				}
+				spans.0.insert(0, Span::styled(marker, marker_style));
+
+				spans
+			})
+			.collect()
+	}
+
+	fn line_content(line: &DiffLine) -> String {
+		tabs_to_spaces(
+			line.content.trim_end_matches(['\n', '\r']).to_string(),
+		)
+	}
+
+	/// scrolls down until the rows of the wrapped lines from the top
+	/// down to the selection fit into `height`
+	fn scroll_to_wrapped_selection(&self, width: u16, height: usize) {
+		let content_width =
+			usize::from(width.saturating_sub(1)).max(1);
+		let selection = self.selection.get_end();
+
+		if let Some(diff) = &self.diff {
+			let rows = diff
+				.hunks
+				.iter()
+				.flat_map(|hunk| hunk.lines.iter())
+				.map(|line| {
+					Self::line_content(line).width().saturating_sub(1)
+						/ content_width + 1
+				})
+				.take(selection + 1)
+				.collect::<Vec<_>>();
+
+			let mut top = self.scroll.get_top().min(selection);
+			while top < selection
+				&& rows.iter().skip(top).sum::<usize>() > height
+			{
+				top += 1;
+			}
+
+			self.scroll.set_top(top);
+		}
+	}
+
 	const fn hunk_visible(
 		hunk_min: usize,
 		hunk_max: usize,
@@ -603,10 +712,8 @@ impl DrawableComponent for DiffComponent {
 		f: &mut Frame<B>,
 		r: Rect,
 	) -> Result<()> {
-		self.current_size.set((
-			r.width.saturating_sub(2),
-			r.height.saturating_sub(2),
-		));
+		let inner = self.theme.pane_inner(r);
+		self.current_size.set((inner.width, inner.height));
 
 		let current_height = self.current_size.get().1;
 
@@ -616,6 +723,13 @@ impl DrawableComponent for DiffComponent {
 			usize::from(current_height),
 		);
 
+		if self.wrap {
+			self.scroll_to_wrapped_selection(
+				inner.width,
+				usize::from(current_height),
+			);
+		}
+
 		let title = format!(
 			"{}{}",
 			strings::title_diff(&self.key_config),
@@ -628,7 +742,7 @@ impl DrawableComponent for DiffComponent {
 				self.theme.text(false, false),
 			)])]
 		} else {
-			self.get_text(r.width, current_height)
+			self.get_text(inner.width, current_height)
 		};
 
 		f.render_widget(
@@ -638,7 +752,7 @@ impl DrawableComponent for DiffComponent {
 						title.as_str(),
 						self.theme.title(self.focused()),
 					))
-					.borders(Borders::ALL)
+					.borders(self.theme.pane_borders())
 					.border_style(self.theme.block(self.focused())),
 			),
 			r,
@@ -719,46 +833,70 @@ impl Component for DiffComponent {
 			self.focused(),
 		));
 
+		out.push(CommandInfo::new(
+			strings::commands::toggle_wrap(
+				&self.key_config,
+				self.wrap,
+			),
+			true,
+			self.focused(),
+		));
+
 		CommandBlocking::PassingOn
 	}
 
-	#[allow(clippy::cognitive_complexity)]
+	#[allow(clippy::cognitive_complexity, clippy::too_many_lines)]
 	fn event(&mut self, ev: &Event) -> Result<EventState> {
 		if self.focused() {
+			if let Event::Mouse(mouse) = ev {
+				if let Some(top) = self.scroll.click(*mouse) {
+					self.update_selection(top);
+					return Ok(EventState::Consumed);
+				}
+			}
+
 			if let Event::Key(e) = ev {
-				return if key_match(e, self.key_config.keys.move_down)
-				{
+				return if key_match(
+					e,
+					self.key_config.keys().move_down,
+				) {
 					self.move_selection(ScrollType::Down);
 					Ok(EventState::Consumed)
 				} else if key_match(
 					e,
-					self.key_config.keys.shift_down,
+					self.key_config.keys().shift_down,
 				) {
 					self.modify_selection(Direction::Down);
 					Ok(EventState::Consumed)
-				} else if key_match(e, self.key_config.keys.shift_up)
-				{
+				} else if key_match(
+					e,
+					self.key_config.keys().shift_up,
+				) {
 					self.modify_selection(Direction::Up);
 					Ok(EventState::Consumed)
-				} else if key_match(e, self.key_config.keys.end) {
+				} else if key_match(e, self.key_config.keys().end) {
 					self.move_selection(ScrollType::End);
 					Ok(EventState::Consumed)
-				} else if key_match(e, self.key_config.keys.home) {
+				} else if key_match(e, self.key_config.keys().home) {
 					self.move_selection(ScrollType::Home);
 					Ok(EventState::Consumed)
-				} else if key_match(e, self.key_config.keys.move_up) {
+				} else if key_match(e, self.key_config.keys().move_up)
+				{
 					self.move_selection(ScrollType::Up);
 					Ok(EventState::Consumed)
-				} else if key_match(e, self.key_config.keys.page_up) {
+				} else if key_match(e, self.key_config.keys().page_up)
+				{
 					self.move_selection(ScrollType::PageUp);
 					Ok(EventState::Consumed)
-				} else if key_match(e, self.key_config.keys.page_down)
-				{
+				} else if key_match(
+					e,
+					self.key_config.keys().page_down,
+				) {
 					self.move_selection(ScrollType::PageDown);
 					Ok(EventState::Consumed)
 				} else if key_match(
 					e,
-					self.key_config.keys.stage_unstage_item,
+					self.key_config.keys().stage_unstage_item,
 				) && !self.is_immutable
 				{
 					try_or_popup!(
@@ -770,7 +908,7 @@ impl Component for DiffComponent {
 					Ok(EventState::Consumed)
 				} else if key_match(
 					e,
-					self.key_config.keys.status_reset_item,
+					self.key_config.keys().status_reset_item,
 				) && !self.is_immutable
 					&& !self.is_stage()
 				{
@@ -784,14 +922,14 @@ impl Component for DiffComponent {
 					Ok(EventState::Consumed)
 				} else if key_match(
 					e,
-					self.key_config.keys.diff_stage_lines,
+					self.key_config.keys().diff_stage_lines,
 				) && !self.is_immutable
 				{
 					self.stage_lines();
 					Ok(EventState::Consumed)
 				} else if key_match(
 					e,
-					self.key_config.keys.diff_reset_lines,
+					self.key_config.keys().diff_reset_lines,
 				) && !self.is_immutable
 					&& !self.is_stage()
 				{
@@ -802,7 +940,13 @@ impl Component for DiffComponent {
 						}
 					}
 					Ok(EventState::Consumed)
-				} else if key_match(e, self.key_config.keys.copy) {
+				} else if key_match(
+					e,
+					self.key_config.keys().toggle_wrap,
+				) {
+					self.wrap = !self.wrap;
+					Ok(EventState::Consumed)
+				} else if key_match(e, self.key_config.keys().copy) {
 					self.copy_selection();
 					Ok(EventState::Consumed)
 				} else {
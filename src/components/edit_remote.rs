@@ -0,0 +1,208 @@
+use super::{
+	textinput::TextInputComponent, visibility_blocking,
+	CommandBlocking, CommandInfo, Component, DrawableComponent,
+	EventState,
+};
+use crate::{
+	keys::{key_match, SharedKeyConfig},
+	queue::{InternalEvent, NeedsUpdate, Queue, RemoteEdit},
+	strings,
+	ui::style::SharedTheme,
+};
+use anyhow::Result;
+use asyncgit::sync::{self, RepoPathRef};
+use crossterm::event::Event;
+use tui::{backend::Backend, layout::Rect, Frame};
+
+pub struct EditRemoteComponent {
+	repo: RepoPathRef,
+	input: TextInputComponent,
+	edit: Option<RemoteEdit>,
+	queue: Queue,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for EditRemoteComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		self.input.draw(f, rect)?;
+
+		Ok(())
+	}
+}
+
+impl Component for EditRemoteComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			self.input.commands(out, force_all);
+
+			out.push(CommandInfo::new(
+				strings::commands::remote_edit_confirm(
+					&self.key_config,
+				),
+				self.can_confirm(),
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, ev: &Event) -> Result<EventState> {
+		if self.is_visible() {
+			if self.input.event(ev)?.is_consumed() {
+				if !self.input.is_visible() {
+					// closed by exit key
+					self.queue.push(InternalEvent::ViewRemotes);
+				}
+				return Ok(EventState::Consumed);
+			}
+
+			if let Event::Key(e) = ev {
+				if key_match(e, self.key_config.keys().enter)
+					&& self.can_confirm()
+				{
+					self.confirm()?;
+				}
+
+				return Ok(EventState::Consumed);
+			}
+		}
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.input.is_visible()
+	}
+
+	fn hide(&mut self) {
+		self.input.hide();
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.input.show()?;
+
+		Ok(())
+	}
+}
+
+impl EditRemoteComponent {
+	///
+	pub fn new(
+		repo: RepoPathRef,
+		queue: Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			repo,
+			queue,
+			input: TextInputComponent::new(
+				theme,
+				key_config.clone(),
+				"",
+				"",
+				true,
+			),
+			edit: None,
+			key_config,
+		}
+	}
+
+	///
+	pub fn open(&mut self, edit: RemoteEdit) -> Result<()> {
+		let (title, msg, text) = match &edit {
+			RemoteEdit::AddName => (
+				strings::remote_add_title(),
+				strings::remote_name_msg(),
+				String::new(),
+			),
+			RemoteEdit::AddUrl(name) => (
+				strings::remote_url_title(name),
+				strings::remote_url_msg(),
+				String::new(),
+			),
+			RemoteEdit::Rename(name) => (
+				strings::remote_rename_title(name),
+				strings::remote_name_msg(),
+				name.clone(),
+			),
+			RemoteEdit::Url(name, url) => (
+				strings::remote_url_title(name),
+				strings::remote_url_msg(),
+				url.clone().unwrap_or_default(),
+			),
+			RemoteEdit::PushUrl(name, url) => (
+				strings::remote_push_url_title(name),
+				strings::remote_push_url_msg(),
+				url.clone().unwrap_or_default(),
+			),
+		};
+
+		self.input.set_title(title);
+		self.input.set_default_msg(msg);
+		self.input.set_text(text);
+		self.edit = Some(edit);
+		self.show()?;
+
+		Ok(())
+	}
+
+	/// only the push url can be set empty to remove it
+	fn can_confirm(&self) -> bool {
+		matches!(self.edit, Some(RemoteEdit::PushUrl(..)))
+			|| !self.input.get_text().is_empty()
+	}
+
+	fn confirm(&mut self) -> Result<()> {
+		if let Some(edit) = self.edit.take() {
+			let repo = self.repo.borrow().clone();
+			let text = self.input.get_text().to_string();
+
+			let res = match &edit {
+				RemoteEdit::AddName => {
+					return self.open(RemoteEdit::AddUrl(text));
+				}
+				RemoteEdit::AddUrl(name) => {
+					sync::add_remote(&repo, name, &text)
+				}
+				RemoteEdit::Rename(name) => {
+					sync::rename_remote(&repo, name, &text)
+				}
+				RemoteEdit::Url(name, _) => {
+					sync::set_remote_url(&repo, name, &text)
+				}
+				RemoteEdit::PushUrl(name, _) => {
+					sync::set_remote_push_url(&repo, name, &text)
+				}
+			};
+
+			match res {
+				Ok(()) => {
+					self.hide();
+					self.input.clear();
+					self.queue.push(InternalEvent::Update(
+						NeedsUpdate::ALL | NeedsUpdate::BRANCHES,
+					));
+					self.queue.push(InternalEvent::ViewRemotes);
+				}
+				Err(e) => {
+					log::error!("edit remote: {}", e);
+					self.edit = Some(edit);
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						format!("remote error:\n{}", e),
+					));
+				}
+			}
+		}
+
+		Ok(())
+	}
+}
@@ -146,7 +146,7 @@ impl DrawableComponent for ExternalEditorComponent {
 						Block::default()
 							.borders(Borders::ALL)
 							.border_type(BorderType::Thick)
-							.border_style(self.theme.block(true)),
+							.border_style(self.theme.popup_border()),
 					)
 					.style(self.theme.block(true)),
 				area,
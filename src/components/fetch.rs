@@ -1,7 +1,8 @@
 use crate::{
 	components::{
-		cred::CredComponent, visibility_blocking, CommandBlocking,
-		CommandInfo, Component, DrawableComponent, EventState,
+		cred::CredComponent, utils::error_chain, visibility_blocking,
+		CommandBlocking, CommandInfo, Component, DrawableComponent,
+		EventState, SharedOptions,
 	},
 	keys::SharedKeyConfig,
 	queue::{InternalEvent, NeedsUpdate, Queue},
@@ -16,28 +17,30 @@ use asyncgit::{
 			extract_username_password, need_username_password,
 			BasicAuthCredential,
 		},
-		RepoPathRef,
+		FetchAllProgress, RepoPathRef,
 	},
-	AsyncFetchJob, AsyncGitNotification, ProgressPercent,
+	AsyncFetchJob, AsyncGitNotification,
 };
 use crossbeam_channel::Sender;
 use crossterm::event::Event;
 use tui::{
 	backend::Backend,
-	layout::Rect,
+	layout::{Margin, Rect},
 	text::Span,
 	widgets::{Block, BorderType, Borders, Clear, Gauge},
 	Frame,
 };
+use unicode_truncate::UnicodeTruncateStr;
 
 ///
 pub struct FetchComponent {
 	repo: RepoPathRef,
 	visible: bool,
 	async_fetch: AsyncSingleJob<AsyncFetchJob>,
-	progress: Option<ProgressPercent>,
+	progress: Option<FetchAllProgress>,
 	pending: bool,
 	queue: Queue,
+	options: SharedOptions,
 	theme: SharedTheme,
 	key_config: SharedKeyConfig,
 	input_cred: CredComponent,
@@ -51,9 +54,11 @@ impl FetchComponent {
 		sender: &Sender<AsyncGitNotification>,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
+		options: SharedOptions,
 	) -> Self {
 		Self {
 			queue: queue.clone(),
+			options,
 			pending: false,
 			visible: false,
 			async_fetch: AsyncSingleJob::new(sender.clone()),
@@ -92,9 +97,10 @@ impl FetchComponent {
 	fn fetch_all(&mut self, cred: Option<BasicAuthCredential>) {
 		self.pending = true;
 		self.progress = None;
-		self.progress = Some(ProgressPercent::empty());
 		self.async_fetch.spawn(AsyncFetchJob::new(
 			self.repo.borrow().clone(),
+			self.options.borrow().fetch_prune,
+			self.options.borrow().network_retries.retries(),
 			cred,
 		));
 	}
@@ -118,8 +124,74 @@ impl FetchComponent {
 
 		if !self.pending {
 			self.hide();
-			self.queue
-				.push(InternalEvent::Update(NeedsUpdate::BRANCHES));
+
+			match self
+				.async_fetch
+				.take_last()
+				.and_then(|job| job.result())
+			{
+				Some(Ok(_)) => {
+					self.queue.push(InternalEvent::ShowToast(
+						strings::toast_fetched(),
+					));
+				}
+				Some(Err(e)) => {
+					self.queue.push(InternalEvent::ShowErrorRetry(
+						format!("fetch failed:\n{}", error_chain(&e)),
+						Box::new(InternalEvent::FetchRemotes),
+					));
+				}
+				None => (),
+			}
+
+			// refresh branch list and log decorations
+			self.queue.push(InternalEvent::Update(
+				NeedsUpdate::ALL | NeedsUpdate::BRANCHES,
+			));
+		}
+	}
+
+	fn draw_remotes<B: Backend>(&self, f: &mut Frame<B>, area: Rect) {
+		let remotes = self.progress.as_deref().unwrap_or_default();
+
+		for (idx, remote) in remotes.iter().enumerate() {
+			let row = Rect {
+				y: area.y.saturating_add(
+					u16::try_from(idx).unwrap_or(u16::MAX),
+				),
+				height: 1,
+				..area
+			};
+			if row.bottom() > area.bottom() {
+				break;
+			}
+
+			let name = remote
+				.name
+				.unicode_truncate(usize::from(area.width) / 2)
+				.0;
+			let label = if remote.failed {
+				format!("{} failed", name)
+			} else if let Some(retry) =
+				remote.retry.filter(|_| !remote.done)
+			{
+				format!(
+					"{} {} {}%",
+					name,
+					strings::network_retry(retry.attempt, retry.max),
+					remote.progress.progress
+				)
+			} else {
+				format!("{} {}%", name, remote.progress.progress)
+			};
+
+			f.render_widget(
+				Gauge::default()
+					.gauge_style(self.theme.push_gauge())
+					.label(label)
+					.percent(u16::from(remote.progress.progress)),
+				row,
+			);
 		}
 	}
 }
@@ -131,27 +203,34 @@ impl DrawableComponent for FetchComponent {
 		rect: Rect,
 	) -> Result<()> {
 		if self.visible {
-			let progress = self.progress.unwrap_or_default().progress;
+			let remotes =
+				self.progress.as_ref().map_or(0, Vec::len).max(1);
+			let height = u16::try_from(remotes)
+				.unwrap_or(u16::MAX)
+				.saturating_add(2);
 
-			let area = ui::centered_rect_absolute(30, 3, f.size());
+			let area =
+				ui::centered_rect_absolute(40, height, f.size());
 
 			f.render_widget(Clear, area);
 			f.render_widget(
-				Gauge::default()
-					.block(
-						Block::default()
-							.title(Span::styled(
-								strings::FETCH_POPUP_MSG,
-								self.theme.title(true),
-							))
-							.borders(Borders::ALL)
-							.border_type(BorderType::Thick)
-							.border_style(self.theme.block(true)),
-					)
-					.gauge_style(self.theme.push_gauge())
-					.percent(u16::from(progress)),
+				Block::default()
+					.title(Span::styled(
+						strings::FETCH_POPUP_MSG,
+						self.theme.title(true),
+					))
+					.borders(Borders::ALL)
+					.border_type(BorderType::Thick)
+					.border_style(self.theme.popup_border()),
 				area,
 			);
+			self.draw_remotes(
+				f,
+				area.inner(&Margin {
+					horizontal: 1,
+					vertical: 1,
+				}),
+			);
 			self.input_cred.draw(f, rect)?;
 		}
 
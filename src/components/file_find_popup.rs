@@ -5,24 +5,72 @@ use super::{
 use crate::{
 	keys::{key_match, SharedKeyConfig},
 	queue::{InternalEvent, Queue},
-	string_utils::trim_length_left,
+	string_utils::{
+		glob_match, is_glob, tabs_to_spaces, trim_length_left,
+	},
 	strings,
 	ui::{self, style::SharedTheme},
 };
 use anyhow::Result;
-use asyncgit::sync::TreeFile;
+use asyncgit::sync::{
+	self, diff::DiffLineType, CommitId, RepoPathRef, TreeFile,
+};
 use crossterm::event::Event;
 use fuzzy_matcher::FuzzyMatcher;
 use std::borrow::Cow;
 use tui::{
 	backend::Backend,
 	layout::{Constraint, Direction, Layout, Margin, Rect},
+	style::Style,
 	text::{Span, Spans},
-	widgets::{Block, Borders, Clear},
+	widgets::{Block, Borders, Clear, Paragraph},
 	Frame,
 };
 
+/// lines of the highlighted file shown in the preview
+const PREVIEW_LINES: usize = 100;
+
+/// indices of the files matching `query` with the matched character
+/// positions, best fuzzy match first or in tree order for a glob
+pub fn find_files(
+	files: &[TreeFile],
+	query: &str,
+) -> Vec<(usize, Vec<usize>)> {
+	if is_glob(query) {
+		return files
+			.iter()
+			.enumerate()
+			.filter(|(_, file)| {
+				file.path
+					.to_str()
+					.map_or(false, |path| glob_match(query, path))
+			})
+			.map(|(idx, _)| (idx, Vec::new()))
+			.collect();
+	}
+
+	let matcher = fuzzy_matcher::skim::SkimMatcherV2::default();
+
+	let mut files = files
+		.iter()
+		.enumerate()
+		.filter_map(|a| {
+			a.1.path.to_str().and_then(|path| {
+				matcher
+					.fuzzy_indices(path, query)
+					.map(|(score, indices)| (score, a.0, indices))
+			})
+		})
+		.collect::<Vec<(_, _, _)>>();
+
+	files
+		.sort_by(|(score1, _, _), (score2, _, _)| score2.cmp(score1));
+
+	files.into_iter().map(|entry| (entry.1, entry.2)).collect()
+}
+
 pub struct FileFindPopup {
+	repo: RepoPathRef,
 	queue: Queue,
 	visible: bool,
 	find_text: TextInputComponent,
@@ -32,12 +80,20 @@ pub struct FileFindPopup {
 	selection: usize,
 	selected_index: Option<usize>,
 	files_filtered: Vec<(usize, Vec<usize>)>,
+	/// revision the files are listed and previewed from
+	revision: Option<CommitId>,
+	/// whether `revision` is `HEAD`, previewing work tree changes
+	head: bool,
+	/// head of the highlighted file, or its changes if modified
+	preview: Vec<(String, Style)>,
+	preview_is_diff: bool,
 	key_config: SharedKeyConfig,
 }
 
 impl FileFindPopup {
 	///
 	pub fn new(
+		repo: RepoPathRef,
 		queue: &Queue,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
@@ -52,6 +108,7 @@ impl FileFindPopup {
 		find_text.embed();
 
 		Self {
+			repo,
 			queue: queue.clone(),
 			visible: false,
 			query: None,
@@ -60,6 +117,10 @@ impl FileFindPopup {
 			files: Vec::new(),
 			files_filtered: Vec::new(),
 			selected_index: None,
+			revision: None,
+			head: false,
+			preview: Vec::new(),
+			preview_is_diff: false,
 			key_config,
 			selection: 0,
 		}
@@ -85,29 +146,7 @@ impl FileFindPopup {
 		self.files_filtered.clear();
 
 		if let Some(q) = &self.query {
-			let matcher =
-				fuzzy_matcher::skim::SkimMatcherV2::default();
-
-			let mut files = self
-				.files
-				.iter()
-				.enumerate()
-				.filter_map(|a| {
-					a.1.path.to_str().and_then(|path| {
-						matcher.fuzzy_indices(path, q).map(
-							|(score, indices)| (score, a.0, indices),
-						)
-					})
-				})
-				.collect::<Vec<(_, _, _)>>();
-
-			files.sort_by(|(score1, _, _), (score2, _, _)| {
-				score2.cmp(score1)
-			});
-
-			self.files_filtered.extend(
-				files.into_iter().map(|entry| (entry.1, entry.2)),
-			);
+			self.files_filtered = find_files(&self.files, q);
 		}
 
 		self.selection = 0;
@@ -126,11 +165,77 @@ impl FileFindPopup {
 				.and_then(|index| self.files.get(index))
 				.map(|f| f.path.clone());
 
+			self.update_preview(
+				file.as_ref().and_then(|f| f.to_str()),
+			);
+
 			self.queue.push(InternalEvent::FileFinderChanged(file));
 		}
 	}
 
-	pub fn open(&mut self, files: &[TreeFile]) -> Result<()> {
+	/// shows the changes of a file modified in the work tree if the
+	/// files are those of `HEAD`, otherwise the head of its content
+	fn update_preview(&mut self, path: Option<&str>) {
+		self.preview.clear();
+		self.preview_is_diff = false;
+
+		let (path, revision) = match (path, self.revision) {
+			(Some(path), Some(revision)) => (path, revision),
+			_ => return,
+		};
+
+		let repo = self.repo.borrow();
+
+		if self.head {
+			if let Ok(diff) = sync::diff::get_diff_head_to_workdir(
+				&repo, path, false, None,
+			) {
+				self.preview = diff
+					.hunks
+					.iter()
+					.flat_map(|hunk| hunk.lines.iter())
+					.take(PREVIEW_LINES)
+					.map(|line| {
+						(
+							tabs_to_spaces(
+								line.content.trim_end().to_string(),
+							),
+							self.theme
+								.diff_line(line.line_type, false),
+						)
+					})
+					.collect();
+				self.preview_is_diff = !self.preview.is_empty();
+			}
+		}
+
+		if !self.preview_is_diff {
+			self.preview = match sync::commit_file_content(
+				&repo, revision, path,
+			) {
+				Ok(content) => tabs_to_spaces(content)
+					.lines()
+					.take(PREVIEW_LINES)
+					.map(|line| {
+						(
+							line.to_string(),
+							self.theme.text(true, false),
+						)
+					})
+					.collect(),
+				Err(e) => vec![(
+					e.to_string(),
+					self.theme.diff_line(DiffLineType::Header, false),
+				)],
+			};
+		}
+	}
+
+	pub fn open(
+		&mut self,
+		files: &[TreeFile],
+		revision: Option<CommitId>,
+	) -> Result<()> {
 		self.show()?;
 		self.find_text.show()?;
 		self.find_text.set_text(String::new());
@@ -138,11 +243,51 @@ impl FileFindPopup {
 		if self.files != *files {
 			self.files = files.to_owned();
 		}
+		self.head = revision.is_some()
+			&& sync::get_head(&self.repo.borrow()).ok() == revision;
+		self.revision = revision;
+		self.selected_index = None;
 		self.update_query();
 
 		Ok(())
 	}
 
+	fn draw_preview<B: Backend>(&self, f: &mut Frame<B>, area: Rect) {
+		let height = usize::from(area.height.saturating_sub(1));
+
+		let lines = self
+			.preview
+			.iter()
+			.take(height)
+			.map(|(line, style)| {
+				Spans::from(Span::styled(line.as_str(), *style))
+			})
+			.collect::<Vec<_>>();
+
+		f.render_widget(
+			Paragraph::new(lines).block(
+				Block::default()
+					.title(Span::styled(
+						strings::file_find_preview_title(
+							self.preview_is_diff,
+						),
+						self.theme.title(true),
+					))
+					.borders(Borders::TOP | Borders::LEFT)
+					.border_style(self.theme.block(false)),
+			),
+			area,
+		);
+	}
+
+	/// prunes the file tree to the hits of the current query
+	fn apply_filter(&mut self) {
+		self.queue.push(InternalEvent::FileFinderFilter(
+			self.query.clone(),
+		));
+		self.hide();
+	}
+
 	fn move_selection(&mut self, move_type: ScrollType) -> bool {
 		let new_selection = match move_type {
 			ScrollType::Up => self.selection.saturating_sub(1),
@@ -171,12 +316,21 @@ impl DrawableComponent for FileFindPopup {
 	) -> Result<()> {
 		if self.is_visible() {
 			const MAX_SIZE: (u16, u16) = (50, 20);
+			const PREVIEW_SIZE: (u16, u16) = (70, 30);
 
 			let any_hits = !self.files_filtered.is_empty();
 
-			let area = ui::centered_rect_absolute(
-				MAX_SIZE.0, MAX_SIZE.1, area,
-			);
+			let area = if any_hits {
+				ui::centered_rect_absolute(
+					MAX_SIZE.0 + PREVIEW_SIZE.0,
+					PREVIEW_SIZE.1,
+					area,
+				)
+			} else {
+				ui::centered_rect_absolute(
+					MAX_SIZE.0, MAX_SIZE.1, area,
+				)
+			};
 
 			let area = if any_hits {
 				area
@@ -197,6 +351,7 @@ impl DrawableComponent for FileFindPopup {
 			f.render_widget(
 				Block::default()
 					.borders(Borders::all())
+					.border_style(self.theme.popup_border())
 					.style(self.theme.title(true))
 					.title(Span::styled(
 						strings::POPUP_TITLE_FUZZY_FIND,
@@ -222,11 +377,24 @@ impl DrawableComponent for FileFindPopup {
 			self.find_text.draw(f, chunks[0])?;
 
 			if any_hits {
+				let chunks = Layout::default()
+					.direction(Direction::Horizontal)
+					.constraints(
+						[
+							Constraint::Length(MAX_SIZE.0),
+							Constraint::Min(0),
+						]
+						.as_ref(),
+					)
+					.split(chunks[1]);
+
+				self.draw_preview(f, chunks[1]);
+
 				let title =
 					format!("Hits: {}", self.files_filtered.len());
 
-				let height = usize::from(chunks[1].height);
-				let width = usize::from(chunks[1].width);
+				let height = usize::from(chunks[0].height);
+				let width = usize::from(chunks[0].width);
 
 				let items = self
 					.files_filtered
@@ -261,7 +429,7 @@ impl DrawableComponent for FileFindPopup {
 
 				ui::draw_list_block(
 					f,
-					chunks[1],
+					chunks[0],
 					Block::default()
 						.title(Span::styled(
 							title,
@@ -297,6 +465,12 @@ impl Component for FileFindPopup {
 				true,
 				true,
 			));
+
+			out.push(CommandInfo::new(
+				strings::commands::file_find_filter(&self.key_config),
+				true,
+				true,
+			));
 		}
 
 		visibility_blocking(self)
@@ -308,18 +482,23 @@ impl Component for FileFindPopup {
 	) -> Result<EventState> {
 		if self.is_visible() {
 			if let Event::Key(key) = event {
-				if key_match(key, self.key_config.keys.exit_popup)
-					|| key_match(key, self.key_config.keys.enter)
+				if key_match(key, self.key_config.keys().exit_popup)
+					|| key_match(key, self.key_config.keys().enter)
 				{
 					self.hide();
 				} else if key_match(
 					key,
-					self.key_config.keys.popup_down,
+					self.key_config.keys().file_find_filter,
+				) {
+					self.apply_filter();
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_down,
 				) {
 					self.move_selection(ScrollType::Down);
 				} else if key_match(
 					key,
-					self.key_config.keys.popup_up,
+					self.key_config.keys().popup_up,
 				) {
 					self.move_selection(ScrollType::Up);
 				}
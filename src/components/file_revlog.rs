@@ -1,5 +1,8 @@
 use super::{utils::logitems::ItemBatch, SharedOptions};
-use super::{visibility_blocking, BlameFileOpen, InspectCommitOpen};
+use super::{
+	visibility_blocking, BlameFileOpen, FileViewOpen,
+	InspectCommitOpen,
+};
 use crate::keys::key_match;
 use crate::queue::StackablePopupOpen;
 use crate::{
@@ -8,17 +11,18 @@ use crate::{
 		DiffComponent, DrawableComponent, EventState, ScrollType,
 	},
 	keys::SharedKeyConfig,
-	queue::{InternalEvent, NeedsUpdate, Queue},
-	strings,
-	ui::{draw_scrollbar, style::SharedTheme},
+	queue::{Action, InternalEvent, NeedsUpdate, Queue},
+	strings::{self, symbol},
+	ui::{draw_scrollbar, scrollbar_position, style::SharedTheme},
 };
 use anyhow::Result;
 use asyncgit::{
 	sync::{
-		diff_contains_file, get_commits_info, CommitId, RepoPathRef,
+		diff_contains_file_follow, get_commits_info, CommitId,
+		FollowedPaths, RepoPathRef,
 	},
 	AsyncDiff, AsyncGitNotification, AsyncLog, DiffParams, DiffType,
-	FetchStatus,
+	FetchStatus, StatusItemType,
 };
 use chrono::{DateTime, Local};
 use crossbeam_channel::Sender;
@@ -51,6 +55,8 @@ impl FileRevOpen {
 ///
 pub struct FileRevlogComponent {
 	git_log: Option<AsyncLog>,
+	/// paths of the file before it got renamed
+	paths: FollowedPaths,
 	git_diff: AsyncDiff,
 	theme: SharedTheme,
 	queue: Queue,
@@ -59,6 +65,11 @@ pub struct FileRevlogComponent {
 	visible: bool,
 	repo_path: RepoPathRef,
 	open_request: Option<FileRevOpen>,
+	/// previewing what restoring the selected revision would change,
+	/// `true` when restoring into the stage
+	restore_preview: Option<bool>,
+	/// revisions to diff against each other
+	marked: Vec<CommitId>,
 	table_state: std::cell::Cell<TableState>,
 	items: ItemBatch,
 	count_total: usize,
@@ -66,6 +77,8 @@ pub struct FileRevlogComponent {
 	options: SharedOptions,
 	current_width: std::cell::Cell<usize>,
 	current_height: std::cell::Cell<usize>,
+	/// where the commit table was drawn last
+	area: std::cell::Cell<Rect>,
 }
 
 impl FileRevlogComponent {
@@ -90,6 +103,7 @@ impl FileRevlogComponent {
 				true,
 			),
 			git_log: None,
+			paths: FollowedPaths::default(),
 			git_diff: AsyncDiff::new(
 				repo_path.borrow().clone(),
 				sender,
@@ -97,12 +111,15 @@ impl FileRevlogComponent {
 			visible: false,
 			repo_path: repo_path.clone(),
 			open_request: None,
+			restore_preview: None,
+			marked: Vec::with_capacity(2),
 			table_state: std::cell::Cell::new(TableState::default()),
 			items: ItemBatch::default(),
 			count_total: 0,
 			key_config,
 			current_width: std::cell::Cell::new(0),
 			current_height: std::cell::Cell::new(0),
+			area: std::cell::Cell::new(Rect::default()),
 			options,
 		}
 	}
@@ -114,11 +131,12 @@ impl FileRevlogComponent {
 	///
 	pub fn open(&mut self, open_request: FileRevOpen) -> Result<()> {
 		self.open_request = Some(open_request.clone());
+		self.restore_preview = None;
+		self.marked.clear();
 
-		let filter = diff_contains_file(
-			self.repo_path.borrow().clone(),
-			open_request.file_path,
-		);
+		let (filter, paths) =
+			diff_contains_file_follow(open_request.file_path);
+		self.paths = paths;
 		self.git_log = Some(AsyncLog::new(
 			self.repo_path.borrow().clone(),
 			&self.sender,
@@ -186,33 +204,25 @@ impl FileRevlogComponent {
 
 	pub fn update_diff(&mut self) -> Result<()> {
 		if self.is_visible() {
-			if let Some(commit_id) = self.selected_commit() {
-				if let Some(open_request) = &self.open_request {
-					let diff_params = DiffParams {
-						path: open_request.file_path.clone(),
-						diff_type: DiffType::Commit(commit_id),
-						options: self.options.borrow().diff,
-					};
-
-					if let Some((params, last)) =
-						self.git_diff.last()?
-					{
-						if params == diff_params {
-							self.diff.update(
-								open_request.file_path.to_string(),
-								false,
-								last,
-							);
-
-							return Ok(());
-						}
+			if let Some((file_path, diff_type)) = self.diff_type() {
+				let diff_params = DiffParams {
+					path: file_path.clone(),
+					diff_type,
+					options: self.options.borrow().diff,
+				};
+
+				if let Some((params, last)) = self.git_diff.last()? {
+					if params == diff_params {
+						self.diff.update(file_path, false, last);
+
+						return Ok(());
 					}
+				}
 
-					self.git_diff.request(diff_params)?;
-					self.diff.clear(true);
+				self.git_diff.request(diff_params)?;
+				self.diff.clear(true);
 
-					return Ok(());
-				}
+				return Ok(());
 			}
 
 			self.diff.clear(false);
@@ -221,6 +231,74 @@ impl FileRevlogComponent {
 		Ok(())
 	}
 
+	/// the diff shows the two marked revisions against each other,
+	/// otherwise the selected one (or what restoring it would change)
+	fn diff_type(&self) -> Option<(String, DiffType)> {
+		if let [first, second] = self.marked[..] {
+			// the file is named like in the newer one of both
+			let position = |id: CommitId| {
+				self.items.iter().position(|entry| entry.id == id)
+			};
+			let newer = if position(second) < position(first) {
+				second
+			} else {
+				first
+			};
+
+			return self.path_at(newer).map(|file_path| {
+				(file_path, DiffType::Commits((first, second)))
+			});
+		}
+
+		let commit_id = self.selected_commit()?;
+		let diff_type = self.restore_preview.map_or(
+			DiffType::Commit(commit_id),
+			|stage| DiffType::Restore {
+				id: commit_id,
+				stage,
+			},
+		);
+
+		self.path_at(commit_id)
+			.map(|file_path| (file_path, diff_type))
+	}
+
+	/// opens the file as of the selected revision
+	fn view_file(&mut self) {
+		if let Some(commit_id) = self.selected_commit() {
+			if let Some(file_path) = self.path_at(commit_id) {
+				self.hide_stacked(true);
+				self.queue.push(InternalEvent::OpenPopup(
+					StackablePopupOpen::FileView(FileViewOpen {
+						file_path,
+						commit_id,
+						selection: None,
+					}),
+				));
+			}
+		}
+	}
+
+	/// marks up to two revisions to compare, marking another one
+	/// replaces the one marked first
+	fn mark(&mut self) -> Result<()> {
+		if let Some(commit_id) = self.selected_commit() {
+			if self.marked.contains(&commit_id) {
+				self.marked.retain(|marked| marked != &commit_id);
+			} else {
+				if self.marked.len() == 2 {
+					self.marked.remove(0);
+				}
+				self.marked.push(commit_id);
+			}
+
+			self.restore_preview = None;
+			self.update_diff()?;
+		}
+
+		Ok(())
+	}
+
 	fn fetch_commits(&mut self) -> Result<()> {
 		if let Some(git_log) = &mut self.git_log {
 			let table_state = self.table_state.take();
@@ -260,6 +338,55 @@ impl FileRevlogComponent {
 		commit_id
 	}
 
+	/// previews restoring the selected revision first and asks to
+	/// confirm it when pressed again
+	fn restore(&mut self, stage: bool) -> Result<()> {
+		if let Some(commit_id) = self.selected_commit() {
+			if self.restore_preview == Some(stage) {
+				if let Some(path) = self.path_at(commit_id) {
+					self.queue.push(InternalEvent::ConfirmAction(
+						Action::RestoreFile {
+							path,
+							commit_id,
+							stage,
+						},
+					));
+				}
+			} else {
+				self.restore_preview = Some(stage);
+				self.marked.clear();
+				self.update_diff()?;
+			}
+		}
+
+		Ok(())
+	}
+
+	///
+	pub fn end_restore_preview(&mut self) -> Result<()> {
+		if self.restore_preview.take().is_some() {
+			self.update_diff()?;
+		}
+
+		Ok(())
+	}
+
+	/// path of the file in `commit_id`, which differs from the
+	/// requested one in commits before it got renamed
+	fn path_at(&self, commit_id: CommitId) -> Option<String> {
+		let followed = self.paths.lock().ok().and_then(|paths| {
+			paths
+				.get(&commit_id)
+				.map(|followed| followed.path.clone())
+		});
+
+		followed.or_else(|| {
+			self.open_request
+				.as_ref()
+				.map(|open_request| open_request.file_path.clone())
+		})
+	}
+
 	fn can_focus_diff(&self) -> bool {
 		self.selected_commit().is_some()
 	}
@@ -276,20 +403,61 @@ impl FileRevlogComponent {
 		self.open_request.as_ref().map_or(
 			"<no history available>".into(),
 			|open_request| {
-				strings::file_log_title(
+				let title = strings::file_log_title(
 					&open_request.file_path,
 					selected,
 					revisions,
-				)
+				);
+
+				if let [first, second] = self.marked[..] {
+					format!(
+						"{}{}",
+						title,
+						strings::file_log_compare(
+							&first.get_short_string(),
+							&second.get_short_string()
+						)
+					)
+				} else if let Some(stage) = self.restore_preview {
+					format!(
+						"{}{}",
+						title,
+						strings::file_log_restore_preview(stage)
+					)
+				} else {
+					title
+				}
 			},
 		)
 	}
 
 	fn get_rows(&self, now: DateTime<Local>) -> Vec<Row> {
+		let paths = self.paths.lock().ok();
+
 		self.items
 			.iter()
 			.map(|entry| {
-				let spans = Spans::from(vec![
+				let renamed_from = paths.as_ref().and_then(|paths| {
+					paths.get(&entry.id).and_then(|followed| {
+						followed.renamed_from.clone()
+					})
+				});
+
+				let mut spans = Spans::default();
+
+				if !self.marked.is_empty() {
+					spans.0.push(Span::styled(
+						if self.marked.contains(&entry.id) {
+							symbol::CHECKMARK
+						} else {
+							symbol::EMPTY_SPACE
+						},
+						self.theme.log_marker(false),
+					));
+					spans.0.push(Span::raw(" "));
+				}
+
+				spans.0.extend(vec![
 					Span::styled(
 						entry.hash_short.to_string(),
 						self.theme.commit_hash(false),
@@ -306,10 +474,26 @@ impl FileRevlogComponent {
 					),
 				]);
 
+				let change = if let Some(renamed_from) = renamed_from
+				{
+					spans.0.push(Span::styled(
+						strings::file_log_renamed_from(&renamed_from),
+						self.theme
+							.item(StatusItemType::Renamed, false),
+					));
+
+					Cell::from("R").style(
+						self.theme
+							.item(StatusItemType::Renamed, false),
+					)
+				} else {
+					Cell::from("")
+				};
+
 				let mut text = Text::from(spans);
 				text.extend(Text::raw(entry.msg.to_string()));
 
-				let cells = vec![Cell::from(""), Cell::from(text)];
+				let cells = vec![change, Cell::from(text)];
 
 				Row::new(cells).height(2)
 			})
@@ -323,9 +507,10 @@ impl FileRevlogComponent {
 	}
 
 	fn move_selection(&mut self, scroll_type: ScrollType) -> bool {
-		let mut table_state = self.table_state.take();
-
+		let table_state = self.table_state.take();
 		let old_selection = table_state.selected().unwrap_or(0);
+		self.table_state.set(table_state);
+
 		let max_selection = self.get_max_selection();
 		let height_in_items = self.current_height.get() / 2;
 
@@ -343,13 +528,21 @@ impl FileRevlogComponent {
 				.min(max_selection),
 		};
 
-		let needs_update = new_selection != old_selection;
+		self.set_selection(new_selection)
+	}
+
+	fn set_selection(&mut self, selection: usize) -> bool {
+		let mut table_state = self.table_state.take();
+
+		let selection = selection.min(self.get_max_selection());
+		let needs_update = table_state.selected() != Some(selection);
 
 		if needs_update {
+			self.restore_preview = None;
 			self.queue.push(InternalEvent::Update(NeedsUpdate::DIFF));
 		}
 
-		table_state.select(Some(new_selection));
+		table_state.select(Some(selection));
 		self.table_state.set(table_state);
 
 		needs_update
@@ -405,6 +598,7 @@ impl FileRevlogComponent {
 		f.render_widget(Clear, area);
 		f.render_stateful_widget(table, area, &mut table_state);
 
+		self.area.set(area);
 		draw_scrollbar(
 			f,
 			area,
@@ -482,23 +676,61 @@ impl Component for FileRevlogComponent {
 				return Ok(EventState::Consumed);
 			}
 
+			if let Event::Mouse(mouse) = event {
+				if let Some(pos) = scrollbar_position(
+					self.area.get(),
+					self.count_total,
+					*mouse,
+				) {
+					self.set_selection(pos);
+				}
+			}
+
 			if let Event::Key(key) = event {
-				if key_match(key, self.key_config.keys.exit_popup) {
-					self.hide_stacked(false);
+				if key_match(key, self.key_config.keys().exit_popup) {
+					if self.restore_preview.is_some() {
+						self.end_restore_preview()?;
+					} else if !self.marked.is_empty() {
+						self.marked.clear();
+						self.update_diff()?;
+					} else {
+						self.hide_stacked(false);
+					}
+				} else if key_match(
+					key,
+					self.key_config.keys().view_file,
+				) {
+					self.view_file();
+				} else if key_match(
+					key,
+					self.key_config.keys().log_mark_commit,
+				) {
+					self.mark()?;
 				} else if key_match(
 					key,
-					self.key_config.keys.focus_right,
+					self.key_config.keys().file_history_restore,
+				) {
+					self.restore(false)?;
+				} else if key_match(
+					key,
+					self.key_config.keys().file_history_restore_stage,
+				) {
+					self.restore(true)?;
+				} else if key_match(
+					key,
+					self.key_config.keys().focus_right,
 				) && self.can_focus_diff()
 				{
 					self.diff.focus(true);
 				} else if key_match(
 					key,
-					self.key_config.keys.focus_left,
+					self.key_config.keys().focus_left,
 				) {
 					if self.diff.focused() {
 						self.diff.focus(false);
 					}
-				} else if key_match(key, self.key_config.keys.enter) {
+				} else if key_match(key, self.key_config.keys().enter)
+				{
 					if let Some(commit_id) = self.selected_commit() {
 						self.hide_stacked(true);
 						self.queue.push(InternalEvent::OpenPopup(
@@ -507,51 +739,61 @@ impl Component for FileRevlogComponent {
 							),
 						));
 					};
-				} else if key_match(key, self.key_config.keys.blame) {
-					if let Some(open_request) =
-						self.open_request.clone()
-					{
+				} else if key_match(key, self.key_config.keys().blame)
+				{
+					let commit_id = self.selected_commit();
+					if let Some(file_path) = commit_id
+						.and_then(|commit_id| self.path_at(commit_id))
+						.or_else(|| {
+							self.open_request
+								.as_ref()
+								.map(|open| open.file_path.clone())
+						}) {
 						self.hide_stacked(true);
 						self.queue.push(InternalEvent::OpenPopup(
 							StackablePopupOpen::BlameFile(
 								BlameFileOpen {
-									file_path: open_request.file_path,
-									commit_id: self.selected_commit(),
+									file_path,
+									commit_id,
 									selection: None,
 								},
 							),
 						));
 					}
-				} else if key_match(key, self.key_config.keys.move_up)
-				{
+				} else if key_match(
+					key,
+					self.key_config.keys().move_up,
+				) {
 					self.move_selection(ScrollType::Up);
 				} else if key_match(
 					key,
-					self.key_config.keys.move_down,
+					self.key_config.keys().move_down,
 				) {
 					self.move_selection(ScrollType::Down);
 				} else if key_match(
 					key,
-					self.key_config.keys.shift_up,
+					self.key_config.keys().shift_up,
 				) || key_match(
 					key,
-					self.key_config.keys.home,
+					self.key_config.keys().home,
 				) {
 					self.move_selection(ScrollType::Home);
 				} else if key_match(
 					key,
-					self.key_config.keys.shift_down,
+					self.key_config.keys().shift_down,
 				) || key_match(
 					key,
-					self.key_config.keys.end,
+					self.key_config.keys().end,
 				) {
 					self.move_selection(ScrollType::End);
-				} else if key_match(key, self.key_config.keys.page_up)
-				{
+				} else if key_match(
+					key,
+					self.key_config.keys().page_up,
+				) {
 					self.move_selection(ScrollType::PageUp);
 				} else if key_match(
 					key,
-					self.key_config.keys.page_down,
+					self.key_config.keys().page_down,
 				) {
 					self.move_selection(ScrollType::PageDown);
 				}
@@ -595,6 +837,49 @@ impl Component for FileRevlogComponent {
 				)
 				.order(1),
 			);
+			out.push(
+				CommandInfo::new(
+					strings::commands::view_file(&self.key_config),
+					self.selected_commit().is_some(),
+					true,
+				)
+				.order(1),
+			);
+			out.push(
+				CommandInfo::new(
+					strings::commands::commit_list_mark(
+						&self.key_config,
+						self.selected_commit().map_or(false, |id| {
+							self.marked.contains(&id)
+						}),
+					),
+					self.selected_commit().is_some(),
+					true,
+				)
+				.order(1),
+			);
+			out.push(
+				CommandInfo::new(
+					strings::commands::file_history_restore(
+						&self.key_config,
+						self.restore_preview == Some(false),
+					),
+					self.selected_commit().is_some(),
+					true,
+				)
+				.order(1),
+			);
+			out.push(
+				CommandInfo::new(
+					strings::commands::file_history_restore_stage(
+						&self.key_config,
+						self.restore_preview == Some(true),
+					),
+					self.selected_commit().is_some(),
+					true,
+				)
+				.order(1),
+			);
 
 			out.push(CommandInfo::new(
 				strings::commands::diff_focus_right(&self.key_config),
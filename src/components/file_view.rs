@@ -0,0 +1,503 @@
+use super::{
+	textinput::TextInputComponent,
+	utils::scroll_vertical::VerticalScroll, visibility_blocking,
+	CommandBlocking, CommandInfo, Component, DrawableComponent,
+	EventState,
+};
+use crate::{
+	keys::{key_match, SharedKeyConfig},
+	queue::{InternalEvent, Queue, StackablePopupOpen},
+	string_utils::tabs_to_spaces,
+	strings,
+	ui::{self, common_nav, style::SharedTheme, AsyncSyntaxJob},
+	AsyncAppNotification, AsyncNotification, SyntaxHighlightProgress,
+};
+use anyhow::Result;
+use asyncgit::{
+	asyncjob::AsyncSingleJob,
+	sync::{self, CommitId, RepoPathRef},
+};
+use crossbeam_channel::Sender;
+use crossterm::event::Event;
+use filetreelist::MoveSelection;
+use std::{cell::Cell, path::Path};
+use tui::{
+	backend::Backend,
+	layout::Rect,
+	symbols::line::VERTICAL,
+	text::{Span, Spans, Text},
+	widgets::{Block, Borders, Clear, Paragraph},
+	Frame,
+};
+
+#[derive(Clone, Debug)]
+pub struct FileViewOpen {
+	pub file_path: String,
+	pub commit_id: CommitId,
+	pub selection: Option<usize>,
+}
+
+/// read-only view of a file as of a commit
+pub struct FileViewPopup {
+	repo: RepoPathRef,
+	queue: Queue,
+	theme: SharedTheme,
+	key_config: SharedKeyConfig,
+	visible: bool,
+	open_request: Option<FileViewOpen>,
+	lines: Vec<String>,
+	syntax: Option<ui::SyntaxText>,
+	async_highlighting: AsyncSingleJob<AsyncSyntaxJob>,
+	selection: usize,
+	scroll: VerticalScroll,
+	current_height: Cell<usize>,
+	search_input: TextInputComponent,
+	/// lines matching the last search
+	matches: Vec<usize>,
+}
+
+impl FileViewPopup {
+	///
+	pub fn new(
+		repo: &RepoPathRef,
+		queue: &Queue,
+		sender: &Sender<AsyncAppNotification>,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			repo: repo.clone(),
+			queue: queue.clone(),
+			search_input: TextInputComponent::new(
+				theme.clone(),
+				key_config.clone(),
+				&strings::file_view_search_title(),
+				&strings::file_view_search_msg(),
+				false,
+			),
+			theme,
+			key_config,
+			visible: false,
+			open_request: None,
+			lines: Vec::new(),
+			syntax: None,
+			async_highlighting: AsyncSingleJob::new(sender.clone()),
+			selection: 0,
+			scroll: VerticalScroll::new(),
+			current_height: Cell::new(0),
+			matches: Vec::new(),
+		}
+	}
+
+	///
+	pub fn open(&mut self, open: FileViewOpen) -> Result<()> {
+		let content = sync::commit_file_content(
+			&self.repo.borrow(),
+			open.commit_id,
+			&open.file_path,
+		);
+
+		self.lines = match content {
+			Ok(content) => {
+				let content = tabs_to_spaces(content);
+				self.async_highlighting.spawn(AsyncSyntaxJob::new(
+					content.clone(),
+					open.file_path.clone(),
+				));
+				content.lines().map(String::from).collect()
+			}
+			Err(e) => vec![format!("error loading file: {}", e)],
+		};
+
+		self.syntax = None;
+		self.matches.clear();
+		self.search_input.clear();
+		self.selection = open
+			.selection
+			.unwrap_or_default()
+			.min(self.lines.len().saturating_sub(1));
+		self.open_request = Some(open);
+		self.show()?;
+
+		Ok(())
+	}
+
+	///
+	pub fn update(&mut self, ev: AsyncNotification) {
+		if ev
+			== AsyncNotification::App(
+				AsyncAppNotification::SyntaxHighlighting(
+					SyntaxHighlightProgress::Done,
+				),
+			) {
+			if let Some(syntax) = self
+				.async_highlighting
+				.take_last()
+				.and_then(|job| job.result())
+			{
+				let current_file = self.open_request.as_ref().map_or(
+					false,
+					|open| {
+						syntax.path() == Path::new(&open.file_path)
+					},
+				);
+
+				if current_file {
+					self.syntax = Some(syntax);
+				}
+			}
+		}
+	}
+
+	///
+	pub fn any_work_pending(&self) -> bool {
+		self.async_highlighting.is_pending()
+	}
+
+	fn hide_stacked(&mut self, stack: bool) {
+		self.hide();
+
+		if stack {
+			if let Some(open) = self.open_request.clone() {
+				self.queue.push(InternalEvent::PopupStackPush(
+					StackablePopupOpen::FileView(FileViewOpen {
+						selection: Some(self.selection),
+						..open
+					}),
+				));
+			}
+		} else {
+			self.queue.push(InternalEvent::PopupStackPop);
+		}
+	}
+
+	fn move_selection(&mut self, nav: MoveSelection) -> bool {
+		let max = self.lines.len().saturating_sub(1);
+		let page = self.current_height.get().saturating_sub(1);
+
+		let new_selection = match nav {
+			MoveSelection::Up => self.selection.saturating_sub(1),
+			MoveSelection::Down => self.selection.saturating_add(1),
+			MoveSelection::Top => 0,
+			MoveSelection::End => max,
+			MoveSelection::PageUp => {
+				self.selection.saturating_sub(page)
+			}
+			MoveSelection::PageDown => {
+				self.selection.saturating_add(page)
+			}
+			MoveSelection::Left | MoveSelection::Right => {
+				self.selection
+			}
+		}
+		.min(max);
+
+		let changed = new_selection != self.selection;
+		self.selection = new_selection;
+
+		changed
+	}
+
+	/// finds the lines containing the search text, ignoring case
+	fn search(&mut self) {
+		let needle = self.search_input.get_text().to_lowercase();
+
+		self.matches = if needle.is_empty() {
+			Vec::new()
+		} else {
+			self.lines
+				.iter()
+				.enumerate()
+				.filter(|(_, line)| {
+					line.to_lowercase().contains(&needle)
+				})
+				.map(|(index, _)| index)
+				.collect()
+		};
+
+		self.search_input.hide();
+
+		if !self.matches.contains(&self.selection) {
+			self.jump_to_match(true);
+		}
+	}
+
+	/// selects the next (or previous) matching line, wrapping around
+	fn jump_to_match(&mut self, forward: bool) {
+		let next = if forward {
+			self.matches
+				.iter()
+				.find(|line| **line > self.selection)
+				.or_else(|| self.matches.first())
+		} else {
+			self.matches
+				.iter()
+				.rev()
+				.find(|line| **line < self.selection)
+				.or_else(|| self.matches.last())
+		};
+
+		if let Some(next) = next {
+			self.selection = *next;
+		}
+	}
+
+	fn get_title(&self) -> String {
+		let title = self.open_request.as_ref().map_or_else(
+			String::new,
+			|open| {
+				strings::file_view_title(
+					&open.file_path,
+					&open.commit_id.get_short_string(),
+				)
+			},
+		);
+
+		let search = self.search_input.get_text();
+		if search.is_empty() || self.search_input.is_visible() {
+			title
+		} else {
+			let current = self
+				.matches
+				.iter()
+				.position(|line| *line == self.selection)
+				.map(|index| index + 1);
+
+			format!(
+				"{}{}",
+				title,
+				strings::file_view_matches(
+					search,
+					current,
+					self.matches.len()
+				)
+			)
+		}
+	}
+
+	fn get_lines(&self, top: usize, height: usize) -> Vec<Spans> {
+		let highlighted = self
+			.syntax
+			.as_ref()
+			.map(|syntax| Text::from(syntax).lines);
+		let number_width = self.lines.len().to_string().len();
+
+		self.lines
+			.iter()
+			.enumerate()
+			.skip(top)
+			.take(height)
+			.map(|(index, line)| {
+				let selected = index == self.selection;
+				let matched =
+					self.matches.binary_search(&index).is_ok();
+
+				let mut spans = highlighted
+					.as_ref()
+					.and_then(|lines| lines.get(index).cloned())
+					.unwrap_or_else(|| Spans::from(line.as_str()));
+
+				if selected {
+					for span in &mut spans.0 {
+						span.style = span
+							.style
+							.patch(self.theme.text(false, true));
+					}
+				}
+
+				spans.0.insert(
+					0,
+					Span::styled(
+						format!(
+							"{:>width$}{}",
+							index + 1,
+							VERTICAL,
+							width = number_width
+						),
+						if matched {
+							self.theme.text(true, true)
+						} else {
+							self.theme.text(false, false)
+						},
+					),
+				);
+
+				spans
+			})
+			.collect()
+	}
+}
+
+impl DrawableComponent for FileViewPopup {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		area: Rect,
+	) -> Result<()> {
+		if self.is_visible() {
+			let height = usize::from(area.height.saturating_sub(2));
+			let top = self.scroll.update(
+				self.selection,
+				self.lines.len(),
+				height,
+			);
+
+			let paragraph =
+				Paragraph::new(self.get_lines(top, height)).block(
+					Block::default()
+						.borders(Borders::ALL)
+						.title(Span::styled(
+							self.get_title(),
+							self.theme.title(true),
+						))
+						.border_style(self.theme.block(true)),
+				);
+
+			f.render_widget(Clear, area);
+			f.render_widget(paragraph, area);
+
+			self.scroll.draw(f, area, &self.theme);
+			self.current_height.set(height);
+
+			self.search_input.draw(f, area)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for FileViewPopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			if self.search_input.is_visible() {
+				self.search_input.commands(out, force_all);
+				return visibility_blocking(self);
+			}
+
+			out.push(
+				CommandInfo::new(
+					strings::commands::close_popup(&self.key_config),
+					true,
+					true,
+				)
+				.order(1),
+			);
+			out.push(
+				CommandInfo::new(
+					strings::commands::scroll(&self.key_config),
+					true,
+					true,
+				)
+				.order(1),
+			);
+			out.push(
+				CommandInfo::new(
+					strings::commands::file_view_search(
+						&self.key_config,
+					),
+					true,
+					true,
+				)
+				.order(1),
+			);
+			out.push(
+				CommandInfo::new(
+					strings::commands::file_view_search_next(
+						&self.key_config,
+					),
+					!self.matches.is_empty(),
+					true,
+				)
+				.order(1),
+			);
+			out.push(CommandInfo::new(
+				strings::commands::copy_path(&self.key_config),
+				true,
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, ev: &Event) -> Result<EventState> {
+		if self.is_visible() {
+			if self.search_input.is_visible() {
+				if self.search_input.event(ev)?.is_consumed() {
+					return Ok(EventState::Consumed);
+				}
+
+				if let Event::Key(key) = ev {
+					if key_match(key, self.key_config.keys().enter) {
+						self.search();
+					}
+				}
+
+				return Ok(EventState::Consumed);
+			}
+
+			if let Event::Mouse(mouse) = ev {
+				if let Some(top) = self.scroll.click(*mouse) {
+					self.selection = top;
+				}
+			}
+
+			if let Event::Key(key) = ev {
+				if key_match(key, self.key_config.keys().exit_popup) {
+					self.hide_stacked(false);
+				} else if key_match(
+					key,
+					self.key_config.keys().search,
+				) {
+					self.search_input.show()?;
+				} else if key_match(
+					key,
+					self.key_config.keys().search_next,
+				) {
+					self.jump_to_match(true);
+				} else if key_match(
+					key,
+					self.key_config.keys().search_prev,
+				) {
+					self.jump_to_match(false);
+				} else if key_match(
+					key,
+					self.key_config.keys().copy_path,
+				) {
+					if let Some(open) = &self.open_request {
+						self.queue.push(InternalEvent::CopyPath(
+							open.file_path.clone(),
+							Some(self.selection),
+						));
+					}
+				} else if let Some(nav) =
+					common_nav(key, &self.key_config)
+				{
+					self.move_selection(nav);
+				}
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+
+		Ok(())
+	}
+}
@@ -0,0 +1,479 @@
+use super::{
+	visibility_blocking, BlameFileOpen, CommandBlocking, CommandInfo,
+	Component, DrawableComponent, EventState, FileViewOpen,
+	ScrollType, TextInputComponent,
+};
+use crate::{
+	keys::{key_match, SharedKeyConfig},
+	queue::{InternalEvent, Queue, StackablePopupOpen},
+	strings,
+	ui::{self, style::SharedTheme},
+};
+use anyhow::Result;
+use asyncgit::{
+	asyncjob::AsyncSingleJob,
+	sync::{CommitId, GrepMatch, RepoPathRef},
+	AsyncGitNotification, AsyncGrepJob,
+};
+use crossbeam_channel::Sender;
+use crossterm::event::Event;
+use std::cell::Cell;
+use tui::{
+	backend::Backend,
+	layout::{Constraint, Direction, Layout, Margin, Rect},
+	text::{Span, Spans},
+	widgets::{Block, Borders, Clear},
+	Frame,
+};
+
+/// search stops after this many matching lines
+const MAX_MATCHES: usize = 1000;
+
+#[derive(PartialEq, Eq)]
+enum Focus {
+	Input,
+	Results,
+}
+
+/// searches file contents of the work tree or a revision, like
+/// `git grep`, listing the matches grouped by file
+pub struct GrepPopup {
+	repo: RepoPathRef,
+	queue: Queue,
+	visible: bool,
+	find_text: TextInputComponent,
+	theme: SharedTheme,
+	key_config: SharedKeyConfig,
+	/// revision to search, the work tree if `None`
+	commit: Option<CommitId>,
+	async_grep: AsyncSingleJob<AsyncGrepJob>,
+	/// pattern of the last search started
+	pattern: String,
+	matches: Vec<GrepMatch>,
+	selection: usize,
+	scroll_top: Cell<usize>,
+	/// where the results were drawn last
+	results_area: Cell<Rect>,
+	focus: Focus,
+}
+
+impl GrepPopup {
+	///
+	pub fn new(
+		repo: RepoPathRef,
+		queue: &Queue,
+		sender: &Sender<AsyncGitNotification>,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		let mut find_text = TextInputComponent::new(
+			theme.clone(),
+			key_config.clone(),
+			"",
+			&strings::grep_popup_msg(),
+			false,
+		);
+		find_text.embed();
+
+		Self {
+			repo,
+			queue: queue.clone(),
+			visible: false,
+			find_text,
+			theme,
+			key_config,
+			commit: None,
+			async_grep: AsyncSingleJob::new(sender.clone()),
+			pattern: String::new(),
+			matches: Vec::new(),
+			selection: 0,
+			scroll_top: Cell::new(0),
+			results_area: Cell::new(Rect::default()),
+			focus: Focus::Input,
+		}
+	}
+
+	/// keeps the previous search when reopened for the same revision
+	pub fn open(&mut self, commit: Option<CommitId>) -> Result<()> {
+		if commit != self.commit {
+			self.commit = commit;
+			self.pattern.clear();
+			self.matches.clear();
+			self.find_text.set_text(String::new());
+		}
+
+		self.focus = if self.matches.is_empty() {
+			Focus::Input
+		} else {
+			Focus::Results
+		};
+		self.find_text.show()?;
+		self.show()?;
+
+		Ok(())
+	}
+
+	///
+	pub fn any_work_pending(&self) -> bool {
+		self.async_grep.is_pending()
+	}
+
+	///
+	pub fn update_git(&mut self, ev: AsyncGitNotification) {
+		if ev != AsyncGitNotification::Grep {
+			return;
+		}
+
+		if let Some(job) = self.async_grep.take_last() {
+			if job.pattern() != self.pattern {
+				return;
+			}
+
+			match job.result() {
+				Some(Ok(matches)) => {
+					self.matches = matches;
+					self.selection = 0;
+					self.scroll_top.set(0);
+					if !self.matches.is_empty() {
+						self.focus = Focus::Results;
+					}
+				}
+				Some(Err(e)) => {
+					log::error!("grep: {}", e);
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						format!("search failed:\n{}", e),
+					));
+				}
+				None => (),
+			}
+		}
+	}
+
+	fn search(&mut self) {
+		let pattern = self.find_text.get_text().to_string();
+		if pattern.is_empty() {
+			return;
+		}
+
+		self.pattern.clone_from(&pattern);
+		self.matches.clear();
+		self.async_grep.spawn(AsyncGrepJob::new(
+			self.repo.borrow().clone(),
+			pattern,
+			self.commit,
+			MAX_MATCHES,
+		));
+	}
+
+	fn move_selection(&mut self, move_type: ScrollType) {
+		let new_selection = match move_type {
+			ScrollType::Up => self.selection.saturating_sub(1),
+			ScrollType::Down => self.selection.saturating_add(1),
+			ScrollType::Home => 0,
+			ScrollType::End => self.matches.len(),
+			_ => self.selection,
+		};
+
+		self.selection =
+			new_selection.min(self.matches.len().saturating_sub(1));
+	}
+
+	/// opens the file viewer for a revision, blame for the work tree
+	fn open_selected(&mut self, blame: bool) {
+		if let Some(hit) = self.matches.get(self.selection) {
+			let popup = match self.commit {
+				Some(commit_id) if !blame => {
+					StackablePopupOpen::FileView(FileViewOpen {
+						file_path: hit.path.clone(),
+						commit_id,
+						selection: Some(hit.line),
+					})
+				}
+				commit_id => {
+					StackablePopupOpen::BlameFile(BlameFileOpen {
+						file_path: hit.path.clone(),
+						commit_id,
+						selection: Some(hit.line),
+					})
+				}
+			};
+
+			self.hide();
+			self.queue.push(InternalEvent::PopupStackPush(
+				StackablePopupOpen::Grep(self.commit),
+			));
+			self.queue.push(InternalEvent::OpenPopup(popup));
+		}
+	}
+
+	/// one row per file followed by its matching lines, together with
+	/// the index of the match a row shows
+	fn get_rows(&self) -> Vec<(Option<usize>, Spans)> {
+		let mut rows = Vec::new();
+		let mut current_file = None;
+
+		for (index, hit) in self.matches.iter().enumerate() {
+			if current_file != Some(&hit.path) {
+				current_file = Some(&hit.path);
+				rows.push((
+					None,
+					Spans::from(Span::styled(
+						hit.path.as_str(),
+						self.theme.title(true),
+					)),
+				));
+			}
+
+			let selected = index == self.selection
+				&& self.focus == Focus::Results;
+
+			rows.push((
+				Some(index),
+				Spans::from(vec![
+					Span::styled(
+						format!("{:>6} ", hit.line + 1),
+						self.theme.text(false, selected),
+					),
+					Span::styled(
+						hit.text.as_str(),
+						self.theme.text(true, selected),
+					),
+				]),
+			));
+		}
+
+		rows
+	}
+
+	fn get_title(&self) -> String {
+		if self.any_work_pending() {
+			strings::grep_searching()
+		} else {
+			strings::grep_results(
+				self.matches.len(),
+				self.matches.len() >= MAX_MATCHES,
+			)
+		}
+	}
+}
+
+impl DrawableComponent for GrepPopup {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		area: Rect,
+	) -> Result<()> {
+		if self.is_visible() {
+			let area = ui::centered_rect(90, 80, area);
+
+			f.render_widget(Clear, area);
+			f.render_widget(
+				Block::default()
+					.borders(Borders::all())
+					.border_style(self.theme.popup_border())
+					.style(self.theme.title(true))
+					.title(Span::styled(
+						strings::grep_popup_title(
+							self.commit
+								.map(|c| c.get_short_string())
+								.as_deref(),
+						),
+						self.theme.title(true),
+					)),
+				area,
+			);
+
+			let chunks = Layout::default()
+				.direction(Direction::Vertical)
+				.constraints(
+					[
+						Constraint::Length(1),
+						Constraint::Percentage(100),
+					]
+					.as_ref(),
+				)
+				.split(area.inner(&Margin {
+					horizontal: 1,
+					vertical: 1,
+				}));
+
+			self.find_text.draw(f, chunks[0])?;
+
+			let rows = self.get_rows();
+			let height =
+				usize::from(chunks[1].height.saturating_sub(1));
+			let selected_row = rows
+				.iter()
+				.position(|(index, _)| *index == Some(self.selection))
+				.unwrap_or_default();
+			self.scroll_top.set(ui::calc_scroll_top(
+				self.scroll_top.get(),
+				height,
+				selected_row,
+			));
+
+			ui::draw_list_block(
+				f,
+				chunks[1],
+				Block::default()
+					.title(Span::styled(
+						self.get_title(),
+						self.theme
+							.title(self.focus == Focus::Results),
+					))
+					.borders(Borders::TOP),
+				rows.into_iter()
+					.skip(self.scroll_top.get())
+					.take(height)
+					.map(|(_, row)| row),
+			);
+
+			self.results_area.set(chunks[1]);
+			ui::draw_scrollbar(
+				f,
+				chunks[1],
+				&self.theme,
+				self.matches.len().saturating_sub(1),
+				self.selection,
+			);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for GrepPopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			out.push(
+				CommandInfo::new(
+					strings::commands::close_popup(&self.key_config),
+					true,
+					true,
+				)
+				.order(1),
+			);
+
+			let results = self.focus == Focus::Results || force_all;
+
+			out.push(CommandInfo::new(
+				strings::commands::grep_search(&self.key_config),
+				self.focus == Focus::Input
+					&& !self.find_text.get_text().is_empty(),
+				self.focus == Focus::Input || force_all,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::grep_edit(&self.key_config),
+				true,
+				results,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::grep_open(
+					&self.key_config,
+					self.commit.is_some(),
+				),
+				!self.matches.is_empty(),
+				results,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::blame_file(&self.key_config),
+				!self.matches.is_empty(),
+				results && self.commit.is_some(),
+			));
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				results,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: &Event) -> Result<EventState> {
+		if self.is_visible() {
+			if let Event::Mouse(mouse) = event {
+				if let Some(pos) = ui::scrollbar_position(
+					self.results_area.get(),
+					self.matches.len().saturating_sub(1),
+					*mouse,
+				) {
+					self.selection = pos;
+					self.focus = Focus::Results;
+				}
+			}
+
+			if let Event::Key(key) = event {
+				if key_match(key, self.key_config.keys().exit_popup) {
+					self.hide();
+					return Ok(EventState::Consumed);
+				}
+
+				if self.focus == Focus::Input {
+					if key_match(key, self.key_config.keys().enter) {
+						self.search();
+					} else if key_match(
+						key,
+						self.key_config.keys().popup_down,
+					) && !self.matches.is_empty()
+					{
+						self.focus = Focus::Results;
+					} else {
+						self.find_text.event(event)?;
+					}
+				} else if key_match(key, self.key_config.keys().enter)
+				{
+					self.open_selected(false);
+				} else if key_match(key, self.key_config.keys().blame)
+				{
+					self.open_selected(true);
+				} else if key_match(
+					key,
+					self.key_config.keys().search,
+				) {
+					self.focus = Focus::Input;
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_down,
+				) {
+					self.move_selection(ScrollType::Down);
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_up,
+				) {
+					if self.selection == 0 {
+						self.focus = Focus::Input;
+					} else {
+						self.move_selection(ScrollType::Up);
+					}
+				} else if key_match(key, self.key_config.keys().home)
+				{
+					self.move_selection(ScrollType::Home);
+				} else if key_match(key, self.key_config.keys().end) {
+					self.move_selection(ScrollType::End);
+				}
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+		Ok(())
+	}
+}
@@ -1,6 +1,6 @@
 use super::{
-	visibility_blocking, CommandBlocking, CommandInfo, Component,
-	DrawableComponent, EventState,
+	visibility_blocking, CommandBlocking, CommandInfo, CommandText,
+	Component, DrawableComponent, EventState, TextInputComponent,
 };
 use crate::{
 	keys::{key_match, SharedKeyConfig},
@@ -8,10 +8,9 @@ use crate::{
 	version::Version,
 };
 use anyhow::Result;
-use asyncgit::hash;
 use crossterm::event::Event;
 use itertools::Itertools;
-use std::{borrow::Cow, cmp, convert::TryFrom};
+use std::{borrow::Cow, cell::Cell, cmp, convert::TryFrom};
 use tui::{
 	backend::Backend,
 	layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -22,11 +21,20 @@ use tui::{
 };
 use ui::style::SharedTheme;
 
-///
+/// lists the commands grouped by the view they belong to, either
+/// the ones usable where help was opened or all of them
 pub struct HelpComponent {
 	cmds: Vec<CommandInfo>,
+	/// commands available when help was opened
+	context: Vec<CommandText>,
+	/// indices into `cmds` left after the context and filter
+	filtered: Vec<usize>,
+	show_all: bool,
+	filter: TextInputComponent,
 	visible: bool,
 	selection: u16,
+	/// where the popup was drawn last
+	area: Cell<Rect>,
 	theme: SharedTheme,
 	key_config: SharedKeyConfig,
 }
@@ -40,8 +48,6 @@ impl DrawableComponent for HelpComponent {
 		if self.visible {
 			const SIZE: (u16, u16) = (65, 24);
 			let scroll_threshold = SIZE.1 / 3;
-			let scroll =
-				self.selection.saturating_sub(scroll_threshold);
 
 			let area =
 				ui::centered_rect_absolute(SIZE.0, SIZE.1, f.size());
@@ -49,8 +55,12 @@ impl DrawableComponent for HelpComponent {
 			f.render_widget(Clear, area);
 			f.render_widget(
 				Block::default()
-					.title(strings::help_title(&self.key_config))
+					.title(strings::help_title(
+						&self.key_config,
+						self.show_all,
+					))
 					.borders(Borders::ALL)
+					.border_style(self.theme.popup_border())
 					.border_type(BorderType::Thick),
 				area,
 			);
@@ -60,16 +70,26 @@ impl DrawableComponent for HelpComponent {
 				.horizontal_margin(1)
 				.direction(Direction::Vertical)
 				.constraints(
-					[Constraint::Min(1), Constraint::Length(1)]
-						.as_ref(),
+					[
+						Constraint::Length(1),
+						Constraint::Min(1),
+						Constraint::Length(1),
+					]
+					.as_ref(),
 				)
 				.split(area);
 
+			self.filter.draw(f, chunks[0])?;
+
+			let (text, selected_line) = self.get_text();
+			let scroll =
+				selected_line.saturating_sub(scroll_threshold);
+
 			f.render_widget(
-				Paragraph::new(self.get_text())
+				Paragraph::new(text)
 					.scroll((scroll, 0))
 					.alignment(Alignment::Left),
-				chunks[0],
+				chunks[1],
 			);
 
 			f.render_widget(
@@ -78,7 +98,16 @@ impl DrawableComponent for HelpComponent {
 					Style::default(),
 				)]))
 				.alignment(Alignment::Right),
-				chunks[1],
+				chunks[2],
+			);
+
+			self.area.set(area);
+			ui::draw_scrollbar(
+				f,
+				area,
+				&self.theme,
+				self.filtered.len().saturating_sub(1),
+				usize::from(self.selection),
 			);
 		}
 
@@ -109,6 +138,15 @@ impl Component for HelpComponent {
 				true,
 				true,
 			));
+
+			out.push(CommandInfo::new(
+				strings::commands::help_toggle_context(
+					&self.key_config,
+					self.show_all,
+				),
+				true,
+				true,
+			));
 		}
 
 		if !self.visible || force_all {
@@ -127,21 +165,44 @@ impl Component for HelpComponent {
 
 	fn event(&mut self, ev: &Event) -> Result<EventState> {
 		if self.visible {
+			if let Event::Mouse(mouse) = ev {
+				if let Some(pos) = ui::scrollbar_position(
+					self.area.get(),
+					self.filtered.len().saturating_sub(1),
+					*mouse,
+				) {
+					self.selection =
+						u16::try_from(pos).unwrap_or_default();
+				}
+			}
+
 			if let Event::Key(e) = ev {
-				if key_match(e, self.key_config.keys.exit_popup) {
+				if key_match(e, self.key_config.keys().exit_popup) {
 					self.hide();
-				} else if key_match(e, self.key_config.keys.move_down)
-				{
+				} else if key_match(
+					e,
+					self.key_config.keys().popup_down,
+				) {
 					self.move_selection(true);
-				} else if key_match(e, self.key_config.keys.move_up) {
+				} else if key_match(
+					e,
+					self.key_config.keys().popup_up,
+				) {
 					self.move_selection(false);
-				} else {
+				} else if key_match(
+					e,
+					self.key_config.keys().help_toggle_context,
+				) {
+					self.show_all = !self.show_all;
+					self.update_filter();
+				} else if self.filter.event(ev)?.is_consumed() {
+					self.update_filter();
 				}
 			}
 
 			Ok(EventState::Consumed)
 		} else if let Event::Key(k) = ev {
-			if key_match(k, self.key_config.keys.open_help) {
+			if key_match(k, self.key_config.keys().open_help) {
 				self.show()?;
 				Ok(EventState::Consumed)
 			} else {
@@ -162,33 +223,100 @@ impl Component for HelpComponent {
 
 	fn show(&mut self) -> Result<()> {
 		self.visible = true;
+		self.show_all = self.context.is_empty();
+		self.filter.clear();
+		self.filter.show()?;
+		self.update_filter();
 
 		Ok(())
 	}
 }
 
 impl HelpComponent {
-	pub const fn new(
+	pub fn new(
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
 	) -> Self {
+		let mut filter = TextInputComponent::new(
+			theme.clone(),
+			key_config.clone(),
+			"",
+			&strings::help_filter_msg(),
+			false,
+		);
+		filter.embed();
+
 		Self {
 			cmds: vec![],
+			context: vec![],
+			filtered: vec![],
+			show_all: false,
+			filter,
 			visible: false,
 			selection: 0,
+			area: Cell::new(Rect {
+				x: 0,
+				y: 0,
+				width: 0,
+				height: 0,
+			}),
 			theme,
 			key_config,
 		}
 	}
-	///
+	/// all commands, kept in the order of the views they come from
 	pub fn set_cmds(&mut self, cmds: Vec<CommandInfo>) {
+		let mut groups: Vec<&'static str> = Vec::new();
+
 		self.cmds = cmds
 			.into_iter()
 			.filter(|e| !e.text.hide_help)
 			.collect::<Vec<_>>();
+
+		for c in &self.cmds {
+			if !groups.contains(&c.text.group) {
+				groups.push(c.text.group);
+			}
+		}
+
 		self.cmds.sort_by_key(|e| e.text.clone());
 		self.cmds.dedup_by_key(|e| e.text.clone());
-		self.cmds.sort_by_key(|e| hash(&e.text.group));
+		self.cmds.sort_by_key(|e| {
+			groups.iter().position(|g| *g == e.text.group)
+		});
+		self.update_filter();
+	}
+
+	/// remembers the commands available right now, to be shown when
+	/// help gets opened
+	pub fn set_context_cmds(&mut self, cmds: &[CommandInfo]) {
+		self.context = cmds
+			.iter()
+			.filter(|e| e.available)
+			.map(|e| e.text.clone())
+			.collect();
+	}
+
+	/// matches the filter against name and description, ignoring case
+	fn update_filter(&mut self) {
+		let filter = self.filter.get_text().to_lowercase();
+
+		self.filtered = self
+			.cmds
+			.iter()
+			.enumerate()
+			.filter(|(_, e)| {
+				self.show_all || self.context.contains(&e.text)
+			})
+			.filter(|(_, e)| {
+				filter.is_empty()
+					|| e.text.name.to_lowercase().contains(&filter)
+					|| e.text.desc.to_lowercase().contains(&filter)
+			})
+			.map(|(idx, _)| idx)
+			.collect();
+
+		self.selection = 0;
 	}
 
 	fn move_selection(&mut self, inc: bool) {
@@ -202,19 +330,31 @@ impl HelpComponent {
 		new_selection = cmp::max(new_selection, 0);
 
 		if let Ok(max) =
-			u16::try_from(self.cmds.len().saturating_sub(1))
+			u16::try_from(self.filtered.len().saturating_sub(1))
 		{
 			self.selection = cmp::min(new_selection, max);
 		}
 	}
 
-	fn get_text(&self) -> Vec<Spans> {
+	/// the lines together with the one of the selected command
+	fn get_text(&self) -> (Vec<Spans>, u16) {
 		let mut txt: Vec<Spans> = Vec::new();
+		let mut selected_line = 0;
+
+		if self.filtered.is_empty() {
+			txt.push(Spans::from(Span::styled(
+				Cow::from(strings::help_no_match()),
+				self.theme.text(false, false),
+			)));
+		}
 
 		let mut processed = 0_u16;
 
-		for (key, group) in
-			&self.cmds.iter().group_by(|e| e.text.group)
+		for (key, group) in &self
+			.filtered
+			.iter()
+			.filter_map(|idx| self.cmds.get(*idx))
+			.group_by(|e| e.text.group)
 		{
 			txt.push(Spans::from(Span::styled(
 				Cow::from(key.to_string()),
@@ -226,6 +366,11 @@ impl HelpComponent {
 
 				processed += 1;
 
+				if is_selected {
+					selected_line =
+						u16::try_from(txt.len()).unwrap_or_default();
+				}
+
 				txt.push(Spans::from(Span::styled(
 					Cow::from(if is_selected {
 						format!(">{}", command_info.text.name)
@@ -247,6 +392,6 @@ impl HelpComponent {
 			}
 		}
 
-		txt
+		(txt, selected_line)
 	}
 }
@@ -0,0 +1,210 @@
+use super::{
+	utils::scroll_vertical::VerticalScroll, visibility_blocking,
+	CommandBlocking, CommandInfo, Component, DrawableComponent,
+	EventState, ScrollType,
+};
+use crate::{
+	keys::{key_match, SharedKeyConfig},
+	queue::{InternalEvent, Queue},
+	strings, ui,
+};
+use anyhow::Result;
+use crossterm::event::Event;
+use tui::{
+	backend::Backend,
+	layout::{Constraint, Direction, Layout, Margin, Rect},
+	text::{Span, Spans},
+	widgets::{Block, BorderType, Borders, Clear, Paragraph},
+	Frame,
+};
+use ui::style::SharedTheme;
+
+/// shows the full output of a failed commit hook
+/// and offers to commit anyway skipping the hooks
+pub struct HookOutputPopup {
+	hook: String,
+	output: String,
+	visible: bool,
+	scroll: VerticalScroll,
+	queue: Queue,
+	theme: SharedTheme,
+	key_config: SharedKeyConfig,
+}
+
+impl HookOutputPopup {
+	///
+	pub fn new(
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			hook: String::new(),
+			output: String::new(),
+			visible: false,
+			scroll: VerticalScroll::new(),
+			queue: queue.clone(),
+			theme,
+			key_config,
+		}
+	}
+
+	///
+	pub fn open(
+		&mut self,
+		hook: String,
+		output: String,
+	) -> Result<()> {
+		self.hook = hook;
+		self.output = output;
+		self.scroll.reset();
+
+		self.show()
+	}
+}
+
+impl DrawableComponent for HookOutputPopup {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		_rect: Rect,
+	) -> Result<()> {
+		if !self.visible {
+			return Ok(());
+		}
+
+		let area = ui::centered_rect(80, 60, f.size());
+
+		f.render_widget(Clear, area);
+		f.render_widget(
+			Block::default()
+				.title(Span::styled(
+					strings::hook_failed_title(&self.hook),
+					self.theme.text_danger(),
+				))
+				.borders(Borders::ALL)
+				.border_style(self.theme.popup_border())
+				.border_type(BorderType::Thick),
+			area,
+		);
+
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.constraints(
+				[Constraint::Min(1), Constraint::Length(1)].as_ref(),
+			)
+			.split(area.inner(&Margin {
+				horizontal: 1,
+				vertical: 1,
+			}));
+
+		let height = usize::from(chunks[0].height);
+		let top = self
+			.scroll
+			.update_no_selection(self.output.lines().count(), height);
+
+		let lines = self
+			.output
+			.lines()
+			.skip(top)
+			.take(height)
+			.map(|line| Spans::from(line.replace('\t', "  ")))
+			.collect::<Vec<_>>();
+
+		f.render_widget(Paragraph::new(lines), chunks[0]);
+		self.scroll.draw(f, chunks[0], &self.theme);
+
+		f.render_widget(
+			Paragraph::new(strings::hook_skip_hint(&self.key_config))
+				.style(self.theme.text_danger()),
+			chunks[1],
+		);
+
+		Ok(())
+	}
+}
+
+impl Component for HookOutputPopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			out.push(
+				CommandInfo::new(
+					strings::commands::close_popup(&self.key_config),
+					true,
+					true,
+				)
+				.order(1),
+			);
+
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::commit_no_verify(&self.key_config),
+				true,
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, ev: &Event) -> Result<EventState> {
+		if self.visible {
+			if let Event::Key(e) = ev {
+				if key_match(e, self.key_config.keys().exit_popup)
+					|| key_match(e, self.key_config.keys().enter)
+				{
+					self.hide();
+				} else if key_match(
+					e,
+					self.key_config.keys().commit_no_verify,
+				) {
+					self.hide();
+					self.queue.push(InternalEvent::CommitNoVerify);
+				} else if key_match(e, self.key_config.keys().move_up)
+				{
+					self.scroll.move_top(ScrollType::Up);
+				} else if key_match(
+					e,
+					self.key_config.keys().move_down,
+				) {
+					self.scroll.move_top(ScrollType::Down);
+				} else if key_match(e, self.key_config.keys().home)
+					|| key_match(e, self.key_config.keys().shift_up)
+				{
+					self.scroll.move_top(ScrollType::Home);
+				} else if key_match(e, self.key_config.keys().end)
+					|| key_match(e, self.key_config.keys().shift_down)
+				{
+					self.scroll.move_top(ScrollType::End);
+				}
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+
+		Ok(())
+	}
+}
@@ -156,25 +156,25 @@ impl Component for InspectCommitComponent {
 			}
 
 			if let Event::Key(e) = ev {
-				if key_match(e, self.key_config.keys.exit_popup) {
+				if key_match(e, self.key_config.keys().exit_popup) {
 					self.hide_stacked(false);
 				} else if key_match(
 					e,
-					self.key_config.keys.focus_right,
+					self.key_config.keys().focus_right,
 				) && self.can_focus_diff()
 				{
 					self.details.focus(false);
 					self.diff.focus(true);
 				} else if key_match(
 					e,
-					self.key_config.keys.focus_left,
+					self.key_config.keys().focus_left,
 				) && self.diff.focused()
 				{
 					self.details.focus(true);
 					self.diff.focus(false);
 				} else if key_match(
 					e,
-					self.key_config.keys.open_file_tree,
+					self.key_config.keys().open_file_tree,
 				) {
 					if let Some(commit) = self
 						.open_request
@@ -190,7 +190,7 @@ impl Component for InspectCommitComponent {
 					}
 				} else if key_match(
 					e,
-					self.key_config.keys.focus_left,
+					self.key_config.keys().focus_left,
 				) {
 					self.hide_stacked(false);
 				}
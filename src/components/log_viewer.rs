@@ -0,0 +1,360 @@
+use super::{
+	utils::scroll_vertical::VerticalScroll, visibility_blocking,
+	CommandBlocking, CommandInfo, Component, DrawableComponent,
+	EventState, ScrollType,
+};
+use crate::{
+	args::log_file_path,
+	keys::{key_match, SharedKeyConfig},
+	queue::{InternalEvent, Queue},
+	strings, try_or_popup,
+	ui::{self, style::SharedTheme},
+};
+use anyhow::Result;
+use crossterm::event::Event;
+use log::{Level, LevelFilter};
+use std::{cell::Cell, fs, path::PathBuf, str::FromStr};
+use tui::{
+	backend::Backend,
+	layout::{Margin, Rect},
+	text::{Span, Spans},
+	widgets::{Block, Borders, Clear, Paragraph},
+	Frame,
+};
+
+/// lines read from the end of the log file at most
+const MAX_LINES: usize = 2000;
+
+/// least to most detailed
+const LEVELS: [Level; 5] = [
+	Level::Error,
+	Level::Warn,
+	Level::Info,
+	Level::Debug,
+	Level::Trace,
+];
+
+/// shows the end of the log file written with `--logging`, reloading
+/// it while open
+pub struct LogViewerPopup {
+	queue: Queue,
+	visible: bool,
+	path: Option<PathBuf>,
+	/// lines with the level of the entry they belong to
+	lines: Vec<(Level, String)>,
+	/// most detailed level shown
+	level: Level,
+	scroll: VerticalScroll,
+	/// stick to the end when the log grows
+	follow: Cell<bool>,
+	theme: SharedTheme,
+	key_config: SharedKeyConfig,
+}
+
+impl LogViewerPopup {
+	///
+	pub fn new(
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			queue: queue.clone(),
+			visible: false,
+			path: None,
+			lines: Vec::new(),
+			level: Level::Info,
+			scroll: VerticalScroll::new(),
+			follow: Cell::new(true),
+			theme,
+			key_config,
+		}
+	}
+
+	///
+	pub fn open(&mut self) -> Result<()> {
+		self.path = log_file_path().ok();
+		self.follow.set(true);
+		self.reload();
+		self.show()
+	}
+
+	/// picks up what got logged since the last time
+	pub fn update(&mut self) {
+		if self.is_visible() {
+			self.reload();
+		}
+	}
+
+	fn reload(&mut self) {
+		let content = self
+			.path
+			.as_ref()
+			.and_then(|path| fs::read(path).ok())
+			.unwrap_or_default();
+		let content = String::from_utf8_lossy(&content);
+
+		let lines = content.lines().collect::<Vec<_>>();
+		let mut level = Level::Info;
+
+		self.lines = lines[lines.len().saturating_sub(MAX_LINES)..]
+			.iter()
+			.map(|line| {
+				// lines without a level continue the entry before
+				if let Some(line_level) = parse_level(line) {
+					level = line_level;
+				}
+				(level, (*line).to_string())
+			})
+			.collect();
+	}
+
+	fn filtered(&self) -> impl Iterator<Item = &(Level, String)> {
+		self.lines.iter().filter(|(level, _)| *level <= self.level)
+	}
+
+	fn change_level(&mut self, more: bool) {
+		let idx = LEVELS
+			.iter()
+			.position(|level| *level == self.level)
+			.unwrap_or_default();
+
+		let idx = if more {
+			(idx + 1).min(LEVELS.len() - 1)
+		} else {
+			idx.saturating_sub(1)
+		};
+
+		self.level = LEVELS[idx];
+		self.follow.set(true);
+	}
+
+	fn copy(&self) {
+		let lines = self
+			.filtered()
+			.map(|(_, line)| line.as_str())
+			.collect::<Vec<_>>();
+
+		if lines.is_empty() {
+			return;
+		}
+
+		let res = crate::clipboard::copy_string(&lines.join("\n"));
+		if res.is_ok() {
+			self.queue.push(InternalEvent::ShowToast(
+				strings::toast_log_copied(lines.len()),
+			));
+		}
+		try_or_popup!(self, "copy to clipboard error:", res);
+	}
+
+	fn move_scroll(&self, move_type: ScrollType) {
+		self.scroll.move_top(move_type);
+		self.follow.set(matches!(move_type, ScrollType::End));
+	}
+
+	fn get_text(&self, height: usize) -> Vec<Spans> {
+		let path = self
+			.path
+			.as_ref()
+			.map(|path| path.to_string_lossy().to_string())
+			.unwrap_or_default();
+
+		if log::max_level() == LevelFilter::Off {
+			return vec![Spans::from(Span::styled(
+				strings::log_viewer_off(&path),
+				self.theme.text(false, false),
+			))];
+		}
+
+		if self.lines.is_empty() {
+			return vec![Spans::from(Span::styled(
+				strings::log_viewer_empty(&path),
+				self.theme.text(false, false),
+			))];
+		}
+
+		self.filtered()
+			.skip(self.scroll.get_top())
+			.take(height)
+			.map(|(level, line)| {
+				let style = match level {
+					Level::Error | Level::Warn => {
+						self.theme.text_danger()
+					}
+					Level::Info => self.theme.text(true, false),
+					Level::Debug | Level::Trace => {
+						self.theme.text(false, false)
+					}
+				};
+				Spans::from(Span::styled(line.as_str(), style))
+			})
+			.collect()
+	}
+}
+
+/// level of a line like `12:00:00 [INFO] gitui::app: ..`
+fn parse_level(line: &str) -> Option<Level> {
+	let (_, rest) = line.split_once('[')?;
+	let (level, _) = rest.split_once(']')?;
+	Level::from_str(level.trim()).ok()
+}
+
+impl DrawableComponent for LogViewerPopup {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		area: Rect,
+	) -> Result<()> {
+		if self.is_visible() {
+			let area = ui::centered_rect(90, 80, area);
+			let height = usize::from(
+				area.inner(&Margin {
+					horizontal: 1,
+					vertical: 1,
+				})
+				.height,
+			);
+
+			let count = self.filtered().count();
+			if self.follow.get() {
+				self.scroll.set_top(count.saturating_sub(height));
+			}
+			self.scroll.update_no_selection(count, height);
+
+			f.render_widget(Clear, area);
+			f.render_widget(
+				Paragraph::new(self.get_text(height)).block(
+					Block::default()
+						.title(Span::styled(
+							strings::log_viewer_title(
+								self.level.as_str(),
+							),
+							self.theme.title(true),
+						))
+						.borders(Borders::ALL)
+						.border_style(self.theme.popup_border()),
+				),
+				area,
+			);
+
+			self.scroll.draw(f, area, &self.theme);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for LogViewerPopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			out.push(
+				CommandInfo::new(
+					strings::commands::close_popup(&self.key_config),
+					true,
+					true,
+				)
+				.order(1),
+			);
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::log_viewer_level(&self.key_config),
+				true,
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::log_viewer_copy(&self.key_config),
+				self.filtered().next().is_some(),
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: &Event) -> Result<EventState> {
+		if self.is_visible() {
+			if let Event::Mouse(mouse) = event {
+				if self.scroll.click(*mouse).is_some() {
+					self.follow.set(false);
+				}
+			} else if let Event::Key(key) = event {
+				if key_match(key, self.key_config.keys().exit_popup)
+					|| key_match(
+						key,
+						self.key_config.keys().open_log_viewer,
+					) {
+					self.hide();
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_down,
+				) {
+					self.move_scroll(ScrollType::Down);
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_up,
+				) {
+					self.move_scroll(ScrollType::Up);
+				} else if key_match(key, self.key_config.keys().home)
+				{
+					self.move_scroll(ScrollType::Home);
+				} else if key_match(key, self.key_config.keys().end) {
+					self.move_scroll(ScrollType::End);
+				} else if key_match(
+					key,
+					self.key_config.keys().move_right,
+				) {
+					self.change_level(true);
+				} else if key_match(
+					key,
+					self.key_config.keys().move_left,
+				) {
+					self.change_level(false);
+				} else if key_match(key, self.key_config.keys().copy)
+				{
+					self.copy();
+				}
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_level() {
+		assert_eq!(
+			parse_level("14:02:11 [WARN] gitui::app: failed"),
+			Some(Level::Warn)
+		);
+		assert_eq!(parse_level("continued [from] above"), None);
+		assert_eq!(parse_level("no level here"), None);
+	}
+}
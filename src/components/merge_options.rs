@@ -0,0 +1,278 @@
+use super::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState, ScrollType,
+};
+use crate::{
+	keys::{key_match, SharedKeyConfig},
+	queue::{InternalEvent, NeedsUpdate, Queue},
+	strings, try_or_popup,
+	ui::{self, style::SharedTheme},
+};
+use anyhow::Result;
+use asyncgit::sync::{
+	self, get_config_string, BranchType, MergeKind, MergeOutcome,
+	RepoPathRef,
+};
+use crossterm::event::Event;
+use tui::{
+	backend::Backend,
+	layout::{Margin, Rect},
+	text::{Span, Spans},
+	widgets::{Block, BorderType, Borders, Clear},
+	Frame,
+};
+
+const KINDS: [MergeKind; 4] = [
+	MergeKind::FastForward,
+	MergeKind::NoFastForward,
+	MergeKind::FastForwardOnly,
+	MergeKind::Squash,
+];
+
+const fn kind_text(kind: MergeKind) -> (&'static str, &'static str) {
+	match kind {
+		MergeKind::FastForward => (
+			"--ff",
+			"fast-forward if possible, merge commit otherwise",
+		),
+		MergeKind::NoFastForward => {
+			("--no-ff", "always create a merge commit")
+		}
+		MergeKind::FastForwardOnly => {
+			("--ff-only", "only merge if fast-forward is possible")
+		}
+		MergeKind::Squash => {
+			("--squash", "stage all changes for a single new commit")
+		}
+	}
+}
+
+/// picks how to merge a branch into `HEAD`
+pub struct MergeOptionsPopup {
+	repo: RepoPathRef,
+	queue: Queue,
+	visible: bool,
+	branch: String,
+	branch_type: BranchType,
+	selection: usize,
+	theme: SharedTheme,
+	key_config: SharedKeyConfig,
+}
+
+impl MergeOptionsPopup {
+	///
+	pub fn new(
+		repo: RepoPathRef,
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			repo,
+			queue: queue.clone(),
+			visible: false,
+			branch: String::new(),
+			branch_type: BranchType::Local,
+			selection: 0,
+			theme,
+			key_config,
+		}
+	}
+
+	/// preselects the fast-forward behavior configured in `merge.ff`
+	pub fn open(
+		&mut self,
+		branch: String,
+		branch_type: BranchType,
+	) -> Result<()> {
+		let configured =
+			match get_config_string(&self.repo.borrow(), "merge.ff")
+				.ok()
+				.flatten()
+				.as_deref()
+			{
+				Some("false") => MergeKind::NoFastForward,
+				Some("only") => MergeKind::FastForwardOnly,
+				_ => MergeKind::FastForward,
+			};
+
+		self.branch = branch;
+		self.branch_type = branch_type;
+		self.selection = KINDS
+			.iter()
+			.position(|kind| *kind == configured)
+			.unwrap_or_default();
+		self.show()?;
+
+		Ok(())
+	}
+
+	fn move_selection(&mut self, move_type: ScrollType) {
+		let new_selection = match move_type {
+			ScrollType::Up => self.selection.saturating_sub(1),
+			ScrollType::Down => self.selection.saturating_add(1),
+			_ => self.selection,
+		};
+
+		self.selection =
+			new_selection.clamp(0, KINDS.len().saturating_sub(1));
+	}
+
+	fn merge(&mut self) -> Result<()> {
+		self.hide();
+
+		let outcome = sync::merge_branch_with(
+			&self.repo.borrow(),
+			&self.branch,
+			self.branch_type,
+			KINDS[self.selection],
+		)?;
+
+		self.queue.push(InternalEvent::Update(NeedsUpdate::ALL));
+
+		match outcome {
+			MergeOutcome::UpToDate => {
+				self.queue.push(InternalEvent::ShowInfoMsg(
+					strings::merge_up_to_date(&self.branch),
+				));
+			}
+			MergeOutcome::FastForwarded => (),
+			MergeOutcome::NeedsCommit => {
+				self.queue.push(InternalEvent::TabSwitchStatus);
+				if !sync::has_conflicts(&self.repo.borrow())? {
+					self.queue.push(InternalEvent::OpenCommit);
+				}
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl DrawableComponent for MergeOptionsPopup {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		area: Rect,
+	) -> Result<()> {
+		if self.is_visible() {
+			const SIZE: (u16, u16) = (60, 6);
+
+			let area =
+				ui::centered_rect_absolute(SIZE.0, SIZE.1, area);
+
+			f.render_widget(Clear, area);
+			f.render_widget(
+				Block::default()
+					.borders(Borders::all())
+					.border_style(self.theme.popup_border())
+					.border_type(BorderType::Thick)
+					.title(Span::styled(
+						strings::merge_options_title(&self.branch),
+						self.theme.title(true),
+					)),
+				area,
+			);
+
+			let area = area.inner(&Margin {
+				horizontal: 1,
+				vertical: 1,
+			});
+
+			let items =
+				KINDS.iter().enumerate().map(|(idx, kind)| {
+					let selected = idx == self.selection;
+					let (flag, desc) = kind_text(*kind);
+					Spans::from(vec![
+						Span::styled(
+							format!("{:<12}", flag),
+							self.theme.text(true, selected),
+						),
+						Span::styled(
+							desc,
+							self.theme.text(false, selected),
+						),
+					])
+				});
+
+			ui::draw_list_block(f, area, Block::default(), items);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for MergeOptionsPopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			out.push(
+				CommandInfo::new(
+					strings::commands::close_popup(&self.key_config),
+					true,
+					true,
+				)
+				.order(1),
+			);
+
+			out.push(CommandInfo::new(
+				strings::commands::merge_options_confirm(
+					&self.key_config,
+				),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: &Event) -> Result<EventState> {
+		if self.is_visible() {
+			if let Event::Key(key) = event {
+				if key_match(key, self.key_config.keys().exit_popup) {
+					self.hide();
+				} else if key_match(key, self.key_config.keys().enter)
+				{
+					try_or_popup!(self, "merge error:", self.merge());
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_down,
+				) {
+					self.move_selection(ScrollType::Down);
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_up,
+				) {
+					self.move_selection(ScrollType::Up);
+				}
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+		Ok(())
+	}
+}
@@ -1,73 +1,128 @@
+mod add_worktree;
 mod blame_file;
+mod branch_description;
 mod branchlist;
 mod changes;
+mod coauthors_popup;
 mod command;
 mod commit;
 mod commit_details;
+mod commit_msg_picker;
 mod commitlist;
+mod compare_branches;
 mod compare_commits;
 mod create_branch;
 mod cred;
+mod deepen;
+mod delete_tags;
 mod diff;
+mod edit_remote;
 mod externaleditor;
 mod fetch;
 mod file_find_popup;
 mod file_revlog;
+mod file_view;
+mod grep_popup;
 mod help;
+mod hook_output;
 mod inspect_commit;
+mod log_viewer;
+mod merge_options;
 mod msg;
 mod options_popup;
+mod prune_branches;
 mod pull;
 mod push;
 mod push_tags;
+mod push_target;
+mod recent_branches;
+mod remotes;
 mod rename_branch;
+mod repo_switcher;
 mod reset;
 mod revision_files;
 mod revision_files_popup;
+mod revision_picker;
+mod stash_branch;
 mod stashmsg;
 mod status_tree;
 mod submodules;
 mod syntax_text;
 mod tag_commit;
+mod tag_remote;
 mod taglist;
 mod textinput;
+mod theme_picker;
+mod toasts;
 mod utils;
+mod worktrees;
 
 pub use self::status_tree::StatusTreeComponent;
+pub use add_worktree::AddWorktreeComponent;
 pub use blame_file::{BlameFileComponent, BlameFileOpen};
+pub use branch_description::BranchDescriptionComponent;
 pub use branchlist::BranchListComponent;
 pub use changes::ChangesComponent;
+pub use coauthors_popup::CoAuthorsPopup;
 pub use command::{CommandInfo, CommandText};
 pub use commit::CommitComponent;
 pub use commit_details::CommitDetailsComponent;
+pub use commit_msg_picker::CommitMsgPickerPopup;
 pub use commitlist::CommitList;
+pub use compare_branches::{
+	CompareBranchesComponent, CompareBranchesOpen,
+};
 pub use compare_commits::CompareCommitsComponent;
 pub use create_branch::CreateBranchComponent;
+pub use deepen::DeepenPopup;
+pub use delete_tags::DeleteTagsComponent;
 pub use diff::DiffComponent;
+pub use edit_remote::EditRemoteComponent;
 pub use externaleditor::ExternalEditorComponent;
 pub use fetch::FetchComponent;
 pub use file_find_popup::FileFindPopup;
 pub use file_revlog::{FileRevOpen, FileRevlogComponent};
+pub use file_view::{FileViewOpen, FileViewPopup};
+pub use grep_popup::GrepPopup;
 pub use help::HelpComponent;
+pub use hook_output::HookOutputPopup;
 pub use inspect_commit::{InspectCommitComponent, InspectCommitOpen};
+pub use log_viewer::LogViewerPopup;
+pub use merge_options::MergeOptionsPopup;
 pub use msg::MsgComponent;
 pub use options_popup::{
-	AppOption, OptionsPopupComponent, SharedOptions,
+	AppOption, AutoFetch, ClipboardBackend, CompactMode,
+	Confirmations, CopyPathFormat, MouseSupport, NerdIcons,
+	NetworkRetries, OptionsPopupComponent, PaneLayout, SharedOptions,
+	StartTab, StatusSort, TerminalTitle, ToastTimeout,
 };
+pub use prune_branches::PruneBranchesPopup;
 pub use pull::PullComponent;
 pub use push::PushComponent;
 pub use push_tags::PushTagsComponent;
+pub use push_target::PushTargetPopup;
+pub use recent_branches::RecentBranchesPopup;
+pub use remotes::RemotesPopup;
 pub use rename_branch::RenameBranchComponent;
+pub use repo_switcher::{remember_repo, RepoSwitcherPopup};
 pub use reset::ConfirmComponent;
 pub use revision_files::RevisionFilesComponent;
 pub use revision_files_popup::{FileTreeOpen, RevisionFilesPopup};
+pub use revision_picker::RevisionPickerPopup;
+pub use stash_branch::StashBranchComponent;
 pub use stashmsg::StashMsgComponent;
 pub use submodules::SubmodulesListComponent;
 pub use syntax_text::SyntaxTextComponent;
 pub use tag_commit::TagCommitComponent;
+pub use tag_remote::{TagRemoteAction, TagRemotePopup};
 pub use taglist::TagListComponent;
 pub use textinput::{InputType, TextInputComponent};
-pub use utils::filetree::FileTreeItemKind;
+pub use theme_picker::ThemePickerPopup;
+pub use toasts::ToastsComponent;
+pub use utils::{
+	error_chain, filetree::FileTreeItemKind, time_to_age,
+};
+pub use worktrees::WorktreesPopup;
 
 use crate::ui::style::Theme;
 use anyhow::Result;
@@ -123,7 +178,7 @@ macro_rules! draw_popups {
             .constraints(
                 [
                     Constraint::Min(1),
-                    Constraint::Length($self.cmdbar.borrow().height()),
+                    Constraint::Length(1),
                 ]
                 .as_ref(),
             )
@@ -294,7 +349,7 @@ fn dialog_paragraph<'a>(
 		.block(
 			Block::default()
 				.title(Span::styled(title, theme.title(focused)))
-				.borders(Borders::ALL)
+				.borders(theme.pane_borders())
 				.border_style(theme.block(focused)),
 		)
 		.alignment(Alignment::Left)
@@ -320,7 +375,11 @@ where
 				.title(Span::styled(title, theme.title(focused)))
 				.borders(Borders::ALL)
 				.border_type(BorderType::Thick)
-				.border_style(theme.block(focused)),
+				.border_style(if focused {
+					theme.popup_border()
+				} else {
+					theme.block(false)
+				}),
 		)
 	} else {
 		paragraph
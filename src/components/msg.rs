@@ -4,14 +4,15 @@ use super::{
 };
 use crate::{
 	keys::{key_match, SharedKeyConfig},
-	strings, ui,
+	queue::{InternalEvent, Queue},
+	strings, try_or_popup, ui,
 };
 use crossterm::event::Event;
 use std::convert::TryFrom;
 use tui::{
 	backend::Backend,
 	layout::{Alignment, Rect},
-	text::Span,
+	text::{Span, Spans, Text},
 	widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap},
 	Frame,
 };
@@ -19,7 +20,11 @@ use ui::style::SharedTheme;
 pub struct MsgComponent {
 	title: String,
 	msg: String,
+	/// sent when retrying the operation that failed
+	retry: Option<InternalEvent>,
+	error: bool,
 	visible: bool,
+	queue: Queue,
 	theme: SharedTheme,
 	key_config: SharedKeyConfig,
 }
@@ -58,7 +63,7 @@ impl DrawableComponent for MsgComponent {
 		let area = ui::centered_rect_absolute(width, 25, f.size());
 		f.render_widget(Clear, area);
 		f.render_widget(
-			Paragraph::new(self.msg.clone())
+			Paragraph::new(self.get_text())
 				.block(
 					Block::default()
 						.title(Span::styled(
@@ -66,6 +71,7 @@ impl DrawableComponent for MsgComponent {
 							self.theme.text_danger(),
 						))
 						.borders(Borders::ALL)
+						.border_style(self.theme.popup_border())
 						.border_type(BorderType::Thick),
 				)
 				.alignment(Alignment::Left)
@@ -88,6 +94,16 @@ impl Component for MsgComponent {
 			true,
 			self.visible,
 		));
+		out.push(CommandInfo::new(
+			strings::commands::error_retry(&self.key_config),
+			true,
+			self.visible && self.retry.is_some(),
+		));
+		out.push(CommandInfo::new(
+			strings::commands::error_copy(&self.key_config),
+			true,
+			self.visible && self.error,
+		));
 
 		visibility_blocking(self)
 	}
@@ -95,8 +111,20 @@ impl Component for MsgComponent {
 	fn event(&mut self, ev: &Event) -> Result<EventState> {
 		if self.visible {
 			if let Event::Key(e) = ev {
-				if key_match(e, self.key_config.keys.enter) {
+				if key_match(e, self.key_config.keys().enter) {
 					self.hide();
+				} else if key_match(
+					e,
+					self.key_config.keys().error_retry,
+				) {
+					if let Some(retry) = self.retry.take() {
+						self.hide();
+						self.queue.push(retry);
+					}
+				} else if self.error
+					&& key_match(e, self.key_config.keys().copy)
+				{
+					self.copy();
 				}
 			}
 			Ok(EventState::Consumed)
@@ -111,6 +139,7 @@ impl Component for MsgComponent {
 
 	fn hide(&mut self) {
 		self.visible = false;
+		self.retry = None;
 	}
 
 	fn show(&mut self) -> Result<()> {
@@ -121,14 +150,18 @@ impl Component for MsgComponent {
 }
 
 impl MsgComponent {
-	pub const fn new(
+	pub fn new(
+		queue: &Queue,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
 	) -> Self {
 		Self {
 			title: String::new(),
 			msg: String::new(),
+			retry: None,
+			error: false,
 			visible: false,
+			queue: queue.clone(),
 			theme,
 			key_config,
 		}
@@ -138,17 +171,75 @@ impl MsgComponent {
 	pub fn show_error(&mut self, msg: &str) -> Result<()> {
 		self.title = strings::msg_title_error(&self.key_config);
 		self.msg = msg.to_string();
+		self.retry = None;
+		self.error = true;
 		self.show()?;
 
 		Ok(())
 	}
 
+	/// error that offers to send `retry` to run the operation again
+	pub fn show_error_retry(
+		&mut self,
+		msg: &str,
+		retry: InternalEvent,
+	) -> Result<()> {
+		self.show_error(msg)?;
+		self.retry = Some(retry);
+
+		Ok(())
+	}
+
 	///
 	pub fn show_info(&mut self, msg: &str) -> Result<()> {
 		self.title = strings::msg_title_info(&self.key_config);
 		self.msg = msg.to_string();
+		self.retry = None;
+		self.error = false;
 		self.show()?;
 
 		Ok(())
 	}
+
+	/// errors start with what failed followed by why,
+	/// which gets set apart from the rest
+	fn get_text(&self) -> Text<'_> {
+		if !self.error {
+			return Text::from(self.msg.as_str());
+		}
+
+		let mut lines = self.msg.lines();
+
+		lines
+			.next()
+			.map(|summary| {
+				Spans::from(Span::styled(
+					summary,
+					self.theme.text_danger(),
+				))
+			})
+			.into_iter()
+			.chain(lines.map(|line| {
+				let style = if line.starts_with("caused by:") {
+					self.theme.text(false, false)
+				} else {
+					self.theme.text(true, false)
+				};
+				Spans::from(Span::styled(line, style))
+			}))
+			.collect::<Vec<_>>()
+			.into()
+	}
+
+	fn copy(&self) {
+		let res = crate::clipboard::copy_string(
+			&strings::error_report(&self.title, &self.msg),
+		);
+		if res.is_ok() {
+			self.queue.push(InternalEvent::ShowToast(
+				strings::toast_error_copied(),
+			));
+		}
+		try_or_popup!(self, "copy to clipboard error:", res);
+	}
 }
@@ -1,4 +1,9 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{
+	cell::{Cell, RefCell},
+	path::Path,
+	rc::Rc,
+	time::Duration,
+};
 
 use super::{
 	visibility_blocking, CommandBlocking, CommandInfo, Component,
@@ -7,16 +12,22 @@ use super::{
 use crate::{
 	components::utils::string_width_align,
 	keys::{key_match, SharedKeyConfig},
-	queue::{InternalEvent, Queue},
+	options_file::OptionsFile,
+	queue::{Action, InternalEvent, Queue},
 	strings::{self},
 	ui::{self, style::SharedTheme},
 };
 use anyhow::Result;
-use asyncgit::sync::{diff::DiffOptions, ShowUntrackedFilesConfig};
+use asyncgit::sync::{
+	diff::DiffOptions, get_config_bool, get_config_string,
+	set_config_string, status::StatusSortData, PullMode, RepoPath,
+	ShowUntrackedFilesConfig,
+};
 use crossterm::event::Event;
+use serde::{Deserialize, Serialize};
 use tui::{
 	backend::Backend,
-	layout::{Alignment, Rect},
+	layout::{Alignment, Constraint, Direction, Layout, Rect},
 	style::{Modifier, Style},
 	text::{Span, Spans},
 	widgets::{Block, Borders, Clear, Paragraph},
@@ -26,15 +37,984 @@ use tui::{
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum AppOption {
 	StatusShowUntracked,
+	StatusSort,
 	DiffIgnoreWhitespaces,
 	DiffContextLines,
 	DiffInterhunkLines,
+	Autostash,
+	RebaseUpdateRefs,
+	FetchPrune,
+	PullMode,
+	PushFollowTags,
+	AutoFetch,
+	NetworkRetries,
+	CopyPathFormat,
+	Clipboard,
+	Mouse,
+	Icons,
+	Compact,
+	TerminalTitle,
+	ToastTimeout,
+	StartTab,
+	StatusSplit,
+	StatusOrientation,
+	LogSplit,
+	LogOrientation,
+	FilesSplit,
+	FilesOrientation,
+	Confirm(Confirmation),
+}
+
+/// order of the files in the status lists
+#[derive(
+	Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize,
+)]
+pub enum StatusSort {
+	Path,
+	/// grouped by kind of change (new, modified, deleted..)
+	Status,
+	/// most recently modified first
+	ModTime,
+	/// most changed lines first
+	DiffSize,
+}
+
+impl Default for StatusSort {
+	fn default() -> Self {
+		Self::Path
+	}
+}
+
+impl StatusSort {
+	///
+	pub const fn name(self) -> &'static str {
+		match self {
+			Self::Path => "Path",
+			Self::Status => "Status",
+			Self::ModTime => "Modified",
+			Self::DiffSize => "Diff size",
+		}
+	}
+
+	/// what the status job has to gather to sort by this
+	pub const fn sort_data(self) -> StatusSortData {
+		match self {
+			Self::Path | Self::Status => StatusSortData::None,
+			Self::ModTime => StatusSortData::ModTime,
+			Self::DiffSize => StatusSortData::DiffSize,
+		}
+	}
+
+	const fn next(self) -> Self {
+		match self {
+			Self::Path => Self::Status,
+			Self::Status => Self::ModTime,
+			Self::ModTime => Self::DiffSize,
+			Self::DiffSize => Self::Path,
+		}
+	}
+
+	const fn prev(self) -> Self {
+		match self {
+			Self::Path => Self::DiffSize,
+			Self::Status => Self::Path,
+			Self::ModTime => Self::Status,
+			Self::DiffSize => Self::ModTime,
+		}
+	}
+
+	/// sort order persisted in the options file
+	pub fn save(self) -> Result<()> {
+		OptionsFile::update(|options| options.status_sort = self)
+	}
 }
 
+/// minutes between background fetches, `0` is off
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct AutoFetch(u64);
+
+impl AutoFetch {
+	const CONFIG_KEY: &'static str = "gitui.autoFetch";
+	const STEPS: [u64; 6] = [0, 1, 5, 15, 30, 60];
+
+	///
+	pub fn name(self) -> String {
+		if self.0 == 0 {
+			String::from("Off")
+		} else {
+			format!("{}m", self.0)
+		}
+	}
+
+	///
+	pub const fn interval(self) -> Option<Duration> {
+		if self.0 == 0 {
+			None
+		} else {
+			Some(Duration::from_secs(self.0 * 60))
+		}
+	}
+
+	fn step(self) -> usize {
+		Self::STEPS
+			.iter()
+			.position(|minutes| *minutes >= self.0)
+			.unwrap_or(Self::STEPS.len() - 1)
+	}
+
+	fn next(self) -> Self {
+		Self(Self::STEPS[(self.step() + 1) % Self::STEPS.len()])
+	}
+
+	fn prev(self) -> Self {
+		Self(
+			Self::STEPS[(self.step() + Self::STEPS.len() - 1)
+				% Self::STEPS.len()],
+		)
+	}
+
+	/// interval persisted in the repo config
+	pub fn load(repo: &RepoPath) -> Self {
+		Self(
+			get_config_string(repo, Self::CONFIG_KEY)
+				.ok()
+				.flatten()
+				.and_then(|value| value.parse().ok())
+				.unwrap_or_default(),
+		)
+	}
+
+	///
+	pub fn save(self, repo: &RepoPath) -> Result<()> {
+		set_config_string(
+			repo,
+			Self::CONFIG_KEY,
+			&self.0.to_string(),
+		)?;
+		Ok(())
+	}
+}
+
+/// how often fetch, pull and push retry transient network errors
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct NetworkRetries(usize);
+
+impl Default for NetworkRetries {
+	fn default() -> Self {
+		Self(2)
+	}
+}
+
+impl NetworkRetries {
+	const CONFIG_KEY: &'static str = "gitui.networkRetries";
+	const MAX: usize = 5;
+
+	///
+	pub fn name(self) -> String {
+		if self.0 == 0 {
+			String::from("Off")
+		} else {
+			self.0.to_string()
+		}
+	}
+
+	///
+	pub const fn retries(self) -> usize {
+		self.0
+	}
+
+	const fn next(self) -> Self {
+		Self((self.0 + 1) % (Self::MAX + 1))
+	}
+
+	const fn prev(self) -> Self {
+		Self((self.0 + Self::MAX) % (Self::MAX + 1))
+	}
+
+	/// retries persisted in the repo config
+	pub fn load(repo: &RepoPath) -> Self {
+		get_config_string(repo, Self::CONFIG_KEY)
+			.ok()
+			.flatten()
+			.and_then(|value| value.parse().ok())
+			.map_or_else(Self::default, |retries: usize| {
+				Self(retries.min(Self::MAX))
+			})
+	}
+
+	///
+	pub fn save(self, repo: &RepoPath) -> Result<()> {
+		set_config_string(
+			repo,
+			Self::CONFIG_KEY,
+			&self.0.to_string(),
+		)?;
+		Ok(())
+	}
+}
+
+/// how the copy path action writes a file path to the clipboard
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum CopyPathFormat {
+	/// relative to the repository root
+	Relative,
+	/// relative with `:<line>` appended where a line is selected
+	RelativeLine,
+	Absolute,
+	/// absolute with `:<line>` appended where a line is selected
+	AbsoluteLine,
+}
+
+impl Default for CopyPathFormat {
+	fn default() -> Self {
+		Self::Relative
+	}
+}
+
+impl CopyPathFormat {
+	const CONFIG_KEY: &'static str = "gitui.copyPathFormat";
+
+	///
+	pub const fn name(self) -> &'static str {
+		match self {
+			Self::Relative => "Relative",
+			Self::RelativeLine => "Relative:line",
+			Self::Absolute => "Absolute",
+			Self::AbsoluteLine => "Absolute:line",
+		}
+	}
+
+	const fn config_value(self) -> &'static str {
+		match self {
+			Self::Relative => "relative",
+			Self::RelativeLine => "relative-line",
+			Self::Absolute => "absolute",
+			Self::AbsoluteLine => "absolute-line",
+		}
+	}
+
+	const fn next(self) -> Self {
+		match self {
+			Self::Relative => Self::RelativeLine,
+			Self::RelativeLine => Self::Absolute,
+			Self::Absolute => Self::AbsoluteLine,
+			Self::AbsoluteLine => Self::Relative,
+		}
+	}
+
+	const fn prev(self) -> Self {
+		match self {
+			Self::Relative => Self::AbsoluteLine,
+			Self::RelativeLine => Self::Relative,
+			Self::Absolute => Self::RelativeLine,
+			Self::AbsoluteLine => Self::Absolute,
+		}
+	}
+
+	/// `path` is relative to the repository root at `work_dir`, `line`
+	/// is zero based
+	pub fn format(
+		self,
+		work_dir: &str,
+		path: &str,
+		line: Option<usize>,
+	) -> String {
+		let path = match self {
+			Self::Relative | Self::RelativeLine => path.to_string(),
+			Self::Absolute | Self::AbsoluteLine => {
+				Path::new(work_dir)
+					.join(path)
+					.to_string_lossy()
+					.to_string()
+			}
+		};
+
+		match (self, line) {
+			(Self::RelativeLine | Self::AbsoluteLine, Some(line)) => {
+				format!("{}:{}", path, line + 1)
+			}
+			_ => path,
+		}
+	}
+
+	/// format persisted in the repo config
+	pub fn load(repo: &RepoPath) -> Self {
+		let value = get_config_string(repo, Self::CONFIG_KEY)
+			.ok()
+			.flatten()
+			.unwrap_or_default();
+
+		[Self::RelativeLine, Self::Absolute, Self::AbsoluteLine]
+			.into_iter()
+			.find(|format| format.config_value() == value)
+			.unwrap_or_default()
+	}
+
+	///
+	pub fn save(self, repo: &RepoPath) -> Result<()> {
+		set_config_string(
+			repo,
+			Self::CONFIG_KEY,
+			self.config_value(),
+		)?;
+		Ok(())
+	}
+}
+
+/// how copied text gets into the clipboard
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum ClipboardBackend {
+	/// OSC 52 over ssh or when no clipboard tool is found, the
+	/// clipboard tool of the system otherwise
+	Auto,
+	/// `pbcopy`, `clip`, `wl-copy`, `xclip` or `xsel`
+	System,
+	/// escape sequence making the terminal set the clipboard
+	Osc52,
+}
+
+impl Default for ClipboardBackend {
+	fn default() -> Self {
+		Self::Auto
+	}
+}
+
+impl ClipboardBackend {
+	const CONFIG_KEY: &'static str = "gitui.clipboard";
+
+	///
+	pub const fn name(self) -> &'static str {
+		match self {
+			Self::Auto => "Auto",
+			Self::System => "System",
+			Self::Osc52 => "OSC 52",
+		}
+	}
+
+	const fn config_value(self) -> &'static str {
+		match self {
+			Self::Auto => "auto",
+			Self::System => "system",
+			Self::Osc52 => "osc52",
+		}
+	}
+
+	const fn next(self) -> Self {
+		match self {
+			Self::Auto => Self::System,
+			Self::System => Self::Osc52,
+			Self::Osc52 => Self::Auto,
+		}
+	}
+
+	const fn prev(self) -> Self {
+		match self {
+			Self::Auto => Self::Osc52,
+			Self::System => Self::Auto,
+			Self::Osc52 => Self::System,
+		}
+	}
+
+	/// backend persisted in the repo config
+	pub fn load(repo: &RepoPath) -> Self {
+		let value = get_config_string(repo, Self::CONFIG_KEY)
+			.ok()
+			.flatten()
+			.unwrap_or_default();
+
+		[Self::System, Self::Osc52]
+			.into_iter()
+			.find(|backend| backend.config_value() == value)
+			.unwrap_or_default()
+	}
+
+	///
+	pub fn save(self, repo: &RepoPath) -> Result<()> {
+		set_config_string(
+			repo,
+			Self::CONFIG_KEY,
+			self.config_value(),
+		)?;
+		Ok(())
+	}
+}
+
+/// whether clicks, the mouse wheel and dragging are handled
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct MouseSupport(bool);
+
+impl MouseSupport {
+	const CONFIG_KEY: &'static str = "gitui.mouse";
+
+	///
+	pub const fn enabled(self) -> bool {
+		self.0
+	}
+
+	const fn toggled(self) -> Self {
+		Self(!self.0)
+	}
+
+	/// setting persisted in the repo config
+	pub fn load(repo: &RepoPath) -> Self {
+		Self(load_flag(repo, Self::CONFIG_KEY))
+	}
+
+	///
+	pub fn save(self, repo: &RepoPath) -> Result<()> {
+		set_config_string(
+			repo,
+			Self::CONFIG_KEY,
+			&self.0.to_string(),
+		)?;
+		Ok(())
+	}
+}
+
+/// whether files, branches, tags and stashes are drawn with Nerd Font
+/// glyphs, which needs a patched font in the terminal
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct NerdIcons(bool);
+
+impl NerdIcons {
+	const CONFIG_KEY: &'static str = "gitui.icons";
+
+	///
+	pub const fn enabled(self) -> bool {
+		self.0
+	}
+
+	const fn toggled(self) -> Self {
+		Self(!self.0)
+	}
+
+	/// setting persisted in the repo config
+	pub fn load(repo: &RepoPath) -> Self {
+		Self(load_flag(repo, Self::CONFIG_KEY))
+	}
+
+	///
+	pub fn save(self, repo: &RepoPath) -> Result<()> {
+		set_config_string(
+			repo,
+			Self::CONFIG_KEY,
+			&self.0.to_string(),
+		)?;
+		Ok(())
+	}
+}
+
+/// thinner borders and less padding so more content fits into small
+/// terminals
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct CompactMode(bool);
+
+impl CompactMode {
+	const CONFIG_KEY: &'static str = "gitui.compact";
+
+	///
+	pub const fn enabled(self) -> bool {
+		self.0
+	}
+
+	const fn toggled(self) -> Self {
+		Self(!self.0)
+	}
+
+	/// setting persisted in the repo config
+	pub fn load(repo: &RepoPath) -> Self {
+		Self(load_flag(repo, Self::CONFIG_KEY))
+	}
+
+	///
+	pub fn save(self, repo: &RepoPath) -> Result<()> {
+		set_config_string(
+			repo,
+			Self::CONFIG_KEY,
+			&self.0.to_string(),
+		)?;
+		Ok(())
+	}
+}
+
+/// whether the window title of the terminal shows the repository,
+/// branch and pending operation
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct TerminalTitle(bool);
+
+impl TerminalTitle {
+	const CONFIG_KEY: &'static str = "gitui.terminalTitle";
+
+	///
+	pub const fn enabled(self) -> bool {
+		self.0
+	}
+
+	const fn toggled(self) -> Self {
+		Self(!self.0)
+	}
+
+	/// setting persisted in the repo config
+	pub fn load(repo: &RepoPath) -> Self {
+		Self(load_flag(repo, Self::CONFIG_KEY))
+	}
+
+	///
+	pub fn save(self, repo: &RepoPath) -> Result<()> {
+		set_config_string(
+			repo,
+			Self::CONFIG_KEY,
+			&self.0.to_string(),
+		)?;
+		Ok(())
+	}
+}
+
+/// seconds notifications stay on screen, they only go to the
+/// history when off
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct ToastTimeout(u64);
+
+impl Default for ToastTimeout {
+	fn default() -> Self {
+		Self(3)
+	}
+}
+
+impl ToastTimeout {
+	const CONFIG_KEY: &'static str = "gitui.toastTimeout";
+	const STEPS: [u64; 5] = [0, 2, 3, 5, 10];
+
+	///
+	pub fn name(self) -> String {
+		if self.0 == 0 {
+			String::from("Off")
+		} else {
+			format!("{}s", self.0)
+		}
+	}
+
+	/// `None` when notifications are not shown
+	pub const fn duration(self) -> Option<Duration> {
+		if self.0 == 0 {
+			None
+		} else {
+			Some(Duration::from_secs(self.0))
+		}
+	}
+
+	fn step(self) -> usize {
+		Self::STEPS
+			.iter()
+			.position(|secs| *secs >= self.0)
+			.unwrap_or(Self::STEPS.len() - 1)
+	}
+
+	fn next(self) -> Self {
+		Self(Self::STEPS[(self.step() + 1) % Self::STEPS.len()])
+	}
+
+	fn prev(self) -> Self {
+		Self(
+			Self::STEPS[(self.step() + Self::STEPS.len() - 1)
+				% Self::STEPS.len()],
+		)
+	}
+
+	/// timeout persisted in the repo config
+	pub fn load(repo: &RepoPath) -> Self {
+		get_config_string(repo, Self::CONFIG_KEY)
+			.ok()
+			.flatten()
+			.and_then(|value| value.parse().ok())
+			.map_or_else(Self::default, Self)
+	}
+
+	///
+	pub fn save(self, repo: &RepoPath) -> Result<()> {
+		set_config_string(
+			repo,
+			Self::CONFIG_KEY,
+			&self.0.to_string(),
+		)?;
+		Ok(())
+	}
+}
+
+/// tab gitui starts on unless `--tab` says otherwise
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum StartTab {
+	Status,
+	Log,
+	Files,
+	Stashing,
+	Stashes,
+}
+
+impl Default for StartTab {
+	fn default() -> Self {
+		Self::Status
+	}
+}
+
+impl StartTab {
+	const CONFIG_KEY: &'static str = "gitui.startTab";
+	const ALL: [Self; 5] = [
+		Self::Status,
+		Self::Log,
+		Self::Files,
+		Self::Stashing,
+		Self::Stashes,
+	];
+
+	///
+	pub const fn name(self) -> &'static str {
+		match self {
+			Self::Status => "Status",
+			Self::Log => "Log",
+			Self::Files => "Files",
+			Self::Stashing => "Stashing",
+			Self::Stashes => "Stashes",
+		}
+	}
+
+	/// as used in the config and by `--tab`
+	pub const fn config_value(self) -> &'static str {
+		match self {
+			Self::Status => "status",
+			Self::Log => "log",
+			Self::Files => "files",
+			Self::Stashing => "stashing",
+			Self::Stashes => "stashes",
+		}
+	}
+
+	///
+	pub fn from_config_value(value: &str) -> Option<Self> {
+		Self::ALL
+			.into_iter()
+			.find(|tab| tab.config_value() == value)
+	}
+
+	/// position in the tab bar
+	pub const fn index(self) -> usize {
+		self as usize
+	}
+
+	const fn next(self) -> Self {
+		Self::ALL[(self.index() + 1) % Self::ALL.len()]
+	}
+
+	const fn prev(self) -> Self {
+		Self::ALL
+			[(self.index() + Self::ALL.len() - 1) % Self::ALL.len()]
+	}
+
+	/// tab persisted in the repo config
+	pub fn load(repo: &RepoPath) -> Self {
+		get_config_string(repo, Self::CONFIG_KEY)
+			.ok()
+			.flatten()
+			.and_then(|value| Self::from_config_value(&value))
+			.unwrap_or_default()
+	}
+
+	///
+	pub fn save(self, repo: &RepoPath) -> Result<()> {
+		set_config_string(
+			repo,
+			Self::CONFIG_KEY,
+			self.config_value(),
+		)?;
+		Ok(())
+	}
+}
+
+/// destructive operations whose confirmation popup can be turned off
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Confirmation {
+	StashDrop,
+	ForcePush,
+	ResetFile,
+	DeleteBranch,
+}
+
+impl Confirmation {
+	///
+	pub const fn name(self) -> &'static str {
+		match self {
+			Self::StashDrop => "Drop stash",
+			Self::ForcePush => "Force push",
+			Self::ResetFile => "Reset file",
+			Self::DeleteBranch => "Delete branch",
+		}
+	}
+
+	const fn next(self) -> Option<Self> {
+		match self {
+			Self::StashDrop => Some(Self::ForcePush),
+			Self::ForcePush => Some(Self::ResetFile),
+			Self::ResetFile => Some(Self::DeleteBranch),
+			Self::DeleteBranch => None,
+		}
+	}
+
+	const fn prev(self) -> Option<Self> {
+		match self {
+			Self::StashDrop => None,
+			Self::ForcePush => Some(Self::StashDrop),
+			Self::ResetFile => Some(Self::ForcePush),
+			Self::DeleteBranch => Some(Self::ResetFile),
+		}
+	}
+
+	/// `None` for actions that always ask
+	const fn of(action: &Action) -> Option<Self> {
+		match action {
+			Action::StashDrop(_) => Some(Self::StashDrop),
+			Action::ForcePush(..) => Some(Self::ForcePush),
+			Action::Reset(_) => Some(Self::ResetFile),
+			Action::DeleteLocalBranch(_)
+			| Action::DeleteLocalBranches(_)
+			| Action::DeleteRemoteBranch(_) => Some(Self::DeleteBranch),
+			_ => None,
+		}
+	}
+}
+
+/// which destructive operations ask before going ahead, all of them
+/// unless turned off in the `confirmations` section of the options file
+#[allow(clippy::struct_excessive_bools)]
+#[derive(
+	Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize,
+)]
+#[serde(default)]
+pub struct Confirmations {
+	stash_drop: bool,
+	force_push: bool,
+	reset_file: bool,
+	delete_branch: bool,
+}
+
+impl Default for Confirmations {
+	fn default() -> Self {
+		Self {
+			stash_drop: true,
+			force_push: true,
+			reset_file: true,
+			delete_branch: true,
+		}
+	}
+}
+
+impl Confirmations {
+	///
+	pub const fn get(self, kind: Confirmation) -> bool {
+		match kind {
+			Confirmation::StashDrop => self.stash_drop,
+			Confirmation::ForcePush => self.force_push,
+			Confirmation::ResetFile => self.reset_file,
+			Confirmation::DeleteBranch => self.delete_branch,
+		}
+	}
+
+	fn get_mut(&mut self, kind: Confirmation) -> &mut bool {
+		match kind {
+			Confirmation::StashDrop => &mut self.stash_drop,
+			Confirmation::ForcePush => &mut self.force_push,
+			Confirmation::ResetFile => &mut self.reset_file,
+			Confirmation::DeleteBranch => &mut self.delete_branch,
+		}
+	}
+
+	fn toggle(&mut self, kind: Confirmation) {
+		let value = self.get_mut(kind);
+		*value = !*value;
+	}
+
+	/// `true` if `action` has to be confirmed by the user first
+	pub fn required(self, action: &Action) -> bool {
+		Confirmation::of(action).map_or(true, |kind| self.get(kind))
+	}
+
+	///
+	pub fn save(self) -> Result<()> {
+		OptionsFile::update(|options| options.confirmations = self)
+	}
+}
+
+/// boolean setting of the repo config, off unless set to true
+fn load_flag(repo: &RepoPath, key: &str) -> bool {
+	get_config_bool(repo, key)
+		.ok()
+		.flatten()
+		.unwrap_or_default()
+}
+
+/// how a tab divides its area between its two main panes
+#[derive(
+	Copy, Clone, Default, PartialEq, Eq, Debug, Serialize, Deserialize,
+)]
+#[serde(default)]
+pub struct PaneLayout {
+	/// size of the first pane in percent, `None` keeps the default
+	pub split: Option<u16>,
+	/// stacks the panes instead of placing them side by side
+	pub vertical: bool,
+}
+
+impl PaneLayout {
+	/// bounds of the split, leaving room for both panes
+	pub const MIN: u16 = 10;
+	pub const MAX: u16 = 90;
+	const STEP: u16 = 10;
+
+	/// splits `area`, giving the first pane `default` percent unless
+	/// configured otherwise
+	pub fn split(self, area: Rect, default: u16) -> Vec<Rect> {
+		let first = self.split.unwrap_or(default);
+
+		Layout::default()
+			.direction(if self.vertical {
+				Direction::Vertical
+			} else {
+				Direction::Horizontal
+			})
+			.constraints(
+				[
+					Constraint::Percentage(first),
+					Constraint::Percentage(100 - first),
+				]
+				.as_ref(),
+			)
+			.split(area)
+	}
+
+	///
+	pub fn split_name(self) -> String {
+		self.split.map_or_else(
+			|| String::from("Default"),
+			|split| format!("{}%", split),
+		)
+	}
+
+	///
+	pub const fn orientation_name(self) -> &'static str {
+		if self.vertical {
+			"Vertical"
+		} else {
+			"Horizontal"
+		}
+	}
+
+	const fn next_split(self) -> Self {
+		Self {
+			split: match self.split {
+				None => Some(Self::MIN),
+				Some(split) if split >= Self::MAX => None,
+				Some(split) => Some(split + Self::STEP),
+			},
+			..self
+		}
+	}
+
+	const fn prev_split(self) -> Self {
+		Self {
+			split: match self.split {
+				None => Some(Self::MAX),
+				Some(split) if split <= Self::MIN => None,
+				Some(split) => Some(split - Self::STEP),
+			},
+			..self
+		}
+	}
+
+	const fn toggled_orientation(self) -> Self {
+		Self {
+			vertical: !self.vertical,
+			..self
+		}
+	}
+
+	/// layout of `tab` persisted in the options file
+	pub fn load(options: &OptionsFile, tab: &str) -> Self {
+		options.layouts.get(tab).map_or_else(
+			Self::default,
+			|layout| Self {
+				split: layout
+					.split
+					.map(|split| split.clamp(Self::MIN, Self::MAX)),
+				..*layout
+			},
+		)
+	}
+
+	///
+	pub fn save(self, tab: &str) -> Result<()> {
+		OptionsFile::update(|options| {
+			options.layouts.insert(tab.to_string(), self);
+		})
+	}
+}
+
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Default, Copy, Clone)]
 pub struct Options {
 	pub status_show_untracked: Option<ShowUntrackedFilesConfig>,
+	pub status_sort: StatusSort,
 	pub diff: DiffOptions,
+	/// stash local changes around checkout, rebase and pull
+	pub autostash: bool,
+	/// move stacked branches along when rebasing (`rebase.updateRefs`)
+	pub rebase_update_refs: bool,
+	/// drop remote tracking branches gone on the remote when fetching
+	pub fetch_prune: bool,
+	/// `None` follows `pull.rebase` and `pull.ff`
+	pub pull_mode: Option<PullMode>,
+	/// push annotated tags along with the branch (`push.followTags`)
+	pub push_follow_tags: bool,
+	/// fetch all remotes in the background every so often
+	pub auto_fetch: AutoFetch,
+	///
+	pub network_retries: NetworkRetries,
+	///
+	pub copy_path_format: CopyPathFormat,
+	///
+	pub clipboard: ClipboardBackend,
+	///
+	pub mouse: MouseSupport,
+	///
+	pub icons: NerdIcons,
+	///
+	pub compact: CompactMode,
+	///
+	pub terminal_title: TerminalTitle,
+	///
+	pub toast_timeout: ToastTimeout,
+	///
+	pub start_tab: StartTab,
+	///
+	pub confirmations: Confirmations,
+	/// file lists next to the diff
+	pub status_layout: PaneLayout,
+	/// commit list next to the commit details
+	pub log_layout: PaneLayout,
+	/// file tree next to the file content
+	pub files_layout: PaneLayout,
+}
+
+impl Options {
+	/// picks up the defaults git itself is configured with
+	pub fn load_git_config(&mut self, repo: &RepoPath) {
+		self.rebase_update_refs =
+			load_flag(repo, "rebase.updateRefs");
+		// unlike git we prune unless told otherwise (`fetch.prune`)
+		self.fetch_prune = get_config_bool(repo, "fetch.prune")
+			.ok()
+			.flatten()
+			.unwrap_or(true);
+		self.push_follow_tags = load_flag(repo, "push.followTags");
+	}
 }
 
 pub type SharedOptions = Rc<RefCell<Options>>;
@@ -46,6 +1026,8 @@ pub struct OptionsPopupComponent {
 	key_config: SharedKeyConfig,
 	options: SharedOptions,
 	theme: SharedTheme,
+	/// line of the selected entry, to keep it scrolled into view
+	selected_line: Cell<usize>,
 }
 
 impl OptionsPopupComponent {
@@ -63,6 +1045,7 @@ impl OptionsPopupComponent {
 			key_config,
 			options,
 			theme,
+			selected_line: Cell::new(0),
 		}
 	}
 
@@ -70,6 +1053,10 @@ impl OptionsPopupComponent {
 		let mut txt: Vec<Spans> = Vec::with_capacity(10);
 
 		self.add_status(&mut txt, width);
+		self.add_branches(&mut txt, width);
+		self.add_files(&mut txt, width);
+		self.add_interface(&mut txt, width);
+		self.add_confirmations(&mut txt, width);
 
 		txt
 	}
@@ -89,6 +1076,13 @@ impl OptionsPopupComponent {
 			},
 			self.is_select(AppOption::StatusShowUntracked),
 		);
+		self.add_entry(
+			txt,
+			width,
+			"Sort files by",
+			self.options.borrow().status_sort.name(),
+			self.is_select(AppOption::StatusSort),
+		);
 		Self::add_header(txt, "");
 
 		Self::add_header(txt, "Diff");
@@ -115,6 +1109,195 @@ impl OptionsPopupComponent {
 		);
 	}
 
+	fn add_branches(&self, txt: &mut Vec<Spans>, width: u16) {
+		Self::add_header(txt, "");
+
+		Self::add_header(txt, "Branches");
+		self.add_entry(
+			txt,
+			width,
+			"Autostash",
+			&self.options.borrow().autostash.to_string(),
+			self.is_select(AppOption::Autostash),
+		);
+		self.add_entry(
+			txt,
+			width,
+			"Rebase update refs",
+			&self.options.borrow().rebase_update_refs.to_string(),
+			self.is_select(AppOption::RebaseUpdateRefs),
+		);
+		self.add_entry(
+			txt,
+			width,
+			"Fetch prune",
+			&self.options.borrow().fetch_prune.to_string(),
+			self.is_select(AppOption::FetchPrune),
+		);
+		self.add_entry(
+			txt,
+			width,
+			"Pull mode",
+			match self.options.borrow().pull_mode {
+				None => "Gitconfig",
+				Some(PullMode::Merge) => "Merge",
+				Some(PullMode::Rebase) => "Rebase",
+				Some(PullMode::FastForwardOnly) => "FF only",
+			},
+			self.is_select(AppOption::PullMode),
+		);
+		self.add_entry(
+			txt,
+			width,
+			"Push follow tags",
+			&self.options.borrow().push_follow_tags.to_string(),
+			self.is_select(AppOption::PushFollowTags),
+		);
+		self.add_entry(
+			txt,
+			width,
+			"Auto fetch",
+			&self.options.borrow().auto_fetch.name(),
+			self.is_select(AppOption::AutoFetch),
+		);
+		self.add_entry(
+			txt,
+			width,
+			"Network retries",
+			&self.options.borrow().network_retries.name(),
+			self.is_select(AppOption::NetworkRetries),
+		);
+	}
+
+	fn add_files(&self, txt: &mut Vec<Spans>, width: u16) {
+		Self::add_header(txt, "");
+
+		Self::add_header(txt, "Files");
+		self.add_entry(
+			txt,
+			width,
+			"Copy path format",
+			self.options.borrow().copy_path_format.name(),
+			self.is_select(AppOption::CopyPathFormat),
+		);
+		self.add_entry(
+			txt,
+			width,
+			"Clipboard",
+			self.options.borrow().clipboard.name(),
+			self.is_select(AppOption::Clipboard),
+		);
+	}
+
+	fn add_interface(&self, txt: &mut Vec<Spans>, width: u16) {
+		Self::add_header(txt, "");
+
+		Self::add_header(txt, "Interface");
+		self.add_entry(
+			txt,
+			width,
+			"Mouse",
+			&self.options.borrow().mouse.enabled().to_string(),
+			self.is_select(AppOption::Mouse),
+		);
+		self.add_entry(
+			txt,
+			width,
+			"Nerd Font icons",
+			&self.options.borrow().icons.enabled().to_string(),
+			self.is_select(AppOption::Icons),
+		);
+		self.add_entry(
+			txt,
+			width,
+			"Compact mode",
+			&self.options.borrow().compact.enabled().to_string(),
+			self.is_select(AppOption::Compact),
+		);
+		self.add_entry(
+			txt,
+			width,
+			"Terminal title",
+			&self
+				.options
+				.borrow()
+				.terminal_title
+				.enabled()
+				.to_string(),
+			self.is_select(AppOption::TerminalTitle),
+		);
+		self.add_entry(
+			txt,
+			width,
+			"Notifications",
+			&self.options.borrow().toast_timeout.name(),
+			self.is_select(AppOption::ToastTimeout),
+		);
+		self.add_entry(
+			txt,
+			width,
+			"Start tab",
+			self.options.borrow().start_tab.name(),
+			self.is_select(AppOption::StartTab),
+		);
+
+		let layouts = [
+			(
+				"Status",
+				self.options.borrow().status_layout,
+				AppOption::StatusSplit,
+				AppOption::StatusOrientation,
+			),
+			(
+				"Log",
+				self.options.borrow().log_layout,
+				AppOption::LogSplit,
+				AppOption::LogOrientation,
+			),
+			(
+				"Files",
+				self.options.borrow().files_layout,
+				AppOption::FilesSplit,
+				AppOption::FilesOrientation,
+			),
+		];
+
+		for (tab, layout, split, orientation) in layouts {
+			self.add_entry(
+				txt,
+				width,
+				&format!("{} split", tab),
+				&layout.split_name(),
+				self.is_select(split),
+			);
+			self.add_entry(
+				txt,
+				width,
+				&format!("{} orientation", tab),
+				layout.orientation_name(),
+				self.is_select(orientation),
+			);
+		}
+	}
+
+	fn add_confirmations(&self, txt: &mut Vec<Spans>, width: u16) {
+		Self::add_header(txt, "");
+
+		Self::add_header(txt, "Confirmations");
+		let confirmations = self.options.borrow().confirmations;
+		let mut kind = Some(Confirmation::StashDrop);
+		while let Some(current) = kind {
+			self.add_entry(
+				txt,
+				width,
+				current.name(),
+				&confirmations.get(current).to_string(),
+				self.is_select(AppOption::Confirm(current)),
+			);
+			kind = current.next();
+		}
+	}
+
 	fn is_select(&self, kind: AppOption) -> bool {
 		self.selection == kind
 	}
@@ -131,10 +1314,14 @@ impl OptionsPopupComponent {
 		&self,
 		txt: &mut Vec<Spans>,
 		width: u16,
-		entry: &'static str,
+		entry: &str,
 		value: &str,
 		selected: bool,
 	) {
+		if selected {
+			self.selected_line.set(txt.len());
+		}
+
 		let half = usize::from(width / 2);
 		txt.push(Spans::from(vec![
 			Span::styled(
@@ -152,21 +1339,56 @@ impl OptionsPopupComponent {
 		if up {
 			self.selection = match self.selection {
 				AppOption::StatusShowUntracked => {
-					AppOption::DiffInterhunkLines
+					AppOption::Confirm(Confirmation::DeleteBranch)
 				}
-				AppOption::DiffIgnoreWhitespaces => {
+				AppOption::StatusSort => {
 					AppOption::StatusShowUntracked
 				}
+				AppOption::DiffIgnoreWhitespaces => {
+					AppOption::StatusSort
+				}
 				AppOption::DiffContextLines => {
 					AppOption::DiffIgnoreWhitespaces
 				}
 				AppOption::DiffInterhunkLines => {
 					AppOption::DiffContextLines
 				}
+				AppOption::Autostash => AppOption::DiffInterhunkLines,
+				AppOption::RebaseUpdateRefs => AppOption::Autostash,
+				AppOption::FetchPrune => AppOption::RebaseUpdateRefs,
+				AppOption::PullMode => AppOption::FetchPrune,
+				AppOption::PushFollowTags => AppOption::PullMode,
+				AppOption::AutoFetch => AppOption::PushFollowTags,
+				AppOption::NetworkRetries => AppOption::AutoFetch,
+				AppOption::CopyPathFormat => {
+					AppOption::NetworkRetries
+				}
+				AppOption::Clipboard => AppOption::CopyPathFormat,
+				AppOption::Mouse => AppOption::Clipboard,
+				AppOption::Icons => AppOption::Mouse,
+				AppOption::Compact => AppOption::Icons,
+				AppOption::TerminalTitle => AppOption::Compact,
+				AppOption::ToastTimeout => AppOption::TerminalTitle,
+				AppOption::StartTab => AppOption::ToastTimeout,
+				AppOption::StatusSplit => AppOption::StartTab,
+				AppOption::StatusOrientation => {
+					AppOption::StatusSplit
+				}
+				AppOption::LogSplit => AppOption::StatusOrientation,
+				AppOption::LogOrientation => AppOption::LogSplit,
+				AppOption::FilesSplit => AppOption::LogOrientation,
+				AppOption::FilesOrientation => AppOption::FilesSplit,
+				AppOption::Confirm(kind) => kind.prev().map_or(
+					AppOption::FilesOrientation,
+					AppOption::Confirm,
+				),
 			};
 		} else {
 			self.selection = match self.selection {
 				AppOption::StatusShowUntracked => {
+					AppOption::StatusSort
+				}
+				AppOption::StatusSort => {
 					AppOption::DiffIgnoreWhitespaces
 				}
 				AppOption::DiffIgnoreWhitespaces => {
@@ -175,13 +1397,74 @@ impl OptionsPopupComponent {
 				AppOption::DiffContextLines => {
 					AppOption::DiffInterhunkLines
 				}
-				AppOption::DiffInterhunkLines => {
-					AppOption::StatusShowUntracked
+				AppOption::DiffInterhunkLines => AppOption::Autostash,
+				AppOption::Autostash => AppOption::RebaseUpdateRefs,
+				AppOption::RebaseUpdateRefs => AppOption::FetchPrune,
+				AppOption::FetchPrune => AppOption::PullMode,
+				AppOption::PullMode => AppOption::PushFollowTags,
+				AppOption::PushFollowTags => AppOption::AutoFetch,
+				AppOption::AutoFetch => AppOption::NetworkRetries,
+				AppOption::NetworkRetries => {
+					AppOption::CopyPathFormat
 				}
+				AppOption::CopyPathFormat => AppOption::Clipboard,
+				AppOption::Clipboard => AppOption::Mouse,
+				AppOption::Mouse => AppOption::Icons,
+				AppOption::Icons => AppOption::Compact,
+				AppOption::Compact => AppOption::TerminalTitle,
+				AppOption::TerminalTitle => AppOption::ToastTimeout,
+				AppOption::ToastTimeout => AppOption::StartTab,
+				AppOption::StartTab => AppOption::StatusSplit,
+				AppOption::StatusSplit => {
+					AppOption::StatusOrientation
+				}
+				AppOption::StatusOrientation => AppOption::LogSplit,
+				AppOption::LogSplit => AppOption::LogOrientation,
+				AppOption::LogOrientation => AppOption::FilesSplit,
+				AppOption::FilesSplit => AppOption::FilesOrientation,
+				AppOption::FilesOrientation => {
+					AppOption::Confirm(Confirmation::StashDrop)
+				}
+				AppOption::Confirm(kind) => kind.next().map_or(
+					AppOption::StatusShowUntracked,
+					AppOption::Confirm,
+				),
 			};
 		}
 	}
 
+	/// applies `split` or flipping the orientation, whichever the
+	/// selection names, to the layout of its tab
+	fn switch_layout(&mut self, split: fn(PaneLayout) -> PaneLayout) {
+		let mut options = self.options.borrow_mut();
+		let (layout, orientation) = match self.selection {
+			AppOption::StatusSplit => {
+				(&mut options.status_layout, false)
+			}
+			AppOption::StatusOrientation => {
+				(&mut options.status_layout, true)
+			}
+			AppOption::LogSplit => (&mut options.log_layout, false),
+			AppOption::LogOrientation => {
+				(&mut options.log_layout, true)
+			}
+			AppOption::FilesSplit => {
+				(&mut options.files_layout, false)
+			}
+			AppOption::FilesOrientation => {
+				(&mut options.files_layout, true)
+			}
+			_ => return,
+		};
+
+		*layout = if orientation {
+			layout.toggled_orientation()
+		} else {
+			split(*layout)
+		};
+	}
+
+	#[allow(clippy::too_many_lines)]
 	fn switch_option(&mut self, right: bool) {
 		if right {
 			match self.selection {
@@ -205,6 +1488,11 @@ impl OptionsPopupComponent {
 					self.options.borrow_mut().status_show_untracked =
 						untracked;
 				}
+				AppOption::StatusSort => {
+					let old = self.options.borrow().status_sort;
+					self.options.borrow_mut().status_sort =
+						old.next();
+				}
 				AppOption::DiffIgnoreWhitespaces => {
 					let old =
 						self.options.borrow().diff.ignore_whitespace;
@@ -224,6 +1512,95 @@ impl OptionsPopupComponent {
 					self.options.borrow_mut().diff.interhunk_lines =
 						old.saturating_add(1);
 				}
+				AppOption::Autostash => {
+					let old = self.options.borrow().autostash;
+					self.options.borrow_mut().autostash = !old;
+				}
+				AppOption::RebaseUpdateRefs => {
+					let old =
+						self.options.borrow().rebase_update_refs;
+					self.options.borrow_mut().rebase_update_refs =
+						!old;
+				}
+				AppOption::FetchPrune => {
+					let old = self.options.borrow().fetch_prune;
+					self.options.borrow_mut().fetch_prune = !old;
+				}
+				AppOption::PullMode => {
+					let mode = match self.options.borrow().pull_mode {
+						None => Some(PullMode::Merge),
+						Some(PullMode::Merge) => {
+							Some(PullMode::Rebase)
+						}
+						Some(PullMode::Rebase) => {
+							Some(PullMode::FastForwardOnly)
+						}
+						Some(PullMode::FastForwardOnly) => None,
+					};
+					self.options.borrow_mut().pull_mode = mode;
+				}
+				AppOption::PushFollowTags => {
+					let old = self.options.borrow().push_follow_tags;
+					self.options.borrow_mut().push_follow_tags = !old;
+				}
+				AppOption::AutoFetch => {
+					let old = self.options.borrow().auto_fetch;
+					self.options.borrow_mut().auto_fetch = old.next();
+				}
+				AppOption::NetworkRetries => {
+					let old = self.options.borrow().network_retries;
+					self.options.borrow_mut().network_retries =
+						old.next();
+				}
+				AppOption::CopyPathFormat => {
+					let old = self.options.borrow().copy_path_format;
+					self.options.borrow_mut().copy_path_format =
+						old.next();
+				}
+				AppOption::Clipboard => {
+					let old = self.options.borrow().clipboard;
+					self.options.borrow_mut().clipboard = old.next();
+				}
+				AppOption::Mouse => {
+					let old = self.options.borrow().mouse;
+					self.options.borrow_mut().mouse = old.toggled();
+				}
+				AppOption::Icons => {
+					let old = self.options.borrow().icons;
+					self.options.borrow_mut().icons = old.toggled();
+				}
+				AppOption::Compact => {
+					let old = self.options.borrow().compact;
+					self.options.borrow_mut().compact = old.toggled();
+				}
+				AppOption::TerminalTitle => {
+					let old = self.options.borrow().terminal_title;
+					self.options.borrow_mut().terminal_title =
+						old.toggled();
+				}
+				AppOption::ToastTimeout => {
+					let old = self.options.borrow().toast_timeout;
+					self.options.borrow_mut().toast_timeout =
+						old.next();
+				}
+				AppOption::StartTab => {
+					let old = self.options.borrow().start_tab;
+					self.options.borrow_mut().start_tab = old.next();
+				}
+				AppOption::StatusSplit
+				| AppOption::StatusOrientation
+				| AppOption::LogSplit
+				| AppOption::LogOrientation
+				| AppOption::FilesSplit
+				| AppOption::FilesOrientation => {
+					self.switch_layout(PaneLayout::next_split);
+				}
+				AppOption::Confirm(kind) => {
+					self.options
+						.borrow_mut()
+						.confirmations
+						.toggle(kind);
+				}
 			};
 		} else {
 			match self.selection {
@@ -247,6 +1624,11 @@ impl OptionsPopupComponent {
 					self.options.borrow_mut().status_show_untracked =
 						untracked;
 				}
+				AppOption::StatusSort => {
+					let old = self.options.borrow().status_sort;
+					self.options.borrow_mut().status_sort =
+						old.prev();
+				}
 				AppOption::DiffIgnoreWhitespaces => {
 					let old =
 						self.options.borrow().diff.ignore_whitespace;
@@ -266,6 +1648,95 @@ impl OptionsPopupComponent {
 					self.options.borrow_mut().diff.interhunk_lines =
 						old.saturating_sub(1);
 				}
+				AppOption::Autostash => {
+					let old = self.options.borrow().autostash;
+					self.options.borrow_mut().autostash = !old;
+				}
+				AppOption::RebaseUpdateRefs => {
+					let old =
+						self.options.borrow().rebase_update_refs;
+					self.options.borrow_mut().rebase_update_refs =
+						!old;
+				}
+				AppOption::FetchPrune => {
+					let old = self.options.borrow().fetch_prune;
+					self.options.borrow_mut().fetch_prune = !old;
+				}
+				AppOption::PullMode => {
+					let mode = match self.options.borrow().pull_mode {
+						None => Some(PullMode::FastForwardOnly),
+						Some(PullMode::FastForwardOnly) => {
+							Some(PullMode::Rebase)
+						}
+						Some(PullMode::Rebase) => {
+							Some(PullMode::Merge)
+						}
+						Some(PullMode::Merge) => None,
+					};
+					self.options.borrow_mut().pull_mode = mode;
+				}
+				AppOption::PushFollowTags => {
+					let old = self.options.borrow().push_follow_tags;
+					self.options.borrow_mut().push_follow_tags = !old;
+				}
+				AppOption::AutoFetch => {
+					let old = self.options.borrow().auto_fetch;
+					self.options.borrow_mut().auto_fetch = old.prev();
+				}
+				AppOption::NetworkRetries => {
+					let old = self.options.borrow().network_retries;
+					self.options.borrow_mut().network_retries =
+						old.prev();
+				}
+				AppOption::CopyPathFormat => {
+					let old = self.options.borrow().copy_path_format;
+					self.options.borrow_mut().copy_path_format =
+						old.prev();
+				}
+				AppOption::Clipboard => {
+					let old = self.options.borrow().clipboard;
+					self.options.borrow_mut().clipboard = old.prev();
+				}
+				AppOption::Mouse => {
+					let old = self.options.borrow().mouse;
+					self.options.borrow_mut().mouse = old.toggled();
+				}
+				AppOption::Icons => {
+					let old = self.options.borrow().icons;
+					self.options.borrow_mut().icons = old.toggled();
+				}
+				AppOption::Compact => {
+					let old = self.options.borrow().compact;
+					self.options.borrow_mut().compact = old.toggled();
+				}
+				AppOption::TerminalTitle => {
+					let old = self.options.borrow().terminal_title;
+					self.options.borrow_mut().terminal_title =
+						old.toggled();
+				}
+				AppOption::ToastTimeout => {
+					let old = self.options.borrow().toast_timeout;
+					self.options.borrow_mut().toast_timeout =
+						old.prev();
+				}
+				AppOption::StartTab => {
+					let old = self.options.borrow().start_tab;
+					self.options.borrow_mut().start_tab = old.prev();
+				}
+				AppOption::StatusSplit
+				| AppOption::StatusOrientation
+				| AppOption::LogSplit
+				| AppOption::LogOrientation
+				| AppOption::FilesSplit
+				| AppOption::FilesOrientation => {
+					self.switch_layout(PaneLayout::prev_split);
+				}
+				AppOption::Confirm(kind) => {
+					self.options
+						.borrow_mut()
+						.confirmations
+						.toggle(kind);
+				}
 			};
 		}
 
@@ -281,15 +1752,22 @@ impl DrawableComponent for OptionsPopupComponent {
 		area: Rect,
 	) -> Result<()> {
 		if self.is_visible() {
-			const SIZE: (u16, u16) = (50, 10);
+			const SIZE: (u16, u16) = (50, 31);
 			let area =
 				ui::centered_rect_absolute(SIZE.0, SIZE.1, area);
 
 			let width = area.width;
+			let text = self.get_text(width);
+			let height = area.height.saturating_sub(2);
+			let scroll = u16::try_from(self.selected_line.get())
+				.unwrap_or_default()
+				.saturating_add(1)
+				.saturating_sub(height);
 
 			f.render_widget(Clear, area);
 			f.render_widget(
-				Paragraph::new(self.get_text(width))
+				Paragraph::new(text)
+					.scroll((scroll, 0))
 					.block(
 						Block::default()
 							.borders(Borders::ALL)
@@ -344,24 +1822,26 @@ impl Component for OptionsPopupComponent {
 	) -> Result<EventState> {
 		if self.is_visible() {
 			if let Event::Key(key) = &event {
-				if key_match(key, self.key_config.keys.exit_popup) {
+				if key_match(key, self.key_config.keys().exit_popup) {
 					self.hide();
-				} else if key_match(key, self.key_config.keys.move_up)
-				{
+				} else if key_match(
+					key,
+					self.key_config.keys().move_up,
+				) {
 					self.move_selection(true);
 				} else if key_match(
 					key,
-					self.key_config.keys.move_down,
+					self.key_config.keys().move_down,
 				) {
 					self.move_selection(false);
 				} else if key_match(
 					key,
-					self.key_config.keys.move_right,
+					self.key_config.keys().move_right,
 				) {
 					self.switch_option(true);
 				} else if key_match(
 					key,
-					self.key_config.keys.move_left,
+					self.key_config.keys().move_left,
 				) {
 					self.switch_option(false);
 				}
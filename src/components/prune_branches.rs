@@ -0,0 +1,269 @@
+use super::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState, ScrollType,
+};
+use crate::{
+	keys::{key_match, SharedKeyConfig},
+	queue::{Action, InternalEvent, Queue},
+	strings::{self, symbol},
+	ui::{self, style::SharedTheme},
+};
+use anyhow::Result;
+use asyncgit::sync::{self, RepoPathRef};
+use crossterm::event::Event;
+use tui::{
+	backend::Backend,
+	layout::{Margin, Rect},
+	text::{Span, Spans},
+	widgets::{Block, BorderType, Borders, Clear, Paragraph},
+	Frame,
+};
+
+/// lists local branches whose upstream is gone to delete them at once
+pub struct PruneBranchesPopup {
+	repo: RepoPathRef,
+	queue: Queue,
+	visible: bool,
+	/// branch names and whether they are marked for deletion
+	branches: Vec<(String, bool)>,
+	selection: usize,
+	theme: SharedTheme,
+	key_config: SharedKeyConfig,
+}
+
+impl PruneBranchesPopup {
+	///
+	pub fn new(
+		repo: RepoPathRef,
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			repo,
+			queue: queue.clone(),
+			visible: false,
+			branches: Vec::new(),
+			selection: 0,
+			theme,
+			key_config,
+		}
+	}
+
+	///
+	pub fn open(&mut self) -> Result<()> {
+		self.branches =
+			sync::get_branches_gone_upstream(&self.repo.borrow())?
+				.into_iter()
+				.map(|name| (name, true))
+				.collect();
+		self.selection = 0;
+		self.show()?;
+
+		Ok(())
+	}
+
+	fn move_selection(&mut self, move_type: ScrollType) {
+		let new_selection = match move_type {
+			ScrollType::Up => self.selection.saturating_sub(1),
+			ScrollType::Down => self.selection.saturating_add(1),
+			_ => self.selection,
+		};
+
+		self.selection = new_selection
+			.clamp(0, self.branches.len().saturating_sub(1));
+	}
+
+	fn toggle_mark(&mut self) {
+		if let Some((_, marked)) =
+			self.branches.get_mut(self.selection)
+		{
+			*marked = !*marked;
+		}
+	}
+
+	fn marked(&self) -> Vec<String> {
+		self.branches
+			.iter()
+			.filter(|(_, marked)| *marked)
+			.map(|(name, _)| name.clone())
+			.collect()
+	}
+
+	fn confirm(&mut self) {
+		let marked = self.marked();
+		if !marked.is_empty() {
+			self.queue.push(InternalEvent::ConfirmAction(
+				Action::PruneBranches(marked),
+			));
+			self.hide();
+		}
+	}
+}
+
+impl DrawableComponent for PruneBranchesPopup {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		area: Rect,
+	) -> Result<()> {
+		if self.is_visible() {
+			const MAX_SIZE: (u16, u16) = (60, 20);
+
+			let area = ui::centered_rect_absolute(
+				MAX_SIZE.0, MAX_SIZE.1, area,
+			);
+
+			f.render_widget(Clear, area);
+			f.render_widget(
+				Block::default()
+					.borders(Borders::all())
+					.border_style(self.theme.popup_border())
+					.border_type(BorderType::Thick)
+					.title(Span::styled(
+						strings::POPUP_TITLE_PRUNE_BRANCHES,
+						self.theme.title(true),
+					)),
+				area,
+			);
+
+			let area = area.inner(&Margin {
+				horizontal: 1,
+				vertical: 1,
+			});
+
+			if self.branches.is_empty() {
+				f.render_widget(
+					Paragraph::new(strings::prune_branches_none())
+						.style(self.theme.text(false, false)),
+					area,
+				);
+				return Ok(());
+			}
+
+			let height = usize::from(area.height);
+			let scroll_top = ui::calc_scroll_top(
+				0,
+				height.saturating_sub(1),
+				self.selection,
+			);
+
+			let items = self
+				.branches
+				.iter()
+				.enumerate()
+				.skip(scroll_top)
+				.take(height)
+				.map(|(idx, (name, marked))| {
+					let selected = idx == self.selection;
+					Spans::from(vec![
+						Span::styled(
+							format!(
+								"{} ",
+								if *marked {
+									symbol::CHECKMARK
+								} else {
+									symbol::EMPTY_SPACE
+								}
+							),
+							self.theme.log_marker(selected),
+						),
+						Span::styled(
+							name.clone(),
+							self.theme.text(true, selected),
+						),
+					])
+				});
+
+			ui::draw_list_block(f, area, Block::default(), items);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for PruneBranchesPopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			out.push(
+				CommandInfo::new(
+					strings::commands::close_popup(&self.key_config),
+					true,
+					true,
+				)
+				.order(1),
+			);
+
+			out.push(CommandInfo::new(
+				strings::commands::prune_branches_confirm(
+					&self.key_config,
+				),
+				!self.marked().is_empty(),
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::branch_mark(&self.key_config),
+				!self.branches.is_empty(),
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: &Event) -> Result<EventState> {
+		if self.is_visible() {
+			if let Event::Key(key) = event {
+				if key_match(key, self.key_config.keys().exit_popup) {
+					self.hide();
+				} else if key_match(key, self.key_config.keys().enter)
+				{
+					self.confirm();
+				} else if key_match(
+					key,
+					self.key_config.keys().branch_mark,
+				) {
+					self.toggle_mark();
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_down,
+				) {
+					self.move_selection(ScrollType::Down);
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_up,
+				) {
+					self.move_selection(ScrollType::Up);
+				}
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+		Ok(())
+	}
+}
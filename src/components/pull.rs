@@ -1,11 +1,12 @@
 use super::PushComponent;
 use crate::{
 	components::{
-		cred::CredComponent, visibility_blocking, CommandBlocking,
-		CommandInfo, Component, DrawableComponent, EventState,
+		cred::CredComponent, utils::autostash::run_with_autostash,
+		visibility_blocking, CommandBlocking, CommandInfo, Component,
+		DrawableComponent, EventState, SharedOptions,
 	},
 	keys::SharedKeyConfig,
-	queue::{Action, InternalEvent, Queue},
+	queue::{Action, AutostashOp, InternalEvent, Queue},
 	strings, try_or_popup,
 	ui::{self, style::SharedTheme},
 };
@@ -17,7 +18,7 @@ use asyncgit::{
 			extract_username_password, need_username_password,
 			BasicAuthCredential,
 		},
-		get_default_remote, RepoPathRef,
+		get_default_remote, PullMode, RepoPathRef,
 	},
 	AsyncGitNotification, AsyncPull, FetchRequest, RemoteProgress,
 };
@@ -43,6 +44,7 @@ pub struct PullComponent {
 	theme: SharedTheme,
 	key_config: SharedKeyConfig,
 	input_cred: CredComponent,
+	options: SharedOptions,
 }
 
 impl PullComponent {
@@ -53,6 +55,7 @@ impl PullComponent {
 		sender: &Sender<AsyncGitNotification>,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
+		options: SharedOptions,
 	) -> Self {
 		Self {
 			repo: repo.clone(),
@@ -68,6 +71,7 @@ impl PullComponent {
 			),
 			theme,
 			key_config,
+			options,
 		}
 	}
 
@@ -100,6 +104,7 @@ impl PullComponent {
 		self.git_fetch.request(FetchRequest {
 			remote: get_default_remote(&self.repo.borrow())?,
 			branch: self.branch.clone(),
+			retries: self.options.borrow().network_retries.retries(),
 			basic_credential: cred,
 		})?;
 
@@ -151,14 +156,40 @@ impl PullComponent {
 			&self.branch,
 		)?;
 		if branch_compare.behind > 0 {
-			let ff_res = sync::branch_merge_upstream_fastforward(
+			let ff_res = run_with_autostash(
 				&self.repo.borrow(),
-				&self.branch,
+				&self.queue,
+				self.options.borrow().autostash,
+				AutostashOp::PullMerge(self.rebase()),
+				|| {
+					sync::branch_merge_upstream_fastforward(
+						&self.repo.borrow(),
+						&self.branch,
+					)
+				},
 			);
-			if let Err(err) = ff_res {
-				log::trace!("ff failed: {}", err);
-				self.confirm_merge(branch_compare.behind);
+			match ff_res {
+				Ok(Some(())) => {
+					self.queue.push(InternalEvent::ShowToast(
+						strings::toast_pulled(&self.branch),
+					));
+				}
+				Err(err) => {
+					log::trace!("ff failed: {}", err);
+					if self.pull_mode() == PullMode::FastForwardOnly {
+						self.queue.push(InternalEvent::ShowErrorMsg(
+							strings::pull_ff_only_msg(&self.branch),
+						));
+					} else {
+						self.confirm_merge(branch_compare.behind);
+					}
+				}
+				Ok(None) => (),
 			}
+		} else {
+			self.queue.push(InternalEvent::ShowToast(
+				strings::toast_up_to_date(&self.branch),
+			));
 		}
 
 		self.hide();
@@ -167,35 +198,114 @@ impl PullComponent {
 	}
 
 	pub fn try_conflict_free_merge(&self, rebase: bool) {
+		let autostash = self.options.borrow().autostash;
+
 		if rebase {
 			try_or_popup!(
 				self,
 				"rebase failed:",
-				sync::merge_upstream_rebase(
-					&self.repo.borrow(),
-					&self.branch
-				)
+				self.merge_upstream(autostash, rebase)
 			);
 		} else {
 			try_or_popup!(
 				self,
 				"merge failed:",
-				sync::merge_upstream_commit(
-					&self.repo.borrow(),
-					&self.branch
-				)
+				self.merge_upstream(autostash, rebase)
 			);
 		}
 	}
 
+	/// retry of merging pulled changes with local changes stashed,
+	/// tries a fast-forward first
+	pub fn merge_with_autostash(&self, rebase: bool) {
+		try_or_popup!(
+			self,
+			"merge failed:",
+			run_with_autostash(
+				&self.repo.borrow(),
+				&self.queue,
+				true,
+				AutostashOp::PullMerge(rebase),
+				|| {
+					let repo = self.repo.borrow();
+
+					if sync::branch_merge_upstream_fastforward(
+						&repo,
+						&self.branch,
+					)
+					.is_ok()
+					{
+						return Ok(());
+					}
+
+					if rebase {
+						sync::pull_rebase(
+							&repo,
+							&self.branch,
+							self.options.borrow().rebase_update_refs,
+						)
+						.map(|_| ())
+					} else {
+						sync::merge_upstream_commit(
+							&repo,
+							&self.branch,
+						)
+						.map(|_| ())
+					}
+				},
+			)
+		);
+	}
+
+	fn merge_upstream(
+		&self,
+		autostash: bool,
+		rebase: bool,
+	) -> Result<()> {
+		run_with_autostash(
+			&self.repo.borrow(),
+			&self.queue,
+			autostash,
+			AutostashOp::PullMerge(rebase),
+			|| {
+				// conflicts leave the rebase in progress
+				if rebase {
+					sync::pull_rebase(
+						&self.repo.borrow(),
+						&self.branch,
+						self.options.borrow().rebase_update_refs,
+					)
+					.map(|_| ())
+				} else {
+					sync::merge_upstream_commit(
+						&self.repo.borrow(),
+						&self.branch,
+					)
+					.map(|_| ())
+				}
+			},
+		)?;
+
+		Ok(())
+	}
+
+	/// picked in the options or configured by `pull.rebase`/`pull.ff`
+	fn pull_mode(&self) -> PullMode {
+		self.options.borrow().pull_mode.unwrap_or_else(|| {
+			sync::config_pull_mode(&self.repo.borrow())
+				.unwrap_or_default()
+		})
+	}
+
+	fn rebase(&self) -> bool {
+		self.pull_mode() == PullMode::Rebase
+	}
+
 	fn confirm_merge(&mut self, incoming: usize) {
 		self.queue.push(InternalEvent::ConfirmAction(
 			Action::PullMerge {
 				incoming,
-				rebase: sync::config_is_pull_rebase(
-					&self.repo.borrow(),
-				)
-				.unwrap_or_default(),
+				rebase: self.rebase(),
 			},
 		));
 		self.hide();
@@ -226,7 +336,7 @@ impl DrawableComponent for PullComponent {
 							))
 							.borders(Borders::ALL)
 							.border_type(BorderType::Thick)
-							.border_style(self.theme.block(true)),
+							.border_style(self.theme.popup_border()),
 					)
 					.gauge_style(self.theme.push_gauge())
 					.percent(u16::from(progress)),
@@ -2,9 +2,10 @@ use crate::{
 	components::{
 		cred::CredComponent, visibility_blocking, CommandBlocking,
 		CommandInfo, Component, DrawableComponent, EventState,
+		SharedOptions,
 	},
 	keys::{key_match, SharedKeyConfig},
-	queue::{InternalEvent, Queue},
+	queue::{Action, InternalEvent, Queue},
 	strings,
 	ui::{self, style::SharedTheme},
 };
@@ -15,7 +16,8 @@ use asyncgit::{
 			extract_username_password, need_username_password,
 			BasicAuthCredential,
 		},
-		get_branch_remote, get_default_remote, RepoPathRef,
+		get_branch_remote, get_default_remote, push_destination,
+		RepoPathRef,
 	},
 	AsyncGitNotification, AsyncPush, PushRequest, PushType,
 	RemoteProgress, RemoteProgressState,
@@ -26,7 +28,9 @@ use tui::{
 	backend::Backend,
 	layout::Rect,
 	text::Span,
-	widgets::{Block, BorderType, Borders, Clear, Gauge},
+	widgets::{
+		Block, BorderType, Borders, Clear, Gauge, Paragraph, Wrap,
+	},
 	Frame,
 };
 
@@ -49,6 +53,7 @@ impl PushComponentModifier {
 }
 
 ///
+#[allow(clippy::struct_excessive_bools)]
 pub struct PushComponent {
 	repo: RepoPathRef,
 	modifier: PushComponentModifier,
@@ -57,8 +62,21 @@ pub struct PushComponent {
 	progress: Option<RemoteProgress>,
 	pending: bool,
 	branch: String,
+	/// remote to push to instead of the upstream or default one
+	remote: Option<String>,
+	/// name on the remote if it differs from `branch`
+	remote_branch: Option<String>,
+	/// more branches pushed along (`push.default=matching`),
+	/// or more tags deleted along
+	matching: Vec<String>,
 	push_type: PushType,
+	/// remote to set as upstream, while asking whether to do so
+	ask_upstream: Option<String>,
+	set_upstream: bool,
+	/// a force push only overwrites what we fetched last
+	force_with_lease: bool,
 	queue: Queue,
+	options: SharedOptions,
 	theme: SharedTheme,
 	key_config: SharedKeyConfig,
 	input_cred: CredComponent,
@@ -72,15 +90,23 @@ impl PushComponent {
 		sender: &Sender<AsyncGitNotification>,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
+		options: SharedOptions,
 	) -> Self {
 		Self {
 			repo: repo.clone(),
 			queue: queue.clone(),
+			options,
 			modifier: PushComponentModifier::None,
 			pending: false,
 			visible: false,
 			branch: String::new(),
+			remote: None,
+			remote_branch: None,
+			matching: Vec::new(),
 			push_type: PushType::Branch,
+			ask_upstream: None,
+			set_upstream: true,
+			force_with_lease: true,
 			git_push: AsyncPush::new(repo.borrow().clone(), sender),
 			progress: None,
 			input_cred: CredComponent::new(
@@ -100,8 +126,31 @@ impl PushComponent {
 		force: bool,
 		delete: bool,
 	) -> Result<()> {
+		self.remote = None;
+		self.remote_branch = None;
+		self.matching = Vec::new();
+
+		if matches!(push_type, PushType::Branch) && !delete {
+			match push_destination(&self.repo.borrow(), &branch) {
+				Ok(destination) => {
+					self.remote_branch = (destination.branch
+						!= branch)
+						.then(|| destination.branch);
+					self.remote = Some(destination.remote);
+					self.matching = destination.matching;
+				}
+				Err(e) => {
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						format!("push failed:\n{}", e),
+					));
+					return Ok(());
+				}
+			}
+		}
+
 		self.branch = branch;
 		self.push_type = push_type;
+		self.force_with_lease = true;
 		self.modifier = match (force, delete) {
 			(true, true) => PushComponentModifier::ForceDelete,
 			(false, true) => PushComponentModifier::Delete,
@@ -111,6 +160,138 @@ impl PushComponent {
 
 		self.show()?;
 
+		self.set_upstream = true;
+		self.ask_upstream = None;
+		if let Some(remote) = &self.remote {
+			if matches!(
+				get_branch_remote(&self.repo.borrow(), &self.branch),
+				Ok(None)
+			) {
+				self.ask_upstream = Some(remote.clone());
+				return Ok(());
+			}
+		}
+
+		self.start_push()
+	}
+
+	/// deletes `branch` on `remote`
+	pub fn delete_remote_branch(
+		&mut self,
+		remote: String,
+		branch: String,
+	) -> Result<()> {
+		self.branch = branch;
+		self.remote = Some(remote);
+		self.remote_branch = None;
+		self.matching = Vec::new();
+		self.push_type = PushType::Branch;
+		self.modifier = PushComponentModifier::Delete;
+		self.ask_upstream = None;
+
+		self.show()?;
+		self.start_push()
+	}
+
+	/// pushes `branch` to `remote_branch` on `remote`
+	/// regardless of its upstream
+	pub fn push_to(
+		&mut self,
+		branch: String,
+		remote: String,
+		remote_branch: String,
+	) -> Result<()> {
+		self.remote_branch =
+			(remote_branch != branch).then(|| remote_branch);
+		self.branch = branch;
+		self.remote = Some(remote.clone());
+		self.matching = Vec::new();
+		self.push_type = PushType::Branch;
+		self.modifier = PushComponentModifier::None;
+
+		self.show()?;
+
+		self.set_upstream = true;
+		self.ask_upstream = None;
+		if matches!(
+			get_branch_remote(&self.repo.borrow(), &self.branch),
+			Ok(None)
+		) {
+			self.ask_upstream = Some(remote);
+			return Ok(());
+		}
+
+		self.start_push()
+	}
+
+	/// pushes `tag` to `remote` or deletes it there
+	pub fn push_tag(
+		&mut self,
+		tag: String,
+		remote: String,
+		delete: bool,
+	) -> Result<()> {
+		self.branch = tag;
+		self.remote = Some(remote);
+		self.remote_branch = None;
+		self.matching = Vec::new();
+		self.push_type = PushType::Tag;
+		self.modifier = if delete {
+			PushComponentModifier::Delete
+		} else {
+			PushComponentModifier::None
+		};
+		self.ask_upstream = None;
+
+		self.show()?;
+		self.start_push()
+	}
+
+	/// deletes all of `tags` on `remote` in one go
+	pub fn delete_remote_tags(
+		&mut self,
+		mut tags: Vec<String>,
+		remote: String,
+	) -> Result<()> {
+		if tags.is_empty() {
+			return Ok(());
+		}
+
+		self.branch = tags.remove(0);
+		self.remote = Some(remote);
+		self.remote_branch = None;
+		self.matching = tags;
+		self.push_type = PushType::Tag;
+		self.modifier = PushComponentModifier::Delete;
+		self.ask_upstream = None;
+
+		self.show()?;
+		self.start_push()
+	}
+
+	/// retries the last push (that got rejected by the lease)
+	/// as a plain force push
+	pub fn force_push_unsafe(&mut self) -> Result<()> {
+		self.modifier = PushComponentModifier::Force;
+		self.force_with_lease = false;
+		self.ask_upstream = None;
+
+		self.show()?;
+		self.start_push()
+	}
+
+	/// runs the last push again the same way
+	pub fn retry(&mut self) -> Result<()> {
+		self.ask_upstream = None;
+
+		self.show()?;
+		self.start_push()
+	}
+
+	/// asks for credentials if needed before pushing
+	fn start_push(&mut self) -> Result<()> {
+		let force = self.modifier.force();
+
 		if need_username_password(&self.repo.borrow())? {
 			let cred = extract_username_password(&self.repo.borrow())
 				.unwrap_or_else(|_| {
@@ -132,7 +313,9 @@ impl PushComponent {
 		cred: Option<BasicAuthCredential>,
 		force: bool,
 	) -> Result<()> {
-		let remote = if let Ok(Some(remote)) =
+		let remote = if let Some(remote) = self.remote.clone() {
+			remote
+		} else if let Ok(Some(remote)) =
 			get_branch_remote(&self.repo.borrow(), &self.branch)
 		{
 			log::info!("push: branch '{}' has upstream for remote '{}' - using that",self.branch,remote);
@@ -153,9 +336,15 @@ impl PushComponent {
 		self.git_push.request(PushRequest {
 			remote,
 			branch: self.branch.clone(),
+			remote_branch: self.remote_branch.clone(),
 			push_type: self.push_type,
 			force,
+			force_with_lease: self.force_with_lease,
 			delete: self.modifier.delete(),
+			set_upstream: self.set_upstream,
+			follow_tags: self.options.borrow().push_follow_tags,
+			matching: self.matching.clone(),
+			retries: self.options.borrow().network_retries.retries(),
 			basic_credential: cred,
 		})?;
 		Ok(())
@@ -179,9 +368,20 @@ impl PushComponent {
 		self.progress = self.git_push.progress()?;
 
 		if !self.pending {
-			if let Some(err) = self.git_push.last_result()? {
-				self.queue.push(InternalEvent::ShowErrorMsg(
+			if self.git_push.lease_rejected()? {
+				self.queue.push(InternalEvent::ConfirmAction(
+					Action::ForcePushUnsafe(self.branch.clone()),
+				));
+			} else if let Some(err) = self.git_push.last_result()? {
+				self.queue.push(InternalEvent::ShowErrorRetry(
 					format!("push failed:\n{}", err),
+					Box::new(InternalEvent::RetryPush),
+				));
+			} else {
+				self.queue.push(InternalEvent::ShowToast(
+					strings::toast_pushed(
+						self.destination().as_deref(),
+					),
 				));
 			}
 			self.hide();
@@ -190,6 +390,17 @@ impl PushComponent {
 		Ok(())
 	}
 
+	/// `remote/branch` once the remote is known
+	fn destination(&self) -> Option<String> {
+		self.remote.as_ref().map(|remote| {
+			format!(
+				"{}/{}",
+				remote,
+				self.remote_branch.as_deref().unwrap_or(&self.branch)
+			)
+		})
+	}
+
 	///
 	pub const fn any_work_pending(&self) -> bool {
 		self.pending
@@ -213,22 +424,24 @@ impl PushComponent {
 	fn progress_state_name(state: &RemoteProgressState) -> String {
 		match state {
 			RemoteProgressState::PackingAddingObject => {
-				strings::PUSH_POPUP_STATES_ADDING
+				strings::PUSH_POPUP_STATES_ADDING.into()
 			}
 			RemoteProgressState::PackingDeltafiction => {
-				strings::PUSH_POPUP_STATES_DELTAS
+				strings::PUSH_POPUP_STATES_DELTAS.into()
 			}
 			RemoteProgressState::Pushing => {
-				strings::PUSH_POPUP_STATES_PUSHING
+				strings::PUSH_POPUP_STATES_PUSHING.into()
 			}
 			RemoteProgressState::Transfer => {
-				strings::PUSH_POPUP_STATES_TRANSFER
+				strings::PUSH_POPUP_STATES_TRANSFER.into()
+			}
+			RemoteProgressState::Retrying(retry) => {
+				strings::network_retry(retry.attempt, retry.max)
 			}
 			RemoteProgressState::Done => {
-				strings::PUSH_POPUP_STATES_DONE
+				strings::PUSH_POPUP_STATES_DONE.into()
 			}
 		}
-		.into()
 	}
 }
 
@@ -239,10 +452,43 @@ impl DrawableComponent for PushComponent {
 		rect: Rect,
 	) -> Result<()> {
 		if self.visible {
+			if let Some(remote) = &self.ask_upstream {
+				let area =
+					ui::centered_rect_absolute(50, 4, f.size());
+
+				f.render_widget(Clear, area);
+				f.render_widget(
+					Paragraph::new(
+						strings::push_popup_no_upstream_msg(
+							&self.branch,
+							remote,
+							self.remote_branch
+								.as_deref()
+								.unwrap_or(&self.branch),
+						),
+					)
+					.block(
+						Block::default()
+							.title(Span::styled(
+								strings::PUSH_POPUP_MSG,
+								self.theme.title(true),
+							))
+							.borders(Borders::ALL)
+							.border_type(BorderType::Thick)
+							.border_style(self.theme.popup_border()),
+					)
+					.style(self.theme.text(true, false))
+					.wrap(Wrap { trim: true }),
+					area,
+				);
+
+				return Ok(());
+			}
+
 			let (state, progress) =
 				Self::get_progress(&self.progress);
 
-			let area = ui::centered_rect_absolute(30, 3, f.size());
+			let area = ui::centered_rect_absolute(50, 3, f.size());
 
 			f.render_widget(Clear, area);
 			f.render_widget(
@@ -251,16 +497,15 @@ impl DrawableComponent for PushComponent {
 					.block(
 						Block::default()
 							.title(Span::styled(
-								if self.modifier.force() {
-									strings::FORCE_PUSH_POPUP_MSG
-								} else {
-									strings::PUSH_POPUP_MSG
-								},
+								strings::push_popup_title(
+									self.modifier.force(),
+									self.destination().as_deref(),
+								),
 								self.theme.title(true),
 							))
 							.borders(Borders::ALL)
 							.border_type(BorderType::Thick)
-							.border_style(self.theme.block(true)),
+							.border_style(self.theme.popup_border()),
 					)
 					.gauge_style(self.theme.push_gauge())
 					.percent(u16::from(progress)),
@@ -287,6 +532,28 @@ impl Component for PushComponent {
 			if self.input_cred.is_visible() {
 				return self.input_cred.commands(out, force_all);
 			}
+			if self.ask_upstream.is_some() {
+				out.push(CommandInfo::new(
+					strings::commands::push_set_upstream(
+						&self.key_config,
+					),
+					true,
+					true,
+				));
+				out.push(CommandInfo::new(
+					strings::commands::push_without_upstream(
+						&self.key_config,
+					),
+					true,
+					true,
+				));
+				out.push(CommandInfo::new(
+					strings::commands::close_popup(&self.key_config),
+					true,
+					true,
+				));
+				return visibility_blocking(self);
+			}
 			out.push(CommandInfo::new(
 				strings::commands::close_msg(&self.key_config),
 				!self.pending,
@@ -300,7 +567,26 @@ impl Component for PushComponent {
 	fn event(&mut self, ev: &Event) -> Result<EventState> {
 		if self.visible {
 			if let Event::Key(e) = ev {
-				if self.input_cred.is_visible() {
+				if self.ask_upstream.is_some() {
+					if key_match(e, self.key_config.keys().exit_popup)
+					{
+						self.ask_upstream = None;
+						self.hide();
+					} else if key_match(
+						e,
+						self.key_config.keys().enter,
+					) || key_match(
+						e,
+						self.key_config.keys().push,
+					) {
+						self.set_upstream = key_match(
+							e,
+							self.key_config.keys().enter,
+						);
+						self.ask_upstream = None;
+						self.start_push()?;
+					}
+				} else if self.input_cred.is_visible() {
 					self.input_cred.event(ev)?;
 
 					if self.input_cred.get_cred().is_complete()
@@ -314,7 +600,7 @@ impl Component for PushComponent {
 					}
 				} else if key_match(
 					e,
-					self.key_config.keys.exit_popup,
+					self.key_config.keys().exit_popup,
 				) && !self.pending
 				{
 					self.hide();
@@ -122,8 +122,13 @@ impl PushTagsComponent {
 
 		if !self.pending {
 			if let Some(err) = self.git_push.last_result()? {
-				self.queue.push(InternalEvent::ShowErrorMsg(
+				self.queue.push(InternalEvent::ShowErrorRetry(
 					format!("push tags failed:\n{}", err),
+					Box::new(InternalEvent::PushTags),
+				));
+			} else {
+				self.queue.push(InternalEvent::ShowToast(
+					strings::toast_pushed_tags(),
 				));
 			}
 			self.hide();
@@ -190,7 +195,7 @@ impl DrawableComponent for PushTagsComponent {
 							))
 							.borders(Borders::ALL)
 							.border_type(BorderType::Thick)
-							.border_style(self.theme.block(true)),
+							.border_style(self.theme.popup_border()),
 					)
 					.gauge_style(self.theme.push_gauge())
 					.percent(u16::from(progress)),
@@ -243,7 +248,7 @@ impl Component for PushTagsComponent {
 					}
 				} else if key_match(
 					e,
-					self.key_config.keys.exit_popup,
+					self.key_config.keys().exit_popup,
 				) && !self.pending
 				{
 					self.hide();
@@ -0,0 +1,290 @@
+use super::{
+	textinput::TextInputComponent, visibility_blocking,
+	CommandBlocking, CommandInfo, Component, DrawableComponent,
+	EventState, ScrollType,
+};
+use crate::{
+	keys::{key_match, SharedKeyConfig},
+	queue::{InternalEvent, Queue},
+	strings,
+	ui::{self, style::SharedTheme},
+};
+use anyhow::Result;
+use asyncgit::sync::{self, RepoPathRef};
+use crossterm::event::Event;
+use tui::{
+	backend::Backend,
+	layout::{Margin, Rect},
+	text::{Span, Spans},
+	widgets::{Block, BorderType, Borders, Clear},
+	Frame,
+};
+
+/// picks the remote and the remote branch name to push a branch to
+pub struct PushTargetPopup {
+	repo: RepoPathRef,
+	queue: Queue,
+	visible: bool,
+	branch: String,
+	remotes: Vec<String>,
+	selection: usize,
+	input: TextInputComponent,
+	theme: SharedTheme,
+	key_config: SharedKeyConfig,
+}
+
+impl PushTargetPopup {
+	///
+	pub fn new(
+		repo: RepoPathRef,
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			repo,
+			queue: queue.clone(),
+			visible: false,
+			branch: String::new(),
+			remotes: Vec::new(),
+			selection: 0,
+			input: TextInputComponent::new(
+				theme.clone(),
+				key_config.clone(),
+				"",
+				&strings::push_target_branch_msg(),
+				false,
+			),
+			theme,
+			key_config,
+		}
+	}
+
+	/// preselects the remote `branch` tracks or the default remote
+	pub fn open(&mut self, branch: String) -> Result<()> {
+		let repo = self.repo.borrow().clone();
+
+		self.remotes = sync::get_remotes(&repo)?;
+
+		let remote = sync::get_branch_remote(&repo, &branch)
+			.ok()
+			.flatten()
+			.or_else(|| sync::get_default_remote(&repo).ok());
+		self.selection = remote
+			.and_then(|remote| {
+				self.remotes.iter().position(|r| *r == remote)
+			})
+			.unwrap_or_default();
+
+		self.branch = branch;
+		self.input.hide();
+		self.show()?;
+
+		Ok(())
+	}
+
+	fn selected_remote(&self) -> Option<&String> {
+		self.remotes.get(self.selection)
+	}
+
+	fn move_selection(&mut self, move_type: ScrollType) {
+		let new_selection = match move_type {
+			ScrollType::Up => self.selection.saturating_sub(1),
+			ScrollType::Down => self.selection.saturating_add(1),
+			_ => self.selection,
+		};
+
+		self.selection = new_selection
+			.clamp(0, self.remotes.len().saturating_sub(1));
+	}
+
+	fn pick_remote(&mut self) -> Result<()> {
+		if let Some(remote) = self.selected_remote() {
+			self.input.set_title(strings::push_target_branch_title(
+				&self.branch,
+				remote,
+			));
+			self.input.set_text(self.branch.clone());
+			self.input.show()?;
+		}
+
+		Ok(())
+	}
+
+	fn confirm(&mut self) {
+		if let Some(remote) = self.selected_remote().cloned() {
+			self.queue.push(InternalEvent::PushTo(
+				self.branch.clone(),
+				remote,
+				self.input.get_text().to_string(),
+			));
+			self.hide();
+		}
+	}
+
+	fn get_items(&self) -> Vec<Spans> {
+		self.remotes
+			.iter()
+			.enumerate()
+			.map(|(idx, remote)| {
+				Spans::from(Span::styled(
+					remote.as_str(),
+					self.theme.text(true, idx == self.selection),
+				))
+			})
+			.collect()
+	}
+}
+
+impl DrawableComponent for PushTargetPopup {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		if self.is_visible() {
+			if self.input.is_visible() {
+				return self.input.draw(f, rect);
+			}
+
+			let height = u16::try_from(self.remotes.len())
+				.unwrap_or(u16::MAX)
+				.clamp(1, 10)
+				.saturating_add(2);
+			let area = ui::centered_rect_absolute(40, height, rect);
+
+			f.render_widget(Clear, area);
+			f.render_widget(
+				Block::default()
+					.borders(Borders::all())
+					.border_style(self.theme.popup_border())
+					.border_type(BorderType::Thick)
+					.title(Span::styled(
+						strings::push_target_title(&self.branch),
+						self.theme.title(true),
+					)),
+				area,
+			);
+
+			let area = area.inner(&Margin {
+				horizontal: 1,
+				vertical: 1,
+			});
+
+			let height = usize::from(area.height);
+			let scroll_top = ui::calc_scroll_top(
+				0,
+				height.saturating_sub(1),
+				self.selection,
+			);
+
+			ui::draw_list_block(
+				f,
+				area,
+				Block::default(),
+				self.get_items()
+					.into_iter()
+					.skip(scroll_top)
+					.take(height),
+			);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for PushTargetPopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			if self.input.is_visible() {
+				self.input.commands(out, force_all);
+			} else {
+				out.push(
+					CommandInfo::new(
+						strings::commands::close_popup(
+							&self.key_config,
+						),
+						true,
+						true,
+					)
+					.order(1),
+				);
+				out.push(CommandInfo::new(
+					strings::commands::scroll(&self.key_config),
+					true,
+					true,
+				));
+			}
+
+			out.push(CommandInfo::new(
+				strings::commands::push_target_confirm(
+					&self.key_config,
+				),
+				self.selected_remote().is_some()
+					&& (!self.input.is_visible()
+						|| !self.input.get_text().is_empty()),
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, ev: &Event) -> Result<EventState> {
+		if self.is_visible() {
+			if self.input.is_visible() {
+				// leaving the input goes back to the remotes
+				if self.input.event(ev)?.is_consumed() {
+					return Ok(EventState::Consumed);
+				}
+
+				if let Event::Key(e) = ev {
+					if key_match(e, self.key_config.keys().enter)
+						&& !self.input.get_text().is_empty()
+					{
+						self.confirm();
+					}
+				}
+			} else if let Event::Key(key) = ev {
+				if key_match(key, self.key_config.keys().exit_popup) {
+					self.hide();
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_down,
+				) {
+					self.move_selection(ScrollType::Down);
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_up,
+				) {
+					self.move_selection(ScrollType::Up);
+				} else if key_match(key, self.key_config.keys().enter)
+				{
+					self.pick_remote()?;
+				}
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.input.hide();
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+		Ok(())
+	}
+}
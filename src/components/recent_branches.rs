@@ -0,0 +1,272 @@
+use super::{
+	utils::{autostash::run_with_autostash, time_to_string},
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState, ScrollType, SharedOptions,
+};
+use crate::{
+	keys::{key_match, SharedKeyConfig},
+	queue::{AutostashOp, InternalEvent, NeedsUpdate, Queue},
+	strings, try_or_popup,
+	ui::{self, style::SharedTheme},
+};
+use anyhow::Result;
+use asyncgit::sync::{self, RecentBranch, RepoPathRef};
+use crossterm::event::Event;
+use tui::{
+	backend::Backend,
+	layout::{Margin, Rect},
+	text::{Span, Spans},
+	widgets::{Block, BorderType, Borders, Clear, Paragraph},
+	Frame,
+};
+
+/// how many branches the reflog is searched for
+const MAX_BRANCHES: usize = 20;
+
+/// lists local branches by most recent checkout to switch back quickly
+pub struct RecentBranchesPopup {
+	repo: RepoPathRef,
+	queue: Queue,
+	visible: bool,
+	branches: Vec<RecentBranch>,
+	selection: usize,
+	options: SharedOptions,
+	theme: SharedTheme,
+	key_config: SharedKeyConfig,
+}
+
+impl RecentBranchesPopup {
+	///
+	pub fn new(
+		repo: RepoPathRef,
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+		options: SharedOptions,
+	) -> Self {
+		Self {
+			repo,
+			queue: queue.clone(),
+			visible: false,
+			branches: Vec::new(),
+			selection: 0,
+			options,
+			theme,
+			key_config,
+		}
+	}
+
+	/// selects the previously checked out branch
+	pub fn open(&mut self) -> Result<()> {
+		self.branches = sync::get_recent_branches(
+			&self.repo.borrow(),
+			MAX_BRANCHES,
+		)?;
+		self.selection = 0;
+		self.show()?;
+
+		Ok(())
+	}
+
+	fn move_selection(&mut self, move_type: ScrollType) {
+		let new_selection = match move_type {
+			ScrollType::Up => self.selection.saturating_sub(1),
+			ScrollType::Down => self.selection.saturating_add(1),
+			_ => self.selection,
+		};
+
+		self.selection = new_selection
+			.clamp(0, self.branches.len().saturating_sub(1));
+	}
+
+	fn checkout(&mut self) -> Result<()> {
+		if let Some(branch) = self.branches.get(self.selection) {
+			let reference = format!("refs/heads/{}", branch.name);
+			let autostash = self.options.borrow().autostash;
+
+			self.hide();
+
+			let done = run_with_autostash(
+				&self.repo.borrow(),
+				&self.queue,
+				autostash,
+				AutostashOp::Checkout(reference.clone()),
+				|| {
+					sync::checkout_branch(
+						&self.repo.borrow(),
+						&reference,
+					)
+				},
+			)?;
+
+			if done.is_some() {
+				self.queue
+					.push(InternalEvent::Update(NeedsUpdate::ALL));
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl DrawableComponent for RecentBranchesPopup {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		area: Rect,
+	) -> Result<()> {
+		if self.is_visible() {
+			const MAX_SIZE: (u16, u16) = (60, 20);
+
+			let area = ui::centered_rect_absolute(
+				MAX_SIZE.0, MAX_SIZE.1, area,
+			);
+
+			f.render_widget(Clear, area);
+			f.render_widget(
+				Block::default()
+					.borders(Borders::all())
+					.border_style(self.theme.popup_border())
+					.border_type(BorderType::Thick)
+					.title(Span::styled(
+						strings::POPUP_TITLE_RECENT_BRANCHES,
+						self.theme.title(true),
+					)),
+				area,
+			);
+
+			let area = area.inner(&Margin {
+				horizontal: 1,
+				vertical: 1,
+			});
+
+			if self.branches.is_empty() {
+				f.render_widget(
+					Paragraph::new(strings::recent_branches_none())
+						.style(self.theme.text(false, false)),
+					area,
+				);
+				return Ok(());
+			}
+
+			let height = usize::from(area.height);
+			let scroll_top = ui::calc_scroll_top(
+				0,
+				height.saturating_sub(1),
+				self.selection,
+			);
+			let name_width =
+				usize::from(area.width).saturating_sub(20).max(10);
+
+			let items = self
+				.branches
+				.iter()
+				.enumerate()
+				.skip(scroll_top)
+				.take(height)
+				.map(|(idx, branch)| {
+					let selected = idx == self.selection;
+					Spans::from(vec![
+						Span::styled(
+							format!(
+								"{:w$} ",
+								branch.name,
+								w = name_width
+							),
+							self.theme.branch(selected, false),
+						),
+						Span::styled(
+							time_to_string(branch.time, false),
+							self.theme.commit_time(selected),
+						),
+					])
+				});
+
+			ui::draw_list_block(f, area, Block::default(), items);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for RecentBranchesPopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			out.push(
+				CommandInfo::new(
+					strings::commands::close_popup(&self.key_config),
+					true,
+					true,
+				)
+				.order(1),
+			);
+
+			out.push(CommandInfo::new(
+				strings::commands::select_branch_popup(
+					&self.key_config,
+				),
+				!self.branches.is_empty(),
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: &Event) -> Result<EventState> {
+		if self.is_visible() {
+			if let Event::Key(key) = event {
+				if key_match(key, self.key_config.keys().exit_popup) {
+					self.hide();
+				} else if key_match(key, self.key_config.keys().enter)
+					|| key_match(
+						key,
+						self.key_config.keys().recent_branches,
+					) {
+					try_or_popup!(
+						self,
+						"checkout error:",
+						self.checkout()
+					);
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_down,
+				) {
+					self.move_selection(ScrollType::Down);
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_up,
+				) {
+					self.move_selection(ScrollType::Up);
+				}
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+		Ok(())
+	}
+}
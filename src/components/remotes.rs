@@ -0,0 +1,333 @@
+use super::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState, ScrollType,
+};
+use crate::{
+	keys::{key_match, SharedKeyConfig},
+	queue::{Action, InternalEvent, Queue, RemoteEdit},
+	strings,
+	ui::{self, style::SharedTheme},
+};
+use anyhow::Result;
+use asyncgit::sync::{self, RemoteInfo, RepoPathRef};
+use crossterm::event::Event;
+use tui::{
+	backend::Backend,
+	layout::{Margin, Rect},
+	text::{Span, Spans},
+	widgets::{Block, BorderType, Borders, Clear, Paragraph},
+	Frame,
+};
+use unicode_truncate::UnicodeTruncateStr;
+
+/// lists the configured remotes to add, remove, rename or edit them
+pub struct RemotesPopup {
+	repo: RepoPathRef,
+	queue: Queue,
+	visible: bool,
+	remotes: Vec<RemoteInfo>,
+	selection: usize,
+	theme: SharedTheme,
+	key_config: SharedKeyConfig,
+}
+
+impl RemotesPopup {
+	///
+	pub fn new(
+		repo: RepoPathRef,
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			repo,
+			queue: queue.clone(),
+			visible: false,
+			remotes: Vec::new(),
+			selection: 0,
+			theme,
+			key_config,
+		}
+	}
+
+	///
+	pub fn open(&mut self) -> Result<()> {
+		self.update_remotes()?;
+		self.show()?;
+
+		Ok(())
+	}
+
+	///
+	pub fn update_remotes(&mut self) -> Result<()> {
+		self.remotes = sync::get_remotes_info(&self.repo.borrow())?;
+		self.move_selection(ScrollType::Home);
+
+		Ok(())
+	}
+
+	fn selected_remote(&self) -> Option<&RemoteInfo> {
+		self.remotes.get(self.selection)
+	}
+
+	fn move_selection(&mut self, move_type: ScrollType) {
+		let new_selection = match move_type {
+			ScrollType::Up => self.selection.saturating_sub(1),
+			ScrollType::Down => self.selection.saturating_add(1),
+			_ => self.selection,
+		};
+
+		self.selection = new_selection
+			.clamp(0, self.remotes.len().saturating_sub(1));
+	}
+
+	fn edit(&mut self, edit: fn(RemoteInfo) -> RemoteEdit) {
+		if let Some(remote) = self.selected_remote().cloned() {
+			self.hide();
+			self.queue.push(InternalEvent::EditRemote(edit(remote)));
+		}
+	}
+
+	fn remove(&self) {
+		if let Some(remote) = self.selected_remote() {
+			self.queue.push(InternalEvent::ConfirmAction(
+				Action::RemoveRemote(remote.name.clone()),
+			));
+		}
+	}
+
+	fn get_items(&self, width: usize) -> Vec<Spans> {
+		let name_width = (width / 4).max(8);
+		let url_width = width.saturating_sub(name_width + 1) / 2;
+
+		self.remotes
+			.iter()
+			.enumerate()
+			.map(|(idx, remote)| {
+				let selected = idx == self.selection;
+
+				let name = remote.name.unicode_truncate(name_width).0;
+				let url = remote.url.as_deref().unwrap_or_default();
+				let url = url.unicode_truncate_start(url_width).0;
+				let push_url = remote
+					.push_url
+					.as_deref()
+					.unwrap_or_default()
+					.unicode_truncate_start(url_width)
+					.0;
+
+				Spans::from(vec![
+					Span::styled(
+						format!("{:w$} ", name, w = name_width),
+						self.theme.branch(selected, false),
+					),
+					Span::styled(
+						format!("{:w$}", url, w = url_width),
+						self.theme.text(true, selected),
+					),
+					Span::styled(
+						format!(" {}", push_url),
+						self.theme.text(false, selected),
+					),
+				])
+			})
+			.collect()
+	}
+}
+
+impl DrawableComponent for RemotesPopup {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		area: Rect,
+	) -> Result<()> {
+		if self.is_visible() {
+			const PERCENT_SIZE: ui::Size = ui::Size::new(80, 50);
+			const MIN_SIZE: ui::Size = ui::Size::new(60, 12);
+
+			let area = ui::centered_rect(
+				PERCENT_SIZE.width,
+				PERCENT_SIZE.height,
+				area,
+			);
+			let area =
+				ui::rect_inside(MIN_SIZE, f.size().into(), area);
+			let area = area.intersection(f.size());
+
+			f.render_widget(Clear, area);
+			f.render_widget(
+				Block::default()
+					.borders(Borders::all())
+					.border_style(self.theme.popup_border())
+					.border_type(BorderType::Thick)
+					.title(Span::styled(
+						strings::POPUP_TITLE_REMOTES,
+						self.theme.title(true),
+					)),
+				area,
+			);
+
+			let area = area.inner(&Margin {
+				horizontal: 1,
+				vertical: 1,
+			});
+
+			if self.remotes.is_empty() {
+				f.render_widget(
+					Paragraph::new(strings::remotes_none())
+						.style(self.theme.text(false, false)),
+					area,
+				);
+				return Ok(());
+			}
+
+			let height = usize::from(area.height);
+			let scroll_top = ui::calc_scroll_top(
+				0,
+				height.saturating_sub(1),
+				self.selection,
+			);
+
+			let items = self
+				.get_items(usize::from(area.width))
+				.into_iter()
+				.skip(scroll_top)
+				.take(height);
+
+			ui::draw_list_block(f, area, Block::default(), items);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for RemotesPopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			out.push(
+				CommandInfo::new(
+					strings::commands::close_popup(&self.key_config),
+					true,
+					true,
+				)
+				.order(1),
+			);
+
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+
+			let selected = self.selected_remote().is_some();
+
+			out.push(CommandInfo::new(
+				strings::commands::remote_add(&self.key_config),
+				true,
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::remote_remove(&self.key_config),
+				selected,
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::remote_rename(&self.key_config),
+				selected,
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::remote_edit_url(&self.key_config),
+				selected,
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::remote_edit_push_url(
+					&self.key_config,
+				),
+				selected,
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: &Event) -> Result<EventState> {
+		if self.is_visible() {
+			if let Event::Key(key) = event {
+				if key_match(key, self.key_config.keys().exit_popup) {
+					self.hide();
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_down,
+				) {
+					self.move_selection(ScrollType::Down);
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_up,
+				) {
+					self.move_selection(ScrollType::Up);
+				} else if key_match(
+					key,
+					self.key_config.keys().remote_add,
+				) {
+					self.hide();
+					self.queue.push(InternalEvent::EditRemote(
+						RemoteEdit::AddName,
+					));
+				} else if key_match(
+					key,
+					self.key_config.keys().remote_remove,
+				) {
+					self.remove();
+				} else if key_match(
+					key,
+					self.key_config.keys().remote_rename,
+				) {
+					self.edit(|remote| {
+						RemoteEdit::Rename(remote.name)
+					});
+				} else if key_match(
+					key,
+					self.key_config.keys().remote_edit_url,
+				) {
+					self.edit(|remote| {
+						RemoteEdit::Url(remote.name, remote.url)
+					});
+				} else if key_match(
+					key,
+					self.key_config.keys().remote_edit_push_url,
+				) {
+					self.edit(|remote| {
+						RemoteEdit::PushUrl(
+							remote.name,
+							remote.push_url,
+						)
+					});
+				}
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+		Ok(())
+	}
+}
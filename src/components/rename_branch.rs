@@ -62,7 +62,7 @@ impl Component for RenameBranchComponent {
 			}
 
 			if let Event::Key(e) = ev {
-				if key_match(e, self.key_config.keys.enter) {
+				if key_match(e, self.key_config.keys().enter) {
 					self.rename_branch();
 				}
 
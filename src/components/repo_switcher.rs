@@ -0,0 +1,475 @@
+use super::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState, ScrollType,
+};
+use crate::{
+	args::recent_repos_path,
+	keys::{key_match, SharedKeyConfig},
+	queue::{InternalEvent, Queue},
+	strings, try_or_popup,
+	ui::{self, style::SharedTheme},
+};
+use anyhow::Result;
+use asyncgit::sync::{self, RepoPath, RepoPathRef};
+use crossterm::event::Event;
+use std::{
+	fs,
+	path::{Path, PathBuf},
+};
+use tui::{
+	backend::Backend,
+	layout::{Margin, Rect},
+	text::{Span, Spans},
+	widgets::{Block, BorderType, Borders, Clear},
+	Frame,
+};
+use unicode_truncate::UnicodeTruncateStr;
+
+/// repositories remembered at most
+const MAX_RECENT: usize = 20;
+
+/// a directory looked at while browsing for a repository
+struct DirEntry {
+	name: String,
+	is_repo: bool,
+}
+
+/// switches to a recently opened repository or one found by browsing
+/// the file system, without restarting gitui
+pub struct RepoSwitcherPopup {
+	repo: RepoPathRef,
+	queue: Queue,
+	visible: bool,
+	/// most recent first, without the current one
+	recent: Vec<PathBuf>,
+	/// directory browsed and its sub directories, `None` while
+	/// showing the recent repositories
+	browse: Option<(PathBuf, Vec<DirEntry>)>,
+	selection: usize,
+	theme: SharedTheme,
+	key_config: SharedKeyConfig,
+}
+
+impl RepoSwitcherPopup {
+	///
+	pub fn new(
+		repo: RepoPathRef,
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			repo,
+			queue: queue.clone(),
+			visible: false,
+			recent: Vec::new(),
+			browse: None,
+			selection: 0,
+			theme,
+			key_config,
+		}
+	}
+
+	///
+	pub fn open(&mut self) -> Result<()> {
+		let current = current_work_dir(&self.repo.borrow());
+		self.recent = load_recent_repos()
+			.into_iter()
+			.filter(|path| Some(path) != current.as_ref())
+			.collect();
+		self.browse = None;
+		self.selection = 0;
+		self.show()
+	}
+
+	fn len(&self) -> usize {
+		self.browse
+			.as_ref()
+			.map_or(self.recent.len(), |(_, entries)| entries.len())
+	}
+
+	fn move_selection(&mut self, move_type: ScrollType) {
+		let new_selection = match move_type {
+			ScrollType::Up => self.selection.saturating_sub(1),
+			ScrollType::Down => self.selection.saturating_add(1),
+			ScrollType::Home => 0,
+			ScrollType::End => self.len(),
+			_ => self.selection,
+		};
+
+		self.selection =
+			new_selection.min(self.len().saturating_sub(1));
+	}
+
+	/// starts next to the current repository
+	fn start_browsing(&mut self) -> Result<()> {
+		let dir = current_work_dir(&self.repo.borrow())
+			.and_then(|dir| dir.parent().map(Path::to_path_buf))
+			.map_or_else(std::env::current_dir, Ok)?;
+
+		self.browse_dir(dir)
+	}
+
+	fn browse_dir(&mut self, dir: PathBuf) -> Result<()> {
+		let mut entries = fs::read_dir(&dir)?
+			.filter_map(|entry| {
+				let entry = entry.ok()?;
+				let name =
+					entry.file_name().to_string_lossy().to_string();
+				(!name.starts_with('.') && entry.path().is_dir())
+					.then(|| DirEntry {
+						is_repo: entry.path().join(".git").exists(),
+						name,
+					})
+			})
+			.collect::<Vec<_>>();
+		entries.sort_by(|a, b| a.name.cmp(&b.name));
+
+		self.browse = Some((dir, entries));
+		self.selection = 0;
+
+		Ok(())
+	}
+
+	fn browse_parent(&mut self) -> Result<()> {
+		let parent = self
+			.browse
+			.as_ref()
+			.and_then(|(dir, _)| dir.parent().map(Path::to_path_buf));
+
+		if let Some(parent) = parent {
+			let child = self.browse.as_ref().and_then(|(dir, _)| {
+				dir.file_name()
+					.map(|name| name.to_string_lossy().to_string())
+			});
+
+			self.browse_dir(parent)?;
+
+			// keep the directory we came from selected
+			if let Some((_, entries)) = &self.browse {
+				self.selection = entries
+					.iter()
+					.position(|entry| {
+						Some(&entry.name) == child.as_ref()
+					})
+					.unwrap_or_default();
+			}
+		}
+
+		Ok(())
+	}
+
+	fn selected_path(&self) -> Option<(PathBuf, bool)> {
+		match &self.browse {
+			Some((dir, entries)) => entries
+				.get(self.selection)
+				.map(|entry| (dir.join(&entry.name), entry.is_repo)),
+			None => self
+				.recent
+				.get(self.selection)
+				.map(|path| (path.clone(), true)),
+		}
+	}
+
+	/// opens repositories and walks into other directories,
+	/// `descend` walks into repositories as well
+	fn confirm(&mut self, descend: bool) -> Result<()> {
+		if let Some((path, is_repo)) = self.selected_path() {
+			if is_repo && !descend {
+				self.hide();
+				self.queue.push(InternalEvent::OpenRepo(
+					RepoPath::Path(path),
+				));
+			} else if self.browse.is_some() {
+				self.browse_dir(path)?;
+			}
+		}
+
+		Ok(())
+	}
+
+	fn get_items(&self, width: usize) -> Vec<Spans> {
+		if let Some((_, entries)) = &self.browse {
+			return entries
+				.iter()
+				.enumerate()
+				.map(|(idx, entry)| {
+					let selected = idx == self.selection;
+					Spans::from(vec![
+						Span::styled(
+							if entry.is_repo { "* " } else { "  " },
+							self.theme.text(true, selected),
+						),
+						Span::styled(
+							format!("{}/", entry.name),
+							self.theme.text(entry.is_repo, selected),
+						),
+					])
+				})
+				.collect();
+		}
+
+		if self.recent.is_empty() {
+			return vec![Spans::from(Span::styled(
+				strings::repo_switcher_empty(&self.key_config),
+				self.theme.text(false, false),
+			))];
+		}
+
+		self.recent
+			.iter()
+			.enumerate()
+			.map(|(idx, path)| {
+				let path = path.to_string_lossy();
+				Spans::from(Span::styled(
+					path.unicode_truncate_start(width).0.to_string(),
+					self.theme.text(true, idx == self.selection),
+				))
+			})
+			.collect()
+	}
+
+	fn get_title(&self) -> String {
+		self.browse.as_ref().map_or_else(
+			|| strings::POPUP_TITLE_RECENT_REPOS.to_string(),
+			|(dir, _)| dir.to_string_lossy().to_string(),
+		)
+	}
+}
+
+/// canonical work dir to compare and remember repositories by
+fn current_work_dir(repo: &RepoPath) -> Option<PathBuf> {
+	sync::utils::repo_work_dir(repo)
+		.ok()
+		.and_then(|dir| fs::canonicalize(dir).ok())
+}
+
+/// most recent first, leaving out those that are gone
+fn load_recent_repos() -> Vec<PathBuf> {
+	recent_repos_path()
+		.ok()
+		.and_then(|path| fs::read_to_string(path).ok())
+		.and_then(|content| {
+			ron::from_str::<Vec<PathBuf>>(&content).ok()
+		})
+		.unwrap_or_default()
+		.into_iter()
+		.filter(|path| path.is_dir())
+		.collect()
+}
+
+/// puts `repo` in front of the recently opened repositories
+pub fn remember_repo(repo: &RepoPath) -> Result<()> {
+	if let Some(dir) = current_work_dir(repo) {
+		let mut recent = load_recent_repos();
+		recent.retain(|path| path != &dir);
+		recent.insert(0, dir);
+		recent.truncate(MAX_RECENT);
+
+		fs::write(recent_repos_path()?, ron::to_string(&recent)?)?;
+	}
+
+	Ok(())
+}
+
+impl DrawableComponent for RepoSwitcherPopup {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		area: Rect,
+	) -> Result<()> {
+		if self.is_visible() {
+			const PERCENT_SIZE: ui::Size = ui::Size::new(70, 50);
+			const MIN_SIZE: ui::Size = ui::Size::new(60, 12);
+
+			let area = ui::centered_rect(
+				PERCENT_SIZE.width,
+				PERCENT_SIZE.height,
+				area,
+			);
+			let area =
+				ui::rect_inside(MIN_SIZE, f.size().into(), area);
+			let area = area.intersection(f.size());
+
+			let inner = area.inner(&Margin {
+				horizontal: 1,
+				vertical: 1,
+			});
+			let title = self.get_title();
+			let title = title
+				.unicode_truncate_start(usize::from(inner.width))
+				.0;
+
+			f.render_widget(Clear, area);
+			f.render_widget(
+				Block::default()
+					.borders(Borders::all())
+					.border_style(self.theme.popup_border())
+					.border_type(BorderType::Thick)
+					.title(Span::styled(
+						title,
+						self.theme.title(true),
+					)),
+				area,
+			);
+
+			let height = usize::from(inner.height);
+			let scroll_top = ui::calc_scroll_top(
+				0,
+				height.saturating_sub(1),
+				self.selection,
+			);
+
+			let items = self
+				.get_items(usize::from(inner.width))
+				.into_iter()
+				.skip(scroll_top)
+				.take(height);
+
+			ui::draw_list_block(f, inner, Block::default(), items);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for RepoSwitcherPopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			out.push(
+				CommandInfo::new(
+					strings::commands::close_popup(&self.key_config),
+					true,
+					true,
+				)
+				.order(1),
+			);
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+
+			let selected = self.selected_path();
+			out.push(CommandInfo::new(
+				strings::commands::repo_switcher_open(
+					&self.key_config,
+				),
+				selected.as_ref().map_or(false, |(_, repo)| *repo),
+				true,
+			));
+
+			let browsing = self.browse.is_some() || force_all;
+			out.push(CommandInfo::new(
+				strings::commands::repo_switcher_browse(
+					&self.key_config,
+				),
+				true,
+				self.browse.is_none() || force_all,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::repo_switcher_enter_dir(
+					&self.key_config,
+				),
+				selected.is_some(),
+				browsing,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::repo_switcher_parent_dir(
+					&self.key_config,
+				),
+				true,
+				browsing,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: &Event) -> Result<EventState> {
+		if self.is_visible() {
+			if let Event::Key(key) = event {
+				if key_match(key, self.key_config.keys().exit_popup) {
+					if self.browse.is_some() {
+						self.browse = None;
+						self.selection = 0;
+					} else {
+						self.hide();
+					}
+				} else if key_match(key, self.key_config.keys().enter)
+				{
+					try_or_popup!(
+						self,
+						"open repository error:",
+						self.confirm(false)
+					);
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_down,
+				) {
+					self.move_selection(ScrollType::Down);
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_up,
+				) {
+					self.move_selection(ScrollType::Up);
+				} else if key_match(key, self.key_config.keys().home)
+				{
+					self.move_selection(ScrollType::Home);
+				} else if key_match(key, self.key_config.keys().end) {
+					self.move_selection(ScrollType::End);
+				} else if self.browse.is_none() {
+					if key_match(
+						key,
+						self.key_config.keys().repo_switcher_browse,
+					) {
+						try_or_popup!(
+							self,
+							"browse error:",
+							self.start_browsing()
+						);
+					}
+				} else if key_match(
+					key,
+					self.key_config.keys().move_right,
+				) {
+					try_or_popup!(
+						self,
+						"browse error:",
+						self.confirm(true)
+					);
+				} else if key_match(
+					key,
+					self.key_config.keys().move_left,
+				) {
+					try_or_popup!(
+						self,
+						"browse error:",
+						self.browse_parent()
+					);
+				}
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+		Ok(())
+	}
+}
@@ -4,7 +4,7 @@ use crate::{
 		CommandInfo, Component, DrawableComponent, EventState,
 	},
 	keys::{key_match, SharedKeyConfig},
-	queue::{Action, InternalEvent, Queue},
+	queue::{Action, AutostashOp, InternalEvent, Queue},
 	strings, ui,
 };
 use anyhow::Result;
@@ -73,9 +73,9 @@ impl Component for ConfirmComponent {
 	fn event(&mut self, ev: &Event) -> Result<EventState> {
 		if self.visible {
 			if let Event::Key(e) = ev {
-				if key_match(e, self.key_config.keys.exit_popup) {
+				if key_match(e, self.key_config.keys().exit_popup) {
 					self.hide();
-				} else if key_match(e, self.key_config.keys.enter) {
+				} else if key_match(e, self.key_config.keys().enter) {
 					self.confirm();
 				}
 
@@ -132,6 +132,8 @@ impl ConfirmComponent {
 		self.hide();
 	}
 
+	#[allow(clippy::too_many_lines)]
+	#[rustfmt::skip]
 	fn get_text(&self) -> (String, String) {
 		if let Some(ref a) = self.target {
 			return match a {
@@ -183,18 +185,32 @@ impl ConfirmComponent {
                         &self.key_config,
                         tag_name,
                     ),
+                ),
+				Action::ForcePushUnsafe(branch) => (
+                    strings::confirm_title_force_push(
+                        &self.key_config,
+                    ),
+                    strings::confirm_msg_force_push_unsafe(branch),
                 ),
 				Action::DeleteRemoteTag(_tag_name,remote) => (
                     strings::confirm_title_delete_tag_remote(),
                     strings::confirm_msg_delete_tag_remote(remote),
                 ),
-                Action::ForcePush(branch, _force) => (
+				Action::DeleteTags(tags) => (
+                    strings::confirm_title_delete_tags(),
+                    strings::confirm_msg_delete_tags(tags),
+                ),
+				Action::DeleteRemoteTags(tags, remote) => (
+                    strings::confirm_title_delete_tag_remote(),
+                    strings::confirm_msg_delete_tags_remote(tags, remote),
+                ),
+                Action::ForcePush(_branch, _force, destination) => (
                     strings::confirm_title_force_push(
                         &self.key_config,
                     ),
                     strings::confirm_msg_force_push(
                         &self.key_config,
-                        branch.rsplit('/').next().expect("There was no / in the head reference which is impossible in git"),
+                        destination,
                     ),
                 ),
                 Action::PullMerge{incoming,rebase} => (
@@ -212,6 +228,58 @@ impl ConfirmComponent {
 				Action::AbortRevert => (
                     strings::confirm_title_abortrevert(),
                     strings::confirm_msg_revertchanges(),
+                ),
+				Action::AbortCherryPick => (
+                    strings::confirm_title_abortcherrypick(),
+                    strings::confirm_msg_revertchanges(),
+                ),
+				Action::AbortBisect => (
+                    strings::confirm_title_abortbisect(),
+                    strings::confirm_msg_abortbisect(),
+                ),
+				Action::DeleteLocalBranches(branches) => (
+                    strings::confirm_title_delete_branches(),
+                    strings::confirm_msg_delete_branches(branches),
+                ),
+				Action::PruneBranches(branches) => (
+                    strings::confirm_title_prune_branches(),
+                    strings::confirm_msg_prune_branches(branches),
+                ),
+				Action::RemoveWorktree(name) => (
+                    strings::confirm_title_remove_worktree(),
+                    strings::confirm_msg_remove_worktree(name),
+                ),
+				Action::RemoveRemote(name) => (
+                    strings::confirm_title_remove_remote(),
+                    strings::confirm_msg_remove_remote(name),
+                ),
+				Action::RestoreFile { path, commit_id, stage } => (
+                    strings::confirm_title_restore_file(),
+                    strings::confirm_msg_restore_file(
+                        path,
+                        &commit_id.get_short_string(),
+                        *stage,
+                    ),
+                ),
+				Action::Autostash(op) => (
+                    strings::confirm_title_autostash(),
+                    strings::confirm_msg_autostash(&match op {
+                        AutostashOp::Checkout(branch)
+                        | AutostashOp::CheckoutRemote(branch)
+                        | AutostashOp::CheckoutTag(branch) => {
+                            format!("checkout of '{}'", branch)
+                        }
+                        AutostashOp::Rebase(branch, _) => {
+                            format!("rebase onto '{}'", branch)
+                        }
+                        AutostashOp::PullMerge(_) => {
+                            String::from("merging pulled changes")
+                        }
+                    }),
+                ),
+				Action::CommitEmpty => (
+                    strings::confirm_title_commit_empty(),
+                    strings::confirm_msg_commit_empty(),
                 ),
             };
 		}
@@ -1,30 +1,33 @@
 use super::{
+	file_find_popup::find_files,
 	utils::scroll_vertical::VerticalScroll, BlameFileOpen,
 	CommandBlocking, CommandInfo, Component, DrawableComponent,
-	EventState, FileRevOpen, SyntaxTextComponent,
+	EventState, FileRevOpen, FileViewOpen, SharedOptions,
+	SyntaxTextComponent,
 };
 use crate::{
 	keys::{key_match, SharedKeyConfig},
 	queue::{InternalEvent, Queue, StackablePopupOpen},
 	strings::{self, order, symbol},
-	ui::{self, common_nav, style::SharedTheme},
+	ui::{self, common_nav, icons, style::SharedTheme},
 	AsyncAppNotification, AsyncNotification,
 };
 use anyhow::Result;
 use asyncgit::sync::{self, CommitId, RepoPathRef, TreeFile};
+use bytesize::ByteSize;
 use crossbeam_channel::Sender;
 use crossterm::event::Event;
 use filetreelist::{FileTree, FileTreeItem};
 use std::{
-	collections::BTreeSet,
+	collections::{BTreeSet, HashMap},
 	convert::From,
 	path::{Path, PathBuf},
 };
 use tui::{
 	backend::Backend,
-	layout::{Constraint, Direction, Layout, Rect},
-	text::Span,
-	widgets::{Block, Borders},
+	layout::Rect,
+	text::{Span, Spans},
+	widgets::Block,
 	Frame,
 };
 
@@ -44,8 +47,20 @@ pub struct RevisionFilesComponent {
 	scroll: VerticalScroll,
 	visible: bool,
 	revision: Option<CommitId>,
+	/// branch or tag the revision was picked by
+	revision_name: Option<String>,
+	/// file finder query the tree is pruned to
+	filter: Option<String>,
+	/// size and mode per file path
+	file_info: HashMap<PathBuf, (u64, i32)>,
+	/// cumulative size per directory path
+	dir_sizes: HashMap<PathBuf, u64>,
+	show_dir_sizes: bool,
 	focus: Focus,
 	key_config: SharedKeyConfig,
+	options: SharedOptions,
+	/// the focused pane takes up the whole area
+	zoomed: bool,
 }
 
 impl RevisionFilesComponent {
@@ -56,6 +71,7 @@ impl RevisionFilesComponent {
 		sender: &Sender<AsyncAppNotification>,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
+		options: SharedOptions,
 	) -> Self {
 		Self {
 			queue: queue.clone(),
@@ -70,8 +86,15 @@ impl RevisionFilesComponent {
 			theme,
 			files: Vec::new(),
 			revision: None,
+			revision_name: None,
+			filter: None,
+			file_info: HashMap::new(),
+			dir_sizes: HashMap::new(),
+			show_dir_sizes: false,
 			focus: Focus::Tree,
 			key_config,
+			options,
+			zoomed: false,
 			repo,
 			visible: false,
 		}
@@ -86,16 +109,67 @@ impl RevisionFilesComponent {
 		if !same_id {
 			self.files =
 				sync::tree_files(&self.repo.borrow(), commit)?;
-			let filenames: Vec<&Path> =
-				self.files.iter().map(|f| f.path.as_path()).collect();
-			self.tree = FileTree::new(&filenames, &BTreeSet::new())?;
-			self.tree.collapse_but_root();
 			self.revision = Some(commit);
+			self.update_sizes();
+			self.build_tree()?;
+		}
+
+		Ok(())
+	}
+
+	/// prunes the tree to the files matching the file finder `query`
+	pub fn set_filter(
+		&mut self,
+		query: Option<String>,
+	) -> Result<()> {
+		self.filter = query;
+		self.build_tree()
+	}
+
+	fn update_sizes(&mut self) {
+		self.file_info = self
+			.files
+			.iter()
+			.map(|f| (f.path.clone(), (f.size, f.filemode)))
+			.collect();
+
+		self.dir_sizes.clear();
+		for file in &self.files {
+			for dir in file.path.ancestors().skip(1) {
+				*self
+					.dir_sizes
+					.entry(dir.to_path_buf())
+					.or_default() += file.size;
+			}
+		}
+	}
+
+	fn build_tree(&mut self) -> Result<()> {
+		let filenames: Vec<&Path> = if let Some(filter) = &self.filter
+		{
+			let mut hits = find_files(&self.files, filter);
+			hits.sort_unstable_by_key(|(idx, _)| *idx);
+			hits.into_iter()
+				.map(|(idx, _)| self.files[idx].path.as_path())
+				.collect()
+		} else {
+			self.files.iter().map(|f| f.path.as_path()).collect()
+		};
+
+		self.tree = FileTree::new(&filenames, &BTreeSet::new())?;
+		// a filtered tree stays expanded to show all hits
+		if self.filter.is_none() {
+			self.tree.collapse_but_root();
 		}
 
 		Ok(())
 	}
 
+	///
+	pub fn set_revision_name(&mut self, name: Option<String>) {
+		self.revision_name = name;
+	}
+
 	///
 	pub const fn revision(&self) -> Option<CommitId> {
 		self.revision
@@ -116,11 +190,11 @@ impl RevisionFilesComponent {
 		self.current_file.any_work_pending()
 	}
 
-	fn tree_item_to_span<'a>(
+	fn tree_item_to_spans<'a>(
+		&self,
 		item: &'a FileTreeItem,
-		theme: &SharedTheme,
 		selected: bool,
-	) -> Span<'a> {
+	) -> Spans<'a> {
 		let path = item.info().path_str();
 		let indent = item.info().indent();
 
@@ -131,18 +205,56 @@ impl RevisionFilesComponent {
 		};
 
 		let is_path = item.kind().is_path();
-		let path_arrow = if is_path {
+		let path_arrow = if self.theme.icons() {
+			let icon = if is_path {
+				icons::folder(item.kind().is_path_collapsed())
+			} else {
+				icons::file(path)
+			};
+			format!("{} ", icon)
+		} else if is_path {
 			if item.kind().is_path_collapsed() {
-				symbol::FOLDER_ICON_COLLAPSED
+				symbol::FOLDER_ICON_COLLAPSED.to_string()
 			} else {
-				symbol::FOLDER_ICON_EXPANDED
+				symbol::FOLDER_ICON_EXPANDED.to_string()
 			}
 		} else {
-			symbol::EMPTY_STR
+			String::new()
 		};
 
-		let path = format!("{}{}{}", indent_str, path_arrow, path);
-		Span::styled(path, theme.file_tree_item(is_path, selected))
+		let full_path = item.info().full_path();
+		let (size, indicator) = if is_path {
+			(
+				self.dir_sizes
+					.get(full_path)
+					.filter(|_| self.show_dir_sizes),
+				symbol::EMPTY_STR,
+			)
+		} else {
+			self.file_info
+				.get(full_path)
+				.map_or((None, symbol::EMPTY_STR), |(size, mode)| {
+					(Some(size), mode_indicator(*mode))
+				})
+		};
+
+		let path = format!(
+			"{}{}{}{}",
+			indent_str, path_arrow, path, indicator
+		);
+
+		let mut spans = vec![Span::styled(
+			path,
+			self.theme.file_tree_item(is_path, selected),
+		)];
+		if let Some(size) = size {
+			spans.push(Span::styled(
+				format!(" {}", ByteSize::b(*size)),
+				self.theme.text(false, selected),
+			));
+		}
+
+		Spans::from(spans)
 	}
 
 	fn blame(&self) -> bool {
@@ -159,6 +271,23 @@ impl RevisionFilesComponent {
 		})
 	}
 
+	fn view_file(&self) -> bool {
+		match (self.selected_file_path(), self.revision) {
+			(Some(file_path), Some(commit_id)) => {
+				self.queue.push(InternalEvent::OpenPopup(
+					StackablePopupOpen::FileView(FileViewOpen {
+						file_path,
+						commit_id,
+						selection: None,
+					}),
+				));
+
+				true
+			}
+			_ => false,
+		}
+	}
+
 	fn file_history(&self) -> bool {
 		self.selected_file_path().map_or(false, |path| {
 			self.queue.push(InternalEvent::OpenPopup(
@@ -172,8 +301,10 @@ impl RevisionFilesComponent {
 	}
 
 	fn open_finder(&self) {
-		self.queue
-			.push(InternalEvent::OpenFileFinder(self.files.clone()));
+		self.queue.push(InternalEvent::OpenFileFinder(
+			self.files.clone(),
+			self.revision,
+		));
 	}
 
 	pub fn find_file(&mut self, file: &Option<PathBuf>) {
@@ -220,7 +351,8 @@ impl RevisionFilesComponent {
 	}
 
 	fn draw_tree<B: Backend>(&self, f: &mut Frame<B>, area: Rect) {
-		let tree_height = usize::from(area.height.saturating_sub(2));
+		let tree_height =
+			usize::from(self.theme.pane_inner(area).height);
 
 		self.tree.visual_selection().map_or_else(
 			|| {
@@ -239,17 +371,22 @@ impl RevisionFilesComponent {
 			.tree
 			.iterate(self.scroll.get_top(), tree_height)
 			.map(|(item, selected)| {
-				Self::tree_item_to_span(item, &self.theme, selected)
+				self.tree_item_to_spans(item, selected)
 			});
 
 		let is_tree_focused = matches!(self.focus, Focus::Tree);
 
-		let title = format!(
-			"Files at [{}]",
-			self.revision
-				.map(|c| c.get_short_string())
-				.unwrap_or_default(),
+		let revision = self
+			.revision
+			.map(|c| c.get_short_string())
+			.unwrap_or_default();
+		let mut title = self.revision_name.as_ref().map_or_else(
+			|| format!("Files at [{}]", revision),
+			|name| format!("Files at [{}: {}]", name, revision),
 		);
+		if let Some(filter) = &self.filter {
+			title.push_str(&strings::files_filtered(filter));
+		}
 		ui::draw_list_block(
 			f,
 			area,
@@ -258,7 +395,7 @@ impl RevisionFilesComponent {
 					title,
 					self.theme.title(is_tree_focused),
 				))
-				.borders(Borders::ALL)
+				.borders(self.theme.pane_borders())
 				.border_style(self.theme.block(is_tree_focused)),
 			items,
 		);
@@ -275,17 +412,18 @@ impl DrawableComponent for RevisionFilesComponent {
 		f: &mut Frame<B>,
 		area: Rect,
 	) -> Result<()> {
-		if self.is_visible() {
-			let chunks = Layout::default()
-				.direction(Direction::Horizontal)
-				.constraints(
-					[
-						Constraint::Percentage(40),
-						Constraint::Percentage(60),
-					]
-					.as_ref(),
-				)
-				.split(area);
+		if !self.is_visible() {
+			return Ok(());
+		}
+
+		if self.zoomed {
+			match self.focus {
+				Focus::Tree => self.draw_tree(f, area),
+				Focus::File => self.current_file.draw(f, area)?,
+			}
+		} else {
+			let chunks =
+				self.options.borrow().files_layout.split(area, 40);
 
 			self.draw_tree(f, chunks[0]);
 
@@ -331,11 +469,62 @@ impl Component for RevisionFilesComponent {
 				)
 				.order(order::RARE_ACTION),
 			);
+			out.push(
+				CommandInfo::new(
+					strings::commands::view_tree_file(
+						&self.key_config,
+					),
+					self.tree.selected_file().is_some(),
+					true,
+				)
+				.order(order::NAV),
+			);
+			out.push(
+				CommandInfo::new(
+					strings::commands::copy_path(&self.key_config),
+					self.tree.selected_file().is_some(),
+					true,
+				)
+				.order(order::RARE_ACTION),
+			);
+			out.push(
+				CommandInfo::new(
+					strings::commands::open_externally(
+						&self.key_config,
+					),
+					self.tree.selected_file().is_some(),
+					true,
+				)
+				.order(order::RARE_ACTION),
+			);
+			out.push(
+				CommandInfo::new(
+					strings::commands::tree_dir_sizes(
+						&self.key_config,
+						self.show_dir_sizes,
+					),
+					true,
+					true,
+				)
+				.order(order::RARE_ACTION),
+			);
 			tree_nav_cmds(&self.tree, &self.key_config, out);
 		} else {
 			self.current_file.commands(out, force_all);
 		}
 
+		out.push(
+			CommandInfo::new(
+				strings::commands::toggle_zoom(
+					&self.key_config,
+					self.zoomed,
+				),
+				true,
+				true,
+			)
+			.order(order::NAV),
+		);
+
 		CommandBlocking::PassingOn
 	}
 
@@ -347,6 +536,12 @@ impl Component for RevisionFilesComponent {
 			return Ok(EventState::NotConsumed);
 		}
 
+		if matches!(event, Event::Mouse(_))
+			&& matches!(self.focus, Focus::File)
+		{
+			return self.current_file.event(event);
+		}
+
 		if let Event::Key(key) = event {
 			let is_tree_focused = matches!(self.focus, Focus::Tree);
 			if is_tree_focused
@@ -354,40 +549,92 @@ impl Component for RevisionFilesComponent {
 			{
 				self.selection_changed();
 				return Ok(EventState::Consumed);
-			} else if key_match(key, self.key_config.keys.blame) {
+			} else if key_match(key, self.key_config.keys().blame) {
 				if self.blame() {
 					self.hide();
 					return Ok(EventState::Consumed);
 				}
+			} else if key_match(key, self.key_config.keys().view_file)
+				|| (is_tree_focused
+					&& key_match(key, self.key_config.keys().enter))
+			{
+				if self.view_file() {
+					self.hide();
+					return Ok(EventState::Consumed);
+				}
 			} else if key_match(
 				key,
-				self.key_config.keys.file_history,
+				self.key_config.keys().file_history,
 			) {
 				if self.file_history() {
 					self.hide();
 					return Ok(EventState::Consumed);
 				}
-			} else if key_match(key, self.key_config.keys.move_right)
-			{
+			} else if key_match(
+				key,
+				self.key_config.keys().toggle_zoom,
+			) {
+				self.zoomed = !self.zoomed;
+				return Ok(EventState::Consumed);
+			} else if key_match(
+				key,
+				self.key_config.keys().move_right,
+			) {
 				if is_tree_focused {
 					self.focus = Focus::File;
 					self.current_file.focus(true);
 					self.focus(true);
 					return Ok(EventState::Consumed);
 				}
-			} else if key_match(key, self.key_config.keys.move_left) {
+			} else if key_match(key, self.key_config.keys().move_left)
+			{
 				if !is_tree_focused {
 					self.focus = Focus::Tree;
 					self.current_file.focus(false);
 					self.focus(false);
 					return Ok(EventState::Consumed);
 				}
-			} else if key_match(key, self.key_config.keys.file_find) {
+			} else if key_match(key, self.key_config.keys().copy_path)
+			{
+				if is_tree_focused {
+					if let Some(path) = self.selected_file_path() {
+						self.queue.push(InternalEvent::CopyPath(
+							path, None,
+						));
+						return Ok(EventState::Consumed);
+					}
+				}
+			} else if key_match(
+				key,
+				self.key_config.keys().open_externally,
+			) {
+				if is_tree_focused {
+					if let Some(path) = self.selected_file_path() {
+						self.queue.push(
+							InternalEvent::OpenExternally(
+								path,
+								self.revision,
+							),
+						);
+						return Ok(EventState::Consumed);
+					}
+				}
+			} else if key_match(
+				key,
+				self.key_config.keys().tree_dir_sizes,
+			) {
+				if is_tree_focused {
+					self.show_dir_sizes = !self.show_dir_sizes;
+					return Ok(EventState::Consumed);
+				}
+			} else if key_match(key, self.key_config.keys().file_find)
+			{
 				if is_tree_focused {
 					self.open_finder();
 					return Ok(EventState::Consumed);
 				}
-			} else if key_match(key, self.key_config.keys.edit_file) {
+			} else if key_match(key, self.key_config.keys().edit_file)
+			{
 				if let Some(file) =
 					self.selected_file_path_with_prefix()
 				{
@@ -421,6 +668,18 @@ impl Component for RevisionFilesComponent {
 	}
 }
 
+/// marks executables and symlinks like `ls -F`
+const fn mode_indicator(mode: i32) -> &'static str {
+	const EXECUTABLE: i32 = 0o100_755;
+	const SYMLINK: i32 = 0o120_000;
+
+	match mode {
+		EXECUTABLE => "*",
+		SYMLINK => "@",
+		_ => "",
+	}
+}
+
 //TODO: reuse for other tree usages
 fn tree_nav_cmds(
 	tree: &FileTree,
@@ -445,11 +704,14 @@ fn tree_nav(
 ) -> bool {
 	if let Some(common_nav) = common_nav(key, key_config) {
 		tree.move_selection(common_nav)
-	} else if key_match(key, key_config.keys.tree_collapse_recursive)
-	{
+	} else if key_match(
+		key,
+		key_config.keys().tree_collapse_recursive,
+	) {
 		tree.collapse_recursive();
 		true
-	} else if key_match(key, key_config.keys.tree_expand_recursive) {
+	} else if key_match(key, key_config.keys().tree_expand_recursive)
+	{
 		tree.expand_recursive();
 		true
 	} else {
@@ -3,7 +3,7 @@ use std::path::PathBuf;
 use super::{
 	revision_files::RevisionFilesComponent, visibility_blocking,
 	CommandBlocking, CommandInfo, Component, DrawableComponent,
-	EventState,
+	EventState, SharedOptions,
 };
 use crate::{
 	keys::{key_match, SharedKeyConfig},
@@ -49,6 +49,7 @@ impl RevisionFilesPopup {
 		sender: &Sender<AsyncAppNotification>,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
+		options: SharedOptions,
 	) -> Self {
 		Self {
 			files: RevisionFilesComponent::new(
@@ -57,6 +58,7 @@ impl RevisionFilesPopup {
 				sender,
 				theme,
 				key_config.clone(),
+				options,
 			),
 			visible: false,
 			key_config,
@@ -88,6 +90,13 @@ impl RevisionFilesPopup {
 		self.files.find_file(file);
 	}
 
+	pub fn file_finder_filter(
+		&mut self,
+		query: Option<String>,
+	) -> Result<()> {
+		self.files.set_filter(query)
+	}
+
 	fn hide_stacked(&mut self, stack: bool) {
 		self.hide();
 
@@ -150,7 +159,7 @@ impl Component for RevisionFilesPopup {
 	) -> Result<EventState> {
 		if self.is_visible() {
 			if let Event::Key(key) = event {
-				if key_match(key, self.key_config.keys.exit_popup) {
+				if key_match(key, self.key_config.keys().exit_popup) {
 					self.hide_stacked(false);
 				}
 			}
@@ -0,0 +1,387 @@
+use super::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState, ScrollType, TextInputComponent,
+};
+use crate::{
+	keys::{key_match, SharedKeyConfig},
+	queue::{InternalEvent, Queue},
+	strings,
+	ui::{self, style::SharedTheme},
+};
+use anyhow::Result;
+use asyncgit::sync::{self, CommitId, RepoPathRef};
+use crossterm::event::Event;
+use fuzzy_matcher::FuzzyMatcher;
+use std::borrow::Cow;
+use tui::{
+	backend::Backend,
+	layout::{Constraint, Direction, Layout, Margin, Rect},
+	text::{Span, Spans},
+	widgets::{Block, Borders, Clear},
+	Frame,
+};
+
+/// fuzzy picker for the revision the files tab shows, offering
+/// branches and tags or any revision typed into the search
+pub struct RevisionPickerPopup {
+	repo: RepoPathRef,
+	queue: Queue,
+	visible: bool,
+	find_text: TextInputComponent,
+	theme: SharedTheme,
+	/// name and commit of every revision to pick from
+	items: Vec<(String, CommitId)>,
+	selection: usize,
+	/// `None` stands for the query itself as a revision
+	filtered: Vec<(Option<usize>, Vec<usize>)>,
+	key_config: SharedKeyConfig,
+}
+
+impl RevisionPickerPopup {
+	///
+	pub fn new(
+		repo: RepoPathRef,
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		let mut find_text = TextInputComponent::new(
+			theme.clone(),
+			key_config.clone(),
+			"",
+			&strings::revision_picker_msg(),
+			false,
+		);
+		find_text.embed();
+
+		Self {
+			repo,
+			queue: queue.clone(),
+			visible: false,
+			find_text,
+			theme,
+			items: Vec::new(),
+			filtered: Vec::new(),
+			selection: 0,
+			key_config,
+		}
+	}
+
+	///
+	pub fn open(&mut self) -> Result<()> {
+		let repo = self.repo.borrow();
+
+		self.items =
+			vec![(String::from("HEAD"), sync::get_head(&repo)?)];
+
+		for local in [true, false] {
+			self.items.extend(
+				sync::get_branches_info(&repo, local)?
+					.into_iter()
+					.map(|branch| (branch.name, branch.top_commit)),
+			);
+		}
+
+		let mut tags = sync::get_tags(&repo)?
+			.into_iter()
+			.flat_map(|(id, tags)| {
+				tags.into_iter().map(move |tag| (tag.name, id))
+			})
+			.collect::<Vec<_>>();
+		tags.sort_by(|(a, _), (b, _)| b.cmp(a));
+		self.items.extend(tags);
+
+		drop(repo);
+
+		self.find_text.set_text(String::new());
+		self.find_text.show()?;
+		self.update_query();
+		self.show()?;
+
+		Ok(())
+	}
+
+	fn update_query(&mut self) {
+		let query = self.find_text.get_text();
+
+		self.filtered.clear();
+
+		if query.is_empty() {
+			self.filtered.extend(
+				(0..self.items.len())
+					.map(|idx| (Some(idx), Vec::new())),
+			);
+		} else {
+			let matcher =
+				fuzzy_matcher::skim::SkimMatcherV2::default();
+
+			let mut items = self
+				.items
+				.iter()
+				.enumerate()
+				.filter_map(|(idx, (name, _))| {
+					matcher
+						.fuzzy_indices(name, query)
+						.map(|(score, indices)| (score, idx, indices))
+				})
+				.collect::<Vec<(_, _, _)>>();
+
+			items.sort_by(|(score1, _, _), (score2, _, _)| {
+				score2.cmp(score1)
+			});
+
+			if !self.items.iter().any(|(name, _)| name == query) {
+				self.filtered.push((None, Vec::new()));
+			}
+
+			self.filtered.extend(
+				items
+					.into_iter()
+					.map(|entry| (Some(entry.1), entry.2)),
+			);
+		}
+
+		self.selection = 0;
+	}
+
+	fn move_selection(&mut self, move_type: ScrollType) {
+		let new_selection = match move_type {
+			ScrollType::Up => self.selection.saturating_sub(1),
+			ScrollType::Down => self.selection.saturating_add(1),
+			_ => self.selection,
+		};
+
+		self.selection = new_selection
+			.clamp(0, self.filtered.len().saturating_sub(1));
+	}
+
+	fn confirm(&mut self) {
+		let selected = match self.filtered.get(self.selection) {
+			Some((Some(idx), _)) => self.items.get(*idx).cloned(),
+			Some((None, _)) => {
+				let query = self.find_text.get_text().trim();
+
+				match sync::resolve_revision(
+					&self.repo.borrow(),
+					query,
+				) {
+					Ok(id) => Some((query.to_string(), id)),
+					Err(e) => {
+						self.queue.push(InternalEvent::ShowErrorMsg(
+							strings::revision_picker_invalid(
+								query,
+								&e.to_string(),
+							),
+						));
+						None
+					}
+				}
+			}
+			None => None,
+		};
+
+		if let Some((name, id)) = selected {
+			self.queue.push(InternalEvent::SelectFilesRevision(
+				(name != "HEAD").then(|| (id, name)),
+			));
+			self.hide();
+		}
+	}
+}
+
+impl DrawableComponent for RevisionPickerPopup {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		area: Rect,
+	) -> Result<()> {
+		if self.is_visible() {
+			const MAX_SIZE: (u16, u16) = (60, 20);
+
+			let area = ui::centered_rect_absolute(
+				MAX_SIZE.0, MAX_SIZE.1, area,
+			);
+
+			f.render_widget(Clear, area);
+			f.render_widget(
+				Block::default()
+					.borders(Borders::all())
+					.border_style(self.theme.popup_border())
+					.style(self.theme.title(true))
+					.title(Span::styled(
+						strings::POPUP_TITLE_REVISION_PICKER,
+						self.theme.title(true),
+					)),
+				area,
+			);
+
+			let chunks = Layout::default()
+				.direction(Direction::Vertical)
+				.constraints(
+					[
+						Constraint::Length(1),
+						Constraint::Percentage(100),
+					]
+					.as_ref(),
+				)
+				.split(area.inner(&Margin {
+					horizontal: 1,
+					vertical: 1,
+				}));
+
+			self.find_text.draw(f, chunks[0])?;
+
+			let title = format!("Revisions: {}", self.filtered.len());
+
+			let height = usize::from(chunks[1].height);
+			let scroll_top = ui::calc_scroll_top(
+				0,
+				height.saturating_sub(1),
+				self.selection,
+			);
+
+			let items = self
+				.filtered
+				.iter()
+				.enumerate()
+				.skip(scroll_top)
+				.take(height)
+				.map(|(pos, (idx, indices))| {
+					let selected = pos == self.selection;
+
+					let (prefix, name) = idx
+						.and_then(|idx| self.items.get(idx))
+						.map_or_else(
+							|| {
+								(
+									String::new(),
+									strings::revision_picker_query(
+										self.find_text.get_text(),
+									),
+								)
+							},
+							|(name, id)| {
+								(
+									format!(
+										"{} ",
+										id.get_short_string()
+									),
+									name.clone(),
+								)
+							},
+						);
+
+					let mut spans = vec![Span::styled(
+						Cow::from(prefix),
+						self.theme.text(selected, false),
+					)];
+					spans.extend(name.chars().enumerate().map(
+						|(c_idx, c)| {
+							Span::styled(
+								Cow::from(c.to_string()),
+								self.theme.text(
+									selected,
+									indices.contains(&c_idx),
+								),
+							)
+						},
+					));
+
+					Spans::from(spans)
+				});
+
+			ui::draw_list_block(
+				f,
+				chunks[1],
+				Block::default()
+					.title(Span::styled(
+						title,
+						self.theme.title(true),
+					))
+					.borders(Borders::TOP),
+				items,
+			);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for RevisionPickerPopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			out.push(
+				CommandInfo::new(
+					strings::commands::close_popup(&self.key_config),
+					true,
+					true,
+				)
+				.order(1),
+			);
+
+			out.push(CommandInfo::new(
+				strings::commands::revision_picker_confirm(
+					&self.key_config,
+				),
+				!self.filtered.is_empty(),
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: &Event) -> Result<EventState> {
+		if self.is_visible() {
+			if let Event::Key(key) = event {
+				if key_match(key, self.key_config.keys().exit_popup) {
+					self.hide();
+				} else if key_match(key, self.key_config.keys().enter)
+				{
+					self.confirm();
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_down,
+				) {
+					self.move_selection(ScrollType::Down);
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_up,
+				) {
+					self.move_selection(ScrollType::Up);
+				}
+			}
+
+			if self.find_text.event(event)?.is_consumed() {
+				self.update_query();
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+		Ok(())
+	}
+}
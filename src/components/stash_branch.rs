@@ -0,0 +1,151 @@
+use super::{
+	textinput::TextInputComponent, visibility_blocking,
+	CommandBlocking, CommandInfo, Component, DrawableComponent,
+	EventState,
+};
+use crate::{
+	keys::{key_match, SharedKeyConfig},
+	queue::{InternalEvent, NeedsUpdate, Queue},
+	strings,
+	ui::style::SharedTheme,
+};
+use anyhow::Result;
+use asyncgit::sync::{self, CommitId, RepoPathRef};
+use crossterm::event::Event;
+use tui::{backend::Backend, layout::Rect, Frame};
+
+pub struct StashBranchComponent {
+	repo: RepoPathRef,
+	input: TextInputComponent,
+	stash: Option<CommitId>,
+	queue: Queue,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for StashBranchComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		self.input.draw(f, rect)?;
+
+		Ok(())
+	}
+}
+
+impl Component for StashBranchComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			self.input.commands(out, force_all);
+
+			out.push(CommandInfo::new(
+				strings::commands::stash_branch_confirm_msg(
+					&self.key_config,
+				),
+				!self.input.get_text().is_empty(),
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, ev: &Event) -> Result<EventState> {
+		if self.is_visible() {
+			if self.input.event(ev)?.is_consumed() {
+				return Ok(EventState::Consumed);
+			}
+
+			if let Event::Key(e) = ev {
+				if key_match(e, self.key_config.keys().enter)
+					&& !self.input.get_text().is_empty()
+				{
+					self.stash_branch();
+				}
+
+				return Ok(EventState::Consumed);
+			}
+		}
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.input.is_visible()
+	}
+
+	fn hide(&mut self) {
+		self.input.hide();
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.input.show()?;
+
+		Ok(())
+	}
+}
+
+impl StashBranchComponent {
+	///
+	pub fn new(
+		repo: RepoPathRef,
+		queue: Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			repo,
+			queue,
+			input: TextInputComponent::new(
+				theme,
+				key_config.clone(),
+				&strings::stash_branch_popup_title(&key_config),
+				&strings::stash_branch_popup_msg(&key_config),
+				true,
+			),
+			stash: None,
+			key_config,
+		}
+	}
+
+	///
+	pub fn open(&mut self, stash: CommitId) -> Result<()> {
+		self.stash = Some(stash);
+		self.input.clear();
+		self.show()?;
+
+		Ok(())
+	}
+
+	/// checks out a new branch at the base of the stash and pops it
+	pub fn stash_branch(&mut self) {
+		if let Some(stash) = self.stash {
+			let res = sync::stash_branch(
+				&self.repo.borrow(),
+				stash,
+				self.input.get_text(),
+			);
+
+			match res {
+				Ok(()) => {
+					self.hide();
+					self.input.clear();
+					self.queue.push(InternalEvent::Update(
+						NeedsUpdate::ALL | NeedsUpdate::BRANCHES,
+					));
+					self.queue.push(InternalEvent::TabSwitchStatus);
+				}
+				Err(e) => {
+					log::error!("stash branch: {}", e);
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						format!("stash branch error:\n{}", e),
+					));
+				}
+			}
+		}
+	}
+}
@@ -18,6 +18,8 @@ use tui::{backend::Backend, layout::Rect, Frame};
 pub struct StashMsgComponent {
 	repo: RepoPathRef,
 	options: StashingOptions,
+	/// stash only these paths unless empty
+	paths: Vec<String>,
 	input: TextInputComponent,
 	queue: Queue,
 	key_config: SharedKeyConfig,
@@ -63,17 +65,33 @@ impl Component for StashMsgComponent {
 			}
 
 			if let Event::Key(e) = ev {
-				if key_match(e, self.key_config.keys.enter) {
-					let result = sync::stash_save(
-						&self.repo.borrow(),
-						if self.input.get_text().is_empty() {
-							None
-						} else {
-							Some(self.input.get_text())
-						},
-						self.options.stash_untracked,
-						self.options.keep_index,
-					);
+				if key_match(e, self.key_config.keys().enter) {
+					let message = if self.input.get_text().is_empty()
+					{
+						None
+					} else {
+						Some(self.input.get_text())
+					};
+					let result = if self.options.untracked_only {
+						sync::stash_save_untracked(
+							&self.repo.borrow(),
+							message,
+						)
+					} else if self.paths.is_empty() {
+						sync::stash_save(
+							&self.repo.borrow(),
+							message,
+							self.options.stash_untracked,
+							self.options.keep_index,
+						)
+					} else {
+						sync::stash_save_paths(
+							&self.repo.borrow(),
+							message,
+							&self.paths,
+							self.options.keep_index,
+						)
+					};
 					match result {
 						Ok(_) => {
 							self.input.clear();
@@ -82,6 +100,11 @@ impl Component for StashMsgComponent {
 							self.queue.push(InternalEvent::Update(
 								NeedsUpdate::ALL,
 							));
+							self.queue.push(
+								InternalEvent::ShowToast(
+									strings::toast_stash_saved(),
+								),
+							);
 						}
 						Err(e) => {
 							self.hide();
@@ -132,6 +155,7 @@ impl StashMsgComponent {
 	) -> Self {
 		Self {
 			options: StashingOptions::default(),
+			paths: Vec::new(),
 			queue,
 			input: TextInputComponent::new(
 				theme,
@@ -146,7 +170,12 @@ impl StashMsgComponent {
 	}
 
 	///
-	pub fn options(&mut self, options: StashingOptions) {
+	pub fn options(
+		&mut self,
+		options: StashingOptions,
+		paths: Vec<String>,
+	) {
 		self.options = options;
+		self.paths = paths;
 	}
 }
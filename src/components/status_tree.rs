@@ -9,14 +9,21 @@ use crate::{
 	components::{CommandInfo, Component, EventState},
 	keys::{key_match, SharedKeyConfig},
 	queue::{InternalEvent, NeedsUpdate, Queue, StackablePopupOpen},
-	strings::{self, order},
+	strings::{self, order, symbol},
 	ui,
-	ui::style::SharedTheme,
+	ui::{
+		draw_scrollbar, icons, scrollbar_position, style::SharedTheme,
+	},
 };
 use anyhow::Result;
-use asyncgit::{hash, StatusItem, StatusItemType};
-use crossterm::event::Event;
-use std::{borrow::Cow, cell::Cell, convert::From, path::Path};
+use asyncgit::{
+	hash, sync::FileDiffStats, StatusItem, StatusItemType,
+};
+use crossterm::event::{Event, MouseButton, MouseEventKind};
+use std::{
+	borrow::Cow, cell::Cell, collections::BTreeSet, convert::From,
+	path::Path,
+};
 use tui::{backend::Backend, layout::Rect, text::Span, Frame};
 
 //TODO: use new `filetreelist` crate
@@ -34,7 +41,13 @@ pub struct StatusTreeComponent {
 	theme: SharedTheme,
 	key_config: SharedKeyConfig,
 	scroll_top: Cell<usize>,
+	/// where the list was drawn last
+	area: Cell<Rect>,
 	visible: bool,
+	/// full paths of the marked files
+	marked: BTreeSet<String>,
+	/// added/deleted lines per file, summed up on collapsed folders
+	diff_stats: FileDiffStats,
 }
 
 impl StatusTreeComponent {
@@ -56,17 +69,32 @@ impl StatusTreeComponent {
 			theme,
 			key_config,
 			scroll_top: Cell::new(0),
+			area: Cell::new(Rect::default()),
 			pending: true,
 			visible: false,
+			marked: BTreeSet::new(),
+			diff_stats: FileDiffStats::new(),
 		}
 	}
 
 	///
 	pub fn update(&mut self, list: &[StatusItem]) -> Result<()> {
+		self.update_list(list, false)
+	}
+
+	/// see `StatusTree::update_list`
+	pub fn update_list(
+		&mut self,
+		list: &[StatusItem],
+		flat: bool,
+	) -> Result<()> {
 		self.pending = false;
-		let new_hash = hash(list);
+		self.marked.retain(|path| {
+			list.iter().any(|item| &item.path == path)
+		});
+		let new_hash = hash(&(list, flat));
 		if self.current_hash != new_hash {
-			self.tree.update(list)?;
+			self.tree.update_list(list, flat)?;
 			self.current_hash = new_hash;
 		}
 
@@ -89,6 +117,67 @@ impl StatusTreeComponent {
 		})
 	}
 
+	///
+	pub const fn marked(&self) -> &BTreeSet<String> {
+		&self.marked
+	}
+
+	/// marks or unmarks the selected file or all files in the
+	/// selected folder
+	pub fn toggle_mark(&mut self) {
+		if let Some(item) = self.selection() {
+			let folder = format!("{}/", item.info.full_path);
+			let files = self
+				.tree
+				.tree
+				.items()
+				.iter()
+				.filter_map(|item| match &item.kind {
+					FileTreeItemKind::File(file) => Some(&file.path),
+					FileTreeItemKind::Path(_) => None,
+				})
+				.filter(|path| {
+					**path == item.info.full_path
+						|| path.starts_with(&folder)
+				})
+				.cloned()
+				.collect::<Vec<_>>();
+
+			if files.iter().all(|path| self.marked.contains(path)) {
+				for path in &files {
+					self.marked.remove(path);
+				}
+			} else {
+				self.marked.extend(files);
+			}
+		}
+	}
+
+	///
+	pub fn set_diff_stats(&mut self, stats: FileDiffStats) {
+		self.diff_stats = stats;
+	}
+
+	/// sum of added and deleted lines of all files in a collapsed
+	/// folder
+	fn item_diff_stats(
+		&self,
+		item: &TextDrawInfo,
+	) -> Option<(usize, usize)> {
+		match item.item_kind {
+			FileTreeItemKind::Path(collapsed) if collapsed.0 => (),
+			_ => return None,
+		}
+
+		let prefix = format!("{}/", item.full_path);
+
+		self.diff_stats
+			.iter()
+			.filter(|(path, _)| path.starts_with(&prefix))
+			.map(|(_, stats)| *stats)
+			.reduce(|a, b| (a.0 + b.0, a.1 + b.1))
+	}
+
 	///
 	pub fn show_selection(&mut self, show: bool) {
 		self.show_selection = show;
@@ -112,6 +201,7 @@ impl StatusTreeComponent {
 	///
 	pub fn clear(&mut self) -> Result<()> {
 		self.current_hash = 0;
+		self.diff_stats.clear();
 		self.pending = true;
 		self.tree.update(&[])
 	}
@@ -126,6 +216,65 @@ impl StatusTreeComponent {
 		})
 	}
 
+	/// `None` while nothing is marked to not waste the space
+	fn item_marked(&self, item: &FileTreeItemKind) -> Option<bool> {
+		if self.marked.is_empty() {
+			return None;
+		}
+
+		Some(match item {
+			FileTreeItemKind::File(file) => {
+				self.marked.contains(&file.path)
+			}
+			FileTreeItemKind::Path(_) => false,
+		})
+	}
+
+	/// selects the item drawn in the given row of the screen
+	fn select_row(&mut self, row: u16) -> bool {
+		let area = self.theme.pane_inner(self.area.get());
+		if row < area.y || row >= area.bottom() {
+			return false;
+		}
+
+		self.select_visible(
+			self.scroll_top.get() + usize::from(row - area.y),
+		)
+	}
+
+	/// number of items not folded into their parent folder
+	fn visible_count(&self) -> usize {
+		self.build_vec_text_draw_info_for_drawing()
+			.0
+			.iter()
+			.filter(|info| info.visible)
+			.count()
+	}
+
+	/// selects the nth item not folded into its parent folder
+	fn select_visible(&mut self, nth: usize) -> bool {
+		let index = self
+			.build_vec_text_draw_info_for_drawing()
+			.0
+			.iter()
+			.filter(|info| info.visible)
+			.nth(nth)
+			.map(|info| info.index);
+
+		let changed = index.map_or(false, |index| {
+			self.tree.available_selections.contains(&index)
+				&& self.tree.selection.replace(index) != Some(index)
+		});
+
+		if changed {
+			if let Some(ref queue) = self.queue {
+				queue.push(InternalEvent::Update(NeedsUpdate::DIFF));
+			}
+		}
+
+		changed
+	}
+
 	fn move_selection(&mut self, dir: MoveSelection) -> bool {
 		let changed = self.tree.move_selection(dir);
 
@@ -149,6 +298,7 @@ impl StatusTreeComponent {
 		}
 	}
 
+	#[allow(clippy::too_many_arguments)]
 	fn item_to_text<'b>(
 		string: &str,
 		indent: usize,
@@ -156,6 +306,8 @@ impl StatusTreeComponent {
 		file_item_kind: &FileTreeItemKind,
 		width: u16,
 		selected: bool,
+		marked: Option<bool>,
+		diff_stats: Option<(usize, usize)>,
 		theme: &'b SharedTheme,
 	) -> Option<Span<'b>> {
 		let indent_str = if indent == 0 {
@@ -163,6 +315,11 @@ impl StatusTreeComponent {
 		} else {
 			format!("{:w$}", " ", w = (indent as usize) * 2)
 		};
+		let mark_str = match marked {
+			Some(true) => format!("{} ", symbol::CHECKMARK),
+			Some(false) => String::from("  "),
+			None => String::new(),
+		};
 
 		if !visible {
 			return None;
@@ -170,23 +327,37 @@ impl StatusTreeComponent {
 
 		match file_item_kind {
 			FileTreeItemKind::File(status_item) => {
-				let status_char =
-					Self::item_status_char(status_item.status);
 				let file = Path::new(&status_item.path)
 					.file_name()
 					.and_then(std::ffi::OsStr::to_str)
 					.expect("invalid path.");
+				let (status_char, file) = if theme.icons() {
+					(
+						icons::status(status_item.status).to_string(),
+						format!("{} {}", icons::file(file), file),
+					)
+				} else {
+					(
+						Self::item_status_char(status_item.status)
+							.to_string(),
+						file.to_string(),
+					)
+				};
 
 				let txt = if selected {
 					format!(
-						"{} {}{:w$}",
+						"{}{} {}{:w$}",
+						mark_str,
 						status_char,
 						indent_str,
 						file,
 						w = width as usize
 					)
 				} else {
-					format!("{} {}{}", status_char, indent_str, file)
+					format!(
+						"{}{} {}{}",
+						mark_str, status_char, indent_str, file
+					)
 				};
 
 				Some(Span::styled(
@@ -196,12 +367,25 @@ impl StatusTreeComponent {
 			}
 
 			FileTreeItemKind::Path(path_collapsed) => {
-				let collapse_char =
-					if path_collapsed.0 { '▸' } else { '▾' };
+				let collapse_char = if theme.icons() {
+					format!("{} ", icons::folder(path_collapsed.0))
+				} else if path_collapsed.0 {
+					String::from("▸")
+				} else {
+					String::from("▾")
+				};
+
+				let string = match diff_stats {
+					Some((added, deleted)) => {
+						format!("{} +{} -{}", string, added, deleted)
+					}
+					None => string.to_string(),
+				};
 
 				let txt = if selected {
 					format!(
-						"  {}{}{:w$}",
+						"{}  {}{}{:w$}",
+						mark_str,
 						indent_str,
 						collapse_char,
 						string,
@@ -209,8 +393,8 @@ impl StatusTreeComponent {
 					)
 				} else {
 					format!(
-						"  {}{}{}",
-						indent_str, collapse_char, string,
+						"{}  {}{}{}",
+						mark_str, indent_str, collapse_char, string,
 					)
 				};
 
@@ -247,7 +431,9 @@ impl StatusTreeComponent {
 			}
 
 			vec_draw_text_info.push(TextDrawInfo {
+				index,
 				name: item.info.path.clone(),
+				full_path: &item.info.full_path,
 				indent: item.info.indent,
 				visible: item.info.visible,
 				item_kind: &item.kind,
@@ -302,7 +488,10 @@ impl StatusTreeComponent {
 
 /// Used for drawing the `FileTreeComponent`
 struct TextDrawInfo<'a> {
+	/// position of the (first folded up) item in the tree
+	index: usize,
 	name: String,
+	full_path: &'a str,
 	indent: u8,
 	visible: bool,
 	item_kind: &'a FileTreeItemKind,
@@ -318,6 +507,8 @@ impl DrawableComponent for StatusTreeComponent {
 			return Ok(());
 		}
 
+		self.area.set(r);
+
 		if self.pending {
 			let items = vec![Span::styled(
 				Cow::from(strings::loading_text(&self.key_config)),
@@ -344,7 +535,8 @@ impl DrawableComponent for StatusTreeComponent {
 				.selection
 				.map(|idx| idx.saturating_sub(selection_offset))
 				.unwrap_or_default();
-			let tree_height = r.height.saturating_sub(2) as usize;
+			let tree_height =
+				usize::from(self.theme.pane_inner(r).height);
 
 			self.scroll_top.set(ui::calc_scroll_top(
 				self.scroll_top.get(),
@@ -363,6 +555,8 @@ impl DrawableComponent for StatusTreeComponent {
 						draw_text_info.item_kind,
 						r.width,
 						self.show_selection && select == index,
+						self.item_marked(draw_text_info.item_kind),
+						self.item_diff_stats(draw_text_info),
 						&self.theme,
 					)
 				})
@@ -375,6 +569,20 @@ impl DrawableComponent for StatusTreeComponent {
 				self.focused,
 				&self.theme,
 			);
+
+			if self.focused {
+				draw_scrollbar(
+					f,
+					r,
+					&self.theme,
+					vec_draw_text_info
+						.iter()
+						.filter(|info| info.visible)
+						.count()
+						.saturating_sub(1),
+					select.saturating_sub(selection_offset_visible),
+				);
+			}
 		}
 
 		Ok(())
@@ -413,14 +621,45 @@ impl Component for StatusTreeComponent {
 			)
 			.order(order::RARE_ACTION),
 		);
+		out.push(
+			CommandInfo::new(
+				strings::commands::copy_path(&self.key_config),
+				self.selection_file().is_some(),
+				self.focused || force_all,
+			)
+			.order(order::RARE_ACTION),
+		);
 
 		CommandBlocking::PassingOn
 	}
 
 	fn event(&mut self, ev: &Event) -> Result<EventState> {
 		if self.focused {
+			if let Event::Mouse(mouse) = ev {
+				if let Some(pos) = scrollbar_position(
+					self.area.get(),
+					self.visible_count().saturating_sub(1),
+					*mouse,
+				) {
+					self.select_visible(pos);
+					return Ok(EventState::Consumed);
+				}
+
+				if mouse.kind
+					== MouseEventKind::Down(MouseButton::Left)
+					&& self.area.get().intersects(Rect::new(
+						mouse.column,
+						mouse.row,
+						1,
+						1,
+					)) {
+					self.select_row(mouse.row);
+					return Ok(EventState::Consumed);
+				}
+			}
+
 			if let Event::Key(e) = ev {
-				return if key_match(e, self.key_config.keys.blame) {
+				return if key_match(e, self.key_config.keys().blame) {
 					if let Some(status_item) = self.selection_file() {
 						self.hide();
 						if let Some(queue) = &self.queue {
@@ -438,7 +677,7 @@ impl Component for StatusTreeComponent {
 					Ok(EventState::Consumed)
 				} else if key_match(
 					e,
-					self.key_config.keys.file_history,
+					self.key_config.keys().file_history,
 				) {
 					if let Some(status_item) = self.selection_file() {
 						self.hide();
@@ -453,31 +692,49 @@ impl Component for StatusTreeComponent {
 						}
 					}
 					Ok(EventState::Consumed)
-				} else if key_match(e, self.key_config.keys.move_down)
-				{
+				} else if key_match(
+					e,
+					self.key_config.keys().copy_path,
+				) {
+					if let Some(status_item) = self.selection_file() {
+						if let Some(queue) = &self.queue {
+							queue.push(InternalEvent::CopyPath(
+								status_item.path,
+								None,
+							));
+						}
+					}
+					Ok(EventState::Consumed)
+				} else if key_match(
+					e,
+					self.key_config.keys().move_down,
+				) {
 					Ok(self
 						.move_selection(MoveSelection::Down)
 						.into())
-				} else if key_match(e, self.key_config.keys.move_up) {
+				} else if key_match(e, self.key_config.keys().move_up)
+				{
 					Ok(self.move_selection(MoveSelection::Up).into())
-				} else if key_match(e, self.key_config.keys.home)
-					|| key_match(e, self.key_config.keys.shift_up)
+				} else if key_match(e, self.key_config.keys().home)
+					|| key_match(e, self.key_config.keys().shift_up)
 				{
 					Ok(self
 						.move_selection(MoveSelection::Home)
 						.into())
-				} else if key_match(e, self.key_config.keys.end)
-					|| key_match(e, self.key_config.keys.shift_down)
+				} else if key_match(e, self.key_config.keys().end)
+					|| key_match(e, self.key_config.keys().shift_down)
 				{
 					Ok(self.move_selection(MoveSelection::End).into())
-				} else if key_match(e, self.key_config.keys.move_left)
-				{
+				} else if key_match(
+					e,
+					self.key_config.keys().move_left,
+				) {
 					Ok(self
 						.move_selection(MoveSelection::Left)
 						.into())
 				} else if key_match(
 					e,
-					self.key_config.keys.move_right,
+					self.key_config.keys().move_right,
 				) {
 					Ok(self
 						.move_selection(MoveSelection::Right)
@@ -60,7 +60,8 @@ impl DrawableComponent for SubmodulesListComponent {
 				Block::default()
 					.title(strings::POPUP_TITLE_SUBMODULES)
 					.border_type(BorderType::Thick)
-					.borders(Borders::ALL),
+					.borders(Borders::ALL)
+					.border_style(self.theme.popup_border()),
 				area,
 			);
 
@@ -117,35 +118,35 @@ impl Component for SubmodulesListComponent {
 		}
 
 		if let Event::Key(e) = ev {
-			if key_match(e, self.key_config.keys.exit_popup) {
+			if key_match(e, self.key_config.keys().exit_popup) {
 				self.hide();
-			} else if key_match(e, self.key_config.keys.move_down) {
+			} else if key_match(e, self.key_config.keys().move_down) {
 				return self
 					.move_selection(ScrollType::Up)
 					.map(Into::into);
-			} else if key_match(e, self.key_config.keys.move_up) {
+			} else if key_match(e, self.key_config.keys().move_up) {
 				return self
 					.move_selection(ScrollType::Down)
 					.map(Into::into);
-			} else if key_match(e, self.key_config.keys.page_down) {
+			} else if key_match(e, self.key_config.keys().page_down) {
 				return self
 					.move_selection(ScrollType::PageDown)
 					.map(Into::into);
-			} else if key_match(e, self.key_config.keys.page_up) {
+			} else if key_match(e, self.key_config.keys().page_up) {
 				return self
 					.move_selection(ScrollType::PageUp)
 					.map(Into::into);
-			} else if key_match(e, self.key_config.keys.home) {
+			} else if key_match(e, self.key_config.keys().home) {
 				return self
 					.move_selection(ScrollType::Home)
 					.map(Into::into);
-			} else if key_match(e, self.key_config.keys.end) {
+			} else if key_match(e, self.key_config.keys().end) {
 				return self
 					.move_selection(ScrollType::End)
 					.map(Into::into);
 			} else if key_match(
 				e,
-				self.key_config.keys.cmd_bar_toggle,
+				self.key_config.keys().cmd_bar_toggle,
 			) {
 				//do not consume if its the more key
 				return Ok(EventState::NotConsumed);
@@ -27,7 +27,7 @@ use tui::{
 	backend::Backend,
 	layout::Rect,
 	text::Text,
-	widgets::{Block, Borders, Wrap},
+	widgets::{Block, Wrap},
 	Frame,
 };
 
@@ -38,6 +38,8 @@ pub struct SyntaxTextComponent {
 	syntax_progress: Option<ProgressPercent>,
 	key_config: SharedKeyConfig,
 	paragraph_state: Cell<ParagraphState>,
+	/// where the text was drawn last
+	area: Cell<Rect>,
 	focused: bool,
 	theme: SharedTheme,
 }
@@ -55,6 +57,7 @@ impl SyntaxTextComponent {
 			syntax_progress: None,
 			current_file: None,
 			paragraph_state: Cell::new(ParagraphState::default()),
+			area: Cell::new(Rect::default()),
 			focused: false,
 			key_config,
 			theme,
@@ -141,6 +144,12 @@ impl SyntaxTextComponent {
 		}
 	}
 
+	/// rows of the paragraph inside its borders
+	fn content_height(&self, state: ParagraphState) -> u16 {
+		let borders = if self.theme.compact() { 1 } else { 2 };
+		state.height().saturating_sub(borders)
+	}
+
 	fn scroll(&self, nav: MoveSelection) -> bool {
 		let state = self.paragraph_state.get();
 
@@ -150,15 +159,15 @@ impl SyntaxTextComponent {
 			MoveSelection::Top => 0,
 			MoveSelection::End => state
 				.lines()
-				.saturating_sub(state.height().saturating_sub(2)),
+				.saturating_sub(self.content_height(state)),
 			MoveSelection::PageUp => state
 				.scroll()
 				.y
-				.saturating_sub(state.height().saturating_sub(2)),
+				.saturating_sub(self.content_height(state)),
 			MoveSelection::PageDown => state
 				.scroll()
 				.y
-				.saturating_add(state.height().saturating_sub(2)),
+				.saturating_add(self.content_height(state)),
 			_ => state.scroll().y,
 		};
 
@@ -169,9 +178,7 @@ impl SyntaxTextComponent {
 		let mut state = self.paragraph_state.get();
 
 		let new_scroll_pos = pos.min(
-			state
-				.lines()
-				.saturating_sub(state.height().saturating_sub(2)),
+			state.lines().saturating_sub(self.content_height(state)),
 		);
 
 		if new_scroll_pos == state.scroll().y {
@@ -218,7 +225,7 @@ impl DrawableComponent for SyntaxTextComponent {
 			.block(
 				Block::default()
 					.title(title)
-					.borders(Borders::ALL)
+					.borders(self.theme.pane_borders())
 					.border_style(self.theme.title(self.focused())),
 			);
 
@@ -230,14 +237,18 @@ impl DrawableComponent for SyntaxTextComponent {
 
 		self.set_scroll(state.scroll().y);
 
+		self.area.set(area);
+
 		if self.focused() {
 			ui::draw_scrollbar(
 				f,
 				area,
 				&self.theme,
-				usize::from(state.lines().saturating_sub(
-					state.height().saturating_sub(2),
-				)),
+				usize::from(
+					state
+						.lines()
+						.saturating_sub(self.content_height(state)),
+				),
 				usize::from(state.scroll().y),
 			);
 		}
@@ -269,6 +280,22 @@ impl Component for SyntaxTextComponent {
 		&mut self,
 		event: &crossterm::event::Event,
 	) -> Result<EventState> {
+		if let Event::Mouse(mouse) = event {
+			let state = self.paragraph_state.get();
+			let max = state
+				.lines()
+				.saturating_sub(self.content_height(state));
+
+			if let Some(pos) = ui::scrollbar_position(
+				self.area.get(),
+				usize::from(max),
+				*mouse,
+			) {
+				self.set_scroll(u16::try_from(pos).unwrap_or(max));
+				return Ok(EventState::Consumed);
+			}
+		}
+
 		if let Event::Key(key) = event {
 			if let Some(nav) = common_nav(key, &self.key_config) {
 				return Ok(self
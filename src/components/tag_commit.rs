@@ -11,12 +11,22 @@ use crate::{
 };
 use anyhow::Result;
 use asyncgit::sync::{self, CommitId, RepoPathRef};
-use crossterm::event::Event;
+use crossterm::{
+	event::Event,
+	terminal::{EnterAlternateScreen, LeaveAlternateScreen},
+	ExecutableCommand,
+};
+use scopeguard::defer;
+use std::io;
 use tui::{backend::Backend, layout::Rect, Frame};
 
 enum Mode {
+	/// revision to tag, before asking for the name
+	Revision,
 	Name,
-	Annotation { tag_name: String },
+	Annotation {
+		tag_name: String,
+	},
 }
 
 pub struct TagCommitComponent {
@@ -24,6 +34,9 @@ pub struct TagCommitComponent {
 	mode: Mode,
 	input: TextInputComponent,
 	commit_id: Option<CommitId>,
+	sign: bool,
+	/// name and annotation of a signed tag waiting for the terminal
+	signing: Option<(String, String)>,
 	queue: Queue,
 	key_config: SharedKeyConfig,
 }
@@ -62,6 +75,15 @@ impl Component for TagCommitComponent {
 				self.is_valid_tag(),
 				matches!(self.mode, Mode::Name),
 			));
+
+			out.push(CommandInfo::new(
+				strings::commands::tag_sign(
+					&self.key_config,
+					self.sign,
+				),
+				true,
+				true,
+			));
 		}
 
 		visibility_blocking(self)
@@ -74,28 +96,32 @@ impl Component for TagCommitComponent {
 			}
 
 			if let Event::Key(e) = ev {
-				if key_match(e, self.key_config.keys.enter)
+				if key_match(e, self.key_config.keys().enter)
 					&& self.is_valid_tag()
 				{
-					self.tag();
+					if matches!(self.mode, Mode::Revision) {
+						self.pick_revision();
+					} else if self.sign
+						&& matches!(self.mode, Mode::Name)
+					{
+						// signed tags are always annotated
+						self.annotate();
+					} else {
+						self.tag();
+					}
 				} else if key_match(
 					e,
-					self.key_config.keys.tag_annotate,
+					self.key_config.keys().tag_annotate,
 				) && self.is_valid_tag()
+					&& !matches!(self.mode, Mode::Revision)
 				{
-					let tag_name: String =
-						self.input.get_text().into();
-
-					self.input.clear();
-					self.input.set_title(
-						strings::tag_popup_annotation_title(
-							&tag_name,
-						),
-					);
-					self.input.set_default_msg(
-						strings::tag_popup_annotation_msg(),
-					);
-					self.mode = Mode::Annotation { tag_name };
+					self.annotate();
+				} else if key_match(
+					e,
+					self.key_config.keys().tag_sign,
+				) {
+					self.sign = !self.sign;
+					self.update_title();
 				}
 
 				return Ok(EventState::Consumed);
@@ -114,7 +140,9 @@ impl Component for TagCommitComponent {
 
 	fn show(&mut self) -> Result<()> {
 		self.mode = Mode::Name;
-		self.input.set_title(strings::tag_popup_name_title());
+		self.sign = sync::tag_gpgsign_enabled(&self.repo.borrow())
+			.unwrap_or_default();
+		self.update_title();
 		self.input.set_default_msg(strings::tag_popup_name_msg());
 		self.input.show()?;
 
@@ -140,6 +168,8 @@ impl TagCommitComponent {
 				true,
 			),
 			commit_id: None,
+			sign: false,
+			signing: None,
 			key_config,
 			repo,
 			mode: Mode::Name,
@@ -154,13 +184,86 @@ impl TagCommitComponent {
 		Ok(())
 	}
 
+	/// asks for the revision to tag first, prefilled with `revision`
+	pub fn open_at_revision(
+		&mut self,
+		revision: String,
+	) -> Result<()> {
+		self.commit_id = None;
+		self.show()?;
+
+		self.mode = Mode::Revision;
+		self.update_title();
+		self.input
+			.set_default_msg(strings::tag_popup_revision_msg());
+		self.input.set_text(revision);
+
+		Ok(())
+	}
+
+	/// resolves the typed revision and asks for the tag name
+	fn pick_revision(&mut self) {
+		let res = sync::resolve_revision(
+			&self.repo.borrow(),
+			self.input.get_text(),
+		);
+
+		match res {
+			Ok(id) => {
+				self.commit_id = Some(id);
+				self.mode = Mode::Name;
+				self.update_title();
+				self.input
+					.set_default_msg(strings::tag_popup_name_msg());
+				self.input.clear();
+			}
+			Err(e) => {
+				self.queue.push(InternalEvent::ShowErrorMsg(
+					format!("tag error:\n{}", e),
+				));
+			}
+		}
+	}
+
 	fn is_valid_tag(&self) -> bool {
 		!self.input.get_text().is_empty()
 	}
 
+	fn annotate(&mut self) {
+		let tag_name: String = self.input.get_text().into();
+
+		self.input.clear();
+		self.input
+			.set_default_msg(strings::tag_popup_annotation_msg());
+		self.mode = Mode::Annotation { tag_name };
+		self.update_title();
+	}
+
+	fn update_title(&mut self) {
+		let title = match &self.mode {
+			Mode::Revision => strings::tag_popup_revision_title(),
+			Mode::Name => strings::tag_popup_name_title(),
+			Mode::Annotation { tag_name } => {
+				strings::tag_popup_annotation_title(tag_name)
+			}
+		};
+
+		self.input.set_title(if self.sign {
+			format!(
+				"{} {}",
+				title,
+				strings::tag_popup_sign_indicator()
+			)
+		} else {
+			title
+		});
+	}
+
 	fn tag_info(&self) -> (String, Option<String>) {
 		match &self.mode {
-			Mode::Name => (self.input.get_text().into(), None),
+			Mode::Revision | Mode::Name => {
+				(self.input.get_text().into(), None)
+			}
 			Mode::Annotation { tag_name } => {
 				(tag_name.clone(), Some(self.input.get_text().into()))
 			}
@@ -171,6 +274,14 @@ impl TagCommitComponent {
 	pub fn tag(&mut self) {
 		let (tag_name, tag_annotation) = self.tag_info();
 
+		if self.sign {
+			// gpg/ssh-keygen may need the terminal to ask for a passphrase
+			self.signing =
+				Some((tag_name, tag_annotation.unwrap_or_default()));
+			self.queue.push(InternalEvent::SuspendForSigning);
+			return;
+		}
+
 		if let Some(commit_id) = self.commit_id {
 			let result = sync::tag_commit(
 				&self.repo.borrow(),
@@ -178,25 +289,67 @@ impl TagCommitComponent {
 				&tag_name,
 				tag_annotation.as_deref(),
 			);
-			match result {
-				Ok(_) => {
-					self.input.clear();
-					self.hide();
-
-					self.queue.push(InternalEvent::Update(
-						NeedsUpdate::ALL,
-					));
-				}
-				Err(e) => {
-					// go back to tag name if something goes wrong
-					self.input.set_text(tag_name);
-					self.hide();
-
-					log::error!("e: {}", e,);
-					self.queue.push(InternalEvent::ShowErrorMsg(
-						format!("tag error:\n{}", e,),
-					));
+			self.tag_done(result, tag_name);
+		}
+	}
+
+	/// `true` if a signed tag waits for the terminal to be handed
+	/// over to the signing program
+	pub const fn is_signing_pending(&self) -> bool {
+		self.signing.is_some()
+	}
+
+	/// creates the pending signed tag,
+	/// only call this while input polling is suspended
+	pub fn finish_signed_tag(&mut self) -> Result<()> {
+		if let (Some((tag_name, message)), Some(commit_id)) =
+			(self.signing.take(), self.commit_id)
+		{
+			let result = {
+				io::stdout().execute(LeaveAlternateScreen)?;
+				defer! {
+					io::stdout().execute(EnterAlternateScreen).expect("reset terminal");
 				}
+
+				sync::tag_commit_signed(
+					&self.repo.borrow(),
+					&commit_id,
+					&tag_name,
+					&message,
+				)
+			};
+			self.tag_done(result, tag_name);
+		}
+
+		Ok(())
+	}
+
+	fn tag_done(
+		&mut self,
+		result: asyncgit::Result<CommitId>,
+		tag_name: String,
+	) {
+		match result {
+			Ok(_) => {
+				self.input.clear();
+				self.hide();
+
+				self.queue.push(InternalEvent::Update(
+					NeedsUpdate::ALL | NeedsUpdate::TAGS,
+				));
+				self.queue.push(InternalEvent::ShowToast(
+					strings::toast_tag_created(&tag_name),
+				));
+			}
+			Err(e) => {
+				// go back to tag name if something goes wrong
+				self.input.set_text(tag_name);
+				self.hide();
+
+				log::error!("e: {}", e,);
+				self.queue.push(InternalEvent::ShowErrorMsg(
+					format!("tag error:\n{}", e,),
+				));
 			}
 		}
 	}
@@ -0,0 +1,270 @@
+use super::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState, ScrollType,
+};
+use crate::{
+	keys::{key_match, SharedKeyConfig},
+	queue::{Action, InternalEvent, Queue},
+	strings,
+	ui::{self, style::SharedTheme},
+};
+use anyhow::Result;
+use asyncgit::sync::{self, RepoPathRef};
+use crossterm::event::Event;
+use tui::{
+	backend::Backend,
+	layout::{Margin, Rect},
+	text::{Span, Spans},
+	widgets::{Block, BorderType, Borders, Clear},
+	Frame,
+};
+
+/// what to do with a tag on the picked remote
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TagRemoteAction {
+	Push,
+	Delete,
+}
+
+/// picks the remote to push a tag to or delete it from
+pub struct TagRemotePopup {
+	repo: RepoPathRef,
+	queue: Queue,
+	visible: bool,
+	tag: String,
+	action: TagRemoteAction,
+	remotes: Vec<String>,
+	selection: usize,
+	theme: SharedTheme,
+	key_config: SharedKeyConfig,
+}
+
+impl TagRemotePopup {
+	///
+	pub fn new(
+		repo: RepoPathRef,
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			repo,
+			queue: queue.clone(),
+			visible: false,
+			tag: String::new(),
+			action: TagRemoteAction::Push,
+			remotes: Vec::new(),
+			selection: 0,
+			theme,
+			key_config,
+		}
+	}
+
+	/// preselects the default remote, skips asking if there is
+	/// only one
+	pub fn open(
+		&mut self,
+		tag: String,
+		action: TagRemoteAction,
+	) -> Result<()> {
+		let repo = self.repo.borrow().clone();
+
+		self.remotes = sync::get_remotes(&repo)?;
+		self.selection = sync::get_default_remote(&repo)
+			.ok()
+			.and_then(|remote| {
+				self.remotes.iter().position(|r| *r == remote)
+			})
+			.unwrap_or_default();
+		self.tag = tag;
+		self.action = action;
+
+		if self.remotes.len() == 1 {
+			self.confirm();
+		} else {
+			self.show()?;
+		}
+
+		Ok(())
+	}
+
+	fn selected_remote(&self) -> Option<&String> {
+		self.remotes.get(self.selection)
+	}
+
+	fn move_selection(&mut self, move_type: ScrollType) {
+		let new_selection = match move_type {
+			ScrollType::Up => self.selection.saturating_sub(1),
+			ScrollType::Down => self.selection.saturating_add(1),
+			_ => self.selection,
+		};
+
+		self.selection = new_selection
+			.clamp(0, self.remotes.len().saturating_sub(1));
+	}
+
+	fn confirm(&mut self) {
+		if let Some(remote) = self.selected_remote().cloned() {
+			let tag = self.tag.clone();
+			self.queue.push(match self.action {
+				TagRemoteAction::Push => {
+					InternalEvent::PushTag(tag, remote)
+				}
+				TagRemoteAction::Delete => {
+					InternalEvent::ConfirmAction(
+						Action::DeleteRemoteTag(tag, remote),
+					)
+				}
+			});
+			self.hide();
+		}
+	}
+
+	fn get_items(&self) -> Vec<Spans> {
+		self.remotes
+			.iter()
+			.enumerate()
+			.map(|(idx, remote)| {
+				Spans::from(Span::styled(
+					remote.as_str(),
+					self.theme.text(true, idx == self.selection),
+				))
+			})
+			.collect()
+	}
+}
+
+impl DrawableComponent for TagRemotePopup {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		if self.is_visible() {
+			let height = u16::try_from(self.remotes.len())
+				.unwrap_or(u16::MAX)
+				.clamp(1, 10)
+				.saturating_add(2);
+			let area = ui::centered_rect_absolute(40, height, rect);
+
+			let title = match self.action {
+				TagRemoteAction::Push => {
+					strings::tag_remote_push_title(&self.tag)
+				}
+				TagRemoteAction::Delete => {
+					strings::tag_remote_delete_title(&self.tag)
+				}
+			};
+
+			f.render_widget(Clear, area);
+			f.render_widget(
+				Block::default()
+					.borders(Borders::all())
+					.border_style(self.theme.popup_border())
+					.border_type(BorderType::Thick)
+					.title(Span::styled(
+						title,
+						self.theme.title(true),
+					)),
+				area,
+			);
+
+			let area = area.inner(&Margin {
+				horizontal: 1,
+				vertical: 1,
+			});
+
+			let height = usize::from(area.height);
+			let scroll_top = ui::calc_scroll_top(
+				0,
+				height.saturating_sub(1),
+				self.selection,
+			);
+
+			ui::draw_list_block(
+				f,
+				area,
+				Block::default(),
+				self.get_items()
+					.into_iter()
+					.skip(scroll_top)
+					.take(height),
+			);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for TagRemotePopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			out.push(
+				CommandInfo::new(
+					strings::commands::close_popup(&self.key_config),
+					true,
+					true,
+				)
+				.order(1),
+			);
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::tag_remote_confirm(
+					&self.key_config,
+				),
+				self.selected_remote().is_some(),
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, ev: &Event) -> Result<EventState> {
+		if self.is_visible() {
+			if let Event::Key(key) = ev {
+				if key_match(key, self.key_config.keys().exit_popup) {
+					self.hide();
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_down,
+				) {
+					self.move_selection(ScrollType::Down);
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_up,
+				) {
+					self.move_selection(ScrollType::Up);
+				} else if key_match(key, self.key_config.keys().enter)
+				{
+					self.confirm();
+				}
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+		Ok(())
+	}
+}
@@ -1,13 +1,19 @@
 use super::{
-	utils, visibility_blocking, CommandBlocking, CommandInfo,
-	Component, DrawableComponent, EventState,
+	textinput::TextInputComponent,
+	utils::{self, autostash::run_with_autostash},
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState, SharedOptions,
 };
 use crate::{
-	components::ScrollType,
+	components::{CompareBranchesOpen, ScrollType, TagRemoteAction},
 	keys::{key_match, SharedKeyConfig},
-	queue::{Action, InternalEvent, Queue},
-	strings,
-	ui::{self, Size},
+	queue::{
+		Action, AutostashOp, InternalEvent, NeedsUpdate, Queue,
+		StackablePopupOpen,
+	},
+	strings::{self, symbol},
+	try_or_popup,
+	ui::{self, icons, Size},
 	AsyncNotification,
 };
 use anyhow::Result;
@@ -19,39 +25,139 @@ use asyncgit::{
 		BasicAuthCredential,
 	},
 	sync::{
-		self, get_tags_with_metadata, RepoPathRef, TagWithMetadata,
+		self, get_config_string, get_config_strings, get_tag_details,
+		get_tags_with_metadata, set_config_string,
+		sign::Verification, version_cmp, RepoPath, RepoPathRef,
+		TagDetails, TagKind, TagWithMetadata,
 	},
 	AsyncGitNotification,
 };
 use crossbeam_channel::Sender;
 use crossterm::event::Event;
-use std::convert::TryInto;
+use std::{borrow::Cow, cmp::Reverse, convert::TryInto};
 use tui::{
 	backend::Backend,
-	layout::{Constraint, Margin, Rect},
-	text::Span,
+	layout::{Constraint, Direction, Layout, Margin, Rect},
+	text::{Span, Spans},
 	widgets::{
-		Block, BorderType, Borders, Cell, Clear, Row, Table,
-		TableState,
+		Block, BorderType, Borders, Cell, Clear, Paragraph, Row,
+		Table, TableState, Wrap,
 	},
 	Frame,
 };
 use ui::style::SharedTheme;
 
+/// lines of the details panel below the list, including borders
+const DETAILS_HEIGHT: u16 = 8;
+
+/// which kind of tags to list
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TagKindFilter {
+	All,
+	Only(TagKind),
+}
+
+impl TagKindFilter {
+	const fn next(self) -> Self {
+		match self {
+			Self::All => Self::Only(TagKind::Lightweight),
+			Self::Only(TagKind::Lightweight) => {
+				Self::Only(TagKind::Annotated)
+			}
+			Self::Only(TagKind::Annotated) => {
+				Self::Only(TagKind::Signed)
+			}
+			Self::Only(TagKind::Signed) => Self::All,
+		}
+	}
+
+	const fn name(self) -> &'static str {
+		match self {
+			Self::All => "all",
+			Self::Only(TagKind::Lightweight) => "lightweight",
+			Self::Only(TagKind::Annotated) => "annotated",
+			Self::Only(TagKind::Signed) => "signed",
+		}
+	}
+
+	fn matches(self, kind: TagKind) -> bool {
+		match self {
+			Self::All => true,
+			Self::Only(only) => only == kind,
+		}
+	}
+}
+
+/// order of the tag list
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TagSort {
+	/// most recently created first
+	Date,
+	/// highest version first, respecting `versionsort.suffix`
+	Version,
+}
+
+impl TagSort {
+	const CONFIG_KEY: &'static str = "gitui.tagSort";
+
+	const fn next(self) -> Self {
+		match self {
+			Self::Date => Self::Version,
+			Self::Version => Self::Date,
+		}
+	}
+
+	const fn name(self) -> &'static str {
+		match self {
+			Self::Date => "date",
+			Self::Version => "version",
+		}
+	}
+
+	/// sort order persisted in the repo config
+	fn load(repo: &RepoPath) -> Self {
+		let value = get_config_string(repo, Self::CONFIG_KEY)
+			.ok()
+			.flatten()
+			.unwrap_or_default();
+
+		if value == Self::Version.name() {
+			Self::Version
+		} else {
+			Self::Date
+		}
+	}
+
+	fn save(self, repo: &RepoPath) -> Result<()> {
+		set_config_string(repo, Self::CONFIG_KEY, self.name())?;
+		Ok(())
+	}
+}
+
 ///
 pub struct TagListComponent {
 	repo: RepoPathRef,
 	theme: SharedTheme,
 	queue: Queue,
 	tags: Option<Vec<TagWithMetadata>>,
+	all_tags: Vec<TagWithMetadata>,
+	details: Option<TagDetails>,
+	/// tag the selected one gets compared against instead of `HEAD`
+	marked: Option<String>,
+	sort: TagSort,
+	kind_filter: TagKindFilter,
+	filter: TextInputComponent,
 	visible: bool,
 	table_state: std::cell::Cell<TableState>,
 	current_height: std::cell::Cell<usize>,
+	/// where the scrollbar was drawn last
+	scrollbar_area: std::cell::Cell<Rect>,
 	missing_remote_tags: Option<Vec<String>>,
 	has_remotes: bool,
 	basic_credential: Option<BasicAuthCredential>,
 	async_remote_tags: AsyncSingleJob<AsyncRemoteTagsJob>,
 	key_config: SharedKeyConfig,
+	options: SharedOptions,
 }
 
 impl DrawableComponent for TagListComponent {
@@ -73,15 +179,37 @@ impl DrawableComponent for TagListComponent {
 				ui::rect_inside(MIN_SIZE, f.size().into(), area);
 			let area = area.intersection(rect);
 
+			f.render_widget(Clear, area);
+
+			let area = self.draw_filter(f, area)?;
+
+			let chunks = Layout::default()
+				.direction(Direction::Vertical)
+				.constraints(
+					[
+						Constraint::Min(1),
+						Constraint::Length(DETAILS_HEIGHT),
+					]
+					.as_ref(),
+				)
+				.split(area);
+			let area = chunks[0];
+
+			self.draw_details(f, chunks[1]);
+
 			let tag_name_width =
 				self.tags.as_ref().map_or(0, |tags| {
 					tags.iter()
 						.fold(0, |acc, tag| acc.max(tag.name.len()))
-				});
+				}) + if self.theme.icons() { 2 } else { 0 };
 
 			let constraints = [
+				// symbol if tag is marked for comparison
+				Constraint::Length(1),
 				// symbol if tag is not yet on remote and can be pushed
 				Constraint::Length(1),
+				// lightweight, annotated or signed
+				Constraint::Length(1),
 				// tag name
 				Constraint::Length(tag_name_width.try_into()?),
 				// commit date
@@ -105,16 +233,15 @@ impl DrawableComponent for TagListComponent {
 					Block::default()
 						.borders(Borders::ALL)
 						.title(Span::styled(
-							strings::title_tags(),
+							self.title(),
 							self.theme.title(true),
 						))
-						.border_style(self.theme.block(true))
+						.border_style(self.theme.popup_border())
 						.border_type(BorderType::Thick),
 				);
 
 			let mut table_state = self.table_state.take();
 
-			f.render_widget(Clear, area);
 			f.render_stateful_widget(table, area, &mut table_state);
 
 			let area = area.inner(&Margin {
@@ -122,6 +249,7 @@ impl DrawableComponent for TagListComponent {
 				horizontal: 0,
 			});
 
+			self.scrollbar_area.set(area);
 			ui::draw_scrollbar(
 				f,
 				area,
@@ -161,21 +289,85 @@ impl Component for TagListComponent {
 				true,
 			));
 
+			out.push(CommandInfo::new(
+				strings::commands::tag_filter(&self.key_config),
+				true,
+				!self.is_filtering() || force_all,
+			));
+
 			out.push(CommandInfo::new(
 				strings::commands::delete_tag_popup(&self.key_config),
 				self.valid_selection(),
 				true,
 			));
+			out.push(CommandInfo::new(
+				strings::commands::tag_sort(
+					&self.key_config,
+					self.sort.name(),
+				),
+				true,
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::tag_kind_filter(
+					&self.key_config,
+					self.kind_filter.name(),
+				),
+				true,
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::tag_revision(&self.key_config),
+				true,
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::delete_matching_tags(
+					&self.key_config,
+				),
+				true,
+				true,
+			));
 			out.push(CommandInfo::new(
 				strings::commands::select_tag(&self.key_config),
 				self.valid_selection(),
 				true,
 			));
+			out.push(CommandInfo::new(
+				strings::commands::tag_mark(&self.key_config),
+				self.valid_selection(),
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::tag_compare(
+					&self.key_config,
+					self.marked.is_some(),
+				),
+				self.valid_selection(),
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::tag_checkout(&self.key_config),
+				self.valid_selection(),
+				true,
+			));
 			out.push(CommandInfo::new(
 				strings::commands::push_tags(&self.key_config),
 				self.has_remotes,
 				true,
 			));
+			out.push(CommandInfo::new(
+				strings::commands::push_tag(&self.key_config),
+				self.has_remotes && self.valid_selection(),
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::tag_delete_remote(
+					&self.key_config,
+				),
+				self.has_remotes && self.valid_selection(),
+				true,
+			));
 			out.push(CommandInfo::new(
 				strings::commands::show_tag_annotation(
 					&self.key_config,
@@ -187,52 +379,76 @@ impl Component for TagListComponent {
 		visibility_blocking(self)
 	}
 
+	#[allow(clippy::too_many_lines)]
 	fn event(&mut self, event: &Event) -> Result<EventState> {
 		if self.visible {
+			if self.is_filtering() {
+				return self.filter_event(event);
+			}
+
+			if let Event::Mouse(mouse) = event {
+				if let Some(pos) = ui::scrollbar_position(
+					self.scrollbar_area.get(),
+					self.max_selection(),
+					*mouse,
+				) {
+					self.set_selection(pos);
+				}
+			}
+
 			if let Event::Key(key) = event {
-				if key_match(key, self.key_config.keys.exit_popup) {
+				if key_match(key, self.key_config.keys().exit_popup) {
 					self.hide();
-				} else if key_match(key, self.key_config.keys.move_up)
-				{
+				} else if key_match(
+					key,
+					self.key_config.keys().tag_filter,
+				) {
+					self.filter.show()?;
+				} else if key_match(
+					key,
+					self.key_config.keys().move_up,
+				) {
 					self.move_selection(ScrollType::Up);
 				} else if key_match(
 					key,
-					self.key_config.keys.move_down,
+					self.key_config.keys().move_down,
 				) {
 					self.move_selection(ScrollType::Down);
 				} else if key_match(
 					key,
-					self.key_config.keys.shift_up,
+					self.key_config.keys().shift_up,
 				) || key_match(
 					key,
-					self.key_config.keys.home,
+					self.key_config.keys().home,
 				) {
 					self.move_selection(ScrollType::Home);
 				} else if key_match(
 					key,
-					self.key_config.keys.shift_down,
+					self.key_config.keys().shift_down,
 				) || key_match(
 					key,
-					self.key_config.keys.end,
+					self.key_config.keys().end,
 				) {
 					self.move_selection(ScrollType::End);
 				} else if key_match(
 					key,
-					self.key_config.keys.page_down,
+					self.key_config.keys().page_down,
 				) {
 					self.move_selection(ScrollType::PageDown);
-				} else if key_match(key, self.key_config.keys.page_up)
-				{
+				} else if key_match(
+					key,
+					self.key_config.keys().page_up,
+				) {
 					self.move_selection(ScrollType::PageUp);
 				} else if key_match(
 					key,
-					self.key_config.keys.move_right,
+					self.key_config.keys().move_right,
 				) && self.can_show_annotation()
 				{
 					self.show_annotation();
 				} else if key_match(
 					key,
-					self.key_config.keys.delete_tag,
+					self.key_config.keys().delete_tag,
 				) {
 					return self.selected_tag().map_or(
 						Ok(EventState::NotConsumed),
@@ -249,7 +465,36 @@ impl Component for TagListComponent {
 					);
 				} else if key_match(
 					key,
-					self.key_config.keys.select_tag,
+					self.key_config.keys().tag_sort,
+				) {
+					self.sort = self.sort.next();
+					try_or_popup!(
+						self,
+						"save tag sort error:",
+						self.sort.save(&self.repo.borrow())
+					);
+					self.update_tags()?;
+				} else if key_match(
+					key,
+					self.key_config.keys().tag_kind_filter,
+				) {
+					self.kind_filter = self.kind_filter.next();
+					self.table_state.get_mut().select(Some(0));
+					self.apply_filter();
+				} else if key_match(
+					key,
+					self.key_config.keys().log_tag_commit,
+				) {
+					self.queue.push(InternalEvent::TagRevision);
+				} else if key_match(
+					key,
+					self.key_config.keys().tag_delete_matching,
+				) {
+					self.queue
+						.push(InternalEvent::DeleteMatchingTags);
+				} else if key_match(
+					key,
+					self.key_config.keys().select_tag,
 				) {
 					return self.selected_tag().map_or(
 						Ok(EventState::NotConsumed),
@@ -262,10 +507,48 @@ impl Component for TagListComponent {
 							Ok(EventState::Consumed)
 						},
 					);
-				} else if key_match(key, self.key_config.keys.push)
+				} else if key_match(
+					key,
+					self.key_config.keys().tag_mark,
+				) {
+					self.toggle_mark();
+				} else if key_match(
+					key,
+					self.key_config.keys().compare_commits,
+				) {
+					self.compare();
+				} else if key_match(
+					key,
+					self.key_config.keys().tag_checkout,
+				) {
+					if let Some(name) = self
+						.selected_tag()
+						.map(|tag| tag.name.clone())
+					{
+						let autostash =
+							self.options.borrow().autostash;
+						try_or_popup!(
+							self,
+							"checkout error:",
+							self.checkout_tag(&name, autostash)
+						);
+					}
+				} else if key_match(key, self.key_config.keys().push)
 					&& self.has_remotes
 				{
 					self.queue.push(InternalEvent::PushTags);
+				} else if key_match(
+					key,
+					self.key_config.keys().tag_push,
+				) && self.has_remotes
+				{
+					self.open_tag_remote(TagRemoteAction::Push);
+				} else if key_match(
+					key,
+					self.key_config.keys().tag_delete_remote,
+				) && self.has_remotes
+				{
+					self.open_tag_remote(TagRemoteAction::Delete);
 				}
 			}
 
@@ -281,6 +564,9 @@ impl Component for TagListComponent {
 
 	fn hide(&mut self) {
 		self.visible = false;
+		self.marked = None;
+		self.filter.hide();
+		self.filter.clear();
 	}
 
 	fn show(&mut self) -> Result<()> {
@@ -297,19 +583,37 @@ impl TagListComponent {
 		sender: &Sender<AsyncGitNotification>,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
+		options: SharedOptions,
 	) -> Self {
+		let mut filter = TextInputComponent::new(
+			theme.clone(),
+			key_config.clone(),
+			"",
+			&strings::tag_filter_msg(),
+			false,
+		);
+		filter.embed();
+
 		Self {
 			theme,
 			queue: queue.clone(),
 			tags: None,
+			all_tags: Vec::new(),
+			details: None,
+			marked: None,
+			sort: TagSort::Date,
+			kind_filter: TagKindFilter::All,
+			filter,
 			visible: false,
 			has_remotes: false,
 			table_state: std::cell::Cell::new(TableState::default()),
 			current_height: std::cell::Cell::new(0),
+			scrollbar_area: std::cell::Cell::new(Rect::default()),
 			basic_credential: None,
 			missing_remote_tags: None,
 			async_remote_tags: AsyncSingleJob::new(sender.clone()),
 			key_config,
+			options,
 			repo,
 		}
 	}
@@ -317,6 +621,7 @@ impl TagListComponent {
 	///
 	pub fn open(&mut self) -> Result<()> {
 		self.table_state.get_mut().select(Some(0));
+		self.sort = TagSort::load(&self.repo.borrow());
 		self.show()?;
 
 		self.has_remotes =
@@ -364,7 +669,11 @@ impl TagListComponent {
 		} else if matches!(
 			ev,
 			AsyncNotification::Git(AsyncGitNotification::PushTags)
-		) {
+		) || (self.visible
+			&& matches!(
+				ev,
+				AsyncNotification::Git(AsyncGitNotification::Push)
+			)) {
 			self.update_missing_remote_tags();
 		}
 	}
@@ -376,13 +685,228 @@ impl TagListComponent {
 
 	/// fetch list of tags
 	pub fn update_tags(&mut self) -> Result<()> {
-		let tags = get_tags_with_metadata(&self.repo.borrow())?;
+		self.all_tags = get_tags_with_metadata(&self.repo.borrow())?;
 
-		self.tags = Some(tags);
+		self.sort_tags()?;
+		self.apply_filter();
+
+		Ok(())
+	}
+
+	fn sort_tags(&mut self) -> Result<()> {
+		match self.sort {
+			TagSort::Date => {
+				self.all_tags.sort_by_key(|tag| Reverse(tag.created));
+			}
+			TagSort::Version => {
+				let suffixes = get_config_strings(
+					&self.repo.borrow(),
+					"versionsort.suffix",
+				)?;
+				self.all_tags.sort_by(|a, b| {
+					version_cmp(&b.name, &a.name, &suffixes)
+				});
+			}
+		}
 
 		Ok(())
 	}
 
+	/// shows tags whose name or annotation contains the filter text,
+	/// ignoring case
+	fn apply_filter(&mut self) {
+		let query = self.filter.get_text().to_lowercase();
+
+		let tags = self
+			.all_tags
+			.iter()
+			.filter(|tag| self.kind_filter.matches(tag.kind))
+			.filter(|tag| {
+				query.is_empty()
+					|| tag.name.to_lowercase().contains(&query)
+					|| tag.annotation.as_ref().map_or(false, |a| {
+						a.to_lowercase().contains(&query)
+					})
+			})
+			.cloned()
+			.collect::<Vec<_>>();
+
+		let selection = self.table_state.get_mut().selected();
+		self.table_state.get_mut().select(
+			selection.map(|s| s.min(tags.len().saturating_sub(1))),
+		);
+
+		self.tags = Some(tags);
+
+		self.update_details();
+	}
+
+	/// loads tagger and full annotation of the selected tag
+	fn update_details(&mut self) {
+		self.details = self.selected_tag().and_then(|tag| {
+			get_tag_details(&self.repo.borrow(), &tag.name)
+				.map_err(|e| log::error!("tag details: {}", e))
+				.ok()
+		});
+	}
+
+	fn draw_details<B: Backend>(&self, f: &mut Frame<B>, area: Rect) {
+		let text =
+			self.details.as_ref().map_or_else(Vec::new, |details| {
+				self.details_text(details)
+			});
+
+		f.render_widget(
+			Paragraph::new(text)
+				.block(
+					Block::default()
+						.borders(Borders::ALL)
+						.title(Span::styled(
+							strings::tag_details_title(),
+							self.theme.title(false),
+						))
+						.border_style(self.theme.block(false)),
+				)
+				.wrap(Wrap { trim: false }),
+			area,
+		);
+	}
+
+	fn details_text(&self, details: &TagDetails) -> Vec<Spans> {
+		let label = |s: String| {
+			Span::styled(Cow::from(s), self.theme.text(false, false))
+		};
+
+		let mut res = vec![];
+
+		if let Some(tagger) = &details.tagger {
+			res.push(Spans::from(vec![
+				label(strings::tag_details_tagger()),
+				Span::styled(
+					Cow::from(format!(
+						"{} <{}>",
+						tagger.name, tagger.email
+					)),
+					self.theme.commit_author(false),
+				),
+			]));
+			res.push(Spans::from(vec![
+				label(strings::commit::details_date()),
+				Span::styled(
+					Cow::from(utils::time_to_string(
+						tagger.time,
+						false,
+					)),
+					self.theme.commit_time(false),
+				),
+			]));
+		} else {
+			res.push(Spans::from(label(
+				strings::tag_details_lightweight(),
+			)));
+		}
+
+		let subject = self
+			.selected_tag()
+			.map(|tag| tag.message.clone())
+			.unwrap_or_default();
+		res.push(Spans::from(vec![
+			label(strings::tag_details_target()),
+			Span::styled(
+				Cow::from(details.target.get_short_string()),
+				self.theme.commit_hash(false),
+			),
+			Span::styled(
+				Cow::from(format!(" {}", subject)),
+				self.theme.text(true, false),
+			),
+		]));
+
+		if let Some(annotation) = &details.annotation {
+			res.push(Spans::default());
+			res.extend(annotation.lines().map(|line| {
+				Spans::from(Span::styled(
+					Cow::from(line.to_string()),
+					self.theme.text(true, false),
+				))
+			}));
+		}
+
+		res
+	}
+
+	fn title(&self) -> String {
+		let query = self.filter.get_text();
+		let mut title = format!(
+			"{} (sort: {}",
+			strings::title_tags(),
+			self.sort.name()
+		);
+		if self.kind_filter != TagKindFilter::All {
+			title.push_str(", kind: ");
+			title.push_str(self.kind_filter.name());
+		}
+		title.push(')');
+
+		if query.is_empty() {
+			title
+		} else {
+			format!("{} [{}]", title, query)
+		}
+	}
+
+	/// draws the filter line on top while it is edited, returns
+	/// the area left for the table
+	fn draw_filter<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		area: Rect,
+	) -> Result<Rect> {
+		if !self.is_filtering() {
+			return Ok(area);
+		}
+
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.constraints(
+				[Constraint::Length(1), Constraint::Min(1)].as_ref(),
+			)
+			.split(area);
+
+		self.filter.draw(f, chunks[0])?;
+
+		Ok(chunks[1])
+	}
+
+	/// whether the filter text is being edited
+	fn is_filtering(&self) -> bool {
+		self.filter.is_visible()
+	}
+
+	fn filter_event(&mut self, ev: &Event) -> Result<EventState> {
+		if self.filter.event(ev)?.is_consumed() {
+			if !self.filter.is_visible() {
+				// closed by exit key
+				self.filter.clear();
+			}
+			self.table_state.get_mut().select(Some(0));
+			self.apply_filter();
+			return Ok(EventState::Consumed);
+		}
+
+		if let Event::Key(k) = ev {
+			if key_match(k, self.key_config.keys().enter) {
+				self.filter.hide();
+			} else if key_match(k, self.key_config.keys().move_up) {
+				self.move_selection(ScrollType::Up);
+			} else if key_match(k, self.key_config.keys().move_down) {
+				self.move_selection(ScrollType::Down);
+			}
+		}
+
+		Ok(EventState::Consumed)
+	}
+
 	pub fn update_missing_remote_tags(&mut self) {
 		if self.has_remotes {
 			self.async_remote_tags.spawn(AsyncRemoteTagsJob::new(
@@ -393,12 +917,18 @@ impl TagListComponent {
 	}
 
 	///
-	fn move_selection(&mut self, scroll_type: ScrollType) -> bool {
-		let mut table_state = self.table_state.take();
+	fn max_selection(&self) -> usize {
+		self.tags
+			.as_ref()
+			.map_or(0, |tags| tags.len().saturating_sub(1))
+	}
 
+	fn move_selection(&mut self, scroll_type: ScrollType) -> bool {
+		let table_state = self.table_state.take();
 		let old_selection = table_state.selected().unwrap_or(0);
-		let max_selection =
-			self.tags.as_ref().map_or(0, |tags| tags.len() - 1);
+		self.table_state.set(table_state);
+
+		let max_selection = self.max_selection();
 
 		let new_selection = match scroll_type {
 			ScrollType::Up => old_selection.saturating_sub(1),
@@ -417,20 +947,120 @@ impl TagListComponent {
 				.min(max_selection),
 		};
 
-		let needs_update = new_selection != old_selection;
+		self.set_selection(new_selection)
+	}
+
+	fn set_selection(&mut self, selection: usize) -> bool {
+		let mut table_state = self.table_state.take();
+
+		let selection = selection.min(self.max_selection());
+		let needs_update = table_state.selected() != Some(selection);
 
-		table_state.select(Some(new_selection));
+		table_state.select(Some(selection));
 		self.table_state.set(table_state);
 
+		if needs_update {
+			self.update_details();
+		}
+
 		needs_update
 	}
 
+	fn toggle_mark(&mut self) {
+		if let Some(name) =
+			self.selected_tag().map(|t| t.name.clone())
+		{
+			if self.marked.as_ref() == Some(&name) {
+				self.marked = None;
+			} else {
+				self.marked = Some(name);
+			}
+		}
+	}
+
+	/// compares the selected tag with the marked one,
+	/// or with `HEAD` if there is none
+	fn compare(&mut self) {
+		if let Some(name) =
+			self.selected_tag().map(|t| t.name.clone())
+		{
+			let left = self
+				.marked
+				.take()
+				.filter(|marked| marked != &name)
+				.unwrap_or_else(|| String::from("HEAD"));
+
+			self.hide();
+			self.queue.push(InternalEvent::OpenPopup(
+				StackablePopupOpen::CompareBranches(
+					CompareBranchesOpen { left, right: name },
+				),
+			));
+		}
+	}
+
+	/// checks out tag `name` into a detached `HEAD`
+	pub fn checkout_tag(
+		&mut self,
+		name: &str,
+		autostash: bool,
+	) -> Result<()> {
+		let commit_id =
+			match self.all_tags.iter().find(|t| t.name == name) {
+				Some(tag) => tag.commit_id,
+				None => return Ok(()),
+			};
+
+		self.hide();
+
+		let done = run_with_autostash(
+			&self.repo.borrow(),
+			&self.queue,
+			autostash,
+			AutostashOp::CheckoutTag(name.to_string()),
+			|| sync::checkout_commit(&self.repo.borrow(), commit_id),
+		)?;
+
+		if done.is_some() {
+			self.queue.push(InternalEvent::Update(NeedsUpdate::ALL));
+		}
+
+		Ok(())
+	}
+
+	fn open_tag_remote(&self, action: TagRemoteAction) {
+		if let Some(tag) = self.selected_tag() {
+			self.queue.push(InternalEvent::OpenTagRemote(
+				tag.name.clone(),
+				action,
+			));
+		}
+	}
+
 	fn show_annotation(&self) {
 		if let Some(tag) = self.selected_tag() {
 			if let Some(annotation) = &tag.annotation {
-				self.queue.push(InternalEvent::ShowInfoMsg(
-					annotation.clone(),
-				));
+				let signature = match sync::verify_tag(
+					&self.repo.borrow(),
+					&tag.name,
+				) {
+					Ok(Some(Verification::Good(signer))) => {
+						strings::tag_signature_good(&signer)
+					}
+					Ok(Some(Verification::Bad(reason))) => {
+						strings::tag_signature_bad(&reason)
+					}
+					Ok(None) => strings::tag_signature_unsigned(),
+					Err(e) => {
+						strings::tag_signature_bad(&e.to_string())
+					}
+				};
+
+				self.queue.push(InternalEvent::ShowInfoMsg(format!(
+					"{}\n\n{}",
+					annotation.trim_end(),
+					signature
+				)));
 			}
 		}
 	}
@@ -453,6 +1083,9 @@ impl TagListComponent {
 		const UPSTREAM_SYMBOL: &str = "\u{2191}";
 		const ATTACHEMENT_SYMBOL: &str = "@";
 		const EMPTY_SYMBOL: &str = " ";
+		const LIGHTWEIGHT_SYMBOL: &str = "L";
+		const ANNOTATED_SYMBOL: &str = "A";
+		const SIGNED_SYMBOL: &str = "S";
 
 		let is_tag_missing_on_remote = self
 			.missing_remote_tags
@@ -475,11 +1108,36 @@ impl TagListComponent {
 			EMPTY_SYMBOL
 		};
 
+		let (kind_str, kind_style) = match tag.kind {
+			TagKind::Lightweight => {
+				(LIGHTWEIGHT_SYMBOL, self.theme.text_danger())
+			}
+			TagKind::Annotated => {
+				(ANNOTATED_SYMBOL, self.theme.text(true, false))
+			}
+			TagKind::Signed => {
+				(SIGNED_SYMBOL, self.theme.commit_hash(false))
+			}
+		};
+
+		let marked_str = if self.marked.as_ref() == Some(&tag.name) {
+			symbol::CHECKMARK
+		} else {
+			EMPTY_SYMBOL
+		};
+
 		let cells: Vec<Cell> = vec![
+			Cell::from(marked_str)
+				.style(self.theme.log_marker(false)),
 			Cell::from(has_remote_str)
 				.style(self.theme.commit_author(false)),
-			Cell::from(tag.name.clone())
-				.style(self.theme.text(true, false)),
+			Cell::from(kind_str).style(kind_style),
+			Cell::from(if self.theme.icons() {
+				format!("{} {}", icons::TAG, tag.name)
+			} else {
+				tag.name.clone()
+			})
+			.style(self.theme.text(true, false)),
 			Cell::from(utils::time_to_string(tag.time, true))
 				.style(self.theme.commit_time(false)),
 			Cell::from(tag.author.clone())
@@ -0,0 +1,216 @@
+use super::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState, ScrollType,
+};
+use crate::{
+	keys::{key_match, SharedKeyConfig},
+	queue::{InternalEvent, Queue},
+	strings, try_or_popup,
+	ui::{
+		self,
+		style::{SharedTheme, ThemePreset},
+	},
+};
+use anyhow::Result;
+use crossterm::event::Event;
+use tui::{
+	backend::Backend,
+	layout::{Margin, Rect},
+	text::{Span, Spans},
+	widgets::{Block, BorderType, Borders, Clear},
+	Frame,
+};
+
+/// switches between the theme file and the bundled themes
+pub struct ThemePickerPopup {
+	queue: Queue,
+	visible: bool,
+	selection: usize,
+	theme: SharedTheme,
+	key_config: SharedKeyConfig,
+}
+
+impl ThemePickerPopup {
+	///
+	pub fn new(
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			queue: queue.clone(),
+			visible: false,
+			selection: 0,
+			theme,
+			key_config,
+		}
+	}
+
+	/// preselects the theme in use
+	pub fn open(&mut self) -> Result<()> {
+		let current = ThemePreset::load();
+
+		self.selection = ThemePreset::ALL
+			.iter()
+			.position(|preset| *preset == current)
+			.unwrap_or_default();
+		self.show()?;
+
+		Ok(())
+	}
+
+	fn move_selection(&mut self, move_type: ScrollType) {
+		let new_selection = match move_type {
+			ScrollType::Up => self.selection.saturating_sub(1),
+			ScrollType::Down => self.selection.saturating_add(1),
+			_ => self.selection,
+		};
+
+		self.selection = new_selection
+			.clamp(0, ThemePreset::ALL.len().saturating_sub(1));
+	}
+
+	fn confirm(&mut self) -> Result<()> {
+		self.hide();
+
+		let preset = ThemePreset::ALL[self.selection];
+		if preset != ThemePreset::load() {
+			preset.save()?;
+			self.queue.push(InternalEvent::ReloadConfig);
+		}
+
+		Ok(())
+	}
+}
+
+impl DrawableComponent for ThemePickerPopup {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		area: Rect,
+	) -> Result<()> {
+		if self.is_visible() {
+			const SIZE: (u16, u16) = (55, 7);
+
+			let area =
+				ui::centered_rect_absolute(SIZE.0, SIZE.1, area);
+
+			f.render_widget(Clear, area);
+			f.render_widget(
+				Block::default()
+					.borders(Borders::all())
+					.border_style(self.theme.popup_border())
+					.border_type(BorderType::Thick)
+					.title(Span::styled(
+						strings::POPUP_TITLE_THEME_PICKER,
+						self.theme.title(true),
+					)),
+				area,
+			);
+
+			let area = area.inner(&Margin {
+				horizontal: 1,
+				vertical: 1,
+			});
+
+			let items = ThemePreset::ALL.iter().enumerate().map(
+				|(idx, preset)| {
+					let selected = idx == self.selection;
+					Spans::from(vec![
+						Span::styled(
+							format!("{:<15}", preset.name()),
+							self.theme.text(true, selected),
+						),
+						Span::styled(
+							preset.description(),
+							self.theme.text(false, selected),
+						),
+					])
+				},
+			);
+
+			ui::draw_list_block(f, area, Block::default(), items);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for ThemePickerPopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			out.push(
+				CommandInfo::new(
+					strings::commands::close_popup(&self.key_config),
+					true,
+					true,
+				)
+				.order(1),
+			);
+
+			out.push(CommandInfo::new(
+				strings::commands::theme_picker_confirm(
+					&self.key_config,
+				),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: &Event) -> Result<EventState> {
+		if self.is_visible() {
+			if let Event::Key(key) = event {
+				if key_match(key, self.key_config.keys().exit_popup) {
+					self.hide();
+				} else if key_match(key, self.key_config.keys().enter)
+				{
+					try_or_popup!(
+						self,
+						"theme switch error:",
+						self.confirm()
+					);
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_down,
+				) {
+					self.move_selection(ScrollType::Down);
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_up,
+				) {
+					self.move_selection(ScrollType::Up);
+				}
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+		Ok(())
+	}
+}
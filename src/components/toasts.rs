@@ -0,0 +1,270 @@
+use super::{
+	utils::scroll_vertical::VerticalScroll, visibility_blocking,
+	CommandBlocking, CommandInfo, Component, DrawableComponent,
+	EventState, ScrollType, SharedOptions,
+};
+use crate::{
+	keys::{key_match, SharedKeyConfig},
+	strings,
+	ui::{self, style::SharedTheme},
+};
+use anyhow::Result;
+use chrono::{DateTime, Local};
+use crossterm::event::Event;
+use std::{collections::VecDeque, time::Instant};
+use tui::{
+	backend::Backend,
+	layout::{Margin, Rect},
+	text::{Span, Spans},
+	widgets::{Block, BorderType, Borders, Clear, Paragraph},
+	Frame,
+};
+use unicode_truncate::UnicodeTruncateStr;
+use unicode_width::UnicodeWidthStr;
+
+/// notifications on screen at the same time
+const MAX_ACTIVE: usize = 3;
+/// notifications kept for the history popup
+const MAX_HISTORY: usize = 100;
+const MAX_TOAST_WIDTH: u16 = 50;
+
+/// short lived notifications about finished operations drawn in the
+/// bottom right corner, all of them can be looked at again in a popup
+pub struct ToastsComponent {
+	/// messages on screen with the time they go away
+	active: VecDeque<(Instant, String)>,
+	/// newest first
+	history: VecDeque<(DateTime<Local>, String)>,
+	visible: bool,
+	scroll: VerticalScroll,
+	options: SharedOptions,
+	theme: SharedTheme,
+	key_config: SharedKeyConfig,
+}
+
+impl ToastsComponent {
+	///
+	pub fn new(
+		options: SharedOptions,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			active: VecDeque::new(),
+			history: VecDeque::new(),
+			visible: false,
+			scroll: VerticalScroll::new(),
+			options,
+			theme,
+			key_config,
+		}
+	}
+
+	/// only adds to the history while notifications are turned off
+	pub fn push(&mut self, msg: String) {
+		if let Some(timeout) =
+			self.options.borrow().toast_timeout.duration()
+		{
+			if self.active.len() >= MAX_ACTIVE {
+				self.active.pop_front();
+			}
+			self.active
+				.push_back((Instant::now() + timeout, msg.clone()));
+		}
+
+		self.history.push_front((Local::now(), msg));
+		self.history.truncate(MAX_HISTORY);
+	}
+
+	/// drops notifications that timed out, returns whether any did
+	pub fn expire(&mut self) -> bool {
+		let now = Instant::now();
+		let count = self.active.len();
+		self.active.retain(|(until, _)| *until > now);
+
+		count != self.active.len()
+	}
+
+	/// stacks the notifications upwards from the bottom right of `r`
+	pub fn draw_toasts<B: Backend>(&self, f: &mut Frame<B>, r: Rect) {
+		let mut bottom = r.bottom();
+
+		for (_, msg) in self.active.iter().rev() {
+			let width = u16::try_from(msg.width())
+				.unwrap_or(u16::MAX)
+				.saturating_add(4)
+				.min(MAX_TOAST_WIDTH)
+				.min(r.width);
+
+			if width < 5 || bottom < r.y + 3 {
+				break;
+			}
+
+			let area = Rect::new(
+				r.right().saturating_sub(width + 1),
+				bottom - 3,
+				width,
+				3,
+			);
+			bottom -= 3;
+
+			let (msg, _) =
+				msg.unicode_truncate(usize::from(width - 4));
+
+			f.render_widget(Clear, area);
+			f.render_widget(
+				Paragraph::new(Spans::from(Span::styled(
+					msg,
+					self.theme.text(true, false),
+				)))
+				.block(
+					Block::default()
+						.borders(Borders::ALL)
+						.border_type(BorderType::Rounded)
+						.border_style(self.theme.popup_border()),
+				),
+				area,
+			);
+		}
+	}
+
+	fn get_text(&self, height: usize) -> Vec<Spans> {
+		if self.history.is_empty() {
+			return vec![Spans::from(Span::styled(
+				strings::toast_history_empty(),
+				self.theme.text(false, false),
+			))];
+		}
+
+		self.history
+			.iter()
+			.skip(self.scroll.get_top())
+			.take(height)
+			.map(|(time, msg)| {
+				Spans::from(vec![
+					Span::styled(
+						time.format("%H:%M:%S ").to_string(),
+						self.theme.commit_time(false),
+					),
+					Span::styled(
+						msg.as_str(),
+						self.theme.text(true, false),
+					),
+				])
+			})
+			.collect()
+	}
+}
+
+impl DrawableComponent for ToastsComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		area: Rect,
+	) -> Result<()> {
+		if self.is_visible() {
+			let area = ui::centered_rect(70, 60, area);
+			let inner = area.inner(&Margin {
+				horizontal: 1,
+				vertical: 1,
+			});
+			let height = usize::from(inner.height);
+
+			self.scroll
+				.update_no_selection(self.history.len(), height);
+
+			f.render_widget(Clear, area);
+			f.render_widget(
+				Paragraph::new(self.get_text(height)).block(
+					Block::default()
+						.title(Span::styled(
+							strings::POPUP_TITLE_TOAST_HISTORY,
+							self.theme.title(true),
+						))
+						.borders(Borders::ALL)
+						.border_style(self.theme.popup_border()),
+				),
+				area,
+			);
+
+			self.scroll.draw(f, area, &self.theme);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for ToastsComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			out.push(
+				CommandInfo::new(
+					strings::commands::close_popup(&self.key_config),
+					true,
+					true,
+				)
+				.order(1),
+			);
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				self.history.len() > 1,
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: &Event) -> Result<EventState> {
+		if self.is_visible() {
+			if let Event::Mouse(mouse) = event {
+				self.scroll.click(*mouse);
+			} else if let Event::Key(key) = event {
+				if key_match(key, self.key_config.keys().exit_popup)
+					|| key_match(
+						key,
+						self.key_config.keys().toast_history,
+					) {
+					self.hide();
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_down,
+				) {
+					self.scroll.move_top(ScrollType::Down);
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_up,
+				) {
+					self.scroll.move_top(ScrollType::Up);
+				} else if key_match(key, self.key_config.keys().home)
+				{
+					self.scroll.move_top(ScrollType::Home);
+				} else if key_match(key, self.key_config.keys().end) {
+					self.scroll.move_top(ScrollType::End);
+				}
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.scroll.reset();
+		self.visible = true;
+		Ok(())
+	}
+}
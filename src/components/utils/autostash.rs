@@ -0,0 +1,64 @@
+use crate::{
+	queue::{Action, AutostashOp, InternalEvent, Queue},
+	strings,
+};
+use anyhow::Result;
+use asyncgit::sync::{self, AutostashResult, RepoPath};
+
+/// runs `op` which needs a clean working tree.
+///
+/// with `autostash` set local changes are stashed around `op`.
+/// otherwise if `op` refuses to overwrite local changes we offer to
+/// retry with autostash and return `None`.
+pub fn run_with_autostash<T, F>(
+	repo_path: &RepoPath,
+	queue: &Queue,
+	autostash: bool,
+	retry: AutostashOp,
+	op: F,
+) -> Result<Option<T>>
+where
+	F: FnOnce() -> asyncgit::Result<T>,
+{
+	if autostash {
+		let (res, stash) = sync::autostash(repo_path, op)?;
+
+		match stash {
+			AutostashResult::Kept { stash, conflicts } => {
+				queue.push(InternalEvent::ShowErrorMsg(
+					strings::autostash_kept_msg(
+						&stash.get_short_string(),
+						&conflicts,
+					),
+				));
+			}
+			AutostashResult::Pending { stash } => {
+				queue.push(InternalEvent::ShowInfoMsg(
+					strings::autostash_pending_msg(
+						&stash.get_short_string(),
+					),
+				));
+			}
+			AutostashResult::Clean | AutostashResult::Reapplied => (),
+		}
+
+		return Ok(Some(res));
+	}
+
+	match op() {
+		Ok(res) => Ok(Some(res)),
+		Err(e) => {
+			if sync::blocked_by_local_changes(&e)
+				&& sync::has_local_changes(repo_path)?
+			{
+				log::info!("offer autostash after: {}", e);
+				queue.push(InternalEvent::ConfirmAction(
+					Action::Autostash(retry),
+				));
+				Ok(None)
+			} else {
+				Err(e.into())
+			}
+		}
+	}
+}
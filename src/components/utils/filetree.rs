@@ -81,6 +81,18 @@ impl FileTreeItem {
 		}
 	}
 
+	/// file entry of a flat list, showing its full path
+	fn new_flat_file(item: &StatusItem) -> Self {
+		Self {
+			info: TreeItemInfo::new(
+				0,
+				item.path.clone(),
+				item.path.clone(),
+			),
+			kind: FileTreeItemKind::File(item.clone()),
+		}
+	}
+
 	fn new_path(
 		path: &Path,
 		path_string: String,
@@ -167,6 +179,17 @@ impl FileTreeItems {
 		})
 	}
 
+	/// list of files without folders, keeping the order of `list`
+	pub(crate) fn new_flat(list: &[StatusItem]) -> Self {
+		Self {
+			items: list
+				.iter()
+				.map(FileTreeItem::new_flat_file)
+				.collect(),
+			file_count: list.len(),
+		}
+	}
+
 	///
 	pub(crate) const fn items(&self) -> &Vec<FileTreeItem> {
 		&self.items
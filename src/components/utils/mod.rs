@@ -1,6 +1,7 @@
 use chrono::{DateTime, Local, NaiveDateTime, Utc};
 use unicode_width::UnicodeWidthStr;
 
+pub mod autostash;
 #[cfg(feature = "ghemoji")]
 pub mod emoji;
 pub mod filetree;
@@ -37,6 +38,53 @@ pub fn time_to_string(secs: i64, short: bool) -> String {
 	.to_string()
 }
 
+/// compact age of unix time `secs` at unix time `now`, like `3d ago`
+pub fn time_to_age(secs: i64, now: i64) -> String {
+	const MINUTE: i64 = 60;
+	const HOUR: i64 = 60 * MINUTE;
+	const DAY: i64 = 24 * HOUR;
+	const WEEK: i64 = 7 * DAY;
+	const MONTH: i64 = 30 * DAY;
+	const YEAR: i64 = 365 * DAY;
+
+	let delta = now.saturating_sub(secs);
+
+	let (value, unit) = if delta < MINUTE {
+		return String::from("<1m ago");
+	} else if delta < HOUR {
+		(delta / MINUTE, "m")
+	} else if delta < DAY {
+		(delta / HOUR, "h")
+	} else if delta < WEEK {
+		(delta / DAY, "d")
+	} else if delta < MONTH {
+		(delta / WEEK, "w")
+	} else if delta < YEAR {
+		(delta / MONTH, "mo")
+	} else {
+		(delta / YEAR, "y")
+	};
+
+	format!("{}{} ago", value, unit)
+}
+
+/// `err` followed by what caused it, one per line, leaving out causes
+/// whose text the error before already contains
+pub fn error_chain(err: &dyn std::error::Error) -> String {
+	let mut lines = vec![err.to_string()];
+	let mut source = err.source();
+
+	while let Some(cause) = source {
+		let text = cause.to_string();
+		if !lines.last().map_or(false, |last| last.contains(&text)) {
+			lines.push(format!("caused by: {}", text));
+		}
+		source = cause.source();
+	}
+
+	lines.join("\n")
+}
+
 #[inline]
 pub fn string_width_align(s: &str, width: usize) -> String {
 	static POSTFIX: &str = "..";
@@ -59,3 +107,35 @@ pub fn string_width_align(s: &str, width: usize) -> String {
 fn find_truncate_point(s: &str, chars: usize) -> usize {
 	s.chars().take(chars).map(char::len_utf8).sum()
 }
+
+#[cfg(test)]
+mod tests {
+	use super::{error_chain, time_to_age};
+	use anyhow::anyhow;
+
+	#[test]
+	fn test_time_to_age() {
+		let now = 1_000_000_000;
+
+		assert_eq!(time_to_age(now, now), "<1m ago");
+		assert_eq!(time_to_age(now + 10, now), "<1m ago");
+		assert_eq!(time_to_age(now - 150, now), "2m ago");
+		assert_eq!(time_to_age(now - 3 * 3600, now), "3h ago");
+		assert_eq!(time_to_age(now - 2 * 86400, now), "2d ago");
+		assert_eq!(time_to_age(now - 15 * 86400, now), "2w ago");
+		assert_eq!(time_to_age(now - 100 * 86400, now), "3mo ago");
+		assert_eq!(time_to_age(now - 800 * 86400, now), "2y ago");
+	}
+
+	#[test]
+	fn test_error_chain() {
+		let err = anyhow!("connection refused")
+			.context("fetch origin failed: connection refused")
+			.context("fetch failed");
+
+		assert_eq!(
+			error_chain(err.as_ref()),
+			"fetch failed\ncaused by: fetch origin failed: connection refused"
+		);
+	}
+}
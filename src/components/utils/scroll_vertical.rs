@@ -1,15 +1,18 @@
 use std::cell::Cell;
 
+use crossterm::event::MouseEvent;
 use tui::{backend::Backend, layout::Rect, Frame};
 
 use crate::{
 	components::ScrollType,
-	ui::{draw_scrollbar, style::SharedTheme},
+	ui::{draw_scrollbar, scrollbar_position, style::SharedTheme},
 };
 
 pub struct VerticalScroll {
 	top: Cell<usize>,
 	max_top: Cell<usize>,
+	/// where the scrollbar was drawn last
+	area: Cell<Rect>,
 }
 
 impl VerticalScroll {
@@ -17,6 +20,12 @@ impl VerticalScroll {
 		Self {
 			top: Cell::new(0),
 			max_top: Cell::new(0),
+			area: Cell::new(Rect {
+				x: 0,
+				y: 0,
+				width: 0,
+				height: 0,
+			}),
 		}
 	}
 
@@ -24,6 +33,10 @@ impl VerticalScroll {
 		self.top.get()
 	}
 
+	pub fn set_top(&self, top: usize) {
+		self.top.set(top);
+	}
+
 	pub fn reset(&self) {
 		self.top.set(0);
 	}
@@ -51,6 +64,19 @@ impl VerticalScroll {
 		true
 	}
 
+	/// scrolls to the position clicked or dragged to on the
+	/// scrollbar, returns the new top if the mouse hit the bar
+	pub fn click(&self, mouse: MouseEvent) -> Option<usize> {
+		let top = scrollbar_position(
+			self.area.get(),
+			self.max_top.get(),
+			mouse,
+		)?;
+		self.top.set(top);
+
+		Some(top)
+	}
+
 	pub fn update(
 		&self,
 		selection: usize,
@@ -89,6 +115,7 @@ impl VerticalScroll {
 		r: Rect,
 		theme: &SharedTheme,
 	) {
+		self.area.set(r);
 		draw_scrollbar(
 			f,
 			r,
@@ -43,13 +43,27 @@ impl SelectionChange {
 impl StatusTree {
 	/// update tree with a new list, try to retain selection and collapse states
 	pub fn update(&mut self, list: &[StatusItem]) -> Result<()> {
+		self.update_list(list, false)
+	}
+
+	/// like `update` but `flat` shows the files in the order of `list`
+	/// instead of grouped in folders
+	pub fn update_list(
+		&mut self,
+		list: &[StatusItem],
+		flat: bool,
+	) -> Result<()> {
 		let last_collapsed = self.all_collapsed();
 
 		let last_selection =
 			self.selected_item().map(|e| e.info.full_path);
 		let last_selection_index = self.selection.unwrap_or(0);
 
-		self.tree = FileTreeItems::new(list, &last_collapsed)?;
+		self.tree = if flat {
+			FileTreeItems::new_flat(list)
+		} else {
+			FileTreeItems::new(list, &last_collapsed)?
+		};
 		self.selection = last_selection.as_ref().map_or_else(
 			|| self.tree.items().first().map(|_| 0),
 			|last_selection| {
@@ -482,6 +496,29 @@ mod tests {
 		assert_eq!(res.selection, Some(1));
 	}
 
+	#[test]
+	fn test_flat_keeps_order() {
+		let mut res = StatusTree::default();
+		res.update_list(&string_vec_to_status(&["b/c", "a"]), true)
+			.unwrap();
+
+		assert_eq!(
+			res.tree
+				.items()
+				.iter()
+				.map(|e| e.info.full_path.as_str())
+				.collect::<Vec<_>>(),
+			vec!["b/c", "a"]
+		);
+		assert_eq!(get_visibles(&res), vec![true, true]);
+
+		res.selection = Some(1);
+		res.update_list(&string_vec_to_status(&["a", "b/c"]), true)
+			.unwrap();
+
+		assert_eq!(res.selection, Some(0));
+	}
+
 	#[test]
 	fn test_keep_selected_index() {
 		let mut res = StatusTree::default();
@@ -0,0 +1,434 @@
+use super::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState, ScrollType,
+};
+use crate::{
+	keys::{key_match, SharedKeyConfig},
+	queue::{Action, InternalEvent, NeedsUpdate, Queue},
+	strings, try_or_popup,
+	ui::{self, style::SharedTheme},
+};
+use anyhow::Result;
+use asyncgit::sync::{self, RepoPathRef, WorktreeInfo};
+use crossterm::event::Event;
+use tui::{
+	backend::Backend,
+	layout::{Margin, Rect},
+	text::{Span, Spans},
+	widgets::{Block, BorderType, Borders, Clear},
+	Frame,
+};
+use unicode_truncate::UnicodeTruncateStr;
+
+const STATE_WIDTH: usize = 7;
+
+/// lists the worktrees of the repo to add, remove, prune or switch to them
+pub struct WorktreesPopup {
+	repo: RepoPathRef,
+	queue: Queue,
+	visible: bool,
+	worktrees: Vec<WorktreeInfo>,
+	/// branches not checked out anywhere while picking one to add
+	free_branches: Option<Vec<String>>,
+	selection: usize,
+	theme: SharedTheme,
+	key_config: SharedKeyConfig,
+}
+
+impl WorktreesPopup {
+	///
+	pub fn new(
+		repo: RepoPathRef,
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			repo,
+			queue: queue.clone(),
+			visible: false,
+			worktrees: Vec::new(),
+			free_branches: None,
+			selection: 0,
+			theme,
+			key_config,
+		}
+	}
+
+	///
+	pub fn open(&mut self) -> Result<()> {
+		self.free_branches = None;
+		self.selection = 0;
+		self.update_worktrees()?;
+		self.show()?;
+
+		Ok(())
+	}
+
+	///
+	pub fn update_worktrees(&mut self) -> Result<()> {
+		self.worktrees = sync::get_worktrees(&self.repo.borrow())?;
+		self.move_selection(ScrollType::Home);
+
+		Ok(())
+	}
+
+	fn len(&self) -> usize {
+		self.free_branches
+			.as_ref()
+			.map_or(self.worktrees.len(), Vec::len)
+	}
+
+	fn selected_worktree(&self) -> Option<&WorktreeInfo> {
+		if self.free_branches.is_some() {
+			return None;
+		}
+
+		self.worktrees.get(self.selection)
+	}
+
+	fn move_selection(&mut self, move_type: ScrollType) {
+		let new_selection = match move_type {
+			ScrollType::Up => self.selection.saturating_sub(1),
+			ScrollType::Down => self.selection.saturating_add(1),
+			_ => self.selection,
+		};
+
+		self.selection =
+			new_selection.clamp(0, self.len().saturating_sub(1));
+	}
+
+	fn start_picking_branch(&mut self) -> Result<()> {
+		let branches =
+			sync::get_branches_info(&self.repo.borrow(), true)?
+				.into_iter()
+				.map(|branch| branch.name)
+				.filter(|name| {
+					!self
+						.worktrees
+						.iter()
+						.any(|wt| wt.branch.as_ref() == Some(name))
+				})
+				.collect::<Vec<_>>();
+
+		if branches.is_empty() {
+			self.queue.push(InternalEvent::ShowInfoMsg(
+				strings::worktree_no_free_branch(),
+			));
+		} else {
+			self.free_branches = Some(branches);
+			self.selection = 0;
+		}
+
+		Ok(())
+	}
+
+	fn stop_picking_branch(&mut self) {
+		self.free_branches = None;
+		self.selection = 0;
+	}
+
+	fn confirm(&mut self) {
+		if let Some(branches) = &self.free_branches {
+			if let Some(branch) = branches.get(self.selection) {
+				self.queue
+					.push(InternalEvent::AddWorktree(branch.clone()));
+				self.hide();
+			}
+		} else if let Some(worktree) = self.selected_worktree() {
+			if !worktree.is_current && !worktree.is_stale {
+				self.queue.push(InternalEvent::OpenRepo(
+					worktree.repo_path(),
+				));
+				self.hide();
+			}
+		}
+	}
+
+	fn remove(&self) {
+		if let Some(name) = self
+			.selected_worktree()
+			.filter(|wt| !wt.is_current)
+			.and_then(|wt| wt.name.clone())
+		{
+			self.queue.push(InternalEvent::ConfirmAction(
+				Action::RemoveWorktree(name),
+			));
+		}
+	}
+
+	fn prune(&mut self) -> Result<()> {
+		let count = sync::prune_worktrees(&self.repo.borrow())?;
+		self.update_worktrees()?;
+
+		self.queue.push(InternalEvent::ShowInfoMsg(
+			strings::worktrees_pruned(count),
+		));
+		self.queue.push(InternalEvent::Update(NeedsUpdate::ALL));
+
+		Ok(())
+	}
+
+	fn get_items(&self, width: usize) -> Vec<Spans> {
+		if let Some(branches) = &self.free_branches {
+			return branches
+				.iter()
+				.enumerate()
+				.map(|(idx, name)| {
+					Spans::from(Span::styled(
+						name.clone(),
+						self.theme.text(true, idx == self.selection),
+					))
+				})
+				.collect();
+		}
+
+		let branch_width = (width / 3).max(10);
+		let path_width = width
+			.saturating_sub(branch_width + STATE_WIDTH + 4)
+			.max(10);
+
+		self.worktrees
+			.iter()
+			.enumerate()
+			.map(|(idx, wt)| {
+				let selected = idx == self.selection;
+
+				let path = wt.path.to_string_lossy();
+				let path = path.unicode_truncate_start(path_width).0;
+				let branch =
+					wt.branch.as_deref().unwrap_or("(detached)");
+				let branch = branch.unicode_truncate(branch_width).0;
+
+				let state = if wt.is_stale {
+					"stale"
+				} else if wt.is_locked {
+					"locked"
+				} else if wt.is_dirty {
+					"dirty"
+				} else {
+					"clean"
+				};
+
+				Spans::from(vec![
+					Span::styled(
+						if wt.is_current { "* " } else { "  " },
+						self.theme.text(true, selected),
+					),
+					Span::styled(
+						format!("{:w$} ", path, w = path_width),
+						self.theme.text(!wt.is_stale, selected),
+					),
+					Span::styled(
+						format!("{:w$} ", branch, w = branch_width),
+						self.theme.branch(selected, wt.is_current),
+					),
+					Span::styled(
+						format!("{:w$}", state, w = STATE_WIDTH),
+						self.theme.text(!wt.is_dirty, selected),
+					),
+				])
+			})
+			.collect()
+	}
+}
+
+impl DrawableComponent for WorktreesPopup {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		area: Rect,
+	) -> Result<()> {
+		if self.is_visible() {
+			const PERCENT_SIZE: ui::Size = ui::Size::new(80, 50);
+			const MIN_SIZE: ui::Size = ui::Size::new(60, 12);
+
+			let area = ui::centered_rect(
+				PERCENT_SIZE.width,
+				PERCENT_SIZE.height,
+				area,
+			);
+			let area =
+				ui::rect_inside(MIN_SIZE, f.size().into(), area);
+			let area = area.intersection(f.size());
+
+			f.render_widget(Clear, area);
+			f.render_widget(
+				Block::default()
+					.borders(Borders::all())
+					.border_style(self.theme.popup_border())
+					.border_type(BorderType::Thick)
+					.title(Span::styled(
+						if self.free_branches.is_some() {
+							strings::POPUP_TITLE_WORKTREE_BRANCH
+						} else {
+							strings::POPUP_TITLE_WORKTREES
+						},
+						self.theme.title(true),
+					)),
+				area,
+			);
+
+			let area = area.inner(&Margin {
+				horizontal: 1,
+				vertical: 1,
+			});
+
+			let height = usize::from(area.height);
+			let scroll_top = ui::calc_scroll_top(
+				0,
+				height.saturating_sub(1),
+				self.selection,
+			);
+
+			let items = self
+				.get_items(usize::from(area.width))
+				.into_iter()
+				.skip(scroll_top)
+				.take(height);
+
+			ui::draw_list_block(f, area, Block::default(), items);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for WorktreesPopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			out.push(
+				CommandInfo::new(
+					strings::commands::close_popup(&self.key_config),
+					true,
+					true,
+				)
+				.order(1),
+			);
+
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+
+			if self.free_branches.is_some() {
+				out.push(CommandInfo::new(
+					strings::commands::worktree_pick_branch(
+						&self.key_config,
+					),
+					true,
+					true,
+				));
+			} else {
+				let selected = self.selected_worktree();
+
+				out.push(CommandInfo::new(
+					strings::commands::worktree_switch(
+						&self.key_config,
+					),
+					selected.map_or(false, |wt| {
+						!wt.is_current && !wt.is_stale
+					}),
+					true,
+				));
+				out.push(CommandInfo::new(
+					strings::commands::worktree_add(&self.key_config),
+					true,
+					true,
+				));
+				out.push(CommandInfo::new(
+					strings::commands::worktree_remove(
+						&self.key_config,
+					),
+					selected.map_or(false, |wt| {
+						!wt.is_current && wt.name.is_some()
+					}),
+					true,
+				));
+				out.push(CommandInfo::new(
+					strings::commands::worktree_prune(
+						&self.key_config,
+					),
+					self.worktrees.iter().any(|wt| wt.is_stale),
+					true,
+				));
+			}
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: &Event) -> Result<EventState> {
+		if self.is_visible() {
+			if let Event::Key(key) = event {
+				if key_match(key, self.key_config.keys().exit_popup) {
+					if self.free_branches.is_some() {
+						self.stop_picking_branch();
+					} else {
+						self.hide();
+					}
+				} else if key_match(key, self.key_config.keys().enter)
+				{
+					self.confirm();
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_down,
+				) {
+					self.move_selection(ScrollType::Down);
+				} else if key_match(
+					key,
+					self.key_config.keys().popup_up,
+				) {
+					self.move_selection(ScrollType::Up);
+				} else if self.free_branches.is_none() {
+					if key_match(
+						key,
+						self.key_config.keys().worktree_add,
+					) {
+						try_or_popup!(
+							self,
+							"worktree error:",
+							self.start_picking_branch()
+						);
+					} else if key_match(
+						key,
+						self.key_config.keys().worktree_remove,
+					) {
+						self.remove();
+					} else if key_match(
+						key,
+						self.key_config.keys().worktree_prune,
+					) {
+						try_or_popup!(
+							self,
+							"prune worktrees error:",
+							self.prune()
+						);
+					}
+				}
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+		Ok(())
+	}
+}
@@ -0,0 +1,41 @@
+use std::{fs, path::PathBuf, time::SystemTime};
+
+/// notices changes to config files by their modification time
+pub struct ConfigWatcher {
+	files: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+impl ConfigWatcher {
+	///
+	pub fn new(files: Vec<PathBuf>) -> Self {
+		Self {
+			files: files
+				.into_iter()
+				.map(|file| {
+					let modified = Self::modified(&file);
+					(file, modified)
+				})
+				.collect(),
+		}
+	}
+
+	fn modified(file: &PathBuf) -> Option<SystemTime> {
+		fs::metadata(file).and_then(|meta| meta.modified()).ok()
+	}
+
+	/// true if any file was written, created or removed since the
+	/// last call
+	pub fn changed(&mut self) -> bool {
+		let mut changed = false;
+
+		for (file, last_modified) in &mut self.files {
+			let modified = Self::modified(file);
+			if modified != *last_modified {
+				*last_modified = modified;
+				changed = true;
+			}
+		}
+
+		changed
+	}
+}
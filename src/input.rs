@@ -1,8 +1,12 @@
 use crate::notify_mutex::NotifyableMutex;
 use anyhow::Result;
 use crossbeam_channel::{unbounded, Receiver, Sender};
-use crossterm::event::{self, Event};
+use crossterm::{
+	event::{self, DisableMouseCapture, EnableMouseCapture, Event},
+	ExecutableCommand,
+};
 use std::{
+	io,
 	sync::{
 		atomic::{AtomicBool, Ordering},
 		Arc,
@@ -13,6 +17,17 @@ use std::{
 
 static POLL_DURATION: Duration = Duration::from_millis(1000);
 
+/// makes the terminal report mouse events, or stop doing so
+pub fn set_mouse_capture(enabled: bool) -> Result<()> {
+	if enabled {
+		io::stdout().execute(EnableMouseCapture)?;
+	} else {
+		io::stdout().execute(DisableMouseCapture)?;
+	}
+
+	Ok(())
+}
+
 ///
 #[derive(Clone, Copy, Debug)]
 pub enum InputState {
@@ -28,6 +43,7 @@ pub enum InputEvent {
 }
 
 ///
+#[derive(Clone)]
 pub struct Input {
 	desired_state: Arc<NotifyableMutex<bool>>,
 	current_state: Arc<AtomicBool>,
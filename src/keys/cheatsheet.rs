@@ -0,0 +1,110 @@
+use anyhow::Result;
+use ron::ser::PrettyConfig;
+use std::path::Path;
+
+use super::{key_list::GituiKeyEvent, KeyConfig};
+
+/// how `cheatsheet` lays out the key bindings
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum CheatsheetFormat {
+	Markdown,
+	Text,
+}
+
+impl CheatsheetFormat {
+	/// Markdown for `.md` files, plain text otherwise
+	pub fn from_path(path: &Path) -> Self {
+		if path.extension().map_or(false, |ext| ext == "md") {
+			Self::Markdown
+		} else {
+			Self::Text
+		}
+	}
+}
+
+/// every key binding in effect after the overrides of
+/// `key_bindings.ron` and `key_symbols.ron`
+pub fn cheatsheet(
+	key_config: &KeyConfig,
+	format: CheatsheetFormat,
+) -> Result<String> {
+	let bindings = bindings(key_config)?;
+
+	let lines = match format {
+		CheatsheetFormat::Markdown => [
+			String::from("# gitui key bindings"),
+			String::new(),
+			String::from("| Action | Key |"),
+			String::from("| --- | --- |"),
+		]
+		.into_iter()
+		.chain(bindings.into_iter().map(|(name, hint)| {
+			format!("| {} | {} |", name, hint.replace('|', "\\|"))
+		}))
+		.collect::<Vec<_>>(),
+		CheatsheetFormat::Text => {
+			let width = bindings
+				.iter()
+				.map(|(name, _)| name.len())
+				.max()
+				.unwrap_or_default();
+			bindings
+				.into_iter()
+				.map(|(name, hint)| {
+					format!(
+						"{:width$}  {}",
+						name,
+						hint,
+						width = width
+					)
+				})
+				.collect()
+		}
+	};
+
+	Ok(lines.into_iter().map(|line| line + "\n").collect())
+}
+
+/// names as used in `key_bindings.ron` with the hint of their key,
+/// in the order of the key list
+fn bindings(key_config: &KeyConfig) -> Result<Vec<(String, String)>> {
+	// one field per line lists the keys without naming each of them
+	let keys = ron::ser::to_string_pretty(
+		&*key_config.keys(),
+		PrettyConfig::new().depth_limit(1),
+	)?;
+
+	keys.lines()
+		.filter_map(|line| line.trim().split_once(':'))
+		.map(|(name, key)| {
+			let key: GituiKeyEvent =
+				ron::from_str(key.trim().trim_end_matches(','))?;
+			Ok((name.to_string(), key_config.get_hint(key)))
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_cheatsheet() {
+		let config = KeyConfig::default();
+
+		let markdown =
+			cheatsheet(&config, CheatsheetFormat::Markdown).unwrap();
+		assert!(markdown.contains("| quit | q |\n"));
+
+		let text =
+			cheatsheet(&config, CheatsheetFormat::Text).unwrap();
+		assert!(text
+			.lines()
+			.any(|line| line.starts_with("quit ")
+				&& line.ends_with(" q")));
+		assert_eq!(
+			text.lines().count(),
+			markdown.lines().count() - 4
+		);
+	}
+}
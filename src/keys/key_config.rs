@@ -1,6 +1,10 @@
 use anyhow::Result;
 use crossterm::event::{KeyCode, KeyModifiers};
-use std::{path::PathBuf, rc::Rc};
+use std::{
+	cell::{Ref, RefCell},
+	path::PathBuf,
+	rc::Rc,
+};
 
 use crate::{args::get_app_config_path, strings::symbol};
 
@@ -11,10 +15,12 @@ use super::{
 
 pub type SharedKeyConfig = Rc<KeyConfig>;
 
-#[derive(Default)]
+/// shared by all components and changed in place when the config
+/// files change
+#[derive(Default, Clone)]
 pub struct KeyConfig {
-	pub keys: KeysList,
-	symbols: KeySymbols,
+	keys: RefCell<KeysList>,
+	symbols: RefCell<KeySymbols>,
 }
 
 impl KeyConfig {
@@ -31,30 +37,62 @@ impl KeyConfig {
 	pub fn init() -> Result<Self> {
 		let keys = KeysList::init(Self::get_config_file()?);
 		let symbols = KeySymbols::init(Self::get_symbols_file()?);
-		Ok(Self { keys, symbols })
+		Ok(Self::new(keys, symbols))
 	}
 
-	fn get_key_symbol(&self, k: KeyCode) -> &str {
-		match k {
-			KeyCode::Enter => &self.symbols.enter,
-			KeyCode::Left => &self.symbols.left,
-			KeyCode::Right => &self.symbols.right,
-			KeyCode::Up => &self.symbols.up,
-			KeyCode::Down => &self.symbols.down,
-			KeyCode::Backspace => &self.symbols.backspace,
-			KeyCode::Home => &self.symbols.home,
-			KeyCode::End => &self.symbols.end,
-			KeyCode::PageUp => &self.symbols.page_up,
-			KeyCode::PageDown => &self.symbols.page_down,
-			KeyCode::Tab => &self.symbols.tab,
-			KeyCode::BackTab => &self.symbols.back_tab,
-			KeyCode::Delete => &self.symbols.delete,
-			KeyCode::Insert => &self.symbols.insert,
-			KeyCode::Esc => &self.symbols.esc,
-			_ => "?",
+	/// reads the config files again, keeping invalid files untouched
+	pub fn reload() -> Result<Self> {
+		let keys = KeysList::load(Self::get_config_file()?)?;
+		let symbols = KeySymbols::load(Self::get_symbols_file()?)?;
+		Ok(Self::new(keys, symbols))
+	}
+
+	const fn new(keys: KeysList, symbols: KeySymbols) -> Self {
+		Self {
+			keys: RefCell::new(keys),
+			symbols: RefCell::new(symbols),
 		}
 	}
 
+	/// current key bindings, not to be held across a reload
+	pub fn keys(&self) -> Ref<'_, KeysList> {
+		self.keys.borrow()
+	}
+
+	/// takes over the key bindings and symbols of `other`
+	pub fn replace(&self, other: Self) {
+		self.keys.replace(other.keys.into_inner());
+		self.symbols.replace(other.symbols.into_inner());
+	}
+
+	/// files the key config is read from
+	pub fn config_files() -> Result<Vec<PathBuf>> {
+		Ok(vec![Self::get_config_file()?, Self::get_symbols_file()?])
+	}
+
+	fn get_key_symbol(&self, k: KeyCode) -> String {
+		let symbols = self.symbols.borrow();
+		let symbol = match k {
+			KeyCode::Enter => &symbols.enter,
+			KeyCode::Left => &symbols.left,
+			KeyCode::Right => &symbols.right,
+			KeyCode::Up => &symbols.up,
+			KeyCode::Down => &symbols.down,
+			KeyCode::Backspace => &symbols.backspace,
+			KeyCode::Home => &symbols.home,
+			KeyCode::End => &symbols.end,
+			KeyCode::PageUp => &symbols.page_up,
+			KeyCode::PageDown => &symbols.page_down,
+			KeyCode::Tab => &symbols.tab,
+			KeyCode::BackTab => &symbols.back_tab,
+			KeyCode::Delete => &symbols.delete,
+			KeyCode::Insert => &symbols.insert,
+			KeyCode::Esc => &symbols.esc,
+			_ => "?",
+		};
+		symbol.to_string()
+	}
+
 	pub fn get_hint(&self, ev: GituiKeyEvent) -> String {
 		match ev.code {
 			KeyCode::Down
@@ -93,19 +131,86 @@ impl KeyConfig {
 					u
 				)
 			}
-			KeyCode::Null => {
-				self.get_modifier_hint(ev.modifiers).into()
-			}
+			KeyCode::Null => self.get_modifier_hint(ev.modifiers),
 			_ => "".into(),
 		}
 	}
 
-	fn get_modifier_hint(&self, modifier: KeyModifiers) -> &str {
+	/// inverse of `get_hint`, `None` for hints not naming a single key
+	pub fn key_from_hint(&self, hint: &str) -> Option<GituiKeyEvent> {
+		if hint == symbol::SPACE {
+			return Some(GituiKeyEvent::new(
+				KeyCode::Char(' '),
+				KeyModifiers::empty(),
+			));
+		}
+
+		let (modifiers, key) = [
+			KeyModifiers::CONTROL,
+			KeyModifiers::SHIFT,
+			KeyModifiers::ALT,
+		]
+		.into_iter()
+		.find_map(|modifier| {
+			let prefix = self.get_modifier_hint(modifier);
+			if prefix.is_empty() {
+				None
+			} else {
+				hint.strip_prefix(prefix.as_str())
+					.map(|key| (modifier, key))
+			}
+		})
+		.unwrap_or((KeyModifiers::empty(), hint));
+
+		let code = [
+			KeyCode::Enter,
+			KeyCode::Left,
+			KeyCode::Right,
+			KeyCode::Up,
+			KeyCode::Down,
+			KeyCode::Backspace,
+			KeyCode::Home,
+			KeyCode::End,
+			KeyCode::PageUp,
+			KeyCode::PageDown,
+			KeyCode::Tab,
+			KeyCode::BackTab,
+			KeyCode::Delete,
+			KeyCode::Insert,
+			KeyCode::Esc,
+		]
+		.into_iter()
+		.find(|code| self.get_key_symbol(*code) == key)
+		.or_else(|| {
+			let mut chars = key.chars();
+			match (chars.next(), chars.next()) {
+				(Some(c), None) => Some(KeyCode::Char(c)),
+				_ => key
+					.strip_prefix('F')
+					.and_then(|n| n.parse().ok())
+					.map(KeyCode::F),
+			}
+		})?;
+
+		Some(GituiKeyEvent::new(code, modifiers))
+	}
+
+	/// key of the `[hint]` a command bar entry ends with
+	pub fn key_from_command(
+		&self,
+		name: &str,
+	) -> Option<GituiKeyEvent> {
+		let (_, hint) = name.trim_end().rsplit_once('[')?;
+		self.key_from_hint(hint.strip_suffix(']')?)
+	}
+
+	fn get_modifier_hint(&self, modifier: KeyModifiers) -> String {
+		let symbols = self.symbols.borrow();
 		match modifier {
-			KeyModifiers::CONTROL => &self.symbols.control,
-			KeyModifiers::SHIFT => &self.symbols.shift,
-			KeyModifiers::ALT => &self.symbols.alt,
-			_ => "",
+			KeyModifiers::CONTROL => symbols.control.clone(),
+			KeyModifiers::SHIFT => symbols.shift.clone(),
+			KeyModifiers::ALT => symbols.alt.clone(),
+			_ => String::new(),
 		}
 	}
 }
@@ -124,4 +229,43 @@ mod tests {
 		));
 		assert_eq!(h, "^c");
 	}
+
+	#[test]
+	fn test_key_from_hint() {
+		let config = KeyConfig::default();
+
+		for key in [
+			GituiKeyEvent::new(
+				KeyCode::Char('c'),
+				KeyModifiers::CONTROL,
+			),
+			GituiKeyEvent::new(
+				KeyCode::Char('S'),
+				KeyModifiers::SHIFT,
+			),
+			GituiKeyEvent::new(
+				KeyCode::Char(' '),
+				KeyModifiers::empty(),
+			),
+			GituiKeyEvent::new(KeyCode::Enter, KeyModifiers::empty()),
+			GituiKeyEvent::new(KeyCode::F(5), KeyModifiers::empty()),
+		] {
+			assert_eq!(
+				config.key_from_hint(&config.get_hint(key)),
+				Some(key)
+			);
+		}
+
+		assert_eq!(
+			config.key_from_command("Stage [s]"),
+			Some(GituiKeyEvent::new(
+				KeyCode::Char('s'),
+				KeyModifiers::empty()
+			))
+		);
+		assert_eq!(
+			config.key_from_command("Scroll [\u{2191}\u{2193}]"),
+			None
+		);
+	}
 }
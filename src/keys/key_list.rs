@@ -1,3 +1,4 @@
+use anyhow::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -34,6 +35,7 @@ impl From<&GituiKeyEvent> for KeyEvent {
 	}
 }
 
+#[derive(Clone, Serialize)]
 pub struct KeysList {
 	pub tab_status: GituiKeyEvent,
 	pub tab_log: GituiKeyEvent,
@@ -108,10 +110,73 @@ pub struct KeysList {
 	pub stage_unstage_item: GituiKeyEvent,
 	pub tag_annotate: GituiKeyEvent,
 	pub view_submodules: GituiKeyEvent,
+	pub commit_signoff: GituiKeyEvent,
+	pub commit_coauthor: GituiKeyEvent,
+	pub commit_no_verify: GituiKeyEvent,
+	pub commit_reuse_msg: GituiKeyEvent,
+	pub skip_rebase: GituiKeyEvent,
+	pub branch_filter: GituiKeyEvent,
+	pub branch_set_upstream: GituiKeyEvent,
+	pub branch_prune_gone: GituiKeyEvent,
+	pub branch_mark: GituiKeyEvent,
+	pub branch_sort: GituiKeyEvent,
+	pub view_worktrees: GituiKeyEvent,
+	pub worktree_add: GituiKeyEvent,
+	pub worktree_remove: GituiKeyEvent,
+	pub worktree_prune: GituiKeyEvent,
+	pub recent_branches: GituiKeyEvent,
+	pub branch_description: GituiKeyEvent,
+	pub stashing_mark_file: GituiKeyEvent,
+	pub stash_branch: GituiKeyEvent,
+	pub stash_toggle_index: GituiKeyEvent,
+	pub stashing_toggle_untracked_only: GituiKeyEvent,
+	pub stash_filter: GituiKeyEvent,
+	pub view_remotes: GituiKeyEvent,
+	pub remote_add: GituiKeyEvent,
+	pub remote_remove: GituiKeyEvent,
+	pub remote_rename: GituiKeyEvent,
+	pub remote_edit_url: GituiKeyEvent,
+	pub remote_edit_push_url: GituiKeyEvent,
+	pub fetch_all: GituiKeyEvent,
+	pub tag_push: GituiKeyEvent,
+	pub push_to: GituiKeyEvent,
+	pub log_deepen: GituiKeyEvent,
+	pub tag_delete_remote: GituiKeyEvent,
+	pub tag_filter: GituiKeyEvent,
+	pub tag_sign: GituiKeyEvent,
+	pub tag_checkout: GituiKeyEvent,
+	pub detached_create_branch: GituiKeyEvent,
+	pub tag_mark: GituiKeyEvent,
+	pub tag_delete_matching: GituiKeyEvent,
+	pub tag_sort: GituiKeyEvent,
+	pub tag_kind_filter: GituiKeyEvent,
+	pub blame_parent: GituiKeyEvent,
+	pub file_history_restore: GituiKeyEvent,
+	pub file_history_restore_stage: GituiKeyEvent,
+	pub view_file: GituiKeyEvent,
+	pub search: GituiKeyEvent,
+	pub search_next: GituiKeyEvent,
+	pub search_prev: GituiKeyEvent,
+	pub files_revision: GituiKeyEvent,
+	pub file_find_filter: GituiKeyEvent,
+	pub open_grep: GituiKeyEvent,
+	pub copy_path: GituiKeyEvent,
+	pub tree_dir_sizes: GituiKeyEvent,
+	pub open_externally: GituiKeyEvent,
+	pub open_theme_picker: GituiKeyEvent,
+	pub toggle_zoom: GituiKeyEvent,
+	pub toggle_wrap: GituiKeyEvent,
+	pub toast_history: GituiKeyEvent,
+	pub help_toggle_context: GituiKeyEvent,
+	pub open_log_viewer: GituiKeyEvent,
+	pub error_retry: GituiKeyEvent,
+	pub open_repo_switcher: GituiKeyEvent,
+	pub repo_switcher_browse: GituiKeyEvent,
 }
 
 #[rustfmt::skip]
 impl Default for KeysList {
+	#[allow(clippy::too_many_lines)]
 	fn default() -> Self {
 		Self {
 			tab_status: GituiKeyEvent::new(KeyCode::Char('1'), KeyModifiers::empty()),
@@ -187,6 +252,68 @@ impl Default for KeysList {
 			stage_unstage_item: GituiKeyEvent::new(KeyCode::Enter,  KeyModifiers::empty()),
 			tag_annotate: GituiKeyEvent::new(KeyCode::Char('a'),  KeyModifiers::CONTROL),
 			view_submodules: GituiKeyEvent::new(KeyCode::Char('S'),  KeyModifiers::SHIFT),
+			commit_signoff: GituiKeyEvent::new(KeyCode::Char('s'),  KeyModifiers::CONTROL),
+			commit_coauthor: GituiKeyEvent::new(KeyCode::Char('o'),  KeyModifiers::CONTROL),
+			commit_no_verify: GituiKeyEvent::new(KeyCode::Char('n'),  KeyModifiers::CONTROL),
+			commit_reuse_msg: GituiKeyEvent::new(KeyCode::Char('r'),  KeyModifiers::CONTROL),
+			skip_rebase: GituiKeyEvent::new(KeyCode::Char('K'),  KeyModifiers::SHIFT),
+			branch_filter: GituiKeyEvent::new(KeyCode::Char('F'),  KeyModifiers::SHIFT),
+			branch_set_upstream: GituiKeyEvent::new(KeyCode::Char('u'),  KeyModifiers::empty()),
+			branch_prune_gone: GituiKeyEvent::new(KeyCode::Char('G'),  KeyModifiers::SHIFT),
+			branch_mark: GituiKeyEvent::new(KeyCode::Char(' '),  KeyModifiers::empty()),
+			branch_sort: GituiKeyEvent::new(KeyCode::Char('s'),  KeyModifiers::empty()),
+			view_worktrees: GituiKeyEvent::new(KeyCode::Char('W'),  KeyModifiers::SHIFT),
+			worktree_add: GituiKeyEvent::new(KeyCode::Char('a'),  KeyModifiers::empty()),
+			worktree_remove: GituiKeyEvent::new(KeyCode::Char('D'),  KeyModifiers::SHIFT),
+			worktree_prune: GituiKeyEvent::new(KeyCode::Char('p'),  KeyModifiers::empty()),
+			recent_branches: GituiKeyEvent::new(KeyCode::Char('-'),  KeyModifiers::empty()),
+			branch_description: GituiKeyEvent::new(KeyCode::Char('d'),  KeyModifiers::empty()),
+			stashing_mark_file: GituiKeyEvent::new(KeyCode::Char(' '),  KeyModifiers::empty()),
+			stash_branch: GituiKeyEvent::new(KeyCode::Char('b'),  KeyModifiers::empty()),
+			stash_toggle_index: GituiKeyEvent::new(KeyCode::Char('i'),  KeyModifiers::empty()),
+			stashing_toggle_untracked_only: GituiKeyEvent::new(KeyCode::Char('U'),  KeyModifiers::SHIFT),
+			stash_filter: GituiKeyEvent::new(KeyCode::Char('f'),  KeyModifiers::empty()),
+			view_remotes: GituiKeyEvent::new(KeyCode::Char('R'),  KeyModifiers::SHIFT),
+			remote_add: GituiKeyEvent::new(KeyCode::Char('a'),  KeyModifiers::empty()),
+			remote_remove: GituiKeyEvent::new(KeyCode::Char('D'),  KeyModifiers::SHIFT),
+			remote_rename: GituiKeyEvent::new(KeyCode::Char('r'),  KeyModifiers::empty()),
+			remote_edit_url: GituiKeyEvent::new(KeyCode::Char('e'),  KeyModifiers::empty()),
+			remote_edit_push_url: GituiKeyEvent::new(KeyCode::Char('E'),  KeyModifiers::SHIFT),
+			fetch_all: GituiKeyEvent::new(KeyCode::Char('F'),  KeyModifiers::SHIFT),
+			tag_push: GituiKeyEvent::new(KeyCode::Char('P'),  KeyModifiers::SHIFT),
+			push_to: GituiKeyEvent::new(KeyCode::Char('p'),  KeyModifiers::CONTROL),
+			log_deepen: GituiKeyEvent::new(KeyCode::Char('d'),  KeyModifiers::empty()),
+			tag_delete_remote: GituiKeyEvent::new(KeyCode::Char('d'),  KeyModifiers::empty()),
+			tag_filter: GituiKeyEvent::new(KeyCode::Char('f'),  KeyModifiers::empty()),
+			tag_sign: GituiKeyEvent::new(KeyCode::Char('s'),  KeyModifiers::CONTROL),
+			tag_checkout: GituiKeyEvent::new(KeyCode::Char('c'),  KeyModifiers::empty()),
+			detached_create_branch: GituiKeyEvent::new(KeyCode::Char('C'),  KeyModifiers::SHIFT),
+			tag_mark: GituiKeyEvent::new(KeyCode::Char(' '),  KeyModifiers::empty()),
+			tag_delete_matching: GituiKeyEvent::new(KeyCode::Char('X'),  KeyModifiers::SHIFT),
+			tag_sort: GituiKeyEvent::new(KeyCode::Char('s'),  KeyModifiers::empty()),
+			tag_kind_filter: GituiKeyEvent::new(KeyCode::Char('F'),  KeyModifiers::SHIFT),
+			blame_parent: GituiKeyEvent::new(KeyCode::Char('p'),  KeyModifiers::empty()),
+			file_history_restore: GituiKeyEvent::new(KeyCode::Char('r'),  KeyModifiers::empty()),
+			file_history_restore_stage: GituiKeyEvent::new(KeyCode::Char('R'),  KeyModifiers::SHIFT),
+			view_file: GituiKeyEvent::new(KeyCode::Char('v'),  KeyModifiers::empty()),
+			search: GituiKeyEvent::new(KeyCode::Char('/'),  KeyModifiers::empty()),
+			search_next: GituiKeyEvent::new(KeyCode::Char('n'),  KeyModifiers::empty()),
+			search_prev: GituiKeyEvent::new(KeyCode::Char('N'),  KeyModifiers::SHIFT),
+			files_revision: GituiKeyEvent::new(KeyCode::Char('r'),  KeyModifiers::empty()),
+			file_find_filter: GituiKeyEvent::new(KeyCode::Char('f'),  KeyModifiers::CONTROL),
+			open_grep: GituiKeyEvent::new(KeyCode::Char('g'),  KeyModifiers::CONTROL),
+			copy_path: GituiKeyEvent::new(KeyCode::Char('Y'),  KeyModifiers::SHIFT),
+			tree_dir_sizes: GituiKeyEvent::new(KeyCode::Char('s'),  KeyModifiers::empty()),
+			open_externally: GituiKeyEvent::new(KeyCode::Char('O'),  KeyModifiers::SHIFT),
+			open_theme_picker: GituiKeyEvent::new(KeyCode::Char('t'),  KeyModifiers::CONTROL),
+			toggle_zoom: GituiKeyEvent::new(KeyCode::Char('z'),  KeyModifiers::empty()),
+			toggle_wrap: GituiKeyEvent::new(KeyCode::Char('L'),  KeyModifiers::SHIFT),
+			toast_history: GituiKeyEvent::new(KeyCode::Char('M'),  KeyModifiers::SHIFT),
+			help_toggle_context: GituiKeyEvent::new(KeyCode::Tab,  KeyModifiers::empty()),
+			open_log_viewer: GituiKeyEvent::new(KeyCode::Char('l'),  KeyModifiers::CONTROL),
+			error_retry: GituiKeyEvent::new(KeyCode::Char('r'),  KeyModifiers::empty()),
+			open_repo_switcher: GituiKeyEvent::new(KeyCode::Char('w'),  KeyModifiers::CONTROL),
+			repo_switcher_browse: GituiKeyEvent::new(KeyCode::Char('b'),  KeyModifiers::empty()),
 
 		}
 	}
@@ -194,12 +321,15 @@ impl Default for KeysList {
 
 impl KeysList {
 	pub fn init(file: PathBuf) -> Self {
+		Self::load(file).unwrap_or_default()
+	}
+
+	/// like `init` but failing on an invalid file
+	pub fn load(file: PathBuf) -> Result<Self> {
 		if file.exists() {
-			let file =
-				KeysListFile::read_file(file).unwrap_or_default();
-			file.get_list()
+			Ok(KeysListFile::read_file(file)?.get_list())
 		} else {
-			Self::default()
+			Ok(Self::default())
 		}
 	}
 }
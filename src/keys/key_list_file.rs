@@ -80,6 +80,68 @@ pub struct KeysListFile {
 	pub stage_unstage_item: Option<GituiKeyEvent>,
 	pub tag_annotate: Option<GituiKeyEvent>,
 	pub view_submodules: Option<GituiKeyEvent>,
+	pub commit_signoff: Option<GituiKeyEvent>,
+	pub commit_coauthor: Option<GituiKeyEvent>,
+	pub commit_no_verify: Option<GituiKeyEvent>,
+	pub commit_reuse_msg: Option<GituiKeyEvent>,
+	pub skip_rebase: Option<GituiKeyEvent>,
+	pub branch_filter: Option<GituiKeyEvent>,
+	pub branch_set_upstream: Option<GituiKeyEvent>,
+	pub branch_prune_gone: Option<GituiKeyEvent>,
+	pub branch_mark: Option<GituiKeyEvent>,
+	pub branch_sort: Option<GituiKeyEvent>,
+	pub view_worktrees: Option<GituiKeyEvent>,
+	pub worktree_add: Option<GituiKeyEvent>,
+	pub worktree_remove: Option<GituiKeyEvent>,
+	pub worktree_prune: Option<GituiKeyEvent>,
+	pub recent_branches: Option<GituiKeyEvent>,
+	pub branch_description: Option<GituiKeyEvent>,
+	pub stashing_mark_file: Option<GituiKeyEvent>,
+	pub stash_branch: Option<GituiKeyEvent>,
+	pub stash_toggle_index: Option<GituiKeyEvent>,
+	pub stashing_toggle_untracked_only: Option<GituiKeyEvent>,
+	pub stash_filter: Option<GituiKeyEvent>,
+	pub view_remotes: Option<GituiKeyEvent>,
+	pub remote_add: Option<GituiKeyEvent>,
+	pub remote_remove: Option<GituiKeyEvent>,
+	pub remote_rename: Option<GituiKeyEvent>,
+	pub remote_edit_url: Option<GituiKeyEvent>,
+	pub remote_edit_push_url: Option<GituiKeyEvent>,
+	pub fetch_all: Option<GituiKeyEvent>,
+	pub tag_push: Option<GituiKeyEvent>,
+	pub push_to: Option<GituiKeyEvent>,
+	pub log_deepen: Option<GituiKeyEvent>,
+	pub tag_delete_remote: Option<GituiKeyEvent>,
+	pub tag_filter: Option<GituiKeyEvent>,
+	pub tag_sign: Option<GituiKeyEvent>,
+	pub tag_checkout: Option<GituiKeyEvent>,
+	pub detached_create_branch: Option<GituiKeyEvent>,
+	pub tag_mark: Option<GituiKeyEvent>,
+	pub tag_delete_matching: Option<GituiKeyEvent>,
+	pub tag_sort: Option<GituiKeyEvent>,
+	pub tag_kind_filter: Option<GituiKeyEvent>,
+	pub blame_parent: Option<GituiKeyEvent>,
+	pub file_history_restore: Option<GituiKeyEvent>,
+	pub file_history_restore_stage: Option<GituiKeyEvent>,
+	pub view_file: Option<GituiKeyEvent>,
+	pub search: Option<GituiKeyEvent>,
+	pub search_next: Option<GituiKeyEvent>,
+	pub search_prev: Option<GituiKeyEvent>,
+	pub files_revision: Option<GituiKeyEvent>,
+	pub file_find_filter: Option<GituiKeyEvent>,
+	pub open_grep: Option<GituiKeyEvent>,
+	pub copy_path: Option<GituiKeyEvent>,
+	pub tree_dir_sizes: Option<GituiKeyEvent>,
+	pub open_externally: Option<GituiKeyEvent>,
+	pub open_theme_picker: Option<GituiKeyEvent>,
+	pub toggle_zoom: Option<GituiKeyEvent>,
+	pub toggle_wrap: Option<GituiKeyEvent>,
+	pub toast_history: Option<GituiKeyEvent>,
+	pub help_toggle_context: Option<GituiKeyEvent>,
+	pub open_log_viewer: Option<GituiKeyEvent>,
+	pub error_retry: Option<GituiKeyEvent>,
+	pub open_repo_switcher: Option<GituiKeyEvent>,
+	pub repo_switcher_browse: Option<GituiKeyEvent>,
 }
 
 impl KeysListFile {
@@ -91,6 +153,7 @@ impl KeysListFile {
 	}
 
 	#[rustfmt::skip]
+	#[allow(clippy::too_many_lines)]
 	pub fn get_list(self) -> KeysList {
 		let default = KeysList::default();
 
@@ -168,6 +231,68 @@ impl KeysListFile {
 			stage_unstage_item: self.stage_unstage_item.unwrap_or(default.stage_unstage_item),
 			tag_annotate: self.tag_annotate.unwrap_or(default.tag_annotate),
 			view_submodules: self.view_submodules.unwrap_or(default.view_submodules),
+			commit_signoff: self.commit_signoff.unwrap_or(default.commit_signoff),
+			commit_coauthor: self.commit_coauthor.unwrap_or(default.commit_coauthor),
+			commit_no_verify: self.commit_no_verify.unwrap_or(default.commit_no_verify),
+			commit_reuse_msg: self.commit_reuse_msg.unwrap_or(default.commit_reuse_msg),
+			skip_rebase: self.skip_rebase.unwrap_or(default.skip_rebase),
+			branch_filter: self.branch_filter.unwrap_or(default.branch_filter),
+			branch_set_upstream: self.branch_set_upstream.unwrap_or(default.branch_set_upstream),
+			branch_prune_gone: self.branch_prune_gone.unwrap_or(default.branch_prune_gone),
+			branch_mark: self.branch_mark.unwrap_or(default.branch_mark),
+			branch_sort: self.branch_sort.unwrap_or(default.branch_sort),
+			view_worktrees: self.view_worktrees.unwrap_or(default.view_worktrees),
+			worktree_add: self.worktree_add.unwrap_or(default.worktree_add),
+			worktree_remove: self.worktree_remove.unwrap_or(default.worktree_remove),
+			worktree_prune: self.worktree_prune.unwrap_or(default.worktree_prune),
+			recent_branches: self.recent_branches.unwrap_or(default.recent_branches),
+			branch_description: self.branch_description.unwrap_or(default.branch_description),
+			stashing_mark_file: self.stashing_mark_file.unwrap_or(default.stashing_mark_file),
+			stash_branch: self.stash_branch.unwrap_or(default.stash_branch),
+			stash_toggle_index: self.stash_toggle_index.unwrap_or(default.stash_toggle_index),
+			stashing_toggle_untracked_only: self.stashing_toggle_untracked_only.unwrap_or(default.stashing_toggle_untracked_only),
+			stash_filter: self.stash_filter.unwrap_or(default.stash_filter),
+			view_remotes: self.view_remotes.unwrap_or(default.view_remotes),
+			remote_add: self.remote_add.unwrap_or(default.remote_add),
+			remote_remove: self.remote_remove.unwrap_or(default.remote_remove),
+			remote_rename: self.remote_rename.unwrap_or(default.remote_rename),
+			remote_edit_url: self.remote_edit_url.unwrap_or(default.remote_edit_url),
+			remote_edit_push_url: self.remote_edit_push_url.unwrap_or(default.remote_edit_push_url),
+			fetch_all: self.fetch_all.unwrap_or(default.fetch_all),
+			tag_push: self.tag_push.unwrap_or(default.tag_push),
+			push_to: self.push_to.unwrap_or(default.push_to),
+			log_deepen: self.log_deepen.unwrap_or(default.log_deepen),
+			tag_delete_remote: self.tag_delete_remote.unwrap_or(default.tag_delete_remote),
+			tag_filter: self.tag_filter.unwrap_or(default.tag_filter),
+			tag_sign: self.tag_sign.unwrap_or(default.tag_sign),
+			tag_checkout: self.tag_checkout.unwrap_or(default.tag_checkout),
+			detached_create_branch: self.detached_create_branch.unwrap_or(default.detached_create_branch),
+			tag_mark: self.tag_mark.unwrap_or(default.tag_mark),
+			tag_delete_matching: self.tag_delete_matching.unwrap_or(default.tag_delete_matching),
+			tag_sort: self.tag_sort.unwrap_or(default.tag_sort),
+			tag_kind_filter: self.tag_kind_filter.unwrap_or(default.tag_kind_filter),
+			blame_parent: self.blame_parent.unwrap_or(default.blame_parent),
+			file_history_restore: self.file_history_restore.unwrap_or(default.file_history_restore),
+			file_history_restore_stage: self.file_history_restore_stage.unwrap_or(default.file_history_restore_stage),
+			view_file: self.view_file.unwrap_or(default.view_file),
+			search: self.search.unwrap_or(default.search),
+			search_next: self.search_next.unwrap_or(default.search_next),
+			search_prev: self.search_prev.unwrap_or(default.search_prev),
+			files_revision: self.files_revision.unwrap_or(default.files_revision),
+			file_find_filter: self.file_find_filter.unwrap_or(default.file_find_filter),
+			open_grep: self.open_grep.unwrap_or(default.open_grep),
+			copy_path: self.copy_path.unwrap_or(default.copy_path),
+			tree_dir_sizes: self.tree_dir_sizes.unwrap_or(default.tree_dir_sizes),
+			open_externally: self.open_externally.unwrap_or(default.open_externally),
+			open_theme_picker: self.open_theme_picker.unwrap_or(default.open_theme_picker),
+			toggle_zoom: self.toggle_zoom.unwrap_or(default.toggle_zoom),
+			toggle_wrap: self.toggle_wrap.unwrap_or(default.toggle_wrap),
+			toast_history: self.toast_history.unwrap_or(default.toast_history),
+			help_toggle_context: self.help_toggle_context.unwrap_or(default.help_toggle_context),
+			open_log_viewer: self.open_log_viewer.unwrap_or(default.open_log_viewer),
+			error_retry: self.error_retry.unwrap_or(default.error_retry),
+			open_repo_switcher: self.open_repo_switcher.unwrap_or(default.open_repo_switcher),
+			repo_switcher_browse: self.repo_switcher_browse.unwrap_or(default.repo_switcher_browse),
 		}
 	}
 }
@@ -1,7 +1,9 @@
+mod cheatsheet;
 mod key_config;
 mod key_list;
 mod key_list_file;
 mod symbols;
 
+pub use cheatsheet::{cheatsheet, CheatsheetFormat};
 pub use key_config::{KeyConfig, SharedKeyConfig};
 pub use key_list::key_match;
@@ -3,7 +3,7 @@ use std::{fs::File, io::Read, path::PathBuf};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct KeySymbols {
 	pub enter: String,
 	pub left: String,
@@ -53,12 +53,15 @@ impl Default for KeySymbols {
 
 impl KeySymbols {
 	pub fn init(file: PathBuf) -> Self {
+		Self::load(file).unwrap_or_default()
+	}
+
+	/// like `init` but failing on an invalid file
+	pub fn load(file: PathBuf) -> Result<Self> {
 		if file.exists() {
-			let file =
-				KeySymbolsFile::read_file(file).unwrap_or_default();
-			file.get_symbols()
+			Ok(KeySymbolsFile::read_file(file)?.get_symbols())
 		} else {
-			Self::default()
+			Ok(Self::default())
 		}
 	}
 }
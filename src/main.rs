@@ -21,12 +21,16 @@
 mod app;
 mod args;
 mod bug_report;
+mod cli_output;
 mod clipboard;
 mod cmdbar;
 mod components;
+mod config_watcher;
 mod input;
 mod keys;
 mod notify_mutex;
+mod opener;
+mod options_file;
 mod popup_stack;
 mod profiler;
 mod queue;
@@ -34,10 +38,17 @@ mod spinner;
 mod string_utils;
 mod strings;
 mod tabs;
+mod title_bar;
 mod ui;
 mod version;
 
-use crate::{app::App, args::process_cmdline};
+use crate::{
+	app::{App, QuitState},
+	args::{process_cmdline, KeysArgs},
+	components::{error_chain, remember_repo, StartTab},
+	config_watcher::ConfigWatcher,
+	queue::{InternalEvent, NeedsUpdate, StackablePopupOpen},
+};
 use anyhow::{bail, Result};
 use asyncgit::{sync::RepoPath, AsyncGitNotification};
 use backtrace::Backtrace;
@@ -50,22 +61,26 @@ use crossterm::{
 	ExecutableCommand,
 };
 use input::{Input, InputEvent, InputState};
-use keys::KeyConfig;
+use keys::{cheatsheet, KeyConfig, SharedKeyConfig};
 use profiler::Profiler;
 use scopeguard::defer;
 use scopetime::scope_time;
 use spinner::Spinner;
 use std::{
 	cell::RefCell,
+	fs,
 	io::{self, Write},
-	panic, process,
+	panic,
+	path::PathBuf,
+	process,
+	rc::Rc,
 	time::{Duration, Instant},
 };
 use tui::{
 	backend::{Backend, CrosstermBackend},
 	Terminal,
 };
-use ui::style::Theme;
+use ui::style::{SharedTheme, Theme, ThemePreset};
 
 static TICK_INTERVAL: Duration = Duration::from_secs(5);
 static SPINNER_INTERVAL: Duration = Duration::from_millis(80);
@@ -106,17 +121,54 @@ fn main() -> Result<()> {
 
 	asyncgit::register_tracing_logging();
 
+	if let Err(e) = asyncgit::sync::remotes::register_ssh_transport(
+		&cliargs.repo_path,
+	) {
+		log::error!("ssh transport: {}", e);
+	}
+
+	if let Some(keys) = cliargs.keys {
+		return export_keys(&keys);
+	}
+
 	if !valid_path(&cliargs.repo_path) {
 		eprintln!("invalid path\nplease run gitui inside of a non-bare git repository");
 		return Ok(());
 	}
 
+	if let Some(status) = cliargs.status {
+		return cli_output::print_status(&cliargs.repo_path, &status);
+	}
+
+	if let Some(log) = cliargs.log {
+		return cli_output::print_log(&cliargs.repo_path, &log);
+	}
+
+	let mut open = match cliargs
+		.open
+		.map(|open| open.open(&cliargs.repo_path))
+		.transpose()
+	{
+		Ok(open) => open,
+		Err(e) => {
+			eprintln!("open: {}", e);
+			return Ok(());
+		}
+	};
+
 	let key_config = KeyConfig::init()
 		.map_err(|e| eprintln!("KeyConfig loading error: {}", e))
 		.unwrap_or_default();
-	let theme = Theme::init(cliargs.theme)
-		.map_err(|e| eprintln!("Theme loading error: {}", e))
-		.unwrap_or_default();
+	let theme = match ThemePreset::load().theme() {
+		Some(theme) => theme,
+		None => Theme::init(cliargs.theme.clone())
+			.map_err(|e| eprintln!("Theme loading error: {}", e))
+			.unwrap_or_default(),
+	};
+
+	let mut config_files = vec![cliargs.theme.clone()];
+	config_files
+		.extend(KeyConfig::config_files().unwrap_or_default());
 
 	setup_terminal()?;
 	defer! {
@@ -125,26 +177,80 @@ fn main() -> Result<()> {
 
 	set_panic_handlers()?;
 
+	let mut repo_path = cliargs.repo_path;
 	let mut terminal = start_terminal(io::stdout())?;
+	let input = Input::new();
+	let mut config_watcher = ConfigWatcher::new(config_files);
+	let mut tab = cliargs.tab.map(StartTab::index);
+	// shared across repositories to keep what was reloaded
+	let theme = Rc::new(theme);
+	let key_config = Rc::new(key_config);
+
+	loop {
+		let quit_state = run_app(
+			repo_path.clone(),
+			theme.clone(),
+			key_config.clone(),
+			cliargs.theme.clone(),
+			open.take(),
+			tab.take(),
+			&mut config_watcher,
+			&input,
+			&mut terminal,
+		)?;
+
+		match quit_state {
+			QuitState::OpenRepo(path) => repo_path = path,
+			_ => break,
+		}
+	}
 
+	Ok(())
+}
+
+/// `open` is a view to start in, `tab` the tab to start on (the
+/// configured one if `None`)
+#[allow(clippy::too_many_arguments)]
+fn run_app(
+	repo: RepoPath,
+	theme: SharedTheme,
+	key_config: SharedKeyConfig,
+	theme_file: PathBuf,
+	open: Option<StackablePopupOpen>,
+	tab: Option<usize>,
+	config_watcher: &mut ConfigWatcher,
+	input: &Input,
+	terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+) -> Result<QuitState> {
 	let (tx_git, rx_git) = unbounded();
 	let (tx_app, rx_app) = unbounded();
 
-	let input = Input::new();
-
 	let rx_input = input.receiver();
 	let ticker = tick(TICK_INTERVAL);
 	let spinner_ticker = tick(SPINNER_INTERVAL);
 
+	if let Err(e) = remember_repo(&repo) {
+		log::error!("remember repo: {}", e);
+	}
+
 	let mut app = App::new(
-		RefCell::new(cliargs.repo_path),
+		RefCell::new(repo),
 		&tx_git,
 		&tx_app,
-		input,
+		input.clone(),
 		theme,
 		key_config,
+		theme_file,
 	);
 
+	let tab = tab.unwrap_or_else(|| app.start_tab());
+	app.set_tab(tab)?;
+	app.update_mouse_capture()?;
+
+	if let Some(open) = open {
+		app.open_on_start(open)?;
+	}
+
 	let mut spinner = Spinner::default();
 	let mut first_update = true;
 
@@ -164,8 +270,12 @@ fn main() -> Result<()> {
 
 		{
 			if let QueueEvent::SpinnerUpdate = event {
+				// notifications time out in between ticks
+				if app.expire_toasts() {
+					draw(terminal, &app)?;
+				}
 				spinner.update();
-				spinner.draw(&mut terminal)?;
+				spinner.draw(terminal)?;
 				continue;
 			}
 
@@ -175,12 +285,21 @@ fn main() -> Result<()> {
 				QueueEvent::InputEvent(ev) => {
 					if let InputEvent::State(InputState::Polling) = ev
 					{
-						//Note: external ed closed, we need to re-hide cursor
 						terminal.hide_cursor()?;
 					}
 					app.event(ev)?;
 				}
-				QueueEvent::Tick => app.update()?,
+				QueueEvent::Tick => {
+					if let Err(error) = app.update() {
+						log::error!("refresh: {:?}", error);
+						app.show_error_retry(
+							&strings::refresh_error(&error_chain(
+								error.as_ref(),
+							)),
+							InternalEvent::Update(NeedsUpdate::ALL),
+						)?;
+					}
+				}
 				QueueEvent::AsyncEvent(ev) => {
 					if !matches!(
 						ev,
@@ -194,10 +313,19 @@ fn main() -> Result<()> {
 				QueueEvent::SpinnerUpdate => unreachable!(),
 			}
 
-			draw(&mut terminal, &app)?;
+			if config_watcher.changed() {
+				if let Err(e) = app.reload_config() {
+					log::error!("config reload: {}", e);
+					app.show_error(&strings::config_reload_error(
+						&e.to_string(),
+					))?;
+				}
+			}
+
+			draw(terminal, &app)?;
 
 			spinner.set_state(app.any_work_pending());
-			spinner.draw(&mut terminal)?;
+			spinner.draw(terminal)?;
 
 			if app.is_quit() {
 				break;
@@ -205,16 +333,25 @@ fn main() -> Result<()> {
 		}
 	}
 
-	Ok(())
+	Ok(app.quit_state())
 }
 
 fn setup_terminal() -> Result<()> {
 	enable_raw_mode()?;
 	io::stdout().execute(EnterAlternateScreen)?;
+	title_bar::save_terminal_title()?;
 	Ok(())
 }
 
 fn shutdown_terminal() {
+	if let Err(e) = input::set_mouse_capture(false) {
+		eprintln!("disable mouse capture failed:\n{}", e);
+	}
+
+	if let Err(e) = title_bar::restore_terminal_title() {
+		eprintln!("restore terminal title failed:\n{}", e);
+	}
+
 	let leave_screen =
 		io::stdout().execute(LeaveAlternateScreen).map(|_f| ());
 
@@ -246,6 +383,22 @@ fn draw<B: Backend>(
 	Ok(())
 }
 
+/// writes the cheatsheet of `gitui keys`, with the key bindings
+/// loaded the same way as when starting the ui
+fn export_keys(args: &KeysArgs) -> Result<()> {
+	let key_config = KeyConfig::init()
+		.map_err(|e| eprintln!("KeyConfig loading error: {}", e))
+		.unwrap_or_default();
+	let sheet = cheatsheet(&key_config, args.format)?;
+
+	match &args.file {
+		Some(file) => fs::write(file, sheet)?,
+		None => io::stdout().write_all(sheet.as_bytes())?,
+	}
+
+	Ok(())
+}
+
 fn valid_path(repo_path: &RepoPath) -> bool {
 	asyncgit::sync::is_repo(repo_path)
 }
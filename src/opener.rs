@@ -0,0 +1,37 @@
+use anyhow::{anyhow, Result};
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+fn exec_open(
+	command: &str,
+	args: &[&str],
+	path: &Path,
+) -> Result<()> {
+	Command::new(command)
+		.args(args)
+		.arg(path)
+		.stdin(Stdio::null())
+		.stdout(Stdio::null())
+		.stderr(Stdio::null())
+		.spawn()
+		.map_err(|e| anyhow!("`{:?}`: {}", command, e))?;
+
+	Ok(())
+}
+
+/// opens `path` with the default application of the os
+#[cfg(all(target_family = "unix", not(target_os = "macos")))]
+pub fn open_file(path: &Path) -> Result<()> {
+	exec_open("xdg-open", &[], path)
+}
+
+#[cfg(target_os = "macos")]
+pub fn open_file(path: &Path) -> Result<()> {
+	exec_open("open", &[], path)
+}
+
+#[cfg(windows)]
+pub fn open_file(path: &Path) -> Result<()> {
+	// the empty argument is the window title `start` expects first
+	exec_open("cmd", &["/C", "start", ""], path)
+}
@@ -0,0 +1,101 @@
+//! options persisted per user in `options.ron` of the config dir,
+//! shared by all repositories
+
+use crate::{
+	args::options_path,
+	components::{Confirmations, PaneLayout, StatusSort},
+	ui::style::ThemePreset,
+};
+use anyhow::Result;
+use ron::ser::{to_string_pretty, PrettyConfig};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, path::Path};
+
+/// entries missing in the file keep their defaults
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OptionsFile {
+	pub theme: ThemePreset,
+	pub status_sort: StatusSort,
+	/// by tab
+	pub layouts: BTreeMap<String, PaneLayout>,
+	pub confirmations: Confirmations,
+}
+
+impl OptionsFile {
+	/// defaults if the file is missing or can not be read
+	pub fn load() -> Self {
+		options_path()
+			.and_then(|path| Self::read(&path))
+			.unwrap_or_else(|e| {
+				log::error!("options file loading error: {}", e);
+				Self::default()
+			})
+	}
+
+	/// changes the options in the file, keeping the others as they
+	/// are on disk
+	pub fn update<F>(f: F) -> Result<()>
+	where
+		F: FnOnce(&mut Self),
+	{
+		let path = options_path()?;
+		let mut options = Self::read(&path)?;
+		f(&mut options);
+
+		fs::write(
+			path,
+			to_string_pretty(&options, PrettyConfig::default())?,
+		)?;
+
+		Ok(())
+	}
+
+	fn read(path: &Path) -> Result<Self> {
+		if !path.exists() {
+			return Ok(Self::default());
+		}
+
+		Ok(ron::from_str(&fs::read_to_string(path)?)?)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::queue::Action;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn test_missing_entries() {
+		let options: OptionsFile = ron::from_str("()").unwrap();
+		assert_eq!(options.theme, ThemePreset::Custom);
+
+		let options: OptionsFile =
+			ron::from_str("(theme: Solarized)").unwrap();
+		assert_eq!(options.theme, ThemePreset::Solarized);
+
+		let options: OptionsFile =
+			ron::from_str("(layouts: {\"log\": (vertical: true)})")
+				.unwrap();
+		assert_eq!(
+			options.layouts.get("log"),
+			Some(&PaneLayout {
+				split: None,
+				vertical: true
+			})
+		);
+
+		let options: OptionsFile =
+			ron::from_str("(confirmations: (force_push: false))")
+				.unwrap();
+		assert!(options
+			.confirmations
+			.required(&Action::StashDrop(Vec::new())));
+		assert!(!options.confirmations.required(&Action::ForcePush(
+			String::new(),
+			false,
+			String::new()
+		)));
+	}
+}
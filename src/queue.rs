@@ -1,12 +1,16 @@
 use crate::{
 	components::{
-		AppOption, BlameFileOpen, FileRevOpen, FileTreeOpen,
-		InspectCommitOpen,
+		AppOption, BlameFileOpen, CompareBranchesOpen, FileRevOpen,
+		FileTreeOpen, FileViewOpen, InspectCommitOpen,
+		TagRemoteAction,
 	},
 	tabs::StashingOptions,
 };
 use asyncgit::{
-	sync::{diff::DiffLinePosition, CommitId, TreeFile},
+	sync::{
+		diff::DiffLinePosition, BranchType, CommitId, RepoPath,
+		TreeFile,
+	},
 	PushType,
 };
 use bitflags::bitflags;
@@ -25,6 +29,8 @@ bitflags! {
 		const COMMANDS = 0b100;
 		/// branches have changed
 		const BRANCHES = 0b1000;
+		/// tags have changed
+		const TAGS = 0b1_0000;
 	}
 }
 
@@ -36,6 +42,35 @@ pub struct ResetItem {
 	pub is_folder: bool,
 }
 
+/// operation that got retried with local changes stashed around it
+pub enum AutostashOp {
+	/// checkout local branch by reference
+	Checkout(String),
+	/// checkout remote branch by name
+	CheckoutRemote(String),
+	/// rebase `HEAD` onto branch
+	Rebase(String, BranchType),
+	/// merge upstream (via rebase if `true`) after pull
+	PullMerge(bool),
+	/// checkout tag by name into a detached `HEAD`
+	CheckoutTag(String),
+}
+
+/// what the remote input popup asks for
+#[derive(Clone)]
+pub enum RemoteEdit {
+	/// name of a new remote
+	AddName,
+	/// url of the new remote with this name
+	AddUrl(String),
+	/// new name of the remote
+	Rename(String),
+	/// fetch url of the remote, starting from the current one
+	Url(String, Option<String>),
+	/// push url of the remote, starting from the current one
+	PushUrl(String, Option<String>),
+}
+
 ///
 pub enum Action {
 	Reset(ResetItem),
@@ -44,14 +79,43 @@ pub enum Action {
 	StashDrop(Vec<CommitId>),
 	StashPop(CommitId),
 	DeleteLocalBranch(String),
+	/// delete these local branches unless unmerged
+	DeleteLocalBranches(Vec<String>),
 	DeleteRemoteBranch(String),
 	DeleteTag(String),
 	DeleteRemoteTag(String, String),
-	ForcePush(String, bool),
-	PullMerge { incoming: usize, rebase: bool },
+	/// delete these local tags (matching a pattern)
+	DeleteTags(Vec<String>),
+	/// delete these tags on the remote as well
+	DeleteRemoteTags(Vec<String>, String),
+	/// branch, force and where it goes (`remote/branch`)
+	ForcePush(String, bool, String),
+	/// force push without lease after it got rejected
+	ForcePushUnsafe(String),
+	PullMerge {
+		incoming: usize,
+		rebase: bool,
+	},
 	AbortMerge,
 	AbortRebase,
 	AbortRevert,
+	AbortCherryPick,
+	AbortBisect,
+	CommitEmpty,
+	/// delete these local branches whose upstream is gone
+	PruneBranches(Vec<String>),
+	/// remove linked worktree by name
+	RemoveWorktree(String),
+	/// remove remote by name
+	RemoveRemote(String),
+	/// restore a file to its version in a commit, in the stage
+	/// instead of the workdir if `stage` is set
+	RestoreFile {
+		path: String,
+		commit_id: CommitId,
+		stage: bool,
+	},
+	Autostash(AutostashOp),
 }
 
 #[derive(Debug)]
@@ -63,9 +127,15 @@ pub enum StackablePopupOpen {
 	///
 	FileTree(FileTreeOpen),
 	///
+	FileView(FileViewOpen),
+	/// content search in a revision, the work tree if `None`
+	Grep(Option<CommitId>),
+	///
 	InspectCommit(InspectCommitOpen),
 	///
 	CompareCommits(InspectCommitOpen),
+	///
+	CompareBranches(CompareBranchesOpen),
 }
 
 ///
@@ -76,8 +146,13 @@ pub enum InternalEvent {
 	ConfirmedAction(Action),
 	///
 	ShowErrorMsg(String),
+	/// error of an operation that is started over by sending the
+	/// event again
+	ShowErrorRetry(String, Box<Self>),
 	///
 	ShowInfoMsg(String),
+	/// short notification about a finished operation
+	ShowToast(String),
 	///
 	Update(NeedsUpdate),
 	///
@@ -85,35 +160,89 @@ pub enum InternalEvent {
 	/// open commit msg input
 	OpenCommit,
 	///
-	PopupStashing(StashingOptions),
+	/// stash only the given paths unless empty
+	PopupStashing(StashingOptions, Vec<String>),
+	/// ask for the name of a branch to pop the stash onto
+	StashBranch(CommitId),
+	/// ask how much older history to fetch into a shallow clone
+	DeepenHistory,
 	///
 	TabSwitchStatus,
 	///
 	SelectCommitInRevlog(CommitId),
 	///
 	TagCommit(CommitId),
+	/// ask for the revision to tag, starting from the marked commit
+	TagRevision,
 	///
 	Tags,
+	/// ask for a pattern of tags to delete
+	DeleteMatchingTags,
 	///
 	CreateBranch,
 	///
 	RenameBranch(String, String),
+	/// edit description of local branch (name, current description)
+	EditBranchDescription(String, String),
 	///
 	SelectBranch,
 	///
+	OpenRecentBranches,
+	///
 	OpenExternalEditor(Option<String>),
+	/// suspend input polling to let gpg/ssh-keygen ask for a passphrase
+	SuspendForSigning,
+	///
+	OpenCoAuthors,
+	///
+	OpenPruneBranches,
+	/// pick how to merge branch (by name) into `HEAD`
+	OpenMergeOptions(String, BranchType),
+	/// append trailer line to the commit message
+	AddCommitTrailer(String),
+	///
+	OpenCommitMsgPicker,
+	/// load the message of this commit into the commit popup
+	ReuseCommitMessage(CommitId),
+	///
+	OpenRevisionPicker,
+	/// revision (and its name) to show in the files tab, `None` for `HEAD`
+	SelectFilesRevision(Option<(CommitId, String)>),
+	/// name of the failed hook and its output
+	ShowHookOutput(String, String),
+	///
+	CommitNoVerify,
 	///
 	Push(String, PushType, bool, bool),
+	/// pick the remote and branch name to push the branch to
+	OpenPushTarget(String),
+	/// branch, remote, branch name on the remote
+	PushTo(String, String, String),
+	/// runs the last push again after it failed
+	RetryPush,
+	/// pick the remote to push the tag to or delete it from
+	OpenTagRemote(String, TagRemoteAction),
+	/// tag, remote
+	PushTag(String, String),
 	///
 	Pull(String),
 	///
 	PushTags,
 	///
 	OptionSwitched(AppOption),
-	///
-	OpenFileFinder(Vec<TreeFile>),
+	/// restart the app to apply a changed theme or key config
+	ReloadConfig,
+	/// files of a revision to pick from, previewed as of that revision
+	OpenFileFinder(Vec<TreeFile>, Option<CommitId>),
 	///
 	FileFinderChanged(Option<PathBuf>),
+	/// open a repo relative path with the os default application, as of
+	/// a commit or in the work tree if `None`
+	OpenExternally(String, Option<CommitId>),
+	/// copy a repo relative path (and zero based line) to the clipboard
+	CopyPath(String, Option<usize>),
+	/// prune the file tree to the finder hits of this query, `None` clears
+	FileFinderFilter(Option<String>),
 	///
 	FetchRemotes,
 	///
@@ -124,6 +253,16 @@ pub enum InternalEvent {
 	PopupStackPush(StackablePopupOpen),
 	///
 	ViewSubmodules,
+	///
+	ViewWorktrees,
+	/// ask for the path of a new worktree checking out this branch
+	AddWorktree(String),
+	///
+	ViewRemotes,
+	/// ask for a name or url of a remote
+	EditRemote(RemoteEdit),
+	/// restart gitui in another repository (or worktree)
+	OpenRepo(RepoPath),
 }
 
 /// single threaded simple queue for components to communicate with each other
@@ -21,15 +21,125 @@ pub fn tabs_to_spaces(input: String) -> String {
 	}
 }
 
+/// whether a file finder query is a glob rather than a fuzzy search
+pub fn is_glob(query: &str) -> bool {
+	query.contains(['*', '?', '['])
+}
+
+/// matches `path` against a glob supporting `*`, `?`, `[...]` and
+/// `**` across directories. patterns without a `/` match the file
+/// name in any directory.
+pub fn glob_match(pattern: &str, path: &str) -> bool {
+	let path = if pattern.contains('/') {
+		path
+	} else {
+		path.rsplit('/').next().unwrap_or(path)
+	};
+
+	let pattern: Vec<char> = pattern.chars().collect();
+	let path: Vec<char> = path.chars().collect();
+
+	glob_match_chars(&pattern, &path)
+}
+
+fn glob_match_chars(pattern: &[char], path: &[char]) -> bool {
+	match pattern {
+		[] => path.is_empty(),
+		['*', '*', '/', rest @ ..] => {
+			glob_match_chars(rest, path)
+				|| path.iter().enumerate().any(|(i, c)| {
+					*c == '/'
+						&& glob_match_chars(rest, &path[i + 1..])
+				})
+		}
+		['*', '*', rest @ ..] => (0..=path.len())
+			.any(|i| glob_match_chars(rest, &path[i..])),
+		['*', rest @ ..] => {
+			let segment_end = path
+				.iter()
+				.position(|c| *c == '/')
+				.unwrap_or(path.len());
+
+			(0..=segment_end)
+				.any(|i| glob_match_chars(rest, &path[i..]))
+		}
+		['?', rest @ ..] => match path {
+			[c, path @ ..] if *c != '/' => {
+				glob_match_chars(rest, path)
+			}
+			_ => false,
+		},
+		['[', class @ ..] => {
+			// a `]` right after the opening bracket is a member
+			let end = class
+				.iter()
+				.skip(1)
+				.position(|c| *c == ']')
+				.map(|end| end + 1);
+
+			match (end, path) {
+				(Some(end), [c, path @ ..]) => {
+					class_contains(&class[..end], *c)
+						&& glob_match_chars(&class[end + 1..], path)
+				}
+				(None, ['[', path @ ..]) => {
+					glob_match_chars(class, path)
+				}
+				_ => false,
+			}
+		}
+		[p, rest @ ..] => match path {
+			[c, path @ ..] if c == p => glob_match_chars(rest, path),
+			_ => false,
+		},
+	}
+}
+
+/// `class` is the content of `[...]`, negated by a leading `!` or `^`
+fn class_contains(class: &[char], c: char) -> bool {
+	let (negated, class) = match class {
+		['!' | '^', class @ ..] if !class.is_empty() => (true, class),
+		_ => (false, class),
+	};
+
+	let mut contained = false;
+	let mut i = 0;
+	while i < class.len() {
+		if class.get(i + 1) == Some(&'-') && i + 2 < class.len() {
+			contained |= (class[i]..=class[i + 2]).contains(&c);
+			i += 3;
+		} else {
+			contained |= class[i] == c;
+			i += 1;
+		}
+	}
+
+	contained != negated
+}
+
 #[cfg(test)]
 mod test {
 	use pretty_assertions::assert_eq;
 
-	use crate::string_utils::trim_length_left;
+	use crate::string_utils::{glob_match, trim_length_left};
 
 	#[test]
 	fn test_trim() {
 		assert_eq!(trim_length_left("👍foo", 3), "foo");
 		assert_eq!(trim_length_left("👍foo", 4), "foo");
 	}
+
+	#[test]
+	fn test_glob_match() {
+		assert!(glob_match("src/**/*.rs", "src/main.rs"));
+		assert!(glob_match("src/**/*.rs", "src/components/diff.rs"));
+		assert!(!glob_match("src/**/*.rs", "asyncgit/src/lib.rs"));
+		assert!(!glob_match("src/*.rs", "src/components/diff.rs"));
+		assert!(glob_match("*.md", "docs/README.md"));
+		assert!(glob_match("**/Cargo.toml", "Cargo.toml"));
+		assert!(glob_match("src/?ab.rs", "src/tab.rs"));
+		assert!(glob_match("[a-c]*.rs", "app.rs"));
+		assert!(!glob_match("[!a-c]*.rs", "app.rs"));
+		assert!(!glob_match("*.rs", "main.rs.orig"));
+	}
 }
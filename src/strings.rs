@@ -1,4 +1,4 @@
-use asyncgit::sync::CommitId;
+use asyncgit::sync::{CommitId, RepoState};
 
 use crate::keys::SharedKeyConfig;
 
@@ -25,6 +25,19 @@ pub static PUSH_TAGS_STATES_DONE: &str = "done";
 
 pub static POPUP_TITLE_SUBMODULES: &str = "Submodules";
 pub static POPUP_TITLE_FUZZY_FIND: &str = "Fuzzy Finder";
+pub static POPUP_TITLE_COAUTHORS: &str = "Co-Authors";
+pub static POPUP_TITLE_COMMIT_MSG_PICKER: &str = "Reuse Message";
+pub static POPUP_TITLE_REVISION_PICKER: &str = "Files At Revision";
+pub static POPUP_TITLE_THEME_PICKER: &str = "Theme";
+pub static POPUP_TITLE_PRUNE_BRANCHES: &str =
+	"Branches With Gone Upstream";
+pub static POPUP_TITLE_WORKTREES: &str = "Worktrees";
+pub static POPUP_TITLE_RECENT_BRANCHES: &str = "Recent Branches";
+pub static POPUP_TITLE_REMOTES: &str = "Remotes";
+pub static POPUP_TITLE_WORKTREE_BRANCH: &str =
+	"Branch For New Worktree";
+pub static POPUP_TITLE_TOAST_HISTORY: &str = "Notifications";
+pub static POPUP_TITLE_RECENT_REPOS: &str = "Recent Repositories";
 
 pub mod symbol {
 	pub const WHITESPACE: &str = "\u{00B7}"; //·
@@ -39,9 +52,86 @@ pub mod symbol {
 pub fn title_branches() -> String {
 	"Branches".to_string()
 }
+pub fn title_pick_upstream(branch: &str) -> String {
+	format!("Pick upstream for '{}'", branch)
+}
+pub fn title_compare_branches(
+	left: &str,
+	right: &str,
+	only_left: usize,
+	only_right: usize,
+) -> String {
+	format!("{}...{} (<{} >{})", left, right, only_left, only_right)
+}
+pub fn push_popup_no_upstream_msg(
+	branch: &str,
+	remote: &str,
+	remote_branch: &str,
+) -> String {
+	format!(
+		"'{}' has no upstream.\nSet it to '{}/{}' while pushing?",
+		branch, remote, remote_branch
+	)
+}
+pub fn push_popup_title(
+	force: bool,
+	destination: Option<&str>,
+) -> String {
+	let title = if force {
+		FORCE_PUSH_POPUP_MSG
+	} else {
+		PUSH_POPUP_MSG
+	};
+	destination.map_or_else(
+		|| title.to_string(),
+		|destination| format!("{} to {}", title, destination),
+	)
+}
+pub fn auto_fetch_pending() -> String {
+	"fetching\u{2026}".to_string()
+}
+pub fn auto_fetch_last(age: &str) -> String {
+	format!("fetched {}", age)
+}
+pub fn network_retry(attempt: usize, max: usize) -> String {
+	format!("network error, retry {}/{}", attempt, max)
+}
+pub fn push_target_title(branch: &str) -> String {
+	format!("Push '{}' to", branch)
+}
+pub fn push_target_branch_title(
+	branch: &str,
+	remote: &str,
+) -> String {
+	format!("Push '{}' to '{}' as", branch, remote)
+}
+pub fn push_target_branch_msg() -> String {
+	"branch name on the remote".to_string()
+}
+pub fn pull_ff_only_msg(branch: &str) -> String {
+	format!(
+		"Cannot fast-forward '{}' to its upstream.\nPull mode is fast-forward only.",
+		branch
+	)
+}
+pub fn merge_options_title(branch: &str) -> String {
+	format!("Merge '{}'", branch)
+}
+pub fn merge_up_to_date(branch: &str) -> String {
+	format!("Already up to date with '{}'.", branch)
+}
 pub fn title_tags() -> String {
 	"Tags".to_string()
 }
+pub fn tag_filter_msg() -> String {
+	"filter by name or annotation".to_string()
+}
+pub fn tag_remote_push_title(tag: &str) -> String {
+	format!("Push tag '{}' to", tag)
+}
+pub fn tag_remote_delete_title(tag: &str) -> String {
+	format!("Delete tag '{}' from", tag)
+}
 pub fn title_status(_key_config: &SharedKeyConfig) -> String {
 	"Unstaged Changes".to_string()
 }
@@ -54,28 +144,31 @@ pub fn title_index(_key_config: &SharedKeyConfig) -> String {
 pub fn tab_status(key_config: &SharedKeyConfig) -> String {
 	format!(
 		"Status [{}]",
-		key_config.get_hint(key_config.keys.tab_status)
+		key_config.get_hint(key_config.keys().tab_status)
 	)
 }
 pub fn tab_log(key_config: &SharedKeyConfig) -> String {
-	format!("Log [{}]", key_config.get_hint(key_config.keys.tab_log))
+	format!(
+		"Log [{}]",
+		key_config.get_hint(key_config.keys().tab_log)
+	)
 }
 pub fn tab_files(key_config: &SharedKeyConfig) -> String {
 	format!(
 		"Files [{}]",
-		key_config.get_hint(key_config.keys.tab_files)
+		key_config.get_hint(key_config.keys().tab_files)
 	)
 }
 pub fn tab_stashing(key_config: &SharedKeyConfig) -> String {
 	format!(
 		"Stashing [{}]",
-		key_config.get_hint(key_config.keys.tab_stashing)
+		key_config.get_hint(key_config.keys().tab_stashing)
 	)
 }
 pub fn tab_stashes(key_config: &SharedKeyConfig) -> String {
 	format!(
 		"Stashes [{}]",
-		key_config.get_hint(key_config.keys.tab_stashes)
+		key_config.get_hint(key_config.keys().tab_stashes)
 	)
 }
 pub fn tab_divider(_key_config: &SharedKeyConfig) -> String {
@@ -84,6 +177,54 @@ pub fn tab_divider(_key_config: &SharedKeyConfig) -> String {
 pub fn cmd_splitter(_key_config: &SharedKeyConfig) -> String {
 	" ".to_string()
 }
+pub const fn repo_state_name(state: &RepoState) -> &'static str {
+	match state {
+		RepoState::Clean => "",
+		RepoState::Merge => "merge",
+		RepoState::Rebase => "rebase",
+		RepoState::Revert => "revert",
+		RepoState::CherryPick => "cherry-pick",
+		RepoState::Bisect => "bisect",
+		RepoState::Other => "operation",
+	}
+}
+pub fn title_bar_splitter(_key_config: &SharedKeyConfig) -> String {
+	" | ".to_string()
+}
+pub fn title_bar_stashes(count: usize) -> String {
+	format!("stashes: {}", count)
+}
+pub fn terminal_title(
+	repo_name: &str,
+	branch: Option<&str>,
+	state: &RepoState,
+) -> String {
+	let branch = branch
+		.map(|branch| format!(" ({})", branch))
+		.unwrap_or_default();
+	let state = if *state == RepoState::Clean {
+		String::new()
+	} else {
+		format!(" [{}]", repo_state_name(state))
+	};
+
+	format!("gitui: {}{}{}", repo_name, branch, state)
+}
+pub fn cmd_group_splitter(_key_config: &SharedKeyConfig) -> String {
+	" | ".to_string()
+}
+pub fn cmd_page(
+	key_config: &SharedKeyConfig,
+	page: usize,
+	pages: usize,
+) -> String {
+	format!(
+		"{}/{} [{}]",
+		page + 1,
+		pages,
+		key_config.get_hint(key_config.keys().cmd_bar_toggle)
+	)
+}
 pub fn msg_opening_editor(_key_config: &SharedKeyConfig) -> String {
 	"opening editor...".to_string()
 }
@@ -103,12 +244,143 @@ pub fn commit_title_merge() -> String {
 pub fn commit_title_revert() -> String {
 	"Commit (Revert)".to_string()
 }
+pub fn commit_title_cherrypick() -> String {
+	"Commit (Cherry-pick)".to_string()
+}
+pub fn commit_title_empty() -> String {
+	"Commit (Empty)".to_string()
+}
 pub fn commit_title_amend() -> String {
 	"Commit (Amend)".to_string()
 }
 pub fn commit_msg(_key_config: &SharedKeyConfig) -> String {
 	"type commit message..".to_string()
 }
+pub fn commit_signoff_indicator() -> String {
+	"[signed-off]".to_string()
+}
+pub fn commit_author_label() -> String {
+	"Author: ".to_string()
+}
+pub fn commit_date_label() -> String {
+	"Date:   ".to_string()
+}
+pub fn coauthors_popup_msg() -> String {
+	"name <email>..".to_string()
+}
+pub fn commit_msg_picker_msg() -> String {
+	"search commits..".to_string()
+}
+pub fn files_filtered(query: &str) -> String {
+	format!(" filtered by '{}'", query)
+}
+pub fn config_reload_error(error: &str) -> String {
+	format!("reloading theme or key config failed, keeping the previous one:\n{}", error)
+}
+pub fn file_find_preview_title(changes: bool) -> String {
+	if changes {
+		"Changes".to_string()
+	} else {
+		"Preview".to_string()
+	}
+}
+pub fn grep_popup_title(revision: Option<&str>) -> String {
+	format!("Search Contents [{}]", revision.unwrap_or("work tree"))
+}
+pub fn grep_popup_msg() -> String {
+	"search file contents..".to_string()
+}
+pub fn grep_searching() -> String {
+	"Searching..".to_string()
+}
+pub fn grep_results(count: usize, truncated: bool) -> String {
+	format!("Matches: {}{}", count, if truncated { "+" } else { "" })
+}
+pub fn log_viewer_title(level: &str) -> String {
+	format!("gitui log (up to {})", level)
+}
+pub fn log_viewer_off(path: &str) -> String {
+	format!("logging is off, start gitui with -l to write {}", path)
+}
+pub fn log_viewer_empty(path: &str) -> String {
+	format!("nothing logged to {}", path)
+}
+pub fn repo_switcher_empty(key_config: &SharedKeyConfig) -> String {
+	format!(
+		"no other repository opened yet, browse for one with [{}]",
+		key_config.get_hint(key_config.keys().repo_switcher_browse)
+	)
+}
+pub fn toast_log_copied(lines: usize) -> String {
+	format!("copied {} log lines", lines)
+}
+pub fn toast_error_copied() -> String {
+	"copied error report".to_string()
+}
+/// error popup contents as pasted into a bug report
+pub fn error_report(title: &str, msg: &str) -> String {
+	format!(
+		"{}\n\n{}\n\ngitui {} ({})",
+		title,
+		msg,
+		env!("CARGO_PKG_VERSION"),
+		std::env::consts::OS
+	)
+}
+pub fn refresh_error(err: &str) -> String {
+	format!("refresh failed:\n{}", err)
+}
+pub fn toast_history_empty() -> String {
+	"no notifications yet".to_string()
+}
+pub fn toast_pushed(destination: Option<&str>) -> String {
+	destination.map_or_else(
+		|| "pushed".to_string(),
+		|destination| format!("pushed to {}", destination),
+	)
+}
+pub fn toast_pushed_tags() -> String {
+	"pushed tags".to_string()
+}
+pub fn toast_fetched() -> String {
+	"fetched all remotes".to_string()
+}
+pub fn toast_auto_fetched(behind: usize) -> String {
+	format!("background fetch: {} new commits upstream", behind)
+}
+pub fn toast_pulled(branch: &str) -> String {
+	format!("fast-forwarded '{}'", branch)
+}
+pub fn toast_up_to_date(branch: &str) -> String {
+	format!("'{}' is up to date", branch)
+}
+pub fn toast_stash_saved() -> String {
+	"stash saved".to_string()
+}
+pub fn toast_branch_created(name: &str) -> String {
+	format!("created branch '{}'", name)
+}
+pub fn toast_tag_created(name: &str) -> String {
+	format!("created tag '{}'", name)
+}
+pub fn revision_picker_msg() -> String {
+	"branch, tag or commit..".to_string()
+}
+pub fn revision_picker_query(query: &str) -> String {
+	format!("revision '{}'", query)
+}
+pub fn revision_picker_invalid(revision: &str, err: &str) -> String {
+	format!("cannot resolve revision '{}':\n{}", revision, err)
+}
+pub fn hook_failed_title(hook: &str) -> String {
+	format!("{} hook failed", hook)
+}
+pub fn hook_skip_hint(key_config: &SharedKeyConfig) -> String {
+	format!(
+		"[{}] commit anyway without running hooks (--no-verify)",
+		key_config.get_hint(key_config.keys().commit_no_verify),
+	)
+}
 pub fn commit_first_line_warning(count: usize) -> String {
 	format!("[subject length: {}]", count)
 }
@@ -127,6 +399,28 @@ pub fn stash_popup_title(_key_config: &SharedKeyConfig) -> String {
 pub fn stash_popup_msg(_key_config: &SharedKeyConfig) -> String {
 	"type name (optional)".to_string()
 }
+pub fn stash_branch_popup_title(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"Stash Branch".to_string()
+}
+pub fn stash_branch_popup_msg(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"branch name".to_string()
+}
+pub fn deepen_popup_title() -> String {
+	"Fetch Older History".to_string()
+}
+pub fn deepen_popup_msg() -> String {
+	"number of commits (empty for all)".to_string()
+}
+pub fn deepen_popup_pending() -> String {
+	"Fetching Older History...".to_string()
+}
+pub fn shallow_boundary() -> String {
+	" [shallow: older history not fetched]".to_string()
+}
 pub fn confirm_title_reset() -> String {
 	"Reset".to_string()
 }
@@ -173,6 +467,132 @@ pub fn confirm_msg_revertchanges() -> String {
 	"This will revert all uncommitted changes. Are you sure?"
 		.to_string()
 }
+pub fn confirm_title_abortcherrypick() -> String {
+	"Abort cherry-pick?".to_string()
+}
+pub fn confirm_title_abortbisect() -> String {
+	"Reset bisect?".to_string()
+}
+pub fn confirm_msg_abortbisect() -> String {
+	"This will end the bisect session and check out the commit it was started from. Are you sure?"
+		.to_string()
+}
+pub fn confirm_title_delete_branches() -> String {
+	"Delete Branches".to_string()
+}
+pub fn confirm_msg_delete_branches(branches: &[String]) -> String {
+	format!(
+		"Confirm deleting {} marked branch(es)? Unmerged ones are kept:\n{}",
+		branches.len(),
+		branches.join(", ")
+	)
+}
+pub fn confirm_title_prune_branches() -> String {
+	"Delete branches?".to_string()
+}
+pub fn confirm_msg_prune_branches(branches: &[String]) -> String {
+	format!(
+		"Confirm deleting {} local branch(es) whose upstream is gone:\n{}",
+		branches.len(),
+		branches.join(", ")
+	)
+}
+pub fn prune_branches_none() -> String {
+	"No local branch tracks an upstream that is gone.".to_string()
+}
+pub fn delete_branches_summary(
+	deleted: &[String],
+	unmerged: &[String],
+	failed: &[(String, String)],
+) -> String {
+	let mut lines =
+		vec![format!("deleted {} branch(es)", deleted.len())];
+	lines.extend(deleted.iter().cloned());
+	if !unmerged.is_empty() {
+		lines.push(format!(
+			"\nrefused {} unmerged branch(es):",
+			unmerged.len()
+		));
+		lines.extend(unmerged.iter().cloned());
+	}
+	if !failed.is_empty() {
+		lines.push(format!("\nfailed to delete {}:", failed.len()));
+		lines.extend(
+			failed.iter().map(|(branch, error)| {
+				format!("{}: {}", branch, error)
+			}),
+		);
+	}
+	lines.join("\n")
+}
+pub fn recent_branches_none() -> String {
+	"No other branch was checked out recently.".to_string()
+}
+pub fn confirm_title_remove_worktree() -> String {
+	"Remove worktree?".to_string()
+}
+pub fn confirm_msg_remove_worktree(name: &str) -> String {
+	format!(
+		"Confirm removing worktree '{}' including its directory?",
+		name
+	)
+}
+pub fn confirm_title_restore_file() -> String {
+	"Restore file?".to_string()
+}
+pub fn confirm_msg_restore_file(
+	path: &str,
+	commit: &str,
+	stage: bool,
+) -> String {
+	format!(
+		"Confirm restoring '{}' in the {} to its version in {}? Your {} changes to it are lost.",
+		path,
+		if stage { "stage" } else { "workdir" },
+		commit,
+		if stage { "staged" } else { "unstaged" },
+	)
+}
+pub fn confirm_title_remove_remote() -> String {
+	"Remove remote?".to_string()
+}
+pub fn confirm_msg_remove_remote(name: &str) -> String {
+	format!(
+		"Confirm removing remote '{}' and its remote tracking branches?",
+		name
+	)
+}
+pub fn remotes_none() -> String {
+	"No remotes configured.".to_string()
+}
+pub fn remote_add_title() -> String {
+	"Add Remote".to_string()
+}
+pub fn remote_rename_title(name: &str) -> String {
+	format!("Rename Remote: {}", name)
+}
+pub fn remote_url_title(name: &str) -> String {
+	format!("Url: {}", name)
+}
+pub fn remote_push_url_title(name: &str) -> String {
+	format!("Push Url: {}", name)
+}
+pub fn remote_name_msg() -> String {
+	"remote name".to_string()
+}
+pub fn remote_url_msg() -> String {
+	"url to fetch from (and push to)".to_string()
+}
+pub fn remote_push_url_msg() -> String {
+	"url to push to (empty to push to the fetch url)".to_string()
+}
+pub fn worktrees_pruned(count: usize) -> String {
+	format!("pruned {} stale worktree(s)", count)
+}
+pub fn worktree_no_free_branch() -> String {
+	"Every local branch is checked out in a worktree already."
+		.to_string()
+}
 pub fn confirm_title_abortrebase() -> String {
 	"Abort rebase?".to_string()
 }
@@ -180,6 +600,48 @@ pub fn confirm_msg_abortrebase() -> String {
 	"This will revert all uncommitted changes. Are you sure?"
 		.to_string()
 }
+pub fn confirm_title_commit_empty() -> String {
+	"Empty commit".to_string()
+}
+pub fn confirm_msg_commit_empty() -> String {
+	"Nothing is staged. Create an empty commit anyway (--allow-empty)?"
+		.to_string()
+}
+pub fn confirm_title_autostash() -> String {
+	"Uncommitted changes".to_string()
+}
+pub fn confirm_msg_autostash(operation: &str) -> String {
+	format!(
+		"Local changes prevent {}.\n\nStash them, retry and re-apply them afterwards?",
+		operation
+	)
+}
+pub fn autostash_kept_msg(
+	stash: &str,
+	conflicts: &[String],
+) -> String {
+	let mut lines = vec![format!(
+		"local changes could not be re-applied cleanly.\nthey are kept in stash {}",
+		stash
+	)];
+	if !conflicts.is_empty() {
+		lines.push(format!(
+			"\nconflicting with the new HEAD on {} file(s):",
+			conflicts.len()
+		));
+		lines.extend(conflicts.iter().cloned());
+	}
+	lines.push(String::from(
+		"\napply it from the stashes tab once resolved",
+	));
+	lines.join("\n")
+}
+pub fn autostash_pending_msg(stash: &str) -> String {
+	format!(
+		"local changes are kept in stash {}\n\npop it from the stashes tab once the merge or rebase is resolved",
+		stash
+	)
+}
 pub fn confirm_msg_reset() -> String {
 	"confirm file reset?".to_string()
 }
@@ -255,6 +717,51 @@ pub fn confirm_title_delete_tag_remote() -> String {
 pub fn confirm_msg_delete_tag_remote(remote_name: &str) -> String {
 	format!("Confirm deleting tag on remote '{}'?", remote_name)
 }
+pub fn confirm_title_delete_tags() -> String {
+	"Delete Tags".to_string()
+}
+pub fn confirm_msg_delete_tags(tags: &[String]) -> String {
+	format!(
+		"Confirm deleting {} matching tag(s)?\n{}",
+		tags.len(),
+		tags.join(", ")
+	)
+}
+pub fn confirm_msg_delete_tags_remote(
+	tags: &[String],
+	remote_name: &str,
+) -> String {
+	format!(
+		"Confirm deleting {} tag(s) on remote '{}'?",
+		tags.len(),
+		remote_name
+	)
+}
+pub fn delete_tags_popup_title() -> String {
+	"Delete Tags".to_string()
+}
+pub fn delete_tags_popup_msg() -> String {
+	"pattern, e.g. nightly-*".to_string()
+}
+pub fn delete_tags_none(pattern: &str) -> String {
+	format!("No tag matches '{}'.", pattern)
+}
+pub fn delete_tags_summary(
+	deleted: &[String],
+	failed: &[(String, String)],
+) -> String {
+	let mut lines = vec![format!("deleted {} tag(s)", deleted.len())];
+	lines.extend(deleted.iter().cloned());
+	if !failed.is_empty() {
+		lines.push(format!("\nfailed to delete {}:", failed.len()));
+		lines.extend(
+			failed
+				.iter()
+				.map(|(tag, error)| format!("{}: {}", tag, error)),
+		);
+	}
+	lines.join("\n")
+}
 pub fn confirm_title_force_push(
 	_key_config: &SharedKeyConfig,
 ) -> String {
@@ -262,13 +769,19 @@ pub fn confirm_title_force_push(
 }
 pub fn confirm_msg_force_push(
 	_key_config: &SharedKeyConfig,
-	branch_ref: &str,
+	destination: &str,
 ) -> String {
 	format!(
-        "Confirm force push to branch '{}' ?  This may rewrite history.",
-        branch_ref
+        "Confirm force push to '{}' ?  This may rewrite history.\nIt fails if the remote branch changed since the last fetch.",
+        destination
     )
 }
+pub fn confirm_msg_force_push_unsafe(branch: &str) -> String {
+	format!(
+		"Remote branch '{}' changed since the last fetch.\nForce push anyway, dropping the remote changes?",
+		branch
+	)
+}
 pub fn log_title(_key_config: &SharedKeyConfig) -> String {
 	"Commit".to_string()
 }
@@ -282,26 +795,120 @@ pub fn file_log_title(
 		file_path, selected, revisions
 	)
 }
+pub fn file_log_compare(first: &str, second: &str) -> String {
+	format!(" -- comparing {} and {}", first, second)
+}
+pub fn file_log_restore_preview(stage: bool) -> String {
+	format!(
+		" -- restore preview ({})",
+		if stage { "stage" } else { "workdir" }
+	)
+}
+pub fn file_log_renamed_from(file_path: &str) -> String {
+	format!(" (renamed from '{}')", file_path)
+}
+pub fn file_view_title(file_path: &str, commit: &str) -> String {
+	format!("{} @ {}", file_path, commit)
+}
+pub fn file_view_search_title() -> String {
+	"Search".to_string()
+}
+pub fn file_view_search_msg() -> String {
+	"text to find in the file".to_string()
+}
+pub fn file_view_matches(
+	search: &str,
+	current: Option<usize>,
+	total: usize,
+) -> String {
+	match (current, total) {
+		(_, 0) => format!(" -- '{}' not found", search),
+		(Some(current), total) => {
+			format!(" -- '{}' ({}/{})", search, current, total)
+		}
+		(None, total) => format!(" -- '{}' ({})", search, total),
+	}
+}
 pub fn blame_title(_key_config: &SharedKeyConfig) -> String {
 	"Blame".to_string()
 }
+pub fn blame_no_parent(commit: &str) -> String {
+	format!("The line was introduced in {}, there is no earlier version to blame.", commit)
+}
 pub fn tag_popup_name_title() -> String {
 	"Tag".to_string()
 }
 pub fn tag_popup_name_msg() -> String {
 	"type tag name".to_string()
 }
+pub fn tag_popup_revision_title() -> String {
+	"Tag Revision".to_string()
+}
+pub fn tag_popup_revision_msg() -> String {
+	"type revision to tag (hash, branch, HEAD~2, ...)".to_string()
+}
 pub fn tag_popup_annotation_title(name: &str) -> String {
 	format!("Tag Annotation ({})", name)
 }
 pub fn tag_popup_annotation_msg() -> String {
 	"type tag annotation".to_string()
 }
+pub fn tag_popup_sign_indicator() -> String {
+	"[signed]".to_string()
+}
+pub fn detached_head_title() -> String {
+	"Detached HEAD".to_string()
+}
+pub fn detached_head_msg(head: &str) -> String {
+	format!("HEAD detached at {}", head)
+}
+pub fn tag_details_title() -> String {
+	"Tag Details".to_string()
+}
+pub fn tag_details_tagger() -> String {
+	"Tagger: ".to_string()
+}
+pub fn tag_details_target() -> String {
+	"Target: ".to_string()
+}
+pub fn tag_details_lightweight() -> String {
+	"lightweight tag".to_string()
+}
+pub fn tag_signature_good(signer: &str) -> String {
+	format!("Good signature from {}", signer)
+}
+pub fn tag_signature_bad(reason: &str) -> String {
+	format!("Bad signature: {}", reason)
+}
+pub fn tag_signature_unsigned() -> String {
+	"Not signed".to_string()
+}
 pub fn stashlist_title(_key_config: &SharedKeyConfig) -> String {
 	"Stashes".to_string()
 }
-pub fn help_title(_key_config: &SharedKeyConfig) -> String {
-	"Help: all commands".to_string()
+pub fn stashlist_filter_msg(_key_config: &SharedKeyConfig) -> String {
+	"filter by message or branch".to_string()
+}
+pub fn stashlist_title_index(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"Stashes (apply with index)".to_string()
+}
+pub fn help_title(
+	_key_config: &SharedKeyConfig,
+	show_all: bool,
+) -> String {
+	if show_all {
+		"Help: all commands".to_string()
+	} else {
+		"Help: current context".to_string()
+	}
+}
+pub fn help_filter_msg() -> String {
+	"type to search commands..".to_string()
+}
+pub fn help_no_match() -> String {
+	"no matching commands".to_string()
 }
 pub fn stashing_files_title(_key_config: &SharedKeyConfig) -> String {
 	"Files to Stash".to_string()
@@ -347,6 +954,29 @@ pub fn rename_branch_popup_msg(
 ) -> String {
 	"new branch name".to_string()
 }
+pub fn add_worktree_popup_title(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"Add Worktree".to_string()
+}
+pub fn add_worktree_popup_msg(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"path of the new worktree".to_string()
+}
+pub fn branch_description_panel_title() -> String {
+	"Description".to_string()
+}
+pub fn branch_description_popup_title(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"Branch Description".to_string()
+}
+pub fn branch_description_popup_msg(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"describe the branch (empty to remove)".to_string()
+}
 
 pub mod commit {
 	use crate::keys::SharedKeyConfig;
@@ -390,6 +1020,9 @@ pub mod commit {
 	) -> String {
 		"Files:".to_string()
 	}
+	pub fn details_files_untracked(count: usize) -> String {
+		format!("({} untracked)", count)
+	}
 }
 
 pub mod commands {
@@ -409,7 +1042,7 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Next [{}]",
-				key_config.get_hint(key_config.keys.tab_toggle)
+				key_config.get_hint(key_config.keys().tab_toggle)
 			),
 			"switch to next tab",
 			CMD_GROUP_GENERAL,
@@ -419,7 +1052,7 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Find [{}]",
-				key_config.get_hint(key_config.keys.file_find)
+				key_config.get_hint(key_config.keys().file_find)
 			),
 			"find file in tree",
 			CMD_GROUP_GENERAL,
@@ -431,11 +1064,11 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Tab [{}{}{}{}{}]",
-				key_config.get_hint(key_config.keys.tab_status),
-				key_config.get_hint(key_config.keys.tab_log),
-				key_config.get_hint(key_config.keys.tab_files),
-				key_config.get_hint(key_config.keys.tab_stashing),
-				key_config.get_hint(key_config.keys.tab_stashes),
+				key_config.get_hint(key_config.keys().tab_status),
+				key_config.get_hint(key_config.keys().tab_log),
+				key_config.get_hint(key_config.keys().tab_files),
+				key_config.get_hint(key_config.keys().tab_stashing),
+				key_config.get_hint(key_config.keys().tab_stashes),
 			),
 			"switch top level tabs directly",
 			CMD_GROUP_GENERAL,
@@ -447,7 +1080,7 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Options [{}]",
-				key_config.get_hint(key_config.keys.open_options),
+				key_config.get_hint(key_config.keys().open_options),
 			),
 			"open options popup",
 			CMD_GROUP_GENERAL,
@@ -457,7 +1090,7 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Help [{}]",
-				key_config.get_hint(key_config.keys.open_help)
+				key_config.get_hint(key_config.keys().open_help)
 			),
 			"open this help screen",
 			CMD_GROUP_GENERAL,
@@ -469,8 +1102,8 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Nav [{}{}]",
-				key_config.get_hint(key_config.keys.move_up),
-				key_config.get_hint(key_config.keys.move_down)
+				key_config.get_hint(key_config.keys().move_up),
+				key_config.get_hint(key_config.keys().move_down)
 			),
 			"navigate commit message",
 			CMD_GROUP_GENERAL,
@@ -482,10 +1115,10 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Nav [{}{}{}{}]",
-				key_config.get_hint(key_config.keys.move_up),
-				key_config.get_hint(key_config.keys.move_down),
-				key_config.get_hint(key_config.keys.move_right),
-				key_config.get_hint(key_config.keys.move_left)
+				key_config.get_hint(key_config.keys().move_up),
+				key_config.get_hint(key_config.keys().move_down),
+				key_config.get_hint(key_config.keys().move_right),
+				key_config.get_hint(key_config.keys().move_left)
 			),
 			"navigate tree view, collapse, expand",
 			CMD_GROUP_GENERAL,
@@ -495,8 +1128,8 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Scroll [{}{}]",
-				key_config.get_hint(key_config.keys.focus_above),
-				key_config.get_hint(key_config.keys.focus_below)
+				key_config.get_hint(key_config.keys().focus_above),
+				key_config.get_hint(key_config.keys().focus_below)
 			),
 			"scroll up or down in focused view",
 			CMD_GROUP_GENERAL,
@@ -510,7 +1143,8 @@ pub mod commands {
 			format!(
 				"{} [{}]",
 				if marked { "Unmark" } else { "Mark" },
-				key_config.get_hint(key_config.keys.log_mark_commit),
+				key_config
+					.get_hint(key_config.keys().log_mark_commit),
 			),
 			"mark multiple commits",
 			CMD_GROUP_GENERAL,
@@ -520,17 +1154,54 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Copy [{}]",
-				key_config.get_hint(key_config.keys.copy),
+				key_config.get_hint(key_config.keys().copy),
 			),
 			"copy selected lines to clipboard",
 			CMD_GROUP_DIFF,
 		)
 	}
+	pub fn copy_path(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Copy Path [{}]",
+				key_config.get_hint(key_config.keys().copy_path),
+			),
+			"copy the path of the selected file to clipboard",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn open_externally(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Open Externally [{}]",
+				key_config
+					.get_hint(key_config.keys().open_externally),
+			),
+			"open the selected file with the default application",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn tree_dir_sizes(
+		key_config: &SharedKeyConfig,
+		shown: bool,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"{} Dir Sizes [{}]",
+				if shown { "Hide" } else { "Show" },
+				key_config.get_hint(key_config.keys().tree_dir_sizes),
+			),
+			"toggle the cumulative size of directories",
+			CMD_GROUP_GENERAL,
+		)
+	}
 	pub fn copy_hash(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
 				"Copy Hash [{}]",
-				key_config.get_hint(key_config.keys.copy),
+				key_config.get_hint(key_config.keys().copy),
 			),
 			"copy selected commit hash to clipboard",
 			CMD_GROUP_LOG,
@@ -540,281 +1211,927 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Push Tags [{}]",
-				key_config.get_hint(key_config.keys.push),
+				key_config.get_hint(key_config.keys().push),
 			),
-			"push tags to remote",
+			"push all tags missing on remote",
 			CMD_GROUP_LOG,
 		)
 	}
-	pub fn show_tag_annotation(
-		key_config: &SharedKeyConfig,
-	) -> CommandText {
+	pub fn push_tag(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
-				"Annotation [{}]",
-				key_config.get_hint(key_config.keys.move_right),
+				"Push Tag [{}]",
+				key_config.get_hint(key_config.keys().tag_push),
 			),
-			"show tag annotation",
+			"push selected tag to remote",
 			CMD_GROUP_LOG,
 		)
 	}
-	pub fn diff_home_end(
+	pub fn tag_delete_remote(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Jump up/down [{},{},{},{}]",
-				key_config.get_hint(key_config.keys.home),
-				key_config.get_hint(key_config.keys.end),
-				key_config.get_hint(key_config.keys.move_up),
-				key_config.get_hint(key_config.keys.move_down)
+				"Delete On Remote [{}]",
+				key_config
+					.get_hint(key_config.keys().tag_delete_remote),
 			),
-			"scroll to top or bottom of diff",
-			CMD_GROUP_DIFF,
+			"delete selected tag from a remote",
+			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn diff_hunk_add(
-		key_config: &SharedKeyConfig,
-	) -> CommandText {
+	pub fn tag_filter(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
-				"Add hunk [{}]",
-				key_config
-					.get_hint(key_config.keys.stage_unstage_item),
+				"Filter [{}]",
+				key_config.get_hint(key_config.keys().tag_filter),
 			),
-			"adds selected hunk to stage",
-			CMD_GROUP_DIFF,
+			"filter tags by name or annotation",
+			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn diff_hunk_revert(
+	pub fn tag_remote_confirm(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Select [{}]",
+				key_config.get_hint(key_config.keys().enter),
+			),
+			"use the selected remote",
+			CMD_GROUP_GENERAL,
+		)
+		.hide_help()
+	}
+	pub fn show_tag_annotation(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Annotation [{}]",
+				key_config.get_hint(key_config.keys().move_right),
+			),
+			"show tag annotation",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn diff_home_end(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Jump up/down [{},{},{},{}]",
+				key_config.get_hint(key_config.keys().home),
+				key_config.get_hint(key_config.keys().end),
+				key_config.get_hint(key_config.keys().move_up),
+				key_config.get_hint(key_config.keys().move_down)
+			),
+			"scroll to top or bottom of diff",
+			CMD_GROUP_DIFF,
+		)
+	}
+	pub fn diff_hunk_add(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Add hunk [{}]",
+				key_config
+					.get_hint(key_config.keys().stage_unstage_item),
+			),
+			"adds selected hunk to stage",
+			CMD_GROUP_DIFF,
+		)
+	}
+	pub fn diff_hunk_revert(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
 				"Reset hunk [{}]",
 				key_config
-					.get_hint(key_config.keys.status_reset_item),
+					.get_hint(key_config.keys().status_reset_item),
+			),
+			"reverts selected hunk",
+			CMD_GROUP_DIFF,
+		)
+	}
+	pub fn diff_lines_revert(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Reset lines [{}]",
+				key_config
+					.get_hint(key_config.keys().diff_reset_lines),
+			),
+			"resets selected lines",
+			CMD_GROUP_DIFF,
+		)
+	}
+	pub fn diff_lines_stage(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Stage lines [{}]",
+				key_config
+					.get_hint(key_config.keys().diff_stage_lines),
+			),
+			"stage selected lines",
+			CMD_GROUP_DIFF,
+		)
+	}
+	pub fn diff_lines_unstage(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Unstage lines [{}]",
+				key_config
+					.get_hint(key_config.keys().diff_stage_lines),
+			),
+			"unstage selected lines",
+			CMD_GROUP_DIFF,
+		)
+	}
+	pub fn diff_hunk_remove(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Remove hunk [{}]",
+				key_config
+					.get_hint(key_config.keys().stage_unstage_item),
+			),
+			"removes selected hunk from stage",
+			CMD_GROUP_DIFF,
+		)
+	}
+	pub fn close_popup(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Close [{}]",
+				key_config.get_hint(key_config.keys().exit_popup),
+			),
+			"close overlay (e.g commit, help)",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn close_msg(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Close [{}]",
+				key_config.get_hint(key_config.keys().enter),
+			),
+			"close msg popup (e.g msg)",
+			CMD_GROUP_GENERAL,
+		)
+		.hide_help()
+	}
+	pub fn validate_msg(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Validate [{}]",
+				key_config.get_hint(key_config.keys().enter),
+			),
+			"validate msg",
+			CMD_GROUP_GENERAL,
+		)
+		.hide_help()
+	}
+
+	pub fn abort_merge(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Abort merge [{}]",
+				key_config.get_hint(key_config.keys().abort_merge),
+			),
+			"abort ongoing merge",
+			CMD_GROUP_GENERAL,
+		)
+	}
+
+	pub fn abort_revert(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Abort revert [{}]",
+				key_config.get_hint(key_config.keys().abort_merge),
+			),
+			"abort ongoing revert",
+			CMD_GROUP_GENERAL,
+		)
+	}
+
+	pub fn view_submodules(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Submodules [{}]",
+				key_config
+					.get_hint(key_config.keys().view_submodules),
+			),
+			"open submodule view",
+			CMD_GROUP_GENERAL,
+		)
+	}
+
+	pub fn view_worktrees(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Worktrees [{}]",
+				key_config.get_hint(key_config.keys().view_worktrees),
+			),
+			"open worktree view",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn view_remotes(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Remotes [{}]",
+				key_config.get_hint(key_config.keys().view_remotes),
+			),
+			"open remotes view",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn remote_add(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Add [{}]",
+				key_config.get_hint(key_config.keys().remote_add),
+			),
+			"add a new remote",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn remote_remove(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Remove [{}]",
+				key_config.get_hint(key_config.keys().remote_remove),
+			),
+			"remove selected remote",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn remote_rename(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Rename [{}]",
+				key_config.get_hint(key_config.keys().remote_rename),
+			),
+			"rename selected remote",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn remote_edit_url(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Edit Url [{}]",
+				key_config
+					.get_hint(key_config.keys().remote_edit_url),
+			),
+			"edit url of selected remote",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn remote_edit_push_url(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Edit Push Url [{}]",
+				key_config
+					.get_hint(key_config.keys().remote_edit_push_url),
+			),
+			"edit push url of selected remote",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn remote_edit_confirm(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Confirm [{}]",
+				key_config.get_hint(key_config.keys().enter),
+			),
+			"save remote",
+			CMD_GROUP_GENERAL,
+		)
+		.hide_help()
+	}
+	pub fn push_target_confirm(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Push [{}]",
+				key_config.get_hint(key_config.keys().enter),
+			),
+			"push to the selected remote",
+			CMD_GROUP_GENERAL,
+		)
+		.hide_help()
+	}
+	pub fn worktree_switch(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Switch [{}]",
+				key_config.get_hint(key_config.keys().enter),
+			),
+			"reopen gitui in the selected worktree",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn worktree_add(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Add [{}]",
+				key_config.get_hint(key_config.keys().worktree_add),
+			),
+			"check out a branch into a new worktree",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn worktree_pick_branch(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Pick Branch [{}]",
+				key_config.get_hint(key_config.keys().enter),
+			),
+			"check out selected branch into a new worktree",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn worktree_add_confirm(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Add Worktree [{}]",
+				key_config.get_hint(key_config.keys().enter),
+			),
+			"create worktree at path",
+			CMD_GROUP_GENERAL,
+		)
+		.hide_help()
+	}
+	pub fn worktree_remove(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Remove [{}]",
+				key_config
+					.get_hint(key_config.keys().worktree_remove),
+			),
+			"remove worktree and its directory",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn worktree_prune(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Prune [{}]",
+				key_config.get_hint(key_config.keys().worktree_prune),
+			),
+			"forget worktrees whose directory is gone",
+			CMD_GROUP_GENERAL,
+		)
+	}
+
+	pub fn continue_rebase(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Continue rebase [{}]",
+				key_config.get_hint(key_config.keys().rebase_branch),
+			),
+			"continue ongoing rebase",
+			CMD_GROUP_GENERAL,
+		)
+	}
+
+	pub fn skip_rebase(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Skip step [{}]",
+				key_config.get_hint(key_config.keys().skip_rebase),
+			),
+			"drop the current rebase step and continue",
+			CMD_GROUP_GENERAL,
+		)
+	}
+
+	pub fn abort_cherrypick(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Abort cherry-pick [{}]",
+				key_config.get_hint(key_config.keys().abort_merge),
+			),
+			"abort ongoing cherry-pick",
+			CMD_GROUP_GENERAL,
+		)
+	}
+
+	pub fn abort_bisect(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Reset bisect [{}]",
+				key_config.get_hint(key_config.keys().abort_merge),
+			),
+			"end ongoing bisect session",
+			CMD_GROUP_GENERAL,
+		)
+	}
+
+	pub fn abort_rebase(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Abort rebase [{}]",
+				key_config.get_hint(key_config.keys().abort_merge),
+			),
+			"abort ongoing rebase",
+			CMD_GROUP_GENERAL,
+		)
+	}
+
+	pub fn select_staging(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"To stage [{}]",
+				key_config
+					.get_hint(key_config.keys().toggle_workarea),
+			),
+			"focus/select staging area",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn select_unstaged(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"To unstaged [{}]",
+				key_config
+					.get_hint(key_config.keys().toggle_workarea),
+			),
+			"focus/select unstaged area",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn undo_commit(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Undo Commit [{}]",
+				key_config.get_hint(key_config.keys().undo_commit),
+			),
+			"undo last commit",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn commit_open(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Commit [{}]",
+				key_config.get_hint(key_config.keys().open_commit),
+			),
+			"open commit popup (available in non-empty stage)",
+			CMD_GROUP_COMMIT,
+		)
+	}
+	pub fn commit_empty(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Commit empty [{}]",
+				key_config.get_hint(key_config.keys().open_commit),
+			),
+			"create a commit without staged changes (like --allow-empty)",
+			CMD_GROUP_COMMIT,
+		)
+	}
+	pub fn commit_open_editor(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Open editor [{}]",
+				key_config
+					.get_hint(key_config.keys().open_commit_editor),
+			),
+			"open commit editor (available in commit popup)",
+			CMD_GROUP_COMMIT,
+		)
+	}
+	pub fn commit_enter(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Commit [{}]",
+				key_config.get_hint(key_config.keys().enter),
+			),
+			"commit (available when commit message is non-empty)",
+			CMD_GROUP_COMMIT,
+		)
+		.hide_help()
+	}
+	pub fn commit_amend(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Amend [{}]",
+				key_config.get_hint(key_config.keys().commit_amend),
+			),
+			"amend last commit (available in commit popup)",
+			CMD_GROUP_COMMIT,
+		)
+	}
+	pub fn commit_signoff(
+		key_config: &SharedKeyConfig,
+		enabled: bool,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"{} [{}]",
+				if enabled { "No sign-off" } else { "Sign-off" },
+				key_config.get_hint(key_config.keys().commit_signoff),
+			),
+			"toggle adding a 'Signed-off-by' trailer (remembered per repo)",
+			CMD_GROUP_COMMIT,
+		)
+	}
+	pub fn commit_next_field(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Next field [{}]",
+				key_config.get_hint(key_config.keys().tab_toggle),
+			),
+			"switch between message, author and date when amending",
+			CMD_GROUP_COMMIT,
+		)
+	}
+	pub fn commit_reuse_msg(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Reuse msg [{}]",
+				key_config.get_hint(key_config.keys().commit_reuse_msg),
+			),
+			"load the full message of a previous commit (like 'commit -c')",
+			CMD_GROUP_COMMIT,
+		)
+	}
+	pub fn reuse_msg_confirm(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Reuse [{}]",
+				key_config.get_hint(key_config.keys().enter),
+			),
+			"use message of selected commit",
+			CMD_GROUP_COMMIT,
+		)
+	}
+	pub fn file_find_filter(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Filter [{}]",
+				key_config
+					.get_hint(key_config.keys().file_find_filter),
+			),
+			"prune the file tree to the hits, an empty search clears",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn open_theme_picker(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Theme [{}]",
+				key_config
+					.get_hint(key_config.keys().open_theme_picker),
+			),
+			"switch between the theme file and the bundled themes",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn theme_picker_confirm(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Use [{}]",
+				key_config.get_hint(key_config.keys().enter),
+			),
+			"use the selected theme",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn toggle_zoom(
+		key_config: &SharedKeyConfig,
+		zoomed: bool,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"{} [{}]",
+				if zoomed { "Unzoom" } else { "Zoom" },
+				key_config.get_hint(key_config.keys().toggle_zoom),
+			),
+			"expand the focused panel to the whole tab or restore the layout",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn toggle_wrap(
+		key_config: &SharedKeyConfig,
+		wrapped: bool,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"{} [{}]",
+				if wrapped { "Truncate" } else { "Wrap" },
+				key_config.get_hint(key_config.keys().toggle_wrap),
+			),
+			"switch between wrapping and truncating long lines",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn help_toggle_context(
+		key_config: &SharedKeyConfig,
+		show_all: bool,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"{} [{}]",
+				if show_all { "Current context" } else { "All" },
+				key_config
+					.get_hint(key_config.keys().help_toggle_context),
 			),
-			"reverts selected hunk",
-			CMD_GROUP_DIFF,
+			"switch between the commands usable right now and all of them",
+			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn diff_lines_revert(
+	pub fn open_log_viewer(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Reset lines [{}]",
-				key_config.get_hint(key_config.keys.diff_reset_lines),
+				"Log file [{}]",
+				key_config
+					.get_hint(key_config.keys().open_log_viewer),
 			),
-			"resets selected lines",
-			CMD_GROUP_DIFF,
+			"show the log gitui writes when started with -l",
+			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn diff_lines_stage(
+	pub fn open_repo_switcher(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Stage lines [{}]",
-				key_config.get_hint(key_config.keys.diff_stage_lines),
+				"Repositories [{}]",
+				key_config.get_hint(key_config.keys().open_repo_switcher),
 			),
-			"stage selected lines",
-			CMD_GROUP_DIFF,
+			"switch to a recently opened repository or browse for one",
+			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn diff_lines_unstage(
+	pub fn repo_switcher_open(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Unstage lines [{}]",
-				key_config.get_hint(key_config.keys.diff_stage_lines),
+				"Open [{}]",
+				key_config.get_hint(key_config.keys().enter),
 			),
-			"unstage selected lines",
-			CMD_GROUP_DIFF,
+			"switch to the selected repository",
+			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn diff_hunk_remove(
+	pub fn repo_switcher_browse(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Remove hunk [{}]",
+				"Browse [{}]",
 				key_config
-					.get_hint(key_config.keys.stage_unstage_item),
+					.get_hint(key_config.keys().repo_switcher_browse),
 			),
-			"removes selected hunk from stage",
-			CMD_GROUP_DIFF,
+			"look for a repository in the file system",
+			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn close_popup(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn repo_switcher_enter_dir(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Close [{}]",
-				key_config.get_hint(key_config.keys.exit_popup),
+				"Enter dir [{}]",
+				key_config.get_hint(key_config.keys().move_right),
 			),
-			"close overlay (e.g commit, help)",
+			"show the directories inside the selected one",
 			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn close_msg(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn repo_switcher_parent_dir(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Close [{}]",
-				key_config.get_hint(key_config.keys.enter),
+				"Parent dir [{}]",
+				key_config.get_hint(key_config.keys().move_left),
 			),
-			"close msg popup (e.g msg)",
+			"show the directory containing this one",
 			CMD_GROUP_GENERAL,
 		)
-		.hide_help()
 	}
-	pub fn validate_msg(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn log_viewer_level(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Validate [{}]",
-				key_config.get_hint(key_config.keys.enter),
+				"Level [{}{}]",
+				key_config.get_hint(key_config.keys().move_left),
+				key_config.get_hint(key_config.keys().move_right),
 			),
-			"validate msg",
+			"show fewer or more detailed log entries",
 			CMD_GROUP_GENERAL,
 		)
-		.hide_help()
 	}
-
-	pub fn abort_merge(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn log_viewer_copy(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Abort merge [{}]",
-				key_config.get_hint(key_config.keys.abort_merge),
+				"Copy [{}]",
+				key_config.get_hint(key_config.keys().copy),
 			),
-			"abort ongoing merge",
+			"copy the log lines shown to clipboard",
 			CMD_GROUP_GENERAL,
 		)
 	}
-
-	pub fn abort_revert(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn error_retry(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
-				"Abort revert [{}]",
-				key_config.get_hint(key_config.keys.abort_merge),
+				"Retry [{}]",
+				key_config.get_hint(key_config.keys().error_retry),
 			),
-			"abort ongoing revert",
+			"run the failed operation again",
 			CMD_GROUP_GENERAL,
 		)
 	}
-
-	pub fn view_submodules(
-		key_config: &SharedKeyConfig,
-	) -> CommandText {
+	pub fn error_copy(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
-				"Submodules [{}]",
-				key_config.get_hint(key_config.keys.view_submodules),
+				"Copy [{}]",
+				key_config.get_hint(key_config.keys().copy),
 			),
-			"open submodule view",
+			"copy the error with the gitui version to clipboard",
 			CMD_GROUP_GENERAL,
 		)
 	}
-
-	pub fn continue_rebase(
+	pub fn toast_history(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Continue rebase [{}]",
-				key_config.get_hint(key_config.keys.rebase_branch),
+				"Notifications [{}]",
+				key_config.get_hint(key_config.keys().toast_history),
 			),
-			"continue ongoing rebase",
+			"list the notifications shown so far",
 			CMD_GROUP_GENERAL,
 		)
 	}
-
-	pub fn abort_rebase(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn open_grep(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
-				"Abort rebase [{}]",
-				key_config.get_hint(key_config.keys.abort_merge),
+				"Search Contents [{}]",
+				key_config.get_hint(key_config.keys().open_grep),
 			),
-			"abort ongoing rebase",
+			"search the contents of the work tree or the revision of the files tab",
 			CMD_GROUP_GENERAL,
 		)
 	}
-
-	pub fn select_staging(
-		key_config: &SharedKeyConfig,
-	) -> CommandText {
+	pub fn grep_search(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
-				"To stage [{}]",
-				key_config.get_hint(key_config.keys.toggle_workarea),
+				"Search [{}]",
+				key_config.get_hint(key_config.keys().enter),
 			),
-			"focus/select staging area",
+			"search for lines containing the text",
 			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn select_unstaged(
+	pub fn grep_edit(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Edit Search [{}]",
+				key_config.get_hint(key_config.keys().search),
+			),
+			"change the search text",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn grep_open(
 		key_config: &SharedKeyConfig,
+		revision: bool,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"To unstaged [{}]",
-				key_config.get_hint(key_config.keys.toggle_workarea),
+				"{} [{}]",
+				if revision { "View" } else { "Blame" },
+				key_config.get_hint(key_config.keys().enter),
 			),
-			"focus/select unstaged area",
+			"open the file at the matching line",
 			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn undo_commit(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn revision_picker_confirm(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Undo Commit [{}]",
-				key_config.get_hint(key_config.keys.undo_commit),
+				"Show [{}]",
+				key_config.get_hint(key_config.keys().enter),
 			),
-			"undo last commit",
+			"show the files at the selected revision",
 			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn commit_open(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn files_revision(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Commit [{}]",
-				key_config.get_hint(key_config.keys.open_commit),
+				"Revision [{}]",
+				key_config.get_hint(key_config.keys().files_revision),
 			),
-			"open commit popup (available in non-empty stage)",
-			CMD_GROUP_COMMIT,
+			"pick the branch, tag or commit to browse the files of",
+			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn commit_open_editor(
+	pub fn commit_no_verify(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Open editor [{}]",
+				"Commit --no-verify [{}]",
 				key_config
-					.get_hint(key_config.keys.open_commit_editor),
+					.get_hint(key_config.keys().commit_no_verify),
 			),
-			"open commit editor (available in commit popup)",
+			"commit skipping pre-commit and commit-msg hooks",
 			CMD_GROUP_COMMIT,
 		)
 	}
-	pub fn commit_enter(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn commit_coauthor(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Commit [{}]",
-				key_config.get_hint(key_config.keys.enter),
+				"Co-author [{}]",
+				key_config
+					.get_hint(key_config.keys().commit_coauthor),
 			),
-			"commit (available when commit message is non-empty)",
+			"add a 'Co-authored-by' trailer",
 			CMD_GROUP_COMMIT,
 		)
-		.hide_help()
 	}
-	pub fn commit_amend(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn coauthor_confirm(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Amend [{}]",
-				key_config.get_hint(key_config.keys.commit_amend),
+				"Add [{}]",
+				key_config.get_hint(key_config.keys().enter),
 			),
-			"amend last commit (available in commit popup)",
+			"add selected co-author to commit message",
 			CMD_GROUP_COMMIT,
 		)
 	}
@@ -822,7 +2139,7 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Edit [{}]",
-				key_config.get_hint(key_config.keys.edit_file),
+				key_config.get_hint(key_config.keys().edit_file),
 			),
 			"edit the currently selected file in an external editor",
 			CMD_GROUP_CHANGES,
@@ -833,7 +2150,7 @@ pub mod commands {
 			format!(
 				"Stage [{}]",
 				key_config
-					.get_hint(key_config.keys.stage_unstage_item),
+					.get_hint(key_config.keys().stage_unstage_item),
 			),
 			"stage currently selected file or entire path",
 			CMD_GROUP_CHANGES,
@@ -843,7 +2160,8 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Stage All [{}]",
-				key_config.get_hint(key_config.keys.status_stage_all),
+				key_config
+					.get_hint(key_config.keys().status_stage_all),
 			),
 			"stage all changes (in unstaged files)",
 			CMD_GROUP_CHANGES,
@@ -854,7 +2172,7 @@ pub mod commands {
 			format!(
 				"Unstage [{}]",
 				key_config
-					.get_hint(key_config.keys.stage_unstage_item),
+					.get_hint(key_config.keys().stage_unstage_item),
 			),
 			"unstage currently selected file or entire path",
 			CMD_GROUP_CHANGES,
@@ -864,7 +2182,8 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Unstage all [{}]",
-				key_config.get_hint(key_config.keys.status_stage_all),
+				key_config
+					.get_hint(key_config.keys().status_stage_all),
 			),
 			"unstage all files (in staged files)",
 			CMD_GROUP_CHANGES,
@@ -875,7 +2194,7 @@ pub mod commands {
 			format!(
 				"Reset [{}]",
 				key_config
-					.get_hint(key_config.keys.status_reset_item),
+					.get_hint(key_config.keys().status_reset_item),
 			),
 			"revert changes in selected file or entire path",
 			CMD_GROUP_CHANGES,
@@ -886,7 +2205,7 @@ pub mod commands {
 			format!(
 				"Ignore [{}]",
 				key_config
-					.get_hint(key_config.keys.status_ignore_file),
+					.get_hint(key_config.keys().status_ignore_file),
 			),
 			"Add file or path to .gitignore",
 			CMD_GROUP_CHANGES,
@@ -899,7 +2218,7 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Back [{}]",
-				key_config.get_hint(key_config.keys.focus_left),
+				key_config.get_hint(key_config.keys().focus_left),
 			),
 			"view and select changed files",
 			CMD_GROUP_GENERAL,
@@ -911,7 +2230,7 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Diff [{}]",
-				key_config.get_hint(key_config.keys.focus_right),
+				key_config.get_hint(key_config.keys().focus_right),
 			),
 			"inspect file diff",
 			CMD_GROUP_GENERAL,
@@ -921,7 +2240,7 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Quit [{}]",
-				key_config.get_hint(key_config.keys.exit),
+				key_config.get_hint(key_config.keys().exit),
 			),
 			"quit gitui application",
 			CMD_GROUP_GENERAL,
@@ -933,7 +2252,7 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Confirm [{}]",
-				key_config.get_hint(key_config.keys.enter),
+				key_config.get_hint(key_config.keys().enter),
 			),
 			"confirm action",
 			CMD_GROUP_GENERAL,
@@ -941,26 +2260,41 @@ pub mod commands {
 	}
 	pub fn stashing_save(
 		key_config: &SharedKeyConfig,
+		marked: bool,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Save [{}]",
-				key_config.get_hint(key_config.keys.stashing_save),
+				"Save{} [{}]",
+				if marked { " Marked" } else { "" },
+				key_config.get_hint(key_config.keys().stashing_save),
 			),
 			"opens stash name input popup",
 			CMD_GROUP_STASHING,
 		)
 	}
+	pub fn stashing_mark_file(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Mark [{}]",
+				key_config
+					.get_hint(key_config.keys().stashing_mark_file),
+			),
+			"mark or unmark file to stash only the marked ones",
+			CMD_GROUP_STASHING,
+		)
+	}
 	pub fn stashing_toggle_indexed(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Toggle Staged [{}]",
+				"Keep Index [{}]",
 				key_config
-					.get_hint(key_config.keys.stashing_toggle_index),
+					.get_hint(key_config.keys().stashing_toggle_index),
 			),
-			"toggle including staged files into stash",
+			"toggle keeping staged changes in the index when stashing",
 			CMD_GROUP_STASHING,
 		)
 	}
@@ -971,20 +2305,34 @@ pub mod commands {
 			format!(
 				"Toggle Untracked [{}]",
 				key_config.get_hint(
-					key_config.keys.stashing_toggle_untracked
+					key_config.keys().stashing_toggle_untracked
 				),
 			),
 			"toggle including untracked files into stash",
 			CMD_GROUP_STASHING,
 		)
 	}
+	pub fn stashing_toggle_untracked_only(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Untracked Only [{}]",
+				key_config.get_hint(
+					key_config.keys().stashing_toggle_untracked_only
+				),
+			),
+			"toggle stashing only untracked files",
+			CMD_GROUP_STASHING,
+		)
+	}
 	pub fn stashing_confirm_msg(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
 				"Stash [{}]",
-				key_config.get_hint(key_config.keys.enter),
+				key_config.get_hint(key_config.keys().enter),
 			),
 			"save files to stash",
 			CMD_GROUP_STASHING,
@@ -996,12 +2344,37 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Apply [{}]",
-				key_config.get_hint(key_config.keys.stash_apply),
+				key_config.get_hint(key_config.keys().stash_apply),
 			),
 			"apply selected stash",
 			CMD_GROUP_STASHES,
 		)
 	}
+	pub fn stashlist_filter(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Filter [{}]",
+				key_config.get_hint(key_config.keys().stash_filter),
+			),
+			"filter stashes by message or branch of origin",
+			CMD_GROUP_STASHES,
+		)
+	}
+	pub fn stashlist_toggle_index(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Toggle Index [{}]",
+				key_config
+					.get_hint(key_config.keys().stash_toggle_index),
+			),
+			"toggle restoring staged changes into the index on apply",
+			CMD_GROUP_STASHES,
+		)
+	}
 	pub fn stashlist_drop(
 		key_config: &SharedKeyConfig,
 		marked: usize,
@@ -1014,7 +2387,7 @@ pub mod commands {
 				} else {
 					format!(" {}", marked)
 				},
-				key_config.get_hint(key_config.keys.stash_drop),
+				key_config.get_hint(key_config.keys().stash_drop),
 			),
 			"drop selected stash",
 			CMD_GROUP_STASHES,
@@ -1026,7 +2399,7 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Pop [{}]",
-				key_config.get_hint(key_config.keys.enter),
+				key_config.get_hint(key_config.keys().enter),
 			),
 			"pop selected stash",
 			CMD_GROUP_STASHES,
@@ -1038,46 +2411,195 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Inspect [{}]",
-				key_config.get_hint(key_config.keys.focus_right),
+				key_config.get_hint(key_config.keys().focus_right),
 			),
 			"open stash commit details (allows to diff files)",
 			CMD_GROUP_STASHES,
 		)
 	}
+	pub fn stashlist_branch(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Branch [{}]",
+				key_config.get_hint(key_config.keys().stash_branch),
+			),
+			"pop stash onto a new branch at the commit it was based on",
+			CMD_GROUP_STASHES,
+		)
+	}
+	pub fn stash_branch_confirm_msg(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Create Branch [{}]",
+				key_config.get_hint(key_config.keys().enter),
+			),
+			"create branch and pop stash onto it",
+			CMD_GROUP_STASHES,
+		)
+	}
+	pub fn log_deepen(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Fetch History [{}]",
+				key_config.get_hint(key_config.keys().log_deepen),
+			),
+			"fetch older history of this shallow clone",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn deepen_confirm(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Fetch [{}]",
+				key_config.get_hint(key_config.keys().enter),
+			),
+			"fetch this many older commits",
+			CMD_GROUP_LOG,
+		)
+	}
 	pub fn log_details_toggle(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
 				"Details [{}]",
-				key_config.get_hint(key_config.keys.enter),
+				key_config.get_hint(key_config.keys().enter),
+			),
+			"open details of selected commit",
+			CMD_GROUP_LOG,
+		)
+	}
+
+	pub fn blame_inspect_commit(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Inspect [{}]",
+				key_config.get_hint(key_config.keys().enter),
+			),
+			"inspect the commit that introduced the selected line",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn commit_details_open(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Inspect [{}]",
+				key_config.get_hint(key_config.keys().focus_right),
+			),
+			"inspect selected commit in detail",
+			CMD_GROUP_GENERAL,
+		)
+	}
+
+	pub fn blame_file(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Blame [{}]",
+				key_config.get_hint(key_config.keys().blame),
+			),
+			"open blame view of selected file",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn blame_parent(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Blame parent [{}]",
+				key_config.get_hint(key_config.keys().blame_parent),
+			),
+			"blame the file before the commit of the selected line",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn view_file(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"View [{}]",
+				key_config.get_hint(key_config.keys().view_file),
+			),
+			"view the file as of this revision",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn view_tree_file(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"View [{}]",
+				key_config.get_hint(key_config.keys().enter),
+			),
+			"view the selected file in a searchable viewer",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn file_view_search(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Search [{}]",
+				key_config.get_hint(key_config.keys().search),
+			),
+			"search text in the file",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn file_view_search_next(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Next/Prev match [{}/{}]",
+				key_config.get_hint(key_config.keys().search_next),
+				key_config.get_hint(key_config.keys().search_prev),
 			),
-			"open details of selected commit",
-			CMD_GROUP_LOG,
+			"jump to the next or previous match",
+			CMD_GROUP_GENERAL,
 		)
 	}
-
-	pub fn commit_details_open(
+	pub fn file_history_restore(
 		key_config: &SharedKeyConfig,
+		confirm: bool,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Inspect [{}]",
-				key_config.get_hint(key_config.keys.focus_right),
+				"{} [{}]",
+				if confirm { "Confirm restore" } else { "Restore" },
+				key_config.get_hint(key_config.keys().file_history_restore),
 			),
-			"inspect selected commit in detail",
-			CMD_GROUP_GENERAL,
+			"preview and restore the file in the workdir to the selected revision",
+			CMD_GROUP_LOG,
 		)
 	}
-
-	pub fn blame_file(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn file_history_restore_stage(
+		key_config: &SharedKeyConfig,
+		confirm: bool,
+	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Blame [{}]",
-				key_config.get_hint(key_config.keys.blame),
+				"{} [{}]",
+				if confirm {
+					"Confirm restore to stage"
+				} else {
+					"Restore to stage"
+				},
+				key_config.get_hint(
+					key_config.keys().file_history_restore_stage
+				),
 			),
-			"open blame view of selected file",
-			CMD_GROUP_GENERAL,
+			"preview and restore the file in the stage to the selected revision",
+			CMD_GROUP_LOG,
 		)
 	}
 	pub fn open_file_history(
@@ -1086,7 +2608,7 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"History [{}]",
-				key_config.get_hint(key_config.keys.file_history),
+				key_config.get_hint(key_config.keys().file_history),
 			),
 			"open history of selected file",
 			CMD_GROUP_LOG,
@@ -1098,7 +2620,7 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Tag [{}]",
-				key_config.get_hint(key_config.keys.log_tag_commit),
+				key_config.get_hint(key_config.keys().log_tag_commit),
 			),
 			"tag commit",
 			CMD_GROUP_LOG,
@@ -1110,7 +2632,7 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Files [{}]",
-				key_config.get_hint(key_config.keys.open_file_tree),
+				key_config.get_hint(key_config.keys().open_file_tree),
 			),
 			"inspect file tree at specific revision",
 			CMD_GROUP_LOG,
@@ -1123,7 +2645,7 @@ pub mod commands {
 			format!(
 				"Revert [{}]",
 				key_config
-					.get_hint(key_config.keys.status_reset_item),
+					.get_hint(key_config.keys().status_reset_item),
 			),
 			"revert commit",
 			CMD_GROUP_LOG,
@@ -1135,7 +2657,7 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Tag [{}]",
-				key_config.get_hint(key_config.keys.enter),
+				key_config.get_hint(key_config.keys().enter),
 			),
 			"tag commit",
 			CMD_GROUP_LOG,
@@ -1148,20 +2670,35 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Annotate [{}]",
-				key_config.get_hint(key_config.keys.tag_annotate),
+				key_config.get_hint(key_config.keys().tag_annotate),
 			),
 			"annotate tag",
 			CMD_GROUP_LOG,
 		)
 	}
 
+	pub fn tag_sign(
+		key_config: &SharedKeyConfig,
+		enabled: bool,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"{} [{}]",
+				if enabled { "Don't sign" } else { "Sign" },
+				key_config.get_hint(key_config.keys().tag_sign),
+			),
+			"toggle signing the tag (defaults to 'tag.gpgsign')",
+			CMD_GROUP_LOG,
+		)
+	}
+
 	pub fn create_branch_confirm_msg(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
 				"Create Branch [{}]",
-				key_config.get_hint(key_config.keys.enter),
+				key_config.get_hint(key_config.keys().enter),
 			),
 			"create branch",
 			CMD_GROUP_BRANCHES,
@@ -1174,7 +2711,7 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Create [{}]",
-				key_config.get_hint(key_config.keys.create_branch),
+				key_config.get_hint(key_config.keys().create_branch),
 			),
 			"open create branch popup",
 			CMD_GROUP_BRANCHES,
@@ -1186,32 +2723,142 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Rename Branch [{}]",
-				key_config.get_hint(key_config.keys.enter),
+				key_config.get_hint(key_config.keys().enter),
 			),
 			"rename branch",
 			CMD_GROUP_BRANCHES,
 		)
 		.hide_help()
 	}
+	pub fn branch_description_confirm(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Save Description [{}]",
+				key_config.get_hint(key_config.keys().enter),
+			),
+			"save branch description",
+			CMD_GROUP_BRANCHES,
+		)
+		.hide_help()
+	}
+	pub fn branch_description_popup(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Description [{}]",
+				key_config
+					.get_hint(key_config.keys().branch_description),
+			),
+			"edit branch description",
+			CMD_GROUP_BRANCHES,
+		)
+	}
 	pub fn rename_branch_popup(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
 				"Rename Branch [{}]",
-				key_config.get_hint(key_config.keys.rename_branch),
+				key_config.get_hint(key_config.keys().rename_branch),
 			),
 			"rename branch",
 			CMD_GROUP_BRANCHES,
 		)
 	}
+	pub fn branch_set_upstream(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Set Upstream [{}]",
+				key_config
+					.get_hint(key_config.keys().branch_set_upstream),
+			),
+			"set upstream of branch by picking a remote branch",
+			CMD_GROUP_BRANCHES,
+		)
+	}
+	pub fn branch_pick_upstream(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Use As Upstream [{}]",
+				key_config.get_hint(key_config.keys().enter),
+			),
+			"use selected remote branch as upstream",
+			CMD_GROUP_BRANCHES,
+		)
+	}
+	pub fn push_set_upstream(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Push & Set Upstream [{}]",
+				key_config.get_hint(key_config.keys().enter),
+			),
+			"push and set upstream of branch (--set-upstream)",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn push_without_upstream(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Push Only [{}]",
+				key_config.get_hint(key_config.keys().push),
+			),
+			"push without setting an upstream",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn prune_gone_branches(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Prune Gone [{}]",
+				key_config
+					.get_hint(key_config.keys().branch_prune_gone),
+			),
+			"delete local branches whose upstream is gone",
+			CMD_GROUP_BRANCHES,
+		)
+	}
+	pub fn prune_branches_confirm(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Delete Marked [{}]",
+				key_config.get_hint(key_config.keys().enter),
+			),
+			"delete marked branches",
+			CMD_GROUP_BRANCHES,
+		)
+	}
+	pub fn branch_mark(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Mark [{}]",
+				key_config.get_hint(key_config.keys().branch_mark),
+			),
+			"mark or unmark branch",
+			CMD_GROUP_BRANCHES,
+		)
+	}
 	pub fn delete_branch_popup(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
 				"Delete [{}]",
-				key_config.get_hint(key_config.keys.delete_branch),
+				key_config.get_hint(key_config.keys().delete_branch),
 			),
 			"delete a branch",
 			CMD_GROUP_BRANCHES,
@@ -1223,7 +2870,7 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Merge [{}]",
-				key_config.get_hint(key_config.keys.merge_branch),
+				key_config.get_hint(key_config.keys().merge_branch),
 			),
 			"merge a branch",
 			CMD_GROUP_BRANCHES,
@@ -1236,7 +2883,7 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Rebase [{}]",
-				key_config.get_hint(key_config.keys.rebase_branch),
+				key_config.get_hint(key_config.keys().rebase_branch),
 			),
 			"rebase a branch",
 			CMD_GROUP_BRANCHES,
@@ -1249,20 +2896,49 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Compare [{}]",
-				key_config.get_hint(key_config.keys.compare_commits),
+				key_config
+					.get_hint(key_config.keys().compare_commits),
 			),
 			"compare with head",
 			CMD_GROUP_BRANCHES,
 		)
 	}
 
+	pub fn compare_branches(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Compare Branches [{}]",
+				key_config
+					.get_hint(key_config.keys().compare_commits),
+			),
+			"compare the two marked branches",
+			CMD_GROUP_BRANCHES,
+		)
+	}
+
+	pub fn compare_branches_files(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Files [{}]",
+				key_config.get_hint(key_config.keys().focus_below),
+			),
+			"inspect files changed since the merge base",
+			CMD_GROUP_GENERAL,
+		)
+	}
+
 	pub fn compare_commits(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
 				"Compare Commits [{}]",
-				key_config.get_hint(key_config.keys.compare_commits),
+				key_config
+					.get_hint(key_config.keys().compare_commits),
 			),
 			"compare two marked commits",
 			CMD_GROUP_LOG,
@@ -1275,7 +2951,7 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Checkout [{}]",
-				key_config.get_hint(key_config.keys.enter),
+				key_config.get_hint(key_config.keys().enter),
 			),
 			"checkout branch",
 			CMD_GROUP_BRANCHES,
@@ -1289,19 +2965,32 @@ pub mod commands {
 			format!(
 				"{} [{}]",
 				if local { "Remote" } else { "Local" },
-				key_config.get_hint(key_config.keys.tab_toggle),
+				key_config.get_hint(key_config.keys().tab_toggle),
 			),
 			"toggle branch type (remote/local)",
 			CMD_GROUP_BRANCHES,
 		)
 	}
+	pub fn open_recent_branches(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Recent Branches [{}]",
+				key_config
+					.get_hint(key_config.keys().recent_branches),
+			),
+			"switch to a recently checked out branch",
+			CMD_GROUP_BRANCHES,
+		)
+	}
 	pub fn open_branch_select_popup(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
 				"Branches [{}]",
-				key_config.get_hint(key_config.keys.select_branch),
+				key_config.get_hint(key_config.keys().select_branch),
 			),
 			"open branch popup",
 			CMD_GROUP_BRANCHES,
@@ -1314,7 +3003,7 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Tags [{}]",
-				key_config.get_hint(key_config.keys.tags),
+				key_config.get_hint(key_config.keys().tags),
 			),
 			"open tags popup",
 			CMD_GROUP_GENERAL,
@@ -1326,17 +3015,129 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Delete [{}]",
-				key_config.get_hint(key_config.keys.delete_tag),
+				key_config.get_hint(key_config.keys().delete_tag),
 			),
 			"delete a tag",
 			CMD_GROUP_GENERAL,
 		)
 	}
+	pub fn delete_matching_tags(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Delete matching [{}]",
+				key_config
+					.get_hint(key_config.keys().tag_delete_matching),
+			),
+			"delete all tags matching a pattern",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn delete_matching_tags_confirm(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Delete [{}]",
+				key_config.get_hint(key_config.keys().enter),
+			),
+			"list matching tags and confirm deleting them",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn tag_sort(
+		key_config: &SharedKeyConfig,
+		sort: &str,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Sort: {} [{}]",
+				sort,
+				key_config.get_hint(key_config.keys().tag_sort),
+			),
+			"sort tags by creation date or version",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn tag_kind_filter(
+		key_config: &SharedKeyConfig,
+		kind: &str,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Kind: {} [{}]",
+				kind,
+				key_config
+					.get_hint(key_config.keys().tag_kind_filter),
+			),
+			"only list lightweight, annotated or signed tags",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn tag_revision(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Tag revision [{}]",
+				key_config.get_hint(key_config.keys().log_tag_commit),
+			),
+			"tag the marked log commit or any other revision",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn tag_mark(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Mark [{}]",
+				key_config.get_hint(key_config.keys().tag_mark),
+			),
+			"mark tag to compare the selected one against",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn tag_compare(
+		key_config: &SharedKeyConfig,
+		marked: bool,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Compare {} [{}]",
+				if marked { "with marked" } else { "with HEAD" },
+				key_config
+					.get_hint(key_config.keys().compare_commits),
+			),
+			"compare selected tag with the marked tag or HEAD",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn tag_checkout(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Checkout [{}]",
+				key_config.get_hint(key_config.keys().tag_checkout),
+			),
+			"checkout tag into a detached HEAD",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn detached_create_branch(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Create Branch [{}]",
+				key_config
+					.get_hint(key_config.keys().detached_create_branch),
+			),
+			"create a branch at the detached HEAD to keep its commits",
+			CMD_GROUP_GENERAL,
+		)
+	}
 	pub fn select_tag(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
 				"Select commit [{}]",
-				key_config.get_hint(key_config.keys.select_tag),
+				key_config.get_hint(key_config.keys().select_tag),
 			),
 			"Select commit in revlog",
 			CMD_GROUP_LOG,
@@ -1347,7 +3148,7 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Push [{}]",
-				key_config.get_hint(key_config.keys.push),
+				key_config.get_hint(key_config.keys().push),
 			),
 			"push to origin",
 			CMD_GROUP_GENERAL,
@@ -1359,9 +3160,9 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Force Push [{}]",
-				key_config.get_hint(key_config.keys.force_push),
+				key_config.get_hint(key_config.keys().force_push),
 			),
-			"force push to origin",
+			"force push to origin if it did not change since the last fetch",
 			CMD_GROUP_GENERAL,
 		)
 	}
@@ -1369,12 +3170,79 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Pull [{}]",
-				key_config.get_hint(key_config.keys.pull),
+				key_config.get_hint(key_config.keys().pull),
 			),
 			"fetch/merge",
 			CMD_GROUP_GENERAL,
 		)
 	}
+	pub fn status_push_to(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Push to [{}]",
+				key_config.get_hint(key_config.keys().push_to),
+			),
+			"push to a picked remote and branch name",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn status_fetch_all(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Fetch all [{}]",
+				key_config.get_hint(key_config.keys().fetch_all),
+			),
+			"fetch all remotes",
+			CMD_GROUP_GENERAL,
+		)
+	}
+
+	pub fn merge_options_confirm(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Merge [{}]",
+				key_config.get_hint(key_config.keys().enter),
+			),
+			"merge with the selected fast-forward behavior",
+			CMD_GROUP_BRANCHES,
+		)
+	}
+
+	pub fn branch_sort(
+		key_config: &SharedKeyConfig,
+		sort: &str,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Sort: {} [{}]",
+				sort,
+				key_config.get_hint(key_config.keys().branch_sort),
+			),
+			"sort branches by name, last commit date or ahead/behind count",
+			CMD_GROUP_BRANCHES,
+		)
+	}
+
+	pub fn branch_filter(
+		key_config: &SharedKeyConfig,
+		filter: &str,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Filter: {} [{}]",
+				filter,
+				key_config.get_hint(key_config.keys().branch_filter),
+			),
+			"show all branches, those without upstream or those behind it",
+			CMD_GROUP_BRANCHES,
+		)
+	}
 
 	pub fn fetch_remotes(
 		key_config: &SharedKeyConfig,
@@ -1382,7 +3250,7 @@ pub mod commands {
 		CommandText::new(
 			format!(
 				"Fetch [{}]",
-				key_config.get_hint(key_config.keys.pull),
+				key_config.get_hint(key_config.keys().pull),
 			),
 			"fetch/prune",
 			CMD_GROUP_BRANCHES,
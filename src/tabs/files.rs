@@ -4,20 +4,27 @@ use crate::{
 	components::{
 		visibility_blocking, CommandBlocking, CommandInfo, Component,
 		DrawableComponent, EventState, RevisionFilesComponent,
+		SharedOptions,
 	},
-	keys::SharedKeyConfig,
-	queue::Queue,
+	keys::{key_match, SharedKeyConfig},
+	queue::{InternalEvent, Queue},
+	strings,
 	ui::style::SharedTheme,
 	AsyncAppNotification, AsyncNotification,
 };
 use anyhow::Result;
-use asyncgit::sync::{self, RepoPathRef};
+use asyncgit::sync::{self, CommitId, RepoPathRef};
 use crossbeam_channel::Sender;
+use crossterm::event::Event;
 
 pub struct FilesTab {
 	repo: RepoPathRef,
 	visible: bool,
 	files: RevisionFilesComponent,
+	/// picked revision and its name, following `HEAD` if unset
+	revision: Option<(CommitId, String)>,
+	queue: Queue,
+	key_config: SharedKeyConfig,
 }
 
 impl FilesTab {
@@ -28,6 +35,7 @@ impl FilesTab {
 		queue: &Queue,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
+		options: SharedOptions,
 	) -> Self {
 		Self {
 			visible: false,
@@ -36,8 +44,12 @@ impl FilesTab {
 				queue,
 				sender,
 				theme,
-				key_config,
+				key_config.clone(),
+				options,
 			),
+			revision: None,
+			queue: queue.clone(),
+			key_config,
 			repo,
 		}
 	}
@@ -45,7 +57,11 @@ impl FilesTab {
 	///
 	pub fn update(&mut self) -> Result<()> {
 		if self.is_visible() {
-			if let Ok(head) = sync::get_head(&self.repo.borrow()) {
+			if let Some((id, _)) = self.revision {
+				self.files.set_commit(id)?;
+			} else if let Ok(head) =
+				sync::get_head(&self.repo.borrow())
+			{
 				self.files.set_commit(head)?;
 			}
 		}
@@ -53,6 +69,24 @@ impl FilesTab {
 		Ok(())
 	}
 
+	///
+	pub fn set_revision(
+		&mut self,
+		revision: Option<(CommitId, String)>,
+	) -> Result<()> {
+		self.files.set_revision_name(
+			revision.as_ref().map(|(_, name)| name.clone()),
+		);
+		self.revision = revision;
+
+		self.update()
+	}
+
+	/// revision the files are shown at
+	pub const fn revision(&self) -> Option<CommitId> {
+		self.files.revision()
+	}
+
 	///
 	pub fn anything_pending(&self) -> bool {
 		self.files.any_work_pending()
@@ -68,6 +102,13 @@ impl FilesTab {
 	pub fn file_finder_update(&mut self, file: &Option<PathBuf>) {
 		self.files.find_file(file);
 	}
+
+	pub fn file_finder_filter(
+		&mut self,
+		query: Option<String>,
+	) -> Result<()> {
+		self.files.set_filter(query)
+	}
 }
 
 impl DrawableComponent for FilesTab {
@@ -90,6 +131,12 @@ impl Component for FilesTab {
 		force_all: bool,
 	) -> CommandBlocking {
 		if self.visible || force_all {
+			out.push(CommandInfo::new(
+				strings::commands::files_revision(&self.key_config),
+				true,
+				true,
+			));
+
 			return self.files.commands(out, force_all);
 		}
 
@@ -101,7 +148,20 @@ impl Component for FilesTab {
 		ev: &crossterm::event::Event,
 	) -> Result<EventState> {
 		if self.visible {
-			return self.files.event(ev);
+			if self.files.event(ev)?.is_consumed() {
+				return Ok(EventState::Consumed);
+			}
+
+			if let Event::Key(key) = ev {
+				if key_match(
+					key,
+					self.key_config.keys().files_revision,
+				) {
+					self.queue
+						.push(InternalEvent::OpenRevisionPicker);
+					return Ok(EventState::Consumed);
+				}
+			}
 		}
 
 		Ok(EventState::NotConsumed)
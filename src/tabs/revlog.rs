@@ -3,7 +3,7 @@ use crate::{
 		visibility_blocking, CommandBlocking, CommandInfo,
 		CommitDetailsComponent, CommitList, Component,
 		DrawableComponent, EventState, FileTreeOpen,
-		InspectCommitOpen,
+		InspectCommitOpen, SharedOptions,
 	},
 	keys::{key_match, SharedKeyConfig},
 	queue::{InternalEvent, Queue, StackablePopupOpen},
@@ -19,13 +19,9 @@ use asyncgit::{
 };
 use crossbeam_channel::Sender;
 use crossterm::event::Event;
-use std::time::Duration;
+use std::{collections::HashSet, time::Duration};
 use sync::CommitTags;
-use tui::{
-	backend::Backend,
-	layout::{Constraint, Direction, Layout, Rect},
-	Frame,
-};
+use tui::{backend::Backend, layout::Rect, Frame};
 
 const SLICE_SIZE: usize = 1200;
 
@@ -39,7 +35,12 @@ pub struct Revlog {
 	queue: Queue,
 	visible: bool,
 	branch_name: cached::BranchName,
+	/// where the history of a shallow clone ends
+	shallow: HashSet<CommitId>,
 	key_config: SharedKeyConfig,
+	options: SharedOptions,
+	/// the list takes up the whole tab
+	zoomed: bool,
 }
 
 impl Revlog {
@@ -50,6 +51,7 @@ impl Revlog {
 		sender: &Sender<AsyncGitNotification>,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
+		options: SharedOptions,
 	) -> Self {
 		Self {
 			repo: repo.clone(),
@@ -74,7 +76,10 @@ impl Revlog {
 			git_tags: AsyncTags::new(repo.borrow().clone(), sender),
 			visible: false,
 			branch_name: cached::BranchName::new(repo.clone()),
+			shallow: HashSet::new(),
 			key_config,
+			options,
+			zoomed: false,
 		}
 	}
 
@@ -88,6 +93,8 @@ impl Revlog {
 	///
 	pub fn update(&mut self) -> Result<()> {
 		if self.is_visible() {
+			self.update_shallow()?;
+
 			let log_changed =
 				self.git_log.fetch()? == FetchStatus::Started;
 
@@ -143,6 +150,20 @@ impl Revlog {
 		Ok(())
 	}
 
+	/// reloads the log once more history was fetched
+	fn update_shallow(&mut self) -> Result<()> {
+		let shallow = sync::shallow_commits(&self.repo.borrow())
+			.unwrap_or_default();
+
+		if shallow != self.shallow {
+			self.git_log.invalidate()?;
+			self.list.set_shallow_commits(shallow.clone());
+			self.shallow = shallow;
+		}
+
+		Ok(())
+	}
+
 	fn fetch_commits(&mut self) -> Result<()> {
 		let want_min =
 			self.list.selection().saturating_sub(SLICE_SIZE / 2);
@@ -180,6 +201,14 @@ impl Revlog {
 		})
 	}
 
+	/// the commit marked in the log if it is the only one
+	pub fn single_marked_commit(&self) -> Option<CommitId> {
+		match self.list.marked() {
+			[id] => Some(*id),
+			_ => None,
+		}
+	}
+
 	pub fn select_commit(&mut self, id: CommitId) -> Result<()> {
 		let position = self.git_log.position(id)?;
 
@@ -219,18 +248,10 @@ impl DrawableComponent for Revlog {
 		f: &mut Frame<B>,
 		area: Rect,
 	) -> Result<()> {
-		let chunks = Layout::default()
-			.direction(Direction::Horizontal)
-			.constraints(
-				[
-					Constraint::Percentage(60),
-					Constraint::Percentage(40),
-				]
-				.as_ref(),
-			)
-			.split(area);
-
-		if self.commit_details.is_visible() {
+		if self.commit_details.is_visible() && !self.zoomed {
+			let chunks =
+				self.options.borrow().log_layout.split(area, 60);
+
 			self.list.draw(f, chunks[0])?;
 			self.commit_details.draw(f, chunks[1])?;
 		} else {
@@ -252,19 +273,25 @@ impl Component for Revlog {
 				self.update()?;
 				return Ok(EventState::Consumed);
 			} else if let Event::Key(k) = ev {
-				if key_match(k, self.key_config.keys.enter) {
+				if key_match(k, self.key_config.keys().enter) {
 					self.commit_details.toggle_visible()?;
 					self.update()?;
 					return Ok(EventState::Consumed);
-				} else if key_match(k, self.key_config.keys.copy) {
+				} else if key_match(
+					k,
+					self.key_config.keys().toggle_zoom,
+				) {
+					self.zoomed = !self.zoomed;
+					return Ok(EventState::Consumed);
+				} else if key_match(k, self.key_config.keys().copy) {
 					self.copy_commit_hash()?;
 					return Ok(EventState::Consumed);
-				} else if key_match(k, self.key_config.keys.push) {
+				} else if key_match(k, self.key_config.keys().push) {
 					self.queue.push(InternalEvent::PushTags);
 					return Ok(EventState::Consumed);
 				} else if key_match(
 					k,
-					self.key_config.keys.log_tag_commit,
+					self.key_config.keys().log_tag_commit,
 				) {
 					return self.selected_commit().map_or(
 						Ok(EventState::NotConsumed),
@@ -276,20 +303,27 @@ impl Component for Revlog {
 					);
 				} else if key_match(
 					k,
-					self.key_config.keys.focus_right,
+					self.key_config.keys().focus_right,
 				) && self.commit_details.is_visible()
 				{
 					self.inspect_commit();
 					return Ok(EventState::Consumed);
 				} else if key_match(
 					k,
-					self.key_config.keys.select_branch,
+					self.key_config.keys().select_branch,
 				) {
 					self.queue.push(InternalEvent::SelectBranch);
 					return Ok(EventState::Consumed);
 				} else if key_match(
 					k,
-					self.key_config.keys.status_reset_item,
+					self.key_config.keys().recent_branches,
+				) {
+					self.queue
+						.push(InternalEvent::OpenRecentBranches);
+					return Ok(EventState::Consumed);
+				} else if key_match(
+					k,
+					self.key_config.keys().status_reset_item,
 				) {
 					try_or_popup!(
 						self,
@@ -300,7 +334,7 @@ impl Component for Revlog {
 					return Ok(EventState::Consumed);
 				} else if key_match(
 					k,
-					self.key_config.keys.open_file_tree,
+					self.key_config.keys().open_file_tree,
 				) {
 					return self.selected_commit().map_or(
 						Ok(EventState::NotConsumed),
@@ -315,12 +349,32 @@ impl Component for Revlog {
 							Ok(EventState::Consumed)
 						},
 					);
-				} else if key_match(k, self.key_config.keys.tags) {
+				} else if key_match(
+					k,
+					self.key_config.keys().commit_reuse_msg,
+				) {
+					return self.selected_commit().map_or(
+						Ok(EventState::NotConsumed),
+						|id| {
+							self.queue.push(
+								InternalEvent::ReuseCommitMessage(id),
+							);
+							Ok(EventState::Consumed)
+						},
+					);
+				} else if key_match(k, self.key_config.keys().tags) {
 					self.queue.push(InternalEvent::Tags);
 					return Ok(EventState::Consumed);
 				} else if key_match(
 					k,
-					self.key_config.keys.compare_commits,
+					self.key_config.keys().log_deepen,
+				) && !self.shallow.is_empty()
+				{
+					self.queue.push(InternalEvent::DeepenHistory);
+					return Ok(EventState::Consumed);
+				} else if key_match(
+					k,
+					self.key_config.keys().compare_commits,
 				) && self.list.marked_count() > 0
 				{
 					if self.list.marked_count() == 1 {
@@ -369,6 +423,15 @@ impl Component for Revlog {
 			self.visible,
 		));
 
+		out.push(CommandInfo::new(
+			strings::commands::toggle_zoom(
+				&self.key_config,
+				self.zoomed,
+			),
+			true,
+			self.visible || force_all,
+		));
+
 		out.push(CommandInfo::new(
 			strings::commands::commit_details_open(&self.key_config),
 			true,
@@ -384,6 +447,12 @@ impl Component for Revlog {
 			self.visible || force_all,
 		));
 
+		out.push(CommandInfo::new(
+			strings::commands::open_recent_branches(&self.key_config),
+			true,
+			self.visible || force_all,
+		));
+
 		out.push(CommandInfo::new(
 			strings::commands::compare_with_head(&self.key_config),
 			self.list.marked_count() == 1,
@@ -410,6 +479,12 @@ impl Component for Revlog {
 			self.visible || force_all,
 		));
 
+		out.push(CommandInfo::new(
+			strings::commands::commit_reuse_msg(&self.key_config),
+			self.selected_commit().is_some(),
+			self.visible || force_all,
+		));
+
 		out.push(CommandInfo::new(
 			strings::commands::open_tags_popup(&self.key_config),
 			true,
@@ -434,6 +509,12 @@ impl Component for Revlog {
 			self.visible || force_all,
 		));
 
+		out.push(CommandInfo::new(
+			strings::commands::log_deepen(&self.key_config),
+			true,
+			(self.visible && !self.shallow.is_empty()) || force_all,
+		));
+
 		visibility_blocking(self)
 	}
 
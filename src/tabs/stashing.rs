@@ -2,8 +2,9 @@ use crate::{
 	accessors,
 	components::{
 		command_pump, event_pump, visibility_blocking,
-		CommandBlocking, CommandInfo, Component, DrawableComponent,
-		EventState, StatusTreeComponent,
+		CommandBlocking, CommandInfo, Component, DiffComponent,
+		DrawableComponent, EventState, SharedOptions,
+		StatusTreeComponent,
 	},
 	keys::{key_match, SharedKeyConfig},
 	queue::{InternalEvent, Queue},
@@ -13,7 +14,8 @@ use crate::{
 use anyhow::Result;
 use asyncgit::{
 	sync::{self, status::StatusType, RepoPathRef},
-	AsyncGitNotification, AsyncStatus, StatusParams,
+	AsyncDiff, AsyncGitNotification, AsyncStatus, DiffParams,
+	DiffType, StatusParams,
 };
 use crossbeam_channel::Sender;
 use crossterm::event::Event;
@@ -21,28 +23,33 @@ use std::borrow::Cow;
 use tui::{
 	layout::{Alignment, Constraint, Direction, Layout},
 	text::{Span, Spans},
-	widgets::{Block, Borders, Paragraph},
+	widgets::{Block, Paragraph},
 };
 
 #[derive(Default, Clone, Copy, Debug)]
 pub struct StashingOptions {
 	pub stash_untracked: bool,
 	pub keep_index: bool,
+	/// stash nothing but untracked files
+	pub untracked_only: bool,
 }
 
 pub struct Stashing {
 	repo: RepoPathRef,
 	index: StatusTreeComponent,
+	diff: DiffComponent,
 	visible: bool,
 	options: StashingOptions,
+	diff_options: SharedOptions,
 	theme: SharedTheme,
 	git_status: AsyncStatus,
+	git_diff: AsyncDiff,
 	queue: Queue,
 	key_config: SharedKeyConfig,
 }
 
 impl Stashing {
-	accessors!(self, [index]);
+	accessors!(self, [index, diff]);
 
 	///
 	pub fn new(
@@ -51,6 +58,7 @@ impl Stashing {
 		queue: &Queue,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
+		options: SharedOptions,
 	) -> Self {
 		Self {
 			repo: repo.clone(),
@@ -61,16 +69,26 @@ impl Stashing {
 				theme.clone(),
 				key_config.clone(),
 			),
+			diff: DiffComponent::new(
+				repo.clone(),
+				queue.clone(),
+				theme.clone(),
+				key_config.clone(),
+				true,
+			),
 			visible: false,
 			options: StashingOptions {
 				keep_index: false,
 				stash_untracked: true,
+				untracked_only: false,
 			},
+			diff_options: options,
 			theme,
 			git_status: AsyncStatus::new(
 				repo.borrow().clone(),
 				sender.clone(),
 			),
+			git_diff: AsyncDiff::new(repo.borrow().clone(), sender),
 			queue: queue.clone(),
 			key_config,
 		}
@@ -89,7 +107,7 @@ impl Stashing {
 
 	///
 	pub fn anything_pending(&self) -> bool {
-		self.git_status.is_pending()
+		self.git_status.is_pending() || self.git_diff.is_pending()
 	}
 
 	///
@@ -97,14 +115,55 @@ impl Stashing {
 		&mut self,
 		ev: AsyncGitNotification,
 	) -> Result<()> {
-		if self.is_visible() && ev == AsyncGitNotification::Status {
-			let status = self.git_status.last()?;
-			self.index.update(&status.items)?;
+		if self.is_visible() {
+			match ev {
+				AsyncGitNotification::Status => {
+					let status = self.git_status.last()?;
+					self.index.update(&status.items)?;
+					self.update_diff()?;
+				}
+				AsyncGitNotification::Diff => self.update_diff()?,
+				_ => (),
+			}
+		}
+
+		Ok(())
+	}
+
+	/// previews the selected file the way the stash would capture it
+	pub fn update_diff(&mut self) -> Result<()> {
+		if self.is_visible() {
+			if let Some(f) = self.index.selection_file() {
+				let diff_params = DiffParams {
+					path: f.path.clone(),
+					diff_type: DiffType::Stash {
+						untracked: self.options.stash_untracked,
+					},
+					options: self.diff_options.borrow().diff,
+				};
+
+				if let Some((params, last)) = self.git_diff.last()? {
+					if params == diff_params {
+						self.diff.update(f.path, false, last);
+						return Ok(());
+					}
+				}
+
+				self.git_diff.request(diff_params)?;
+				self.diff.clear(true);
+				return Ok(());
+			}
+
+			self.diff.clear(false);
 		}
 
 		Ok(())
 	}
 
+	fn can_focus_diff(&self) -> bool {
+		self.index.is_file_seleted()
+	}
+
 	fn get_option_text(&self) -> Vec<Spans> {
 		let bracket_open = Span::raw(Cow::from("["));
 		let bracket_close = Span::raw(Cow::from("]"));
@@ -126,15 +185,25 @@ impl Stashing {
 				Span::raw(Cow::from(" stash untracked")),
 			]),
 			Spans::from(vec![
-				bracket_open,
+				bracket_open.clone(),
 				if self.options.keep_index {
 					option_on.clone()
 				} else {
 					option_off.clone()
 				},
-				bracket_close,
+				bracket_close.clone(),
 				Span::raw(Cow::from(" keep index")),
 			]),
+			Spans::from(vec![
+				bracket_open,
+				if self.options.untracked_only {
+					option_on
+				} else {
+					option_off
+				},
+				bracket_close,
+				Span::raw(Cow::from(" untracked only")),
+			]),
 		]
 	}
 }
@@ -148,27 +217,36 @@ impl DrawableComponent for Stashing {
 		let chunks = Layout::default()
 			.direction(Direction::Horizontal)
 			.constraints(
-				[Constraint::Min(1), Constraint::Length(22)].as_ref(),
+				[
+					Constraint::Percentage(40),
+					Constraint::Percentage(60),
+				]
+				.as_ref(),
 			)
 			.split(rect);
 
 		let right_chunks = Layout::default()
 			.direction(Direction::Vertical)
 			.constraints(
-				[Constraint::Length(4), Constraint::Min(1)].as_ref(),
+				[Constraint::Length(5), Constraint::Min(1)].as_ref(),
 			)
 			.split(chunks[1]);
 
 		f.render_widget(
 			Paragraph::new(self.get_option_text())
-				.block(Block::default().borders(Borders::ALL).title(
-					strings::stashing_options_title(&self.key_config),
-				))
+				.block(
+					Block::default()
+						.borders(self.theme.pane_borders())
+						.title(strings::stashing_options_title(
+							&self.key_config,
+						)),
+				)
 				.alignment(Alignment::Left),
 			right_chunks[0],
 		);
 
 		self.index.draw(f, chunks[0])?;
+		self.diff.draw(f, right_chunks[1])?;
 
 		Ok(())
 	}
@@ -188,7 +266,28 @@ impl Component for Stashing {
 			);
 
 			out.push(CommandInfo::new(
-				strings::commands::stashing_save(&self.key_config),
+				strings::commands::diff_focus_right(&self.key_config),
+				self.can_focus_diff(),
+				!self.diff.focused() || force_all,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::diff_focus_left(&self.key_config),
+				true,
+				self.diff.focused() || force_all,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::stashing_save(
+					&self.key_config,
+					!self.index.marked().is_empty()
+						&& !self.options.untracked_only,
+				),
+				self.visible && !self.index.is_empty(),
+				self.visible || force_all,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::stashing_mark_file(
+					&self.key_config,
+				),
 				self.visible && !self.index.is_empty(),
 				self.visible || force_all,
 			));
@@ -206,6 +305,13 @@ impl Component for Stashing {
 				self.visible,
 				self.visible || force_all,
 			));
+			out.push(CommandInfo::new(
+				strings::commands::stashing_toggle_untracked_only(
+					&self.key_config,
+				),
+				self.visible,
+				self.visible || force_all,
+			));
 		}
 
 		visibility_blocking(self)
@@ -225,17 +331,45 @@ impl Component for Stashing {
 			if let Event::Key(k) = ev {
 				return if key_match(
 					k,
-					self.key_config.keys.stashing_save,
+					self.key_config.keys().stashing_save,
 				) && !self.index.is_empty()
 				{
 					self.queue.push(InternalEvent::PopupStashing(
 						self.options,
+						self.index.marked().iter().cloned().collect(),
 					));
 
 					Ok(EventState::Consumed)
 				} else if key_match(
 					k,
-					self.key_config.keys.stashing_toggle_index,
+					self.key_config.keys().focus_right,
+				) && !self.diff.focused()
+					&& self.can_focus_diff()
+				{
+					self.index.focus(false);
+					self.diff.focus(true);
+					Ok(EventState::Consumed)
+				} else if (key_match(
+					k,
+					self.key_config.keys().focus_left,
+				) || key_match(
+					k,
+					self.key_config.keys().exit_popup,
+				)) && self.diff.focused()
+				{
+					self.diff.focus(false);
+					self.index.focus(true);
+					Ok(EventState::Consumed)
+				} else if key_match(
+					k,
+					self.key_config.keys().stashing_mark_file,
+				) && self.index.focused()
+				{
+					self.index.toggle_mark();
+					Ok(EventState::Consumed)
+				} else if key_match(
+					k,
+					self.key_config.keys().stashing_toggle_index,
 				) {
 					self.options.keep_index =
 						!self.options.keep_index;
@@ -243,12 +377,21 @@ impl Component for Stashing {
 					Ok(EventState::Consumed)
 				} else if key_match(
 					k,
-					self.key_config.keys.stashing_toggle_untracked,
+					self.key_config.keys().stashing_toggle_untracked,
 				) {
 					self.options.stash_untracked =
 						!self.options.stash_untracked;
 					self.update()?;
 					Ok(EventState::Consumed)
+				} else if key_match(
+					k,
+					self.key_config
+						.keys()
+						.stashing_toggle_untracked_only,
+				) {
+					self.options.untracked_only =
+						!self.options.untracked_only;
+					Ok(EventState::Consumed)
 				} else {
 					Ok(EventState::NotConsumed)
 				};
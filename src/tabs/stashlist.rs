@@ -2,21 +2,28 @@ use crate::{
 	components::{
 		visibility_blocking, CommandBlocking, CommandInfo,
 		CommitList, Component, DrawableComponent, EventState,
-		InspectCommitOpen,
+		InspectCommitOpen, TextInputComponent,
 	},
 	keys::{key_match, SharedKeyConfig},
 	queue::{Action, InternalEvent, Queue, StackablePopupOpen},
 	strings,
-	ui::style::SharedTheme,
+	ui::{icons, style::SharedTheme},
 };
 use anyhow::Result;
-use asyncgit::sync::{self, CommitId, RepoPath, RepoPathRef};
+use asyncgit::sync::{
+	self, CommitId, CommitInfo, RepoPath, RepoPathRef,
+};
 use crossterm::event::Event;
+use std::collections::HashMap;
+use tui::layout::{Constraint, Direction, Layout};
 
 pub struct StashList {
 	repo: RepoPathRef,
 	list: CommitList,
+	stashes: Vec<CommitInfo>,
+	filter: TextInputComponent,
 	visible: bool,
+	reinstate_index: bool,
 	queue: Queue,
 	key_config: SharedKeyConfig,
 }
@@ -29,13 +36,28 @@ impl StashList {
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
 	) -> Self {
+		let mut filter = TextInputComponent::new(
+			theme.clone(),
+			key_config.clone(),
+			"",
+			&strings::stashlist_filter_msg(&key_config),
+			false,
+		);
+		filter.embed();
+
+		let mut list = CommitList::new(
+			&strings::stashlist_title(&key_config),
+			theme,
+			key_config.clone(),
+		);
+		list.set_icon(icons::STASH);
+
 		Self {
 			visible: false,
-			list: CommitList::new(
-				&strings::stashlist_title(&key_config),
-				theme,
-				key_config.clone(),
-			),
+			stashes: Vec::new(),
+			filter,
+			reinstate_index: false,
+			list,
 			queue: queue.clone(),
 			key_config,
 			repo,
@@ -46,23 +68,94 @@ impl StashList {
 	pub fn update(&mut self) -> Result<()> {
 		if self.is_visible() {
 			let stashes = sync::get_stashes(&self.repo.borrow())?;
-			let commits = sync::get_commits_info(
+			self.stashes = sync::get_commits_info(
 				&self.repo.borrow(),
 				stashes.as_slice(),
 				100,
 			)?;
 
-			self.list.set_count_total(commits.len());
-			self.list.items().set_items(0, commits);
+			self.list.set_branches(
+				self.stashes
+					.iter()
+					.filter_map(|stash| {
+						sync::stash_origin_branch(&stash.message).map(
+							|branch| (stash.id, branch.to_string()),
+						)
+					})
+					.collect::<HashMap<_, _>>(),
+			);
+
+			self.apply_filter();
 		}
 
 		Ok(())
 	}
 
+	/// shows stashes whose message (and with it the branch of
+	/// origin) contains the filter text, ignoring case
+	fn apply_filter(&mut self) {
+		let query = self.filter.get_text().to_lowercase();
+
+		let commits = self
+			.stashes
+			.iter()
+			.filter(|stash| {
+				query.is_empty()
+					|| stash.message.to_lowercase().contains(&query)
+			})
+			.cloned()
+			.collect::<Vec<_>>();
+
+		self.list.set_title(&if query.is_empty() {
+			self.title()
+		} else {
+			format!("{} [{}]", self.title(), self.filter.get_text())
+		});
+		self.list.set_count_total(commits.len());
+		self.list.items().set_items(0, commits);
+	}
+
+	fn title(&self) -> String {
+		if self.reinstate_index {
+			strings::stashlist_title_index(&self.key_config)
+		} else {
+			strings::stashlist_title(&self.key_config)
+		}
+	}
+
+	/// whether the filter text is being edited
+	pub fn is_filtering(&self) -> bool {
+		self.filter.is_visible()
+	}
+
+	fn filter_event(&mut self, ev: &Event) -> Result<EventState> {
+		if self.filter.event(ev)?.is_consumed() {
+			if !self.filter.is_visible() {
+				// closed by exit key
+				self.filter.clear();
+			}
+			self.apply_filter();
+			return Ok(EventState::Consumed);
+		}
+
+		if let Event::Key(k) = ev {
+			if key_match(k, self.key_config.keys().enter) {
+				self.filter.hide();
+				return Ok(EventState::Consumed);
+			}
+		}
+
+		self.list.event(ev)
+	}
+
 	fn apply_stash(&mut self) {
 		if let Some(e) = self.list.selected_entry() {
-			match sync::stash_apply(&self.repo.borrow(), e.id, false)
-			{
+			match sync::stash_apply(
+				&self.repo.borrow(),
+				e.id,
+				false,
+				self.reinstate_index,
+			) {
 				Ok(_) => {
 					self.queue.push(InternalEvent::TabSwitchStatus);
 				}
@@ -75,6 +168,11 @@ impl StashList {
 		}
 	}
 
+	fn toggle_reinstate_index(&mut self) {
+		self.reinstate_index = !self.reinstate_index;
+		self.apply_filter();
+	}
+
 	fn drop_stash(&mut self) {
 		if self.list.marked_count() > 0 {
 			self.queue.push(InternalEvent::ConfirmAction(
@@ -95,6 +193,12 @@ impl StashList {
 		}
 	}
 
+	fn stash_branch(&self) {
+		if let Some(e) = self.list.selected_entry() {
+			self.queue.push(InternalEvent::StashBranch(e.id));
+		}
+	}
+
 	fn inspect(&mut self) {
 		if let Some(e) = self.list.selected_entry() {
 			self.queue.push(InternalEvent::OpenPopup(
@@ -151,7 +255,20 @@ impl DrawableComponent for StashList {
 		f: &mut tui::Frame<B>,
 		rect: tui::layout::Rect,
 	) -> Result<()> {
-		self.list.draw(f, rect)?;
+		if self.filter.is_visible() {
+			let chunks = Layout::default()
+				.direction(Direction::Vertical)
+				.constraints(
+					[Constraint::Length(1), Constraint::Min(1)]
+						.as_ref(),
+				)
+				.split(rect);
+
+			self.filter.draw(f, chunks[0])?;
+			self.list.draw(f, chunks[1])?;
+		} else {
+			self.list.draw(f, rect)?;
+		}
 
 		Ok(())
 	}
@@ -166,6 +283,12 @@ impl Component for StashList {
 		if self.visible || force_all {
 			self.list.commands(out, force_all);
 
+			out.push(CommandInfo::new(
+				strings::commands::stashlist_filter(&self.key_config),
+				true,
+				!self.is_filtering() || force_all,
+			));
+
 			let selection_valid =
 				self.list.selected_entry().is_some();
 			out.push(CommandInfo::new(
@@ -178,6 +301,13 @@ impl Component for StashList {
 				selection_valid,
 				true,
 			));
+			out.push(CommandInfo::new(
+				strings::commands::stashlist_toggle_index(
+					&self.key_config,
+				),
+				true,
+				true,
+			));
 			out.push(CommandInfo::new(
 				strings::commands::stashlist_drop(
 					&self.key_config,
@@ -193,6 +323,11 @@ impl Component for StashList {
 				selection_valid,
 				true,
 			));
+			out.push(CommandInfo::new(
+				strings::commands::stashlist_branch(&self.key_config),
+				selection_valid,
+				true,
+			));
 		}
 
 		visibility_blocking(self)
@@ -203,28 +338,47 @@ impl Component for StashList {
 		ev: &crossterm::event::Event,
 	) -> Result<EventState> {
 		if self.is_visible() {
+			if self.is_filtering() {
+				return self.filter_event(ev);
+			}
+
 			if self.list.event(ev)?.is_consumed() {
 				return Ok(EventState::Consumed);
 			}
 
 			if let Event::Key(k) = ev {
-				if key_match(k, self.key_config.keys.enter) {
+				if key_match(k, self.key_config.keys().enter) {
 					self.pop_stash();
 				} else if key_match(
 					k,
-					self.key_config.keys.stash_apply,
+					self.key_config.keys().stash_apply,
 				) {
 					self.apply_stash();
 				} else if key_match(
 					k,
-					self.key_config.keys.stash_drop,
+					self.key_config.keys().stash_filter,
+				) {
+					self.filter.show()?;
+				} else if key_match(
+					k,
+					self.key_config.keys().stash_toggle_index,
+				) {
+					self.toggle_reinstate_index();
+				} else if key_match(
+					k,
+					self.key_config.keys().stash_drop,
 				) {
 					self.drop_stash();
 				} else if key_match(
 					k,
-					self.key_config.keys.stash_open,
+					self.key_config.keys().stash_open,
 				) {
 					self.inspect();
+				} else if key_match(
+					k,
+					self.key_config.keys().stash_branch,
+				) {
+					self.stash_branch();
 				}
 			}
 		}
@@ -1,10 +1,10 @@
 use crate::{
 	accessors,
 	components::{
-		command_pump, event_pump, visibility_blocking,
+		command_pump, event_pump, time_to_age, visibility_blocking,
 		ChangesComponent, CommandBlocking, CommandInfo, Component,
 		DiffComponent, DrawableComponent, EventState,
-		FileTreeItemKind, SharedOptions,
+		FileTreeItemKind, PaneLayout, SharedOptions,
 	},
 	keys::{key_match, SharedKeyConfig},
 	queue::{Action, InternalEvent, NeedsUpdate, Queue, ResetItem},
@@ -18,15 +18,21 @@ use asyncgit::{
 		self, status::StatusType, RepoPath, RepoPathRef, RepoState,
 	},
 	sync::{BranchCompare, CommitId},
-	AsyncDiff, AsyncGitNotification, AsyncStatus, DiffParams,
-	DiffType, PushType, StatusParams,
+	AsyncAutoFetch, AsyncDiff, AsyncGitNotification, AsyncStatus,
+	DiffParams, DiffType, PushType, StatusParams,
 };
 use crossbeam_channel::Sender;
-use crossterm::event::Event;
+use crossterm::event::{
+	Event, MouseButton, MouseEvent, MouseEventKind,
+};
 use itertools::Itertools;
-use std::convert::Into;
+use std::{
+	cell::Cell,
+	convert::Into,
+	time::{SystemTime, UNIX_EPOCH},
+};
 use tui::{
-	layout::{Alignment, Constraint, Direction, Layout},
+	layout::{Alignment, Constraint, Direction, Layout, Rect},
 	style::{Color, Style},
 	widgets::{Block, BorderType, Borders, Paragraph},
 };
@@ -68,14 +74,23 @@ pub struct Status {
 	git_diff: AsyncDiff,
 	has_remotes: bool,
 	git_state: RepoState,
+	/// what `HEAD` points to while it is detached
+	detached_head: Option<String>,
 	git_status_workdir: AsyncStatus,
 	git_status_stage: AsyncStatus,
 	git_branch_state: Option<BranchCompare>,
 	git_branch_name: cached::BranchName,
+	auto_fetch: AsyncAutoFetch,
 	queue: Queue,
 	git_action_executed: bool,
 	options: SharedOptions,
 	key_config: SharedKeyConfig,
+	/// where work dir, stage and diff were drawn last
+	pane_areas: Cell<[Rect; 3]>,
+	/// resizing the panes by dragging the border with the mouse
+	dragging_split: bool,
+	/// the focused pane takes up the whole tab
+	zoomed: bool,
 }
 
 impl DrawableComponent for Status {
@@ -85,7 +100,7 @@ impl DrawableComponent for Status {
 		rect: tui::layout::Rect,
 	) -> Result<()> {
 		let repo_unclean = self.repo_state_unclean();
-		let rects = if repo_unclean {
+		let rects = if repo_unclean || self.detached_head.is_some() {
 			Layout::default()
 				.direction(Direction::Vertical)
 				.constraints(
@@ -97,26 +112,18 @@ impl DrawableComponent for Status {
 			vec![rect]
 		};
 
-		let chunks = Layout::default()
-			.direction(Direction::Horizontal)
-			.constraints(
-				if self.focus == Focus::Diff {
-					[
-						Constraint::Percentage(30),
-						Constraint::Percentage(70),
-					]
-				} else {
-					[
-						Constraint::Percentage(50),
-						Constraint::Percentage(50),
-					]
-				}
-				.as_ref(),
-			)
-			.split(rects[0]);
+		let layout = self.options.borrow().status_layout;
+		let chunks = layout.split(
+			rects[0],
+			if self.focus == Focus::Diff { 30 } else { 50 },
+		);
 
 		let left_chunks = Layout::default()
-			.direction(Direction::Vertical)
+			.direction(if layout.vertical {
+				Direction::Horizontal
+			} else {
+				Direction::Vertical
+			})
 			.constraints(
 				if self.diff_target == DiffTarget::WorkingDir {
 					[
@@ -133,13 +140,25 @@ impl DrawableComponent for Status {
 			)
 			.split(chunks[0]);
 
-		self.index_wd.draw(f, left_chunks[0])?;
-		self.index.draw(f, left_chunks[1])?;
-		self.diff.draw(f, chunks[1])?;
-		self.draw_branch_state(f, &left_chunks);
+		if self.zoomed {
+			self.draw_zoomed(f, rects[0])?;
+		} else {
+			self.pane_areas.set([
+				left_chunks[0],
+				left_chunks[1],
+				chunks[1],
+			]);
+
+			self.index_wd.draw(f, left_chunks[0])?;
+			self.index.draw(f, left_chunks[1])?;
+			self.diff.draw(f, chunks[1])?;
+			self.draw_branch_state(f, &left_chunks);
+		}
 
 		if repo_unclean {
 			self.draw_repo_state(f, rects[1]);
+		} else if let Some(head) = &self.detached_head {
+			self.draw_detached_head(f, rects[1], head);
 		}
 
 		Ok(())
@@ -164,6 +183,7 @@ impl Status {
 			visible: true,
 			has_remotes: false,
 			git_state: RepoState::Clean,
+			detached_head: None,
 			focus: Focus::WorkDir,
 			diff_target: DiffTarget::WorkingDir,
 			index_wd: ChangesComponent::new(
@@ -205,9 +225,36 @@ impl Status {
 			git_action_executed: false,
 			git_branch_state: None,
 			git_branch_name: cached::BranchName::new(repo.clone()),
+			auto_fetch: AsyncAutoFetch::new(sender),
 			key_config,
 			options,
 			repo,
+			pane_areas: Cell::new([Rect::default(); 3]),
+			dragging_split: false,
+			zoomed: false,
+		}
+	}
+
+	fn draw_zoomed<B: tui::backend::Backend>(
+		&self,
+		f: &mut tui::Frame<B>,
+		area: Rect,
+	) -> Result<()> {
+		let none = Rect::default();
+
+		match self.focus {
+			Focus::WorkDir => {
+				self.pane_areas.set([area, none, none]);
+				self.index_wd.draw(f, area)
+			}
+			Focus::Stage => {
+				self.pane_areas.set([none, area, none]);
+				self.index.draw(f, area)
+			}
+			Focus::Diff => {
+				self.pane_areas.set([none, none, area]);
+				self.diff.draw(f, area)
+			}
 		}
 	}
 
@@ -217,6 +264,7 @@ impl Status {
 		chunks: &[tui::layout::Rect],
 	) {
 		if let Some(branch_name) = self.git_branch_name.last() {
+			let fetch_state = self.auto_fetch_state();
 			let ahead_behind = self
 				.git_branch_state
 				.as_ref()
@@ -228,8 +276,8 @@ impl Status {
 				});
 
 			let w = Paragraph::new(format!(
-				"{}{{{}}}",
-				ahead_behind, branch_name
+				"{}{}{{{}}}",
+				fetch_state, ahead_behind, branch_name
 			))
 			.alignment(Alignment::Right);
 
@@ -251,6 +299,29 @@ impl Status {
 		}
 	}
 
+	fn auto_fetch_state(&self) -> String {
+		if self.auto_fetch.is_pending() {
+			return format!("{} ", strings::auto_fetch_pending());
+		}
+
+		let unix_secs = |time: SystemTime| {
+			time.duration_since(UNIX_EPOCH)
+				.ok()
+				.and_then(|d| i64::try_from(d.as_secs()).ok())
+		};
+
+		self.auto_fetch
+			.last_fetch()
+			.and_then(|time| {
+				let age = time_to_age(
+					unix_secs(time)?,
+					unix_secs(SystemTime::now())?,
+				);
+				Some(format!("{} ", strings::auto_fetch_last(&age)))
+			})
+			.unwrap_or_default()
+	}
+
 	fn repo_state_text(repo: &RepoPath, state: &RepoState) -> String {
 		match state {
 			RepoState::Merge => {
@@ -289,10 +360,65 @@ impl Status {
 						.unwrap_or_default(),
 				)
 			}
+			RepoState::CherryPick => {
+				format!(
+					"Cherry-pick {}",
+					sync::cherrypick_head(repo)
+						.ok()
+						.as_ref()
+						.map(CommitId::get_short_string)
+						.unwrap_or_default(),
+				)
+			}
+			RepoState::Bisect => {
+				let state =
+					sync::bisect_state(repo).unwrap_or_default();
+
+				format!(
+					"Bad: {} Good: {} Started from: {}",
+					state
+						.bad
+						.as_ref()
+						.map(CommitId::get_short_string)
+						.unwrap_or_default(),
+					state
+						.good
+						.iter()
+						.map(CommitId::get_short_string)
+						.join(","),
+					state.start,
+				)
+			}
 			_ => format!("{:?}", state),
 		}
 	}
 
+	/// key hints for what can be done about the pending operation
+	fn repo_state_hints(&self) -> String {
+		let keys = self.key_config.keys();
+		let hint = |label: &str, key| {
+			format!("{} [{}]", label, self.key_config.get_hint(key))
+		};
+
+		match self.git_state {
+			RepoState::Merge
+			| RepoState::Revert
+			| RepoState::CherryPick => format!(
+				"{} {}",
+				hint("continue", keys.open_commit),
+				hint("abort", keys.abort_merge)
+			),
+			RepoState::Rebase => format!(
+				"{} {} {}",
+				hint("continue", keys.rebase_branch),
+				hint("skip", keys.skip_rebase),
+				hint("abort", keys.abort_merge)
+			),
+			RepoState::Bisect => hint("reset", keys.abort_merge),
+			RepoState::Clean | RepoState::Other => String::new(),
+		}
+	}
+
 	fn draw_repo_state<B: tui::backend::Backend>(
 		&self,
 		f: &mut tui::Frame<B>,
@@ -304,26 +430,83 @@ impl Status {
 				&self.git_state,
 			);
 
+			let block = Block::default()
+				.border_type(BorderType::Plain)
+				.borders(Borders::all())
+				.border_style(Style::default().fg(Color::Yellow))
+				.title(format!(
+					"Pending {}",
+					strings::repo_state_name(&self.git_state)
+				));
+			let inner = block.inner(r);
+
 			let w = Paragraph::new(txt)
-				.block(
-					Block::default()
-						.border_type(BorderType::Plain)
-						.borders(Borders::all())
-						.border_style(
-							Style::default().fg(Color::Yellow),
-						)
-						.title(format!(
-							"Pending {:?}",
-							self.git_state
-						)),
-				)
+				.block(block)
 				.style(Style::default().fg(Color::Red))
 				.alignment(Alignment::Left);
 
+			let hints = Paragraph::new(self.repo_state_hints())
+				.style(Style::default().fg(Color::Yellow))
+				.alignment(Alignment::Right);
+
 			f.render_widget(w, r);
+			f.render_widget(hints, inner);
 		}
 	}
 
+	fn draw_detached_head<B: tui::backend::Backend>(
+		&self,
+		f: &mut tui::Frame<B>,
+		r: tui::layout::Rect,
+		head: &str,
+	) {
+		let block = Block::default()
+			.border_type(BorderType::Plain)
+			.borders(Borders::all())
+			.border_style(Style::default().fg(Color::Yellow))
+			.title(strings::detached_head_title());
+		let inner = block.inner(r);
+
+		let w = Paragraph::new(strings::detached_head_msg(head))
+			.block(block)
+			.style(Style::default().fg(Color::Yellow))
+			.alignment(Alignment::Left);
+
+		let hints = Paragraph::new(format!(
+			"create branch [{}]",
+			self.key_config.get_hint(
+				self.key_config.keys().detached_create_branch
+			)
+		))
+		.style(Style::default().fg(Color::Yellow))
+		.alignment(Alignment::Right);
+
+		f.render_widget(w, r);
+		f.render_widget(hints, inner);
+	}
+
+	/// names the tags `HEAD` is detached at or its short hash
+	fn detached_head_label(repo: &RepoPath) -> Option<String> {
+		let id = sync::detached_head(repo).ok().flatten()?;
+
+		let tags = sync::get_tags(repo)
+			.ok()
+			.and_then(|tags| {
+				tags.get(&id).map(|tags| {
+					tags.iter()
+						.map(|tag| tag.name.as_str())
+						.join(", ")
+				})
+			})
+			.unwrap_or_default();
+
+		Some(if tags.is_empty() {
+			id.get_short_string()
+		} else {
+			format!("{} ({})", tags, id.get_short_string())
+		})
+	}
+
 	fn repo_state_unclean(&self) -> bool {
 		self.git_state != RepoState::Clean
 	}
@@ -369,6 +552,85 @@ impl Status {
 		Ok(false)
 	}
 
+	/// focuses the pane under the cursor on clicks and scrolling,
+	/// dragging the border between file lists and diff resizes them
+	fn mouse_event(
+		&mut self,
+		mouse: MouseEvent,
+	) -> Result<EventState> {
+		let [work_dir, stage, diff] = self.pane_areas.get();
+		let position = Rect::new(mouse.column, mouse.row, 1, 1);
+		let vertical = self.options.borrow().status_layout.vertical;
+		let on_split = if vertical {
+			(mouse.row + 1 == diff.y || mouse.row == diff.y)
+				&& (diff.x..diff.right()).contains(&mouse.column)
+		} else {
+			(mouse.column + 1 == diff.x || mouse.column == diff.x)
+				&& (diff.y..diff.bottom()).contains(&mouse.row)
+		};
+
+		match mouse.kind {
+			MouseEventKind::Down(MouseButton::Left) if on_split => {
+				self.dragging_split = true;
+				Ok(EventState::Consumed)
+			}
+			MouseEventKind::Drag(MouseButton::Left)
+				if self.dragging_split =>
+			{
+				let area = work_dir.union(diff);
+				let (offset, size) = if vertical {
+					(mouse.row.saturating_sub(area.y), area.height)
+				} else {
+					(mouse.column.saturating_sub(area.x), area.width)
+				};
+				let split =
+					u32::from(offset) * 100 / u32::from(size.max(1));
+
+				self.options.borrow_mut().status_layout.split =
+					u16::try_from(split).ok().map(|split| {
+						split.clamp(PaneLayout::MIN, PaneLayout::MAX)
+					});
+				Ok(EventState::Consumed)
+			}
+			MouseEventKind::Up(MouseButton::Left)
+				if self.dragging_split =>
+			{
+				self.dragging_split = false;
+
+				let layout = self.options.borrow().status_layout;
+				if let Err(e) = layout.save("status") {
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						format!("saving layout failed:\n{}", e),
+					));
+				}
+				Ok(EventState::Consumed)
+			}
+			MouseEventKind::Down(MouseButton::Left)
+			| MouseEventKind::ScrollDown
+			| MouseEventKind::ScrollUp => {
+				let focus = if work_dir.intersects(position) {
+					Some(Focus::WorkDir)
+				} else if stage.intersects(position) {
+					Some(Focus::Stage)
+				} else if diff.intersects(position)
+					&& (self.can_focus_diff()
+						|| self.is_focus_on_diff())
+				{
+					Some(Focus::Diff)
+				} else {
+					None
+				};
+
+				if let Some(focus) = focus {
+					self.switch_focus(focus)?;
+				}
+
+				Ok(EventState::NotConsumed)
+			}
+			_ => Ok(EventState::NotConsumed),
+		}
+	}
+
 	fn set_diff_target(&mut self, target: DiffTarget) {
 		self.diff_target = target;
 		let is_stage = self.diff_target == DiffTarget::Stage;
@@ -395,21 +657,40 @@ impl Status {
 	pub fn update(&mut self) -> Result<()> {
 		self.git_branch_name.lookup().map(Some).unwrap_or(None);
 
+		let (interval, prune, retries) = {
+			let options = self.options.borrow();
+			(
+				options.auto_fetch.interval(),
+				options.fetch_prune,
+				options.network_retries.retries(),
+			)
+		};
+		self.auto_fetch.set_interval(interval);
+		self.auto_fetch.update(
+			&self.repo.borrow(),
+			prune,
+			retries,
+		)?;
+
 		if self.is_visible() {
 			let config = self.options.borrow().status_show_untracked;
+			let sort_data =
+				self.options.borrow().status_sort.sort_data();
 
 			self.git_diff.refresh()?;
-			self.git_status_workdir.fetch(&StatusParams::new(
-				StatusType::WorkingDir,
-				config,
-			))?;
-			self.git_status_stage.fetch(&StatusParams::new(
-				StatusType::Stage,
-				config,
-			))?;
+			self.git_status_workdir.fetch(
+				&StatusParams::new(StatusType::WorkingDir, config)
+					.with_sort_data(sort_data),
+			)?;
+			self.git_status_stage.fetch(
+				&StatusParams::new(StatusType::Stage, config)
+					.with_sort_data(sort_data),
+			)?;
 
 			self.git_state = sync::repo_state(&self.repo.borrow())
 				.unwrap_or(RepoState::Clean);
+			self.detached_head =
+				Self::detached_head_label(&self.repo.borrow());
 
 			self.branch_compare();
 		}
@@ -422,6 +703,7 @@ impl Status {
 		self.git_diff.is_pending()
 			|| self.git_status_stage.is_pending()
 			|| self.git_status_workdir.is_pending()
+			|| self.auto_fetch.is_pending()
 	}
 
 	fn check_remotes(&mut self) {
@@ -439,6 +721,7 @@ impl Status {
 		match ev {
 			AsyncGitNotification::Diff => self.update_diff()?,
 			AsyncGitNotification::Status => self.update_status()?,
+			AsyncGitNotification::AutoFetch => self.auto_fetched(),
 			AsyncGitNotification::Push
 			| AsyncGitNotification::Pull
 			| AsyncGitNotification::CommitFiles => {
@@ -450,12 +733,45 @@ impl Status {
 		Ok(())
 	}
 
+	fn auto_fetched(&mut self) {
+		match self.auto_fetch.take_result() {
+			Some(Ok(())) => {
+				let behind_before = self
+					.git_branch_state
+					.as_ref()
+					.map_or(0, |state| state.behind);
+				self.branch_compare();
+				if let Some(state) = self
+					.git_branch_state
+					.as_ref()
+					.filter(|state| state.behind > behind_before)
+				{
+					self.queue.push(InternalEvent::ShowToast(
+						strings::toast_auto_fetched(state.behind),
+					));
+				}
+				self.queue.push(InternalEvent::Update(
+					NeedsUpdate::ALL | NeedsUpdate::BRANCHES,
+				));
+			}
+			// no popup for something the user did not ask for
+			Some(Err(e)) => log::error!("auto fetch: {}", e),
+			None => (),
+		}
+	}
+
 	fn update_status(&mut self) -> Result<()> {
 		let stage_status = self.git_status_stage.last()?;
-		self.index.set_items(&stage_status.items)?;
+		self.index.set_items(
+			&stage_status.items,
+			&stage_status.sort_keys,
+		)?;
 
 		let workdir_status = self.git_status_workdir.last()?;
-		self.index_wd.set_items(&workdir_status.items)?;
+		self.index_wd.set_items(
+			&workdir_status.items,
+			&workdir_status.sort_keys,
+		)?;
 
 		self.update_diff()?;
 		self.check_remotes();
@@ -563,9 +879,35 @@ impl Status {
 		if self.can_push() {
 			if let Some(branch) = self.git_branch_name.last() {
 				if force {
-					self.queue.push(InternalEvent::ConfirmAction(
-						Action::ForcePush(branch, force),
-					));
+					match sync::push_destination(
+						&self.repo.borrow(),
+						&branch,
+					) {
+						Ok(destination) => {
+							let destination = format!(
+								"{}/{}",
+								destination.remote,
+								destination.branch
+							);
+							self.queue.push(
+								InternalEvent::ConfirmAction(
+									Action::ForcePush(
+										branch,
+										force,
+										destination,
+									),
+								),
+							);
+						}
+						Err(e) => {
+							self.queue.push(
+								InternalEvent::ShowErrorMsg(format!(
+									"push failed:\n{}",
+									e
+								)),
+							);
+						}
+					}
 				} else {
 					self.queue.push(InternalEvent::Push(
 						branch,
@@ -626,6 +968,14 @@ impl Status {
 		self.git_state == RepoState::Revert
 	}
 
+	fn pending_cherrypick(&self) -> bool {
+		self.git_state == RepoState::CherryPick
+	}
+
+	fn pending_bisect(&self) -> bool {
+		self.git_state == RepoState::Bisect
+	}
+
 	pub fn revert_pending_state(&self) {
 		try_or_popup!(
 			self,
@@ -650,6 +1000,22 @@ impl Status {
 		);
 	}
 
+	fn skip_rebase(&self) {
+		try_or_popup!(
+			self,
+			"skip rebase step",
+			sync::skip_pending_rebase(&self.repo.borrow())
+		);
+	}
+
+	pub fn abort_bisect(&self) {
+		try_or_popup!(
+			self,
+			"reset bisect",
+			sync::abort_bisect(&self.repo.borrow())
+		);
+	}
+
 	fn commands_nav(
 		&self,
 		out: &mut Vec<CommandInfo>,
@@ -677,7 +1043,8 @@ impl Status {
 				strings::commands::select_staging(&self.key_config),
 				!focus_on_diff,
 				(self.visible
-					&& !focus_on_diff && self.focus == Focus::WorkDir)
+					&& !focus_on_diff
+					&& self.focus == Focus::WorkDir)
 					|| force_all,
 			)
 			.order(strings::order::NAV),
@@ -687,11 +1054,23 @@ impl Status {
 				strings::commands::select_unstaged(&self.key_config),
 				!focus_on_diff,
 				(self.visible
-					&& !focus_on_diff && self.focus == Focus::Stage)
+					&& !focus_on_diff
+					&& self.focus == Focus::Stage)
 					|| force_all,
 			)
 			.order(strings::order::NAV),
 		);
+		out.push(
+			CommandInfo::new(
+				strings::commands::toggle_zoom(
+					&self.key_config,
+					self.zoomed,
+				),
+				true,
+				self.visible || force_all,
+			)
+			.order(strings::order::NAV),
+		);
 	}
 
 	fn can_commit(&self) -> bool {
@@ -699,9 +1078,15 @@ impl Status {
 			&& !self.index.is_empty()
 			&& !self.pending_rebase()
 	}
+
+	/// nothing staged, committing needs an explicit confirmation
+	fn can_commit_empty(&self) -> bool {
+		self.index.is_empty() && !self.pending_rebase()
+	}
 }
 
 impl Component for Status {
+	#[allow(clippy::too_many_lines)]
 	fn commands(
 		&self,
 		out: &mut Vec<CommandInfo>,
@@ -725,6 +1110,15 @@ impl Component for Status {
 				.order(-1),
 			);
 
+			out.push(
+				CommandInfo::new(
+					strings::commands::commit_empty(&self.key_config),
+					true,
+					self.can_commit_empty() || force_all,
+				)
+				.hidden(),
+			);
+
 			out.push(CommandInfo::new(
 				strings::commands::open_branch_select_popup(
 					&self.key_config,
@@ -733,6 +1127,14 @@ impl Component for Status {
 				!focus_on_diff,
 			));
 
+			out.push(CommandInfo::new(
+				strings::commands::open_recent_branches(
+					&self.key_config,
+				),
+				true,
+				!focus_on_diff,
+			));
+
 			out.push(CommandInfo::new(
 				strings::commands::status_push(&self.key_config),
 				self.can_push(),
@@ -745,11 +1147,21 @@ impl Component for Status {
 				true,
 				self.can_push() && !focus_on_diff,
 			));
+			out.push(CommandInfo::new(
+				strings::commands::status_push_to(&self.key_config),
+				self.has_remotes,
+				!focus_on_diff,
+			));
 			out.push(CommandInfo::new(
 				strings::commands::status_pull(&self.key_config),
 				self.can_pull(),
 				!focus_on_diff,
 			));
+			out.push(CommandInfo::new(
+				strings::commands::status_fetch_all(&self.key_config),
+				self.has_remotes,
+				!focus_on_diff,
+			));
 
 			out.push(CommandInfo::new(
 				strings::commands::undo_commit(&self.key_config),
@@ -770,6 +1182,12 @@ impl Component for Status {
 				self.pending_rebase() || force_all,
 			));
 
+			out.push(CommandInfo::new(
+				strings::commands::skip_rebase(&self.key_config),
+				true,
+				self.pending_rebase() || force_all,
+			));
+
 			out.push(CommandInfo::new(
 				strings::commands::abort_rebase(&self.key_config),
 				true,
@@ -782,11 +1200,44 @@ impl Component for Status {
 				self.pending_revert() || force_all,
 			));
 
+			out.push(CommandInfo::new(
+				strings::commands::abort_cherrypick(&self.key_config),
+				true,
+				self.pending_cherrypick() || force_all,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::abort_bisect(&self.key_config),
+				true,
+				self.pending_bisect() || force_all,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::detached_create_branch(
+					&self.key_config,
+				),
+				true,
+				(self.detached_head.is_some() && !focus_on_diff)
+					|| force_all,
+			));
+
 			out.push(CommandInfo::new(
 				strings::commands::view_submodules(&self.key_config),
 				true,
 				true,
 			));
+
+			out.push(CommandInfo::new(
+				strings::commands::view_worktrees(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::view_remotes(&self.key_config),
+				true,
+				true,
+			));
 		}
 
 		{
@@ -812,6 +1263,12 @@ impl Component for Status {
 		ev: &crossterm::event::Event,
 	) -> Result<EventState> {
 		if self.visible {
+			if let Event::Mouse(mouse) = ev {
+				if self.mouse_event(*mouse)?.is_consumed() {
+					return Ok(EventState::Consumed);
+				}
+			}
+
 			if event_pump(ev, self.components_mut().as_mut_slice())?
 				.is_consumed()
 			{
@@ -820,9 +1277,11 @@ impl Component for Status {
 			}
 
 			if let Event::Key(k) = ev {
-				return if key_match(k, self.key_config.keys.edit_file)
-					&& (self.can_focus_diff()
-						|| self.is_focus_on_diff())
+				return if key_match(
+					k,
+					self.key_config.keys().edit_file,
+				) && (self.can_focus_diff()
+					|| self.is_focus_on_diff())
 				{
 					if let Some((path, _)) = self.selected_path() {
 						self.queue.push(
@@ -834,64 +1293,108 @@ impl Component for Status {
 					Ok(EventState::Consumed)
 				} else if key_match(
 					k,
-					self.key_config.keys.open_commit,
+					self.key_config.keys().toggle_zoom,
+				) {
+					self.zoomed = !self.zoomed;
+					Ok(EventState::Consumed)
+				} else if key_match(
+					k,
+					self.key_config.keys().open_commit,
 				) && self.can_commit()
 				{
 					self.queue.push(InternalEvent::OpenCommit);
 					Ok(EventState::Consumed)
 				} else if key_match(
 					k,
-					self.key_config.keys.toggle_workarea,
+					self.key_config.keys().open_commit,
+				) && self.can_commit_empty()
+				{
+					self.queue.push(InternalEvent::ConfirmAction(
+						Action::CommitEmpty,
+					));
+					Ok(EventState::Consumed)
+				} else if key_match(
+					k,
+					self.key_config.keys().toggle_workarea,
 				) && !self.is_focus_on_diff()
 				{
 					self.switch_focus(self.focus.toggled_focus())
 						.map(Into::into)
 				} else if key_match(
 					k,
-					self.key_config.keys.focus_right,
+					self.key_config.keys().focus_right,
 				) && self.can_focus_diff()
 				{
 					self.switch_focus(Focus::Diff).map(Into::into)
 				} else if key_match(
 					k,
-					self.key_config.keys.focus_left,
+					self.key_config.keys().focus_left,
 				) {
 					self.switch_focus(match self.diff_target {
 						DiffTarget::Stage => Focus::Stage,
 						DiffTarget::WorkingDir => Focus::WorkDir,
 					})
 					.map(Into::into)
-				} else if key_match(k, self.key_config.keys.move_down)
-					&& self.focus == Focus::WorkDir
+				} else if key_match(
+					k,
+					self.key_config.keys().move_down,
+				) && self.focus == Focus::WorkDir
 					&& !self.index.is_empty()
 				{
 					self.switch_focus(Focus::Stage).map(Into::into)
-				} else if key_match(k, self.key_config.keys.move_up)
+				} else if key_match(k, self.key_config.keys().move_up)
 					&& self.focus == Focus::Stage
 					&& !self.index_wd.is_empty()
 				{
 					self.switch_focus(Focus::WorkDir).map(Into::into)
 				} else if key_match(
 					k,
-					self.key_config.keys.select_branch,
+					self.key_config.keys().select_branch,
 				) && !self.is_focus_on_diff()
 				{
 					self.queue.push(InternalEvent::SelectBranch);
 					Ok(EventState::Consumed)
 				} else if key_match(
 					k,
-					self.key_config.keys.force_push,
+					self.key_config.keys().detached_create_branch,
+				) && !self.is_focus_on_diff()
+					&& self.detached_head.is_some()
+				{
+					self.queue.push(InternalEvent::CreateBranch);
+					Ok(EventState::Consumed)
+				} else if key_match(
+					k,
+					self.key_config.keys().recent_branches,
+				) && !self.is_focus_on_diff()
+				{
+					self.queue
+						.push(InternalEvent::OpenRecentBranches);
+					Ok(EventState::Consumed)
+				} else if key_match(
+					k,
+					self.key_config.keys().force_push,
 				) && !self.is_focus_on_diff()
 					&& self.can_push()
 				{
 					self.push(true);
 					Ok(EventState::Consumed)
-				} else if key_match(k, self.key_config.keys.push)
+				} else if key_match(k, self.key_config.keys().push)
 					&& !self.is_focus_on_diff()
 				{
 					self.push(false);
 					Ok(EventState::Consumed)
-				} else if key_match(k, self.key_config.keys.pull)
+				} else if key_match(k, self.key_config.keys().push_to)
+					&& !self.is_focus_on_diff()
+					&& self.has_remotes
+				{
+					if let Some(branch) = self.git_branch_name.last()
+					{
+						self.queue.push(
+							InternalEvent::OpenPushTarget(branch),
+						);
+					}
+					Ok(EventState::Consumed)
+				} else if key_match(k, self.key_config.keys().pull)
 					&& !self.is_focus_on_diff()
 					&& self.can_pull()
 				{
@@ -899,7 +1402,15 @@ impl Component for Status {
 					Ok(EventState::Consumed)
 				} else if key_match(
 					k,
-					self.key_config.keys.undo_commit,
+					self.key_config.keys().fetch_all,
+				) && !self.is_focus_on_diff()
+					&& self.has_remotes
+				{
+					self.queue.push(InternalEvent::FetchRemotes);
+					Ok(EventState::Consumed)
+				} else if key_match(
+					k,
+					self.key_config.keys().undo_commit,
 				) && !self.is_focus_on_diff()
 				{
 					self.undo_last_commit();
@@ -909,7 +1420,7 @@ impl Component for Status {
 					Ok(EventState::Consumed)
 				} else if key_match(
 					k,
-					self.key_config.keys.abort_merge,
+					self.key_config.keys().abort_merge,
 				) {
 					if self.can_abort_merge() {
 						self.queue.push(
@@ -929,12 +1440,24 @@ impl Component for Status {
 								Action::AbortRevert,
 							),
 						);
+					} else if self.pending_cherrypick() {
+						self.queue.push(
+							InternalEvent::ConfirmAction(
+								Action::AbortCherryPick,
+							),
+						);
+					} else if self.pending_bisect() {
+						self.queue.push(
+							InternalEvent::ConfirmAction(
+								Action::AbortBisect,
+							),
+						);
 					}
 
 					Ok(EventState::Consumed)
 				} else if key_match(
 					k,
-					self.key_config.keys.rebase_branch,
+					self.key_config.keys().rebase_branch,
 				) && self.pending_rebase()
 				{
 					self.continue_rebase();
@@ -944,10 +1467,32 @@ impl Component for Status {
 					Ok(EventState::Consumed)
 				} else if key_match(
 					k,
-					self.key_config.keys.view_submodules,
+					self.key_config.keys().skip_rebase,
+				) && self.pending_rebase()
+				{
+					self.skip_rebase();
+					self.queue.push(InternalEvent::Update(
+						NeedsUpdate::ALL,
+					));
+					Ok(EventState::Consumed)
+				} else if key_match(
+					k,
+					self.key_config.keys().view_submodules,
 				) {
 					self.queue.push(InternalEvent::ViewSubmodules);
 					Ok(EventState::Consumed)
+				} else if key_match(
+					k,
+					self.key_config.keys().view_worktrees,
+				) {
+					self.queue.push(InternalEvent::ViewWorktrees);
+					Ok(EventState::Consumed)
+				} else if key_match(
+					k,
+					self.key_config.keys().view_remotes,
+				) {
+					self.queue.push(InternalEvent::ViewRemotes);
+					Ok(EventState::Consumed)
 				} else {
 					Ok(EventState::NotConsumed)
 				};
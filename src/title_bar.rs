@@ -0,0 +1,188 @@
+use crate::{
+	keys::SharedKeyConfig,
+	strings,
+	ui::style::{SharedTheme, TitleSegmentKind},
+};
+use asyncgit::{
+	cached,
+	sync::{self, BranchCompare, RepoPathRef, RepoState},
+};
+use chrono::Local;
+use crossterm::{terminal::SetTitle, ExecutableCommand};
+use std::{
+	io::{self, Write},
+	path::Path,
+};
+use tui::{
+	backend::Backend,
+	layout::{Alignment, Rect},
+	text::{Span, Spans},
+	widgets::Paragraph,
+	Frame,
+};
+use unicode_width::UnicodeWidthStr;
+
+/// repository information drawn right of the tabs, which segments
+/// are shown and in which order is defined by the theme
+pub struct TitleBar {
+	repo: RepoPathRef,
+	theme: SharedTheme,
+	key_config: SharedKeyConfig,
+	branch_name: cached::BranchName,
+	branch_compare: Option<BranchCompare>,
+	stashes: usize,
+	state: RepoState,
+	repo_name: String,
+}
+
+impl TitleBar {
+	///
+	pub fn new(
+		repo: RepoPathRef,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			branch_name: cached::BranchName::new(repo.clone()),
+			repo,
+			theme,
+			key_config,
+			branch_compare: None,
+			stashes: 0,
+			state: RepoState::Clean,
+			repo_name: String::new(),
+		}
+	}
+
+	///
+	pub fn update(&mut self) {
+		let repo = self.repo.borrow();
+
+		let branch = self.branch_name.lookup().ok();
+		self.branch_compare = branch.and_then(|branch| {
+			sync::branch_compare_upstream(&repo, &branch).ok()
+		});
+		self.stashes = sync::get_stashes(&repo)
+			.map_or(0, |stashes| stashes.len());
+		self.state =
+			sync::repo_state(&repo).unwrap_or(RepoState::Clean);
+		self.repo_name = sync::utils::repo_work_dir(&repo)
+			.ok()
+			.and_then(|dir| {
+				Path::new(&dir)
+					.file_name()
+					.map(|name| name.to_string_lossy().to_string())
+			})
+			.unwrap_or_default();
+	}
+
+	/// window title of the terminal like `gitui: repo (branch) [rebase]`
+	pub fn terminal_title(&self) -> String {
+		strings::terminal_title(
+			&self.repo_name,
+			self.branch_name.last().as_deref(),
+			&self.state,
+		)
+	}
+
+	/// `None` if there is nothing to show for the segment
+	fn segment_text(&self, kind: TitleSegmentKind) -> Option<String> {
+		match kind {
+			TitleSegmentKind::Branch => self.branch_name.last(),
+			TitleSegmentKind::AheadBehind => {
+				self.branch_compare.as_ref().map(|compare| {
+					format!(
+						"\u{2191}{} \u{2193}{}",
+						compare.ahead, compare.behind
+					)
+				})
+			}
+			TitleSegmentKind::Stashes => (self.stashes > 0)
+				.then(|| strings::title_bar_stashes(self.stashes)),
+			TitleSegmentKind::State => {
+				(self.state != RepoState::Clean).then(|| {
+					strings::repo_state_name(&self.state).to_string()
+				})
+			}
+			TitleSegmentKind::RepoName => {
+				(!self.repo_name.is_empty())
+					.then(|| self.repo_name.clone())
+			}
+			TitleSegmentKind::Time => {
+				Some(Local::now().format("%H:%M").to_string())
+			}
+		}
+	}
+
+	/// draws the segments right aligned into the first line of `r`,
+	/// leaving out the last ones if they do not fit into `max_width`
+	pub fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		r: Rect,
+		max_width: u16,
+	) {
+		let splitter = strings::title_bar_splitter(&self.key_config);
+
+		let mut spans = Vec::new();
+		let mut width = 0;
+
+		for segment in self.theme.title_segments().iter() {
+			if let Some(text) = self.segment_text(segment.kind) {
+				let splitter_width = if spans.is_empty() {
+					0
+				} else {
+					splitter.width()
+				};
+
+				if width + splitter_width + text.width()
+					> usize::from(max_width)
+				{
+					break;
+				}
+
+				if splitter_width > 0 {
+					spans.push(Span::styled(
+						splitter.clone(),
+						self.theme.text(false, false),
+					));
+				}
+
+				width += splitter_width + text.width();
+				spans.push(Span::styled(text, segment.style()));
+			}
+		}
+
+		let r = Rect {
+			height: 1.min(r.height),
+			..r
+		};
+
+		f.render_widget(
+			Paragraph::new(Spans::from(spans))
+				.alignment(Alignment::Right),
+			r,
+		);
+	}
+}
+
+/// pushes the current window title onto the title stack of the
+/// terminal, terminals without one ignore this
+pub fn save_terminal_title() -> io::Result<()> {
+	let mut stdout = io::stdout();
+	stdout.write_all(b"\x1b[22;0t")?;
+	stdout.flush()
+}
+
+/// brings back the window title saved by `save_terminal_title`
+pub fn restore_terminal_title() -> io::Result<()> {
+	let mut stdout = io::stdout();
+	stdout.write_all(b"\x1b[23;0t")?;
+	stdout.flush()
+}
+
+///
+pub fn set_terminal_title(title: &str) -> io::Result<()> {
+	io::stdout().execute(SetTitle(title))?;
+	Ok(())
+}
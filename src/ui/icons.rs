@@ -0,0 +1,82 @@
+use asyncgit::StatusItemType;
+use std::path::Path;
+
+pub const BRANCH: &str = "\u{e725}";
+pub const REMOTE_BRANCH: &str = "\u{f0c2}";
+pub const TAG: &str = "\u{f02b}";
+pub const STASH: &str = "\u{f01c}";
+pub const FOLDER_CLOSED: &str = "\u{f07b}";
+pub const FOLDER_OPEN: &str = "\u{f07c}";
+
+const FILE: &str = "\u{f15b}";
+
+/// glyph for the type of a file, looked up by name and extension
+pub fn file(path: &str) -> &'static str {
+	let path = Path::new(path);
+	let name = path
+		.file_name()
+		.and_then(std::ffi::OsStr::to_str)
+		.unwrap_or_default();
+
+	match name {
+		".gitignore" | ".gitattributes" | ".gitmodules" => {
+			return "\u{e702}"
+		}
+		"Dockerfile" => return "\u{f308}",
+		"Makefile" => return "\u{e779}",
+		"LICENSE" => return "\u{f0219}",
+		_ => (),
+	}
+
+	let extension = path
+		.extension()
+		.and_then(std::ffi::OsStr::to_str)
+		.unwrap_or_default()
+		.to_lowercase();
+
+	match extension.as_str() {
+		"rs" => "\u{e7a8}",
+		"md" | "markdown" => "\u{f48a}",
+		"toml" | "ini" | "cfg" | "conf" => "\u{e615}",
+		"yml" | "yaml" => "\u{e6a8}",
+		"json" | "ron" => "\u{e60b}",
+		"lock" => "\u{f023}",
+		"js" | "mjs" | "cjs" => "\u{e74e}",
+		"ts" | "tsx" => "\u{e628}",
+		"py" => "\u{e606}",
+		"go" => "\u{e627}",
+		"c" => "\u{e61e}",
+		"cpp" | "cc" | "cxx" | "hpp" => "\u{e61d}",
+		"h" => "\u{f0fd}",
+		"java" => "\u{e738}",
+		"rb" => "\u{e739}",
+		"html" | "htm" => "\u{e736}",
+		"css" | "scss" => "\u{e749}",
+		"sh" | "bash" | "zsh" | "fish" => "\u{f489}",
+		"png" | "jpg" | "jpeg" | "gif" | "svg" | "ico" => "\u{f1c5}",
+		"txt" => "\u{f15c}",
+		"zip" | "gz" | "tar" | "xz" => "\u{f410}",
+		_ => FILE,
+	}
+}
+
+/// marker replacing the status letter of a changed file
+pub const fn status(item_type: StatusItemType) -> &'static str {
+	match item_type {
+		StatusItemType::Modified => "\u{f044}",
+		StatusItemType::New => "\u{f067}",
+		StatusItemType::Deleted => "\u{f068}",
+		StatusItemType::Renamed => "\u{f061}",
+		StatusItemType::Typechange => "\u{f0ec}",
+		StatusItemType::Conflicted => "\u{f071}",
+	}
+}
+
+/// glyph of a folder in a file tree
+pub const fn folder(collapsed: bool) -> &'static str {
+	if collapsed {
+		FOLDER_CLOSED
+	} else {
+		FOLDER_OPEN
+	}
+}
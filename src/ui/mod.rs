@@ -1,3 +1,6 @@
+/// Nerd Font glyphs used while the icon option is on, see
+/// <https://www.nerdfonts.com/cheat-sheet>
+pub mod icons;
 mod reflow;
 mod scrollbar;
 mod scrolllist;
@@ -6,13 +9,17 @@ pub mod style;
 mod syntax_text;
 
 use filetreelist::MoveSelection;
-pub use scrollbar::draw_scrollbar;
+pub use scrollbar::{draw_scrollbar, scrollbar_position};
 pub use scrolllist::{draw_list, draw_list_block};
 pub use stateful_paragraph::{
 	ParagraphState, ScrollPos, StatefulParagraph,
 };
 pub use syntax_text::{AsyncSyntaxJob, SyntaxText};
-use tui::layout::{Constraint, Direction, Layout, Rect};
+use tui::{
+	layout::{Constraint, Direction, Layout, Rect},
+	text::{Span, Spans},
+};
+use unicode_width::UnicodeWidthChar;
 
 use crate::keys::{key_match, SharedKeyConfig};
 
@@ -118,27 +125,103 @@ pub fn common_nav(
 	key: &crossterm::event::KeyEvent,
 	key_config: &SharedKeyConfig,
 ) -> Option<MoveSelection> {
-	if key_match(key, key_config.keys.move_down) {
+	if key_match(key, key_config.keys().move_down) {
 		Some(MoveSelection::Down)
-	} else if key_match(key, key_config.keys.move_up) {
+	} else if key_match(key, key_config.keys().move_up) {
 		Some(MoveSelection::Up)
-	} else if key_match(key, key_config.keys.page_up) {
+	} else if key_match(key, key_config.keys().page_up) {
 		Some(MoveSelection::PageUp)
-	} else if key_match(key, key_config.keys.page_down) {
+	} else if key_match(key, key_config.keys().page_down) {
 		Some(MoveSelection::PageDown)
-	} else if key_match(key, key_config.keys.move_right) {
+	} else if key_match(key, key_config.keys().move_right) {
 		Some(MoveSelection::Right)
-	} else if key_match(key, key_config.keys.move_left) {
+	} else if key_match(key, key_config.keys().move_left) {
 		Some(MoveSelection::Left)
-	} else if key_match(key, key_config.keys.home)
-		|| key_match(key, key_config.keys.shift_up)
+	} else if key_match(key, key_config.keys().home)
+		|| key_match(key, key_config.keys().shift_up)
 	{
 		Some(MoveSelection::Top)
-	} else if key_match(key, key_config.keys.end)
-		|| key_match(key, key_config.keys.shift_down)
+	} else if key_match(key, key_config.keys().end)
+		|| key_match(key, key_config.keys().shift_down)
 	{
 		Some(MoveSelection::End)
 	} else {
 		None
 	}
 }
+
+/// splits `spans` into lines of at most `width` columns, breaking
+/// anywhere since code and diffs are not prose
+pub fn wrap_spans(
+	spans: &Spans,
+	width: usize,
+) -> Vec<Spans<'static>> {
+	let width = width.max(1);
+	let mut lines = Vec::new();
+	let mut line = Vec::new();
+	let mut line_width = 0;
+
+	for span in &spans.0 {
+		let mut text = String::new();
+
+		for c in span.content.chars() {
+			let char_width = c.width().unwrap_or_default();
+
+			if line_width > 0 && line_width + char_width > width {
+				if !text.is_empty() {
+					line.push(Span::styled(
+						std::mem::take(&mut text),
+						span.style,
+					));
+				}
+				lines.push(Spans::from(std::mem::take(&mut line)));
+				line_width = 0;
+			}
+
+			text.push(c);
+			line_width += char_width;
+		}
+
+		if !text.is_empty() {
+			line.push(Span::styled(text, span.style));
+		}
+	}
+
+	lines.push(Spans::from(line));
+	lines
+}
+
+#[cfg(test)]
+mod tests {
+	use super::wrap_spans;
+	use pretty_assertions::assert_eq;
+	use tui::{
+		style::{Color, Style},
+		text::{Span, Spans},
+	};
+
+	#[test]
+	fn test_wrap_spans() {
+		let red = Style::default().fg(Color::Red);
+		let spans = Spans::from(vec![
+			Span::raw("abcd"),
+			Span::styled("ef", red),
+		]);
+
+		assert_eq!(
+			wrap_spans(&spans, 3),
+			vec![
+				Spans::from(Span::raw("abc")),
+				Spans::from(vec![
+					Span::raw("d"),
+					Span::styled("ef", red)
+				]),
+			]
+		);
+		assert_eq!(
+			wrap_spans(&Spans::from("\u{1f44d}\u{1f44d}"), 3),
+			vec![Spans::from("\u{1f44d}"), Spans::from("\u{1f44d}"),]
+		);
+		assert_eq!(wrap_spans(&Spans::from(""), 3).len(), 1);
+	}
+}
@@ -1,4 +1,5 @@
 use super::style::SharedTheme;
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
 use easy_cast::CastFloat;
 use std::convert::TryFrom;
 use tui::{
@@ -30,28 +31,35 @@ impl Scrollbar {
 	}
 }
 
+/// column of the bar and the rows of its track inside `area`,
+/// `None` if there is no room for a scrollbar
+fn track(area: Rect) -> Option<(u16, u16, u16)> {
+	if area.height <= 2 {
+		return None;
+	}
+
+	let right = area.right().saturating_sub(1);
+	if right <= area.left() {
+		return None;
+	}
+
+	let scrollbar_area = area.inner(&Margin {
+		horizontal: 0,
+		vertical: 1,
+	});
+
+	Some((right, scrollbar_area.top(), scrollbar_area.height))
+}
+
 impl Widget for Scrollbar {
 	fn render(self, area: Rect, buf: &mut Buffer) {
-		if area.height <= 2 {
-			return;
-		}
-
 		if self.max == 0 {
 			return;
 		}
 
-		let right = area.right().saturating_sub(1);
-		if right <= area.left() {
-			return;
-		};
-
-		let (bar_top, bar_height) = {
-			let scrollbar_area = area.inner(&Margin {
-				horizontal: 0,
-				vertical: 1,
-			});
-
-			(scrollbar_area.top(), scrollbar_area.height)
+		let (right, bar_top, bar_height) = match track(area) {
+			Some(track) => track,
+			None => return,
 		};
 
 		for y in bar_top..(bar_top + bar_height) {
@@ -69,6 +77,42 @@ impl Widget for Scrollbar {
 	}
 }
 
+/// position between `0` and `max` to scroll to when clicking or
+/// dragging with the left button on the scrollbar drawn into `r`,
+/// `None` if the mouse is somewhere else
+pub fn scrollbar_position(
+	r: Rect,
+	max: usize,
+	mouse: MouseEvent,
+) -> Option<usize> {
+	if !matches!(
+		mouse.kind,
+		MouseEventKind::Down(MouseButton::Left)
+			| MouseEventKind::Drag(MouseButton::Left)
+	) || max == 0
+	{
+		return None;
+	}
+
+	let (right, bar_top, bar_height) = track(r)?;
+	if mouse.column != right
+		|| !(r.top()..r.bottom()).contains(&mouse.row)
+	{
+		return None;
+	}
+
+	let offset = mouse
+		.row
+		.clamp(bar_top, bar_top + bar_height.saturating_sub(1))
+		- bar_top;
+
+	Some(if bar_height > 1 {
+		usize::from(offset) * max / usize::from(bar_height - 1)
+	} else {
+		0
+	})
+}
+
 pub fn draw_scrollbar<B: Backend>(
 	f: &mut Frame<B>,
 	r: Rect,
@@ -78,5 +122,36 @@ pub fn draw_scrollbar<B: Backend>(
 ) {
 	let mut widget = Scrollbar::new(max, pos);
 	widget.style_pos = theme.scroll_bar_pos();
+	widget.style_bar = theme.scroll_bar_track();
 	f.render_widget(widget, r);
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crossterm::event::KeyModifiers;
+	use pretty_assertions::assert_eq;
+
+	fn click(column: u16, row: u16) -> MouseEvent {
+		MouseEvent {
+			kind: MouseEventKind::Down(MouseButton::Left),
+			column,
+			row,
+			modifiers: KeyModifiers::empty(),
+		}
+	}
+
+	#[test]
+	fn test_scrollbar_position() {
+		let r = Rect::new(0, 0, 10, 12);
+
+		assert_eq!(scrollbar_position(r, 100, click(9, 1)), Some(0));
+		assert_eq!(
+			scrollbar_position(r, 100, click(9, 10)),
+			Some(100)
+		);
+		assert_eq!(scrollbar_position(r, 100, click(9, 0)), Some(0));
+		assert_eq!(scrollbar_position(r, 100, click(8, 5)), None);
+		assert_eq!(scrollbar_position(r, 0, click(9, 5)), None);
+	}
+}
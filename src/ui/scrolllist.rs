@@ -6,7 +6,7 @@ use tui::{
 	layout::Rect,
 	style::Style,
 	text::{Span, Text},
-	widgets::{Block, Borders, List, ListItem, Widget},
+	widgets::{Block, List, ListItem, Widget},
 	Frame,
 };
 
@@ -73,7 +73,7 @@ pub fn draw_list<'b, B: Backend, L, S>(
 	let list = ScrollableList::new(items).block(
 		Block::default()
 			.title(Span::styled(title, theme.title(selected)))
-			.borders(Borders::ALL)
+			.borders(theme.pane_borders())
 			.border_style(theme.block(selected)),
 	);
 	f.render_widget(list, r);
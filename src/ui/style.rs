@@ -1,3 +1,4 @@
+use crate::options_file::OptionsFile;
 use anyhow::Result;
 use asyncgit::{DiffLineType, StatusItemType};
 use ron::{
@@ -6,17 +7,146 @@ use ron::{
 };
 use serde::{Deserialize, Serialize};
 use std::{
+	cell::{Cell, Ref, RefCell},
 	fs::{self, File},
 	io::{Read, Write},
 	path::PathBuf,
 	rc::Rc,
 };
-use tui::style::{Color, Modifier, Style};
+use tui::{
+	layout::Rect,
+	style::{Color, Modifier, Style},
+	widgets::{Block, Borders},
+};
 
 pub type SharedTheme = Rc<Theme>;
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct Theme {
+/// built-in themes to use instead of the theme file
+#[derive(
+	Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize,
+)]
+pub enum ThemePreset {
+	/// the theme file, `theme.ron` or the one passed by `--theme`
+	Custom,
+	Dark,
+	Light,
+	Solarized,
+	HighContrast,
+}
+
+impl Default for ThemePreset {
+	fn default() -> Self {
+		Self::Custom
+	}
+}
+
+impl ThemePreset {
+	pub const ALL: [Self; 5] = [
+		Self::Custom,
+		Self::Dark,
+		Self::Light,
+		Self::Solarized,
+		Self::HighContrast,
+	];
+
+	pub const fn name(self) -> &'static str {
+		match self {
+			Self::Custom => "custom",
+			Self::Dark => "dark",
+			Self::Light => "light",
+			Self::Solarized => "solarized",
+			Self::HighContrast => "high contrast",
+		}
+	}
+
+	pub const fn description(self) -> &'static str {
+		match self {
+			Self::Custom => "colors of the theme file",
+			Self::Dark => "default colors for dark terminals",
+			Self::Light => "for terminals with a light background",
+			Self::Solarized => "solarized dark palette",
+			Self::HighContrast => {
+				"bright colors and strong selection"
+			}
+		}
+	}
+
+	/// `None` for `Custom` which is read from the theme file
+	pub fn theme(self) -> Option<Theme> {
+		let colors = match self {
+			Self::Custom => return None,
+			Self::Dark => ThemeColors::default(),
+			Self::Light => ThemeColors::light(),
+			Self::Solarized => ThemeColors::solarized(),
+			Self::HighContrast => ThemeColors::high_contrast(),
+		};
+		Some(Theme::from(colors))
+	}
+
+	/// choice persisted in the options file
+	pub fn load() -> Self {
+		OptionsFile::load().theme
+	}
+
+	///
+	pub fn save(self) -> Result<()> {
+		OptionsFile::update(|options| options.theme = self)
+	}
+}
+
+/// information the title bar can show next to the tabs
+#[derive(
+	Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq,
+)]
+pub enum TitleSegmentKind {
+	/// name of the checked out branch
+	Branch,
+	/// commits ahead and behind the upstream branch
+	AheadBehind,
+	/// number of stashes
+	Stashes,
+	/// merge, rebase or other operation in progress
+	State,
+	/// name of the work tree directory
+	RepoName,
+	/// current time of day
+	Time,
+}
+
+/// one entry of the title bar, drawn in the given color
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TitleSegment {
+	pub kind: TitleSegmentKind,
+	#[serde(with = "Color")]
+	pub fg: Color,
+	#[serde(default)]
+	pub bold: bool,
+}
+
+impl TitleSegment {
+	const fn new(
+		kind: TitleSegmentKind,
+		fg: Color,
+		bold: bool,
+	) -> Self {
+		Self { kind, fg, bold }
+	}
+
+	pub fn style(&self) -> Style {
+		let style = Style::default().fg(self.fg);
+		if self.bold {
+			style.add_modifier(Modifier::BOLD)
+		} else {
+			style
+		}
+	}
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+// colors added later fall back to their default in older theme files
+#[serde(default)]
+#[serde(rename = "Theme")]
+struct ThemeColors {
 	selected_tab: Color,
 	#[serde(with = "Color")]
 	command_fg: Color,
@@ -50,18 +180,72 @@ pub struct Theme {
 	push_gauge_bg: Color,
 	#[serde(with = "Color")]
 	push_gauge_fg: Color,
+	/// blame gutter of the newest commit in the file
+	#[serde(with = "Color")]
+	blame_newest: Color,
+	/// blame gutter of the oldest commit in the file
+	#[serde(with = "Color")]
+	blame_oldest: Color,
+	/// foreground of selected text
+	#[serde(with = "Color")]
+	selection_fg: Color,
+	/// background of the first line of the command bar
+	#[serde(with = "Color")]
+	cmdbar_bg: Color,
+	/// names of the tabs not selected
+	#[serde(with = "Color")]
+	tab_fg: Color,
+	#[serde(with = "Color")]
+	popup_border: Color,
+	#[serde(with = "Color")]
+	scrollbar_thumb: Color,
+	#[serde(with = "Color")]
+	scrollbar_track: Color,
+	#[serde(with = "Color")]
+	diff_hunk_header: Color,
+	/// segments right of the tabs, in the order they are drawn
+	title_segments: Vec<TitleSegment>,
+}
+
+/// styles of the ui, shared by all components and changed in place
+/// to apply another theme without losing any state
+#[derive(Debug, Clone, Default)]
+pub struct Theme {
+	colors: RefCell<ThemeColors>,
+	/// set by the icon option instead of the theme file
+	icons: Cell<bool>,
+	/// set by the density option instead of the theme file
+	compact: Cell<bool>,
 }
 
 impl Theme {
+	fn colors(&self) -> Ref<'_, ThemeColors> {
+		self.colors.borrow()
+	}
+
+	/// takes over the colors of `theme`, the icon and density
+	/// options stay as they are
+	pub fn replace_colors(&self, theme: Self) {
+		self.colors.replace(theme.colors.into_inner());
+	}
+
 	pub fn scroll_bar_pos(&self) -> Style {
-		Style::default().fg(self.selection_bg)
+		Style::default().fg(self.colors().scrollbar_thumb)
+	}
+
+	pub fn scroll_bar_track(&self) -> Style {
+		Style::default().fg(self.colors().scrollbar_track)
+	}
+
+	pub fn popup_border(&self) -> Style {
+		Style::default().fg(self.colors().popup_border)
 	}
 
 	pub fn block(&self, focus: bool) -> Style {
 		if focus {
 			Style::default()
 		} else {
-			Style::default().fg(self.disabled_fg)
+			Style::default().fg(self.colors().disabled_fg)
 		}
 	}
 
@@ -69,7 +253,7 @@ impl Theme {
 		if focused {
 			Style::default().add_modifier(Modifier::BOLD)
 		} else {
-			Style::default().fg(self.disabled_fg)
+			Style::default().fg(self.colors().disabled_fg)
 		}
 	}
 
@@ -83,8 +267,8 @@ impl Theme {
 		if selected {
 			branch.patch(
 				Style::default()
-					.fg(self.command_fg)
-					.bg(self.selection_bg),
+					.fg(self.colors().selection_fg)
+					.bg(self.colors().selection_bg),
 			)
 		} else {
 			branch
@@ -94,19 +278,57 @@ impl Theme {
 	pub fn tab(&self, selected: bool) -> Style {
 		if selected {
 			self.text(true, false)
-				.fg(self.selected_tab)
+				.fg(self.colors().selected_tab)
 				.add_modifier(Modifier::UNDERLINED)
 		} else {
-			self.text(false, false)
+			Style::default().fg(self.colors().tab_fg)
+		}
+	}
+
+	/// whether to draw Nerd Font icons, see `ui::icons`
+	pub fn icons(&self) -> bool {
+		self.icons.get()
+	}
+
+	pub fn set_icons(&self, icons: bool) {
+		self.icons.set(icons);
+	}
+
+	/// whether panes are drawn without side and bottom borders
+	pub fn compact(&self) -> bool {
+		self.compact.get()
+	}
+
+	pub fn set_compact(&self, compact: bool) {
+		self.compact.set(compact);
+	}
+
+	/// borders around a pane, only a title line in compact mode
+	pub fn pane_borders(&self) -> Borders {
+		if self.compact() {
+			Borders::TOP
+		} else {
+			Borders::ALL
 		}
 	}
 
+	/// area left inside the borders of a pane drawn into `r`
+	pub fn pane_inner(&self, r: Rect) -> Rect {
+		Block::default().borders(self.pane_borders()).inner(r)
+	}
+
+	pub fn title_segments(&self) -> Ref<'_, [TitleSegment]> {
+		Ref::map(self.colors(), |colors| {
+			colors.title_segments.as_slice()
+		})
+	}
+
 	pub fn tags(&self, selected: bool) -> Style {
 		Style::default()
-			.fg(self.selected_tab)
+			.fg(self.colors().selected_tab)
 			.add_modifier(Modifier::BOLD)
 			.bg(if selected {
-				self.selection_bg
+				self.colors().selection_bg
 			} else {
 				Color::Reset
 			})
@@ -114,34 +336,37 @@ impl Theme {
 
 	pub fn text(&self, enabled: bool, selected: bool) -> Style {
 		match (enabled, selected) {
-			(false, false) => Style::default().fg(self.disabled_fg),
-			(false, true) => Style::default().bg(self.selection_bg),
+			(false, false) => {
+				Style::default().fg(self.colors().disabled_fg)
+			}
+			(false, true) => {
+				Style::default().bg(self.colors().selection_bg)
+			}
 			(true, false) => Style::default(),
 			(true, true) => Style::default()
-				.fg(self.command_fg)
-				.bg(self.selection_bg),
+				.fg(self.colors().selection_fg)
+				.bg(self.colors().selection_bg),
 		}
 	}
 
 	pub fn item(&self, typ: StatusItemType, selected: bool) -> Style {
-		let style = match typ {
-			StatusItemType::New => {
-				Style::default().fg(self.diff_file_added)
-			}
-			StatusItemType::Modified => {
-				Style::default().fg(self.diff_file_modified)
-			}
-			StatusItemType::Deleted => {
-				Style::default().fg(self.diff_file_removed)
-			}
-			StatusItemType::Renamed => {
-				Style::default().fg(self.diff_file_moved)
-			}
-			StatusItemType::Conflicted => Style::default()
-				.fg(self.diff_file_modified)
-				.add_modifier(Modifier::BOLD),
-			StatusItemType::Typechange => Style::default(),
-		};
+		let style =
+			match typ {
+				StatusItemType::New => {
+					Style::default().fg(self.colors().diff_file_added)
+				}
+				StatusItemType::Modified => Style::default()
+					.fg(self.colors().diff_file_modified),
+				StatusItemType::Deleted => Style::default()
+					.fg(self.colors().diff_file_removed),
+				StatusItemType::Renamed => {
+					Style::default().fg(self.colors().diff_file_moved)
+				}
+				StatusItemType::Conflicted => Style::default()
+					.fg(self.colors().diff_file_modified)
+					.add_modifier(Modifier::BOLD),
+				StatusItemType::Typechange => Style::default(),
+			};
 
 		self.apply_select(style, selected)
 	}
@@ -154,7 +379,7 @@ impl Theme {
 		let style = if is_folder {
 			Style::default()
 		} else {
-			Style::default().fg(self.diff_file_modified)
+			Style::default().fg(self.colors().diff_file_modified)
 		};
 
 		self.apply_select(style, selected)
@@ -162,7 +387,7 @@ impl Theme {
 
 	fn apply_select(&self, style: Style, selected: bool) -> Style {
 		if selected {
-			style.bg(self.selection_bg)
+			style.bg(self.colors().selection_bg)
 		} else {
 			style
 		}
@@ -170,17 +395,17 @@ impl Theme {
 
 	pub fn option(&self, on: bool) -> Style {
 		if on {
-			Style::default().fg(self.diff_line_add)
+			Style::default().fg(self.colors().diff_line_add)
 		} else {
-			Style::default().fg(self.diff_line_delete)
+			Style::default().fg(self.colors().diff_line_delete)
 		}
 	}
 
 	pub fn diff_hunk_marker(&self, selected: bool) -> Style {
 		if selected {
-			Style::default().bg(self.selection_bg)
+			Style::default().bg(self.colors().selection_bg)
 		} else {
-			Style::default().fg(self.disabled_fg)
+			Style::default().fg(self.colors().disabled_fg)
 		}
 	}
 
@@ -191,16 +416,16 @@ impl Theme {
 	) -> Style {
 		let style = match typ {
 			DiffLineType::Add => {
-				Style::default().fg(self.diff_line_add)
+				Style::default().fg(self.colors().diff_line_add)
 			}
 			DiffLineType::Delete => {
-				Style::default().fg(self.diff_line_delete)
+				Style::default().fg(self.colors().diff_line_delete)
 			}
 			DiffLineType::Header => Style::default()
-				.fg(self.disabled_fg)
+				.fg(self.colors().diff_hunk_header)
 				.add_modifier(Modifier::BOLD),
 			DiffLineType::None => Style::default().fg(if selected {
-				self.command_fg
+				self.colors().selection_fg
 			} else {
 				Color::Reset
 			}),
@@ -210,32 +435,32 @@ impl Theme {
 	}
 
 	pub fn text_danger(&self) -> Style {
-		Style::default().fg(self.danger_fg)
+		Style::default().fg(self.colors().danger_fg)
 	}
 
 	pub fn commandbar(&self, enabled: bool, line: usize) -> Style {
 		if enabled {
-			Style::default().fg(self.command_fg)
+			Style::default().fg(self.colors().command_fg)
 		} else {
-			Style::default().fg(self.disabled_fg)
+			Style::default().fg(self.colors().disabled_fg)
 		}
 		.bg(if line == 0 {
-			self.selection_bg
+			self.colors().cmdbar_bg
 		} else {
-			self.cmdbar_extra_lines_bg
+			self.colors().cmdbar_extra_lines_bg
 		})
 	}
 
 	pub fn commit_hash(&self, selected: bool) -> Style {
 		self.apply_select(
-			Style::default().fg(self.commit_hash),
+			Style::default().fg(self.colors().commit_hash),
 			selected,
 		)
 	}
 
 	pub fn log_marker(&self, selected: bool) -> Style {
 		let mut style = Style::default()
-			.fg(self.commit_author)
+			.fg(self.colors().commit_author)
 			.add_modifier(Modifier::BOLD);
 
 		style = self.apply_select(style, selected);
@@ -245,14 +470,14 @@ impl Theme {
 
 	pub fn commit_time(&self, selected: bool) -> Style {
 		self.apply_select(
-			Style::default().fg(self.commit_time),
+			Style::default().fg(self.colors().commit_time),
 			selected,
 		)
 	}
 
 	pub fn commit_author(&self, selected: bool) -> Style {
 		self.apply_select(
-			Style::default().fg(self.commit_author),
+			Style::default().fg(self.colors().commit_author),
 			selected,
 		)
 	}
@@ -263,37 +488,72 @@ impl Theme {
 	) -> Style {
 		if is_blamed_commit {
 			Style::default()
-				.fg(self.commit_hash)
+				.fg(self.colors().commit_hash)
 				.add_modifier(Modifier::BOLD)
 		} else {
-			Style::default().fg(self.commit_hash)
+			Style::default().fg(self.colors().commit_hash)
 		}
 	}
 
-	pub fn push_gauge(&self) -> Style {
-		Style::default()
-			.fg(self.push_gauge_fg)
-			.bg(self.push_gauge_bg)
+	/// blame gutter colored by commit age in percent, from
+	/// `blame_newest` at 0 to `blame_oldest` at 100
+	pub fn blame_age(
+		&self,
+		age: u8,
+		is_blamed_commit: bool,
+	) -> Style {
+		let colors = self.colors();
+		let color = match (colors.blame_newest, colors.blame_oldest) {
+			(
+				Color::Rgb(new_r, new_g, new_b),
+				Color::Rgb(old_r, old_g, old_b),
+			) => {
+				let mix = |new: u8, old: u8| {
+					let mixed = i32::from(new)
+						+ (i32::from(old) - i32::from(new))
+							* i32::from(age.min(100))
+							/ 100;
+					u8::try_from(mixed).unwrap_or(old)
+				};
+
+				Color::Rgb(
+					mix(new_r, old_r),
+					mix(new_g, old_g),
+					mix(new_b, old_b),
+				)
+			}
+			// named colors can not be mixed
+			_ if age < 50 => colors.blame_newest,
+			_ => colors.blame_oldest,
+		};
+
+		let style = Style::default().fg(color);
+		if is_blamed_commit {
+			style.add_modifier(Modifier::BOLD)
+		} else {
+			style
+		}
 	}
 
-	// This will only be called when theme.ron doesn't already exists
-	fn save(&self, theme_file: PathBuf) -> Result<()> {
-		let mut file = File::create(theme_file)?;
-		let data = to_string_pretty(self, PrettyConfig::default())?;
-		file.write_all(data.as_bytes())?;
-		Ok(())
+	pub fn push_gauge(&self) -> Style {
+		Style::default()
+			.fg(self.colors().push_gauge_fg)
+			.bg(self.colors().push_gauge_bg)
 	}
 
-	fn read_file(theme_file: PathBuf) -> Result<Self> {
-		let mut f = File::open(theme_file)?;
-		let mut buffer = Vec::new();
-		f.read_to_end(&mut buffer)?;
-		Ok(from_bytes(&buffer)?)
+	/// reads the theme again, unlike `init` an invalid file is
+	/// neither renamed nor replaced by the defaults
+	pub fn reload(file: PathBuf) -> Result<Self> {
+		if file.exists() {
+			ThemeColors::read_file(file).map(Self::from)
+		} else {
+			Ok(Self::default())
+		}
 	}
 
 	pub fn init(file: PathBuf) -> Result<Self> {
 		if file.exists() {
-			match Self::read_file(file.clone()) {
+			match ThemeColors::read_file(file.clone()) {
 				Err(e) => {
 					let config_path = file.clone();
 					let config_path_old =
@@ -303,21 +563,150 @@ impl Theme {
 						config_path_old.clone(),
 					)?;
 
-					Self::default().save(file)?;
+					ThemeColors::default().save(file)?;
 
 					Err(anyhow::anyhow!("{}\n Old file was renamed to {:?}.\n Defaults loaded and saved as {:?}",
                         e,config_path_old,config_path.to_string_lossy()))
 				}
-				Ok(res) => Ok(res),
+				Ok(res) => Ok(Self::from(res)),
 			}
 		} else {
-			Self::default().save(file)?;
+			ThemeColors::default().save(file)?;
 			Ok(Self::default())
 		}
 	}
 }
 
-impl Default for Theme {
+impl From<ThemeColors> for Theme {
+	fn from(colors: ThemeColors) -> Self {
+		Self {
+			colors: RefCell::new(colors),
+			..Self::default()
+		}
+	}
+}
+
+impl ThemeColors {
+	// This will only be called when theme.ron doesn't already exists
+	fn save(&self, theme_file: PathBuf) -> Result<()> {
+		let mut file = File::create(theme_file)?;
+		let data = to_string_pretty(self, PrettyConfig::default())?;
+		file.write_all(data.as_bytes())?;
+		Ok(())
+	}
+
+	fn read_file(theme_file: PathBuf) -> Result<Self> {
+		let mut f = File::open(theme_file)?;
+		let mut buffer = Vec::new();
+		f.read_to_end(&mut buffer)?;
+		Ok(from_bytes(&buffer)?)
+	}
+
+	fn light() -> Self {
+		Self {
+			selected_tab: Color::Blue,
+			command_fg: Color::Black,
+			selection_bg: Color::Rgb(175, 215, 255),
+			cmdbar_extra_lines_bg: Color::Rgb(175, 215, 255),
+			disabled_fg: Color::Gray,
+			diff_line_add: Color::Rgb(0, 135, 0),
+			diff_line_delete: Color::Rgb(175, 0, 0),
+			diff_file_added: Color::Rgb(0, 135, 0),
+			diff_file_removed: Color::Rgb(175, 0, 0),
+			diff_file_moved: Color::Magenta,
+			diff_file_modified: Color::Rgb(175, 95, 0),
+			commit_hash: Color::Magenta,
+			commit_time: Color::Blue,
+			commit_author: Color::Rgb(0, 135, 0),
+			push_gauge_bg: Color::Rgb(175, 215, 255),
+			push_gauge_fg: Color::Black,
+			selection_fg: Color::Black,
+			cmdbar_bg: Color::Rgb(175, 215, 255),
+			tab_fg: Color::Gray,
+			scrollbar_thumb: Color::Blue,
+			diff_hunk_header: Color::Gray,
+			..Self::default()
+		}
+	}
+
+	fn solarized() -> Self {
+		const BASE02: Color = Color::Rgb(7, 54, 66);
+		const BASE01: Color = Color::Rgb(88, 110, 117);
+		const BASE1: Color = Color::Rgb(147, 161, 161);
+		const YELLOW: Color = Color::Rgb(181, 137, 0);
+		const ORANGE: Color = Color::Rgb(203, 75, 22);
+		const RED: Color = Color::Rgb(220, 50, 47);
+		const MAGENTA: Color = Color::Rgb(211, 54, 130);
+		const VIOLET: Color = Color::Rgb(108, 113, 196);
+		const BLUE: Color = Color::Rgb(38, 139, 210);
+		const CYAN: Color = Color::Rgb(42, 161, 152);
+		const GREEN: Color = Color::Rgb(133, 153, 0);
+
+		Self {
+			selected_tab: YELLOW,
+			command_fg: BASE1,
+			selection_bg: BASE02,
+			cmdbar_extra_lines_bg: BASE02,
+			disabled_fg: BASE01,
+			diff_line_add: GREEN,
+			diff_line_delete: RED,
+			diff_file_added: GREEN,
+			diff_file_removed: RED,
+			diff_file_moved: VIOLET,
+			diff_file_modified: YELLOW,
+			commit_hash: MAGENTA,
+			commit_time: CYAN,
+			commit_author: GREEN,
+			danger_fg: RED,
+			push_gauge_bg: BLUE,
+			push_gauge_fg: BASE02,
+			blame_newest: ORANGE,
+			blame_oldest: BLUE,
+			selection_fg: BASE1,
+			cmdbar_bg: BASE02,
+			tab_fg: BASE01,
+			popup_border: BLUE,
+			scrollbar_thumb: BLUE,
+			scrollbar_track: BASE02,
+			diff_hunk_header: VIOLET,
+			..Self::default()
+		}
+	}
+
+	fn high_contrast() -> Self {
+		Self {
+			selected_tab: Color::Yellow,
+			command_fg: Color::Black,
+			selection_bg: Color::Yellow,
+			cmdbar_extra_lines_bg: Color::Yellow,
+			disabled_fg: Color::Gray,
+			diff_line_add: Color::LightGreen,
+			diff_line_delete: Color::LightRed,
+			diff_file_added: Color::LightGreen,
+			diff_file_removed: Color::LightRed,
+			diff_file_moved: Color::LightMagenta,
+			diff_file_modified: Color::LightYellow,
+			commit_hash: Color::LightMagenta,
+			commit_time: Color::LightCyan,
+			commit_author: Color::LightGreen,
+			danger_fg: Color::LightRed,
+			push_gauge_bg: Color::Yellow,
+			push_gauge_fg: Color::Black,
+			blame_newest: Color::LightYellow,
+			blame_oldest: Color::LightBlue,
+			selection_fg: Color::Black,
+			cmdbar_bg: Color::Yellow,
+			tab_fg: Color::White,
+			popup_border: Color::Yellow,
+			scrollbar_thumb: Color::Yellow,
+			scrollbar_track: Color::Gray,
+			diff_hunk_header: Color::LightCyan,
+			..Self::default()
+		}
+	}
+}
+
+impl Default for ThemeColors {
 	fn default() -> Self {
 		Self {
 			selected_tab: Color::Reset,
@@ -337,6 +726,37 @@ impl Default for Theme {
 			danger_fg: Color::Red,
 			push_gauge_bg: Color::Blue,
 			push_gauge_fg: Color::Reset,
+			blame_newest: Color::Rgb(255, 135, 95),
+			blame_oldest: Color::Rgb(95, 135, 175),
+			selection_fg: Color::White,
+			cmdbar_bg: Color::Blue,
+			tab_fg: Color::DarkGray,
+			popup_border: Color::Reset,
+			scrollbar_thumb: Color::Blue,
+			scrollbar_track: Color::Reset,
+			diff_hunk_header: Color::DarkGray,
+			title_segments: vec![
+				TitleSegment::new(
+					TitleSegmentKind::RepoName,
+					Color::Reset,
+					false,
+				),
+				TitleSegment::new(
+					TitleSegmentKind::Branch,
+					Color::Reset,
+					true,
+				),
+				TitleSegment::new(
+					TitleSegmentKind::AheadBehind,
+					Color::LightCyan,
+					false,
+				),
+				TitleSegment::new(
+					TitleSegmentKind::State,
+					Color::Red,
+					true,
+				),
+			],
 		}
 	}
 }